@@ -0,0 +1,228 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use challenge::{
+    client::{fetch_challenge, find_challenges_by_authority},
+    state::{Challenge, HasPda, HashAlgorithm, SolutionNormalization},
+    tx::{
+        self, AddSolutionsParams, CloseChallengeParams, CreateChallengeParams,
+        TxOptions,
+    },
+    utils::solutions_from_lines,
+};
+use clap::{Parser, Subcommand};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+/// Manage challenges as their creator.
+#[derive(Parser)]
+#[clap(name = "challenge")]
+struct Cli {
+    /// RPC endpoint of the cluster
+    #[clap(long, short, default_value = "http://localhost:8899")]
+    url: String,
+
+    /// Keypair of the creator which also pays for the transactions,
+    /// defaults to ~/.config/solana/id.json
+    #[clap(long, short)]
+    keypair: Option<PathBuf>,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Creates a challenge with the solutions found in the file
+    Create {
+        #[clap(long)]
+        id: String,
+        /// Lamports a challenger pays to be admitted
+        #[clap(long, default_value_t = 0)]
+        admit_cost: u64,
+        #[clap(long, default_value_t = 1)]
+        tries_per_admit: u8,
+        /// File with one solution per line
+        #[clap(long)]
+        from_file: Option<PathBuf>,
+        /// Trim solutions before hashing them
+        #[clap(long)]
+        trim: bool,
+        /// Lowercase solutions before hashing them
+        #[clap(long)]
+        lowercase: bool,
+        /// Apply unicode NFKC normalization to solutions before hashing them
+        #[clap(long)]
+        nfkc: bool,
+        /// Collapse whitespace runs of solutions into a single space before hashing them
+        #[clap(long)]
+        collapse_whitespace: bool,
+    },
+    /// Adds the solutions found in the file to a challenge
+    AddSolutions {
+        #[clap(long)]
+        id: String,
+        /// File with one solution per line
+        #[clap(long)]
+        from_file: PathBuf,
+    },
+    /// Prints the challenges of the creator, only the one with the id if provided
+    Show {
+        creator: Pubkey,
+        #[clap(long)]
+        id: Option<String>,
+    },
+    /// Closes a challenge returning its rent to the recipient
+    Close {
+        #[clap(long)]
+        id: String,
+        /// Defaults to the creator
+        #[clap(long)]
+        recipient: Option<Pubkey>,
+    },
+}
+
+#[tokio::main]
+async fn main() {
+    if let Err(err) = run(Cli::parse()).await {
+        eprintln!("Error: {err}");
+        std::process::exit(1);
+    }
+}
+
+async fn run(cli: Cli) -> CliResult<()> {
+    let rpc = RpcClient::new_with_commitment(
+        cli.url.clone(),
+        CommitmentConfig::confirmed(),
+    );
+    match cli.command {
+        Command::Create {
+            id,
+            admit_cost,
+            tries_per_admit,
+            from_file,
+            trim,
+            lowercase,
+            nfkc,
+            collapse_whitespace,
+        } => {
+            let creator = load_keypair(cli.keypair)?;
+            let text = from_file.map(fs::read_to_string).transpose()?;
+            let solutions = text
+                .as_deref()
+                .map(solutions_from_lines)
+                .unwrap_or_default();
+            let blockhash = rpc.get_latest_blockhash().await?;
+            let tx = tx::create_challenge(
+                &creator.pubkey(),
+                CreateChallengeParams {
+                    creator: creator.pubkey(),
+                    id: id.clone(),
+                    admit_cost,
+                    tries_per_admit,
+                    solutions,
+                    starts_at: None,
+                    ends_at: None,
+                    salt: None,
+                    hash_algorithm: HashAlgorithm::default(),
+                    solution_normalization: SolutionNormalization {
+                        trim,
+                        lowercase,
+                        nfkc,
+                        collapse_whitespace,
+                    },
+                    options: TxOptions::default(),
+                },
+                blockhash,
+            )?;
+            send(&rpc, tx, &creator).await?;
+            let (challenge_pda, _) = Challenge::pda_for(&creator.pubkey(), &id);
+            println!("Created challenge '{id}' at {challenge_pda}");
+        }
+        Command::AddSolutions { id, from_file } => {
+            let creator = load_keypair(cli.keypair)?;
+            let text = fs::read_to_string(from_file)?;
+            let solutions = solutions_from_lines(&text);
+            let len = solutions.len();
+            // solutions are normalized with the rules of the challenge
+            let challenge = fetch_challenge(&rpc, &creator.pubkey(), &id)
+                .await?
+                .ok_or_else(|| format!("Challenge '{id}' not found"))?;
+            let blockhash = rpc.get_latest_blockhash().await?;
+            let tx = tx::add_solutions(
+                &creator.pubkey(),
+                AddSolutionsParams {
+                    creator: creator.pubkey(),
+                    id: id.clone(),
+                    solutions,
+                    solution_normalization: challenge.solution_normalization,
+                    options: TxOptions::default(),
+                },
+                blockhash,
+            )?;
+            send(&rpc, tx, &creator).await?;
+            println!("Added {len} solutions to challenge '{id}'");
+        }
+        Command::Show { creator, id } => {
+            let challenges = match id {
+                Some(id) => fetch_challenge(&rpc, &creator, &id)
+                    .await?
+                    .map(|challenge| vec![(challenge.pda().0, challenge)])
+                    .ok_or_else(|| {
+                        format!("Challenge '{id}' of {creator} not found")
+                    })?,
+                None => find_challenges_by_authority(&rpc, &creator).await?,
+            };
+            for (challenge_pda, challenge) in challenges {
+                println!("{challenge_pda}");
+                println!("{}", serde_json::to_string_pretty(&challenge)?);
+            }
+        }
+        Command::Close { id, recipient } => {
+            let creator = load_keypair(cli.keypair)?;
+            let blockhash = rpc.get_latest_blockhash().await?;
+            let tx = tx::close_challenge(
+                &creator.pubkey(),
+                CloseChallengeParams {
+                    creator: creator.pubkey(),
+                    id: id.clone(),
+                    recipient: recipient.unwrap_or_else(|| creator.pubkey()),
+                    options: TxOptions::default(),
+                },
+                blockhash,
+            )?;
+            send(&rpc, tx, &creator).await?;
+            println!("Closed challenge '{id}'");
+        }
+    }
+    Ok(())
+}
+
+fn load_keypair(path: Option<PathBuf>) -> CliResult<Keypair> {
+    let path = match path {
+        Some(path) => path,
+        None => {
+            PathBuf::from(std::env::var("HOME")?).join(".config/solana/id.json")
+        }
+    };
+    read_keypair_file(&path)
+        .map_err(|err| format!("Failed to read keypair {path:?}: {err}").into())
+}
+
+async fn send(
+    rpc: &RpcClient,
+    mut tx: Transaction,
+    creator: &Keypair,
+) -> CliResult<()> {
+    let blockhash = tx.message.recent_blockhash;
+    tx.try_sign(&[creator], blockhash)?;
+    let signature = rpc.send_and_confirm_transaction(&tx).await?;
+    println!("Signature: {signature}");
+    Ok(())
+}
@@ -1,4 +1,8 @@
-use challenge::{ixs, shank_utils::RenderShankAccounts};
+use challenge::{
+    ixs,
+    shank_utils::RenderShankAccounts,
+    state::{AccountKind, ChallengeTemplate, Payout, TemplateOverrides},
+};
 use solana_program::pubkey::Pubkey;
 
 const PAYER_DESC: &str = "pays for the transaction";
@@ -10,6 +14,27 @@ const CHALLENGER_PDA_DESC: &str = "PDA for the challenger";
 
 const REDEEM_PDA_DESC: &str = "PDA of token to redeem for correct solution";
 const REDEEM_ATA_DESC: &str = "ATA for redeem PDA and challenger";
+const TEMPLATE_PDA_DESC: &str = "PDA for the challenge template";
+const ADMIT_MINT_DESC: &str =
+    "admit mint of the challenge, required if it has one";
+const PAYER_ATA_DESC: &str =
+    "ATA of the payer for the admit mint which pays the admit cost";
+const ESCROW_ATA_DESC: &str =
+    "ATA of the challenge PDA for the admit mint which receives the admit cost";
+const PRIZE_POOL_DESC: &str = "PDA for the prize pool of the challenge";
+const NFT_MINT_DESC: &str =
+    "PDA of the NFT rewarded for the solution, required if the challenge rewards NFTs";
+const NFT_ATA_DESC: &str =
+    "ATA for the NFT and challenger, required if the challenge rewards NFTs";
+const COMMITMENT_PDA_DESC: &str = "PDA holding the challenger's commitment";
+const LEADERBOARD_PDA_DESC: &str = "PDA for the challenge leaderboard";
+const CONFIG_PDA_DESC: &str = "PDA for the program config";
+const ADMIN_DESC: &str = "authority of the config";
+const FEE_DESTINATION_DESC: &str =
+    "receives the protocol fee, required once the config is initialized";
+const FEE_DESTINATION_ATA_DESC: &str = "ATA of the fee destination for the admit mint, required once the config is initialized if the challenge has an admit mint";
+const FIRST_SOLVERS_DESC: &str =
+    "PDA for the first solvers leaderboard, required if the challenge has one";
 
 fn main() {
     {
@@ -20,6 +45,8 @@ fn main() {
             0,
             0,
             vec![],
+            None,
+            None,
         )
         .unwrap();
 
@@ -65,14 +92,19 @@ fn main() {
         );
     }
     {
-        let ix = ixs::admit_challenger(
+        let mut ix = ixs::admit_challenger_with_admit_mint(
             Pubkey::new_unique(),
             Pubkey::new_unique(),
             "id",
             Pubkey::new_unique(),
+            Pubkey::new_unique(),
         )
         .unwrap()
         .ix;
+        ix.accounts.extend(ixs::protocol_fee_accounts(
+            &Pubkey::new_unique(),
+            Some(&Pubkey::new_unique()),
+        ));
         eprintln!(
             "{}\n    AdmitChallenger {{",
             ix.render_shank_accounts(&[
@@ -81,32 +113,886 @@ fn main() {
                 ("challenge_pda", CHALLENGE_PDA_DESC),
                 ("challenger", CHALLENGER_DESC),
                 ("challenger_pda", CHALLENGER_PDA_DESC),
-                ("system_program", "System Program")
+                ("system_program", "System Program"),
+                (
+                    "prize_pool",
+                    "prize pool of the challenge, receives the admit cost if the challenge has one"
+                ),
+                ("admit_mint", ADMIT_MINT_DESC),
+                ("payer_ata", PAYER_ATA_DESC),
+                ("escrow_ata", ESCROW_ATA_DESC),
+                ("token_program", "Token Program"),
+                ("associated_token_program", "Associated Token Program"),
+                ("config", CONFIG_PDA_DESC),
+                ("fee_destination", FEE_DESTINATION_DESC),
+                ("fee_destination_ata", FEE_DESTINATION_ATA_DESC),
             ])
         );
     }
     {
-        let ix = ixs::redeem(
+        let mut ix = ixs::redeem_with_nft(
             Pubkey::new_unique(),
             Pubkey::new_unique(),
             "id",
             Pubkey::new_unique(),
             "solution",
+            0,
         )
         .unwrap();
+        ix.accounts
+            .extend(ixs::first_solvers_accounts(&Pubkey::new_unique()));
         eprintln!(
             "{}\n    Redeem {{",
             ix.render_shank_accounts(&[
                 ("payer", PAYER_DESC),
                 ("challenge_pda", CHALLENGE_PDA_DESC),
-                ("challenger", CHALLENGER_DESC),
+                (
+                    "challenger",
+                    "challenger account which receives the redeemed token and prize"
+                ),
+                ("challenger_pda", CHALLENGER_PDA_DESC),
+                ("redeem", REDEEM_PDA_DESC),
+                ("redeem_ata", REDEEM_ATA_DESC),
+                ("token_program", "Token Program"),
+                ("associated_token_program", "Associated Token Program"),
+                ("system_program", "System Program"),
+                (
+                    "prize_pool",
+                    "prize pool of the challenge, required if it has one"
+                ),
+                ("nft_mint", NFT_MINT_DESC),
+                ("nft_ata", NFT_ATA_DESC),
+                ("first_solvers", FIRST_SOLVERS_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::set_solve_window(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            None,
+            None,
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    SetSolveWindow {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("system_program", "System Program")
+            ])
+        );
+    }
+    {
+        let ix = ixs::get_challenge_state(Pubkey::new_unique(), "id").unwrap();
+        eprintln!(
+            "{}\n    GetChallengeState {{",
+            ix.render_shank_accounts(&[("challenge_pda", CHALLENGE_PDA_DESC)])
+        );
+    }
+    {
+        let ix = ixs::split_challenge(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id",
+            "new-id".to_string(),
+            1,
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    SplitChallenge {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                (
+                    "new_challenge_pda",
+                    "PDA for the challenge created by the split"
+                ),
+                (
+                    "new_redeem_pda",
+                    "PDA of token to redeem for correct solution of the new challenge"
+                ),
+                ("token_program", "Token Program"),
+                ("system_program", "System Program")
+            ])
+        );
+    }
+    {
+        let ix =
+            ixs::truncate_solutions(Pubkey::new_unique(), "id".to_string(), 1)
+                .unwrap();
+        eprintln!(
+            "{}\n    TruncateSolutions {{",
+            ix.render_shank_accounts(&[
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::merge_challenges(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            "source-id".to_string(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    MergeChallenges {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                (
+                    "source_challenge_pda",
+                    "PDA for the challenge whose solutions are merged and which is closed"
+                ),
+                ("system_program", "System Program")
+            ])
+        );
+    }
+    {
+        let ix = ixs::lock_admit_cost(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    LockAdmitCost {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::create_template(
+            Pubkey::new_unique(),
+            &ChallengeTemplate {
+                authority: Pubkey::new_unique(),
+                template_id: 1,
+                admit_cost: 100,
+                tries_per_admit: 1,
+                ..ChallengeTemplate::default()
+            },
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    CreateTemplate {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", "template and challenge authority"),
+                ("template_pda", TEMPLATE_PDA_DESC),
+                ("system_program", "System Program")
+            ])
+        );
+    }
+    {
+        let ix = ixs::create_challenge_from_template(
+            Pubkey::new_unique(),
+            &ChallengeTemplate {
+                authority: Pubkey::new_unique(),
+                template_id: 1,
+                ..ChallengeTemplate::default()
+            },
+            "id".to_string(),
+            TemplateOverrides::default(),
+            vec![],
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    CreateChallengeFromTemplate {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("redeem_pda", REDEEM_PDA_DESC),
+                ("token_program", "Token Program"),
+                ("system_program", "System Program"),
+                ("template_pda", TEMPLATE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::set_oracle(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            Pubkey::new_unique(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    SetOracle {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let mut ixs = ixs::verify_solve_signature(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id",
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            &[0; 32],
+            [0; 64],
+        )
+        .unwrap();
+        ixs[1].accounts.extend(ixs::nft_reward_accounts(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            0,
+        ));
+        ixs[1]
+            .accounts
+            .extend(ixs::first_solvers_accounts(&Pubkey::new_unique()));
+        eprintln!(
+            "{}\n    VerifySolveSignature {{",
+            ixs[1].render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                (
+                    "challenger",
+                    "challenger account which receives the redeemed token and prize"
+                ),
                 ("challenger_pda", CHALLENGER_PDA_DESC),
                 ("redeem", REDEEM_PDA_DESC),
                 ("redeem_ata", REDEEM_ATA_DESC),
                 ("token_program", "Token Program"),
                 ("associated_token_program", "Associated Token Program"),
+                ("system_program", "System Program"),
+                ("instructions_sysvar", "Instructions Sysvar"),
+                (
+                    "prize_pool",
+                    "prize pool of the challenge, required if it has one"
+                ),
+                ("nft_mint", NFT_MINT_DESC),
+                ("nft_ata", NFT_ATA_DESC),
+                ("first_solvers", FIRST_SOLVERS_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::update_solution_hash(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            0,
+            [0; 32],
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    UpdateSolutionHash {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::lock_solutions(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    SetSolutionsLocked {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let mut ix = ixs::reissue_admission_with_admit_mint(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id",
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        )
+        .unwrap();
+        ix.accounts.extend(ixs::protocol_fee_accounts(
+            &Pubkey::new_unique(),
+            Some(&Pubkey::new_unique()),
+        ));
+        eprintln!(
+            "{}\n    ReissueAdmission {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("challenger", CHALLENGER_DESC),
+                ("challenger_pda", CHALLENGER_PDA_DESC),
+                ("system_program", "System Program"),
+                (
+                    "prize_pool",
+                    "prize pool of the challenge, receives the admit cost if the challenge has one"
+                ),
+                ("admit_mint", ADMIT_MINT_DESC),
+                ("payer_ata", PAYER_ATA_DESC),
+                ("escrow_ata", ESCROW_ATA_DESC),
+                ("token_program", "Token Program"),
+                ("associated_token_program", "Associated Token Program"),
+                ("config", CONFIG_PDA_DESC),
+                ("fee_destination", FEE_DESTINATION_DESC),
+                ("fee_destination_ata", FEE_DESTINATION_ATA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::add_co_sponsor(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id",
+            1,
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    AddCoSponsor {{",
+            ix.render_shank_accounts(&[
+                (
+                    "sponsor",
+                    "contributes the lamports and pays for the transaction"
+                ),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("sponsor_log_pda", "PDA logging the sponsor's contributions"),
+                ("system_program", "System Program"),
+                (
+                    "prize_pool",
+                    "prize pool of the challenge, required if it has one"
+                ),
+            ])
+        );
+    }
+    {
+        let ix = ixs::create_leaderboard(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    CreateLeaderboard {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("leaderboard_pda", LEADERBOARD_PDA_DESC),
+                ("system_program", "System Program")
+            ])
+        );
+    }
+    {
+        let ix = ixs::update_leaderboard(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id",
+            Pubkey::new_unique(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    UpdateLeaderboard {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("challenger", "challenger that redeemed"),
+                ("challenger_pda", CHALLENGER_PDA_DESC),
+                ("leaderboard_pda", LEADERBOARD_PDA_DESC),
                 ("system_program", "System Program")
             ])
         );
     }
+    {
+        let ix = ixs::remove_solutions(
+            Pubkey::new_unique(),
+            "id".to_string(),
+            vec![0],
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    RemoveSolutions {{",
+            ix.render_shank_accounts(&[
+                ("creator", "challenge authority, receives the refunded rent"),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::replace_solutions(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            vec!["solution"],
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    ReplaceSolutions {{",
+            ix.render_shank_accounts(&[
+                ("payer", "pays for or receives the rent difference"),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
+    {
+        let ix = ixs::close_challenge(
+            Pubkey::new_unique(),
+            "id".to_string(),
+            Pubkey::new_unique(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    CloseChallenge {{",
+            ix.render_shank_accounts(&[
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("recipient", "receives the lamports of the closed challenge"),
+            ])
+        );
+    }
+    {
+        let ix = ixs::transfer_authority(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            Pubkey::new_unique(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    TransferAuthority {{",
+            ix.render_shank_accounts(&[
+                ("payer", "pays for or receives the rent difference"),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
+    {
+        let ix = ixs::accept_authority(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    AcceptAuthority {{",
+            ix.render_shank_accounts(&[
+                (
+                    "new_authority",
+                    "pending authority of the challenge, receives the refunded rent",
+                ),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::pause_challenge(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    SetPaused {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::update_challenge(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            Some(1),
+            Some(1),
+            Some(Pubkey::new_unique()),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    UpdateChallenge {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("redeem", "the new mint to redeem for correct solutions"),
+            ])
+        );
+    }
+    {
+        let ix = ixs::set_admit_mint(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            Some(Pubkey::new_unique()),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    SetAdmitMint {{",
+            ix.render_shank_accounts(&[
+                ("payer", "pays for or receives the rent difference"),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("system_program", "System Program"),
+                (
+                    "admit_mint",
+                    "the new admit mint, required when setting one"
+                ),
+            ])
+        );
+    }
+    {
+        let ix = ixs::withdraw_admit_tokens(
+            Pubkey::new_unique(),
+            "id".to_string(),
+            Pubkey::new_unique(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    WithdrawAdmitTokens {{",
+            ix.render_shank_accounts(&[
+                ("creator", "challenge authority, receives the admit tokens"),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("admit_mint", "admit mint of the challenge"),
+                ("escrow_ata", "ATA of the challenge PDA for the admit mint"),
+                ("creator_ata", "ATA of the creator for the admit mint"),
+                ("token_program", "Token Program"),
+                ("associated_token_program", "Associated Token Program"),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
+    {
+        let ix = ixs::create_prize_pool(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            Payout::WinnerTakesAll,
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    CreatePrizePool {{",
+            ix.render_shank_accounts(&[
+                (
+                    "payer",
+                    "pays for the transaction and the prize pool account"
+                ),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("prize_pool", PRIZE_POOL_DESC),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
+    {
+        let ix =
+            ixs::withdraw_prize_pool(Pubkey::new_unique(), "id".to_string())
+                .unwrap();
+        eprintln!(
+            "{}\n    WithdrawPrizePool {{",
+            ix.render_shank_accounts(&[
+                (
+                    "creator",
+                    "challenge authority, receives the leftover lamports"
+                ),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("prize_pool", PRIZE_POOL_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::set_nft_reward(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            true,
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    SetNftReward {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::commit_solution(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id",
+            Pubkey::new_unique(),
+            "solution",
+            [0; 32],
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    CommitSolution {{",
+            ix.render_shank_accounts(&[
+                (
+                    "payer",
+                    "pays for the transaction and the commitment account"
+                ),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("challenger", "challenger committing to the solution"),
+                ("challenger_pda", CHALLENGER_PDA_DESC),
+                ("commitment_pda", COMMITMENT_PDA_DESC),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
+    {
+        let mut ix = ixs::reveal_solution(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id",
+            Pubkey::new_unique(),
+            "solution",
+            [0; 32],
+        )
+        .unwrap();
+        ix.accounts.extend(ixs::nft_reward_accounts(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            0,
+        ));
+        ix.accounts
+            .extend(ixs::first_solvers_accounts(&Pubkey::new_unique()));
+        eprintln!(
+            "{}\n    RevealSolution {{",
+            ix.render_shank_accounts(&[
+                (
+                    "payer",
+                    "pays for the transaction and receives the commitment rent"
+                ),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                (
+                    "challenger",
+                    "challenger account which receives the redeemed token and prize"
+                ),
+                ("challenger_pda", CHALLENGER_PDA_DESC),
+                ("redeem", REDEEM_PDA_DESC),
+                ("redeem_ata", REDEEM_ATA_DESC),
+                ("token_program", "Token Program"),
+                ("associated_token_program", "Associated Token Program"),
+                ("system_program", "System Program"),
+                ("commitment_pda", COMMITMENT_PDA_DESC),
+                (
+                    "prize_pool",
+                    "prize pool of the challenge, required if it has one"
+                ),
+                ("nft_mint", NFT_MINT_DESC),
+                ("nft_ata", NFT_ATA_DESC),
+                ("first_solvers", FIRST_SOLVERS_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::set_commit_reveal(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            true,
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    SetCommitReveal {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::create_first_solvers(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    CreateFirstSolvers {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("first_solvers", "PDA for the first solvers leaderboard"),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
+    {
+        let ix = ixs::append_solutions_chunk(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            0,
+            2,
+            vec![],
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    AppendSolutionsChunk {{",
+            ix.render_shank_accounts(&[
+                ("payer", PAYER_DESC),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("solutions_upload", "PDA collecting the uploaded solutions"),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
+    {
+        let ix = ixs::init_config(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            Pubkey::new_unique(),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    InitConfig {{",
+            ix.render_shank_accounts(&[
+                ("payer", "pays for the transaction and the config rent"),
+                ("admin", ADMIN_DESC),
+                ("config", CONFIG_PDA_DESC),
+                (
+                    "program_data",
+                    "program data account holding the upgrade authority",
+                ),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
+    {
+        let ix =
+            ixs::update_config(Pubkey::new_unique(), Some(100), None, None)
+                .unwrap();
+        eprintln!(
+            "{}\n    UpdateConfig {{",
+            ix.render_shank_accounts(&[
+                ("admin", ADMIN_DESC),
+                ("config", CONFIG_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::set_allowlist(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            Some([0; 32]),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    SetAllowlist {{",
+            ix.render_shank_accounts(&[
+                ("payer", "pays for or receives the rent difference"),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
+    {
+        let ix = ixs::set_max_admissions(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "id".to_string(),
+            Some(100),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    SetMaxAdmissions {{",
+            ix.render_shank_accounts(&[
+                ("payer", "pays for or receives the rent difference"),
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
+    {
+        let ix = ixs::cancel_challenge(Pubkey::new_unique(), "id".to_string())
+            .unwrap();
+        eprintln!(
+            "{}\n    CancelChallenge {{",
+            ix.render_shank_accounts(&[
+                ("creator", CREATOR_DESC),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+            ])
+        );
+    }
+    {
+        let ix = ixs::refund_unused_tries(
+            Pubkey::new_unique(),
+            "id".to_string(),
+            Pubkey::new_unique(),
+            Some(Pubkey::new_unique()),
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    RefundUnusedTries {{",
+            ix.render_shank_accounts(&[
+                ("challenger", "the challenger receiving the refund"),
+                ("challenge_pda", CHALLENGE_PDA_DESC),
+                ("challenger_pda", CHALLENGER_PDA_DESC),
+                ("system_program", "System Program"),
+                (
+                    "prize_pool",
+                    "prize pool of the challenge holding the admit cost paid in lamports",
+                ),
+                ("admit_mint", "admit mint of the challenge"),
+                (
+                    "challenger_ata",
+                    "ATA of the challenger receiving the refunded tokens",
+                ),
+                (
+                    "escrow_ata",
+                    "ATA of the challenge PDA holding the admit tokens",
+                ),
+                ("token_program", "SPL Token Program"),
+                (
+                    "associated_token_program",
+                    "SPL Associated Token Program",
+                ),
+            ])
+        );
+    }
+    {
+        let ix = ixs::migrate_account(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            AccountKind::Challenge,
+        )
+        .unwrap();
+        eprintln!(
+            "{}\n    MigrateAccount {{",
+            ix.render_shank_accounts(&[
+                ("payer", "pays for the transaction and the extra rent"),
+                ("account", "the account to migrate"),
+                ("system_program", "System Program"),
+            ])
+        );
+    }
 }
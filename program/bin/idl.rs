@@ -0,0 +1,34 @@
+use std::{env, fs, path::PathBuf};
+
+use shank_idl::{extract_idl, ParseIdlOpts};
+
+/// Generates the IDL of the program from the shank annotations of its instructions and
+/// accounts and writes it to the provided path, defaulting to `ts/idl/challenge.json` from
+/// which the TypeScript client is generated via solita.
+///
+/// cargo run --features idl --bin idl [out_path]
+fn main() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let out_path = env::args().nth(1).map(PathBuf::from).unwrap_or_else(|| {
+        manifest_dir
+            .join("..")
+            .join("ts")
+            .join("idl")
+            .join("challenge.json")
+    });
+
+    let lib_rs = manifest_dir.join("src").join("lib.rs");
+    let idl = extract_idl(
+        lib_rs.to_str().expect("lib.rs path should be valid utf8"),
+        ParseIdlOpts::default(),
+    )
+    .expect("failed to parse program")
+    .expect("program should expose an IDL");
+    let json = idl.try_into_json().expect("failed to serialize IDL");
+
+    if let Some(dir) = out_path.parent() {
+        fs::create_dir_all(dir).expect("failed to create IDL directory");
+    }
+    fs::write(&out_path, json).expect("failed to write IDL");
+    println!("wrote IDL to {}", out_path.display());
+}
@@ -0,0 +1,47 @@
+//! Hashes the bundled weak solutions at build time, see `utils::weak_solutions`.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+use sha2::{Digest, Sha256};
+
+const WEAK_SOLUTIONS_FILE: &str = "weak_solutions.txt";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", WEAK_SOLUTIONS_FILE);
+
+    let text = fs::read_to_string(WEAK_SOLUTIONS_FILE)
+        .expect("failed to read weak solutions");
+    let solutions = text
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect::<Vec<_>>();
+
+    let mut code = String::new();
+    writeln!(
+        code,
+        "pub const WEAK_SOLUTIONS: [&str; {}] = [",
+        solutions.len()
+    )
+    .unwrap();
+    for solution in &solutions {
+        writeln!(code, "    {:?},", solution).unwrap();
+    }
+    writeln!(code, "];").unwrap();
+
+    // the solutions as challengers send them to challenges using Sha256
+    writeln!(
+        code,
+        "pub const WEAK_SOLUTION_HASHES: [[u8; 32]; {}] = [",
+        solutions.len()
+    )
+    .unwrap();
+    for solution in &solutions {
+        let hash: [u8; 32] = Sha256::digest(solution.as_bytes()).into();
+        writeln!(code, "    {:?},", hash).unwrap();
+    }
+    writeln!(code, "];").unwrap();
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("weak_solutions.rs"), code)
+        .expect("failed to write weak solutions");
+}
@@ -0,0 +1,221 @@
+//! Feeds arbitrary instruction data and account states into the processor.
+//!
+//! Run with `cargo fuzz run process_instruction` from the `program` directory.
+//!
+//! The accounts are serialized into the same input buffer the loader passes to the
+//! entrypoint and deserialized via [entrypoint::deserialize], such that account data can be
+//! reallocated just like on chain. CPIs are not executed off chain, thus every lamport the
+//! processor moves has to be moved between the provided accounts.
+#![no_main]
+
+use std::mem::size_of;
+
+use arbitrary::Arbitrary;
+use challenge::{
+    challenge_id, processor,
+    state::{Challenge, HasDiscriminator, HasPda, CHALLENGE_VERSION},
+};
+use libfuzzer_sys::fuzz_target;
+use solana_program::{
+    entrypoint::{self, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER},
+    pubkey::Pubkey,
+    system_program,
+};
+
+const MAX_DATA_LEN: usize = 10 * 1024;
+
+#[derive(Debug, Arbitrary)]
+struct FuzzInput {
+    accounts: Vec<FuzzAccount>,
+    instruction_data: Vec<u8>,
+}
+
+#[derive(Debug, Arbitrary)]
+struct FuzzAccount {
+    key: FuzzKey,
+    is_signer: bool,
+    is_writable: bool,
+    owned_by_program: bool,
+    lamports: u64,
+    data: FuzzData,
+}
+
+#[derive(Debug, Arbitrary)]
+enum FuzzKey {
+    Seeded(u8),
+    SystemProgram,
+    TokenProgram,
+    ChallengeProgram,
+    ChallengePda { creator: u8, id: String },
+}
+
+#[derive(Debug, Arbitrary)]
+enum FuzzData {
+    Raw(Vec<u8>),
+    /// A valid challenge with some of its bytes overwritten afterwards.
+    Challenge {
+        creator: u8,
+        id: String,
+        started: bool,
+        solving: u8,
+        solutions: Vec<[u8; 32]>,
+        mutations: Vec<(u16, u8)>,
+    },
+}
+
+fn seeded_pubkey(seed: u8) -> Pubkey {
+    Pubkey::new_from_array([seed; 32])
+}
+
+impl FuzzKey {
+    fn pubkey(&self) -> Pubkey {
+        match self {
+            FuzzKey::Seeded(seed) => seeded_pubkey(*seed),
+            FuzzKey::SystemProgram => system_program::id(),
+            FuzzKey::TokenProgram => spl_token::id(),
+            FuzzKey::ChallengeProgram => challenge_id(),
+            FuzzKey::ChallengePda { creator, id } => {
+                Challenge::pda_for(&seeded_pubkey(*creator), id).0
+            }
+        }
+    }
+}
+
+impl FuzzData {
+    fn bytes(&self) -> Vec<u8> {
+        let mut data = match self {
+            FuzzData::Raw(data) => data.clone(),
+            FuzzData::Challenge {
+                creator,
+                id,
+                started,
+                solving,
+                solutions,
+                mutations,
+            } => {
+                let challenge = Challenge {
+                    version: CHALLENGE_VERSION,
+                    authority: seeded_pubkey(*creator),
+                    id: id.clone(),
+                    started: *started,
+                    solving: *solving,
+                    solutions: solutions.clone(),
+                    ..Challenge::default()
+                };
+                let challenge = Challenge {
+                    bump: challenge.pda().1,
+                    ..challenge
+                };
+                let mut data = challenge.to_account_data().unwrap_or_default();
+                for (idx, byte) in mutations {
+                    if !data.is_empty() {
+                        let idx = *idx as usize % data.len();
+                        data[idx] = *byte;
+                    }
+                }
+                data
+            }
+        };
+        data.truncate(MAX_DATA_LEN);
+        data
+    }
+}
+
+/// Serializes the accounts and instruction in the format the loader passes to the
+/// entrypoint, marking repeated keys as duplicates.
+/// Returns `u64`s to ensure the buffer is aligned like the loader input.
+fn serialize_input(input: &FuzzInput) -> Vec<u64> {
+    fn push_u64(buf: &mut Vec<u8>, val: u64) {
+        buf.extend_from_slice(&val.to_le_bytes());
+    }
+
+    let mut buf = Vec::new();
+    push_u64(&mut buf, input.accounts.len() as u64);
+
+    let mut keys = Vec::<Pubkey>::new();
+    for account in &input.accounts {
+        let key = account.key.pubkey();
+        if let Some(dup) = keys.iter().position(|k| *k == key) {
+            buf.push(dup as u8);
+            buf.extend_from_slice(&[0; 7]);
+            keys.push(key);
+            continue;
+        }
+        keys.push(key);
+
+        let owner = if account.owned_by_program {
+            challenge_id()
+        } else {
+            system_program::id()
+        };
+        let data = account.data.bytes();
+
+        buf.push(NON_DUP_MARKER);
+        buf.push(account.is_signer as u8);
+        buf.push(account.is_writable as u8);
+        // executable
+        buf.push(0);
+        // original data len, filled in during deserialization
+        buf.extend_from_slice(&[0; size_of::<u32>()]);
+        buf.extend_from_slice(key.as_ref());
+        buf.extend_from_slice(owner.as_ref());
+        push_u64(&mut buf, account.lamports);
+        push_u64(&mut buf, data.len() as u64);
+        buf.extend_from_slice(&data);
+        buf.resize(buf.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+        let padding = (8 - buf.len() % 8) % 8;
+        buf.resize(buf.len() + padding, 0);
+        // rent epoch
+        push_u64(&mut buf, 0);
+    }
+
+    push_u64(&mut buf, input.instruction_data.len() as u64);
+    buf.extend_from_slice(&input.instruction_data);
+    buf.extend_from_slice(challenge_id().as_ref());
+
+    buf.chunks(8)
+        .map(|chunk| {
+            let mut bytes = [0; 8];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(bytes)
+        })
+        .collect()
+}
+
+fuzz_target!(|input: FuzzInput| {
+    // the loader would reject transactions referencing that many accounts
+    if input.accounts.len() > u8::MAX as usize {
+        return;
+    }
+    let mut buf = serialize_input(&input);
+
+    // SAFETY: the buffer was serialized in the exact layout the loader uses and outlives
+    // the deserialized accounts
+    let (program_id, accounts, instruction_data) =
+        unsafe { entrypoint::deserialize(buf.as_mut_ptr() as *mut u8) };
+
+    let unique_accounts = accounts
+        .iter()
+        .enumerate()
+        .filter(|(idx, account)| {
+            !accounts[..*idx].iter().any(|a| a.key == account.key)
+        })
+        .map(|(_, account)| account)
+        .collect::<Vec<_>>();
+    let lamports = || {
+        unique_accounts
+            .iter()
+            .map(|account| account.lamports() as u128)
+            .sum::<u128>()
+    };
+
+    let lamports_before = lamports();
+    let result = processor::process(program_id, &accounts, instruction_data);
+    if result.is_ok() {
+        assert_eq!(
+            lamports(),
+            lamports_before,
+            "lamports are not conserved by a successful instruction"
+        );
+    }
+});
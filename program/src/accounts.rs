@@ -0,0 +1,1267 @@
+//! Positions and expected writability and signedness of the accounts each instruction
+//! receives, mirroring the `#[account]` attributes of
+//! [crate::ixs::ChallengeInstruction].
+//!
+//! The processor validates the provided accounts against these before dispatching the
+//! instruction, see [crate::ixs::ChallengeInstruction::account_specs].
+//! Keep them in sync with the attributes when adding or reordering accounts.
+
+/// Describes the account an instruction expects at a specific index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountSpec {
+    pub name: &'static str,
+    pub writable: bool,
+    pub signer: bool,
+    /// Optional accounts may be omitted or replaced with a placeholder, thus they and all
+    /// accounts following them are verified by the processor once it uses them.
+    pub optional: bool,
+}
+
+impl AccountSpec {
+    const fn new(
+        name: &'static str,
+        writable: bool,
+        signer: bool,
+        optional: bool,
+    ) -> Self {
+        Self {
+            name,
+            writable,
+            signer,
+            optional,
+        }
+    }
+
+    /// A required account that is neither written to nor signs.
+    const fn readonly(name: &'static str) -> Self {
+        Self::new(name, false, false, false)
+    }
+
+    /// A required account that is written to.
+    const fn writable(name: &'static str) -> Self {
+        Self::new(name, true, false, false)
+    }
+
+    /// A required account that signs without being written to.
+    const fn signer(name: &'static str) -> Self {
+        Self::new(name, false, true, false)
+    }
+
+    /// A required account that signs and is written to.
+    const fn writable_signer(name: &'static str) -> Self {
+        Self::new(name, true, true, false)
+    }
+
+    /// An account that is only required in some cases.
+    const fn optional(name: &'static str, writable: bool) -> Self {
+        Self::new(name, writable, false, true)
+    }
+}
+
+pub mod create_challenge {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const REDEEM_PDA: usize = 3;
+    pub const TOKEN_PROGRAM: usize = 4;
+    pub const SYSTEM_PROGRAM: usize = 5;
+    pub const CREATOR_REGISTRY: usize = 6;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::readonly("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("redeem_pda"),
+        AccountSpec::readonly("token_program"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("creator_registry", true),
+    ];
+}
+
+pub mod add_solutions {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod start_challenge {
+    use super::AccountSpec;
+
+    pub const CREATOR: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod admit_challenger {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const CHALLENGER: usize = 3;
+    pub const CHALLENGER_PDA: usize = 4;
+    pub const SYSTEM_PROGRAM: usize = 5;
+    pub const PRIZE_POOL: usize = 6;
+    pub const ADMIT_MINT: usize = 7;
+    pub const PAYER_ATA: usize = 8;
+    pub const ESCROW_ATA: usize = 9;
+    pub const TOKEN_PROGRAM: usize = 10;
+    pub const ASSOCIATED_TOKEN_PROGRAM: usize = 11;
+    pub const CONFIG: usize = 12;
+    pub const FEE_DESTINATION: usize = 13;
+    pub const FEE_DESTINATION_ATA: usize = 14;
+    pub const TICKET_MINT: usize = 15;
+    pub const TICKET_ATA: usize = 16;
+    pub const TICKET_TOKEN_PROGRAM: usize = 17;
+    pub const TICKET_ASSOCIATED_TOKEN_PROGRAM: usize = 18;
+    pub const PREREQUISITE_CHALLENGER_PDA: usize = 19;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::writable("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("challenger"),
+        AccountSpec::writable("challenger_pda"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("prize_pool", true),
+        AccountSpec::optional("admit_mint", false),
+        AccountSpec::optional("payer_ata", true),
+        AccountSpec::optional("escrow_ata", true),
+        AccountSpec::optional("token_program", false),
+        AccountSpec::optional("associated_token_program", false),
+        AccountSpec::readonly("config"),
+        AccountSpec::optional("fee_destination", true),
+        AccountSpec::optional("fee_destination_ata", true),
+        AccountSpec::optional("ticket_mint", true),
+        AccountSpec::optional("ticket_ata", true),
+        AccountSpec::optional("ticket_token_program", false),
+        AccountSpec::optional("ticket_associated_token_program", false),
+        AccountSpec::optional("prerequisite_challenger_pda", false),
+    ];
+}
+
+pub mod redeem {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const CHALLENGER: usize = 2;
+    pub const CHALLENGER_PDA: usize = 3;
+    pub const REDEEM: usize = 4;
+    pub const REDEEM_ATA: usize = 5;
+    pub const TOKEN_PROGRAM: usize = 6;
+    pub const ASSOCIATED_TOKEN_PROGRAM: usize = 7;
+    pub const SYSTEM_PROGRAM: usize = 8;
+    pub const PRIZE_POOL: usize = 9;
+    pub const NFT_MINT: usize = 10;
+    pub const NFT_ATA: usize = 11;
+    pub const FIRST_SOLVERS: usize = 12;
+    pub const WINNERS: usize = 13;
+    pub const TICKET_ATA: usize = 14;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable_signer("challenger"),
+        AccountSpec::writable("challenger_pda"),
+        AccountSpec::writable("redeem"),
+        AccountSpec::writable("redeem_ata"),
+        AccountSpec::readonly("token_program"),
+        AccountSpec::readonly("associated_token_program"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("prize_pool", true),
+        AccountSpec::optional("nft_mint", true),
+        AccountSpec::optional("nft_ata", true),
+        AccountSpec::optional("first_solvers", true),
+        AccountSpec::optional("winners", true),
+        AccountSpec::optional("ticket_ata", false),
+    ];
+}
+
+pub mod set_solve_window {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod get_challenge_state {
+    use super::AccountSpec;
+
+    pub const CHALLENGE_PDA: usize = 0;
+
+    pub const ACCOUNTS: &[AccountSpec] =
+        &[AccountSpec::readonly("challenge_pda")];
+}
+
+pub mod split_challenge {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const NEW_CHALLENGE_PDA: usize = 3;
+    pub const NEW_REDEEM_PDA: usize = 4;
+    pub const TOKEN_PROGRAM: usize = 5;
+    pub const SYSTEM_PROGRAM: usize = 6;
+    pub const CREATOR_REGISTRY: usize = 7;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("new_challenge_pda"),
+        AccountSpec::writable("new_redeem_pda"),
+        AccountSpec::readonly("token_program"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("creator_registry", true),
+    ];
+}
+
+pub mod truncate_solutions {
+    use super::AccountSpec;
+
+    pub const CREATOR: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod merge_challenges {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SOURCE_CHALLENGE_PDA: usize = 3;
+    pub const SYSTEM_PROGRAM: usize = 4;
+    pub const SOURCE_ESCROW_ATA: usize = 5;
+    pub const CREATOR_REGISTRY: usize = 6;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::writable_signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("source_challenge_pda"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("source_escrow_ata", false),
+        AccountSpec::optional("creator_registry", true),
+    ];
+}
+
+pub mod lock_admit_cost {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod create_template {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const TEMPLATE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("template_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod create_challenge_from_template {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const REDEEM_PDA: usize = 3;
+    pub const TOKEN_PROGRAM: usize = 4;
+    pub const SYSTEM_PROGRAM: usize = 5;
+    pub const TEMPLATE_PDA: usize = 6;
+    pub const CREATOR_REGISTRY: usize = 7;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::readonly("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("redeem_pda"),
+        AccountSpec::readonly("token_program"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::readonly("template_pda"),
+        AccountSpec::optional("creator_registry", true),
+    ];
+}
+
+pub mod set_oracle {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod verify_solve_signature {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const CHALLENGER: usize = 2;
+    pub const CHALLENGER_PDA: usize = 3;
+    pub const REDEEM: usize = 4;
+    pub const REDEEM_ATA: usize = 5;
+    pub const TOKEN_PROGRAM: usize = 6;
+    pub const ASSOCIATED_TOKEN_PROGRAM: usize = 7;
+    pub const SYSTEM_PROGRAM: usize = 8;
+    pub const INSTRUCTIONS_SYSVAR: usize = 9;
+    pub const PRIZE_POOL: usize = 10;
+    pub const NFT_MINT: usize = 11;
+    pub const NFT_ATA: usize = 12;
+    pub const FIRST_SOLVERS: usize = 13;
+    pub const WINNERS: usize = 14;
+    pub const TICKET_ATA: usize = 15;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable_signer("challenger"),
+        AccountSpec::writable("challenger_pda"),
+        AccountSpec::writable("redeem"),
+        AccountSpec::writable("redeem_ata"),
+        AccountSpec::readonly("token_program"),
+        AccountSpec::readonly("associated_token_program"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::readonly("instructions_sysvar"),
+        AccountSpec::optional("prize_pool", true),
+        AccountSpec::optional("nft_mint", true),
+        AccountSpec::optional("nft_ata", true),
+        AccountSpec::optional("first_solvers", true),
+        AccountSpec::optional("winners", true),
+        AccountSpec::optional("ticket_ata", false),
+    ];
+}
+
+pub mod update_solution_hash {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod set_solutions_locked {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod reissue_admission {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const CHALLENGER: usize = 3;
+    pub const CHALLENGER_PDA: usize = 4;
+    pub const SYSTEM_PROGRAM: usize = 5;
+    pub const PRIZE_POOL: usize = 6;
+    pub const ADMIT_MINT: usize = 7;
+    pub const PAYER_ATA: usize = 8;
+    pub const ESCROW_ATA: usize = 9;
+    pub const TOKEN_PROGRAM: usize = 10;
+    pub const ASSOCIATED_TOKEN_PROGRAM: usize = 11;
+    pub const CONFIG: usize = 12;
+    pub const FEE_DESTINATION: usize = 13;
+    pub const FEE_DESTINATION_ATA: usize = 14;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::writable("creator"),
+        AccountSpec::readonly("challenge_pda"),
+        AccountSpec::readonly("challenger"),
+        AccountSpec::writable("challenger_pda"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("prize_pool", true),
+        AccountSpec::optional("admit_mint", false),
+        AccountSpec::optional("payer_ata", true),
+        AccountSpec::optional("escrow_ata", true),
+        AccountSpec::optional("token_program", false),
+        AccountSpec::optional("associated_token_program", false),
+        AccountSpec::readonly("config"),
+        AccountSpec::optional("fee_destination", true),
+        AccountSpec::optional("fee_destination_ata", true),
+    ];
+}
+
+pub mod add_co_sponsor {
+    use super::AccountSpec;
+
+    pub const SPONSOR: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const SPONSOR_LOG_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+    pub const PRIZE_POOL: usize = 4;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("sponsor"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("sponsor_log_pda"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("prize_pool", true),
+    ];
+}
+
+pub mod create_leaderboard {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const LEADERBOARD_PDA: usize = 3;
+    pub const SYSTEM_PROGRAM: usize = 4;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::readonly("challenge_pda"),
+        AccountSpec::writable("leaderboard_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod update_leaderboard {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const CHALLENGER: usize = 2;
+    pub const CHALLENGER_PDA: usize = 3;
+    pub const LEADERBOARD_PDA: usize = 4;
+    pub const SYSTEM_PROGRAM: usize = 5;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::readonly("challenge_pda"),
+        AccountSpec::signer("challenger"),
+        AccountSpec::readonly("challenger_pda"),
+        AccountSpec::writable("leaderboard_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod remove_solutions {
+    use super::AccountSpec;
+
+    pub const CREATOR: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod replace_solutions {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod close_challenge {
+    use super::AccountSpec;
+
+    pub const CREATOR: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const RECIPIENT: usize = 2;
+    pub const CREATOR_REGISTRY: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("recipient"),
+        AccountSpec::optional("creator_registry", true),
+    ];
+}
+
+pub mod transfer_authority {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod accept_authority {
+    use super::AccountSpec;
+
+    pub const NEW_AUTHORITY: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("new_authority"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod set_paused {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod update_challenge {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const REDEEM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::optional("redeem", false),
+    ];
+}
+
+pub mod set_admit_mint {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+    pub const ADMIT_MINT: usize = 4;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("admit_mint", false),
+    ];
+}
+
+pub mod withdraw_admit_tokens {
+    use super::AccountSpec;
+
+    pub const CREATOR: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const ADMIT_MINT: usize = 2;
+    pub const ESCROW_ATA: usize = 3;
+    pub const CREATOR_ATA: usize = 4;
+    pub const TOKEN_PROGRAM: usize = 5;
+    pub const ASSOCIATED_TOKEN_PROGRAM: usize = 6;
+    pub const SYSTEM_PROGRAM: usize = 7;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("creator"),
+        AccountSpec::readonly("challenge_pda"),
+        AccountSpec::readonly("admit_mint"),
+        AccountSpec::writable("escrow_ata"),
+        AccountSpec::writable("creator_ata"),
+        AccountSpec::readonly("token_program"),
+        AccountSpec::readonly("associated_token_program"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod create_prize_pool {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const PRIZE_POOL: usize = 3;
+    pub const SYSTEM_PROGRAM: usize = 4;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("prize_pool"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod withdraw_prize_pool {
+    use super::AccountSpec;
+
+    pub const CREATOR: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const PRIZE_POOL: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("prize_pool"),
+    ];
+}
+
+pub mod set_nft_reward {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod commit_solution {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const CHALLENGER: usize = 2;
+    pub const CHALLENGER_PDA: usize = 3;
+    pub const COMMITMENT_PDA: usize = 4;
+    pub const SYSTEM_PROGRAM: usize = 5;
+    pub const TICKET_ATA: usize = 6;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::readonly("challenge_pda"),
+        AccountSpec::signer("challenger"),
+        AccountSpec::readonly("challenger_pda"),
+        AccountSpec::writable("commitment_pda"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("ticket_ata", false),
+    ];
+}
+
+pub mod reveal_solution {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const CHALLENGER: usize = 2;
+    pub const CHALLENGER_PDA: usize = 3;
+    pub const REDEEM: usize = 4;
+    pub const REDEEM_ATA: usize = 5;
+    pub const TOKEN_PROGRAM: usize = 6;
+    pub const ASSOCIATED_TOKEN_PROGRAM: usize = 7;
+    pub const SYSTEM_PROGRAM: usize = 8;
+    pub const COMMITMENT_PDA: usize = 9;
+    pub const PRIZE_POOL: usize = 10;
+    pub const NFT_MINT: usize = 11;
+    pub const NFT_ATA: usize = 12;
+    pub const FIRST_SOLVERS: usize = 13;
+    pub const WINNERS: usize = 14;
+    pub const TICKET_ATA: usize = 15;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable_signer("challenger"),
+        AccountSpec::writable("challenger_pda"),
+        AccountSpec::writable("redeem"),
+        AccountSpec::writable("redeem_ata"),
+        AccountSpec::readonly("token_program"),
+        AccountSpec::readonly("associated_token_program"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::writable("commitment_pda"),
+        AccountSpec::optional("prize_pool", true),
+        AccountSpec::optional("nft_mint", true),
+        AccountSpec::optional("nft_ata", true),
+        AccountSpec::optional("first_solvers", true),
+        AccountSpec::optional("winners", true),
+        AccountSpec::optional("ticket_ata", false),
+    ];
+}
+
+pub mod set_commit_reveal {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod set_allowlist {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod set_max_admissions {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod create_first_solvers {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const FIRST_SOLVERS: usize = 3;
+    pub const SYSTEM_PROGRAM: usize = 4;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("first_solvers"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod append_solutions_chunk {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SOLUTIONS_UPLOAD: usize = 3;
+    pub const SYSTEM_PROGRAM: usize = 4;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("solutions_upload"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod init_config {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const ADMIN: usize = 1;
+    pub const CONFIG: usize = 2;
+    pub const PROGRAM_DATA: usize = 3;
+    pub const SYSTEM_PROGRAM: usize = 4;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("admin"),
+        AccountSpec::writable("config"),
+        AccountSpec::readonly("program_data"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod update_config {
+    use super::AccountSpec;
+
+    pub const ADMIN: usize = 0;
+    pub const CONFIG: usize = 1;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::signer("admin"),
+        AccountSpec::writable("config"),
+    ];
+}
+
+pub mod cancel_challenge {
+    use super::AccountSpec;
+
+    pub const CREATOR: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod refund_unused_tries {
+    use super::AccountSpec;
+
+    pub const CHALLENGER: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const CHALLENGER_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+    pub const PRIZE_POOL: usize = 4;
+    pub const ADMIT_MINT: usize = 5;
+    pub const CHALLENGER_ATA: usize = 6;
+    pub const ESCROW_ATA: usize = 7;
+    pub const TOKEN_PROGRAM: usize = 8;
+    pub const ASSOCIATED_TOKEN_PROGRAM: usize = 9;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("challenger"),
+        AccountSpec::readonly("challenge_pda"),
+        AccountSpec::writable("challenger_pda"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::writable("prize_pool"),
+        AccountSpec::optional("admit_mint", false),
+        AccountSpec::optional("challenger_ata", true),
+        AccountSpec::optional("escrow_ata", true),
+        AccountSpec::optional("token_program", false),
+        AccountSpec::optional("associated_token_program", false),
+    ];
+}
+
+pub mod migrate_account {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const ACCOUNT: usize = 1;
+    pub const SYSTEM_PROGRAM: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::writable("account"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod add_admin {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod remove_admin {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod set_solve_mode {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod set_hints {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod reveal_hint {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const CHALLENGER: usize = 2;
+    pub const CHALLENGER_PDA: usize = 3;
+    pub const REVEALED_HINTS_PDA: usize = 4;
+    pub const AUTHORITY: usize = 5;
+    pub const PRIZE_POOL: usize = 6;
+    pub const SYSTEM_PROGRAM: usize = 7;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::readonly("challenge_pda"),
+        AccountSpec::signer("challenger"),
+        AccountSpec::readonly("challenger_pda"),
+        AccountSpec::writable("revealed_hints_pda"),
+        AccountSpec::writable("authority"),
+        AccountSpec::writable("prize_pool"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod set_attempt_cooldown {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod create_winners {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const WINNERS: usize = 3;
+    pub const SYSTEM_PROGRAM: usize = 4;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("winners"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod set_solution_normalization {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod check_solution {
+    use super::AccountSpec;
+
+    pub const CHALLENGE_PDA: usize = 0;
+
+    pub const ACCOUNTS: &[AccountSpec] =
+        &[AccountSpec::readonly("challenge_pda")];
+}
+
+pub mod set_reject_weak_solutions {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod set_ticket_admission {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod set_metadata {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const METADATA_PDA: usize = 3;
+    pub const SYSTEM_PROGRAM: usize = 4;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::readonly("challenge_pda"),
+        AccountSpec::writable("metadata_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod set_challenge_flags {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
+
+pub mod create_challenge_idempotent {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const REDEEM_PDA: usize = 3;
+    pub const TOKEN_PROGRAM: usize = 4;
+    pub const SYSTEM_PROGRAM: usize = 5;
+    pub const CREATOR_REGISTRY: usize = 6;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::readonly("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("redeem_pda"),
+        AccountSpec::readonly("token_program"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("creator_registry", true),
+    ];
+}
+
+pub mod create_seeded_challenge {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const REDEEM_PDA: usize = 3;
+    pub const TOKEN_PROGRAM: usize = 4;
+    pub const SYSTEM_PROGRAM: usize = 5;
+    pub const CREATOR_REGISTRY: usize = 6;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::readonly("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("redeem_pda"),
+        AccountSpec::readonly("token_program"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("creator_registry", true),
+    ];
+}
+
+pub mod buy_more_tries {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const CHALLENGER: usize = 3;
+    pub const CHALLENGER_PDA: usize = 4;
+    pub const SYSTEM_PROGRAM: usize = 5;
+    pub const PRIZE_POOL: usize = 6;
+    pub const ADMIT_MINT: usize = 7;
+    pub const PAYER_ATA: usize = 8;
+    pub const ESCROW_ATA: usize = 9;
+    pub const TOKEN_PROGRAM: usize = 10;
+    pub const ASSOCIATED_TOKEN_PROGRAM: usize = 11;
+    pub const CONFIG: usize = 12;
+    pub const FEE_DESTINATION: usize = 13;
+    pub const FEE_DESTINATION_ATA: usize = 14;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::writable("creator"),
+        AccountSpec::readonly("challenge_pda"),
+        AccountSpec::readonly("challenger"),
+        AccountSpec::writable("challenger_pda"),
+        AccountSpec::readonly("system_program"),
+        AccountSpec::optional("prize_pool", true),
+        AccountSpec::optional("admit_mint", false),
+        AccountSpec::optional("payer_ata", true),
+        AccountSpec::optional("escrow_ata", true),
+        AccountSpec::optional("token_program", false),
+        AccountSpec::optional("associated_token_program", false),
+        AccountSpec::readonly("config"),
+        AccountSpec::optional("fee_destination", true),
+        AccountSpec::optional("fee_destination_ata", true),
+    ];
+}
+
+pub mod set_max_tries_per_challenger {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod sweep_surplus {
+    use super::AccountSpec;
+
+    pub const CREATOR: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+    pub const TREASURY: usize = 2;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::writable("treasury"),
+    ];
+}
+
+pub mod set_prerequisite {
+    use super::AccountSpec;
+
+    pub const PAYER: usize = 0;
+    pub const CREATOR: usize = 1;
+    pub const CHALLENGE_PDA: usize = 2;
+    pub const SYSTEM_PROGRAM: usize = 3;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::writable_signer("payer"),
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+        AccountSpec::readonly("system_program"),
+    ];
+}
+
+pub mod set_epochs {
+    use super::AccountSpec;
+
+    pub const CREATOR: usize = 0;
+    pub const CHALLENGE_PDA: usize = 1;
+
+    pub const ACCOUNTS: &[AccountSpec] = &[
+        AccountSpec::signer("creator"),
+        AccountSpec::writable("challenge_pda"),
+    ];
+}
@@ -0,0 +1,123 @@
+//! Lets Anchor programs and clients use the accounts of this program like their own.
+//!
+//! The account discriminators already match the ones Anchor derives, see
+//! [HasDiscriminator], thus the accounts can be loaded via `Account<'info, Challenge>` or
+//! deserialized via [AccountDeserialize::try_deserialize].
+//! Instructions keep the Borsh encoding of [ChallengeInstruction] which the program
+//! expects, build them via [crate::ixs] or invoke them via [crate::cpi].
+
+use std::io::Write;
+
+use anchor_lang::{
+    error::ErrorCode, AccountDeserialize, AccountSerialize, Discriminator, Id,
+    Owner,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{hash::hash, pubkey::Pubkey};
+
+use crate::{
+    challenge_id,
+    ixs::ChallengeInstruction,
+    state::{
+        Challenge, ChallengeTemplate, Challenger, Config, FirstSolvers,
+        HasDiscriminator, Leaderboard, PrizePool, RevealedHints,
+        SolutionCommitment, SolutionsUpload, SponsorLog, Winners,
+        DISCRIMINATOR_LEN,
+    },
+    utils::snake_case,
+};
+
+/// Marker to reference this program via `Program<'info, ChallengeProgram>`.
+#[derive(Debug, Clone)]
+pub struct ChallengeProgram;
+
+impl Id for ChallengeProgram {
+    fn id() -> Pubkey {
+        challenge_id()
+    }
+}
+
+macro_rules! impl_anchor_account {
+    ($($account:ty),+ $(,)?) => {
+        $(
+            impl Discriminator for $account {
+                const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+                    <$account as HasDiscriminator>::DISCRIMINATOR;
+            }
+
+            impl Owner for $account {
+                fn owner() -> Pubkey {
+                    challenge_id()
+                }
+            }
+
+            impl AccountDeserialize for $account {
+                fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+                    if buf.len() < DISCRIMINATOR_LEN {
+                        return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+                    }
+                    if buf[..DISCRIMINATOR_LEN]
+                        != <$account as HasDiscriminator>::DISCRIMINATOR
+                    {
+                        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+                    }
+                    Self::try_deserialize_unchecked(buf)
+                }
+
+                /// Accounts may be larger than their state, i.e. after solutions were
+                /// removed, thus trailing bytes are ignored.
+                fn try_deserialize_unchecked(
+                    buf: &mut &[u8],
+                ) -> anchor_lang::Result<Self> {
+                    let mut data = &buf[DISCRIMINATOR_LEN.min(buf.len())..];
+                    <$account as BorshDeserialize>::deserialize(&mut data)
+                        .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+                }
+            }
+
+            impl AccountSerialize for $account {
+                fn try_serialize<W: Write>(
+                    &self,
+                    writer: &mut W,
+                ) -> anchor_lang::Result<()> {
+                    writer
+                        .write_all(&<$account as HasDiscriminator>::DISCRIMINATOR)
+                        .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+                    BorshSerialize::serialize(self, writer)
+                        .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+                }
+            }
+        )+
+    };
+}
+
+impl_anchor_account!(
+    Challenge,
+    Challenger,
+    ChallengeMetadata,
+    ChallengeTemplate,
+    Config,
+    CreatorRegistry,
+    FirstSolvers,
+    Leaderboard,
+    PrizePool,
+    RevealedHints,
+    SolutionCommitment,
+    SolutionsUpload,
+    SponsorLog,
+    Winners,
+);
+
+impl ChallengeInstruction {
+    /// The discriminator Anchor derives for an instruction of the same name, i.e. the first
+    /// 8 bytes of `sha256("global:<instruction_name>")`.
+    /// Useful to map the instructions to Anchor tooling like indexers, the program itself
+    /// only decodes the Borsh encoding of [ChallengeInstruction].
+    pub fn anchor_discriminator(&self) -> [u8; 8] {
+        let preimage = format!("global:{}", snake_case(self.name()));
+        let mut discriminator = [0u8; 8];
+        discriminator
+            .copy_from_slice(&hash(preimage.as_bytes()).to_bytes()[..8]);
+        discriminator
+    }
+}
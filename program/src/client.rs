@@ -0,0 +1,455 @@
+use futures::{future::BoxFuture, stream::BoxStream, StreamExt};
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use solana_client::{
+    client_error::ClientError as RpcClientError,
+    nonblocking::{
+        pubsub_client::{PubsubClient, PubsubClientError},
+        rpc_client::RpcClient,
+    },
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+    rpc_response::RpcSimulateTransactionResult,
+};
+use solana_program::{
+    account_info::Account, clock::Clock, program_error::ProgramError,
+    program_pack::Pack, pubkey::Pubkey, sysvar,
+};
+use solana_sdk::{
+    account::{from_account, Account as SdkAccount},
+    transaction::Transaction,
+};
+use thiserror::Error;
+
+use crate::{
+    challenge_id,
+    clock::Now,
+    return_data::{CheckSolutionResult, RedeemResult},
+    snapshot::{ChallengeSnapshot, EscrowSnapshot, SnapshotAccount},
+    state::{
+        is_open, Challenge, Challenger, HasDiscriminator, HasPda, Leaderboard,
+        PrizePool, CHALLENGER_CHALLENGE_PDA_OFFSET, CHALLENGER_SIZE,
+        CHALLENGE_AUTHORITY_OFFSET, DISCRIMINATOR_LEN,
+    },
+};
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error(transparent)]
+    Rpc(Box<RpcClientError>),
+
+    #[error("Account {0} could not be deserialized: {1}")]
+    InvalidAccountData(Pubkey, ProgramError),
+
+    #[error(transparent)]
+    Pubsub(Box<PubsubClientError>),
+}
+
+impl From<RpcClientError> for ClientError {
+    fn from(err: RpcClientError) -> Self {
+        ClientError::Rpc(Box::new(err))
+    }
+}
+
+impl From<PubsubClientError> for ClientError {
+    fn from(err: PubsubClientError) -> Self {
+        ClientError::Pubsub(Box::new(err))
+    }
+}
+
+pub type ClientResult<T> = Result<T, ClientError>;
+
+/// Ends a subscription when awaited, see [subscribe_challenge].
+pub type Unsubscribe = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
+/// Fetches the challenge that [creator] created with the given [id], `None` if it does not
+/// exist.
+pub async fn fetch_challenge(
+    rpc: &RpcClient,
+    creator: &Pubkey,
+    id: &str,
+) -> ClientResult<Option<Challenge>> {
+    let (challenge_pda, _) = Challenge::pda_for(creator, id);
+    let account = rpc
+        .get_account_with_commitment(&challenge_pda, rpc.commitment())
+        .await?
+        .value;
+    account
+        .map(|account| {
+            Challenge::try_from_account_data(&account.data).map_err(|err| {
+                ClientError::InvalidAccountData(challenge_pda, err)
+            })
+        })
+        .transpose()
+}
+
+/// Fetches all challenges managed by this program keyed by their PDA.
+pub async fn fetch_all_challenges(
+    rpc: &RpcClient,
+) -> ClientResult<Vec<(Pubkey, Challenge)>> {
+    fetch_pda_accounts(rpc, challenge_filters()).await
+}
+
+/// Finds all challenges whose current authority is [authority] keyed by their PDA.
+pub async fn find_challenges_by_authority(
+    rpc: &RpcClient,
+    authority: &Pubkey,
+) -> ClientResult<Vec<(Pubkey, Challenge)>> {
+    fetch_pda_accounts(rpc, challenge_authority_filters(authority)).await
+}
+
+/// Finds all challenges that accept solutions at the current cluster time keyed by their
+/// PDA, see [is_open].
+/// Whether a challenge is open depends on fields that are not at a fixed offset, thus all
+/// challenges are fetched and filtered after decoding them.
+pub async fn find_open_challenges(
+    rpc: &RpcClient,
+) -> ClientResult<Vec<(Pubkey, Challenge)>> {
+    let now = fetch_now(rpc).await?;
+    let challenges = fetch_pda_accounts(rpc, challenge_filters()).await?;
+    Ok(open_challenges(challenges, &now))
+}
+
+/// Fetches the current slot and unix timestamp from the [Clock] sysvar of the cluster.
+pub async fn fetch_now(rpc: &RpcClient) -> ClientResult<Now> {
+    let account = rpc.get_account(&sysvar::clock::id()).await?;
+    from_account::<Clock, _>(&account)
+        .map(Now::from)
+        .ok_or_else(|| {
+            ClientError::InvalidAccountData(
+                sysvar::clock::id(),
+                ProgramError::InvalidAccountData,
+            )
+        })
+}
+
+/// Keeps the [challenges] that accept solutions at [now], see [is_open].
+pub fn open_challenges(
+    challenges: Vec<(Pubkey, Challenge)>,
+    now: &Now,
+) -> Vec<(Pubkey, Challenge)> {
+    challenges
+        .into_iter()
+        .filter(|(_, challenge)| is_open(challenge, now))
+        .collect()
+}
+
+/// Subscribes to changes of the challenge at [challenge_pda] and yields the challenge each
+/// time it is updated, i.e. when a solution was redeemed.
+/// Notifications that don't decode into a challenge, i.e. once the challenge is closed,
+/// are skipped.
+/// Await the returned [Unsubscribe] to end the subscription.
+pub async fn subscribe_challenge<'a>(
+    pubsub: &'a PubsubClient,
+    challenge_pda: &Pubkey,
+) -> ClientResult<(BoxStream<'a, Challenge>, Unsubscribe)> {
+    let config = RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        ..RpcAccountInfoConfig::default()
+    };
+    let (notifications, unsubscribe) = pubsub
+        .account_subscribe(challenge_pda, Some(config))
+        .await?;
+    let challenges = notifications
+        .filter_map(|response| async move {
+            challenge_from_ui_account(&response.value)
+        })
+        .boxed();
+    Ok((challenges, unsubscribe))
+}
+
+/// Decodes the challenge from the data of an account notification, `None` if it isn't one.
+pub fn challenge_from_ui_account(account: &UiAccount) -> Option<Challenge> {
+    let data = account.data.decode()?;
+    // checking the discriminator upfront avoids logging mismatches
+    if !data.starts_with(&Challenge::DISCRIMINATOR) {
+        return None;
+    }
+    Challenge::try_from_account_data(&data).ok()
+}
+
+/// Fetches all challengers admitted to the challenge at [challenge_pda] keyed by their PDA.
+pub async fn fetch_challengers(
+    rpc: &RpcClient,
+    challenge_pda: &Pubkey,
+) -> ClientResult<Vec<(Pubkey, Challenger)>> {
+    fetch_pda_accounts(rpc, challenger_filters(challenge_pda)).await
+}
+
+/// Captures the challenge at [challenge_pda] together with all its challengers, its
+/// leaderboard and the balances it escrows, see [ChallengeSnapshot].
+pub async fn export_challenge(
+    rpc: &RpcClient,
+    challenge_pda: &Pubkey,
+) -> ClientResult<ChallengeSnapshot> {
+    let challenge_account = rpc.get_account(challenge_pda).await?;
+    let challenge = Challenge::try_from_account_data(&challenge_account.data)
+        .map_err(|err| {
+        ClientError::InvalidAccountData(*challenge_pda, err)
+    })?;
+
+    let mut addresses = vec![
+        Leaderboard::pda_for(challenge_pda).0,
+        PrizePool::pda_for(challenge_pda).0,
+        challenge.redeem,
+    ];
+    if let Some(admit_mint) = &challenge.admit_mint {
+        addresses.push(EscrowSnapshot::ata_for(challenge_pda, admit_mint));
+    }
+    let related = rpc.get_multiple_accounts(&addresses).await?;
+
+    let mut accounts = vec![(*challenge_pda, challenge_account)];
+    accounts.extend(
+        addresses
+            .into_iter()
+            .zip(related)
+            .filter_map(|(address, account)| Some((address, account?))),
+    );
+    accounts.extend(
+        fetch_program_accounts(rpc, challenger_filters(challenge_pda)).await?,
+    );
+    challenge_snapshot_from_accounts(challenge_pda, accounts)
+}
+
+/// Builds the snapshot of the challenge at [challenge_pda] from the [accounts] that were
+/// fetched for it, see [export_challenge].
+/// Accounts that don't belong to the challenge are ignored.
+pub fn challenge_snapshot_from_accounts<A: Account>(
+    challenge_pda: &Pubkey,
+    accounts: Vec<(Pubkey, A)>,
+) -> ClientResult<ChallengeSnapshot> {
+    let mut accounts = accounts
+        .into_iter()
+        .map(|(address, mut account)| {
+            let (lamports, data, ..) = account.get();
+            (address, (*lamports, data.to_vec()))
+        })
+        .collect::<Vec<_>>();
+    let mut take = |address: &Pubkey| {
+        accounts
+            .iter()
+            .position(|(x, _)| x == address)
+            .map(|idx| accounts.remove(idx).1)
+    };
+
+    let challenge = snapshot_account::<Challenge>(&mut take, *challenge_pda)?
+        .ok_or(ClientError::InvalidAccountData(
+        *challenge_pda,
+        ProgramError::UninitializedAccount,
+    ))?;
+
+    let leaderboard =
+        snapshot_account(&mut take, Leaderboard::pda_for(challenge_pda).0)?;
+    let prize_pool =
+        snapshot_account(&mut take, PrizePool::pda_for(challenge_pda).0)?;
+    let escrow = match &challenge.state.admit_mint {
+        Some(mint) => {
+            let address = EscrowSnapshot::ata_for(challenge_pda, mint);
+            take(&address)
+                .map(|(_, data)| {
+                    spl_token::state::Account::unpack(&data)
+                        .map(|token_account| EscrowSnapshot {
+                            address,
+                            mint: *mint,
+                            amount: token_account.amount,
+                        })
+                        .map_err(|err| {
+                            ClientError::InvalidAccountData(address, err)
+                        })
+                })
+                .transpose()?
+        }
+        None => None,
+    };
+    let redeem = challenge.state.redeem;
+    let redeem_supply = take(&redeem)
+        .map(|(_, data)| {
+            spl_token::state::Mint::unpack(&data)
+                .map(|mint| mint.supply)
+                .map_err(|err| ClientError::InvalidAccountData(redeem, err))
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    // the remaining accounts are the challengers
+    let challengers = accounts
+        .into_iter()
+        .filter_map(|(address, (lamports, data))| {
+            // checking the discriminator upfront avoids logging mismatches
+            if !data.starts_with(&Challenger::DISCRIMINATOR) {
+                return None;
+            }
+            let state = Challenger::try_from_account_data(&data).ok()?;
+            (state.challenge_pda == *challenge_pda && state.pda().0 == address)
+                .then_some(SnapshotAccount {
+                    address,
+                    lamports,
+                    state,
+                })
+        })
+        .collect();
+
+    Ok(ChallengeSnapshot {
+        challenge,
+        challengers,
+        leaderboard,
+        prize_pool,
+        escrow,
+        redeem_supply,
+    })
+}
+
+fn snapshot_account<T: HasDiscriminator>(
+    take: &mut impl FnMut(&Pubkey) -> Option<(u64, Vec<u8>)>,
+    address: Pubkey,
+) -> ClientResult<Option<SnapshotAccount<T>>> {
+    take(&address)
+        .map(|(lamports, data)| {
+            let state = T::try_from_account_data(&data)
+                .map_err(|err| ClientError::InvalidAccountData(address, err))?;
+            Ok(SnapshotAccount {
+                address,
+                lamports,
+                state,
+            })
+        })
+        .transpose()
+}
+
+/// Simulates the [tx] redeeming a solution and returns the outcome the program reported,
+/// `None` if it reported none, i.e. because the simulation failed.
+pub async fn simulate_redeem(
+    rpc: &RpcClient,
+    tx: &Transaction,
+) -> ClientResult<Option<RedeemResult>> {
+    let response = rpc.simulate_transaction(tx).await?;
+    Ok(redeem_result_from_simulation(&response.value))
+}
+
+/// Decodes the [RedeemResult] from the return data of a simulated redeem transaction.
+pub fn redeem_result_from_simulation(
+    result: &RpcSimulateTransactionResult,
+) -> Option<RedeemResult> {
+    let (program_id, data) = simulation_return_data(result)?;
+    RedeemResult::from_return_data(&program_id, &data)
+}
+
+/// Simulates the [tx] checking a solution, see [crate::ixs::check_solution], and returns
+/// the outcome the program reported, `None` if it reported none, i.e. because the
+/// challenge account is invalid.
+pub async fn simulate_check_solution(
+    rpc: &RpcClient,
+    tx: &Transaction,
+) -> ClientResult<Option<CheckSolutionResult>> {
+    let response = rpc.simulate_transaction(tx).await?;
+    Ok(check_solution_result_from_simulation(&response.value))
+}
+
+/// Decodes the [CheckSolutionResult] from the return data of a simulated check solution
+/// transaction.
+pub fn check_solution_result_from_simulation(
+    result: &RpcSimulateTransactionResult,
+) -> Option<CheckSolutionResult> {
+    let (program_id, data) = simulation_return_data(result)?;
+    CheckSolutionResult::from_return_data(&program_id, &data)
+}
+
+fn simulation_return_data(
+    result: &RpcSimulateTransactionResult,
+) -> Option<(Pubkey, Vec<u8>)> {
+    let return_data = result.return_data.as_ref()?;
+    let program_id = return_data.program_id.parse::<Pubkey>().ok()?;
+    // return data is always base64 encoded
+    let data = base64::decode(&return_data.data.0).ok()?;
+    Some((program_id, data))
+}
+
+/// Filters matching accounts of the kind [T] via their discriminator.
+pub fn discriminator_filter<T: HasDiscriminator>() -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &T::DISCRIMINATOR))
+}
+
+/// Filters matching all challenges.
+pub fn challenge_filters() -> Vec<RpcFilterType> {
+    vec![discriminator_filter::<Challenge>()]
+}
+
+/// Filters matching challenges whose current authority is [authority].
+pub fn challenge_authority_filters(authority: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        discriminator_filter::<Challenge>(),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            CHALLENGE_AUTHORITY_OFFSET,
+            authority.as_ref(),
+        )),
+    ]
+}
+
+/// Filters matching the challengers of the challenge at [challenge_pda].
+pub fn challenger_filters(challenge_pda: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(CHALLENGER_SIZE as u64),
+        discriminator_filter::<Challenger>(),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            CHALLENGER_CHALLENGE_PDA_OFFSET,
+            challenge_pda.as_ref(),
+        )),
+    ]
+}
+
+/// Keeps the accounts that start with the challenge discriminator, deserialize into a
+/// challenge and are stored at its PDA.
+pub fn challenges_from_program_accounts<A: Account>(
+    accounts: Vec<(Pubkey, A)>,
+) -> Vec<(Pubkey, Challenge)> {
+    decode_pda_accounts(accounts)
+}
+
+/// Keeps the accounts that start with the challenger discriminator, deserialize into a
+/// challenger and are stored at its PDA.
+pub fn challengers_from_program_accounts<A: Account>(
+    accounts: Vec<(Pubkey, A)>,
+) -> Vec<(Pubkey, Challenger)> {
+    decode_pda_accounts(accounts)
+}
+
+fn decode_pda_accounts<T: HasDiscriminator + HasPda, A: Account>(
+    accounts: Vec<(Pubkey, A)>,
+) -> Vec<(Pubkey, T)> {
+    accounts
+        .into_iter()
+        .filter_map(|(address, mut account)| {
+            let (_, data, ..) = account.get();
+            // checking the discriminator upfront avoids logging mismatches
+            if !data.starts_with(&T::DISCRIMINATOR) {
+                return None;
+            }
+            let state = T::try_from_slice(&data[DISCRIMINATOR_LEN..]).ok()?;
+            (state.pda().0 == address).then_some((address, state))
+        })
+        .collect()
+}
+
+async fn fetch_pda_accounts<T: HasDiscriminator + HasPda>(
+    rpc: &RpcClient,
+    filters: Vec<RpcFilterType>,
+) -> ClientResult<Vec<(Pubkey, T)>> {
+    let accounts = fetch_program_accounts(rpc, filters).await?;
+    Ok(decode_pda_accounts(accounts))
+}
+
+async fn fetch_program_accounts(
+    rpc: &RpcClient,
+    filters: Vec<RpcFilterType>,
+) -> ClientResult<Vec<(Pubkey, SdkAccount)>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            commitment: Some(rpc.commitment()),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    Ok(rpc
+        .get_program_accounts_with_config(&challenge_id(), config)
+        .await?)
+}
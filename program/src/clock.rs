@@ -0,0 +1,34 @@
+use solana_program::{
+    clock::Clock, program_error::ProgramError, sysvar::Sysvar,
+};
+
+/// The slot and unix timestamp at which an instruction is processed.
+/// Processors read it from the [Clock] sysvar via [Now::from_sysvar] while unit tests and
+/// off-chain code construct it directly in order to evaluate the same rules, i.e.
+/// [crate::state::is_open], at any point in time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Now {
+    pub slot: u64,
+    pub unix_timestamp: i64,
+}
+
+impl Now {
+    pub fn new(slot: u64, unix_timestamp: i64) -> Self {
+        Self {
+            slot,
+            unix_timestamp,
+        }
+    }
+
+    /// Reads the current slot and unix timestamp from the [Clock] sysvar, only available
+    /// on chain.
+    pub fn from_sysvar() -> Result<Self, ProgramError> {
+        Ok(Clock::get()?.into())
+    }
+}
+
+impl From<Clock> for Now {
+    fn from(clock: Clock) -> Self {
+        Self::new(clock.slot, clock.unix_timestamp)
+    }
+}
@@ -0,0 +1,318 @@
+//! Helpers for other programs to invoke challenge instructions via CPI.
+//! Depend on this crate with the `cpi` feature enabled which also disables its entrypoint.
+//!
+//! Accounts are passed as [AccountInfo]s in the same order as the respective
+//! [ChallengeInstruction] declares them. Any account that signs on behalf of the calling
+//! program, i.e. a PDA acting as payer, needs its seeds included in the `signer_seeds`.
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+use crate::{
+    challenge_id, error::ChallengeError, ixs::ChallengeInstruction,
+    utils::assert_keys_equal, Solution,
+};
+
+fn writable(info: &AccountInfo, is_signer: bool) -> AccountMeta {
+    AccountMeta::new(*info.key, is_signer)
+}
+
+fn readonly(info: &AccountInfo, is_signer: bool) -> AccountMeta {
+    AccountMeta::new_readonly(*info.key, is_signer)
+}
+
+fn assert_challenge_program(
+    context: &str,
+    program_info: &AccountInfo,
+) -> ProgramResult {
+    assert_keys_equal(
+        context,
+        program_info.key,
+        &challenge_id(),
+        ChallengeError::ProvidedProgramIncorrect,
+        || {
+            format!(
+                "Provided program ({}) is not the challenge program ({})",
+                program_info.key,
+                challenge_id()
+            )
+        },
+    )
+}
+
+fn invoke_challenge<'info>(
+    program_info: &AccountInfo<'info>,
+    accounts: Vec<AccountMeta>,
+    mut account_infos: Vec<AccountInfo<'info>>,
+    instruction: ChallengeInstruction,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts,
+        data: instruction.try_to_vec()?,
+    };
+    account_infos.push(program_info.clone());
+    invoke_signed(&ix, &account_infos, signer_seeds)
+}
+
+// -----------------
+// Start Challenge
+// -----------------
+pub struct StartChallengeAccounts<'a, 'info> {
+    pub creator: &'a AccountInfo<'info>,
+    pub challenge_pda: &'a AccountInfo<'info>,
+}
+
+/// Starts the challenge with the given [id], see [ChallengeInstruction::StartChallenge].
+/// The creator needs to sign, include its seeds if it is a PDA of the calling program.
+pub fn start_challenge<'a, 'info>(
+    program_info: &'a AccountInfo<'info>,
+    accounts: StartChallengeAccounts<'a, 'info>,
+    id: String,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    assert_challenge_program("cpi::start_challenge", program_info)?;
+    let StartChallengeAccounts {
+        creator,
+        challenge_pda,
+    } = accounts;
+
+    invoke_challenge(
+        program_info,
+        vec![readonly(creator, true), writable(challenge_pda, false)],
+        vec![creator.clone(), challenge_pda.clone()],
+        ChallengeInstruction::StartChallenge { id },
+        signer_seeds,
+    )
+}
+
+// -----------------
+// Admit Challenger
+// -----------------
+/// Accounts needed to pay the admit cost of a challenge that has an admit mint.
+pub struct AdmitMintAccounts<'a, 'info> {
+    pub admit_mint: &'a AccountInfo<'info>,
+    pub payer_ata: &'a AccountInfo<'info>,
+    pub escrow_ata: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+}
+
+/// Accounts receiving the protocol fee once the program config is initialized.
+pub struct ProtocolFeeAccounts<'a, 'info> {
+    pub fee_destination: &'a AccountInfo<'info>,
+    /// Required if the challenge has an admit mint
+    pub fee_destination_ata: Option<&'a AccountInfo<'info>>,
+}
+
+pub struct AdmitChallengerAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub creator: &'a AccountInfo<'info>,
+    pub challenge_pda: &'a AccountInfo<'info>,
+    pub challenger: &'a AccountInfo<'info>,
+    pub challenger_pda: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub prize_pool: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    /// Required if the challenge has an admit mint
+    pub admit_mint: Option<AdmitMintAccounts<'a, 'info>>,
+    /// Required once the program config is initialized
+    pub protocol_fee: Option<ProtocolFeeAccounts<'a, 'info>>,
+}
+
+/// Admits the challenger, see [ChallengeInstruction::AdmitChallenger].
+/// The payer needs to sign, include its seeds if it is a PDA of the calling program.
+///
+/// * [allowlist_proof]: only required if the challenge has an allowlist, see
+///   [crate::utils::allowlist_proof]
+pub fn admit<'a, 'info>(
+    program_info: &'a AccountInfo<'info>,
+    accounts: AdmitChallengerAccounts<'a, 'info>,
+    allowlist_proof: Vec<[u8; 32]>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    assert_challenge_program("cpi::admit", program_info)?;
+    let AdmitChallengerAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        system_program,
+        prize_pool,
+        config,
+        admit_mint,
+        protocol_fee,
+    } = accounts;
+
+    let mut metas = vec![
+        writable(payer, true),
+        writable(creator, false),
+        writable(challenge_pda, false),
+        readonly(challenger, false),
+        writable(challenger_pda, false),
+        readonly(system_program, false),
+        writable(prize_pool, false),
+    ];
+    let mut infos = vec![
+        payer.clone(),
+        creator.clone(),
+        challenge_pda.clone(),
+        challenger.clone(),
+        challenger_pda.clone(),
+        system_program.clone(),
+        prize_pool.clone(),
+    ];
+    if let Some(AdmitMintAccounts {
+        admit_mint,
+        payer_ata,
+        escrow_ata,
+        token_program,
+        associated_token_program,
+    }) = admit_mint
+    {
+        metas.extend([
+            readonly(admit_mint, false),
+            writable(payer_ata, false),
+            writable(escrow_ata, false),
+            readonly(token_program, false),
+            readonly(associated_token_program, false),
+        ]);
+        infos.extend([
+            admit_mint.clone(),
+            payer_ata.clone(),
+            escrow_ata.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+        ]);
+    }
+    metas.push(readonly(config, false));
+    infos.push(config.clone());
+    if let Some(ProtocolFeeAccounts {
+        fee_destination,
+        fee_destination_ata,
+    }) = protocol_fee
+    {
+        metas.push(writable(fee_destination, false));
+        infos.push(fee_destination.clone());
+        if let Some(fee_destination_ata) = fee_destination_ata {
+            metas.push(writable(fee_destination_ata, false));
+            infos.push(fee_destination_ata.clone());
+        }
+    }
+
+    invoke_challenge(
+        program_info,
+        metas,
+        infos,
+        ChallengeInstruction::AdmitChallenger {
+            challenge_pda: *challenge_pda.key,
+            allowlist_proof,
+        },
+        signer_seeds,
+    )
+}
+
+// -----------------
+// Redeem
+// -----------------
+/// Accounts needed to redeem a challenge that rewards NFTs.
+pub struct NftRewardAccounts<'a, 'info> {
+    pub nft_mint: &'a AccountInfo<'info>,
+    pub nft_ata: &'a AccountInfo<'info>,
+}
+
+pub struct RedeemAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub challenge_pda: &'a AccountInfo<'info>,
+    pub challenger: &'a AccountInfo<'info>,
+    pub challenger_pda: &'a AccountInfo<'info>,
+    pub redeem: &'a AccountInfo<'info>,
+    pub redeem_ata: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub prize_pool: &'a AccountInfo<'info>,
+    /// Required if the challenge rewards NFTs
+    pub nft_reward: Option<NftRewardAccounts<'a, 'info>>,
+    /// Required if the challenge has a first solvers leaderboard
+    pub first_solvers: Option<&'a AccountInfo<'info>>,
+}
+
+/// Attempts to redeem the current solution of the challenge, see
+/// [ChallengeInstruction::Redeem].
+/// The payer and the challenger need to sign, include their seeds if they are PDAs of the
+/// calling program.
+///
+/// * [solution]: the solution as the challenger sends it, see
+///   [crate::utils::hash_solution_challenger_sends]
+///
+/// Read the outcome via [crate::return_data::RedeemResult::get] afterwards.
+pub fn redeem<'a, 'info>(
+    program_info: &'a AccountInfo<'info>,
+    accounts: RedeemAccounts<'a, 'info>,
+    solution: Solution,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    assert_challenge_program("cpi::redeem", program_info)?;
+    let RedeemAccounts {
+        payer,
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        redeem,
+        redeem_ata,
+        token_program,
+        associated_token_program,
+        system_program,
+        prize_pool,
+        nft_reward,
+        first_solvers,
+    } = accounts;
+
+    let mut metas = vec![
+        writable(payer, true),
+        writable(challenge_pda, false),
+        writable(challenger, true),
+        writable(challenger_pda, false),
+        writable(redeem, false),
+        writable(redeem_ata, false),
+        readonly(token_program, false),
+        readonly(associated_token_program, false),
+        readonly(system_program, false),
+        writable(prize_pool, false),
+    ];
+    let mut infos = vec![
+        payer.clone(),
+        challenge_pda.clone(),
+        challenger.clone(),
+        challenger_pda.clone(),
+        redeem.clone(),
+        redeem_ata.clone(),
+        token_program.clone(),
+        associated_token_program.clone(),
+        system_program.clone(),
+        prize_pool.clone(),
+    ];
+    if let Some(NftRewardAccounts { nft_mint, nft_ata }) = nft_reward {
+        metas.extend([writable(nft_mint, false), writable(nft_ata, false)]);
+        infos.extend([nft_mint.clone(), nft_ata.clone()]);
+    }
+    if let Some(first_solvers) = first_solvers {
+        metas.push(writable(first_solvers, false));
+        infos.push(first_solvers.clone());
+    }
+
+    invoke_challenge(
+        program_info,
+        metas,
+        infos,
+        ChallengeInstruction::Redeem { solution },
+        signer_seeds,
+    )
+}
@@ -1,4 +1,5 @@
 use num_derive::FromPrimitive;
+use num_traits::FromPrimitive as _;
 use solana_program::{
     decode_error::DecodeError,
     msg,
@@ -82,6 +83,506 @@ pub enum ChallengeError {
     // -----------------
     #[error("Payer does not have sufficient lamports to fund the operation")]
     InsufficientFunds,
+
+    // -----------------
+    // Solve Window
+    // -----------------
+    #[error("The solve window of the challenge is not open yet")]
+    SolveWindowNotOpen,
+
+    #[error("The solve window of the challenge has closed already")]
+    SolveWindowClosed,
+
+    #[error("The solve window needs to open before it closes")]
+    InvalidSolveWindow,
+
+    // -----------------
+    // Get Challenge State
+    // -----------------
+    #[error("Challenge state is too large to be returned via return data, fetch the account instead")]
+    StateTooLargeForReturnData,
+
+    // -----------------
+    // Hints
+    // -----------------
+    #[error("The number of hints needs to match the number of solutions")]
+    HintsDoNotMatchSolutions,
+
+    // -----------------
+    // Splitting Challenge / Truncating Solutions
+    // -----------------
+    #[error("Solutions of this challenge were redeemed already and thus cannot be moved")]
+    ChallengeHasRedeemedSolutions,
+
+    #[error(
+        "The index at which to split or truncate the solutions is out of range"
+    )]
+    InvalidSolutionsSplitIndex,
+
+    // -----------------
+    // Admit Cost
+    // -----------------
+    #[error(
+        "The admit cost of this challenge is locked and cannot be changed"
+    )]
+    AdmitCostLocked,
+
+    // -----------------
+    // Oracle Signed Solutions
+    // -----------------
+    #[error("The challenge has no oracle set and thus does not accept oracle signed solutions")]
+    OracleNotSet,
+
+    #[error("The oracle signature was not verified via the Ed25519 program or does not match")]
+    InvalidOracleSignature,
+
+    #[error("The solution index is not the index of the solution that needs to be found next")]
+    SolutionIndexNotCurrent,
+
+    // -----------------
+    // Updating Solutions
+    // -----------------
+    #[error("The solution index is out of range or refers to a solution that was redeemed already")]
+    InvalidSolutionIndex,
+
+    #[error(
+        "The solutions of this challenge are locked and cannot be changed"
+    )]
+    SolutionsLocked,
+
+    // -----------------
+    // Reissue Admission
+    // -----------------
+    #[error("The challenger still has tries remaining and cannot be admitted again yet")]
+    ChallengerHasTriesRemaining,
+
+    // -----------------
+    // Leaderboard
+    // -----------------
+    #[error("Only challengers that redeemed can be added to the leaderboard")]
+    ChallengerHasNotRedeemed,
+
+    // -----------------
+    // Remove Solutions
+    // -----------------
+    #[error("The solution indices to remove contain duplicates")]
+    DuplicateSolutionIndices,
+
+    // -----------------
+    // Close Challenge
+    // -----------------
+    #[error("Challenge is in progress and challengers may still have tries remaining, thus it cannot be closed")]
+    ChallengeInProgress,
+
+    // -----------------
+    // Transfer Authority
+    // -----------------
+    #[error("The challenge has no pending authority transfer to accept")]
+    NoPendingAuthority,
+
+    // -----------------
+    // Pause Challenge
+    // -----------------
+    #[error("Challenge is paused and is not admitting challengers nor allowing to redeem prices")]
+    ChallengePaused,
+
+    // -----------------
+    // Update Challenge
+    // -----------------
+    #[error("Tries per admit need to be at least one")]
+    InvalidTriesPerAdmit,
+
+    #[error("Redeem needs to be an initialized mint whose mint authority is the challenge PDA")]
+    InvalidRedeemMint,
+
+    // -----------------
+    // Admit Mint
+    // -----------------
+    #[error("Admit mint needs to be an initialized spl token mint")]
+    InvalidAdmitMint,
+
+    #[error("The challenge has no admit mint and thus holds no admit tokens")]
+    AdmitMintNotSet,
+
+    // -----------------
+    // Prize Pool
+    // -----------------
+    #[error("The challenge has no prize pool")]
+    PrizePoolNotSet,
+
+    #[error("The challenge has a prize pool that needs to be withdrawn first")]
+    PrizePoolNotWithdrawn,
+
+    // -----------------
+    // Commit Reveal
+    // -----------------
+    #[error("The challenge requires solutions to be committed and revealed")]
+    CommitRevealRequired,
+
+    #[error("The revealed solution does not match the commitment")]
+    CommitmentMismatch,
+
+    #[error("Not enough slots passed since the solution was committed")]
+    RevealTooEarly,
+
+    // -----------------
+    // Salt
+    // -----------------
+    #[error("The challenges hash their solutions with different salts")]
+    SaltMismatch,
+
+    #[error("The challenges hash their solutions with different algorithms")]
+    HashAlgorithmMismatch,
+
+    // -----------------
+    // Schedule
+    // -----------------
+    #[error(
+        "The challenge has not started accepting challengers and solutions yet"
+    )]
+    ChallengeNotOpenYet,
+
+    #[error("The challenge has stopped accepting challengers and solutions")]
+    ChallengeEnded,
+
+    #[error("The challenge needs to start before it ends")]
+    InvalidSchedule,
+
+    // -----------------
+    // Solutions Upload
+    // -----------------
+    #[error(
+        "The solutions chunk index needs to be less than the total chunks"
+    )]
+    InvalidSolutionsChunk,
+
+    #[error("The solutions chunk is not the one the upload expects next")]
+    UnexpectedSolutionsChunk,
+
+    // -----------------
+    // Config
+    // -----------------
+    #[error("The protocol fee cannot exceed 10,000 basis points")]
+    InvalidFeeBps,
+
+    // -----------------
+    // Allowlist
+    // -----------------
+    #[error("The challenger is not on the allowlist of the challenge")]
+    NotOnAllowlist,
+
+    // -----------------
+    // Max Admissions
+    // -----------------
+    #[error("The challenge admitted the max number of challengers already")]
+    NoAdmissionsLeft,
+
+    // -----------------
+    // Refund
+    // -----------------
+    #[error(
+        "Unused tries can only be refunded once the challenge is cancelled"
+    )]
+    ChallengeNotCancelled,
+
+    #[error("The challenge was cancelled and cannot be resumed")]
+    ChallengeCancelled,
+
+    #[error("The challenger has no unused tries to refund")]
+    NothingToRefund,
+
+    #[error("The admit cost was paid to the creator directly and is not held in escrow")]
+    NoRefundEscrow,
+
+    // -----------------
+    // Discriminator
+    // -----------------
+    #[error("The account data does not start with the discriminator of the expected account kind")]
+    InvalidDiscriminator,
+
+    #[error("The account already starts with a discriminator and does not need to be migrated")]
+    AccountAlreadyMigrated,
+
+    // -----------------
+    // Versioning
+    // -----------------
+    #[error("The account was written with an older layout and needs to be migrated via MigrateAccount")]
+    AccountNeedsMigration,
+
+    #[error("The account was written with a layout version this program does not support")]
+    UnsupportedAccountVersion,
+
+    // -----------------
+    // Ownership
+    // -----------------
+    #[error("The account is not owned by the expected program")]
+    IncorrectAccountOwner,
+
+    // -----------------
+    // Key Mismatch
+    // -----------------
+    #[error("Provided program id does not match this program's id")]
+    IncorrectProgramId,
+
+    #[error(
+        "Provided challenge PDA does not match the expected challenge PDA"
+    )]
+    ProvidedChallengePdaIncorrect,
+
+    #[error(
+        "Provided challenger PDA does not match the expected challenger PDA"
+    )]
+    ProvidedChallengerPdaIncorrect,
+
+    #[error("Provided creator does not match the authority of the challenge")]
+    ProvidedCreatorIncorrect,
+
+    #[error("Provided authority does not match the expected authority")]
+    ProvidedAuthorityIncorrect,
+
+    #[error("Provided redeem mint does not match the redeem of the challenge")]
+    ProvidedRedeemIncorrect,
+
+    #[error("Provided mint does not match the expected mint")]
+    ProvidedMintIncorrect,
+
+    #[error("Provided program does not match the expected program")]
+    ProvidedProgramIncorrect,
+
+    #[error("Provided sysvar does not match the expected sysvar")]
+    ProvidedSysvarIncorrect,
+
+    #[error("Provided fee destination does not match the one of the config")]
+    ProvidedFeeDestinationIncorrect,
+
+    #[error("Provided PDA does not match the expected PDA")]
+    ProvidedPdaIncorrect,
+
+    // -----------------
+    // Rent
+    // -----------------
+    #[error("The account does not hold enough lamports to be rent exempt")]
+    AccountNotRentExempt,
+
+    // -----------------
+    // Duplicate Solutions
+    // -----------------
+    #[error(
+        "The solution to add is already part of the challenge or added twice"
+    )]
+    DuplicateSolution,
+
+    // -----------------
+    // Instruction Data
+    // -----------------
+    #[error("Instruction data is empty")]
+    InstructionDataEmpty,
+
+    #[error("Instruction data exceeds the max size an instruction can have")]
+    InstructionDataTooLarge,
+
+    #[error("Instruction data could not be decoded into a known instruction")]
+    MalformedInstructionData,
+
+    #[error(
+        "Instruction data has trailing bytes after the decoded instruction"
+    )]
+    InstructionDataHasTrailingBytes,
+
+    // -----------------
+    // Admins
+    // -----------------
+    #[error("Signer is neither the challenge authority nor one of its admins")]
+    NotAuthorityOrAdmin,
+
+    #[error("Challenge already has the max number of admins")]
+    TooManyAdmins,
+
+    #[error("Admin was already added to the challenge")]
+    AdminAlreadyAdded,
+
+    #[error("The challenge authority cannot be added as admin")]
+    AdminIsAuthority,
+
+    #[error("Admin is not one of the challenge admins")]
+    AdminNotFound,
+
+    // -----------------
+    // Rewards
+    // -----------------
+    #[error("The number of rewards needs to match the number of solutions")]
+    RewardsDoNotMatchSolutions,
+
+    // -----------------
+    // Revealing Hints
+    // -----------------
+    #[error("The solution has no hint to reveal")]
+    HintNotFound,
+
+    #[error("The hint was already revealed to the challenger")]
+    HintAlreadyRevealed,
+
+    // -----------------
+    // Attempt Cooldown
+    // -----------------
+    #[error("The challenger needs to wait for the attempt cooldown to pass before attempting again")]
+    AttemptTooSoon,
+
+    // -----------------
+    // Multiple Winners
+    // -----------------
+    #[error("The max winners per solution need to be between 2 and MAX_WINNERS_PER_SOLUTION")]
+    InvalidMaxWinnersPerSolution,
+
+    #[error("The challenger already solved this solution")]
+    SolutionAlreadySolvedByChallenger,
+
+    // -----------------
+    // Solution Normalization
+    // -----------------
+    #[error("The challenges normalize their solutions with different rules")]
+    SolutionNormalizationMismatch,
+
+    // -----------------
+    // Invariants
+    // -----------------
+    #[error("The challenge state is inconsistent")]
+    ChallengeInvariantViolated,
+
+    // -----------------
+    // Realloc
+    // -----------------
+    #[error("Growing the account exceeds the max realloc size per instruction, add the solutions in smaller batches")]
+    ReallocTooLarge,
+
+    // -----------------
+    // Account Metas
+    // -----------------
+    #[error("Account should be writable")]
+    AccountShouldBeWritable,
+
+    // -----------------
+    // Weak Solutions
+    // -----------------
+    #[error("Solution is one of the most common answers and would be guessed easily")]
+    WeakSolution,
+
+    // -----------------
+    // Tickets
+    // -----------------
+    #[error("The signer does not hold the ticket of the admission it is redeeming with")]
+    TicketNotHeld,
+
+    // -----------------
+    // Metadata
+    // -----------------
+    #[error("Challenge metadata exceeds the max length")]
+    MetadataTooLong,
+
+    // -----------------
+    // Creator Registry
+    // -----------------
+    #[error("The creator registry lists the max number of challenges")]
+    CreatorRegistryFull,
+
+    // -----------------
+    // Completion
+    // -----------------
+    #[error("All solutions of the challenge were solved and it accepts no further redeems")]
+    ChallengeCompleted,
+
+    // -----------------
+    // Debug
+    // -----------------
+    #[error("Instruction changed the total lamports of its accounts")]
+    LamportsNotConserved,
+
+    // -----------------
+    // Flags
+    // -----------------
+    #[error("Challenge started and does not allow adding solutions anymore")]
+    AddingSolutionsAfterStartNotAllowed,
+
+    #[error("Challenge flags contain bits not supported by this program")]
+    UnknownChallengeFlags,
+
+    // -----------------
+    // Idempotent Create
+    // -----------------
+    #[error("Challenge already exists with parameters different from the provided ones")]
+    ChallengeParamsMismatch,
+
+    // -----------------
+    // Buy More Tries
+    // -----------------
+    #[error("Number of tries to buy is zero or exceeds the tries a challenger can hold")]
+    InvalidTriesCount,
+
+    #[error(
+        "Challenger would be granted more tries than the challenge allows"
+    )]
+    MaxTriesPerChallengerExceeded,
+
+    // -----------------
+    // Sweep Surplus
+    // -----------------
+    #[error("Challenge holds no lamports beyond rent exemption and sponsored lamports")]
+    NoSurplusToSweep,
+
+    // -----------------
+    // Prerequisites
+    // -----------------
+    #[error("Challenge cannot be its own prerequisite")]
+    InvalidPrerequisite,
+
+    #[error("Challenger did not complete the prerequisite challenge")]
+    PrerequisiteNotCompleted,
+
+    // -----------------
+    // Epochs
+    // -----------------
+    #[error("Solutions per epoch require the epoch to last at least one slot")]
+    InvalidEpochs,
+
+    #[error(
+        "Solution is not accepted during the current epoch of the challenge"
+    )]
+    SolutionNotInCurrentEpoch,
+
+    // -----------------
+    // Missing Accounts
+    // -----------------
+    #[error("Challenge does not exist or was closed")]
+    ChallengeNotFound,
+
+    #[error("Challenger was not admitted to the challenge")]
+    ChallengerNotAdmitted,
+
+    // -----------------
+    // Co-Sponsors
+    // -----------------
+    #[error("Contribution would overflow the lamports sponsored so far")]
+    ContributionOverflow,
+
+    // -----------------
+    // Escrow
+    // -----------------
+    #[error("The escrow of the challenge holds admit tokens that need to be withdrawn first")]
+    AdmitTokensNotWithdrawn,
+
+    // -----------------
+    // Remove Solutions
+    // -----------------
+    #[error("Solutions cannot be removed once challengers were admitted since their progress refers to the solution indices")]
+    ChallengeHasAdmittedChallengers,
+}
+
+impl ChallengeError {
+    /// Maps the [code] of a custom program error, i.e. from a failed transaction, back to
+    /// the error it was raised with.
+    pub fn from_code(code: u32) -> Option<Self> {
+        Self::from_u32(code)
+    }
 }
 
 impl PrintProgramError for ChallengeError {
@@ -98,6 +599,6 @@ impl From<ChallengeError> for ProgramError {
 
 impl<T> DecodeError<T> for ChallengeError {
     fn type_of() -> &'static str {
-        "TokenOwner Error"
+        "ChallengeError"
     }
 }
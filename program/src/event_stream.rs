@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_client::GetConfirmedSignaturesForAddress2Config,
+    rpc_config::RpcTransactionConfig,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+use tokio::{sync::broadcast, task::JoinHandle};
+
+use crate::{
+    client::ClientResult,
+    events::{parse_logs, ChallengeEvent},
+};
+
+/// Decodes the events from the logs of a single transaction, [parse_logs] by default.
+pub type LogParser = fn(&[String]) -> Vec<ChallengeEvent>;
+
+/// Number of events a receiver can lag behind before it misses the oldest ones, see
+/// [broadcast::error::RecvError::Lagged].
+pub const EVENT_STREAM_CAPACITY: usize = 256;
+
+/// How long [subscribe] waits between polls for new transactions of a challenge.
+pub const EVENT_STREAM_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Broadcasts the [ChallengeEvent]s of a challenge to all its receivers.
+/// When created via [subscribe] the transactions of the challenge are polled until the
+/// stream is dropped.
+pub struct ChallengeEventStream {
+    sender: broadcast::Sender<ChallengeEvent>,
+    parser: LogParser,
+    poller: Option<JoinHandle<()>>,
+}
+
+impl ChallengeEventStream {
+    /// Creates a stream that broadcasts the events [parser] decodes from the logs passed to
+    /// [Self::publish_logs] without polling a cluster.
+    pub fn new(
+        parser: LogParser,
+    ) -> (Self, broadcast::Receiver<ChallengeEvent>) {
+        let (sender, receiver) = broadcast::channel(EVENT_STREAM_CAPACITY);
+        let stream = Self {
+            sender,
+            parser,
+            poller: None,
+        };
+        (stream, receiver)
+    }
+
+    /// Adds a receiver that gets all events published from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<ChallengeEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Decodes the events from the [logs] of a transaction and sends them to all receivers.
+    /// Returns the number of decoded events.
+    pub fn publish_logs(&self, logs: &[String]) -> usize {
+        publish_logs(&self.sender, self.parser, logs)
+    }
+}
+
+impl Drop for ChallengeEventStream {
+    fn drop(&mut self) {
+        if let Some(poller) = self.poller.take() {
+            poller.abort();
+        }
+    }
+}
+
+fn publish_logs(
+    sender: &broadcast::Sender<ChallengeEvent>,
+    parser: LogParser,
+    logs: &[String],
+) -> usize {
+    let events = parser(logs);
+    let len = events.len();
+    for event in events {
+        // sending only fails while nobody is subscribed in which case the event is dropped
+        let _ = sender.send(event);
+    }
+    len
+}
+
+/// Streams the events of the challenge at [challenge_pda] as transactions involving it are
+/// confirmed on the cluster at [rpc_url].
+/// Only transactions confirmed after subscribing are streamed, failed ones are skipped.
+pub async fn subscribe(
+    challenge_pda: Pubkey,
+    rpc_url: &str,
+) -> (ChallengeEventStream, broadcast::Receiver<ChallengeEvent>) {
+    subscribe_with_parser(
+        RpcClient::new(rpc_url.to_string()),
+        challenge_pda,
+        parse_logs,
+        EVENT_STREAM_POLL_INTERVAL,
+    )
+    .await
+}
+
+/// Same as [subscribe], but polls via [rpc] every [poll_interval] and decodes the logs
+/// with [parser].
+pub async fn subscribe_with_parser(
+    rpc: RpcClient,
+    challenge_pda: Pubkey,
+    parser: LogParser,
+    poll_interval: Duration,
+) -> (ChallengeEventStream, broadcast::Receiver<ChallengeEvent>) {
+    let (mut stream, receiver) = ChallengeEventStream::new(parser);
+    let sender = stream.sender.clone();
+    let mut until = latest_signature(&rpc, &challenge_pda).await.ok();
+
+    stream.poller = Some(tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(poll_interval).await;
+            // without knowing the latest transaction all past ones would be streamed
+            let cursor = match until {
+                Some(cursor) => cursor,
+                None => {
+                    until = latest_signature(&rpc, &challenge_pda).await.ok();
+                    continue;
+                }
+            };
+            // on failure nothing was published and the same range is fetched again
+            if let Ok((latest, logs)) =
+                fetch_logs_until(&rpc, &challenge_pda, cursor).await
+            {
+                for logs in logs {
+                    publish_logs(&sender, parser, &logs);
+                }
+                until = Some(latest);
+            }
+        }
+    }));
+    (stream, receiver)
+}
+
+async fn latest_signature(
+    rpc: &RpcClient,
+    address: &Pubkey,
+) -> ClientResult<Option<Signature>> {
+    let config = GetConfirmedSignaturesForAddress2Config {
+        limit: Some(1),
+        commitment: Some(rpc.commitment()),
+        ..GetConfirmedSignaturesForAddress2Config::default()
+    };
+    let statuses = rpc
+        .get_signatures_for_address_with_config(address, config)
+        .await?;
+    Ok(statuses
+        .first()
+        .and_then(|status| status.signature.parse().ok()))
+}
+
+/// Fetches the logs of the successful transactions involving [address] that were confirmed
+/// after the one with the [until] signature, oldest first, together with the signature of
+/// the latest transaction.
+async fn fetch_logs_until(
+    rpc: &RpcClient,
+    address: &Pubkey,
+    until: Option<Signature>,
+) -> ClientResult<(Option<Signature>, Vec<Vec<String>>)> {
+    let config = GetConfirmedSignaturesForAddress2Config {
+        until,
+        commitment: Some(rpc.commitment()),
+        ..GetConfirmedSignaturesForAddress2Config::default()
+    };
+    // signatures are returned newest first
+    let statuses = rpc
+        .get_signatures_for_address_with_config(address, config)
+        .await?;
+    let latest = statuses
+        .first()
+        .and_then(|status| status.signature.parse().ok())
+        .or(until);
+
+    let mut logs = vec![];
+    for status in statuses.iter().rev().filter(|status| status.err.is_none()) {
+        if let Ok(signature) = status.signature.parse::<Signature>() {
+            logs.push(fetch_transaction_logs(rpc, &signature).await?);
+        }
+    }
+    Ok((latest, logs))
+}
+
+async fn fetch_transaction_logs(
+    rpc: &RpcClient,
+    signature: &Signature,
+) -> ClientResult<Vec<String>> {
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(rpc.commitment()),
+        max_supported_transaction_version: Some(0),
+    };
+    let tx = rpc.get_transaction_with_config(signature, config).await?;
+    Ok(tx
+        .transaction
+        .meta
+        .and_then(|meta| Option::<Vec<String>>::from(meta.log_messages))
+        .unwrap_or_default())
+}
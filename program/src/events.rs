@@ -0,0 +1,96 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    log::sol_log_data, program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::challenge_id;
+
+/// Events emitted via `sol_log_data` so indexers can follow challenges without polling
+/// accounts. They show up as `Program data: <base64>` lines in the transaction logs and are
+/// decoded via [parse_logs].
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ChallengeEvent {
+    ChallengeCreated {
+        challenge_pda: Pubkey,
+        authority: Pubkey,
+        id: String,
+        solutions: u8,
+    },
+    SolutionsAdded {
+        challenge_pda: Pubkey,
+        /// The number of solutions that were added
+        added: u8,
+        /// The number of solutions of the challenge after they were added
+        total: u8,
+    },
+    ChallengerAdmitted {
+        challenge_pda: Pubkey,
+        challenger: Pubkey,
+        tries: u8,
+    },
+    SolutionRedeemed {
+        challenge_pda: Pubkey,
+        challenger: Pubkey,
+        /// Index of the solution that was redeemed
+        solution_index: u8,
+    },
+    ChallengeClosed {
+        challenge_pda: Pubkey,
+    },
+    HintRevealed {
+        challenge_pda: Pubkey,
+        challenger: Pubkey,
+        /// Index of the solution whose hint was revealed
+        solution_index: u8,
+        /// The revealed hint, see `Challenge::hints`
+        hint: [u8; 64],
+    },
+    GuessFailed {
+        challenge_pda: Pubkey,
+        challenger: Pubkey,
+        /// Hash of the incorrect solution the challenger sent
+        guess: [u8; 32],
+        /// Tries the challenger has left after the failed guess
+        tries_remaining: u8,
+    },
+}
+
+impl ChallengeEvent {
+    /// Logs the event as program data.
+    pub fn emit(&self) -> Result<(), ProgramError> {
+        sol_log_data(&[&self.try_to_vec()?]);
+        Ok(())
+    }
+}
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Decodes the events emitted by this program from the [logs] of a transaction.
+/// Data logged by other programs, including ones invoked by or invoking this program, is
+/// skipped.
+pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> Vec<ChallengeEvent> {
+    let program_id = challenge_id().to_string();
+    let invoke_prefix = format!("Program {} invoke", program_id);
+
+    // Tracks whether each program on the invocation stack is this program
+    let mut stack = Vec::<bool>::new();
+    let mut events = vec![];
+    for log in logs.iter().map(AsRef::as_ref) {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_PREFIX) {
+            if stack.last() != Some(&true) {
+                continue;
+            }
+            let event = base64::decode(data)
+                .ok()
+                .and_then(|bytes| ChallengeEvent::try_from_slice(&bytes).ok());
+            events.extend(event);
+        } else if log.starts_with("Program ") && log.contains(" invoke [") {
+            stack.push(log.starts_with(&invoke_prefix));
+        } else if log.starts_with("Program ")
+            && (log.ends_with(" success") || log.contains(" failed: "))
+        {
+            stack.pop();
+        }
+    }
+    events
+}
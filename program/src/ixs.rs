@@ -1,20 +1,42 @@
+use std::fmt;
+
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankInstruction;
 use solana_program::{
     instruction::{AccountMeta, Instruction},
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
-    system_program,
+    system_program, sysvar,
 };
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 
 use crate::{
+    accounts::{self, AccountSpec},
     challenge_id,
-    state::{Challenge, Challenger, HasPda, Redeem},
-    utils::{hash_solution_challenger_sends, hash_solutions},
+    error::ChallengeError,
+    pda,
+    state::{
+        AccountKind, Challenge, ChallengeMetadata, ChallengeTemplate,
+        Challenger, Config, CreatorRegistry, FirstSolvers, HasPda,
+        HashAlgorithm, Leaderboard, NftReward, Payout, PrizePool, Redeem,
+        RevealedHints, SolutionCommitment, SolutionNormalization,
+        SolutionsUpload, SolveMode, SponsorLog, TemplateOverrides, Ticket,
+        Winners,
+    },
+    utils::{
+        ed25519_verify_instruction, hash_solution_challenger_sends,
+        hash_solutions_with_algorithm, solution_commitment,
+    },
+    Hint, Solution,
 };
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, ShankInstruction)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChallengeInstruction {
+    /// Creates a challenge with the provided solutions and the mint of the token that
+    /// challengers receive when they redeem a correct solution.
+    /// The challenge PDA is derived from the creator and the challenge id.
     #[rustfmt::skip]
     #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
     #[account(1, name = "creator", desc="challenge authority")]
@@ -22,6 +44,7 @@ pub enum ChallengeInstruction {
     #[account(3, name = "redeem_pda", mut, desc="PDA of token to redeem for correct solution")]
     #[account(4, name = "token_program", desc="Token Program")]
     #[account(5, name = "system_program", desc="System Program")]
+    #[account(6, name = "creator_registry", mut, optional, desc="PDA listing the challenges of the creator, see [crate::state::CreatorRegistry]")]
     CreateChallenge {
         id: String,
         admit_cost: u64,
@@ -29,6 +52,7 @@ pub enum ChallengeInstruction {
 
         /// The PDA address of the mint that each challenger that solves the challenge receives.
         /// It is derived from the challenge PDA.
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
         redeem: Pubkey,
 
         /// Each solution is a hash array of of 32 bytes.
@@ -36,20 +60,43 @@ pub enum ChallengeInstruction {
         /// Transaction size is ~1,024 bytes which means if more solutions are desired they
         /// need to be added separately via the `AddSolutions` instruction.
         solutions: Vec<[u8; 32]>,
+
+        /// If provided, solutions are hashed as `sha256(salt || solution)` before being
+        /// stored, see [crate::utils::solution_stored_as].
+        salt: Option<[u8; 32]>,
+
+        /// The algorithm used to hash the solutions.
+        hash_algorithm: HashAlgorithm,
+
+        /// If provided, challengers are only admitted and solutions only accepted starting
+        /// at this unix timestamp.
+        starts_at: Option<i64>,
+
+        /// If provided, challengers are only admitted and solutions only accepted up to and
+        /// including this unix timestamp.
+        ends_at: Option<i64>,
     },
 
     /// Appends solutions to the end of the solutions array, keeping existing solutions in place.
+    /// Can be invoked by the authority or any of the admins of the challenge.
     #[rustfmt::skip]
     #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
-    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(1, name = "creator", sig, desc="challenge authority or one of its admins")]
     #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
     #[account(3, name = "system_program", desc="System Program")]
     AddSolutions {
         id: String,
         /// The solutions to add to the challenge
         solutions: Vec<[u8; 32]>,
+        /// Unless set, adding a solution that is already part of the challenge or is
+        /// included more than once fails
+        allow_duplicates: bool,
+        /// The amounts paid out of the prize pool for redeeming each added solution, either
+        /// empty or one reward per solution, see `Challenge::rewards`
+        rewards: Vec<u64>,
     },
 
+    /// Starts the challenge such that challengers can be admitted and redeem solutions.
     #[rustfmt::skip]
     #[account(0, name = "creator", sig, desc="challenge authority")]
     #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
@@ -57,261 +104,4774 @@ pub enum ChallengeInstruction {
         id: String,
     },
 
+    /// Admits a challenger, charging the admit cost which is paid in lamports to the creator
+    /// unless the challenge has an admit mint, in which case it is paid in its tokens to the
+    /// ATA of the challenge PDA.
+    /// Once the program config is initialized the protocol fee is split off the admit cost
+    /// and paid to the fee destination.
+    /// If the challenge has an allowlist the challenger needs to prove being on it.
+    /// If the challenge admits with tickets the challenger receives the ticket of the
+    /// admission, see [crate::state::Ticket].
+    /// If the challenge has a prerequisite the challenger needs to have redeemed a solution
+    /// of it, proven by its challenger PDA of the prerequisite challenge.
     #[rustfmt::skip]
     #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
     #[account(1, name = "creator", mut, desc="challenge authority")]
-    #[account(2, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
     #[account(3, name = "challenger", desc="challenger account which receives the redeemed token")]
     #[account(4, name = "challenger_pda", mut, desc="PDA for the challenger")]
     #[account(5, name = "system_program", desc="System Program")]
+    #[account(6, name = "prize_pool", optional, mut, desc="prize pool of the challenge, receives the admit cost if the challenge has one")]
+    #[account(7, name = "admit_mint", optional, desc="admit mint of the challenge, required if it has one")]
+    #[account(8, name = "payer_ata", optional, mut, desc="ATA of the payer for the admit mint which pays the admit cost")]
+    #[account(9, name = "escrow_ata", optional, mut, desc="ATA of the challenge PDA for the admit mint which receives the admit cost")]
+    #[account(10, name = "token_program", optional, desc="Token Program")]
+    #[account(11, name = "associated_token_program", optional, desc="Associated Token Program")]
+    #[account(12, name = "config", desc="PDA for the program config, follows the prize pool or the admit mint accounts if the challenge has one")]
+    #[account(13, name = "fee_destination", optional, mut, desc="receives the protocol fee, required once the config is initialized")]
+    #[account(14, name = "fee_destination_ata", optional, mut, desc="ATA of the fee destination for the admit mint, required once the config is initialized if the challenge has an admit mint")]
+    #[account(15, name = "ticket_mint", optional, mut, desc="PDA of the ticket minted for the admission, required if the challenge admits with tickets, follows the config or the fee destination accounts once the config is initialized")]
+    #[account(16, name = "ticket_ata", optional, mut, desc="ATA for the ticket and challenger, required if the challenge admits with tickets")]
+    #[account(17, name = "ticket_token_program", optional, desc="Token Program, required if the challenge admits with tickets")]
+    #[account(18, name = "ticket_associated_token_program", optional, desc="Associated Token Program, required if the challenge admits with tickets")]
+    #[account(19, name = "prerequisite_challenger_pda", optional, desc="PDA of the challenger for the prerequisite challenge, required if the challenge has one, expected last")]
     AdmitChallenger {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
         challenge_pda: Pubkey,
+        /// Proof that the challenger is on the allowlist, only required if the challenge has
+        /// one, see [crate::utils::allowlist_proof]
+        allowlist_proof: Vec<[u8; 32]>,
     },
 
+    /// Uses up one try of the challenger to solve the current solution of the challenge.
+    /// If the solution is correct the challenger receives the redeem token as well as any
+    /// prize, NFT reward or first solver spot the challenge offers.
+    /// If the challenge admits with tickets the tries of the admission are used by whoever
+    /// holds its ticket, who then also receives the rewards.
     #[rustfmt::skip]
     #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
     #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
-    #[account(2, name = "challenger", sig, desc="challenger account which receives the redeemed token")]
+    #[account(2, name = "challenger", mut, sig, desc="challenger account which receives the redeemed token and prize")]
     #[account(3, name = "challenger_pda", mut, desc="PDA for the challenger")]
     #[account(4, name = "redeem", mut, desc="PDA of token to redeem for correct solution")]
     #[account(5, name = "redeem_ata", mut, desc="ATA for redeem PDA and challenger")]
     #[account(6, name = "token_program", desc="Token Program")]
     #[account(7, name = "associated_token_program", desc="Associated Token Program")]
     #[account(8, name = "system_program", desc="System Program")]
+    #[account(9, name = "prize_pool", optional, mut, desc="prize pool of the challenge, required if it has one")]
+    #[account(10, name = "nft_mint", optional, mut, desc="PDA of the NFT rewarded for the solution, required if the challenge rewards NFTs")]
+    #[account(11, name = "nft_ata", optional, mut, desc="ATA for the NFT and challenger, required if the challenge rewards NFTs")]
+    #[account(12, name = "first_solvers", optional, mut, desc="PDA for the first solvers leaderboard, required if the challenge has one")]
+    #[account(13, name = "winners", optional, mut, desc="PDA for the winners leaderboard, required if the challenge allows multiple winners per solution")]
+    #[account(14, name = "ticket_ata", optional, desc="token account of the challenger holding the ticket of the admission, required if the challenge admits with tickets")]
     Redeem {
+        /// The solution as the challenger sends it, see
+        /// [crate::utils::hash_solution_challenger_sends]
         solution: [u8; 32],
     },
-    // TODO(thlorenz): may need some ixs for creators that want to mutate solutions, i.e.
-    //  - add solutions at index (replacing existing ones)
-    //  - replace solution at index
-    //  - clear solutions
+
+    /// Sets the slots between which solutions are accepted via `Redeem`.
+    /// Challengers can still be admitted outside of that window.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    SetSolveWindow {
+        id: String,
+        /// First slot at which solutions are accepted, `None` for no restriction
+        from_slot: Option<u64>,
+        /// Last slot at which solutions are accepted, `None` for no restriction
+        until_slot: Option<u64>,
+    },
+
+    /// Returns the challenge account data, i.e. the discriminator followed by the Borsh
+    /// serialized challenge, via return data without modifying it.
+    /// Meant to be used via `simulateTransaction`.
+    #[rustfmt::skip]
+    #[account(0, name = "challenge_pda", desc="PDA for the challenge")]
+    GetChallengeState,
+
+    /// Creates a new challenge with the same authority and settings as an existing one and
+    /// moves the solutions of the existing challenge starting at `split_at` into it, i.e.
+    /// they are removed from the existing challenge.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "new_challenge_pda", mut, desc="PDA for the challenge created by the split")]
+    #[account(4, name = "new_redeem_pda", mut, desc="PDA of token to redeem for correct solution of the new challenge")]
+    #[account(5, name = "token_program", desc="Token Program")]
+    #[account(6, name = "system_program", desc="System Program")]
+    #[account(7, name = "creator_registry", mut, optional, desc="PDA listing the challenges of the creator, see [crate::state::CreatorRegistry]")]
+    SplitChallenge {
+        id: String,
+        /// The id of the challenge created by the split
+        new_id: String,
+        /// The PDA address of the mint for the new challenge, derived from its PDA
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        new_redeem: Pubkey,
+        /// Index of the first solution that is copied to the new challenge
+        split_at: u8,
+    },
+
+    /// Removes all solutions starting at index `len`.
+    /// Only allowed as long as no solution was redeemed yet.
+    #[rustfmt::skip]
+    #[account(0, name = "creator", sig, desc="challenge authority")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    TruncateSolutions {
+        id: String,
+        /// The number of solutions to keep
+        len: u8,
+    },
+
+    /// Appends all solutions of the source challenge to the challenge and closes the source
+    /// challenge, transferring its lamports to the creator.
+    /// Both challenges need to be managed by the same authority and cannot have any solutions
+    /// redeemed yet. The source challenge cannot be in progress and the admit tokens in its
+    /// escrow need to be withdrawn first.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", mut, sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "source_challenge_pda", mut, desc="PDA for the challenge whose solutions are merged and which is closed")]
+    #[account(4, name = "system_program", desc="System Program")]
+    #[account(5, name = "source_escrow_ata", optional, desc="ATA of the source challenge holding its admit tokens, only required if it has an admit mint")]
+    #[account(6, name = "creator_registry", mut, optional, desc="PDA listing the challenges of the creator, see [crate::state::CreatorRegistry]")]
+    MergeChallenges {
+        id: String,
+        /// The id of the challenge whose solutions are appended
+        source_id: String,
+    },
+
+    /// Irreversibly locks the admit cost of the challenge such that it can no longer be
+    /// updated.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    LockAdmitCost {
+        id: String,
+    },
+
+    /// Creates a template holding default settings for challenges created via
+    /// [ChallengeInstruction::CreateChallengeFromTemplate].
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="template and challenge authority")]
+    #[account(2, name = "template_pda", mut, desc="PDA for the challenge template")]
+    #[account(3, name = "system_program", desc="System Program")]
+    CreateTemplate {
+        template_id: u8,
+        admit_cost: u64,
+        tries_per_admit: u8,
+
+        /// The salt challenges created from the template hash their solutions with unless
+        /// overridden, see [ChallengeInstruction::CreateChallenge].
+        salt: Option<[u8; 32]>,
+
+        /// The algorithm challenges created from the template hash their solutions with
+        /// unless overridden.
+        hash_algorithm: HashAlgorithm,
+
+        /// The schedule of challenges created from the template unless overridden.
+        starts_at: Option<i64>,
+        ends_at: Option<i64>,
+    },
+
+    /// Creates a new challenge using the settings of the template unless they are
+    /// overridden.
+    /// Accounts are the same as for [ChallengeInstruction::CreateChallenge] with the
+    /// template PDA appended.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "redeem_pda", mut, desc="PDA of token to redeem for correct solution")]
+    #[account(4, name = "token_program", desc="Token Program")]
+    #[account(5, name = "system_program", desc="System Program")]
+    #[account(6, name = "template_pda", desc="PDA for the challenge template")]
+    #[account(7, name = "creator_registry", mut, optional, desc="PDA listing the challenges of the creator, see [crate::state::CreatorRegistry]")]
+    CreateChallengeFromTemplate {
+        template_id: u8,
+        id: String,
+        /// Overrides the template's admit_cost if provided
+        admit_cost: Option<u64>,
+        /// Overrides the template's tries_per_admit if provided
+        tries_per_admit: Option<u8>,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        redeem: Pubkey,
+        solutions: Vec<[u8; 32]>,
+        /// Overrides the template's salt if provided
+        salt: Option<[u8; 32]>,
+        /// Overrides the template's hash_algorithm if provided
+        hash_algorithm: Option<HashAlgorithm>,
+        /// Overrides the template's starts_at if provided
+        starts_at: Option<i64>,
+        /// Overrides the template's ends_at if provided
+        ends_at: Option<i64>,
+    },
+
+    /// Sets the oracle whose signatures entitle challengers to redeem via
+    /// [ChallengeInstruction::VerifySolveSignature].
+    /// Setting it to the default pubkey disables oracle signed solutions.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetOracle {
+        id: String,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        oracle: Pubkey,
+    },
+
+    /// Redeems the solution at [solution_index] for the challenger without revealing it.
+    /// Instead the oracle signs the [crate::state::Challenge::oracle_message] off-chain and
+    /// the signature is verified via an Ed25519 program instruction which needs to
+    /// immediately precede this instruction.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(2, name = "challenger", mut, sig, desc="challenger account which receives the redeemed token and prize")]
+    #[account(3, name = "challenger_pda", mut, desc="PDA for the challenger")]
+    #[account(4, name = "redeem", mut, desc="PDA of token to redeem for correct solution")]
+    #[account(5, name = "redeem_ata", mut, desc="ATA for redeem PDA and challenger")]
+    #[account(6, name = "token_program", desc="Token Program")]
+    #[account(7, name = "associated_token_program", desc="Associated Token Program")]
+    #[account(8, name = "system_program", desc="System Program")]
+    #[account(9, name = "instructions_sysvar", desc="Instructions Sysvar")]
+    #[account(10, name = "prize_pool", optional, mut, desc="prize pool of the challenge, required if it has one")]
+    #[account(11, name = "nft_mint", optional, mut, desc="PDA of the NFT rewarded for the solution, required if the challenge rewards NFTs")]
+    #[account(12, name = "nft_ata", optional, mut, desc="ATA for the NFT and challenger, required if the challenge rewards NFTs")]
+    #[account(13, name = "first_solvers", optional, mut, desc="PDA for the first solvers leaderboard, required if the challenge has one")]
+    #[account(14, name = "winners", optional, mut, desc="PDA for the winners leaderboard, required if the challenge allows multiple winners per solution")]
+    #[account(15, name = "ticket_ata", optional, desc="token account of the challenger holding the ticket of the admission, required if the challenge admits with tickets")]
+    VerifySolveSignature {
+        solution_index: u8,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hint"))]
+        oracle_signature: [u8; 64],
+    },
+
+    /// Replaces the solution at [index] with [new_hash].
+    /// Solutions that were redeemed already cannot be replaced.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    UpdateSolutionHash {
+        id: String,
+        index: u8,
+        /// The solution as it is stored, see [crate::utils::hash_solutions]
+        new_hash: [u8; 32],
+    },
+
+    /// Locks or unlocks the solutions of the challenge.
+    /// While locked solutions cannot be added, removed or replaced, but challengers can still
+    /// be admitted and redeem.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetSolutionsLocked {
+        id: String,
+        locked: bool,
+    },
+
+    /// Admits a challenger that used up all tries again, charging the admit cost once more
+    /// and resetting the tries to the challenge's tries_per_admit.
+    /// If the challenge has an admit mint the admit cost is paid in its tokens.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", mut, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(3, name = "challenger", desc="challenger account which receives the redeemed token")]
+    #[account(4, name = "challenger_pda", mut, desc="PDA for the challenger")]
+    #[account(5, name = "system_program", desc="System Program")]
+    #[account(6, name = "prize_pool", optional, mut, desc="prize pool of the challenge, receives the admit cost if the challenge has one")]
+    #[account(7, name = "admit_mint", optional, desc="admit mint of the challenge, required if it has one")]
+    #[account(8, name = "payer_ata", optional, mut, desc="ATA of the payer for the admit mint which pays the admit cost")]
+    #[account(9, name = "escrow_ata", optional, mut, desc="ATA of the challenge PDA for the admit mint which receives the admit cost")]
+    #[account(10, name = "token_program", optional, desc="Token Program")]
+    #[account(11, name = "associated_token_program", optional, desc="Associated Token Program")]
+    #[account(12, name = "config", desc="PDA for the program config, follows the prize pool or the admit mint accounts if the challenge has one")]
+    #[account(13, name = "fee_destination", optional, mut, desc="receives the protocol fee, required once the config is initialized")]
+    #[account(14, name = "fee_destination_ata", optional, mut, desc="ATA of the fee destination for the admit mint, required once the config is initialized if the challenge has an admit mint")]
+    ReissueAdmission {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        challenge_pda: Pubkey,
+    },
+
+    /// Transfers lamports from the sponsor into the prize pool of the challenge and records
+    /// the contribution in the sponsor's log. Anyone can sponsor a challenge.
+    /// Unless the challenge has a [crate::state::PrizePool] the challenge PDA holds the prize
+    /// pool lamports.
+    #[rustfmt::skip]
+    #[account(0, name = "sponsor", mut, sig, desc="contributes the lamports and pays for the transaction")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(2, name = "sponsor_log_pda", mut, desc="PDA logging the sponsor's contributions")]
+    #[account(3, name = "system_program", desc="System Program")]
+    #[account(4, name = "prize_pool", optional, mut, desc="prize pool of the challenge, required if it has one")]
+    AddCoSponsor {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        challenge_pda: Pubkey,
+        amount: u64,
+    },
+
+    /// Creates an empty leaderboard for the challenge.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(3, name = "leaderboard_pda", mut, desc="PDA for the challenge leaderboard")]
+    #[account(4, name = "system_program", desc="System Program")]
+    CreateLeaderboard {
+        id: String,
+    },
+
+    /// Adds or improves the challenger's entry on the leaderboard.
+    /// Only challengers that redeemed can be added. Their score is the number of tries they
+    /// had remaining, i.e. challengers that needed fewer tries rank higher.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(2, name = "challenger", sig, desc="challenger that redeemed")]
+    #[account(3, name = "challenger_pda", desc="PDA for the challenger")]
+    #[account(4, name = "leaderboard_pda", mut, desc="PDA for the challenge leaderboard")]
+    #[account(5, name = "system_program", desc="System Program")]
+    UpdateLeaderboard,
+
+    /// Removes the solutions at the given indices, shrinks the challenge account and refunds
+    /// the excess rent to the creator.
+    /// If solutions before the one being solved are removed the `solving` index is adjusted
+    /// so it keeps pointing at the same solution.
+    #[rustfmt::skip]
+    #[account(0, name = "creator", mut, sig, desc="challenge authority, receives the refunded rent")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    RemoveSolutions {
+        id: String,
+        indices: Vec<u8>,
+    },
+
+    /// Replaces all solutions of the challenge at once, resizing the account as needed.
+    /// If the account grows the payer funds the extra rent, if it shrinks the payer receives
+    /// the rent that is no longer needed.
+    /// Only allowed as long as no solution was redeemed yet.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for or receives the rent difference")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    ReplaceSolutions {
+        id: String,
+        /// The solutions that replace the current ones
+        solutions: Vec<[u8; 32]>,
+    },
+
+    /// Closes the challenge, zeroing its data and transferring all its lamports to the
+    /// recipient.
+    /// Only allowed if the challenge was not started yet or has finished, since otherwise
+    /// challengers may still have paid tries remaining.
+    #[rustfmt::skip]
+    #[account(0, name = "creator", sig, desc="challenge authority")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(2, name = "recipient", mut, desc="receives the lamports of the closed challenge")]
+    #[account(3, name = "creator_registry", mut, optional, desc="PDA listing the challenges of the creator, see [crate::state::CreatorRegistry]")]
+    CloseChallenge {
+        id: String,
+    },
+
+    /// Starts transferring the challenge to a new authority which needs to accept it via
+    /// `AcceptAuthority` before it takes effect.
+    /// Transferring to the current authority cancels a pending transfer.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for or receives the rent difference")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    TransferAuthority {
+        id: String,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        new_authority: Pubkey,
+    },
+
+    /// Completes the transfer of the challenge started via `TransferAuthority`.
+    /// The challenge PDA remains derived from the authority that created it.
+    #[rustfmt::skip]
+    #[account(0, name = "new_authority", mut, sig, desc="pending authority of the challenge, receives the refunded rent")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    AcceptAuthority {
+        id: String,
+    },
+
+    /// Pauses or resumes the challenge.
+    /// While paused challengers cannot be admitted nor redeem, but the authority can still
+    /// update the challenge, i.e. add solutions.
+    /// Can be invoked by the authority or any of the admins of the challenge.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority or one of its admins")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetPaused {
+        id: String,
+        paused: bool,
+    },
+
+    /// Updates the economics of the challenge, only the provided values are changed.
+    /// Fails to update the admit cost if it was locked via [ChallengeInstruction::LockAdmitCost].
+    /// The new redeem needs to be a mint whose mint authority is the challenge PDA and is
+    /// only required when the redeem is updated.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "redeem", optional, desc="the new mint to redeem for correct solutions")]
+    UpdateChallenge {
+        id: String,
+        admit_cost: Option<u64>,
+        tries_per_admit: Option<u8>,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::option_pubkey"))]
+        redeem: Option<Pubkey>,
+    },
+
+    /// Sets the mint in whose tokens the admit cost is paid, `None` switches back to lamports.
+    /// Fails if the admit cost was locked via [ChallengeInstruction::LockAdmitCost].
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for or receives the rent difference")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    #[account(4, name = "admit_mint", optional, desc="the new admit mint, required when setting one")]
+    SetAdmitMint {
+        id: String,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::option_pubkey"))]
+        admit_mint: Option<Pubkey>,
+    },
+
+    /// Withdraws all admit tokens held by the challenge PDA to the creator.
+    #[rustfmt::skip]
+    #[account(0, name = "creator", mut, sig, desc="challenge authority, receives the admit tokens")]
+    #[account(1, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(2, name = "admit_mint", desc="admit mint of the challenge")]
+    #[account(3, name = "escrow_ata", mut, desc="ATA of the challenge PDA for the admit mint")]
+    #[account(4, name = "creator_ata", mut, desc="ATA of the creator for the admit mint")]
+    #[account(5, name = "token_program", desc="Token Program")]
+    #[account(6, name = "associated_token_program", desc="Associated Token Program")]
+    #[account(7, name = "system_program", desc="System Program")]
+    WithdrawAdmitTokens {
+        id: String,
+    },
+
+    /// Creates the prize pool of the challenge. From then on admit costs paid in lamports go
+    /// into the pool instead of to the creator and challengers that redeem are paid out from
+    /// it according to [payout].
+    /// The pool needs to be created before the challenge is started.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction and the prize pool account")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "prize_pool", mut, desc="PDA for the prize pool of the challenge")]
+    #[account(4, name = "system_program", desc="System Program")]
+    CreatePrizePool {
+        id: String,
+        payout: Payout,
+    },
+
+    /// Withdraws the lamports left in the prize pool to the creator and closes it.
+    /// Fails while the challenge is in progress.
+    #[rustfmt::skip]
+    #[account(0, name = "creator", mut, sig, desc="challenge authority, receives the leftover lamports")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(2, name = "prize_pool", mut, desc="PDA for the prize pool of the challenge")]
+    WithdrawPrizePool {
+        id: String,
+    },
+
+    /// Enables or disables rewarding challengers that redeem with an NFT.
+    /// The NFT is minted with the challenge PDA as mint authority which is removed right after
+    /// minting the single token.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetNftReward {
+        id: String,
+        enabled: bool,
+    },
+
+    /// Commits the challenger to a solution without revealing it, see
+    /// [crate::utils::solution_commitment].
+    /// Committing again replaces the previous commitment and restarts the reveal delay.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction and the commitment account")]
+    #[account(1, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(2, name = "challenger", sig, desc="challenger committing to the solution")]
+    #[account(3, name = "challenger_pda", desc="PDA for the challenger")]
+    #[account(4, name = "commitment_pda", mut, desc="PDA holding the challenger's commitment")]
+    #[account(5, name = "system_program", desc="System Program")]
+    #[account(6, name = "ticket_ata", optional, desc="token account of the challenger holding the ticket of the admission, required if the challenge admits with tickets")]
+    CommitSolution {
+        commitment: [u8; 32],
+    },
+
+    /// Reveals the solution the challenger committed to via
+    /// [ChallengeInstruction::CommitSolution] and redeems it like
+    /// [ChallengeInstruction::Redeem].
+    /// At least [crate::state::MIN_REVEAL_SLOT_GAP] slots need to pass between committing and
+    /// revealing.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction and receives the commitment rent")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(2, name = "challenger", mut, sig, desc="challenger account which receives the redeemed token and prize")]
+    #[account(3, name = "challenger_pda", mut, desc="PDA for the challenger")]
+    #[account(4, name = "redeem", mut, desc="PDA of token to redeem for correct solution")]
+    #[account(5, name = "redeem_ata", mut, desc="ATA for redeem PDA and challenger")]
+    #[account(6, name = "token_program", desc="Token Program")]
+    #[account(7, name = "associated_token_program", desc="Associated Token Program")]
+    #[account(8, name = "system_program", desc="System Program")]
+    #[account(9, name = "commitment_pda", mut, desc="PDA holding the challenger's commitment")]
+    #[account(10, name = "prize_pool", optional, mut, desc="prize pool of the challenge, required if it has one")]
+    #[account(11, name = "nft_mint", optional, mut, desc="PDA of the NFT rewarded for the solution, required if the challenge rewards NFTs")]
+    #[account(12, name = "nft_ata", optional, mut, desc="ATA for the NFT and challenger, required if the challenge rewards NFTs")]
+    #[account(13, name = "first_solvers", optional, mut, desc="PDA for the first solvers leaderboard, required if the challenge has one")]
+    #[account(14, name = "winners", optional, mut, desc="PDA for the winners leaderboard, required if the challenge allows multiple winners per solution")]
+    #[account(15, name = "ticket_ata", optional, desc="token account of the challenger holding the ticket of the admission, required if the challenge admits with tickets")]
+    RevealSolution {
+        solution: [u8; 32],
+        nonce: [u8; 32],
+    },
+
+    /// Requires or stops requiring solutions to be committed and revealed instead of being
+    /// redeemed directly.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetCommitReveal {
+        id: String,
+        required: bool,
+    },
+
+    /// Restricts admission to challengers on the allowlist with the given Merkle root,
+    /// `None` admits everyone again.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for or receives the rent difference")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    SetAllowlist {
+        id: String,
+        /// Merkle root of the allowlist, see [crate::utils::allowlist_root]
+        root: Option<[u8; 32]>,
+    },
+
+    /// Limits how many challengers are admitted, `None` removes the limit.
+    /// Challengers that were already admitted are not affected by a lower limit.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for or receives the rent difference")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    SetMaxAdmissions {
+        id: String,
+        max_admissions: Option<u32>,
+    },
+
+    /// Creates the leaderboard recording the first solver of each solution, sized for the
+    /// current solutions of the challenge.
+    /// Once created, redeeming requires the leaderboard to be passed after the NFT reward
+    /// accounts, or the prize pool if the challenge does not reward NFTs.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "first_solvers", mut, desc="PDA for the first solvers leaderboard")]
+    #[account(4, name = "system_program", desc="System Program")]
+    CreateFirstSolvers {
+        id: String,
+    },
+
+    /// Uploads solutions in chunks across multiple transactions for solution sets that do
+    /// not fit into a single one.
+    /// Chunks need to be sent in order, the solutions of each are collected in an upload PDA
+    /// and appended to the challenge all at once when the last chunk arrives.
+    /// Sending chunk `0` again starts a new upload, discarding the solutions collected so far.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "solutions_upload", mut, desc="PDA collecting the uploaded solutions")]
+    #[account(4, name = "system_program", desc="System Program")]
+    AppendSolutionsChunk {
+        id: String,
+        /// Index of this chunk, starting at `0`
+        chunk_index: u8,
+        /// Total number of chunks in the upload
+        total_chunks: u8,
+        /// The solutions of this chunk
+        solutions: Vec<[u8; 32]>,
+    },
+
+    /// Initializes the program config and thereby starts charging the protocol fee when
+    /// challengers are admitted. The signing admin becomes the config authority and has to
+    /// be the upgrade authority of the program.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction and the config rent")]
+    #[account(1, name = "admin", sig, desc="authority of the config")]
+    #[account(2, name = "config", mut, desc="PDA for the program config")]
+    #[account(3, name = "program_data", desc="program data account holding the upgrade authority")]
+    #[account(4, name = "system_program", desc="System Program")]
+    InitConfig {
+        /// Protocol fee in basis points of the admit cost
+        fee_bps: u16,
+        /// Receives the protocol fee
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        fee_destination: Pubkey,
+    },
+
+    /// Updates the program config, only the provided values are changed.
+    #[rustfmt::skip]
+    #[account(0, name = "admin", sig, desc="authority of the config")]
+    #[account(1, name = "config", mut, desc="PDA for the program config")]
+    UpdateConfig {
+        fee_bps: Option<u16>,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::option_pubkey"))]
+        fee_destination: Option<Pubkey>,
+        /// Hands the config over to a new admin
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::option_pubkey"))]
+        admin: Option<Pubkey>,
+    },
+
+    /// Cancels the challenge for good, it stays paused and cannot be resumed.
+    /// Admitted challengers can then reclaim the admit cost of their unused tries via
+    /// [ChallengeInstruction::RefundUnusedTries].
+    #[rustfmt::skip]
+    #[account(0, name = "creator", sig, desc="challenge authority")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    CancelChallenge {
+        id: String,
+    },
+
+    /// Refunds the challenger the part of the admit cost that pays for its unused tries once the
+    /// challenge was cancelled. The refund comes out of the escrow ATA if the challenge has an
+    /// admit mint and out of the prize pool otherwise.
+    #[rustfmt::skip]
+    #[account(0, name = "challenger", mut, sig, desc="the challenger receiving the refund")]
+    #[account(1, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(2, name = "challenger_pda", mut, desc="PDA for the challenger")]
+    #[account(3, name = "system_program", desc="System Program")]
+    #[account(4, name = "prize_pool", mut, desc="prize pool of the challenge holding the admit cost paid in lamports")]
+    #[account(5, name = "admit_mint", optional, desc="admit mint of the challenge")]
+    #[account(6, name = "challenger_ata", mut, optional, desc="ATA of the challenger receiving the refunded tokens")]
+    #[account(7, name = "escrow_ata", mut, optional, desc="ATA of the challenge PDA holding the admit tokens")]
+    #[account(8, name = "token_program", optional, desc="SPL Token Program")]
+    #[account(9, name = "associated_token_program", optional, desc="SPL Associated Token Program")]
+    RefundUnusedTries {
+        id: String,
+    },
+
+    /// Prefixes an account created before discriminators were introduced with the
+    /// discriminator of its [AccountKind], growing it by [crate::state::DISCRIMINATOR_LEN]
+    /// bytes. The account state needs to be stored at its PDA which ensures that the provided
+    /// kind is correct. Anyone can migrate an account as long as they pay for the extra rent.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction and the extra rent")]
+    #[account(1, name = "account", mut, desc="the account to migrate")]
+    #[account(2, name = "system_program", desc="System Program")]
+    MigrateAccount {
+        kind: AccountKind,
+    },
+
+    /// Adds a co-admin who may add solutions and pause or resume the challenge, but cannot
+    /// transfer nor close it. A challenge has at most [crate::state::MAX_CHALLENGE_ADMINS].
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction and the extra rent")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    AddAdmin {
+        id: String,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        admin: Pubkey,
+    },
+
+    /// Removes a co-admin added via [ChallengeInstruction::AddAdmin].
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="receives the refunded rent")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    RemoveAdmin {
+        id: String,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        admin: Pubkey,
+    },
+
+    /// Sets whether solutions need to be redeemed in order or can be redeemed in any order.
+    /// Only allowed before the challenge starts.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetSolveMode {
+        id: String,
+        solve_mode: SolveMode,
+    },
+
+    /// Sets the hints of the solutions and the fee challengers pay to reveal one of them,
+    /// resizing the account as needed.
+    /// Passing no hints removes all hints of the challenge.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for or receives the rent difference")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    SetHints {
+        id: String,
+        /// Either empty or one hint per solution, see `Challenge::hints`
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::vec_hint"))]
+        hints: Vec<[u8; 64]>,
+        /// Lamports a challenger pays to reveal a hint
+        hint_fee: u64,
+    },
+
+    /// Reveals the hint of the solution at `solution_index` to the challenger in exchange
+    /// for the hint fee of the challenge, recording it as accessible for them.
+    /// The fee is paid into the prize pool if the challenge has one, otherwise to the
+    /// authority.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction, the hint fee and the revealed hints account")]
+    #[account(1, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(2, name = "challenger", sig, desc="challenger revealing the hint")]
+    #[account(3, name = "challenger_pda", desc="PDA for the challenger")]
+    #[account(4, name = "revealed_hints_pda", mut, desc="PDA recording the hints revealed to the challenger")]
+    #[account(5, name = "authority", mut, desc="challenge authority, receives the hint fee unless the challenge has a prize pool")]
+    #[account(6, name = "prize_pool", mut, desc="prize pool of the challenge, receives the hint fee if the challenge has one")]
+    #[account(7, name = "system_program", desc="System Program")]
+    RevealHint {
+        /// Index of the solution whose hint is revealed
+        solution_index: u8,
+    },
+
+    /// Sets the number of slots a challenger needs to wait after an attempt to redeem
+    /// before attempting again.
+    /// Passing `0` removes the cooldown.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetAttemptCooldown {
+        id: String,
+        attempt_cooldown_slots: u64,
+    },
+
+    /// Allows up to `max_winners_per_solution` distinct challengers to solve each solution,
+    /// creating the leaderboard recording them.
+    /// Each winner receives an equal split of the prize of the solution and it only counts
+    /// as solved once all its winners solved it.
+    /// Only allowed before the challenge starts.
+    /// Once created, redeeming requires the leaderboard to be passed last, after the first
+    /// solvers leaderboard if the challenge has one.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "winners", mut, desc="PDA for the winners leaderboard")]
+    #[account(4, name = "system_program", desc="System Program")]
+    CreateWinners {
+        id: String,
+        max_winners_per_solution: u8,
+    },
+
+    /// Sets the rules clients apply to solutions before hashing them.
+    /// Only allowed before the challenge starts since the solutions added so far need to be
+    /// hashed with the same rules.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetSolutionNormalization {
+        id: String,
+        solution_normalization: SolutionNormalization,
+    },
+
+    /// Checks whether the solution solves the challenge and returns the outcome via return
+    /// data, see [crate::return_data::CheckSolutionResult], without modifying any state or
+    /// consuming a try.
+    /// Meant to be used via `simulateTransaction` to validate the input before redeeming.
+    #[rustfmt::skip]
+    #[account(0, name = "challenge_pda", desc="PDA for the challenge")]
+    CheckSolution {
+        /// The solution as the challenger sends it, see
+        /// [crate::utils::hash_solution_challenger_sends]
+        solution: [u8; 32],
+    },
+
+    /// Sets whether solutions matching one of the most common answers are rejected when
+    /// they are added, see [crate::utils::weak_solutions].
+    /// When enabled the solutions already added are verified as well.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetRejectWeakSolutions {
+        id: String,
+        reject_weak_solutions: bool,
+    },
+
+    /// Enables or disables admitting challengers with tickets, see
+    /// [crate::state::Challenge::ticket_admission].
+    /// Can only be changed before the challenge starts, since challengers admitted without a
+    /// ticket could not redeem otherwise.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetTicketAdmission {
+        id: String,
+        enabled: bool,
+    },
+
+    /// Creates or replaces the metadata describing the challenge, see
+    /// [crate::state::ChallengeMetadata].
+    /// The metadata account is resized to fit, the payer funds or receives the rent
+    /// difference.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction and the metadata account")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(3, name = "metadata_pda", mut, desc="PDA for the challenge metadata")]
+    #[account(4, name = "system_program", desc="System Program")]
+    SetMetadata {
+        id: String,
+        name: String,
+        description: String,
+        uri: String,
+    },
+
+    /// Replaces the flags toggling optional behaviors of the challenge, see
+    /// [crate::state::Challenge::flags].
+    /// Can only be changed before the challenge starts so that challengers can rely on the
+    /// behaviors it started with.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetChallengeFlags {
+        id: String,
+        flags: u32,
+    },
+
+    /// Same as `CreateChallenge` except that it succeeds without changes if the challenge
+    /// already exists and was created with the same parameters, so that clients can retry it
+    /// without handling the failure of the challenge account being in use.
+    /// Fails if the existing challenge differs from the provided parameters, i.e. once
+    /// solutions were added to it.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "redeem_pda", mut, desc="PDA of token to redeem for correct solution")]
+    #[account(4, name = "token_program", desc="Token Program")]
+    #[account(5, name = "system_program", desc="System Program")]
+    #[account(6, name = "creator_registry", mut, optional, desc="PDA listing the challenges of the creator, see [crate::state::CreatorRegistry]")]
+    CreateChallengeIdempotent {
+        id: String,
+        admit_cost: u64,
+        tries_per_admit: u8,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        redeem: Pubkey,
+        solutions: Vec<[u8; 32]>,
+        salt: Option<[u8; 32]>,
+        hash_algorithm: HashAlgorithm,
+        starts_at: Option<i64>,
+        ends_at: Option<i64>,
+    },
+
+    /// Same as `CreateChallenge` except that the challenge PDA is additionally derived from
+    /// the user chosen [seed], i.e. to obtain a vanity address.
+    /// The [seed] is stored with the challenge so that its PDA can be re-derived, see
+    /// [crate::state::Challenge::pda_with_seed].
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge derived with the seed")]
+    #[account(3, name = "redeem_pda", mut, desc="PDA of token to redeem for correct solution")]
+    #[account(4, name = "token_program", desc="Token Program")]
+    #[account(5, name = "system_program", desc="System Program")]
+    #[account(6, name = "creator_registry", mut, optional, desc="PDA listing the challenges of the creator, see [crate::state::CreatorRegistry]")]
+    CreateSeededChallenge {
+        id: String,
+        seed: [u8; 32],
+        admit_cost: u64,
+        tries_per_admit: u8,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        redeem: Pubkey,
+        solutions: Vec<[u8; 32]>,
+        salt: Option<[u8; 32]>,
+        hash_algorithm: HashAlgorithm,
+        starts_at: Option<i64>,
+        ends_at: Option<i64>,
+    },
+
+    /// Adds [count] tries to those a challenger has left, charging the admit cost pro-rated
+    /// by the tries granted per admission, see [crate::state::Challenge::tries_cost].
+    /// If the challenge has an admit mint the cost is paid in its tokens.
+    /// Fails if the challenger would be granted more tries in total than the challenge's
+    /// max_tries_per_challenger.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction and the tries")]
+    #[account(1, name = "creator", mut, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(3, name = "challenger", desc="challenger account which receives the tries")]
+    #[account(4, name = "challenger_pda", mut, desc="PDA for the challenger")]
+    #[account(5, name = "system_program", desc="System Program")]
+    #[account(6, name = "prize_pool", optional, mut, desc="prize pool of the challenge, receives the cost if the challenge has one")]
+    #[account(7, name = "admit_mint", optional, desc="admit mint of the challenge, required if it has one")]
+    #[account(8, name = "payer_ata", optional, mut, desc="ATA of the payer for the admit mint which pays the cost")]
+    #[account(9, name = "escrow_ata", optional, mut, desc="ATA of the challenge PDA for the admit mint which receives the cost")]
+    #[account(10, name = "token_program", optional, desc="Token Program")]
+    #[account(11, name = "associated_token_program", optional, desc="Associated Token Program")]
+    #[account(12, name = "config", desc="PDA for the program config, follows the prize pool or the admit mint accounts if the challenge has one")]
+    #[account(13, name = "fee_destination", optional, mut, desc="receives the protocol fee, required once the config is initialized")]
+    #[account(14, name = "fee_destination_ata", optional, mut, desc="ATA of the fee destination for the admit mint, required once the config is initialized if the challenge has an admit mint")]
+    BuyMoreTries {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+        challenge_pda: Pubkey,
+        count: u8,
+    },
+
+    /// Limits how many tries a challenger is granted in total via admissions and bought
+    /// tries, `None` removes the limit.
+    /// Tries that were already granted are not affected by a lower limit.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for or receives the rent difference")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    SetMaxTriesPerChallenger {
+        id: String,
+        max_tries: Option<u16>,
+    },
+
+    /// Moves the lamports the challenge PDA holds beyond its rent exemption to the treasury,
+    /// i.e. left over from over-funding or after the challenge shrank.
+    /// Lamports contributed by co-sponsors and the prize pool are never swept.
+    /// Fails if there is no surplus.
+    #[rustfmt::skip]
+    #[account(0, name = "creator", sig, desc="challenge authority")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(2, name = "treasury", mut, desc="receives the surplus lamports")]
+    SweepSurplus {
+        id: String,
+    },
+
+    /// Requires challengers to have redeemed a solution of the challenge with the
+    /// [prerequisite] PDA before they are admitted, `None` admits challengers regardless.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for or receives the rent difference")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    SetPrerequisite {
+        id: String,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serde_utils::option_pubkey")
+        )]
+        prerequisite: Option<Pubkey>,
+    },
+
+    /// Splits the challenge into epochs of [epoch_slots] counted from the slot it starts
+    /// such that the same account hosts a recurring puzzle.
+    /// Once an epoch rolls over solving starts over, or with [solutions_per_epoch] each
+    /// epoch advances to the next block of that many solutions.
+    /// An [epoch_slots] of `0` removes the epochs. Fails once the challenge started.
+    #[rustfmt::skip]
+    #[account(0, name = "creator", sig, desc="challenge authority")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    SetEpochs {
+        id: String,
+        epoch_slots: u64,
+        solutions_per_epoch: u8,
+    },
+    // TODO(thlorenz): may need some ixs for creators that want to mutate solutions, i.e.
+    //  - add solutions at index (replacing existing ones)
+    //  - replace solution at index
+    //  - clear solutions
+}
+
+/// Max size of instruction data, an instruction can never carry more data than fits into
+/// a single transaction packet.
+pub const MAX_INSTRUCTION_DATA_LEN: usize = 1232;
+
+/// Max number of solutions an instruction may carry since solutions are indexed by `u8`.
+pub const MAX_INSTRUCTION_SOLUTIONS: u32 = u8::MAX as u32;
+
+// Tags of the instructions that carry solutions, i.e. their index in [ChallengeInstruction]
+const CREATE_CHALLENGE_TAG: u8 = 0;
+const ADD_SOLUTIONS_TAG: u8 = 1;
+const CREATE_CHALLENGE_FROM_TEMPLATE_TAG: u8 = 13;
+const REPLACE_SOLUTIONS_TAG: u8 = 23;
+const APPEND_SOLUTIONS_CHUNK_TAG: u8 = 40;
+const CREATE_CHALLENGE_IDEMPOTENT_TAG: u8 = 59;
+const CREATE_SEEDED_CHALLENGE_TAG: u8 = 60;
+
+impl ChallengeInstruction {
+    /// Decodes the instruction from the provided [data], rejecting data that is empty,
+    /// exceeds [MAX_INSTRUCTION_DATA_LEN] or has bytes left over once the instruction
+    /// was decoded.
+    pub fn try_from_slice_checked(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            msg!("Err: instruction data is empty");
+            return Err(ChallengeError::InstructionDataEmpty.into());
+        }
+        if data.len() > MAX_INSTRUCTION_DATA_LEN {
+            msg!(
+                "Err: instruction data has {} bytes which exceeds max of {}",
+                data.len(),
+                MAX_INSTRUCTION_DATA_LEN
+            );
+            return Err(ChallengeError::InstructionDataTooLarge.into());
+        }
+        // borsh allocates vectors from their length prefix, so check it first
+        if let Ok(Some(len)) = Self::solutions_len(data) {
+            if len > MAX_INSTRUCTION_SOLUTIONS {
+                msg!(
+                    "Err: instruction with tag {} carries {} solutions which exceeds max of {}",
+                    data[0],
+                    len,
+                    MAX_INSTRUCTION_SOLUTIONS
+                );
+                return Err(
+                    ChallengeError::ExceedingMaxSupportedSolutions.into()
+                );
+            }
+        }
+
+        let mut remaining = data;
+        let instruction = Self::deserialize(&mut remaining).map_err(|err| {
+            msg!(
+                "Err: failed to decode instruction with tag {}: {}",
+                data[0],
+                err
+            );
+            ChallengeError::MalformedInstructionData
+        })?;
+        if !remaining.is_empty() {
+            msg!(
+                "Err: {} instruction data has {} trailing bytes",
+                instruction,
+                remaining.len()
+            );
+            return Err(ChallengeError::InstructionDataHasTrailingBytes.into());
+        }
+        Ok(instruction)
+    }
+
+    /// Reads the length prefix of the solutions carried by the instruction [data] without
+    /// deserializing them, `None` if the instruction carries no solutions.
+    /// Only the fields preceding the solutions are read, data that is malformed otherwise
+    /// is rejected when the instruction is decoded.
+    fn solutions_len(data: &[u8]) -> std::io::Result<Option<u32>> {
+        let (tag, fields) = match data.split_first() {
+            Some(split) => split,
+            None => return Ok(None),
+        };
+        let buf = &mut &fields[..];
+        match *tag {
+            CREATE_CHALLENGE_TAG | CREATE_CHALLENGE_IDEMPOTENT_TAG => {
+                String::deserialize(buf)?; // id
+                u64::deserialize(buf)?; // admit_cost
+                u8::deserialize(buf)?; // tries_per_admit
+                Pubkey::deserialize(buf)?; // redeem
+            }
+            ADD_SOLUTIONS_TAG | REPLACE_SOLUTIONS_TAG => {
+                String::deserialize(buf)?; // id
+            }
+            CREATE_CHALLENGE_FROM_TEMPLATE_TAG => {
+                u8::deserialize(buf)?; // template_id
+                String::deserialize(buf)?; // id
+                Option::<u64>::deserialize(buf)?; // admit_cost
+                Option::<u8>::deserialize(buf)?; // tries_per_admit
+                Pubkey::deserialize(buf)?; // redeem
+            }
+            APPEND_SOLUTIONS_CHUNK_TAG => {
+                String::deserialize(buf)?; // id
+                u8::deserialize(buf)?; // chunk_index
+                u8::deserialize(buf)?; // total_chunks
+            }
+            CREATE_SEEDED_CHALLENGE_TAG => {
+                String::deserialize(buf)?; // id
+                <[u8; 32]>::deserialize(buf)?; // seed
+                u64::deserialize(buf)?; // admit_cost
+                u8::deserialize(buf)?; // tries_per_admit
+                Pubkey::deserialize(buf)?; // redeem
+            }
+            _ => return Ok(None),
+        }
+        u32::deserialize(buf).map(Some)
+    }
+
+    /// The name of the instruction as it appears in the IDL.
+    pub fn name(&self) -> &'static str {
+        use ChallengeInstruction::*;
+        match self {
+            CreateChallenge { .. } => "CreateChallenge",
+            AddSolutions { .. } => "AddSolutions",
+            StartChallenge { .. } => "StartChallenge",
+            AdmitChallenger { .. } => "AdmitChallenger",
+            Redeem { .. } => "Redeem",
+            SetSolveWindow { .. } => "SetSolveWindow",
+            GetChallengeState => "GetChallengeState",
+            SplitChallenge { .. } => "SplitChallenge",
+            TruncateSolutions { .. } => "TruncateSolutions",
+            MergeChallenges { .. } => "MergeChallenges",
+            LockAdmitCost { .. } => "LockAdmitCost",
+            CreateTemplate { .. } => "CreateTemplate",
+            CreateChallengeFromTemplate { .. } => "CreateChallengeFromTemplate",
+            SetOracle { .. } => "SetOracle",
+            VerifySolveSignature { .. } => "VerifySolveSignature",
+            UpdateSolutionHash { .. } => "UpdateSolutionHash",
+            SetSolutionsLocked { .. } => "SetSolutionsLocked",
+            ReissueAdmission { .. } => "ReissueAdmission",
+            AddCoSponsor { .. } => "AddCoSponsor",
+            CreateLeaderboard { .. } => "CreateLeaderboard",
+            UpdateLeaderboard => "UpdateLeaderboard",
+            RemoveSolutions { .. } => "RemoveSolutions",
+            ReplaceSolutions { .. } => "ReplaceSolutions",
+            CloseChallenge { .. } => "CloseChallenge",
+            TransferAuthority { .. } => "TransferAuthority",
+            AcceptAuthority { .. } => "AcceptAuthority",
+            SetPaused { .. } => "SetPaused",
+            UpdateChallenge { .. } => "UpdateChallenge",
+            SetAdmitMint { .. } => "SetAdmitMint",
+            WithdrawAdmitTokens { .. } => "WithdrawAdmitTokens",
+            CreatePrizePool { .. } => "CreatePrizePool",
+            WithdrawPrizePool { .. } => "WithdrawPrizePool",
+            SetNftReward { .. } => "SetNftReward",
+            CommitSolution { .. } => "CommitSolution",
+            RevealSolution { .. } => "RevealSolution",
+            SetCommitReveal { .. } => "SetCommitReveal",
+            SetAllowlist { .. } => "SetAllowlist",
+            SetMaxAdmissions { .. } => "SetMaxAdmissions",
+            CreateFirstSolvers { .. } => "CreateFirstSolvers",
+            AppendSolutionsChunk { .. } => "AppendSolutionsChunk",
+            InitConfig { .. } => "InitConfig",
+            UpdateConfig { .. } => "UpdateConfig",
+            CancelChallenge { .. } => "CancelChallenge",
+            RefundUnusedTries { .. } => "RefundUnusedTries",
+            MigrateAccount { .. } => "MigrateAccount",
+            AddAdmin { .. } => "AddAdmin",
+            RemoveAdmin { .. } => "RemoveAdmin",
+            SetSolveMode { .. } => "SetSolveMode",
+            SetHints { .. } => "SetHints",
+            RevealHint { .. } => "RevealHint",
+            SetAttemptCooldown { .. } => "SetAttemptCooldown",
+            CreateWinners { .. } => "CreateWinners",
+            SetSolutionNormalization { .. } => "SetSolutionNormalization",
+            CheckSolution { .. } => "CheckSolution",
+            SetRejectWeakSolutions { .. } => "SetRejectWeakSolutions",
+            SetTicketAdmission { .. } => "SetTicketAdmission",
+            SetMetadata { .. } => "SetMetadata",
+            SetChallengeFlags { .. } => "SetChallengeFlags",
+            CreateChallengeIdempotent { .. } => "CreateChallengeIdempotent",
+            CreateSeededChallenge { .. } => "CreateSeededChallenge",
+            BuyMoreTries { .. } => "BuyMoreTries",
+            SetMaxTriesPerChallenger { .. } => "SetMaxTriesPerChallenger",
+            SweepSurplus { .. } => "SweepSurplus",
+            SetPrerequisite { .. } => "SetPrerequisite",
+            SetEpochs { .. } => "SetEpochs",
+        }
+    }
+
+    /// The accounts the instruction expects in the order it expects them, see
+    /// [crate::accounts].
+    pub fn account_specs(&self) -> &'static [AccountSpec] {
+        use ChallengeInstruction::*;
+        match self {
+            CreateChallenge { .. } => accounts::create_challenge::ACCOUNTS,
+            AddSolutions { .. } => accounts::add_solutions::ACCOUNTS,
+            StartChallenge { .. } => accounts::start_challenge::ACCOUNTS,
+            AdmitChallenger { .. } => accounts::admit_challenger::ACCOUNTS,
+            Redeem { .. } => accounts::redeem::ACCOUNTS,
+            SetSolveWindow { .. } => accounts::set_solve_window::ACCOUNTS,
+            GetChallengeState => accounts::get_challenge_state::ACCOUNTS,
+            SplitChallenge { .. } => accounts::split_challenge::ACCOUNTS,
+            TruncateSolutions { .. } => accounts::truncate_solutions::ACCOUNTS,
+            MergeChallenges { .. } => accounts::merge_challenges::ACCOUNTS,
+            LockAdmitCost { .. } => accounts::lock_admit_cost::ACCOUNTS,
+            CreateTemplate { .. } => accounts::create_template::ACCOUNTS,
+            CreateChallengeFromTemplate { .. } => {
+                accounts::create_challenge_from_template::ACCOUNTS
+            }
+            SetOracle { .. } => accounts::set_oracle::ACCOUNTS,
+            VerifySolveSignature { .. } => {
+                accounts::verify_solve_signature::ACCOUNTS
+            }
+            UpdateSolutionHash { .. } => {
+                accounts::update_solution_hash::ACCOUNTS
+            }
+            SetSolutionsLocked { .. } => {
+                accounts::set_solutions_locked::ACCOUNTS
+            }
+            ReissueAdmission { .. } => accounts::reissue_admission::ACCOUNTS,
+            AddCoSponsor { .. } => accounts::add_co_sponsor::ACCOUNTS,
+            CreateLeaderboard { .. } => accounts::create_leaderboard::ACCOUNTS,
+            UpdateLeaderboard => accounts::update_leaderboard::ACCOUNTS,
+            RemoveSolutions { .. } => accounts::remove_solutions::ACCOUNTS,
+            ReplaceSolutions { .. } => accounts::replace_solutions::ACCOUNTS,
+            CloseChallenge { .. } => accounts::close_challenge::ACCOUNTS,
+            TransferAuthority { .. } => accounts::transfer_authority::ACCOUNTS,
+            AcceptAuthority { .. } => accounts::accept_authority::ACCOUNTS,
+            SetPaused { .. } => accounts::set_paused::ACCOUNTS,
+            UpdateChallenge { .. } => accounts::update_challenge::ACCOUNTS,
+            SetAdmitMint { .. } => accounts::set_admit_mint::ACCOUNTS,
+            WithdrawAdmitTokens { .. } => {
+                accounts::withdraw_admit_tokens::ACCOUNTS
+            }
+            CreatePrizePool { .. } => accounts::create_prize_pool::ACCOUNTS,
+            WithdrawPrizePool { .. } => accounts::withdraw_prize_pool::ACCOUNTS,
+            SetNftReward { .. } => accounts::set_nft_reward::ACCOUNTS,
+            CommitSolution { .. } => accounts::commit_solution::ACCOUNTS,
+            RevealSolution { .. } => accounts::reveal_solution::ACCOUNTS,
+            SetCommitReveal { .. } => accounts::set_commit_reveal::ACCOUNTS,
+            SetAllowlist { .. } => accounts::set_allowlist::ACCOUNTS,
+            SetMaxAdmissions { .. } => accounts::set_max_admissions::ACCOUNTS,
+            CreateFirstSolvers { .. } => {
+                accounts::create_first_solvers::ACCOUNTS
+            }
+            AppendSolutionsChunk { .. } => {
+                accounts::append_solutions_chunk::ACCOUNTS
+            }
+            InitConfig { .. } => accounts::init_config::ACCOUNTS,
+            UpdateConfig { .. } => accounts::update_config::ACCOUNTS,
+            CancelChallenge { .. } => accounts::cancel_challenge::ACCOUNTS,
+            RefundUnusedTries { .. } => accounts::refund_unused_tries::ACCOUNTS,
+            MigrateAccount { .. } => accounts::migrate_account::ACCOUNTS,
+            AddAdmin { .. } => accounts::add_admin::ACCOUNTS,
+            RemoveAdmin { .. } => accounts::remove_admin::ACCOUNTS,
+            SetSolveMode { .. } => accounts::set_solve_mode::ACCOUNTS,
+            SetHints { .. } => accounts::set_hints::ACCOUNTS,
+            RevealHint { .. } => accounts::reveal_hint::ACCOUNTS,
+            SetAttemptCooldown { .. } => {
+                accounts::set_attempt_cooldown::ACCOUNTS
+            }
+            CreateWinners { .. } => accounts::create_winners::ACCOUNTS,
+            SetSolutionNormalization { .. } => {
+                accounts::set_solution_normalization::ACCOUNTS
+            }
+            CheckSolution { .. } => accounts::check_solution::ACCOUNTS,
+            SetRejectWeakSolutions { .. } => {
+                accounts::set_reject_weak_solutions::ACCOUNTS
+            }
+            SetTicketAdmission { .. } => {
+                accounts::set_ticket_admission::ACCOUNTS
+            }
+            SetMetadata { .. } => accounts::set_metadata::ACCOUNTS,
+            SetChallengeFlags { .. } => accounts::set_challenge_flags::ACCOUNTS,
+            CreateChallengeIdempotent { .. } => {
+                accounts::create_challenge_idempotent::ACCOUNTS
+            }
+            CreateSeededChallenge { .. } => {
+                accounts::create_seeded_challenge::ACCOUNTS
+            }
+            BuyMoreTries { .. } => accounts::buy_more_tries::ACCOUNTS,
+            SetMaxTriesPerChallenger { .. } => {
+                accounts::set_max_tries_per_challenger::ACCOUNTS
+            }
+            SweepSurplus { .. } => accounts::sweep_surplus::ACCOUNTS,
+            SetPrerequisite { .. } => accounts::set_prerequisite::ACCOUNTS,
+            SetEpochs { .. } => accounts::set_epochs::ACCOUNTS,
+        }
+    }
+}
+
+impl fmt::Display for ChallengeInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// -----------------
+// Challenge Authority
+// -----------------
+
+/// The authority managing a challenge together with the creator whose key seeds the PDA of
+/// the challenge.
+/// Both are the same until the challenge is handed over via [transfer_authority], which is
+/// why a [Pubkey] converts into a [ChallengeAuthority] that is managed by its creator.
+/// Builders of instructions for an existing challenge accept either, use
+/// [ChallengeAuthority::transferred] once the authority changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChallengeAuthority {
+    /// The current authority of the challenge, see [Challenge::authority].
+    pub authority: Pubkey,
+    /// The authority that created the challenge, see [Challenge::creator].
+    pub creator: Pubkey,
+}
+
+impl ChallengeAuthority {
+    /// The [authority] the challenge created by [creator] was transferred to.
+    pub fn transferred(authority: Pubkey, creator: Pubkey) -> Self {
+        Self { authority, creator }
+    }
+}
+
+impl From<Pubkey> for ChallengeAuthority {
+    fn from(creator: Pubkey) -> Self {
+        Self {
+            authority: creator,
+            creator,
+        }
+    }
+}
+
+// -----------------
+// Create Challenge
+// -----------------
+
+/// Creates a new challenge and is invoked only once by the creator of the challenge.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id identifying the challenge. The same creator cannot reuse ids for different challenges
+/// * [admit_cost]: the amount of SOL that must be paid to admit a challenger
+/// * [tries_per_admit]: the number of tries that a challenger gets for the given admit_cost
+/// * [solutions]: solutions to be solved in clear text, they are encoded via
+///   `sha256(sha256(solution))` before being passed on to the program.
+///   The challenge account is allocated at the exact size needed for them, thus no separate
+///   [add_solutions] is needed unless they don't fit into one transaction, see
+///   [append_solutions_in_chunks]
+/// * [starts_at]: unix timestamp at which the challenge starts admitting challengers and
+///   accepting solutions, no restriction if not provided
+/// * [ends_at]: unix timestamp after which the challenge stops admitting challengers and
+///   accepting solutions, no restriction if not provided
+#[allow(clippy::too_many_arguments)]
+pub fn create_challenge(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<&str>,
+    starts_at: Option<i64>,
+    ends_at: Option<i64>,
+) -> Result<Instruction, ProgramError> {
+    create_salted_challenge(
+        payer,
+        creator,
+        id,
+        admit_cost,
+        tries_per_admit,
+        solutions,
+        starts_at,
+        ends_at,
+        None,
+        HashAlgorithm::Sha256,
+    )
+}
+
+/// Same as [create_challenge] except that the solutions are hashed with the [salt] and
+/// [hash_algorithm] which are stored with the challenge.
+///
+/// * [solutions]: solutions to be solved in clear text, they are encoded via
+///   `H(salt || H(solution))` before being passed on to the program
+/// * [salt]: per challenge salt, unsalted hashes are used if not provided
+/// * [hash_algorithm]: the algorithm `H` used to hash the solutions
+#[allow(clippy::too_many_arguments)]
+pub fn create_salted_challenge(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<&str>,
+    starts_at: Option<i64>,
+    ends_at: Option<i64>,
+    salt: Option<[u8; 32]>,
+    hash_algorithm: HashAlgorithm,
+) -> Result<Instruction, ProgramError> {
+    create_challenge_with(
+        payer,
+        creator,
+        id,
+        admit_cost,
+        tries_per_admit,
+        solutions,
+        starts_at,
+        ends_at,
+        salt,
+        hash_algorithm,
+        CreateChallengeKind::Plain,
+    )
+}
+
+/// The instruction [create_challenge_with] builds.
+enum CreateChallengeKind {
+    Plain,
+    Idempotent,
+    Seeded([u8; 32]),
+}
+
+#[allow(clippy::too_many_arguments)]
+fn create_challenge_with(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<&str>,
+    starts_at: Option<i64>,
+    ends_at: Option<i64>,
+    salt: Option<[u8; 32]>,
+    hash_algorithm: HashAlgorithm,
+    kind: CreateChallengeKind,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) = match &kind {
+        CreateChallengeKind::Seeded(seed) => {
+            Challenge::pda_with_seed(&creator, &id, seed)
+        }
+        _ => Challenge::shank_pda(&challenge_id(), &creator, &id),
+    };
+
+    let redeem = Redeem::new(challenge_pda);
+    let (redeem_pda, _) = redeem.pda();
+
+    let solutions = hash_solutions_with_algorithm(
+        &solutions,
+        salt.as_ref(),
+        hash_algorithm,
+    );
+
+    let instruction = match kind {
+        CreateChallengeKind::Plain => ChallengeInstruction::CreateChallenge {
+            id,
+            admit_cost,
+            tries_per_admit,
+            redeem: redeem_pda,
+            solutions,
+            salt,
+            hash_algorithm,
+            starts_at,
+            ends_at,
+        },
+        CreateChallengeKind::Idempotent => {
+            ChallengeInstruction::CreateChallengeIdempotent {
+                id,
+                admit_cost,
+                tries_per_admit,
+                redeem: redeem_pda,
+                solutions,
+                salt,
+                hash_algorithm,
+                starts_at,
+                ends_at,
+            }
+        }
+        CreateChallengeKind::Seeded(seed) => {
+            ChallengeInstruction::CreateSeededChallenge {
+                id,
+                seed,
+                admit_cost,
+                tries_per_admit,
+                redeem: redeem_pda,
+                solutions,
+                salt,
+                hash_algorithm,
+                starts_at,
+                ends_at,
+            }
+        }
+    };
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(creator, false),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(redeem_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: instruction.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Account listing the challenges of the [creator], see [crate::state::CreatorRegistry].
+/// It can be appended last to the `CreateChallenge`, `CreateChallengeIdempotent`,
+/// `CreateSeededChallenge`, `CreateChallengeFromTemplate`,
+/// `SplitChallenge`, `MergeChallenges` and `CloseChallenge` instructions to keep the
+/// registry up to date.
+pub fn creator_registry_accounts(creator: &Pubkey) -> Vec<AccountMeta> {
+    vec![AccountMeta::new(CreatorRegistry::pda_for(creator).0, false)]
+}
+
+// -----------------
+// Add Solutions
+// -----------------
+
+/// Adds solutions to an existing challenge and can be invoked multiple times.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [solutions]: solutions to be added in clear text, they are encoded via
+///   `sha256(sha256(solution))` before being stored
+/// * [index]: the index at which to insert the solutions
+///   if provided solutions starting at that index are replaced, otherwise they are appended
+pub fn add_solutions(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    solutions: Vec<&str>,
+) -> Result<Instruction, ProgramError> {
+    add_salted_solutions(
+        payer,
+        creator,
+        id,
+        solutions,
+        None,
+        HashAlgorithm::Sha256,
+    )
+}
+
+/// Same as [add_solutions] for a challenge created with a [salt] and [hash_algorithm] which
+/// need to match the ones stored with the challenge.
+pub fn add_salted_solutions(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    solutions: Vec<&str>,
+    salt: Option<[u8; 32]>,
+    hash_algorithm: HashAlgorithm,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let solutions = hash_solutions_with_algorithm(
+        &solutions,
+        salt.as_ref(),
+        hash_algorithm,
+    );
+    add_hashed_solutions(
+        payer,
+        authority,
+        creator,
+        id,
+        solutions,
+        false,
+        vec![],
+    )
+}
+
+/// Same as [add_solutions] except that each solution is rewarded individually with the
+/// amount at the same index of [rewards] which is paid out of the prize pool on redeem.
+///
+/// * [rewards]: one reward in lamports per solution
+pub fn add_solutions_with_rewards(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    solutions: Vec<&str>,
+    rewards: Vec<u64>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let solutions =
+        hash_solutions_with_algorithm(&solutions, None, HashAlgorithm::Sha256);
+    add_hashed_solutions(
+        payer, authority, creator, id, solutions, false, rewards,
+    )
+}
+
+/// Same as [add_solutions] except that solutions which are already part of the challenge or
+/// are included more than once are added anyways.
+pub fn add_solutions_allowing_duplicates(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    solutions: Vec<&str>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let solutions =
+        hash_solutions_with_algorithm(&solutions, None, HashAlgorithm::Sha256);
+    add_hashed_solutions(payer, authority, creator, id, solutions, true, vec![])
+}
+
+/// Same as [add_solutions] when signed by one of the admins of the challenge instead of its
+/// authority.
+///
+/// * [admin]: the admin of the challenge signing the instruction
+/// * [creator]: the authority that created the challenge, used to derive its PDA
+pub fn add_solutions_as_admin(
+    payer: Pubkey,
+    admin: Pubkey,
+    creator: Pubkey,
+    id: String,
+    solutions: Vec<&str>,
+) -> Result<Instruction, ProgramError> {
+    let solutions =
+        hash_solutions_with_algorithm(&solutions, None, HashAlgorithm::Sha256);
+    add_hashed_solutions(payer, admin, creator, id, solutions, false, vec![])
+}
+
+fn add_hashed_solutions(
+    payer: Pubkey,
+    signer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    solutions: Vec<Solution>,
+    allow_duplicates: bool,
+    rewards: Vec<u64>,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(signer, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::AddSolutions {
+            id,
+            solutions,
+            allow_duplicates,
+            rewards,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Start Challenge
+// -----------------
+pub fn start_challenge(
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::StartChallenge { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Admit Challenger
+// -----------------
+pub struct AdmitChallengerIx {
+    pub challenge_pda: Pubkey,
+    pub challenger_pda: Pubkey,
+    pub ix: Instruction,
+}
+pub fn admit_challenger(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+) -> Result<AdmitChallengerIx, ProgramError> {
+    admit_challenger_paying_with(payer, creator, id, challenger, None, vec![])
+}
+
+/// Admits the [challenger] to a challenge that has an allowlist.
+///
+/// * [payer]: pays for the transaction and the admit cost
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger to admit
+/// * [admit_mint]: the admit mint of the challenge if it has one
+/// * [allowlist_proof]: proof that the [challenger] is on the allowlist, see
+///   [crate::utils::allowlist_proof]
+pub fn admit_allowlisted_challenger(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    admit_mint: Option<Pubkey>,
+    allowlist_proof: Vec<[u8; 32]>,
+) -> Result<AdmitChallengerIx, ProgramError> {
+    admit_challenger_paying_with(
+        payer,
+        creator,
+        id,
+        challenger,
+        admit_mint,
+        allowlist_proof,
+    )
+}
+
+/// Admits the [challenger] to a challenge whose admit cost is paid in tokens of [admit_mint].
+///
+/// * [payer]: pays for the transaction and the admit cost from its ATA for [admit_mint]
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger to admit
+/// * [admit_mint]: the admit mint of the challenge
+pub fn admit_challenger_with_admit_mint(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    admit_mint: Pubkey,
+) -> Result<AdmitChallengerIx, ProgramError> {
+    admit_challenger_paying_with(
+        payer,
+        creator,
+        id,
+        challenger,
+        Some(admit_mint),
+        vec![],
+    )
+}
+
+/// Accounts needed to pay the admit cost in tokens of the [admit_mint] of a challenge.
+fn admit_mint_accounts(
+    payer: &Pubkey,
+    challenge_pda: &Pubkey,
+    admit_mint: &Pubkey,
+) -> Vec<AccountMeta> {
+    let payer_ata = get_associated_token_address_with_program_id(
+        payer,
+        admit_mint,
+        &spl_token::id(),
+    );
+    let escrow_ata = get_associated_token_address_with_program_id(
+        challenge_pda,
+        admit_mint,
+        &spl_token::id(),
+    );
+    vec![
+        AccountMeta::new_readonly(*admit_mint, false),
+        AccountMeta::new(payer_ata, false),
+        AccountMeta::new(escrow_ata, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+    ]
+}
+
+/// Accounts receiving the protocol fee which need to be appended to the `AdmitChallenger`
+/// and `ReissueAdmission` instructions once the program config is initialized.
+///
+/// * [fee_destination]: the fee destination stored in the program config
+/// * [admit_mint]: admit mint of the challenge if it has one
+pub fn protocol_fee_accounts(
+    fee_destination: &Pubkey,
+    admit_mint: Option<&Pubkey>,
+) -> Vec<AccountMeta> {
+    let mut accounts = vec![AccountMeta::new(*fee_destination, false)];
+    if let Some(admit_mint) = admit_mint {
+        accounts.push(AccountMeta::new(
+            get_associated_token_address_with_program_id(
+                fee_destination,
+                admit_mint,
+                &spl_token::id(),
+            ),
+            false,
+        ));
+    }
+    accounts
+}
+
+/// Admits the [challenger] to a challenge that admits with tickets, minting the ticket of
+/// the admission to the [challenger].
+/// If the program config is initialized the [protocol_fee_accounts] need to be inserted
+/// before the [ticket_mint_accounts].
+///
+/// * [payer]: pays for the transaction, the admit cost and the ticket mint
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger to admit which receives the ticket
+/// * [admit_mint]: the admit mint of the challenge if it has one
+pub fn admit_challenger_with_ticket(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    admit_mint: Option<Pubkey>,
+) -> Result<AdmitChallengerIx, ProgramError> {
+    let mut admit = admit_challenger_paying_with(
+        payer,
+        creator,
+        id,
+        challenger,
+        admit_mint,
+        vec![],
+    )?;
+    admit
+        .ix
+        .accounts
+        .extend(ticket_mint_accounts(&admit.challenger_pda, &challenger));
+    Ok(admit)
+}
+
+/// Accounts needed to mint the ticket of the admission of the challenger with
+/// [challenger_pda] to the [challenger]. They need to be appended last to the
+/// `AdmitChallenger` instruction of challenges that admit with tickets.
+pub fn ticket_mint_accounts(
+    challenger_pda: &Pubkey,
+    challenger: &Pubkey,
+) -> Vec<AccountMeta> {
+    let ticket = Ticket::new(*challenger_pda);
+    vec![
+        AccountMeta::new(ticket.pda, false),
+        AccountMeta::new(ticket.ata(challenger), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+    ]
+}
+
+/// Accounts proving that the [challenger] completed the [prerequisite] challenge, i.e. its
+/// challenger PDA of that challenge. They need to be appended last to the `AdmitChallenger`
+/// instruction of challenges that have a prerequisite, following the [ticket_mint_accounts]
+/// if the challenge admits with tickets.
+pub fn prerequisite_accounts(
+    prerequisite: &Pubkey,
+    challenger: &Pubkey,
+) -> Vec<AccountMeta> {
+    let (prerequisite_challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), prerequisite, challenger);
+    vec![AccountMeta::new_readonly(
+        prerequisite_challenger_pda,
+        false,
+    )]
+}
+
+/// Admits the [challenger] to a challenge that requires completing the challenge with the
+/// [prerequisite] PDA first.
+/// If the program config is initialized the [protocol_fee_accounts] need to be inserted
+/// before the [prerequisite_accounts].
+///
+/// * [payer]: pays for the transaction and the admit cost
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger to admit
+/// * [prerequisite]: the PDA of the challenge the [challenger] needs to have completed
+pub fn admit_challenger_with_prerequisite(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    prerequisite: Pubkey,
+) -> Result<AdmitChallengerIx, ProgramError> {
+    let mut admit = admit_challenger_paying_with(
+        payer,
+        creator,
+        id,
+        challenger,
+        None,
+        vec![],
+    )?;
+    admit
+        .ix
+        .accounts
+        .extend(prerequisite_accounts(&prerequisite, &challenger));
+    Ok(admit)
+}
+
+fn admit_challenger_paying_with(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    admit_mint: Option<Pubkey>,
+    allowlist_proof: Vec<[u8; 32]>,
+) -> Result<AdmitChallengerIx, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+
+    let mut ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(authority, false),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(challenger, false),
+            AccountMeta::new(challenger_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(PrizePool::pda_for(&challenge_pda).0, false),
+        ],
+        data: ChallengeInstruction::AdmitChallenger {
+            challenge_pda,
+            allowlist_proof,
+        }
+        .try_to_vec()?,
+    };
+    if let Some(admit_mint) = admit_mint {
+        ix.accounts.extend(admit_mint_accounts(
+            &payer,
+            &challenge_pda,
+            &admit_mint,
+        ));
+    }
+    ix.accounts
+        .push(AccountMeta::new_readonly(Config::pda().0, false));
+
+    Ok(AdmitChallengerIx {
+        challenge_pda,
+        challenger_pda,
+        ix,
+    })
+}
+
+#[derive(Debug)]
+struct RedeemAccounts {
+    payer: AccountMeta,
+    challenge_pda: AccountMeta,
+    challenger: AccountMeta,
+    challenger_pda: AccountMeta,
+    redeem_pda: AccountMeta,
+    redeem_ata: AccountMeta,
+    spl_token_program: AccountMeta,
+    spl_associated_token_program: AccountMeta,
+    system_program: AccountMeta,
+    prize_pool: AccountMeta,
+}
+
+// -----------------
+// Redeem by providing solution
+// -----------------
+/// Attempts to redeem by providing a solution.
+///
+/// * [payer]: pays for the transaction and is usually the challenger
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the  account attempting to redeem by providing the solution
+/// * [solution]: solutions to be added in clear text, they are encoded via `sha256(solution)`
+///   before being passed to the challenge
+pub fn redeem(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    solution: &str,
+) -> Result<Instruction, ProgramError> {
+    let challenger_sends = hash_solution_challenger_sends(solution);
+    redeem_with_hash(payer, creator, id, challenger, challenger_sends)
+}
+
+/// Same as [redeem] except that the solution is provided already hashed, i.e. for
+/// challenges whose [HashAlgorithm] is not `Sha256`, see
+/// [crate::utils::hash_solution_challenger_sends_with].
+pub fn redeem_with_hash(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    challenger_sends: Solution,
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    let redeem = Redeem::new(challenge_pda);
+    let redeem_ata = redeem.ata(&challenger);
+
+    let accounts = RedeemAccounts {
+        payer: AccountMeta::new(payer, true),
+        challenge_pda: AccountMeta::new(challenge_pda, false),
+        challenger: AccountMeta::new(challenger, true),
+        challenger_pda: AccountMeta::new(challenger_pda, false),
+        redeem_pda: AccountMeta::new(redeem.pda().0, false),
+        redeem_ata: AccountMeta::new(redeem_ata, false),
+        spl_token_program: AccountMeta::new_readonly(spl_token::id(), false),
+        spl_associated_token_program: AccountMeta::new_readonly(
+            spl_associated_token_account::id(),
+            false,
+        ),
+        system_program: AccountMeta::new_readonly(system_program::id(), false),
+        prize_pool: AccountMeta::new(
+            PrizePool::pda_for(&challenge_pda).0,
+            false,
+        ),
+    };
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            accounts.payer,
+            accounts.challenge_pda,
+            // challenger
+            accounts.challenger,
+            accounts.challenger_pda,
+            // redeem
+            accounts.redeem_pda,
+            accounts.redeem_ata,
+            // programs
+            accounts.spl_token_program,
+            accounts.spl_associated_token_program,
+            accounts.system_program,
+            // prize pool
+            accounts.prize_pool,
+        ],
+        data: ChallengeInstruction::Redeem {
+            solution: challenger_sends,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Attempts to redeem by providing a solution to a challenge that rewards NFTs.
+///
+/// * [payer]: pays for the transaction and the NFT mint
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the  account attempting to redeem by providing the solution
+/// * [solution]: solution in clear text, see [redeem]
+/// * [solution_index]: index of the solution that is solved next, or the one being solved
+///   if the challenge is solved in any order, the NFT is derived from it
+pub fn redeem_with_nft(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    solution: &str,
+    solution_index: u8,
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let mut ix = redeem(payer, creator, id, challenger, solution)?;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    ix.accounts.extend(nft_reward_accounts(
+        &challenge_pda,
+        &challenger,
+        solution_index,
+    ));
+    Ok(ix)
+}
+
+/// Attempts to redeem with the tries of the admission of the [challenger] by providing a
+/// solution to a challenge that admits with tickets, where the [holder] of the ticket of that
+/// admission redeems and receives the rewards.
+/// If the challenge also rewards NFTs, has a first solvers leaderboard or multiple winners
+/// their accounts need to be inserted before the [ticket_accounts].
+///
+/// * [payer]: pays for the transaction and is usually the holder
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [holder]: the account holding the ticket and attempting to redeem
+/// * [challenger]: the challenger that was admitted with the ticket
+/// * [solution]: solution in clear text, see [redeem]
+pub fn redeem_with_ticket(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    holder: Pubkey,
+    challenger: Pubkey,
+    solution: &str,
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let mut ix = redeem(payer, creator, id, holder, solution)?;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    ix.accounts[accounts::redeem::CHALLENGER_PDA] =
+        AccountMeta::new(challenger_pda, false);
+    ix.accounts
+        .extend(ticket_accounts(&challenger_pda, &holder));
+    Ok(ix)
+}
+
+/// Account proving that the [holder] holds the ticket of the admission of the challenger
+/// with [challenger_pda]. It needs to be appended last to the `Redeem`, `RevealSolution`,
+/// `VerifySolveSignature` and `CommitSolution` instructions of challenges that admit with
+/// tickets.
+pub fn ticket_accounts(
+    challenger_pda: &Pubkey,
+    holder: &Pubkey,
+) -> Vec<AccountMeta> {
+    vec![AccountMeta::new_readonly(
+        Ticket::new(*challenger_pda).ata(holder),
+        false,
+    )]
+}
+
+/// Account needed to record the first solver when redeeming a challenge that has a first
+/// solvers leaderboard. It needs to be appended to the `Redeem`, `RevealSolution` and
+/// `VerifySolveSignature` instructions after the [nft_reward_accounts] if the challenge
+/// also rewards NFTs.
+pub fn first_solvers_accounts(challenge_pda: &Pubkey) -> Vec<AccountMeta> {
+    vec![AccountMeta::new(
+        FirstSolvers::pda_for(challenge_pda).0,
+        false,
+    )]
+}
+
+/// Account needed to record the winners of a challenge that allows multiple winners per
+/// solution. It needs to be appended last to the `Redeem`, `RevealSolution` and
+/// `VerifySolveSignature` instructions, i.e. after the [first_solvers_accounts] if the
+/// challenge also has a first solvers leaderboard.
+pub fn winners_accounts(challenge_pda: &Pubkey) -> Vec<AccountMeta> {
+    vec![AccountMeta::new(Winners::pda_for(challenge_pda).0, false)]
+}
+
+/// Accounts needed to mint the NFT rewarded for the solution at [solution_index] to the
+/// [challenger]. They need to be appended to the `Redeem` and `VerifySolveSignature`
+/// instructions of challenges that reward NFTs.
+pub fn nft_reward_accounts(
+    challenge_pda: &Pubkey,
+    challenger: &Pubkey,
+    solution_index: u8,
+) -> Vec<AccountMeta> {
+    let nft_reward = NftReward::new(*challenge_pda, solution_index);
+    vec![
+        AccountMeta::new(nft_reward.pda, false),
+        AccountMeta::new(nft_reward.ata(challenger), false),
+    ]
+}
+
+// -----------------
+// Set Solve Window
+// -----------------
+
+/// Restricts the slots during which the challenge accepts solutions.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [from_slot]: first slot at which solutions are accepted, `None` to accept them right away
+/// * [until_slot]: last slot at which solutions are accepted, `None` to accept them indefinitely
+pub fn set_solve_window(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    from_slot: Option<u64>,
+    until_slot: Option<u64>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::SetSolveWindow {
+            id,
+            from_slot,
+            until_slot,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Get Challenge State
+// -----------------
+
+/// Creates an instruction which returns the Borsh serialized state of the challenge via
+/// return data. It does not modify any state and is meant to be simulated.
+///
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+pub fn get_challenge_state(
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![AccountMeta::new_readonly(challenge_pda, false)],
+        data: ChallengeInstruction::GetChallengeState.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Split Challenge
+// -----------------
+
+/// Splits a challenge into two by moving all solutions starting at [split_at_index] into a
+/// new challenge with id [new_id].
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing both challenges
+/// * [id]: unique id of the challenge to split
+/// * [new_id]: unique id of the challenge created by the split
+/// * [split_at_index]: index of the first solution to move to the new challenge
+pub fn split_challenge(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    new_id: String,
+    split_at_index: u8,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    // the new challenge is created by the current authority
+    let (new_challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &authority, &new_id);
+    let (new_redeem_pda, _) = Redeem::new(new_challenge_pda).pda();
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(new_challenge_pda, false),
+            AccountMeta::new(new_redeem_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::SplitChallenge {
+            id: id.to_string(),
+            new_id,
+            new_redeem: new_redeem_pda,
+            split_at: split_at_index,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Truncate Solutions
+// -----------------
+
+/// Removes all solutions of the challenge starting at index [len].
+///
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [len]: the number of solutions to keep
+pub fn truncate_solutions(
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    len: u8,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::TruncateSolutions { id, len }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Merge Challenges
+// -----------------
+
+/// Appends all solutions of the challenge with [source_id] to the challenge with [id] and
+/// closes the source challenge, returning its rent to the [creator].
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing both challenges
+/// * [id]: unique id of the challenge receiving the solutions
+/// * [source_id]: unique id of the challenge that is merged and closed
+pub fn merge_challenges(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    source_id: String,
+) -> Result<Instruction, ProgramError> {
+    merge_challenges_with_source_escrow(payer, creator, id, source_id, None)
+}
+
+/// Same as [merge_challenges] for a source challenge whose admit cost is paid in tokens
+/// of [source_admit_mint], its escrow is verified to be empty before it is closed.
+pub fn merge_challenges_with_admit_mint(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    source_id: String,
+    source_admit_mint: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    merge_challenges_with_source_escrow(
+        payer,
+        creator,
+        id,
+        source_id,
+        Some(source_admit_mint),
+    )
+}
+
+fn merge_challenges_with_source_escrow(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    source_id: String,
+    source_admit_mint: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let (source_challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &source_id);
+
+    let mut ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(source_challenge_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::MergeChallenges { id, source_id }
+            .try_to_vec()?,
+    };
+    if let Some(source_admit_mint) = source_admit_mint {
+        let source_escrow_ata = get_associated_token_address_with_program_id(
+            &source_challenge_pda,
+            &source_admit_mint,
+            &spl_token::id(),
+        );
+        ix.accounts
+            .push(AccountMeta::new_readonly(source_escrow_ata, false));
+    }
+
+    Ok(ix)
+}
+
+// -----------------
+// Update Admit Cost
+// -----------------
+
+/// Updates the fee that challengers pay when they are admitted to the challenge.
+/// Same as [update_challenge] leaving everything but the admit cost unchanged.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [admit_cost]: the new fee to admit a challenger
+pub fn update_admit_cost(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    admit_cost: u64,
+) -> Result<Instruction, ProgramError> {
+    update_challenge(payer, creator, id, Some(admit_cost), None, None)
+}
+
+// -----------------
+// Lock Admit Cost
+// -----------------
+
+/// Locks the admit cost of the challenge. This cannot be undone.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+pub fn lock_admit_cost(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::LockAdmitCost { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Create Template
+// -----------------
+
+/// Creates a template with default settings from which challenges can be created.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [template]: the defaults of challenges created from it, its authority is the creator
+///   managing the template and challenges created from it
+pub fn create_template(
+    payer: Pubkey,
+    template: &ChallengeTemplate,
+) -> Result<Instruction, ProgramError> {
+    let (template_pda, _) = template.pda();
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(template.authority, true),
+            AccountMeta::new(template_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::CreateTemplate {
+            template_id: template.template_id,
+            admit_cost: template.admit_cost,
+            tries_per_admit: template.tries_per_admit,
+            salt: template.salt,
+            hash_algorithm: template.hash_algorithm,
+            starts_at: template.starts_at,
+            ends_at: template.ends_at,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Create Challenge from Template
+// -----------------
+
+/// Creates a new challenge using the defaults of the [template].
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [template]: the template to apply, its authority manages the challenge
+/// * [id]: unique id identifying the challenge. The same creator cannot reuse ids for different challenges
+/// * [overrides]: settings that override the defaults of the template
+/// * [solutions]: solutions to be solved in clear text, they are encoded via
+///   `H(salt || H(solution))` with the salt and hash algorithm the challenge ends up with
+///   before being passed on to the program
+pub fn create_challenge_from_template(
+    payer: Pubkey,
+    template: &ChallengeTemplate,
+    id: String,
+    overrides: TemplateOverrides,
+    solutions: Vec<&str>,
+) -> Result<Instruction, ProgramError> {
+    let creator = template.authority;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let (template_pda, _) = template.pda();
+
+    let redeem = Redeem::new(challenge_pda);
+    let (redeem_pda, _) = redeem.pda();
+
+    let settings = template.with_overrides(&overrides);
+    let solutions = hash_solutions_with_algorithm(
+        &solutions,
+        settings.salt.as_ref(),
+        settings.hash_algorithm,
+    );
+
+    let TemplateOverrides {
+        admit_cost,
+        tries_per_admit,
+        salt,
+        hash_algorithm,
+        starts_at,
+        ends_at,
+    } = overrides;
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(creator, false),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(redeem_pda, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(template_pda, false),
+        ],
+        data: ChallengeInstruction::CreateChallengeFromTemplate {
+            template_id: template.template_id,
+            id,
+            admit_cost,
+            tries_per_admit,
+            redeem: redeem_pda,
+            solutions,
+            salt,
+            hash_algorithm,
+            starts_at,
+            ends_at,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Set Oracle
+// -----------------
+
+/// Sets the oracle that can sign solutions on behalf of challengers.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [oracle]: the oracle's pubkey, `Pubkey::default()` disables oracle signed solutions
+pub fn set_oracle(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    oracle: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::SetOracle { id, oracle }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Verify Solve Signature
+// -----------------
+
+/// Redeems via a signature the oracle created off-chain over the
+/// [Challenge::oracle_message] for the challenger and the solution at [solution_index].
+/// Returns the Ed25519 program instruction verifying the signature followed by the
+/// instruction to redeem. Both need to be included in the same transaction in that order.
+///
+/// * [payer]: pays for the transaction and is usually the challenger
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the account redeeming, needs to have been admitted
+/// * [oracle]: the oracle set for the challenge
+/// * [solution_index]: index of the solution, needs to be the one that is solved next unless
+///   the challenge is solved in any order
+/// * [solution_hash]: the solution as stored in the challenge at [solution_index]
+/// * [oracle_signature]: the oracle's signature over the [Challenge::oracle_message]
+#[allow(clippy::too_many_arguments)]
+pub fn verify_solve_signature(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    oracle: Pubkey,
+    solution_index: u8,
+    solution_hash: &Solution,
+    oracle_signature: [u8; 64],
+) -> Result<Vec<Instruction>, ProgramError> {
+    let creator = creator.into().creator;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    let redeem = Redeem::new(challenge_pda);
+    let redeem_ata = redeem.ata(&challenger);
+
+    let message =
+        Challenge::oracle_message(&challenge_pda, &challenger, solution_hash);
+    let ed25519_ix =
+        ed25519_verify_instruction(&oracle, &oracle_signature, &message);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(challenge_pda, false),
+            // challenger
+            AccountMeta::new(challenger, true),
+            AccountMeta::new(challenger_pda, false),
+            // redeem
+            AccountMeta::new(redeem.pda().0, false),
+            AccountMeta::new(redeem_ata, false),
+            // programs
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(
+                spl_associated_token_account::id(),
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::id(), false),
+            // sysvars
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            // prize pool
+            AccountMeta::new(PrizePool::pda_for(&challenge_pda).0, false),
+        ],
+        data: ChallengeInstruction::VerifySolveSignature {
+            solution_index,
+            oracle_signature,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(vec![ed25519_ix, ix])
+}
+
+/// Same as [verify_solve_signature] for a challenge that admits with tickets, where the
+/// [holder] of the ticket of the admission of the [challenger] solves and receives the
+/// rewards. The oracle signs the [Challenge::oracle_message] for the [holder].
+/// If the challenge also rewards NFTs, has a first solvers leaderboard or multiple winners
+/// their accounts need to be inserted before the [ticket_accounts].
+///
+/// * [holder]: the account holding the ticket and attempting to redeem
+/// * [challenger]: the challenger that was admitted with the ticket
+#[allow(clippy::too_many_arguments)]
+pub fn verify_solve_signature_with_ticket(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    holder: Pubkey,
+    challenger: Pubkey,
+    oracle: Pubkey,
+    solution_index: u8,
+    solution_hash: &Solution,
+    oracle_signature: [u8; 64],
+) -> Result<Vec<Instruction>, ProgramError> {
+    let creator = creator.into().creator;
+    let mut ixs = verify_solve_signature(
+        payer,
+        creator,
+        id,
+        holder,
+        oracle,
+        solution_index,
+        solution_hash,
+        oracle_signature,
+    )?;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    let ix = &mut ixs[1];
+    ix.accounts[accounts::verify_solve_signature::CHALLENGER_PDA] =
+        AccountMeta::new(challenger_pda, false);
+    ix.accounts
+        .extend(ticket_accounts(&challenger_pda, &holder));
+    Ok(ixs)
+}
+
+// -----------------
+// Update Solution Hash
+// -----------------
+
+/// Replaces a single solution of the challenge, leaving all others in place.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [index]: the index of the solution to replace
+/// * [new_hash]: the solution as it is stored, i.e. obtained via [crate::utils::hash_solutions] or
+///   [hash_solutions_with_algorithm] for salted challenges or ones using another algorithm
+pub fn update_solution_hash(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    index: u8,
+    new_hash: Solution,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::UpdateSolutionHash {
+            id,
+            index,
+            new_hash,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Lock/Unlock Solutions
+// -----------------
+
+fn set_solutions_locked(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    locked: bool,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::SetSolutionsLocked { id, locked }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Locks the solutions of the challenge such that they can no longer be changed until
+/// they are unlocked via [unlock_solutions].
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+pub fn lock_solutions(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    set_solutions_locked(payer, creator, id, true)
+}
+
+/// Unlocks the solutions of the challenge that were locked via [lock_solutions].
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+pub fn unlock_solutions(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    set_solutions_locked(payer, creator, id, false)
+}
+
+// -----------------
+// Reissue Admission
+// -----------------
+
+/// Admits a challenger who has no tries remaining again.
+///
+/// * [payer]: pays the admit cost and for the transaction, usually the challenger
+/// * [creator]: the authority managing the challenge, receives the admit cost
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger that was admitted before and has no tries left
+pub fn reissue_admission(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    reissue_admission_paying_with(payer, creator, id, challenger, None)
+}
+
+/// Admits the [challenger] again to a challenge whose admit cost is paid in tokens of
+/// [admit_mint].
+///
+/// * [payer]: pays for the transaction and the admit cost from its ATA for [admit_mint]
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger that used up all tries
+/// * [admit_mint]: the admit mint of the challenge
+pub fn reissue_admission_with_admit_mint(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    admit_mint: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    reissue_admission_paying_with(
+        payer,
+        creator,
+        id,
+        challenger,
+        Some(admit_mint),
+    )
+}
+
+fn reissue_admission_paying_with(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    admit_mint: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+
+    let mut ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(authority, false),
+            AccountMeta::new_readonly(challenge_pda, false),
+            AccountMeta::new_readonly(challenger, false),
+            AccountMeta::new(challenger_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(PrizePool::pda_for(&challenge_pda).0, false),
+        ],
+        data: ChallengeInstruction::ReissueAdmission { challenge_pda }
+            .try_to_vec()?,
+    };
+    if let Some(admit_mint) = admit_mint {
+        ix.accounts.extend(admit_mint_accounts(
+            &payer,
+            &challenge_pda,
+            &admit_mint,
+        ));
+    }
+    ix.accounts
+        .push(AccountMeta::new_readonly(Config::pda().0, false));
+
+    Ok(ix)
+}
+
+// -----------------
+// Add Co-Sponsor
+// -----------------
+
+/// Contributes lamports to the prize pool of the challenge.
+///
+/// * [payer]: the sponsor, contributes the lamports and pays for the transaction
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [amount]: lamports to contribute
+pub fn add_co_sponsor(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (sponsor_log_pda, _) = SponsorLog::pda_for(&challenge_pda, &payer);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(sponsor_log_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(PrizePool::pda_for(&challenge_pda).0, false),
+        ],
+        data: ChallengeInstruction::AddCoSponsor {
+            challenge_pda,
+            amount,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Leaderboard
+// -----------------
+
+/// Creates the leaderboard of the challenge.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+pub fn create_leaderboard(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let (leaderboard_pda, _) = Leaderboard::pda_for(&challenge_pda);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(challenge_pda, false),
+            AccountMeta::new(leaderboard_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::CreateLeaderboard { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Adds the challenger to the leaderboard of the challenge after they redeemed.
+///
+/// * [payer]: pays for the transaction and is usually the challenger
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger that redeemed
+pub fn update_leaderboard(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    let (leaderboard_pda, _) = Leaderboard::pda_for(&challenge_pda);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(challenge_pda, false),
+            AccountMeta::new_readonly(challenger, true),
+            AccountMeta::new_readonly(challenger_pda, false),
+            AccountMeta::new(leaderboard_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::UpdateLeaderboard.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Remove Solutions
+// -----------------
+
+/// Removes the solutions at [indices] from the challenge.
+///
+/// * [creator]: the authority managing the challenge, receives the refunded rent
+/// * [id]: unique id used when creating the challenge
+/// * [indices]: indices of the solutions to remove, in any order
+pub fn remove_solutions(
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    indices: Vec<u8>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::RemoveSolutions { id, indices }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Replace Solutions
+// -----------------
+
+/// Replaces all solutions of the challenge with [solutions].
+///
+/// * [payer]: pays for or receives the rent difference
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [solutions]: the solutions that replace the current ones, they are hashed before being stored
+pub fn replace_solutions(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    solutions: Vec<&str>,
+) -> Result<Instruction, ProgramError> {
+    replace_salted_solutions(
+        payer,
+        creator,
+        id,
+        solutions,
+        None,
+        HashAlgorithm::Sha256,
+    )
+}
+
+/// Same as [replace_solutions] for a challenge created with a [salt] and [hash_algorithm]
+/// which need to match the ones stored with the challenge.
+pub fn replace_salted_solutions(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    solutions: Vec<&str>,
+    salt: Option<[u8; 32]>,
+    hash_algorithm: HashAlgorithm,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let solutions = hash_solutions_with_algorithm(
+        &solutions,
+        salt.as_ref(),
+        hash_algorithm,
+    );
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::ReplaceSolutions { id, solutions }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Close Challenge
+// -----------------
+
+/// Closes the challenge and transfers its lamports to [recipient].
+///
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [recipient]: receives all lamports held by the challenge account
+pub fn close_challenge(
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    recipient: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(recipient, false),
+        ],
+        data: ChallengeInstruction::CloseChallenge { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Transfer Authority
+// -----------------
+
+/// Starts transferring the challenge to [new_authority].
+///
+/// * [payer]: pays for or receives the rent difference
+/// * [authority]: the current authority of the challenge
+/// * [creator]: the authority that created the challenge, used to derive its PDA
+/// * [id]: unique id used when creating the challenge
+/// * [new_authority]: the authority that needs to accept the transfer
+pub fn transfer_authority(
+    payer: Pubkey,
+    authority: Pubkey,
+    creator: Pubkey,
+    id: String,
+    new_authority: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::TransferAuthority { id, new_authority }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Accepts the pending transfer of the challenge to [new_authority].
+///
+/// * [new_authority]: the pending authority of the challenge
+/// * [creator]: the authority that created the challenge, used to derive its PDA
+/// * [id]: unique id used when creating the challenge
+pub fn accept_authority(
+    new_authority: Pubkey,
+    creator: Pubkey,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(new_authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::AcceptAuthority { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Pause/Resume Challenge
+// -----------------
+
+fn set_paused(
+    payer: Pubkey,
+    signer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    paused: bool,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(signer, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::SetPaused { id, paused }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Pauses the challenge such that no challengers are admitted nor can redeem until it is
+/// resumed via [resume_challenge].
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+pub fn pause_challenge(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    set_paused(payer, authority, creator, id, true)
+}
+
+/// Resumes the challenge that was paused via [pause_challenge].
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+pub fn resume_challenge(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    set_paused(payer, authority, creator, id, false)
+}
+
+/// Same as [pause_challenge] when signed by one of the admins of the challenge.
+///
+/// * [admin]: the admin of the challenge signing the instruction
+/// * [creator]: the authority that created the challenge, used to derive its PDA
+pub fn pause_challenge_as_admin(
+    payer: Pubkey,
+    admin: Pubkey,
+    creator: Pubkey,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    set_paused(payer, admin, creator, id, true)
+}
+
+/// Same as [resume_challenge] when signed by one of the admins of the challenge.
+///
+/// * [admin]: the admin of the challenge signing the instruction
+/// * [creator]: the authority that created the challenge, used to derive its PDA
+pub fn resume_challenge_as_admin(
+    payer: Pubkey,
+    admin: Pubkey,
+    creator: Pubkey,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    set_paused(payer, admin, creator, id, false)
+}
+
+// -----------------
+// Update Challenge
+// -----------------
+
+/// Updates the economics of the challenge, leaving values that are `None` unchanged.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [admit_cost]: the new fee challengers pay when they are admitted
+/// * [tries_per_admit]: the new number of tries challengers get per admission
+/// * [redeem]: the new mint to redeem, its mint authority needs to be the challenge PDA
+pub fn update_challenge(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    admit_cost: Option<u64>,
+    tries_per_admit: Option<u8>,
+    redeem: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let mut accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new(challenge_pda, false),
+    ];
+    if let Some(redeem) = redeem {
+        accounts.push(AccountMeta::new_readonly(redeem, false));
+    }
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts,
+        data: ChallengeInstruction::UpdateChallenge {
+            id,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Admit Mint
+// -----------------
+
+/// Sets the mint in whose tokens the admit cost is paid, `None` switches back to lamports.
+///
+/// * [payer]: pays for or receives the rent difference
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [admit_mint]: the new admit mint
+pub fn set_admit_mint(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    admit_mint: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let mut accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new(challenge_pda, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    if let Some(admit_mint) = admit_mint {
+        accounts.push(AccountMeta::new_readonly(admit_mint, false));
+    }
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts,
+        data: ChallengeInstruction::SetAdmitMint { id, admit_mint }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Withdraws all admit tokens held by the challenge PDA to the ATA of the [creator].
+///
+/// * [creator]: the authority managing the challenge, receives the admit tokens
+/// * [id]: unique id used when creating the challenge
+/// * [admit_mint]: the admit mint of the challenge
+pub fn withdraw_admit_tokens(
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    admit_mint: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let escrow_ata = get_associated_token_address_with_program_id(
+        &challenge_pda,
+        &admit_mint,
+        &spl_token::id(),
+    );
+    let creator_ata = get_associated_token_address_with_program_id(
+        &authority,
+        &admit_mint,
+        &spl_token::id(),
+    );
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(challenge_pda, false),
+            AccountMeta::new_readonly(admit_mint, false),
+            AccountMeta::new(escrow_ata, false),
+            AccountMeta::new(creator_ata, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(
+                spl_associated_token_account::id(),
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::WithdrawAdmitTokens { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Prize Pool
+// -----------------
+
+/// Creates the prize pool of the challenge which collects admit costs paid in lamports.
+///
+/// * [payer]: pays for the transaction and the prize pool account
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [payout]: how the pool is paid out to challengers that redeem
+pub fn create_prize_pool(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    payout: Payout,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let (prize_pool_pda, _) = PrizePool::pda_for(&challenge_pda);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(prize_pool_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::CreatePrizePool { id, payout }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Withdraws what is left in the prize pool of the challenge and closes it.
+///
+/// * [creator]: the authority managing the challenge, receives the leftover lamports
+/// * [id]: unique id used when creating the challenge
+pub fn withdraw_prize_pool(
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let (prize_pool_pda, _) = PrizePool::pda_for(&challenge_pda);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(prize_pool_pda, false),
+        ],
+        data: ChallengeInstruction::WithdrawPrizePool { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// NFT Reward
+// -----------------
+
+/// Enables or disables rewarding an NFT to each challenger that redeems.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [enabled]: whether challengers that redeem receive an NFT
+pub fn set_nft_reward(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    enabled: bool,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::SetNftReward { id, enabled }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Commit/Reveal Solution
+// -----------------
+
+/// Commits the [challenger] to the [solution] without revealing it.
+///
+/// * [payer]: pays for the transaction and the commitment account
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the admitted challenger committing to the solution
+/// * [solution]: solution in clear text, only its commitment is sent
+/// * [nonce]: secret that needs to be provided again when revealing
+pub fn commit_solution(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    solution: &str,
+    nonce: [u8; 32],
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let challenger_sends = hash_solution_challenger_sends(solution);
+    let commitment =
+        solution_commitment(&challenger, &challenger_sends, &nonce);
+
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    let (commitment_pda, _) = SolutionCommitment::pda_for(&challenger_pda);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(challenge_pda, false),
+            AccountMeta::new_readonly(challenger, true),
+            AccountMeta::new_readonly(challenger_pda, false),
+            AccountMeta::new(commitment_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::CommitSolution { commitment }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Commits the [holder] of the ticket of the admission of the [challenger] to a solution of a
+/// challenge that admits with tickets, see [commit_solution].
+///
+/// * [payer]: pays for the transaction and the commitment account
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [holder]: the account holding the ticket and committing to the solution
+/// * [challenger]: the challenger that was admitted with the ticket
+/// * [solution]: solution in clear text, see [redeem]
+/// * [nonce]: random nonce that needs to be provided again when revealing
+pub fn commit_solution_with_ticket(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    holder: Pubkey,
+    challenger: Pubkey,
+    solution: &str,
+    nonce: [u8; 32],
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let mut ix = commit_solution(payer, creator, id, holder, solution, nonce)?;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    let (commitment_pda, _) = SolutionCommitment::pda_for(&challenger_pda);
+    ix.accounts[accounts::commit_solution::CHALLENGER_PDA] =
+        AccountMeta::new_readonly(challenger_pda, false);
+    ix.accounts[accounts::commit_solution::COMMITMENT_PDA] =
+        AccountMeta::new(commitment_pda, false);
+    ix.accounts
+        .extend(ticket_accounts(&challenger_pda, &holder));
+    Ok(ix)
+}
+
+/// Reveals the [solution] committed to via [commit_solution] and redeems it.
+///
+/// * [payer]: pays for the transaction and receives the commitment rent
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger that committed to the solution
+/// * [solution]: solution in clear text, see [redeem]
+/// * [nonce]: the nonce used when committing
+pub fn reveal_solution(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    solution: &str,
+    nonce: [u8; 32],
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let mut ix = redeem(payer, creator, id, challenger, solution)?;
+
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    let (commitment_pda, _) = SolutionCommitment::pda_for(&challenger_pda);
+
+    // same accounts as redeem with the commitment following the system program
+    ix.accounts
+        .insert(9, AccountMeta::new(commitment_pda, false));
+    ix.data = ChallengeInstruction::RevealSolution {
+        solution: hash_solution_challenger_sends(solution),
+        nonce,
+    }
+    .try_to_vec()?;
+
+    Ok(ix)
+}
+
+/// Requires or stops requiring solutions to be committed and revealed.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [required]: whether solutions need to be committed and revealed
+pub fn set_commit_reveal(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    required: bool,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::SetCommitReveal { id, required }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Set Solve Mode
+// -----------------
+
+/// Sets the [solve_mode] of the challenge which determines if its solutions need to be
+/// redeemed in order.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [solve_mode]: the mode in which the solutions are redeemed
+pub fn set_solve_mode(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    solve_mode: SolveMode,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::SetSolveMode { id, solve_mode }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Set Attempt Cooldown
+// -----------------
+
+/// Sets the [attempt_cooldown_slots] a challenger needs to wait after an attempt to
+/// redeem before attempting again.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [attempt_cooldown_slots]: slots to wait between attempts, `0` for no cooldown
+pub fn set_attempt_cooldown(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    attempt_cooldown_slots: u64,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::SetAttemptCooldown {
+            id,
+            attempt_cooldown_slots,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Hints
+// -----------------
+
+/// Sets the [hints] of the solutions of the challenge together with the [hint_fee]
+/// challengers pay to reveal one of them.
+///
+/// * [payer]: pays for or receives the rent difference and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [hints]: one hint per solution, i.e. the pubkey of the account holding the encrypted
+///   hint or its URI padded with zeros, empty to remove all hints
+/// * [hint_fee]: lamports a challenger pays to reveal a hint
+pub fn set_hints(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    hints: Vec<Hint>,
+    hint_fee: u64,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::SetHints {
+            id,
+            hints,
+            hint_fee,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Reveals the hint of the solution at [solution_index] to the [challenger].
+///
+/// * [payer]: pays for the transaction, the hint fee and the revealed hints account
+/// * [creator]: the authority managing the challenge which receives the hint fee unless the
+///   challenge has a prize pool
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the admitted challenger revealing the hint
+/// * [solution_index]: index of the solution whose hint is revealed
+pub fn reveal_hint(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    solution_index: u8,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    let (revealed_hints_pda, _) = RevealedHints::pda_for(&challenger_pda);
+    let (prize_pool_pda, _) = PrizePool::pda_for(&challenge_pda);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(challenge_pda, false),
+            AccountMeta::new_readonly(challenger, true),
+            AccountMeta::new_readonly(challenger_pda, false),
+            AccountMeta::new(revealed_hints_pda, false),
+            AccountMeta::new(authority, false),
+            AccountMeta::new(prize_pool_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::RevealHint { solution_index }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// First Solvers
+// -----------------
+
+/// Creates the leaderboard recording the first solver of each solution of the challenge.
+/// It is sized for the current solutions via [FirstSolvers::needed_size] and grows if more
+/// are added later.
+///
+/// * [payer]: pays for the transaction and the leaderboard rent, usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+pub fn create_first_solvers(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let (first_solvers_pda, _) = FirstSolvers::pda_for(&challenge_pda);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(first_solvers_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::CreateFirstSolvers { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Winners
+// -----------------
+
+/// Allows up to [max_winners_per_solution] challengers to solve each solution of the
+/// challenge and creates the leaderboard recording them.
+///
+/// * [payer]: pays for the transaction and the leaderboard rent, usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [max_winners_per_solution]: distinct challengers that may solve each solution
+pub fn create_winners(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    max_winners_per_solution: u8,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let (winners_pda, _) = Winners::pda_for(&challenge_pda);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(winners_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::CreateWinners {
+            id,
+            max_winners_per_solution,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Set Solution Normalization
+// -----------------
+
+/// Sets the [solution_normalization] rules clients apply to solutions before hashing them,
+/// see [crate::utils::normalize_solution].
+/// The solutions added so far need to have been normalized with the same rules.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [solution_normalization]: the rules to apply
+pub fn set_solution_normalization(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    solution_normalization: SolutionNormalization,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::SetSolutionNormalization {
+            id,
+            solution_normalization,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Check Solution
+// -----------------
+
+/// Creates an instruction which checks whether the [solution] solves the challenge and
+/// returns the outcome via return data. It does not modify any state and is meant to be
+/// simulated.
+///
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [solution]: the solution in clear text, it is hashed before it is sent
+pub fn check_solution(
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    solution: &str,
+) -> Result<Instruction, ProgramError> {
+    let challenger_sends = hash_solution_challenger_sends(solution);
+    check_solution_with_hash(creator, id, challenger_sends)
+}
+
+/// Same as [check_solution] except that the solution is provided already hashed, i.e. for
+/// challenges whose [HashAlgorithm] is not `Sha256`, see
+/// [crate::utils::hash_solution_challenger_sends_with].
+pub fn check_solution_with_hash(
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger_sends: Solution,
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![AccountMeta::new_readonly(challenge_pda, false)],
+        data: ChallengeInstruction::CheckSolution {
+            solution: challenger_sends,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Set Reject Weak Solutions
+// -----------------
+
+/// Creates an instruction which sets whether the challenge rejects solutions matching one
+/// of the most common answers, see [crate::utils::weak_solutions].
+///
+/// * [payer]: pays for the transaction
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [reject_weak_solutions]: if `true` the solutions added so far and from now on are
+///   verified to not be weak
+pub fn set_reject_weak_solutions(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    reject_weak_solutions: bool,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::SetRejectWeakSolutions {
+            id,
+            reject_weak_solutions,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Set Ticket Admission
+// -----------------
+
+/// Enables or disables admitting challengers with transferable tickets, see
+/// [crate::state::Challenge::ticket_admission].
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [enabled]: whether admitting a challenger mints a ticket whose holder redeems
+pub fn set_ticket_admission(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    enabled: bool,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::SetTicketAdmission { id, enabled }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Append Solutions Chunk
+// -----------------
+
+/// Uploads one chunk of solutions, the challenge receives them once the last chunk is
+/// uploaded.
+///
+/// * [payer]: pays for the transaction and the upload rent, usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [chunk_index]: index of this chunk, starting at `0`
+/// * [total_chunks]: total number of chunks in the upload
+/// * [solutions]: solutions of this chunk hashed the way the challenge expects, see
+///   [crate::utils::hash_solutions_with_algorithm]
+pub fn append_solutions_chunk(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    chunk_index: u8,
+    total_chunks: u8,
+    solutions: Vec<Solution>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let (solutions_upload_pda, _) = SolutionsUpload::pda_for(&challenge_pda);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(solutions_upload_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::AppendSolutionsChunk {
+            id,
+            chunk_index,
+            total_chunks,
+            solutions,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Hashes the [solutions] and splits them into [append_solutions_chunk] instructions of
+/// at most [chunk_size] solutions each, to be sent in order in separate transactions.
+///
+/// * [payer]: pays for the transactions and the upload rent, usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [solutions]: the solutions to add to the challenge
+/// * [chunk_size]: max number of solutions per chunk
+/// * [salt]: salt the challenge was created with if any
+/// * [hash_algorithm]: algorithm the challenge was created with
+pub fn append_solutions_in_chunks(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    solutions: &[&str],
+    chunk_size: usize,
+    salt: Option<[u8; 32]>,
+    hash_algorithm: HashAlgorithm,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let solutions =
+        hash_solutions_with_algorithm(solutions, salt.as_ref(), hash_algorithm);
+    let chunks: Vec<&[Solution]> =
+        solutions.chunks(chunk_size.max(1)).collect();
+    let creator = creator.into();
+    let total_chunks = u8::try_from(chunks.len())
+        .map_err(|_| ProgramError::InvalidArgument)?;
+
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(chunk_index, chunk)| {
+            append_solutions_chunk(
+                payer,
+                creator,
+                id.clone(),
+                chunk_index as u8,
+                total_chunks,
+                chunk.to_vec(),
+            )
+        })
+        .collect()
+}
+
+// -----------------
+// Config
+// -----------------
+
+/// Initializes the program config which enables the protocol fee.
+///
+/// * [payer]: pays for the transaction and the config rent
+/// * [admin]: authority of the config, needs to be the upgrade authority of the program
+/// * [fee_bps]: protocol fee in basis points of the admit cost
+/// * [fee_destination]: receives the protocol fee
+pub fn init_config(
+    payer: Pubkey,
+    admin: Pubkey,
+    fee_bps: u16,
+    fee_destination: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new(Config::pda().0, false),
+            AccountMeta::new_readonly(pda::program_data_pda().0, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::InitConfig {
+            fee_bps,
+            fee_destination,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+/// Updates the program config, only the provided values are changed.
+///
+/// * [admin]: current authority of the config
+/// * [fee_bps]: new protocol fee in basis points of the admit cost
+/// * [fee_destination]: new receiver of the protocol fee
+/// * [new_admin]: new authority of the config
+pub fn update_config(
+    admin: Pubkey,
+    fee_bps: Option<u16>,
+    fee_destination: Option<Pubkey>,
+    new_admin: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new(Config::pda().0, false),
+        ],
+        data: ChallengeInstruction::UpdateConfig {
+            fee_bps,
+            fee_destination,
+            admin: new_admin,
+        }
+        .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Allowlist
+// -----------------
+
+/// Restricts admission to challengers on the allowlist with the given [root].
+///
+/// * [payer]: pays for or receives the rent difference
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [root]: Merkle root of the allowlist, see [crate::utils::allowlist_root], `None` to
+///   admit everyone
+pub fn set_allowlist(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    root: Option<[u8; 32]>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::SetAllowlist { id, root }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Max Admissions
+// -----------------
+
+/// Limits how many challengers are admitted to the challenge.
+///
+/// * [payer]: pays for or receives the rent difference
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [max_admissions]: max number of challengers to admit, `None` to admit any number
+pub fn set_max_admissions(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    max_admissions: Option<u32>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::SetMaxAdmissions { id, max_admissions }
+            .try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Cancel Challenge
+// -----------------
+
+/// Cancels the challenge such that it cannot be resumed and challengers can claim refunds via
+/// [refund_unused_tries].
+///
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+pub fn cancel_challenge(
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+        ],
+        data: ChallengeInstruction::CancelChallenge { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Refund Unused Tries
+// -----------------
+
+/// Refunds the [challenger] the admit cost of its unused tries for a cancelled challenge.
+///
+/// * [creator]: the creator of the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger claiming the refund, pays for the transaction
+/// * [admit_mint]: the admit mint of the challenge if it has one
+pub fn refund_unused_tries(
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    challenger: Pubkey,
+    admit_mint: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let creator = creator.into().creator;
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+
+    let mut accounts = vec![
+        AccountMeta::new(challenger, true),
+        AccountMeta::new_readonly(challenge_pda, false),
+        AccountMeta::new(challenger_pda, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(PrizePool::pda_for(&challenge_pda).0, false),
+    ];
+    if let Some(admit_mint) = admit_mint {
+        accounts.extend(admit_mint_accounts(
+            &challenger,
+            &challenge_pda,
+            &admit_mint,
+        ));
+    }
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts,
+        data: ChallengeInstruction::RefundUnusedTries { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
+}
+
+// -----------------
+// Migrate Account
+// -----------------
+
+/// Migrates an account created before discriminators were introduced.
+///
+/// * [payer]: pays for the transaction and the extra rent
+/// * [account]: address of the account to migrate
+/// * [kind]: kind of the account
+pub fn migrate_account(
+    payer: Pubkey,
+    account: Pubkey,
+    kind: AccountKind,
+) -> Result<Instruction, ProgramError> {
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(account, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::MigrateAccount { kind }.try_to_vec()?,
+    };
+
+    Ok(ix)
 }
 
 // -----------------
-// Create Challenge
+// Admins
 // -----------------
 
-/// Creates a new challenge and is invoked only once by the creator of the challenge.
-///
-/// * [payer]: pays for the transaction and is usually the creator
-/// * [creator]: the authority managing the challenge
-/// * [id]: unique id identifying the challenge. The same creator cannot reuse ids for different challenges
-/// * [admit_cost]: the amount of SOL that must be paid to admit a challenger
-/// * [tries_per_admit]: the number of tries that a challenger gets for the given admit_cost
-/// * [solutions]: solutions to be solved in clear text, they are encoded via
-///   `sha256(sha256(solution))` before being passed on to the program
-pub fn create_challenge(
+fn set_admin(
     payer: Pubkey,
-    creator: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
     id: String,
-    admit_cost: u64,
-    tries_per_admit: u8,
-    solutions: Vec<&str>,
+    admin: Pubkey,
+    add: bool,
 ) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
     let (challenge_pda, _) =
         Challenge::shank_pda(&challenge_id(), &creator, &id);
 
-    let redeem = Redeem::new(challenge_pda);
-    let (redeem_pda, _) = redeem.pda();
-
-    let solutions = hash_solutions(&solutions);
-
+    let data = if add {
+        ChallengeInstruction::AddAdmin { id, admin }
+    } else {
+        ChallengeInstruction::RemoveAdmin { id, admin }
+    };
     let ix = Instruction {
         program_id: challenge_id(),
         accounts: vec![
             AccountMeta::new(payer, true),
-            AccountMeta::new_readonly(creator, false),
+            AccountMeta::new_readonly(authority, true),
             AccountMeta::new(challenge_pda, false),
-            AccountMeta::new(redeem_pda, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(system_program::id(), false),
         ],
-        data: ChallengeInstruction::CreateChallenge {
-            id,
-            admit_cost,
-            tries_per_admit,
-            redeem: redeem_pda,
-            solutions,
-        }
-        .try_to_vec()?,
+        data: data.try_to_vec()?,
     };
 
     Ok(ix)
 }
 
+/// Adds [admin] as co-admin of the challenge which allows it to add solutions and pause or
+/// resume the challenge, see [add_solutions_as_admin] and [pause_challenge_as_admin].
+///
+/// * [payer]: pays for the transaction and the extra rent
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [admin]: the co-admin to add
+pub fn add_admin(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    admin: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    set_admin(payer, creator, id, admin, true)
+}
+
+/// Removes [admin] from the co-admins of the challenge.
+///
+/// * [payer]: receives the refunded rent
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [admin]: the co-admin to remove
+pub fn remove_admin(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    admin: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    set_admin(payer, creator, id, admin, false)
+}
+
 // -----------------
-// Add Solutions
+// Set Metadata
 // -----------------
 
-/// Adds solutions to an existing challenge and can be invoked multiple times.
+/// Creates or replaces the metadata describing the challenge, see
+/// [crate::state::ChallengeMetadata].
 ///
-/// * [payer]: pays for the transaction and is usually the creator
+/// * [payer]: pays for the transaction and the metadata account
 /// * [creator]: the authority managing the challenge
 /// * [id]: unique id used when creating the challenge
-/// * [solutions]: solutions to be added in clear text, they are encoded via
-///   `sha256(sha256(solution))` before being stored
-/// * [index]: the index at which to insert the solutions
-///   if provided solutions starting at that index are replaced, otherwise they are appended
-pub fn add_solutions(
+/// * [name]: display name of the challenge
+/// * [description]: description of the challenge
+/// * [uri]: URI of off-chain data describing the challenge
+pub fn set_metadata(
     payer: Pubkey,
-    creator: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
     id: String,
-    solutions: Vec<&str>,
+    name: String,
+    description: String,
+    uri: String,
 ) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
     let (challenge_pda, _) =
         Challenge::shank_pda(&challenge_id(), &creator, &id);
-    let solutions = hash_solutions(&solutions);
+    let (metadata_pda, _) = ChallengeMetadata::pda_for(&challenge_pda);
 
     let ix = Instruction {
         program_id: challenge_id(),
         accounts: vec![
             AccountMeta::new(payer, true),
-            AccountMeta::new_readonly(creator, true),
-            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(challenge_pda, false),
+            AccountMeta::new(metadata_pda, false),
             AccountMeta::new_readonly(system_program::id(), false),
         ],
-        data: ChallengeInstruction::AddSolutions { id, solutions }
-            .try_to_vec()?,
+        data: ChallengeInstruction::SetMetadata {
+            id,
+            name,
+            description,
+            uri,
+        }
+        .try_to_vec()?,
     };
 
     Ok(ix)
 }
 
 // -----------------
-// Start Challenge
+// Set Challenge Flags
 // -----------------
-pub fn start_challenge(
-    creator: Pubkey,
+
+/// Replaces the flags toggling optional behaviors of the challenge, see
+/// [crate::state::Challenge::flags].
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [flags]: the flags to set, i.e. [crate::state::ALLOW_REFUNDS]
+pub fn set_challenge_flags(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
     id: String,
+    flags: u32,
 ) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
     let (challenge_pda, _) =
         Challenge::shank_pda(&challenge_id(), &creator, &id);
 
     let ix = Instruction {
         program_id: challenge_id(),
         accounts: vec![
-            AccountMeta::new_readonly(creator, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
             AccountMeta::new(challenge_pda, false),
         ],
-        data: ChallengeInstruction::StartChallenge { id }.try_to_vec()?,
+        data: ChallengeInstruction::SetChallengeFlags { id, flags }
+            .try_to_vec()?,
     };
 
     Ok(ix)
 }
 
 // -----------------
-// Admit Challenger
+// Create Challenge Idempotent
 // -----------------
-pub struct AdmitChallengerIx {
-    pub challenge_pda: Pubkey,
-    pub challenger_pda: Pubkey,
-    pub ix: Instruction,
+
+/// Same as [create_salted_challenge] except that the instruction succeeds without changes
+/// if the challenge already exists with the same parameters, which makes it safe to retry.
+#[allow(clippy::too_many_arguments)]
+pub fn create_challenge_idempotent(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<&str>,
+    starts_at: Option<i64>,
+    ends_at: Option<i64>,
+    salt: Option<[u8; 32]>,
+    hash_algorithm: HashAlgorithm,
+) -> Result<Instruction, ProgramError> {
+    create_challenge_with(
+        payer,
+        creator,
+        id,
+        admit_cost,
+        tries_per_admit,
+        solutions,
+        starts_at,
+        ends_at,
+        salt,
+        hash_algorithm,
+        CreateChallengeKind::Idempotent,
+    )
 }
-pub fn admit_challenger(
+
+// -----------------
+// Create Seeded Challenge
+// -----------------
+
+/// Same as [create_salted_challenge] except that the challenge PDA is additionally derived
+/// from the user chosen [seed], see [Challenge::pda_with_seed].
+///
+/// * [seed]: 32 bytes the creator may search to obtain a vanity challenge address
+#[allow(clippy::too_many_arguments)]
+pub fn create_seeded_challenge(
     payer: Pubkey,
     creator: Pubkey,
+    id: String,
+    seed: [u8; 32],
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<&str>,
+    starts_at: Option<i64>,
+    ends_at: Option<i64>,
+    salt: Option<[u8; 32]>,
+    hash_algorithm: HashAlgorithm,
+) -> Result<Instruction, ProgramError> {
+    create_challenge_with(
+        payer,
+        creator,
+        id,
+        admit_cost,
+        tries_per_admit,
+        solutions,
+        starts_at,
+        ends_at,
+        salt,
+        hash_algorithm,
+        CreateChallengeKind::Seeded(seed),
+    )
+}
+
+// -----------------
+// Buy More Tries
+// -----------------
+
+/// Adds tries to those the [challenger] has left, paying the pro-rated admit cost.
+///
+/// * [payer]: pays for the tries and the transaction, usually the challenger
+/// * [creator]: the authority managing the challenge, receives the cost
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger that was admitted before
+/// * [count]: the number of tries to buy
+pub fn buy_more_tries(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
     id: &str,
     challenger: Pubkey,
-) -> Result<AdmitChallengerIx, ProgramError> {
+    count: u8,
+) -> Result<Instruction, ProgramError> {
+    buy_more_tries_paying_with(payer, creator, id, challenger, count, None)
+}
+
+/// Adds tries to those the [challenger] has left for a challenge whose admit cost is paid
+/// in tokens of [admit_mint].
+///
+/// * [payer]: pays for the transaction and the tries from its ATA for [admit_mint]
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the challenger that was admitted before
+/// * [count]: the number of tries to buy
+/// * [admit_mint]: the admit mint of the challenge
+pub fn buy_more_tries_with_admit_mint(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    count: u8,
+    admit_mint: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    buy_more_tries_paying_with(
+        payer,
+        creator,
+        id,
+        challenger,
+        count,
+        Some(admit_mint),
+    )
+}
+
+fn buy_more_tries_paying_with(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: &str,
+    challenger: Pubkey,
+    count: u8,
+    admit_mint: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
     let (challenge_pda, _) =
         Challenge::shank_pda(&challenge_id(), &creator, id);
     let (challenger_pda, _) =
         Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
 
-    let ix = Instruction {
+    let mut ix = Instruction {
         program_id: challenge_id(),
         accounts: vec![
             AccountMeta::new(payer, true),
-            AccountMeta::new(creator, false),
+            AccountMeta::new(authority, false),
             AccountMeta::new_readonly(challenge_pda, false),
             AccountMeta::new_readonly(challenger, false),
             AccountMeta::new(challenger_pda, false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(PrizePool::pda_for(&challenge_pda).0, false),
+        ],
+        data: ChallengeInstruction::BuyMoreTries {
+            challenge_pda,
+            count,
+        }
+        .try_to_vec()?,
+    };
+    if let Some(admit_mint) = admit_mint {
+        ix.accounts.extend(admit_mint_accounts(
+            &payer,
+            &challenge_pda,
+            &admit_mint,
+        ));
+    }
+    ix.accounts
+        .push(AccountMeta::new_readonly(Config::pda().0, false));
+
+    Ok(ix)
+}
+
+// -----------------
+// Set Max Tries Per Challenger
+// -----------------
+
+/// Limits the total tries a challenger is granted via admissions and bought tries.
+///
+/// * [payer]: pays for or receives the rent difference
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [max_tries]: the max total tries per challenger, `None` removes the limit
+pub fn set_max_tries_per_challenger(
+    payer: Pubkey,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    max_tries: Option<u16>,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
         ],
-        data: ChallengeInstruction::AdmitChallenger { challenge_pda }
+        data: ChallengeInstruction::SetMaxTriesPerChallenger { id, max_tries }
             .try_to_vec()?,
     };
 
-    Ok(AdmitChallengerIx {
-        challenge_pda,
-        challenger_pda,
-        ix,
-    })
+    Ok(ix)
 }
 
-#[derive(Debug)]
-struct RedeemAccounts {
-    payer: AccountMeta,
-    challenge_pda: AccountMeta,
-    challenger: AccountMeta,
-    challenger_pda: AccountMeta,
-    redeem_pda: AccountMeta,
-    redeem_ata: AccountMeta,
-    spl_token_program: AccountMeta,
-    spl_associated_token_program: AccountMeta,
-    system_program: AccountMeta,
+// -----------------
+// Sweep Surplus
+// -----------------
+
+/// Moves the surplus lamports of the challenge to [treasury].
+///
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [treasury]: receives the lamports held beyond rent and sponsored lamports
+pub fn sweep_surplus(
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    treasury: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new(treasury, false),
+        ],
+        data: ChallengeInstruction::SweepSurplus { id }.try_to_vec()?,
+    };
+
+    Ok(ix)
 }
 
 // -----------------
-// Redeem by providing solution
+// Prerequisite
 // -----------------
-/// Attempts to redeem by providing a solution.
+
+/// Requires challengers to have completed the challenge with the [prerequisite] PDA.
 ///
-/// * [payer]: pays for the transaction and is usually the challenger
+/// * [payer]: pays for or receives the rent difference
 /// * [creator]: the authority managing the challenge
 /// * [id]: unique id used when creating the challenge
-/// * [challenger]: the  account attempting to redeem by providing the solution
-/// * [solution]: solutions to be added in clear text, they are encoded via `sha256(solution)`
-///   before being passed to the challenge
-pub fn redeem(
+/// * [prerequisite]: PDA of the challenge that needs to be completed first, `None` to
+///   admit challengers regardless
+pub fn set_prerequisite(
     payer: Pubkey,
-    creator: Pubkey,
-    id: &str,
-    challenger: Pubkey,
-    solution: &str,
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    prerequisite: Option<Pubkey>,
 ) -> Result<Instruction, ProgramError> {
-    let challenger_sends = hash_solution_challenger_sends(solution);
-
+    let ChallengeAuthority { authority, creator } = creator.into();
     let (challenge_pda, _) =
-        Challenge::shank_pda(&challenge_id(), &creator, id);
-    let (challenger_pda, _) =
-        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
-    let redeem = Redeem::new(challenge_pda);
-    let redeem_ata = redeem.ata(&challenger);
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
 
-    let accounts = RedeemAccounts {
-        payer: AccountMeta::new(payer, true),
-        challenge_pda: AccountMeta::new(challenge_pda, false),
-        challenger: AccountMeta::new_readonly(challenger, true),
-        challenger_pda: AccountMeta::new(challenger_pda, false),
-        redeem_pda: AccountMeta::new(redeem.pda().0, false),
-        redeem_ata: AccountMeta::new(redeem_ata, false),
-        spl_token_program: AccountMeta::new_readonly(spl_token::id(), false),
-        spl_associated_token_program: AccountMeta::new_readonly(
-            spl_associated_token_account::id(),
-            false,
-        ),
-        system_program: AccountMeta::new_readonly(system_program::id(), false),
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: ChallengeInstruction::SetPrerequisite { id, prerequisite }
+            .try_to_vec()?,
     };
 
+    Ok(ix)
+}
+
+// -----------------
+// Epochs
+// -----------------
+
+/// Splits the challenge into epochs so that it recurs, see [ChallengeInstruction::SetEpochs].
+///
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [epoch_slots]: the number of slots each epoch lasts, `0` for no epochs
+/// * [solutions_per_epoch]: the number of solutions solved per epoch, `0` to solve all
+///   of them again in every epoch
+pub fn set_epochs(
+    creator: impl Into<ChallengeAuthority>,
+    id: String,
+    epoch_slots: u64,
+    solutions_per_epoch: u8,
+) -> Result<Instruction, ProgramError> {
+    let ChallengeAuthority { authority, creator } = creator.into();
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
     let ix = Instruction {
         program_id: challenge_id(),
         accounts: vec![
-            accounts.payer,
-            accounts.challenge_pda,
-            // challenger
-            accounts.challenger,
-            accounts.challenger_pda,
-            // redeem
-            accounts.redeem_pda,
-            accounts.redeem_ata,
-            // programs
-            accounts.spl_token_program,
-            accounts.spl_associated_token_program,
-            accounts.system_program,
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(challenge_pda, false),
         ],
-        data: ChallengeInstruction::Redeem {
-            solution: challenger_sends,
+        data: ChallengeInstruction::SetEpochs {
+            id,
+            epoch_slots,
+            solutions_per_epoch,
         }
         .try_to_vec()?,
     };
@@ -1,11 +1,35 @@
 use solana_program::{declare_id, hash::HASH_BYTES, pubkey::Pubkey};
 
+pub mod accounts;
+#[cfg(feature = "anchor")]
+pub mod anchor;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod clock;
+#[cfg(feature = "cpi")]
+pub mod cpi;
+#[cfg(not(feature = "no-entrypoint"))]
 mod entrypoint;
-mod error;
+pub mod error;
+#[cfg(all(feature = "client", not(target_os = "solana")))]
+pub mod event_stream;
+pub mod events;
 pub mod ixs;
-mod processor;
+mod log;
+pub mod pda;
+// wallets and indexers only need the instruction builders, PDAs, state and utils
+#[cfg(not(feature = "offchain"))]
+pub mod processor;
+pub mod return_data;
+#[cfg(feature = "serde")]
+pub mod serde_utils;
 pub mod shank_utils;
+pub mod snapshot;
 pub mod state;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+#[cfg(feature = "client")]
+pub mod tx;
 pub mod utils;
 
 declare_id!("FFFFaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
@@ -15,3 +39,8 @@ pub fn challenge_id() -> Pubkey {
 }
 
 pub type Solution = [u8; HASH_BYTES];
+// Solutions are stored and sized as raw hashes, see Challenge::space_to_store_n_solutions
+const _: () = assert!(std::mem::size_of::<Solution>() == 32);
+
+pub const HINT_BYTES: usize = 64;
+pub type Hint = [u8; HINT_BYTES];
@@ -0,0 +1,53 @@
+//! Prefixed log macros used by the processors.
+//!
+//! Both macros are compiled out unless the `log` feature is enabled which saves the compute
+//! units spent on formatting and logging. Errors are always logged via `msg!` since they
+//! explain why a transaction failed.
+//!
+//! - [log_ix] logs the instruction being processed, i.e. `IX: start challenge`
+//! - [log_state] logs a change of state, i.e. `State: Refunding 1000 for unused tries`
+
+/// Logs the instruction being processed, prefixed with `IX: `.
+/// Only accepts a literal since instruction names never need formatting.
+#[cfg(feature = "log")]
+macro_rules! log_ix {
+    ($ix:literal) => {
+        solana_program::msg!(concat!("IX: ", $ix))
+    };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! log_ix {
+    ($ix:literal) => {{
+        let _ = $ix;
+    }};
+}
+
+/// Logs a change of state, prefixed with `State: `.
+/// Accepts the same arguments as `msg!`.
+#[cfg(feature = "log")]
+macro_rules! log_state {
+    ($msg:literal) => {
+        solana_program::msg!(concat!("State: ", $msg))
+    };
+    ($fmt:literal, $($arg:tt)+) => {
+        solana_program::msg!(concat!("State: ", $fmt), $($arg)+)
+    };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! log_state {
+    ($msg:literal) => {{
+        let _ = $msg;
+    }};
+    // the arguments are still type checked so that values only used for logging don't
+    // trigger unused warnings, the branch is removed when compiling
+    ($fmt:literal, $($arg:tt)+) => {{
+        if false {
+            let _ = format_args!($fmt, $($arg)+);
+        }
+    }};
+}
+
+pub(crate) use log_ix;
+pub(crate) use log_state;
@@ -0,0 +1,113 @@
+//! Deterministic derivation of the PDAs used by this program, usable on chain as well as
+//! off chain.
+//!
+//! `find_program_address` searches for the bump and is expensive when run on chain.
+//! Accounts that store their bump, i.e. [Challenge::bump], are verified via the
+//! `*_with_bump` variants instead which only need a single `create_program_address`.
+use solana_program::{
+    bpf_loader_upgradeable, msg, program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{
+    challenge_id,
+    state::{Challenge, Challenger, Config, PrizePool, Redeem},
+};
+
+/// PDA of the challenge with the given [id] created by [creator] together with its bump.
+pub fn challenge_pda(creator: &Pubkey, id: &str) -> (Pubkey, u8) {
+    Challenge::shank_pda(&challenge_id(), creator, id)
+}
+
+/// PDA of the challenge with the given [id] created by [creator] derived with the [bump]
+/// that was stored when the challenge was created.
+pub fn challenge_pda_with_bump(
+    creator: &Pubkey,
+    id: &str,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    let bump_arr = [bump];
+    let seeds = Challenge::shank_seeds_with_bump(creator, id, &bump_arr);
+    Pubkey::create_program_address(&seeds, &challenge_id()).map_err(|err| {
+        msg!(
+            "Err: failed to derive challenge PDA for creator ({}) and id ({}) with bump {}",
+            creator,
+            id,
+            bump
+        );
+        err.into()
+    })
+}
+
+/// PDA of the challenge with the given [id] created by [creator] that was additionally
+/// derived from the user chosen [seed] together with its bump.
+/// The [seed] allows creators to search for vanity addresses and is stored with the
+/// challenge, see [Challenge::seed].
+pub fn challenge_pda_with_seed(
+    creator: &Pubkey,
+    id: &str,
+    seed: &[u8; 32],
+) -> (Pubkey, u8) {
+    let [prefix, creator_seed, id_seed] = Challenge::shank_seeds(creator, id);
+    Pubkey::find_program_address(
+        &[prefix, creator_seed, id_seed, seed],
+        &challenge_id(),
+    )
+}
+
+/// PDA of the challenge with the given [id] created by [creator] and derived from the user
+/// chosen [seed] with the [bump] that was stored when the challenge was created.
+pub fn challenge_pda_with_seed_and_bump(
+    creator: &Pubkey,
+    id: &str,
+    seed: &[u8; 32],
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    let bump_arr = [bump];
+    let [prefix, creator_seed, id_seed] = Challenge::shank_seeds(creator, id);
+    Pubkey::create_program_address(
+        &[prefix, creator_seed, id_seed, seed, &bump_arr],
+        &challenge_id(),
+    )
+    .map_err(|err| {
+        msg!(
+            "Err: failed to derive seeded challenge PDA for creator ({}) and id ({}) with bump {}",
+            creator,
+            id,
+            bump
+        );
+        err.into()
+    })
+}
+
+/// PDA of the [challenger] attempting to solve the challenge at [challenge_pda] together
+/// with its bump.
+pub fn challenger_pda(
+    challenge_pda: &Pubkey,
+    challenger: &Pubkey,
+) -> (Pubkey, u8) {
+    Challenger::shank_pda(&challenge_id(), challenge_pda, challenger)
+}
+
+/// PDA of the redeem mint of the challenge at [challenge_pda] together with its bump.
+pub fn redeem_pda(challenge_pda: &Pubkey) -> (Pubkey, u8) {
+    Redeem::shank_pda(&challenge_id(), challenge_pda)
+}
+
+/// PDA of the prize pool of the challenge at [challenge_pda] together with its bump.
+pub fn prize_pool_pda(challenge_pda: &Pubkey) -> (Pubkey, u8) {
+    PrizePool::shank_pda(&challenge_id(), challenge_pda)
+}
+
+/// PDA of the program config together with its bump.
+pub fn config_pda() -> (Pubkey, u8) {
+    Config::shank_pda(&challenge_id())
+}
+
+/// Program data account of this program which holds its upgrade authority, derived by
+/// the upgradeable BPF loader.
+pub fn program_data_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[challenge_id().as_ref()],
+        &bpf_loader_upgradeable::id(),
+    )
+}
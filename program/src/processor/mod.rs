@@ -0,0 +1,6703 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::set_return_data,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::{self, Sysvar},
+};
+
+use crate::{
+    challenge_id, check_id,
+    clock::Now,
+    error::ChallengeError,
+    events::ChallengeEvent,
+    ixs::ChallengeInstruction,
+    log::{log_ix, log_state},
+    pda,
+    return_data::{CheckSolutionResult, RedeemResult},
+    state::{
+        AccountKind, Challenge, ChallengeHeader, ChallengeMetadata,
+        ChallengeTemplate, Challenger, Config, CreatorRegistry, FirstSolvers,
+        HasDiscriminator, HasPda, HasSize, HashAlgorithm, Leaderboard,
+        LeaderboardEntry, NftReward, Payout, PrizePool, Redeem, RevealedHints,
+        SolutionCommitment, SolutionNormalization, SolutionsUpload, SolveMode,
+        SponsorLog, StateFromPdaAccountValue, TemplateOverrides, Ticket,
+        TryStateFromAccount, TryStateFromPdaAccount, Winners,
+        CHALLENGE_VERSION, DEFAULT_CHALLENGE_FLAGS, DISCRIMINATOR_LEN,
+        UNACCOUNTED_CHALLENGE_VERSION,
+    },
+    utils::{
+        allocate_account_and_assign_owner, assert_account_does_not_exist,
+        assert_account_has_no_data, assert_account_metas,
+        assert_adding_non_empty, assert_admit_cost_not_locked,
+        assert_associated_token_program, assert_can_add_admin,
+        assert_can_add_after_start, assert_can_add_solutions,
+        assert_can_reveal, assert_can_reveal_hint, assert_challenge_matches,
+        assert_challenger_has_no_tries_remaining,
+        assert_challenger_has_tries_remaining, assert_challenger_redeemed,
+        assert_commit_reveal_not_required, assert_commitment_matches,
+        assert_completed_prerequisite, assert_creator_registry_not_full,
+        assert_ed25519_signature_verified, assert_escrow_empty,
+        assert_expected_solutions_chunk, assert_has_admit_mint,
+        assert_has_no_prize_pool, assert_has_oracle, assert_has_prize_pool,
+        assert_has_solution, assert_has_surplus, assert_hints_match_solutions,
+        assert_holds_ticket, assert_is_admin, assert_is_authority_or_admin,
+        assert_is_mint, assert_is_pending_authority, assert_is_signer,
+        assert_is_signer_or_delegated, assert_keys_equal,
+        assert_keys_not_equal, assert_known_challenge_flags,
+        assert_max_supported_solutions, assert_mint_with_authority,
+        assert_no_solutions_redeemed, assert_no_weak_solutions,
+        assert_not_cancelled, assert_not_completed, assert_not_finished,
+        assert_not_in_progress, assert_not_migrated, assert_not_paused,
+        assert_not_started, assert_owned_by, assert_prize_pool_covers_refund,
+        assert_program_upgrade_authority, assert_same_hash_algorithm,
+        assert_same_salt, assert_same_solution_normalization,
+        assert_solution_in_epoch, assert_solution_index_is_current,
+        assert_solutions_not_locked, assert_solve_window_open,
+        assert_spl_token_program, assert_started,
+        assert_state_fits_return_data, assert_system_program, assert_sysvar,
+        assert_valid_fee_bps, assert_valid_max_winners_per_solution,
+        assert_valid_metadata, assert_valid_schedule,
+        assert_valid_solution_index, assert_valid_solutions_chunk,
+        assert_valid_solutions_split_index, assert_valid_solve_window,
+        assert_within_max_tries_per_challenger, assert_within_schedule,
+        close_account, create_mint, mint_token_to_recvr, reallocate_account,
+        remove_mint_authority, resize_account_settling_rent,
+        shrink_account_refunding_rent, solution_commitment, solution_to_hex,
+        transfer_lamports, transfer_lamports_from_program_account,
+        transfer_tokens_to_recvr, AllocateAndAssignAccountArgs,
+        CloseAccountArgs, CreateMintArgs, MintTokenArgs, ReallocateAccountArgs,
+        RemoveMintAuthorityArgs, ResizeAccountArgs, ShrinkAccountArgs,
+        TransferTokensArgs, SIGNATURE_BYTES,
+    },
+    Hint, Solution,
+};
+
+mod transitions;
+pub use transitions::*;
+
+// -----------------
+// Processor Entry
+// -----------------
+pub fn process<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    check_id(program_id);
+
+    let instruction =
+        ChallengeInstruction::try_from_slice_checked(instruction_data)?;
+    let name = instruction.name();
+    assert_account_metas(name, accounts, instruction.account_specs())?;
+
+    #[cfg(feature = "debug-lamports")]
+    let lamports_before = crate::utils::total_lamports(accounts);
+
+    use ChallengeInstruction::*;
+    let result = match instruction {
+        CreateChallenge {
+            id,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+            solutions,
+            salt,
+            hash_algorithm,
+            starts_at,
+            ends_at,
+        } => process_create_challenge(
+            program_id,
+            accounts,
+            CreateChallengeArgs {
+                id,
+                admit_cost,
+                tries_per_admit,
+                redeem,
+                solutions,
+                salt,
+                hash_algorithm,
+                starts_at,
+                ends_at,
+                seed: None,
+            },
+            accounts.get(crate::accounts::create_challenge::CREATOR_REGISTRY),
+        ),
+        AddSolutions {
+            id,
+            solutions,
+            allow_duplicates,
+            rewards,
+        } => process_add_solutions(
+            program_id,
+            accounts,
+            id,
+            solutions,
+            allow_duplicates,
+            rewards,
+        ),
+        StartChallenge { id } => {
+            process_start_challenge(program_id, accounts, id)
+        }
+        AdmitChallenger {
+            challenge_pda,
+            allowlist_proof,
+        } => process_admit_challenger(
+            program_id,
+            accounts,
+            challenge_pda,
+            allowlist_proof,
+        ),
+        Redeem { solution } => process_redeem(program_id, accounts, solution),
+        SetSolveWindow {
+            id,
+            from_slot,
+            until_slot,
+        } => process_set_solve_window(
+            program_id, accounts, id, from_slot, until_slot,
+        ),
+        GetChallengeState => process_get_challenge_state(program_id, accounts),
+        SplitChallenge {
+            id,
+            new_id,
+            new_redeem,
+            split_at,
+        } => process_split_challenge(
+            program_id, accounts, id, new_id, new_redeem, split_at,
+        ),
+        TruncateSolutions { id, len } => {
+            process_truncate_solutions(program_id, accounts, id, len)
+        }
+        MergeChallenges { id, source_id } => {
+            process_merge_challenges(program_id, accounts, id, source_id)
+        }
+        LockAdmitCost { id } => {
+            process_lock_admit_cost(program_id, accounts, id)
+        }
+        CreateTemplate {
+            template_id,
+            admit_cost,
+            tries_per_admit,
+            salt,
+            hash_algorithm,
+            starts_at,
+            ends_at,
+        } => process_create_template(
+            program_id,
+            accounts,
+            template_id,
+            admit_cost,
+            tries_per_admit,
+            salt,
+            hash_algorithm,
+            starts_at,
+            ends_at,
+        ),
+        CreateChallengeFromTemplate {
+            template_id,
+            id,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+            solutions,
+            salt,
+            hash_algorithm,
+            starts_at,
+            ends_at,
+        } => process_create_challenge_from_template(
+            program_id,
+            accounts,
+            template_id,
+            id,
+            redeem,
+            solutions,
+            TemplateOverrides {
+                admit_cost,
+                tries_per_admit,
+                salt,
+                hash_algorithm,
+                starts_at,
+                ends_at,
+            },
+        ),
+        SetOracle { id, oracle } => {
+            process_set_oracle(program_id, accounts, id, oracle)
+        }
+        VerifySolveSignature {
+            solution_index,
+            oracle_signature,
+        } => process_verify_solve_signature(
+            program_id,
+            accounts,
+            solution_index,
+            oracle_signature,
+        ),
+        UpdateSolutionHash {
+            id,
+            index,
+            new_hash,
+        } => process_update_solution_hash(
+            program_id, accounts, id, index, new_hash,
+        ),
+        SetSolutionsLocked { id, locked } => {
+            process_set_solutions_locked(program_id, accounts, id, locked)
+        }
+        ReissueAdmission { challenge_pda } => {
+            process_reissue_admission(program_id, accounts, challenge_pda)
+        }
+        AddCoSponsor {
+            challenge_pda,
+            amount,
+        } => {
+            process_add_co_sponsor(program_id, accounts, challenge_pda, amount)
+        }
+        CreateLeaderboard { id } => {
+            process_create_leaderboard(program_id, accounts, id)
+        }
+        UpdateLeaderboard => process_update_leaderboard(program_id, accounts),
+        RemoveSolutions { id, indices } => {
+            process_remove_solutions(program_id, accounts, id, indices)
+        }
+        ReplaceSolutions { id, solutions } => {
+            process_replace_solutions(program_id, accounts, id, solutions)
+        }
+        CloseChallenge { id } => {
+            process_close_challenge(program_id, accounts, id)
+        }
+        TransferAuthority { id, new_authority } => {
+            process_transfer_authority(program_id, accounts, id, new_authority)
+        }
+        AcceptAuthority { id } => {
+            process_accept_authority(program_id, accounts, id)
+        }
+        SetPaused { id, paused } => {
+            process_set_paused(program_id, accounts, id, paused)
+        }
+        UpdateChallenge {
+            id,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+        } => process_update_challenge(
+            program_id,
+            accounts,
+            id,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+        ),
+        SetAdmitMint { id, admit_mint } => {
+            process_set_admit_mint(program_id, accounts, id, admit_mint)
+        }
+        WithdrawAdmitTokens { id } => {
+            process_withdraw_admit_tokens(program_id, accounts, id)
+        }
+        CreatePrizePool { id, payout } => {
+            process_create_prize_pool(program_id, accounts, id, payout)
+        }
+        WithdrawPrizePool { id } => {
+            process_withdraw_prize_pool(program_id, accounts, id)
+        }
+        SetNftReward { id, enabled } => {
+            process_set_nft_reward(program_id, accounts, id, enabled)
+        }
+        CommitSolution { commitment } => {
+            process_commit_solution(program_id, accounts, commitment)
+        }
+        RevealSolution { solution, nonce } => {
+            process_reveal_solution(program_id, accounts, solution, nonce)
+        }
+        SetCommitReveal { id, required } => {
+            process_set_commit_reveal(program_id, accounts, id, required)
+        }
+        SetAllowlist { id, root } => {
+            process_set_allowlist(program_id, accounts, id, root)
+        }
+        SetMaxAdmissions { id, max_admissions } => {
+            process_set_max_admissions(program_id, accounts, id, max_admissions)
+        }
+        CancelChallenge { id } => {
+            process_cancel_challenge(program_id, accounts, id)
+        }
+        RefundUnusedTries { id } => {
+            process_refund_unused_tries(program_id, accounts, id)
+        }
+        MigrateAccount { kind } => {
+            process_migrate_account(program_id, accounts, kind)
+        }
+        CreateFirstSolvers { id } => {
+            process_create_first_solvers(program_id, accounts, id)
+        }
+        AppendSolutionsChunk {
+            id,
+            chunk_index,
+            total_chunks,
+            solutions,
+        } => process_append_solutions_chunk(
+            program_id,
+            accounts,
+            id,
+            chunk_index,
+            total_chunks,
+            solutions,
+        ),
+        InitConfig {
+            fee_bps,
+            fee_destination,
+        } => {
+            process_init_config(program_id, accounts, fee_bps, fee_destination)
+        }
+        UpdateConfig {
+            fee_bps,
+            fee_destination,
+            admin,
+        } => process_update_config(
+            program_id,
+            accounts,
+            fee_bps,
+            fee_destination,
+            admin,
+        ),
+        SetSolveMode { id, solve_mode } => {
+            process_set_solve_mode(program_id, accounts, id, solve_mode)
+        }
+        AddAdmin { id, admin } => {
+            process_add_admin(program_id, accounts, id, admin)
+        }
+        RemoveAdmin { id, admin } => {
+            process_remove_admin(program_id, accounts, id, admin)
+        }
+        SetHints {
+            id,
+            hints,
+            hint_fee,
+        } => process_set_hints(program_id, accounts, id, hints, hint_fee),
+        RevealHint { solution_index } => {
+            process_reveal_hint(program_id, accounts, solution_index)
+        }
+        SetAttemptCooldown {
+            id,
+            attempt_cooldown_slots,
+        } => process_set_attempt_cooldown(
+            program_id,
+            accounts,
+            id,
+            attempt_cooldown_slots,
+        ),
+        CreateWinners {
+            id,
+            max_winners_per_solution,
+        } => process_create_winners(
+            program_id,
+            accounts,
+            id,
+            max_winners_per_solution,
+        ),
+        SetSolutionNormalization {
+            id,
+            solution_normalization,
+        } => process_set_solution_normalization(
+            program_id,
+            accounts,
+            id,
+            solution_normalization,
+        ),
+        CheckSolution { solution } => {
+            process_check_solution(program_id, accounts, solution)
+        }
+        SetRejectWeakSolutions {
+            id,
+            reject_weak_solutions,
+        } => process_set_reject_weak_solutions(
+            program_id,
+            accounts,
+            id,
+            reject_weak_solutions,
+        ),
+        SetTicketAdmission { id, enabled } => {
+            process_set_ticket_admission(program_id, accounts, id, enabled)
+        }
+        SetMetadata {
+            id,
+            name,
+            description,
+            uri,
+        } => process_set_metadata(
+            program_id,
+            accounts,
+            id,
+            name,
+            description,
+            uri,
+        ),
+        SetChallengeFlags { id, flags } => {
+            process_set_challenge_flags(program_id, accounts, id, flags)
+        }
+        CreateChallengeIdempotent {
+            id,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+            solutions,
+            salt,
+            hash_algorithm,
+            starts_at,
+            ends_at,
+        } => process_create_challenge_idempotent(
+            program_id,
+            accounts,
+            CreateChallengeArgs {
+                id,
+                admit_cost,
+                tries_per_admit,
+                redeem,
+                solutions,
+                salt,
+                hash_algorithm,
+                starts_at,
+                ends_at,
+                seed: None,
+            },
+            accounts.get(
+                crate::accounts::create_challenge_idempotent::CREATOR_REGISTRY,
+            ),
+        ),
+        CreateSeededChallenge {
+            id,
+            seed,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+            solutions,
+            salt,
+            hash_algorithm,
+            starts_at,
+            ends_at,
+        } => process_create_challenge(
+            program_id,
+            accounts,
+            CreateChallengeArgs {
+                id,
+                admit_cost,
+                tries_per_admit,
+                redeem,
+                solutions,
+                salt,
+                hash_algorithm,
+                starts_at,
+                ends_at,
+                seed: Some(seed),
+            },
+            accounts.get(
+                crate::accounts::create_seeded_challenge::CREATOR_REGISTRY,
+            ),
+        ),
+        BuyMoreTries {
+            challenge_pda,
+            count,
+        } => process_buy_more_tries(program_id, accounts, challenge_pda, count),
+        SetMaxTriesPerChallenger { id, max_tries } => {
+            process_set_max_tries_per_challenger(
+                program_id, accounts, id, max_tries,
+            )
+        }
+        SweepSurplus { id } => process_sweep_surplus(program_id, accounts, id),
+        SetPrerequisite { id, prerequisite } => {
+            process_set_prerequisite(program_id, accounts, id, prerequisite)
+        }
+        SetEpochs {
+            id,
+            epoch_slots,
+            solutions_per_epoch,
+        } => process_set_epochs(
+            program_id,
+            accounts,
+            id,
+            epoch_slots,
+            solutions_per_epoch,
+        ),
+    };
+
+    if result.is_err() {
+        msg!("Err: {} instruction failed", name);
+    }
+    #[cfg(feature = "debug-lamports")]
+    if result.is_ok() {
+        crate::utils::assert_lamports_conserved(
+            name,
+            accounts,
+            lamports_before,
+        )?;
+    }
+    result
+}
+
+// -----------------
+// Create Challenge
+// -----------------
+struct CreateChallengeArgs {
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    redeem: Pubkey,
+    solutions: Vec<Solution>,
+    salt: Option<[u8; 32]>,
+    hash_algorithm: HashAlgorithm,
+    starts_at: Option<i64>,
+    ends_at: Option<i64>,
+    seed: Option<[u8; 32]>,
+}
+
+/// The [creator_registry_info] is passed separately since it follows different accounts
+/// depending on the instruction creating the challenge.
+fn process_create_challenge<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: CreateChallengeArgs,
+    creator_registry_info: Option<&'a AccountInfo<'a>>,
+) -> ProgramResult {
+    log_ix!("create challenge");
+
+    let CreateChallengeArgs {
+        id,
+        admit_cost,
+        tries_per_admit,
+        redeem,
+        solutions,
+        salt,
+        hash_algorithm,
+        starts_at,
+        ends_at,
+        seed,
+    } = args;
+
+    assert_keys_equal(
+        "create_challenge:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    assert_max_supported_solutions("create_challenge", &solutions)?;
+    assert_valid_schedule("create_challenge", starts_at, ends_at)?;
+
+    // TODO(thlorenz): think about if we need to ensure that we don't allow
+    // pre-initialized accounts.
+    // Should not be an issue and would also fail when trying to create the
+    // account again.
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+
+    // TODO(thlorenz): make sure that the creator is rent excempt, as otherwise
+    // he won't be able to receive funds from admitted challengers
+    // Alternatively we can make sure of that here
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let redeem_pda_info = next_account_info(account_info_iter)?;
+    let spl_token_program_info = next_account_info(account_info_iter)?;
+
+    assert_keys_equal(
+        "create_challenge:redeem_pda",
+        redeem_pda_info.key,
+        &redeem,
+        ChallengeError::ProvidedRedeemIncorrect,
+        || {
+            format!(
+                "Provided redeem_account ({}) does not redeem key passed ({})",
+                redeem_pda_info.key, redeem
+            )
+        },
+    )?;
+
+    let bump = allocate_challenge_pda(AllocateChallengePdaArgs {
+        context: "create_challenge:challenge_pda",
+        program_id,
+        payer_info,
+        creator_info,
+        challenge_pda_info,
+        id: &id,
+        seed: seed.as_ref(),
+        size: Challenge::needed_size(&solutions, &id)
+            + Challenge::space_to_store_salt(salt)
+            + Challenge::space_to_store_schedule(starts_at, ends_at)
+            + Challenge::space_to_store_seed(seed),
+    })?;
+
+    create_redeem_mint(CreateRedeemMintArgs {
+        context: "create_challenge:redeem_pda",
+        payer_info,
+        challenge_pda_info,
+        redeem_pda_info,
+        spl_token_program_info,
+    })?;
+
+    // Serialize Challenge
+    let challenge = Challenge {
+        version: CHALLENGE_VERSION,
+        bump,
+        authority: *creator_info.key,
+        pending_authority: None,
+        original_authority: None,
+        admins: vec![],
+        id,
+        started: false,
+        finished: false,
+        paused: false,
+        cancelled: false,
+        admit_cost,
+        admit_cost_locked: false,
+        admit_mint: None,
+        has_prize_pool: false,
+        nft_reward: false,
+        has_first_solvers: false,
+        commit_reveal: false,
+        allowlist_root: None,
+        max_admissions: None,
+        admissions: 0,
+        salt,
+        hash_algorithm,
+        tries_per_admit,
+        redeem,
+        solving: 0,
+        solve_mode: SolveMode::Sequential,
+        solved: [0; 32],
+        solve_open_from_slot: None,
+        solve_open_until_slot: None,
+        starts_at,
+        ends_at,
+        oracle: Pubkey::default(),
+        solutions_locked: false,
+        hint_fee: 0,
+        attempt_cooldown_slots: 0,
+        max_winners_per_solution: 0,
+        solution_normalization: SolutionNormalization::NONE,
+        reject_weak_solutions: false,
+        ticket_admission: false,
+        flags: DEFAULT_CHALLENGE_FLAGS,
+        seed,
+        max_tries_per_challenger: None,
+        sponsored_lamports: 0,
+        prerequisite: None,
+        epoch_slots: 0,
+        epoch_start_slot: 0,
+        solutions_per_epoch: 0,
+        epoch: 0,
+        rewards: vec![],
+        solutions,
+        hints: vec![],
+    };
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    log_state!("Challenge account created and initialized");
+
+    if let Some(creator_registry_info) = creator_registry_info {
+        register_challenge(RegisterChallengeArgs {
+            context: "create_challenge:creator_registry",
+            program_id,
+            payer_info,
+            creator: creator_info.key,
+            challenge_pda: challenge_pda_info.key,
+            creator_registry_info,
+        })?;
+    }
+
+    ChallengeEvent::ChallengeCreated {
+        challenge_pda: *challenge_pda_info.key,
+        authority: challenge.authority,
+        id: challenge.id,
+        solutions: challenge.solutions.len() as u8,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct AllocateChallengePdaArgs<'a, 'b> {
+    context: &'b str,
+    program_id: &'a Pubkey,
+    payer_info: &'a AccountInfo<'a>,
+    creator_info: &'a AccountInfo<'a>,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    id: &'b str,
+    seed: Option<&'b [u8; 32]>,
+    size: usize,
+}
+
+/// Verifies the challenge PDA for creator, id and the optional user chosen seed and
+/// allocates it with the given size.
+/// Returns the bump of the PDA which is stored with the challenge.
+fn allocate_challenge_pda(
+    args: AllocateChallengePdaArgs,
+) -> Result<u8, ProgramError> {
+    let AllocateChallengePdaArgs {
+        context,
+        program_id,
+        payer_info,
+        creator_info,
+        challenge_pda_info,
+        id,
+        seed,
+        size,
+    } = args;
+
+    let (challenge_pda, bump) = match seed {
+        Some(seed) => pda::challenge_pda_with_seed(creator_info.key, id, seed),
+        None => pda::challenge_pda(creator_info.key, id),
+    };
+    let bump_arr = [bump];
+    let mut challenge_seeds =
+        Challenge::shank_seeds_with_bump(creator_info.key, id, &bump_arr)
+            .to_vec();
+    if let Some(seed) = seed {
+        challenge_seeds.insert(challenge_seeds.len() - 1, seed);
+    }
+
+    assert_keys_equal(
+        context,
+        challenge_pda_info.key,
+        &challenge_pda,
+        ChallengeError::ProvidedChallengePdaIncorrect,
+        || {
+            format!(
+            "PDA for the challenge for creator ({}), id ({}) and seed ({:?}) is incorrect",
+            creator_info.key, id, seed
+        )
+        },
+    )?;
+    assert_account_has_no_data(context, challenge_pda_info)?;
+
+    allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        owner: program_id,
+        signer_seeds: &challenge_seeds,
+        size,
+    })?;
+    Ok(bump)
+}
+
+struct CreateRedeemMintArgs<'a, 'b> {
+    context: &'b str,
+    payer_info: &'a AccountInfo<'a>,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    redeem_pda_info: &'a AccountInfo<'a>,
+    spl_token_program_info: &'a AccountInfo<'a>,
+}
+
+/// Verifies the redeem PDA derived from the challenge and creates the mint for it with the
+/// challenge PDA as mint authority.
+fn create_redeem_mint(args: CreateRedeemMintArgs) -> ProgramResult {
+    let CreateRedeemMintArgs {
+        context,
+        payer_info,
+        challenge_pda_info,
+        redeem_pda_info,
+        spl_token_program_info,
+    } = args;
+
+    let (redeem_pda, bump) =
+        Redeem::shank_pda(&challenge_id(), challenge_pda_info.key);
+    let bump_arr = [bump];
+    let redeem_seeds =
+        Redeem::shank_seeds_with_bump(challenge_pda_info.key, &bump_arr);
+
+    assert_keys_equal(
+        context,
+        redeem_pda_info.key,
+        &redeem_pda,
+        ChallengeError::ProvidedRedeemIncorrect,
+        || {
+            format!(
+            "PDA for the challenge redeem ('{}') is incorrect, should be '{}'",
+            redeem_pda_info.key, redeem_pda
+        )
+        },
+    )?;
+    assert_account_has_no_data(context, redeem_pda_info)?;
+    create_mint(CreateMintArgs {
+        payer_info,
+        mint_info: redeem_pda_info,
+        mint_authority_info: challenge_pda_info,
+        spl_token_program_info,
+        signer_seeds: &redeem_seeds,
+    })
+}
+
+struct RegisterChallengeArgs<'a, 'b> {
+    context: &'b str,
+    program_id: &'a Pubkey,
+    payer_info: &'a AccountInfo<'a>,
+    creator: &'b Pubkey,
+    challenge_pda: &'b Pubkey,
+    creator_registry_info: &'a AccountInfo<'a>,
+}
+
+/// Lists the challenge in the registry of its [creator], creating the registry the first
+/// time it is passed and growing it to fit the challenge otherwise.
+fn register_challenge(args: RegisterChallengeArgs) -> ProgramResult {
+    let RegisterChallengeArgs {
+        context,
+        program_id,
+        payer_info,
+        creator,
+        challenge_pda,
+        creator_registry_info,
+    } = args;
+
+    let (creator_registry_pda, bump) = CreatorRegistry::pda_for(creator);
+    assert_keys_equal(
+        context,
+        creator_registry_info.key,
+        &creator_registry_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the creator registry is not valid for the creator ({})",
+                creator_registry_info.key, creator
+            )
+        },
+    )?;
+
+    let is_new = creator_registry_info.data_is_empty();
+    let mut registry = if is_new {
+        CreatorRegistry::new(*creator)
+    } else {
+        creator_registry_info.try_state_from_account()?
+    };
+    if registry.is_registered(challenge_pda) {
+        return Ok(());
+    }
+    assert_creator_registry_not_full(context, &registry)?;
+    registry.challenges.push(*challenge_pda);
+
+    if is_new {
+        let bump_arr = [bump];
+        let seeds = CreatorRegistry::shank_seeds_with_bump(creator, &bump_arr);
+        allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+            payer_info,
+            account_info: creator_registry_info,
+            owner: program_id,
+            signer_seeds: &seeds,
+            size: registry.size(),
+        })?;
+    } else {
+        reallocate_account(ReallocateAccountArgs {
+            payer_info,
+            account_info: creator_registry_info,
+            new_size: registry.size(),
+            zero_init: false,
+        })?;
+    }
+
+    registry.serialize_into_account(creator_registry_info)
+}
+
+struct DeregisterChallengeArgs<'a, 'b> {
+    creator: &'b Pubkey,
+    challenge_pda: &'b Pubkey,
+    creator_registry_info: &'a AccountInfo<'a>,
+    recvr_info: &'a AccountInfo<'a>,
+}
+
+/// Removes the closed challenge from the registry of its [creator], shrinking the registry
+/// and moving the rent that is no longer needed to the [recvr_info].
+/// Challenges that were never listed are ignored.
+fn deregister_challenge(args: DeregisterChallengeArgs) -> ProgramResult {
+    let DeregisterChallengeArgs {
+        creator,
+        challenge_pda,
+        creator_registry_info,
+        recvr_info,
+    } = args;
+
+    let StateFromPdaAccountValue::<CreatorRegistry> {
+        state: mut registry,
+        ..
+    } = creator_registry_info
+        .try_state_from_pda_account(|| CreatorRegistry::pda_for(creator))?;
+    if !registry.deregister(challenge_pda) {
+        return Ok(());
+    }
+
+    shrink_account_refunding_rent(ShrinkAccountArgs {
+        account_info: creator_registry_info,
+        recvr_info,
+        new_size: registry.size(),
+        reserved_lamports: 0,
+    })?;
+    registry.serialize_into_account(creator_registry_info)
+}
+
+// -----------------
+// Add Solutions
+// -----------------
+fn process_add_solutions<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    extra_solutions: Vec<Solution>,
+    allow_duplicates: bool,
+    rewards: Vec<u64>,
+) -> ProgramResult {
+    log_ix!("add solutions");
+
+    assert_keys_equal(
+        "add_solutions:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+    assert_adding_non_empty("add_solutions", &extra_solutions)?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state(
+        "add_solutions:challenge_pda",
+        challenge_pda_info,
+        &id,
+    )?;
+    assert_is_authority_or_admin(
+        "add_solutions:creator",
+        &challenge,
+        creator_info,
+    )?;
+
+    // 1. append solutions
+    let added = extra_solutions.len() as u8;
+    let size_change = add_solutions(
+        &mut challenge,
+        extra_solutions,
+        allow_duplicates,
+        &rewards,
+    )?;
+
+    // 2. reallocate account to fit extra solutions, topping up exactly the lamports needed to
+    //    stay rent exempt at the new size
+    reallocate_account(ReallocateAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: size_change.after,
+        zero_init: false,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    ChallengeEvent::SolutionsAdded {
+        challenge_pda: *challenge_pda_info.key,
+        added,
+        total: challenge.solutions.len() as u8,
+    }
+    .emit()?;
+
+    // TODO(thlorenz): unfinish, if we now can redeem b providing solutions again
+
+    Ok(())
+}
+
+// -----------------
+// Start Challenge
+// -----------------
+fn process_start_challenge(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    id: String,
+) -> ProgramResult {
+    log_ix!("start challenge");
+
+    assert_keys_equal(
+        "start_challenge:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "start_challenge",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    start_challenge(&mut challenge, Now::from_sysvar()?.slot)?;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Admit Challenger
+// -----------------
+fn process_admit_challenger<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    challenge_pda: Pubkey,
+    allowlist_proof: Vec<[u8; 32]>,
+) -> ProgramResult {
+    log_ix!("admit challenger");
+
+    assert_keys_equal(
+        "admit_challenger:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+
+    assert_keys_equal(
+        "admit_challenger:challenge_pda",
+        challenge_pda_info.key,
+        &challenge_pda,
+        ChallengeError::ProvidedChallengePdaIncorrect,
+        || {
+            format!(
+            "Provided challenge pda ({}) does not match the PDA account ({}) provided in the instruction",
+            challenge_pda, challenge_pda_info.key
+        )
+        },
+    )?;
+    assert_account_does_not_exist(
+        "admit_challenger:challenger_pda",
+        challenger_pda_info,
+        "challenger PDA",
+    )?;
+
+    let mut challenge: Challenge =
+        challenge_pda_info.try_state_from_account()?;
+    // a recurring challenge that finished reopens once its epoch rolled over, the rollover
+    // is persisted together with the admission
+    let mut challenger = admit_challenger(
+        &mut challenge,
+        challenge_pda,
+        *challenger_info.key,
+        &allowlist_proof,
+        &Now::from_sysvar()?,
+    )?;
+    assert_keys_equal(
+        "admit_challenger:creator",
+        creator_info.key,
+        &challenge.authority,
+        ChallengeError::ProvidedCreatorIncorrect,
+        || {
+            format!(
+                "Provided creator ({}) is not the authority ({}) of the challenge",
+                creator_info.key, challenge.authority
+            )
+        },
+    )?;
+
+    // 1. create challenger account
+    let (pda, bump) = Challenger::shank_pda(
+        &challenge_id(),
+        &challenge_pda,
+        challenger_info.key,
+    );
+
+    assert_keys_equal(
+        "admit_challenger:challenger_pda",
+        challenger_pda_info.key,
+        &pda,
+        ChallengeError::ProvidedChallengerPdaIncorrect,
+        || {
+            format!(
+            "PDA account ({}) provided for the challenger is not a valid for this challenge",
+            challenger_pda_info.key
+        )
+        },
+    )?;
+
+    let bump_arr = [bump];
+    let seeds = Challenger::shank_seeds_with_bump(
+        &challenge_pda,
+        challenger_info.key,
+        &bump_arr,
+    );
+
+    let size = Challenger::size();
+    allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+        payer_info,
+        account_info: challenger_pda_info,
+        owner: program_id,
+        signer_seeds: &seeds,
+        size,
+    })?;
+
+    // 2. transfer admit cost to creator account or escrow
+    let escrowed = pay_admit_cost(PayAdmitCostArgs {
+        context: "admit_challenger",
+        challenge: &challenge,
+        cost: challenge.admit_cost,
+        payer_info,
+        creator_info,
+        challenge_pda_info,
+        account_info_iter,
+    })?;
+
+    // 3. initialize challenger account with the tries it was admitted with
+    challenger.record_escrowed(escrowed);
+
+    challenger.serialize_into_account(challenger_pda_info)?;
+
+    // 4. mint the ticket whose holder redeems with the tries of this admission
+    if challenge.ticket_admission {
+        mint_ticket(MintTicketArgs {
+            payer_info,
+            challenge_pda_info,
+            challenger_info,
+            challenger_pda_info,
+            challenge: &challenge,
+            account_info_iter,
+        })?;
+    }
+
+    // 5. verify that a gated challenge is entered by challengers that completed its
+    //    prerequisite, whose record is expected last
+    if let Some(prerequisite) = challenge.prerequisite {
+        verify_prerequisite_completed(
+            &prerequisite,
+            challenger_info,
+            next_account_info(account_info_iter)?,
+        )?;
+    }
+
+    // 6. persist the counted admission
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    ChallengeEvent::ChallengerAdmitted {
+        challenge_pda,
+        challenger: *challenger_info.key,
+        tries: challenger.tries_remaining,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Verifies that [prerequisite_challenger_pda_info] is the record of the challenger on the
+/// [prerequisite] challenge and that it redeemed a solution of it.
+fn verify_prerequisite_completed(
+    prerequisite: &Pubkey,
+    challenger_info: &AccountInfo,
+    prerequisite_challenger_pda_info: &AccountInfo,
+) -> ProgramResult {
+    let (pda, _) = Challenger::shank_pda(
+        &challenge_id(),
+        prerequisite,
+        challenger_info.key,
+    );
+    assert_keys_equal(
+        "admit_challenger:prerequisite_challenger_pda",
+        prerequisite_challenger_pda_info.key,
+        &pda,
+        ChallengeError::ProvidedChallengerPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the challenger of the prerequisite challenge should be ({})",
+                prerequisite_challenger_pda_info.key, pda
+            )
+        },
+    )?;
+
+    let record: Option<Challenger> =
+        if prerequisite_challenger_pda_info.data_is_empty() {
+            None
+        } else {
+            assert_owned_by(
+                "admit_challenger:prerequisite_challenger_pda",
+                prerequisite_challenger_pda_info,
+                &challenge_id(),
+                "prerequisite challenger",
+            )?;
+            Some(prerequisite_challenger_pda_info.try_state_from_account()?)
+        };
+    assert_completed_prerequisite(
+        "admit_challenger",
+        prerequisite,
+        challenger_info.key,
+        record.as_ref(),
+    )
+}
+
+struct MintTicketArgs<'a, 'b> {
+    payer_info: &'a AccountInfo<'a>,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    challenger_info: &'a AccountInfo<'a>,
+    challenger_pda_info: &'a AccountInfo<'a>,
+    challenge: &'b Challenge,
+    account_info_iter: &'b mut std::slice::Iter<'a, AccountInfo<'a>>,
+}
+
+/// Creates the ticket mint of the admission of the challenger, mints its single token to
+/// the challenger and removes the mint authority so that no more can be minted.
+/// The ticket accounts are expected last, following the accounts used to pay the admit cost.
+fn mint_ticket(args: MintTicketArgs) -> ProgramResult {
+    let MintTicketArgs {
+        payer_info,
+        challenge_pda_info,
+        challenger_info,
+        challenger_pda_info,
+        challenge,
+        account_info_iter,
+    } = args;
+
+    let ticket_mint_info = next_account_info(account_info_iter)?;
+    let ticket_ata_info = next_account_info(account_info_iter)?;
+    let spl_token_program_info = next_account_info(account_info_iter)?;
+    let spl_associated_token_program_info =
+        next_account_info(account_info_iter)?;
+
+    assert_associated_token_program(
+        "admit_challenger:associated_token_program",
+        spl_associated_token_program_info,
+    )?;
+
+    let (ticket_pda, bump) =
+        Ticket::shank_pda(&challenge_id(), challenger_pda_info.key);
+    assert_keys_equal(
+        "admit_challenger:ticket_mint",
+        ticket_mint_info.key,
+        &ticket_pda,
+        ChallengeError::ProvidedMintIncorrect,
+        || {
+            format!(
+                "PDA for the ticket ('{}') is incorrect, should be '{}'",
+                ticket_mint_info.key, ticket_pda
+            )
+        },
+    )?;
+    assert_account_has_no_data(
+        "admit_challenger:ticket_mint",
+        ticket_mint_info,
+    )?;
+
+    let bump_arr = [bump];
+    let ticket_seeds =
+        Ticket::shank_seeds_with_bump(challenger_pda_info.key, &bump_arr);
+    let challenge_bump_arr = [challenge.bump];
+    let challenge_seeds = challenge.seeds(&challenge_bump_arr);
+
+    create_mint(CreateMintArgs {
+        payer_info,
+        mint_info: ticket_mint_info,
+        mint_authority_info: challenge_pda_info,
+        spl_token_program_info,
+        signer_seeds: &ticket_seeds,
+    })?;
+    mint_token_to_recvr(MintTokenArgs {
+        payer_info,
+        recvr_info: challenger_info,
+        recvr_ata_info: ticket_ata_info,
+        mint_info: ticket_mint_info,
+        mint_authority_info: challenge_pda_info,
+        spl_token_program_info,
+        signer_seeds: &challenge_seeds,
+    })?;
+    remove_mint_authority(RemoveMintAuthorityArgs {
+        mint_info: ticket_mint_info,
+        mint_authority_info: challenge_pda_info,
+        spl_token_program_info,
+        signer_seeds: &challenge_seeds,
+    })
+}
+
+struct PayAdmitCostArgs<'a, 'b> {
+    context: &'b str,
+    challenge: &'b Challenge,
+    cost: u64,
+    payer_info: &'a AccountInfo<'a>,
+    creator_info: &'a AccountInfo<'a>,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    account_info_iter: &'b mut std::slice::Iter<'a, AccountInfo<'a>>,
+}
+
+/// Transfers the [cost], i.e. the admit cost, from the payer, either in lamports to the
+/// creator, in lamports to the prize pool if the challenge has one or, if the challenge has
+/// an admit mint, in its tokens to the ATA of the challenge PDA.
+/// The system program and prize pool accounts are followed by the admit mint accounts if the
+/// challenge has one and then the program config.
+/// Once the config is initialized the protocol fee is split off the cost and paid to the fee
+/// destination, whose accounts are expected last.
+/// Returns the part of the cost that went into the prize pool or escrow and thus can be
+/// refunded, see [Challenger::refundable].
+fn pay_admit_cost(args: PayAdmitCostArgs) -> Result<u64, ProgramError> {
+    let PayAdmitCostArgs {
+        context,
+        challenge,
+        cost,
+        payer_info,
+        creator_info,
+        challenge_pda_info,
+        account_info_iter,
+    } = args;
+
+    let system_program_info = next_account_info(account_info_iter)?;
+    let prize_pool_info = next_account_info(account_info_iter)?;
+
+    assert_system_program(context, system_program_info)?;
+
+    let admit_mint = match challenge.admit_mint {
+        Some(admit_mint) => Some(admit_mint_accounts(
+            context,
+            &admit_mint,
+            account_info_iter,
+        )?),
+        None => None,
+    };
+
+    let config_info = next_account_info(account_info_iter)?;
+    let config = config_state(context, config_info)?;
+    let fee = config.as_ref().map_or(0, |config| config.fee_for(cost));
+    let amount = cost - fee;
+
+    let AdmitMintAccounts {
+        admit_mint_info,
+        payer_ata_info,
+        escrow_ata_info,
+        spl_token_program_info,
+    } = match admit_mint {
+        Some(admit_mint) => admit_mint,
+        None => {
+            let (recvr_info, escrowed) = if challenge.has_prize_pool {
+                prize_pool_state(context, challenge_pda_info, prize_pool_info)?;
+                (prize_pool_info, amount)
+            } else {
+                (creator_info, 0)
+            };
+            transfer_lamports(payer_info, recvr_info, amount)?;
+
+            if let Some(config) = config {
+                let fee_destination_info = fee_destination_account(
+                    context,
+                    &config,
+                    account_info_iter,
+                )?;
+                if fee > 0 {
+                    transfer_lamports(payer_info, fee_destination_info, fee)?;
+                }
+            }
+            return Ok(escrowed);
+        }
+    };
+
+    transfer_tokens_to_recvr(TransferTokensArgs {
+        payer_info,
+        source_owner_info: payer_info,
+        source_ata_info: payer_ata_info,
+        recvr_info: challenge_pda_info,
+        recvr_ata_info: escrow_ata_info,
+        mint_info: admit_mint_info,
+        spl_token_program_info,
+        amount,
+        signer_seeds: None,
+    })?;
+
+    if let Some(config) = config {
+        let fee_destination_info =
+            fee_destination_account(context, &config, account_info_iter)?;
+        let fee_destination_ata_info = next_account_info(account_info_iter)?;
+        if fee > 0 {
+            transfer_tokens_to_recvr(TransferTokensArgs {
+                payer_info,
+                source_owner_info: payer_info,
+                source_ata_info: payer_ata_info,
+                recvr_info: fee_destination_info,
+                recvr_ata_info: fee_destination_ata_info,
+                mint_info: admit_mint_info,
+                spl_token_program_info,
+                amount: fee,
+                signer_seeds: None,
+            })?;
+        }
+    }
+
+    Ok(amount)
+}
+
+struct AdmitMintAccounts<'a> {
+    admit_mint_info: &'a AccountInfo<'a>,
+    payer_ata_info: &'a AccountInfo<'a>,
+    escrow_ata_info: &'a AccountInfo<'a>,
+    spl_token_program_info: &'a AccountInfo<'a>,
+}
+
+/// Takes the accounts needed to pay the admit cost in tokens of the [admit_mint].
+fn admit_mint_accounts<'a>(
+    context: &str,
+    admit_mint: &Pubkey,
+    account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'a>>,
+) -> Result<AdmitMintAccounts<'a>, ProgramError> {
+    let admit_mint_info = next_account_info(account_info_iter)?;
+    let payer_ata_info = next_account_info(account_info_iter)?;
+    let escrow_ata_info = next_account_info(account_info_iter)?;
+    let spl_token_program_info = next_account_info(account_info_iter)?;
+    let spl_associated_token_program_info =
+        next_account_info(account_info_iter)?;
+
+    assert_associated_token_program(
+        context,
+        spl_associated_token_program_info,
+    )?;
+
+    assert_keys_equal(
+        context,
+        admit_mint_info.key,
+        admit_mint,
+        ChallengeError::ProvidedMintIncorrect,
+        || {
+            format!(
+            "Provided admit mint ({}) does not match the admit mint ({}) of the challenge",
+            admit_mint_info.key, admit_mint
+        )
+        },
+    )?;
+    assert_spl_token_program(context, spl_token_program_info)?;
+
+    Ok(AdmitMintAccounts {
+        admit_mint_info,
+        payer_ata_info,
+        escrow_ata_info,
+        spl_token_program_info,
+    })
+}
+
+/// Verifies the program config account and returns its state if it was initialized.
+fn config_state(
+    context: &str,
+    config_info: &AccountInfo,
+) -> Result<Option<Config>, ProgramError> {
+    let (config_pda, _) = Config::pda();
+    assert_keys_equal(
+        context,
+        config_info.key,
+        &config_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+            "Provided config ({}) does not match the program config PDA ({})",
+            config_info.key, config_pda
+        )
+        },
+    )?;
+    if config_info.data_is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(config_info.try_state_from_account()?))
+    }
+}
+
+/// Takes the fee destination account verifying it matches the one of the [config].
+fn fee_destination_account<'a>(
+    context: &str,
+    config: &Config,
+    account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'a>>,
+) -> Result<&'a AccountInfo<'a>, ProgramError> {
+    let fee_destination_info = next_account_info(account_info_iter)?;
+    assert_keys_equal(
+        context,
+        fee_destination_info.key,
+        &config.fee_destination,
+        ChallengeError::ProvidedFeeDestinationIncorrect,
+        || {
+            format!(
+                "Provided fee destination ({}) does not match the one of the config ({})",
+                fee_destination_info.key, config.fee_destination
+            )
+        },
+    )?;
+    Ok(fee_destination_info)
+}
+
+// -----------------
+// Redeem by proposing solution
+// -----------------
+fn process_redeem<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    solution: Solution,
+) -> ProgramResult {
+    log_ix!("redeem");
+    redeem_with_solution(program_id, accounts, solution, None)
+}
+
+/// Shared by `Redeem` and `RevealSolution` which take the same accounts except that the
+/// latter also expects the solution commitment PDA right after the system program.
+/// When revealing, i.e. a [nonce] is provided, the solution is only accepted if it matches
+/// the commitment which is closed afterwards.
+/// The outcome is set as return data, see [RedeemResult].
+fn redeem_with_solution<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    solution: Solution,
+    nonce: Option<[u8; 32]>,
+) -> ProgramResult {
+    assert_keys_equal(
+        "redeem:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    // challenger
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+
+    // redeem
+    let redeem_info = next_account_info(account_info_iter)?;
+    let redeem_ata_challenger_info = next_account_info(account_info_iter)?;
+
+    // programs
+    let spl_token_program_info = next_account_info(account_info_iter)?;
+    let spl_associated_token_program_info =
+        next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_spl_token_program("redeem:token_program", spl_token_program_info)?;
+    assert_associated_token_program(
+        "redeem:associated_token_program",
+        spl_associated_token_program_info,
+    )?;
+    assert_system_program("redeem:system_program", system_program_info)?;
+
+    // commitment, only provided when revealing
+    let commitment_pda_info = match nonce {
+        Some(_) => Some(next_account_info(account_info_iter)?),
+        None => None,
+    };
+
+    // prize pool, only required if the challenge has one
+    let prize_pool_info = next_account_info(account_info_iter).ok();
+
+    assert_is_signer("redeem:payer", payer_info, "payer")?;
+    assert_is_signer("redeem:challenger", challenger_info, "challenger")?;
+
+    // only the current solution is read from the account data, see ChallengeHeader
+    let mut header = ChallengeHeader::try_from_account(challenge_pda_info)?;
+    let now = Now::from_sysvar()?;
+    // the rollover is written right away since incorrect guesses don't write the header
+    if header.roll_over_epoch(&challenge_pda_info.try_borrow_data()?, now.slot)
+    {
+        log_state!(
+            "Challenge rolled over into epoch {}",
+            header.challenge.epoch
+        );
+        header.serialize_into_account(challenge_pda_info)?;
+    }
+    let challenge = &header.challenge;
+
+    // with tickets the holder redeems with the tries of the admission of the ticket, see
+    // assert_holds_ticket below, otherwise deriving the PDA from the signer ensures that only
+    // the challenger can use its tries
+    let mut challenger: Challenger = if challenge.ticket_admission {
+        challenger_pda_info.try_state_from_account()?
+    } else {
+        challenger_pda_info
+            .try_state_from_pda_account(|| {
+                Challenger::shank_pda(
+                    &challenge_id(),
+                    challenge_pda_info.key,
+                    challenger_info.key,
+                )
+            })?
+            .state
+    };
+
+    assert_keys_equal(
+        "redeem:challenge_pda",
+        &challenger.challenge_pda,
+        challenge_pda_info.key,
+        ChallengeError::ProvidedChallengePdaIncorrect,
+        || {
+            format!(
+            "Challenge pda ({}) of provided callenger does not match the PDA account ({}) for which you are trying to redeem",
+            &challenger.challenge_pda, challenge_pda_info.key
+        )
+        },
+    )?;
+
+    // TODO(thlorenz): Technically the challenger would not have been admitted if the challenge
+    // wasn't already started, so might not need this check
+    assert_started("redeem", challenge)?;
+    assert_not_completed("redeem", challenge)?;
+    assert_not_paused("redeem", challenge)?;
+    assert_solve_window_open("redeem", challenge, now.slot)?;
+    assert_within_schedule("redeem", challenge, now.unix_timestamp)?;
+
+    let RewardAccounts {
+        nft_mint_info,
+        nft_ata_info,
+        first_solvers_info,
+        winners_info,
+    } = reward_accounts(challenge, account_info_iter)?;
+    if challenge.ticket_admission {
+        assert_holds_ticket(
+            "redeem:ticket_ata",
+            next_account_info(account_info_iter)?,
+            challenger_info.key,
+            challenger_pda_info.key,
+        )?;
+    }
+
+    assert_keys_equal(
+        "redeem:redeem",
+        redeem_info.key,
+        &challenge.redeem,
+        ChallengeError::ProvidedRedeemIncorrect,
+        || {
+            format!(
+            "Provided redeem ({}) does not match the redeem ({}) for the challenge",
+            redeem_info.key, challenge.redeem
+        )
+        },
+    )?;
+
+    // incorrect guesses use up a try as well
+    attempt_solve("redeem", challenge, &mut challenger, now.slot)?;
+    assert_has_solution("redeem", &header)?;
+
+    if let (Some(nonce), Some(commitment_pda_info)) =
+        (nonce, commitment_pda_info)
+    {
+        consume_commitment(ConsumeCommitmentArgs {
+            payer_info,
+            challenger_info,
+            challenger_pda_info,
+            commitment_pda_info,
+            solution: &solution,
+            nonce: &nonce,
+            slot: now.slot,
+        })?;
+    } else {
+        assert_commit_reveal_not_required("redeem", challenge)?;
+    }
+
+    let solution_index = header.matching_solution_index(
+        &challenge_pda_info.try_borrow_data()?,
+        &solution,
+    );
+    if let Some(solution_index) = solution_index {
+        assert_solution_in_epoch(
+            "redeem",
+            &header.challenge,
+            header.solutions_len,
+            solution_index,
+        )?;
+    }
+    let reward_paid = match solution_index {
+        Some(solution_index) => redeem_solution(RedeemSolutionArgs {
+            payer_info,
+            challenge_pda_info,
+            challenger_info,
+            redeem_info,
+            redeem_ata_challenger_info,
+            spl_token_program_info,
+            prize_pool_info,
+            nft_mint_info,
+            nft_ata_info,
+            first_solvers_info,
+            winners_info,
+            solution_index,
+            slot: now.slot,
+            header: &mut header,
+            challenger: &mut challenger,
+        })?,
+        None => {
+            log_state!("Provided solution was incorrect");
+            0
+        }
+    };
+
+    // in all cases serialize the challenger whose try was used up
+    challenger.serialize_into_account(challenger_pda_info)?;
+
+    // incorrect guesses burn a try without failing the transaction so that they show up
+    // in the attempt history of the challenger
+    if solution_index.is_none() {
+        ChallengeEvent::GuessFailed {
+            challenge_pda: *challenge_pda_info.key,
+            challenger: *challenger_info.key,
+            guess: solution,
+            tries_remaining: challenger.tries_remaining,
+        }
+        .emit()?;
+    }
+
+    RedeemResult {
+        solved_index: solution_index,
+        remaining: header.remaining_solutions(),
+        reward_paid,
+    }
+    .set()?;
+
+    Ok(())
+}
+
+struct ConsumeCommitmentArgs<'a, 'b> {
+    payer_info: &'a AccountInfo<'a>,
+    challenger_info: &'a AccountInfo<'a>,
+    challenger_pda_info: &'a AccountInfo<'a>,
+    commitment_pda_info: &'a AccountInfo<'a>,
+    solution: &'b Solution,
+    nonce: &'b [u8; 32],
+    slot: u64,
+}
+
+/// Verifies that the revealed solution and nonce match the challenger's commitment and
+/// closes the commitment, refunding its rent to the payer.
+fn consume_commitment(args: ConsumeCommitmentArgs) -> ProgramResult {
+    let ConsumeCommitmentArgs {
+        payer_info,
+        challenger_info,
+        challenger_pda_info,
+        commitment_pda_info,
+        solution,
+        nonce,
+        slot,
+    } = args;
+
+    let (commitment_pda, _) =
+        SolutionCommitment::pda_for(challenger_pda_info.key);
+    assert_keys_equal(
+        "reveal_solution:commitment_pda",
+        commitment_pda_info.key,
+        &commitment_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the commitment is not valid for this challenger",
+                commitment_pda_info.key
+            )
+        },
+    )?;
+    let commitment: SolutionCommitment =
+        commitment_pda_info.try_state_from_account()?;
+
+    assert_can_reveal("reveal_solution", &commitment, slot)?;
+    assert_commitment_matches(
+        "reveal_solution",
+        &commitment,
+        &solution_commitment(challenger_info.key, solution, nonce),
+    )?;
+
+    close_account(CloseAccountArgs {
+        account_info: commitment_pda_info,
+        recvr_info: payer_info,
+    })
+}
+
+struct RewardAccounts<'a> {
+    nft_mint_info: Option<&'a AccountInfo<'a>>,
+    nft_ata_info: Option<&'a AccountInfo<'a>>,
+    first_solvers_info: Option<&'a AccountInfo<'a>>,
+    winners_info: Option<&'a AccountInfo<'a>>,
+}
+
+/// Takes the optional accounts that follow the prize pool when redeeming.
+/// Which ones are expected depends on the [challenge], namely the NFT mint and ATA if it
+/// rewards NFTs followed by the first solvers PDA if it has one and the winners PDA if it
+/// allows multiple winners per solution.
+fn reward_accounts<'a>(
+    challenge: &Challenge,
+    account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'a>>,
+) -> Result<RewardAccounts<'a>, ProgramError> {
+    let (nft_mint_info, nft_ata_info) = if challenge.nft_reward {
+        (
+            Some(next_account_info(account_info_iter)?),
+            Some(next_account_info(account_info_iter)?),
+        )
+    } else {
+        (None, None)
+    };
+    let first_solvers_info = if challenge.has_first_solvers {
+        Some(next_account_info(account_info_iter)?)
+    } else {
+        None
+    };
+    let winners_info = if challenge.has_multiple_winners() {
+        Some(next_account_info(account_info_iter)?)
+    } else {
+        None
+    };
+    Ok(RewardAccounts {
+        nft_mint_info,
+        nft_ata_info,
+        first_solvers_info,
+        winners_info,
+    })
+}
+
+struct RedeemSolutionArgs<'a, 'b> {
+    payer_info: &'a AccountInfo<'a>,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    challenger_info: &'a AccountInfo<'a>,
+    redeem_info: &'a AccountInfo<'a>,
+    redeem_ata_challenger_info: &'a AccountInfo<'a>,
+    spl_token_program_info: &'a AccountInfo<'a>,
+    prize_pool_info: Option<&'a AccountInfo<'a>>,
+    nft_mint_info: Option<&'a AccountInfo<'a>>,
+    nft_ata_info: Option<&'a AccountInfo<'a>>,
+    first_solvers_info: Option<&'a AccountInfo<'a>>,
+    winners_info: Option<&'a AccountInfo<'a>>,
+    solution_index: u8,
+    slot: u64,
+    header: &'b mut ChallengeHeader,
+    challenger: &'b mut Challenger,
+}
+
+/// Marks the solution at [solution_index] as solved which advances the challenge to the next
+/// solution unless it is solved in any order, marks the challenger as redeemed and mints the
+/// redeem token to the challenger.
+/// If the challenge has a prize pool the challenger is also paid out from it and if it
+/// rewards NFTs the challenger also receives the NFT for the solution.
+/// If it has a first solvers leaderboard the challenger is recorded as the solver.
+/// The challenger is updated but not serialized.
+/// Returns the lamports paid out from the prize pool.
+fn redeem_solution(args: RedeemSolutionArgs) -> Result<u64, ProgramError> {
+    let RedeemSolutionArgs {
+        payer_info,
+        challenge_pda_info,
+        challenger_info,
+        redeem_info,
+        redeem_ata_challenger_info,
+        spl_token_program_info,
+        prize_pool_info,
+        nft_mint_info,
+        nft_ata_info,
+        first_solvers_info,
+        winners_info,
+        solution_index,
+        header,
+        slot,
+        challenger,
+    } = args;
+
+    // record the winner first to know how many challengers still share the prize
+    let winners_before = match winners_info {
+        Some(winners_info) => record_winner(RecordWinnerArgs {
+            payer_info,
+            challenge_pda_info,
+            winners_info,
+            winner: challenger_info.key,
+            solution_index,
+        })?,
+        None => 0,
+    };
+    let winners_per_solution = header.challenge.winners_per_solution();
+
+    // pay out prize before the solution counts as solved
+    let mut reward_paid = 0;
+    if header.challenge.has_prize_pool {
+        let prize_pool_info =
+            prize_pool_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        reward_paid = pay_out_prize(PayOutPrizeArgs {
+            header,
+            solution_index,
+            winners_left: winners_per_solution.saturating_sub(winners_before),
+            challenge_pda_info,
+            prize_pool_info,
+            challenger_info,
+        })?;
+    }
+
+    // update challenge and challenger, only the header of the challenge is written since
+    // the solutions don't change
+    // the solution stays redeemable until all its winners solved it
+    if solve(header, challenger, solution_index, winners_before)? {
+        if header.challenge.finished {
+            log_state!("Challenge completed, no more player will be admitted or solutions accepted");
+        }
+        header.serialize_into_account(challenge_pda_info)?;
+    }
+    let challenge = &header.challenge;
+
+    ChallengeEvent::SolutionRedeemed {
+        challenge_pda: *challenge_pda_info.key,
+        challenger: *challenger_info.key,
+        solution_index,
+    }
+    .emit()?;
+
+    let bump_arr = [challenge.bump];
+    let challenge_seeds = challenge.seeds(&bump_arr);
+
+    mint_token_to_recvr(MintTokenArgs {
+        payer_info,
+        recvr_info: challenger_info,
+        recvr_ata_info: redeem_ata_challenger_info,
+        mint_info: redeem_info,
+        mint_authority_info: challenge_pda_info,
+        spl_token_program_info,
+        signer_seeds: &challenge_seeds,
+    })?;
+
+    // only the first winner of a solution receives its NFT
+    if challenge.nft_reward && winners_before == 0 {
+        mint_nft_reward(MintNftRewardArgs {
+            payer_info,
+            challenge_pda_info,
+            challenger_info,
+            nft_mint_info: nft_mint_info
+                .ok_or(ProgramError::NotEnoughAccountKeys)?,
+            nft_ata_info: nft_ata_info
+                .ok_or(ProgramError::NotEnoughAccountKeys)?,
+            spl_token_program_info,
+            solution_index,
+            challenge_seeds: &challenge_seeds,
+        })?;
+    }
+
+    if challenge.has_first_solvers {
+        record_first_solver(RecordFirstSolverArgs {
+            payer_info,
+            challenge_pda_info,
+            first_solvers_info: first_solvers_info
+                .ok_or(ProgramError::NotEnoughAccountKeys)?,
+            solver: challenger_info.key,
+            solution_index,
+            slot,
+        })?;
+    }
+
+    Ok(reward_paid)
+}
+
+struct RecordWinnerArgs<'a, 'b> {
+    payer_info: &'a AccountInfo<'a>,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    winners_info: &'a AccountInfo<'a>,
+    winner: &'b Pubkey,
+    solution_index: u8,
+}
+
+/// Records the [winner] of the solution at [solution_index] in the winners leaderboard,
+/// growing it as needed.
+///
+/// Returns the number of challengers that solved the solution before the [winner].
+fn record_winner(args: RecordWinnerArgs) -> Result<u8, ProgramError> {
+    let RecordWinnerArgs {
+        payer_info,
+        challenge_pda_info,
+        winners_info,
+        winner,
+        solution_index,
+    } = args;
+
+    let StateFromPdaAccountValue::<Winners> {
+        state: mut winners, ..
+    } = winners_info.try_state_from_pda_account(|| {
+        Winners::pda_for(challenge_pda_info.key)
+    })?;
+
+    let winners_before = winners.count(solution_index);
+    if !winners.record(solution_index, *winner) {
+        msg!(
+            "Err: challenger ({}) already solved solution {}",
+            winner,
+            solution_index
+        );
+        return Err(ChallengeError::SolutionAlreadySolvedByChallenger.into());
+    }
+
+    if winners.size() > winners_info.data_len() {
+        reallocate_account(ReallocateAccountArgs {
+            payer_info,
+            account_info: winners_info,
+            new_size: winners.size(),
+            zero_init: false,
+        })?;
+    }
+    winners.serialize_into_account(winners_info)?;
+
+    Ok(winners_before)
+}
+
+struct RecordFirstSolverArgs<'a, 'b> {
+    payer_info: &'a AccountInfo<'a>,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    first_solvers_info: &'a AccountInfo<'a>,
+    solver: &'b Pubkey,
+    solution_index: u8,
+    slot: u64,
+}
+
+/// Records the [solver] of the solution at [solution_index] in the first solvers
+/// leaderboard, growing it if solutions were added after it was created.
+fn record_first_solver(args: RecordFirstSolverArgs) -> ProgramResult {
+    let RecordFirstSolverArgs {
+        payer_info,
+        challenge_pda_info,
+        first_solvers_info,
+        solver,
+        solution_index,
+        slot,
+    } = args;
+
+    let StateFromPdaAccountValue::<FirstSolvers> {
+        state: mut first_solvers,
+        ..
+    } = first_solvers_info.try_state_from_pda_account(|| {
+        FirstSolvers::pda_for(challenge_pda_info.key)
+    })?;
+
+    if !first_solvers.record(solution_index, *solver, slot) {
+        log_state!(
+            "Solution {} already has a first solver recorded",
+            solution_index
+        );
+        return Ok(());
+    }
+
+    if first_solvers.size() > first_solvers_info.data_len() {
+        reallocate_account(ReallocateAccountArgs {
+            payer_info,
+            account_info: first_solvers_info,
+            new_size: first_solvers.size(),
+            zero_init: false,
+        })?;
+    }
+    first_solvers.serialize_into_account(first_solvers_info)?;
+
+    Ok(())
+}
+
+struct MintNftRewardArgs<'a, 'b> {
+    payer_info: &'a AccountInfo<'a>,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    challenger_info: &'a AccountInfo<'a>,
+    nft_mint_info: &'a AccountInfo<'a>,
+    nft_ata_info: &'a AccountInfo<'a>,
+    spl_token_program_info: &'a AccountInfo<'a>,
+    solution_index: u8,
+    challenge_seeds: &'b [&'b [u8]],
+}
+
+/// Creates the NFT mint for the solution at [solution_index], mints its single token to the
+/// challenger and removes the mint authority so that no more can be minted.
+fn mint_nft_reward(args: MintNftRewardArgs) -> ProgramResult {
+    let MintNftRewardArgs {
+        payer_info,
+        challenge_pda_info,
+        challenger_info,
+        nft_mint_info,
+        nft_ata_info,
+        spl_token_program_info,
+        solution_index,
+        challenge_seeds,
+    } = args;
+
+    let (nft_mint_pda, bump) = NftReward::shank_pda(
+        &challenge_id(),
+        challenge_pda_info.key,
+        solution_index,
+    );
+    assert_keys_equal(
+        "redeem:nft_mint",
+        nft_mint_info.key,
+        &nft_mint_pda,
+        ChallengeError::ProvidedMintIncorrect,
+        || {
+            format!(
+                "PDA for the NFT reward ('{}') is incorrect, should be '{}'",
+                nft_mint_info.key, nft_mint_pda
+            )
+        },
+    )?;
+    assert_account_has_no_data("redeem:nft_mint", nft_mint_info)?;
+
+    let solution_index_arr = [solution_index];
+    let bump_arr = [bump];
+    let nft_seeds = NftReward::shank_seeds_with_bump(
+        challenge_pda_info.key,
+        &solution_index_arr,
+        &bump_arr,
+    );
+
+    create_mint(CreateMintArgs {
+        payer_info,
+        mint_info: nft_mint_info,
+        mint_authority_info: challenge_pda_info,
+        spl_token_program_info,
+        signer_seeds: &nft_seeds,
+    })?;
+    mint_token_to_recvr(MintTokenArgs {
+        payer_info,
+        recvr_info: challenger_info,
+        recvr_ata_info: nft_ata_info,
+        mint_info: nft_mint_info,
+        mint_authority_info: challenge_pda_info,
+        spl_token_program_info,
+        signer_seeds: challenge_seeds,
+    })?;
+    remove_mint_authority(RemoveMintAuthorityArgs {
+        mint_info: nft_mint_info,
+        mint_authority_info: challenge_pda_info,
+        spl_token_program_info,
+        signer_seeds: challenge_seeds,
+    })
+}
+
+/// Pays the challenger that is redeeming a solution their share of the prize pool.
+/// Challenges solved in any order have no last solution, thus each solution is paid out
+/// per solution regardless of the payout of the pool.
+/// If the solutions are rewarded individually the reward of the solution at
+/// [solution_index] is paid instead, limited to what the pool holds.
+/// Returns the lamports that were paid out.
+struct PayOutPrizeArgs<'a, 'b> {
+    header: &'b ChallengeHeader,
+    solution_index: u8,
+    /// The winners that still share the prize of the solution, including this one
+    winners_left: u8,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    prize_pool_info: &'a AccountInfo<'a>,
+    challenger_info: &'a AccountInfo<'a>,
+}
+
+fn pay_out_prize(args: PayOutPrizeArgs) -> Result<u64, ProgramError> {
+    let PayOutPrizeArgs {
+        header,
+        solution_index,
+        winners_left,
+        challenge_pda_info,
+        prize_pool_info,
+        challenger_info,
+    } = args;
+
+    let prize_pool = prize_pool_state(
+        "redeem:prize_pool",
+        challenge_pda_info,
+        prize_pool_info,
+    )?;
+    let available = prize_pool_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(PrizePool::size()));
+    let prize =
+        prize_for(header, prize_pool, solution_index, winners_left, available);
+
+    if prize > 0 {
+        log_state!("Paying out prize of {} lamports", prize);
+        transfer_lamports_from_program_account(
+            prize_pool_info,
+            challenger_info,
+            prize,
+        )?;
+    }
+    Ok(prize)
+}
+
+/// Verifies that [prize_pool_info] is the prize pool of the challenge and returns its state.
+fn prize_pool_state(
+    context: &str,
+    challenge_pda_info: &AccountInfo,
+    prize_pool_info: &AccountInfo,
+) -> Result<PrizePool, ProgramError> {
+    let (prize_pool_pda, _) = PrizePool::pda_for(challenge_pda_info.key);
+    assert_keys_equal(
+        context,
+        prize_pool_info.key,
+        &prize_pool_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+            "Provided prize pool ({}) is not the prize pool ({}) of the challenge",
+            prize_pool_info.key, prize_pool_pda
+        )
+        },
+    )?;
+    prize_pool_info.try_state_from_account()
+}
+
+// -----------------
+// Set Solve Window
+// -----------------
+fn process_set_solve_window<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    from_slot: Option<u64>,
+    until_slot: Option<u64>,
+) -> ProgramResult {
+    log_ix!("set solve window");
+
+    assert_keys_equal(
+        "set_solve_window:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+    assert_valid_solve_window("set_solve_window", from_slot, until_slot)?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_solve_window",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    challenge.solve_open_from_slot = from_slot;
+    challenge.solve_open_until_slot = until_slot;
+
+    // Setting or clearing the window slots changes the serialized size of the challenge
+    reallocate_account(ReallocateAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: challenge.size(),
+        zero_init: false,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Get Challenge State
+// -----------------
+fn process_get_challenge_state(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    log_ix!("get challenge state");
+
+    assert_keys_equal(
+        "get_challenge_state:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let challenge: Challenge = challenge_pda_info.try_state_from_account()?;
+    let data = challenge.to_account_data()?;
+
+    assert_state_fits_return_data("get_challenge_state", &data)?;
+    set_return_data(&data);
+
+    Ok(())
+}
+
+// -----------------
+// Split Challenge
+// -----------------
+fn process_split_challenge<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    new_id: String,
+    new_redeem: Pubkey,
+    split_at: u8,
+) -> ProgramResult {
+    log_ix!("split challenge");
+
+    assert_keys_equal(
+        "split_challenge:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let new_challenge_pda_info = next_account_info(account_info_iter)?;
+    let new_redeem_pda_info = next_account_info(account_info_iter)?;
+    let spl_token_program_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let creator_registry_info = next_account_info(account_info_iter).ok();
+
+    assert_system_program(
+        "split_challenge:system_program",
+        system_program_info,
+    )?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "split_challenge",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_solutions_not_locked("split_challenge", &challenge)?;
+    assert_no_solutions_redeemed("split_challenge", &challenge)?;
+    assert_valid_solutions_split_index(
+        "split_challenge",
+        &challenge,
+        split_at,
+    )?;
+    assert_keys_equal(
+        "split_challenge:new_redeem_pda",
+        new_redeem_pda_info.key,
+        &new_redeem,
+        ChallengeError::ProvidedRedeemIncorrect,
+        || {
+            format!(
+                "Provided new_redeem_pda ({}) does not match new redeem key passed ({})",
+                new_redeem_pda_info.key, new_redeem
+            )
+        },
+    )?;
+
+    let mut new_challenge = Challenge {
+        version: CHALLENGE_VERSION,
+        bump: 0,
+        authority: challenge.authority,
+        pending_authority: None,
+        original_authority: None,
+        admins: challenge.admins.clone(),
+        id: new_id,
+        started: false,
+        finished: false,
+        paused: false,
+        cancelled: false,
+        admit_cost: challenge.admit_cost,
+        admit_cost_locked: challenge.admit_cost_locked,
+        admit_mint: challenge.admit_mint,
+        has_prize_pool: false,
+        nft_reward: challenge.nft_reward,
+        has_first_solvers: false,
+        commit_reveal: challenge.commit_reveal,
+        allowlist_root: challenge.allowlist_root,
+        max_admissions: challenge.max_admissions,
+        admissions: 0,
+        salt: challenge.salt,
+        hash_algorithm: challenge.hash_algorithm,
+        tries_per_admit: challenge.tries_per_admit,
+        redeem: new_redeem,
+        solving: 0,
+        solve_mode: challenge.solve_mode,
+        solved: [0; 32],
+        solve_open_from_slot: challenge.solve_open_from_slot,
+        solve_open_until_slot: challenge.solve_open_until_slot,
+        starts_at: challenge.starts_at,
+        ends_at: challenge.ends_at,
+        oracle: challenge.oracle,
+        solutions_locked: false,
+        hint_fee: challenge.hint_fee,
+        attempt_cooldown_slots: challenge.attempt_cooldown_slots,
+        max_winners_per_solution: 0,
+        solution_normalization: challenge.solution_normalization,
+        reject_weak_solutions: challenge.reject_weak_solutions,
+        ticket_admission: challenge.ticket_admission,
+        flags: challenge.flags,
+        seed: None,
+        max_tries_per_challenger: challenge.max_tries_per_challenger,
+        sponsored_lamports: 0,
+        prerequisite: challenge.prerequisite,
+        // the split off solutions don't line up with the blocks of the epochs
+        epoch_slots: 0,
+        epoch_start_slot: 0,
+        solutions_per_epoch: 0,
+        epoch: 0,
+        rewards: challenge
+            .rewards
+            .get(split_at as usize..)
+            .map_or_else(Vec::new, <[u64]>::to_vec),
+        solutions: challenge.solutions[split_at as usize..].to_vec(),
+        hints: challenge
+            .hints
+            .get(split_at as usize..)
+            .map_or_else(Vec::new, <[Hint]>::to_vec),
+    };
+
+    new_challenge.bump = allocate_challenge_pda(AllocateChallengePdaArgs {
+        context: "split_challenge:new_challenge_pda",
+        program_id,
+        payer_info,
+        creator_info,
+        challenge_pda_info: new_challenge_pda_info,
+        id: &new_challenge.id,
+        seed: None,
+        size: new_challenge.size(),
+    })?;
+
+    create_redeem_mint(CreateRedeemMintArgs {
+        context: "split_challenge:new_redeem_pda",
+        payer_info,
+        challenge_pda_info: new_challenge_pda_info,
+        redeem_pda_info: new_redeem_pda_info,
+        spl_token_program_info,
+    })?;
+
+    new_challenge.assert_invariants()?;
+    new_challenge.serialize_into_account(new_challenge_pda_info)?;
+
+    // the moved solutions are no longer part of the split challenge, otherwise they could
+    // be redeemed in both challenges
+    let size_change = truncate_solutions(&mut challenge, split_at)?;
+    challenge_pda_info.realloc(size_change.after, false)?;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    if let Some(creator_registry_info) = creator_registry_info {
+        register_challenge(RegisterChallengeArgs {
+            context: "split_challenge:creator_registry",
+            program_id,
+            payer_info,
+            creator: creator_info.key,
+            challenge_pda: new_challenge_pda_info.key,
+            creator_registry_info,
+        })?;
+    }
+
+    log_state!(
+        "Moved {} solutions into challenge '{}'",
+        new_challenge.solutions.len(),
+        new_challenge.id
+    );
+
+    Ok(())
+}
+
+// -----------------
+// Truncate Solutions
+// -----------------
+fn process_truncate_solutions(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    id: String,
+    len: u8,
+) -> ProgramResult {
+    log_ix!("truncate solutions");
+
+    assert_keys_equal(
+        "truncate_solutions:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "truncate_solutions",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    let size_change = truncate_solutions(&mut challenge, len)?;
+
+    // Shrinking does not require extra rent, the account keeps the now surplus lamports
+    challenge_pda_info.realloc(size_change.after, false)?;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Merge Challenges
+// -----------------
+fn process_merge_challenges<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    source_id: String,
+) -> ProgramResult {
+    log_ix!("merge challenges");
+
+    assert_keys_equal(
+        "merge_challenges:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let source_challenge_pda_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_system_program(
+        "merge_challenges:system_program",
+        system_program_info,
+    )?;
+
+    assert_keys_not_equal(
+        "merge_challenges:source_challenge_pda",
+        source_challenge_pda_info.key,
+        challenge_pda_info.key,
+        || "Cannot merge a challenge into itself".to_string(),
+    )?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "merge_challenges",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+    let StateFromPdaAccountValue::<Challenge> {
+        state: source_challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "merge_challenges:source",
+        source_challenge_pda_info,
+        creator_info,
+        &source_id,
+    )?;
+
+    assert_solutions_not_locked("merge_challenges", &challenge)?;
+    assert_can_add_after_start("merge_challenges", &challenge)?;
+    assert_solutions_not_locked("merge_challenges:source", &source_challenge)?;
+    // challengers admitted to the source would lose the tries they paid for
+    assert_not_in_progress("merge_challenges:source", &source_challenge)?;
+    assert_no_solutions_redeemed("merge_challenges", &challenge)?;
+    assert_no_solutions_redeemed("merge_challenges:source", &source_challenge)?;
+    assert_has_no_prize_pool("merge_challenges:source", &source_challenge)?;
+    // the escrow of the source is only reachable via its PDA which is closed below
+    if let Some(admit_mint) = source_challenge.admit_mint {
+        assert_escrow_empty(
+            "merge_challenges:source_escrow_ata",
+            source_challenge_pda_info.key,
+            &admit_mint,
+            next_account_info(account_info_iter)?,
+        )?;
+    }
+    let creator_registry_info = next_account_info(account_info_iter).ok();
+    assert_same_salt("merge_challenges", &challenge, &source_challenge)?;
+    assert_same_hash_algorithm(
+        "merge_challenges",
+        &challenge,
+        &source_challenge,
+    )?;
+    assert_same_solution_normalization(
+        "merge_challenges",
+        &challenge,
+        &source_challenge,
+    )?;
+
+    // 1. append solutions of the source challenge
+    assert_can_add_solutions(
+        "merge_challenges",
+        &challenge.solutions,
+        &source_challenge.solutions,
+    )?;
+    assert_no_weak_solutions(
+        "merge_challenges",
+        &challenge,
+        &source_challenge.solutions,
+    )?;
+    let solutions_before = challenge.solutions.len();
+    let source_creator = *source_challenge.creator();
+    challenge.solutions.extend(source_challenge.solutions);
+    challenge.append_rewards(solutions_before, &source_challenge.rewards)?;
+    challenge.append_hints(solutions_before, &source_challenge.hints);
+
+    // 2. reallocate account to fit extra solutions, including upping lamports to stay rent excempt
+    reallocate_account(ReallocateAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: challenge.size(),
+        zero_init: false,
+    })?;
+
+    challenge.assert_invariants()?;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    // 3. close the source challenge
+    close_account(CloseAccountArgs {
+        account_info: source_challenge_pda_info,
+        recvr_info: creator_info,
+    })?;
+
+    if let Some(creator_registry_info) = creator_registry_info {
+        deregister_challenge(DeregisterChallengeArgs {
+            creator: &source_creator,
+            challenge_pda: source_challenge_pda_info.key,
+            creator_registry_info,
+            recvr_info: creator_info,
+        })?;
+    }
+
+    Ok(())
+}
+
+// -----------------
+// Lock Admit Cost
+// -----------------
+fn process_lock_admit_cost<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+) -> ProgramResult {
+    log_ix!("lock admit cost");
+
+    assert_keys_equal(
+        "lock_admit_cost:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "lock_admit_cost",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // Locking is irreversible and locking an already locked admit cost is a noop
+    challenge.admit_cost_locked = true;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Create Template
+// -----------------
+#[allow(clippy::too_many_arguments)]
+fn process_create_template<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    template_id: u8,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    salt: Option<[u8; 32]>,
+    hash_algorithm: HashAlgorithm,
+    starts_at: Option<i64>,
+    ends_at: Option<i64>,
+) -> ProgramResult {
+    log_ix!("create template");
+
+    assert_keys_equal(
+        "create_template:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let template_pda_info = next_account_info(account_info_iter)?;
+
+    assert_is_signer_or_delegated("create_template", creator_info, "creator")?;
+    assert_valid_schedule("create_template", starts_at, ends_at)?;
+
+    let (template_pda, bump) =
+        ChallengeTemplate::pda_for(creator_info.key, template_id);
+    assert_keys_equal(
+        "create_template:template_pda",
+        template_pda_info.key,
+        &template_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA for the template for creator ({}) and template id ({}) is incorrect",
+                creator_info.key, template_id
+            )
+        },
+    )?;
+    assert_account_has_no_data(
+        "create_template:template_pda",
+        template_pda_info,
+    )?;
+
+    let bump_arr = [bump];
+    let template_id_arr = [template_id];
+    let seeds = ChallengeTemplate::shank_seeds_with_bump(
+        creator_info.key,
+        &template_id_arr,
+        &bump_arr,
+    );
+
+    let template = ChallengeTemplate {
+        authority: *creator_info.key,
+        template_id,
+        admit_cost,
+        tries_per_admit,
+        salt,
+        hash_algorithm,
+        starts_at,
+        ends_at,
+    };
+
+    allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+        payer_info,
+        account_info: template_pda_info,
+        owner: program_id,
+        signer_seeds: &seeds,
+        size: template.size(),
+    })?;
+
+    template.serialize_into_account(template_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Create Challenge from Template
+// -----------------
+fn process_create_challenge_from_template<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    template_id: u8,
+    id: String,
+    redeem: Pubkey,
+    solutions: Vec<Solution>,
+    overrides: TemplateOverrides,
+) -> ProgramResult {
+    log_ix!("create challenge from template");
+
+    // The template PDA is appended to the accounts of the create challenge instruction
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let template_pda_info = account_info_iter
+        .nth(4)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let creator_registry_info = next_account_info(account_info_iter).ok();
+
+    let StateFromPdaAccountValue::<ChallengeTemplate> {
+        state: template, ..
+    } = template_pda_info.try_state_from_pda_account(|| {
+        ChallengeTemplate::pda_for(creator_info.key, template_id)
+    })?;
+
+    let ChallengeTemplate {
+        admit_cost,
+        tries_per_admit,
+        salt,
+        hash_algorithm,
+        starts_at,
+        ends_at,
+        ..
+    } = template.with_overrides(&overrides);
+    process_create_challenge(
+        program_id,
+        accounts,
+        CreateChallengeArgs {
+            id,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+            solutions,
+            salt,
+            hash_algorithm,
+            starts_at,
+            ends_at,
+            seed: None,
+        },
+        creator_registry_info,
+    )
+}
+
+// -----------------
+// Set Oracle
+// -----------------
+fn process_set_oracle<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    oracle: Pubkey,
+) -> ProgramResult {
+    log_ix!("set oracle");
+
+    assert_keys_equal(
+        "set_oracle:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_oracle",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    challenge.oracle = oracle;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Verify Solve Signature
+// -----------------
+fn process_verify_solve_signature<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    solution_index: u8,
+    oracle_signature: [u8; SIGNATURE_BYTES],
+) -> ProgramResult {
+    log_ix!("verify solve signature");
+
+    assert_keys_equal(
+        "verify_solve_signature:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    // challenger
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+
+    // redeem
+    let redeem_info = next_account_info(account_info_iter)?;
+    let redeem_ata_challenger_info = next_account_info(account_info_iter)?;
+
+    // programs
+    let spl_token_program_info = next_account_info(account_info_iter)?;
+    let spl_associated_token_program_info =
+        next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_spl_token_program(
+        "verify_solve_signature:token_program",
+        spl_token_program_info,
+    )?;
+    assert_associated_token_program(
+        "verify_solve_signature:associated_token_program",
+        spl_associated_token_program_info,
+    )?;
+    assert_system_program(
+        "verify_solve_signature:system_program",
+        system_program_info,
+    )?;
+
+    // sysvars
+    let instructions_sysvar_info = next_account_info(account_info_iter)?;
+
+    // prize pool, only required if the challenge has one
+    let prize_pool_info = next_account_info(account_info_iter).ok();
+
+    assert_is_signer("verify_solve_signature:payer", payer_info, "payer")?;
+    assert_is_signer(
+        "verify_solve_signature:challenger",
+        challenger_info,
+        "challenger",
+    )?;
+    assert_sysvar(
+        "verify_solve_signature:instructions_sysvar",
+        instructions_sysvar_info,
+        &sysvar::instructions::id(),
+        "instructions",
+    )?;
+
+    // only the current solution is read from the account data, see ChallengeHeader
+    let mut header = ChallengeHeader::try_from_account(challenge_pda_info)?;
+    let challenge = &header.challenge;
+
+    // with tickets the holder solves with the tries of the admission of the ticket, see
+    // assert_holds_ticket below, otherwise the PDA is derived from the signer
+    let mut challenger: Challenger = if challenge.ticket_admission {
+        challenger_pda_info.try_state_from_account()?
+    } else {
+        challenger_pda_info
+            .try_state_from_pda_account(|| {
+                Challenger::shank_pda(
+                    &challenge_id(),
+                    challenge_pda_info.key,
+                    challenger_info.key,
+                )
+            })?
+            .state
+    };
+
+    assert_keys_equal(
+        "verify_solve_signature:challenge_pda",
+        &challenger.challenge_pda,
+        challenge_pda_info.key,
+        ChallengeError::ProvidedChallengePdaIncorrect,
+        || {
+            format!(
+                "Challenge pda ({}) of provided callenger does not match the PDA account ({}) for which you are trying to redeem",
+                &challenger.challenge_pda, challenge_pda_info.key
+            )
+        },
+    )?;
+
+    assert_started("verify_solve_signature", challenge)?;
+    assert_not_completed("verify_solve_signature", challenge)?;
+    assert_not_paused("verify_solve_signature", challenge)?;
+    let now = Now::from_sysvar()?;
+    assert_solve_window_open("verify_solve_signature", challenge, now.slot)?;
+    assert_within_schedule(
+        "verify_solve_signature",
+        challenge,
+        now.unix_timestamp,
+    )?;
+
+    let RewardAccounts {
+        nft_mint_info,
+        nft_ata_info,
+        first_solvers_info,
+        winners_info,
+    } = reward_accounts(challenge, account_info_iter)?;
+    if challenge.ticket_admission {
+        assert_holds_ticket(
+            "verify_solve_signature:ticket_ata",
+            next_account_info(account_info_iter)?,
+            challenger_info.key,
+            challenger_pda_info.key,
+        )?;
+    }
+
+    assert_keys_equal(
+        "verify_solve_signature:redeem",
+        redeem_info.key,
+        &challenge.redeem,
+        ChallengeError::ProvidedRedeemIncorrect,
+        || {
+            format!(
+                "Provided redeem ({}) does not match the redeem ({}) for the challenge",
+                redeem_info.key, challenge.redeem
+            )
+        },
+    )?;
+
+    attempt_solve(
+        "verify_solve_signature",
+        challenge,
+        &mut challenger,
+        now.slot,
+    )?;
+    assert_has_solution("verify_solve_signature", &header)?;
+    assert_has_oracle("verify_solve_signature", challenge)?;
+    let solution_hash = match challenge.solve_mode {
+        SolveMode::Sequential => {
+            assert_solution_index_is_current(
+                "verify_solve_signature",
+                challenge,
+                solution_index,
+            )?;
+            header.current_solution
+        }
+        SolveMode::AnyOrder => {
+            assert_valid_solution_index(
+                "verify_solve_signature",
+                challenge,
+                solution_index,
+            )?;
+            header.solution_at(
+                &challenge_pda_info.try_borrow_data()?,
+                solution_index,
+            )
+        }
+    }
+    .ok_or(ProgramError::InvalidAccountData)?;
+
+    let message = Challenge::oracle_message(
+        challenge_pda_info.key,
+        challenger_info.key,
+        &solution_hash,
+    );
+    assert_ed25519_signature_verified(
+        "verify_solve_signature:oracle_signature",
+        instructions_sysvar_info,
+        &challenge.oracle,
+        &oracle_signature,
+        &message,
+    )?;
+
+    redeem_solution(RedeemSolutionArgs {
+        payer_info,
+        challenge_pda_info,
+        challenger_info,
+        redeem_info,
+        redeem_ata_challenger_info,
+        spl_token_program_info,
+        prize_pool_info,
+        nft_mint_info,
+        nft_ata_info,
+        first_solvers_info,
+        winners_info,
+        solution_index,
+        slot: now.slot,
+        header: &mut header,
+        challenger: &mut challenger,
+    })?;
+
+    challenger.serialize_into_account(challenger_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Update Solution Hash
+// -----------------
+fn process_update_solution_hash<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    index: u8,
+    new_hash: Solution,
+) -> ProgramResult {
+    log_ix!("update solution hash");
+
+    assert_keys_equal(
+        "update_solution_hash:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "update_solution_hash",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_solutions_not_locked("update_solution_hash", &challenge)?;
+    assert_valid_solution_index("update_solution_hash", &challenge, index)?;
+    assert_no_weak_solutions("update_solution_hash", &challenge, &[new_hash])?;
+
+    let old_hash =
+        std::mem::replace(&mut challenge.solutions[index as usize], new_hash);
+    log_state!(
+        "Replaced solution {} ({}) with ({})",
+        index,
+        solution_to_hex(&old_hash),
+        solution_to_hex(&new_hash)
+    );
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Lock/Unlock Solutions
+// -----------------
+fn process_set_solutions_locked<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    locked: bool,
+) -> ProgramResult {
+    log_ix!("set solutions locked");
+
+    assert_keys_equal(
+        "set_solutions_locked:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_solutions_locked",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    challenge.solutions_locked = locked;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Reissue Admission
+// -----------------
+fn process_reissue_admission<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    challenge_pda: Pubkey,
+) -> ProgramResult {
+    log_ix!("reissue admission");
+
+    assert_keys_equal(
+        "reissue_admission:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+
+    assert_keys_equal(
+        "reissue_admission:challenge_pda",
+        challenge_pda_info.key,
+        &challenge_pda,
+        ChallengeError::ProvidedChallengePdaIncorrect,
+        || {
+            format!(
+                "Provided challenge pda ({}) does not match the PDA account ({}) provided in the instruction",
+                challenge_pda, challenge_pda_info.key
+            )
+        },
+    )?;
+
+    let challenge: Challenge = challenge_pda_info.try_state_from_account()?;
+    assert_started("reissue_admission", &challenge)?;
+    assert_not_finished("reissue_admission", &challenge)?;
+    assert_not_paused("reissue_admission", &challenge)?;
+    assert_within_schedule(
+        "reissue_admission",
+        &challenge,
+        Now::from_sysvar()?.unix_timestamp,
+    )?;
+    assert_keys_equal(
+        "reissue_admission:creator",
+        creator_info.key,
+        &challenge.authority,
+        ChallengeError::ProvidedCreatorIncorrect,
+        || {
+            format!(
+                "Provided creator ({}) is not the authority ({}) of the challenge",
+                creator_info.key, challenge.authority
+            )
+        },
+    )?;
+
+    let StateFromPdaAccountValue::<Challenger> {
+        state: mut challenger,
+        ..
+    } = challenger_pda_info.try_state_from_pda_account(|| {
+        Challenger::shank_pda(
+            &challenge_id(),
+            &challenge_pda,
+            challenger_info.key,
+        )
+    })?;
+    assert_challenger_has_no_tries_remaining("reissue_admission", &challenger)?;
+    assert_within_max_tries_per_challenger(
+        "reissue_admission",
+        &challenge,
+        &challenger,
+        challenge.tries_per_admit,
+    )?;
+
+    // 1. transfer admit cost to creator account or escrow
+    let escrowed = pay_admit_cost(PayAdmitCostArgs {
+        context: "reissue_admission",
+        challenge: &challenge,
+        cost: challenge.admit_cost,
+        payer_info,
+        creator_info,
+        challenge_pda_info,
+        account_info_iter,
+    })?;
+
+    // 2. reset challenger tries
+    challenger.grant_tries(challenge.tries_per_admit);
+    challenger.record_escrowed(escrowed);
+    challenger.serialize_into_account(challenger_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Add Co-Sponsor
+// -----------------
+fn process_add_co_sponsor<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    challenge_pda: Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    log_ix!("add co-sponsor");
+
+    assert_keys_equal(
+        "add_co_sponsor:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let sponsor_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let sponsor_log_pda_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_system_program(
+        "add_co_sponsor:system_program",
+        system_program_info,
+    )?;
+
+    // prize pool, only required if the challenge has one
+    let prize_pool_info = next_account_info(account_info_iter).ok();
+
+    assert_is_signer("add_co_sponsor:sponsor", sponsor_info, "sponsor")?;
+    assert_keys_equal(
+        "add_co_sponsor:challenge_pda",
+        challenge_pda_info.key,
+        &challenge_pda,
+        ChallengeError::ProvidedChallengePdaIncorrect,
+        || {
+            format!(
+                "Provided challenge pda ({}) does not match the PDA account ({}) provided in the instruction",
+                challenge_pda, challenge_pda_info.key
+            )
+        },
+    )?;
+
+    let mut challenge: Challenge =
+        challenge_pda_info.try_state_from_account()?;
+    assert_not_finished("add_co_sponsor", &challenge)?;
+
+    // 1. create the sponsor log on first contribution
+    let (sponsor_log_pda, bump) =
+        SponsorLog::pda_for(&challenge_pda, sponsor_info.key);
+    assert_keys_equal(
+        "add_co_sponsor:sponsor_log_pda",
+        sponsor_log_pda_info.key,
+        &sponsor_log_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the sponsor log is not valid for this challenge and sponsor",
+                sponsor_log_pda_info.key
+            )
+        },
+    )?;
+
+    let mut sponsor_log = if sponsor_log_pda_info.data_is_empty() {
+        let bump_arr = [bump];
+        let seeds = SponsorLog::shank_seeds_with_bump(
+            &challenge_pda,
+            sponsor_info.key,
+            &bump_arr,
+        );
+        allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+            payer_info: sponsor_info,
+            account_info: sponsor_log_pda_info,
+            owner: program_id,
+            signer_seeds: &seeds,
+            size: SponsorLog::size(),
+        })?;
+        SponsorLog {
+            sponsor: *sponsor_info.key,
+            challenge_pda,
+            total_contributed: 0,
+        }
+    } else {
+        sponsor_log_pda_info.try_state_from_account()?
+    };
+
+    // 2. transfer the contribution into the prize pool, held by the challenge PDA unless
+    //    the challenge has a dedicated one
+    if challenge.has_prize_pool {
+        let prize_pool_info =
+            prize_pool_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        prize_pool_state(
+            "add_co_sponsor:prize_pool",
+            challenge_pda_info,
+            prize_pool_info,
+        )?;
+        transfer_lamports(sponsor_info, prize_pool_info, amount)?;
+    } else {
+        transfer_lamports(sponsor_info, challenge_pda_info, amount)?;
+        challenge.sponsored_lamports = challenge
+            .sponsored_lamports
+            .checked_add(amount)
+            .ok_or(ChallengeError::ContributionOverflow)?;
+        challenge.serialize_into_account(challenge_pda_info)?;
+    }
+
+    // 3. record the contribution
+    sponsor_log.total_contributed = sponsor_log
+        .total_contributed
+        .checked_add(amount)
+        .ok_or(ChallengeError::ContributionOverflow)?;
+    sponsor_log.serialize_into_account(sponsor_log_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Create Leaderboard
+// -----------------
+fn process_create_leaderboard<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+) -> ProgramResult {
+    log_ix!("create leaderboard");
+
+    assert_keys_equal(
+        "create_leaderboard:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let leaderboard_pda_info = next_account_info(account_info_iter)?;
+
+    Challenge::account_state_verifying_creator(
+        "create_leaderboard",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    let (leaderboard_pda, bump) = Leaderboard::pda_for(challenge_pda_info.key);
+    assert_keys_equal(
+        "create_leaderboard:leaderboard_pda",
+        leaderboard_pda_info.key,
+        &leaderboard_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the leaderboard is not valid for this challenge",
+                leaderboard_pda_info.key
+            )
+        },
+    )?;
+    assert_account_has_no_data(
+        "create_leaderboard:leaderboard_pda",
+        leaderboard_pda_info,
+    )?;
+
+    let leaderboard = Leaderboard::new(*challenge_pda_info.key);
+
+    let bump_arr = [bump];
+    let seeds =
+        Leaderboard::shank_seeds_with_bump(challenge_pda_info.key, &bump_arr);
+    allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+        payer_info,
+        account_info: leaderboard_pda_info,
+        owner: program_id,
+        signer_seeds: &seeds,
+        size: leaderboard.size(),
+    })?;
+
+    leaderboard.serialize_into_account(leaderboard_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Update Leaderboard
+// -----------------
+fn process_update_leaderboard<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    log_ix!("update leaderboard");
+
+    assert_keys_equal(
+        "update_leaderboard:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+    let leaderboard_pda_info = next_account_info(account_info_iter)?;
+
+    assert_is_signer(
+        "update_leaderboard:challenger",
+        challenger_info,
+        "challenger",
+    )?;
+
+    let StateFromPdaAccountValue::<Challenger> {
+        state: challenger, ..
+    } = challenger_pda_info.try_state_from_pda_account(|| {
+        Challenger::shank_pda(
+            &challenge_id(),
+            challenge_pda_info.key,
+            challenger_info.key,
+        )
+    })?;
+    assert_challenger_redeemed("update_leaderboard", &challenger)?;
+
+    let StateFromPdaAccountValue::<Leaderboard> {
+        state: mut leaderboard,
+        ..
+    } = leaderboard_pda_info.try_state_from_pda_account(|| {
+        Leaderboard::pda_for(challenge_pda_info.key)
+    })?;
+
+    let entry = LeaderboardEntry {
+        wallet: *challenger_info.key,
+        score: challenger.leaderboard_score(),
+        solved_at_slot: Now::from_sysvar()?.slot,
+    };
+    if !leaderboard.upsert(entry) {
+        log_state!("Leaderboard unchanged, challenger does not rank higher than before");
+        return Ok(());
+    }
+
+    reallocate_account(ReallocateAccountArgs {
+        payer_info,
+        account_info: leaderboard_pda_info,
+        new_size: leaderboard.size(),
+        zero_init: false,
+    })?;
+
+    leaderboard.serialize_into_account(leaderboard_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Remove Solutions
+// -----------------
+fn process_remove_solutions<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    indices: Vec<u8>,
+) -> ProgramResult {
+    log_ix!("remove solutions");
+
+    assert_keys_equal(
+        "remove_solutions:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "remove_solutions",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // 1. remove the solutions, keeping solving pointed at the same solution
+    let size_change = remove_solutions(&mut challenge, indices)?;
+
+    // 2. shrink the account and refund the excess rent to the creator
+    shrink_account_refunding_rent(ShrinkAccountArgs {
+        account_info: challenge_pda_info,
+        recvr_info: creator_info,
+        new_size: size_change.after,
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Replace Solutions
+// -----------------
+fn process_replace_solutions<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    solutions: Vec<Solution>,
+) -> ProgramResult {
+    log_ix!("replace solutions");
+
+    assert_keys_equal(
+        "replace_solutions:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+    assert_adding_non_empty("replace_solutions", &solutions)?;
+    assert_can_add_solutions("replace_solutions", &[], &solutions)?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "replace_solutions",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // 1. swap in the new solutions
+    let size_change = replace_solutions(&mut challenge, solutions)?;
+
+    // 2. resize the account, settling the rent difference with the payer
+    resize_account_settling_rent(ResizeAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: size_change.after,
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Close Challenge
+// -----------------
+fn process_close_challenge<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+) -> ProgramResult {
+    log_ix!("close challenge");
+
+    assert_keys_equal(
+        "close_challenge:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let creator_registry_info = next_account_info(account_info_iter).ok();
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: challenge, ..
+    } = Challenge::account_state_verifying_creator(
+        "close_challenge",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_not_in_progress("close_challenge", &challenge)?;
+    assert_has_no_prize_pool("close_challenge", &challenge)?;
+    assert_keys_not_equal(
+        "close_challenge:recipient",
+        recipient_info.key,
+        challenge_pda_info.key,
+        || "The recipient cannot be the challenge being closed".to_string(),
+    )?;
+
+    close_account(CloseAccountArgs {
+        account_info: challenge_pda_info,
+        recvr_info: recipient_info,
+    })?;
+
+    if let Some(creator_registry_info) = creator_registry_info {
+        deregister_challenge(DeregisterChallengeArgs {
+            creator: challenge.creator(),
+            challenge_pda: challenge_pda_info.key,
+            creator_registry_info,
+            recvr_info: recipient_info,
+        })?;
+    }
+
+    ChallengeEvent::ChallengeClosed {
+        challenge_pda: *challenge_pda_info.key,
+    }
+    .emit()
+}
+
+// -----------------
+// Transfer Authority
+// -----------------
+fn process_transfer_authority<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    new_authority: Pubkey,
+) -> ProgramResult {
+    log_ix!("transfer authority");
+
+    assert_keys_equal(
+        "transfer_authority:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "transfer_authority",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    challenge.pending_authority = if new_authority.eq(&challenge.authority) {
+        log_state!("Cancelling pending authority transfer");
+        None
+    } else {
+        Some(new_authority)
+    };
+
+    resize_account_settling_rent(ResizeAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: challenge.size(),
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+fn process_accept_authority<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+) -> ProgramResult {
+    log_ix!("accept authority");
+
+    assert_keys_equal(
+        "accept_authority:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let new_authority_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    assert_is_signer_or_delegated(
+        "accept_authority:new_authority",
+        new_authority_info,
+        "new_authority",
+    )?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state(
+        "accept_authority:challenge_pda",
+        challenge_pda_info,
+        &id,
+    )?;
+
+    assert_is_pending_authority(
+        "accept_authority",
+        &challenge,
+        new_authority_info.key,
+    )?;
+
+    // The creator seed of the PDA never changes, so we remember it unless the challenge
+    // is transferred back to its creator
+    let creator = *challenge.creator();
+    challenge.original_authority =
+        (creator != *new_authority_info.key).then_some(creator);
+    challenge.authority = *new_authority_info.key;
+    challenge.pending_authority = None;
+
+    // Clearing the pending authority frees at least as much space as storing the creator needs
+    shrink_account_refunding_rent(ShrinkAccountArgs {
+        account_info: challenge_pda_info,
+        recvr_info: new_authority_info,
+        new_size: challenge.size(),
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Pause/Resume Challenge
+// -----------------
+fn process_set_paused<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    paused: bool,
+) -> ProgramResult {
+    log_ix!("set paused");
+
+    assert_keys_equal(
+        "set_paused:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state(
+        "set_paused:challenge_pda",
+        challenge_pda_info,
+        &id,
+    )?;
+    assert_is_authority_or_admin(
+        "set_paused:creator",
+        &challenge,
+        creator_info,
+    )?;
+
+    if !paused {
+        assert_not_cancelled("set_paused", &challenge)?;
+    }
+    challenge.paused = paused;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Update Challenge
+// -----------------
+fn process_update_challenge<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    admit_cost: Option<u64>,
+    tries_per_admit: Option<u8>,
+    redeem: Option<Pubkey>,
+) -> ProgramResult {
+    log_ix!("update challenge");
+
+    assert_keys_equal(
+        "update_challenge:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "update_challenge",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    update_challenge(&mut challenge, admit_cost, tries_per_admit, redeem)?;
+
+    // the new redeem needs to be a mint of the challenge
+    if let Some(redeem) = redeem {
+        let redeem_info = next_account_info(account_info_iter)?;
+        assert_keys_equal(
+            "update_challenge:redeem",
+            redeem_info.key,
+            &redeem,
+            ChallengeError::ProvidedRedeemIncorrect,
+            || {
+                format!(
+                    "Provided redeem account ({}) does not match redeem key passed ({})",
+                    redeem_info.key, redeem
+                )
+            },
+        )?;
+        assert_mint_with_authority(
+            "update_challenge:redeem",
+            redeem_info,
+            challenge_pda_info.key,
+        )?;
+    }
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Admit Mint
+// -----------------
+fn process_set_admit_mint<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    admit_mint: Option<Pubkey>,
+) -> ProgramResult {
+    log_ix!("set admit mint");
+
+    assert_keys_equal(
+        "set_admit_mint:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_system_program(
+        "set_admit_mint:system_program",
+        system_program_info,
+    )?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_admit_mint",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_admit_cost_not_locked("set_admit_mint", &challenge)?;
+
+    if let Some(admit_mint) = admit_mint {
+        let admit_mint_info = next_account_info(account_info_iter)?;
+        assert_keys_equal(
+            "set_admit_mint:admit_mint",
+            admit_mint_info.key,
+            &admit_mint,
+            ChallengeError::ProvidedMintIncorrect,
+            || {
+                format!(
+                    "Provided admit mint account ({}) does not match admit mint passed ({})",
+                    admit_mint_info.key, admit_mint
+                )
+            },
+        )?;
+        assert_is_mint("set_admit_mint:admit_mint", admit_mint_info)?;
+    }
+    challenge.admit_mint = admit_mint;
+
+    resize_account_settling_rent(ResizeAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: challenge.size(),
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+fn process_withdraw_admit_tokens<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+) -> ProgramResult {
+    log_ix!("withdraw admit tokens");
+
+    assert_keys_equal(
+        "withdraw_admit_tokens:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let admit_mint_info = next_account_info(account_info_iter)?;
+    let escrow_ata_info = next_account_info(account_info_iter)?;
+    let creator_ata_info = next_account_info(account_info_iter)?;
+    let spl_token_program_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: challenge,
+        bump,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "withdraw_admit_tokens",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // the escrow is kept for the refunds of the challengers once cancelled
+    assert_not_cancelled("withdraw_admit_tokens", &challenge)?;
+    let admit_mint =
+        assert_has_admit_mint("withdraw_admit_tokens", &challenge)?;
+    assert_keys_equal(
+        "withdraw_admit_tokens:admit_mint",
+        admit_mint_info.key,
+        &admit_mint,
+        ChallengeError::ProvidedMintIncorrect,
+        || {
+            format!(
+                "Provided admit mint ({}) does not match the admit mint ({}) of the challenge",
+                admit_mint_info.key, admit_mint
+            )
+        },
+    )?;
+    assert_spl_token_program(
+        "withdraw_admit_tokens:token_program",
+        spl_token_program_info,
+    )?;
+
+    assert_owned_by(
+        "withdraw_admit_tokens:escrow_ata",
+        escrow_ata_info,
+        &spl_token::id(),
+        "escrow",
+    )?;
+    let amount =
+        spl_token::state::Account::unpack(&escrow_ata_info.try_borrow_data()?)?
+            .amount;
+
+    let bump_arr = [bump];
+    let challenge_seeds = challenge.seeds(&bump_arr);
+    transfer_tokens_to_recvr(TransferTokensArgs {
+        payer_info: creator_info,
+        source_owner_info: challenge_pda_info,
+        source_ata_info: escrow_ata_info,
+        recvr_info: creator_info,
+        recvr_ata_info: creator_ata_info,
+        mint_info: admit_mint_info,
+        spl_token_program_info,
+        amount,
+        signer_seeds: Some(&challenge_seeds),
+    })
+}
+
+// -----------------
+// Create Prize Pool
+// -----------------
+fn process_create_prize_pool<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    payout: Payout,
+) -> ProgramResult {
+    log_ix!("create prize pool");
+
+    assert_keys_equal(
+        "create_prize_pool:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let prize_pool_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "create_prize_pool",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_not_started("create_prize_pool", &challenge)?;
+    assert_has_no_prize_pool("create_prize_pool", &challenge)?;
+
+    let (prize_pool_pda, bump) = PrizePool::pda_for(challenge_pda_info.key);
+    assert_keys_equal(
+        "create_prize_pool:prize_pool_pda",
+        prize_pool_pda_info.key,
+        &prize_pool_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the prize pool is not valid for this challenge",
+                prize_pool_pda_info.key
+            )
+        },
+    )?;
+    assert_account_has_no_data(
+        "create_prize_pool:prize_pool_pda",
+        prize_pool_pda_info,
+    )?;
+
+    // 1. create the prize pool account
+    let prize_pool = PrizePool {
+        challenge_pda: *challenge_pda_info.key,
+        payout,
+    };
+
+    let bump_arr = [bump];
+    let seeds =
+        PrizePool::shank_seeds_with_bump(challenge_pda_info.key, &bump_arr);
+    allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+        payer_info,
+        account_info: prize_pool_pda_info,
+        owner: program_id,
+        signer_seeds: &seeds,
+        size: prize_pool.size(),
+    })?;
+
+    prize_pool.serialize_into_account(prize_pool_pda_info)?;
+
+    // 2. mark the challenge as having a prize pool
+    challenge.has_prize_pool = true;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Withdraw Prize Pool
+// -----------------
+fn process_withdraw_prize_pool<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+) -> ProgramResult {
+    log_ix!("withdraw prize pool");
+
+    assert_keys_equal(
+        "withdraw_prize_pool:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let prize_pool_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "withdraw_prize_pool",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // the escrow is kept for the refunds of the challengers once cancelled
+    assert_not_cancelled("withdraw_prize_pool", &challenge)?;
+    assert_has_prize_pool("withdraw_prize_pool", &challenge)?;
+    assert_not_in_progress("withdraw_prize_pool", &challenge)?;
+    prize_pool_state(
+        "withdraw_prize_pool:prize_pool_pda",
+        challenge_pda_info,
+        prize_pool_pda_info,
+    )?;
+
+    // 1. move all lamports of the prize pool to the creator and close it
+    close_account(CloseAccountArgs {
+        account_info: prize_pool_pda_info,
+        recvr_info: creator_info,
+    })?;
+
+    // 2. admit costs go to the creator from now on
+    challenge.has_prize_pool = false;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Set NFT Reward
+// -----------------
+fn process_set_nft_reward<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    enabled: bool,
+) -> ProgramResult {
+    log_ix!("set nft reward");
+
+    assert_keys_equal(
+        "set_nft_reward:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_nft_reward",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    challenge.nft_reward = enabled;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Commit Solution
+// -----------------
+fn process_commit_solution<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    commitment: [u8; 32],
+) -> ProgramResult {
+    log_ix!("commit solution");
+
+    assert_keys_equal(
+        "commit_solution:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+    let commitment_pda_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_system_program(
+        "commit_solution:system_program",
+        system_program_info,
+    )?;
+
+    assert_is_signer("commit_solution:payer", payer_info, "payer")?;
+    assert_is_signer(
+        "commit_solution:challenger",
+        challenger_info,
+        "challenger",
+    )?;
+
+    let challenge: Challenge = challenge_pda_info.try_state_from_account()?;
+    assert_started("commit_solution", &challenge)?;
+    assert_not_finished("commit_solution", &challenge)?;
+    assert_not_paused("commit_solution", &challenge)?;
+
+    // with tickets the holder commits with the tries of the admission of the ticket
+    if challenge.ticket_admission {
+        assert_holds_ticket(
+            "commit_solution:ticket_ata",
+            next_account_info(account_info_iter)?,
+            challenger_info.key,
+            challenger_pda_info.key,
+        )?;
+    } else {
+        let (challenger_pda, _) = Challenger::shank_pda(
+            &challenge_id(),
+            challenge_pda_info.key,
+            challenger_info.key,
+        );
+        assert_keys_equal(
+            "commit_solution:challenger_pda",
+            challenger_pda_info.key,
+            &challenger_pda,
+            ChallengeError::ProvidedChallengerPdaIncorrect,
+            || {
+                format!(
+                    "PDA account ({}) provided for the challenger is not a valid for this challenge",
+                    challenger_pda_info.key
+                )
+            },
+        )?;
+    }
+    let challenger: Challenger =
+        challenger_pda_info.try_state_from_account()?;
+    assert_challenger_has_tries_remaining("commit_solution", &challenger)?;
+
+    // 1. create the commitment account unless the challenger committed before
+    let (commitment_pda, bump) =
+        SolutionCommitment::pda_for(challenger_pda_info.key);
+    assert_keys_equal(
+        "commit_solution:commitment_pda",
+        commitment_pda_info.key,
+        &commitment_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the commitment is not valid for this challenger",
+                commitment_pda_info.key
+            )
+        },
+    )?;
+
+    if commitment_pda_info.data_is_empty() {
+        let bump_arr = [bump];
+        let seeds = SolutionCommitment::shank_seeds_with_bump(
+            challenger_pda_info.key,
+            &bump_arr,
+        );
+        allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+            payer_info,
+            account_info: commitment_pda_info,
+            owner: program_id,
+            signer_seeds: &seeds,
+            size: SolutionCommitment::size(),
+        })?;
+    }
+
+    // 2. store the commitment, replacing a previous one
+    let solution_commitment = SolutionCommitment {
+        challenger_pda: *challenger_pda_info.key,
+        commitment,
+        committed_at_slot: Now::from_sysvar()?.slot,
+    };
+    solution_commitment.serialize_into_account(commitment_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Reveal Solution
+// -----------------
+fn process_reveal_solution<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    solution: Solution,
+    nonce: [u8; 32],
+) -> ProgramResult {
+    log_ix!("reveal solution");
+    redeem_with_solution(program_id, accounts, solution, Some(nonce))
+}
+
+// -----------------
+// Set Commit Reveal
+// -----------------
+fn process_set_commit_reveal<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    required: bool,
+) -> ProgramResult {
+    log_ix!("set commit reveal");
+
+    assert_keys_equal(
+        "set_commit_reveal:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_commit_reveal",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    challenge.commit_reveal = required;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Set Solve Mode
+// -----------------
+fn process_set_solve_mode<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    solve_mode: SolveMode,
+) -> ProgramResult {
+    log_ix!("set solve mode");
+
+    assert_keys_equal(
+        "set_solve_mode:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_solve_mode",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // the solved solutions are tracked differently per mode, so it cannot change once
+    // solutions may have been redeemed
+    assert_not_started("set_solve_mode", &challenge)?;
+    assert_no_solutions_redeemed("set_solve_mode", &challenge)?;
+    challenge.solve_mode = solve_mode;
+
+    challenge.assert_invariants()?;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Set Allowlist
+// -----------------
+fn process_set_allowlist<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    root: Option<[u8; 32]>,
+) -> ProgramResult {
+    log_ix!("set allowlist");
+
+    assert_keys_equal(
+        "set_allowlist:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_system_program("set_allowlist:system_program", system_program_info)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_allowlist",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    challenge.allowlist_root = root;
+
+    resize_account_settling_rent(ResizeAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: challenge.size(),
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Set Max Admissions
+// -----------------
+fn process_set_max_admissions<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    max_admissions: Option<u32>,
+) -> ProgramResult {
+    log_ix!("set max admissions");
+
+    assert_keys_equal(
+        "set_max_admissions:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_system_program(
+        "set_max_admissions:system_program",
+        system_program_info,
+    )?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_max_admissions",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    challenge.max_admissions = max_admissions;
+
+    resize_account_settling_rent(ResizeAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: challenge.size(),
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Create First Solvers
+// -----------------
+fn process_create_first_solvers<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+) -> ProgramResult {
+    log_ix!("create first solvers");
+
+    assert_keys_equal(
+        "create_first_solvers:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let first_solvers_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "create_first_solvers",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    let (first_solvers_pda, bump) =
+        FirstSolvers::pda_for(challenge_pda_info.key);
+    assert_keys_equal(
+        "create_first_solvers:first_solvers_pda",
+        first_solvers_pda_info.key,
+        &first_solvers_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the first solvers is not valid for this challenge",
+                first_solvers_pda_info.key
+            )
+        },
+    )?;
+    assert_account_has_no_data(
+        "create_first_solvers:first_solvers_pda",
+        first_solvers_pda_info,
+    )?;
+
+    // 1. create the leaderboard with room for all current solutions
+    let first_solvers =
+        FirstSolvers::new(*challenge_pda_info.key, challenge.solutions.len());
+
+    let bump_arr = [bump];
+    let seeds =
+        FirstSolvers::shank_seeds_with_bump(challenge_pda_info.key, &bump_arr);
+    allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+        payer_info,
+        account_info: first_solvers_pda_info,
+        owner: program_id,
+        signer_seeds: &seeds,
+        size: first_solvers.size(),
+    })?;
+
+    first_solvers.serialize_into_account(first_solvers_pda_info)?;
+
+    // 2. have redeem record first solvers from now on
+    challenge.has_first_solvers = true;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Append Solutions Chunk
+// -----------------
+fn process_append_solutions_chunk<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    chunk_index: u8,
+    total_chunks: u8,
+    solutions: Vec<Solution>,
+) -> ProgramResult {
+    log_ix!("append solutions chunk");
+
+    assert_keys_equal(
+        "append_solutions_chunk:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+    assert_adding_non_empty("append_solutions_chunk", &solutions)?;
+    assert_valid_solutions_chunk(
+        "append_solutions_chunk",
+        chunk_index,
+        total_chunks,
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let solutions_upload_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "append_solutions_chunk",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_solutions_not_locked("append_solutions_chunk", &challenge)?;
+    assert_can_add_after_start("append_solutions_chunk", &challenge)?;
+
+    let (solutions_upload_pda, bump) =
+        SolutionsUpload::pda_for(challenge_pda_info.key);
+    assert_keys_equal(
+        "append_solutions_chunk:solutions_upload_pda",
+        solutions_upload_pda_info.key,
+        &solutions_upload_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the solutions upload is not valid for this challenge",
+                solutions_upload_pda_info.key
+            )
+        },
+    )?;
+
+    // 1. collect the chunk, the first chunk always starts a new upload
+    let mut upload = if chunk_index == 0 {
+        SolutionsUpload::new(*challenge_pda_info.key, total_chunks)
+    } else {
+        let upload: SolutionsUpload =
+            solutions_upload_pda_info.try_state_from_account()?;
+        assert_expected_solutions_chunk(
+            "append_solutions_chunk",
+            &upload,
+            chunk_index,
+            total_chunks,
+        )?;
+        upload
+    };
+    // checked per chunk to keep the compute units of the last chunk in check
+    assert_no_weak_solutions("append_solutions_chunk", &challenge, &solutions)?;
+    upload.solutions.extend(solutions);
+    upload.next_chunk = chunk_index + 1;
+
+    assert_can_add_solutions(
+        "append_solutions_chunk",
+        &challenge.solutions,
+        &upload.solutions,
+    )?;
+
+    // 2. once the last chunk arrived append all solutions to the challenge at once
+    if upload.next_chunk == upload.total_chunks {
+        let solutions_before = challenge.solutions.len();
+        challenge.solutions.extend(upload.solutions);
+        challenge.append_rewards(solutions_before, &[])?;
+        challenge.append_hints(solutions_before, &[]);
+        reallocate_account(ReallocateAccountArgs {
+            payer_info,
+            account_info: challenge_pda_info,
+            new_size: challenge.size(),
+            zero_init: false,
+        })?;
+        challenge.assert_invariants()?;
+        challenge.serialize_into_account(challenge_pda_info)?;
+
+        if !solutions_upload_pda_info.data_is_empty() {
+            close_account(CloseAccountArgs {
+                account_info: solutions_upload_pda_info,
+                recvr_info: payer_info,
+            })?;
+        }
+        return Ok(());
+    }
+
+    // 3. otherwise store the upload until the next chunk arrives
+    if solutions_upload_pda_info.data_is_empty() {
+        let bump_arr = [bump];
+        let seeds = SolutionsUpload::shank_seeds_with_bump(
+            challenge_pda_info.key,
+            &bump_arr,
+        );
+        allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+            payer_info,
+            account_info: solutions_upload_pda_info,
+            owner: program_id,
+            signer_seeds: &seeds,
+            size: upload.size(),
+        })?;
+    } else {
+        reallocate_account(ReallocateAccountArgs {
+            payer_info,
+            account_info: solutions_upload_pda_info,
+            new_size: upload.size(),
+            zero_init: false,
+        })?;
+    }
+    upload.serialize_into_account(solutions_upload_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Init Config
+// -----------------
+fn process_init_config<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    fee_bps: u16,
+    fee_destination: Pubkey,
+) -> ProgramResult {
+    log_ix!("init config");
+
+    assert_keys_equal(
+        "init_config:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+    assert_valid_fee_bps("init_config", fee_bps)?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_pda_info = next_account_info(account_info_iter)?;
+    let program_data_info = next_account_info(account_info_iter)?;
+
+    assert_is_signer("init_config:admin", admin_info, "admin")?;
+    // only the upgrade authority may claim the config, otherwise anyone getting there
+    // first would collect the protocol fee
+    assert_program_upgrade_authority(
+        "init_config:program_data",
+        program_data_info,
+        admin_info.key,
+    )?;
+
+    let (config_pda, bump) = Config::pda();
+    assert_keys_equal(
+        "init_config:config_pda",
+        config_pda_info.key,
+        &config_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the config is not the program config PDA",
+                config_pda_info.key
+            )
+        },
+    )?;
+    assert_account_has_no_data("init_config:config_pda", config_pda_info)?;
+
+    let config = Config {
+        admin: *admin_info.key,
+        fee_bps,
+        fee_destination,
+    };
+
+    let bump_arr = [bump];
+    let seeds = Config::shank_seeds_with_bump(&bump_arr);
+    allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+        payer_info,
+        account_info: config_pda_info,
+        owner: program_id,
+        signer_seeds: &seeds,
+        size: Config::size(),
+    })?;
+
+    config.serialize_into_account(config_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Update Config
+// -----------------
+fn process_update_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: Option<u16>,
+    fee_destination: Option<Pubkey>,
+    admin: Option<Pubkey>,
+) -> ProgramResult {
+    log_ix!("update config");
+
+    assert_keys_equal(
+        "update_config:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Config> {
+        state: mut config, ..
+    } = Config::account_state_verifying_admin(
+        "update_config",
+        config_pda_info,
+        admin_info,
+    )?;
+
+    if let Some(fee_bps) = fee_bps {
+        assert_valid_fee_bps("update_config", fee_bps)?;
+        config.fee_bps = fee_bps;
+    }
+    if let Some(fee_destination) = fee_destination {
+        config.fee_destination = fee_destination;
+    }
+    if let Some(admin) = admin {
+        config.admin = admin;
+    }
+
+    config.serialize_into_account(config_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Cancel Challenge
+// -----------------
+fn process_cancel_challenge<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+) -> ProgramResult {
+    log_ix!("cancel challenge");
+
+    assert_keys_equal(
+        "cancel_challenge:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "cancel_challenge",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+    cancel_challenge(&mut challenge)?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Refund Unused Tries
+// -----------------
+fn process_refund_unused_tries<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+) -> ProgramResult {
+    log_ix!("refund unused tries");
+
+    assert_keys_equal(
+        "refund_unused_tries:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let prize_pool_info = next_account_info(account_info_iter)?;
+
+    assert_system_program(
+        "refund_unused_tries:system_program",
+        system_program_info,
+    )?;
+
+    assert_is_signer(
+        "refund_unused_tries:challenger",
+        challenger_info,
+        "challenger",
+    )?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: challenge,
+        bump,
+        ..
+    } = Challenge::account_state(
+        "refund_unused_tries",
+        challenge_pda_info,
+        &id,
+    )?;
+
+    let StateFromPdaAccountValue::<Challenger> {
+        state: mut challenger,
+        ..
+    } = challenger_pda_info.try_state_from_pda_account(|| {
+        Challenger::shank_pda(
+            &challenge_id(),
+            challenge_pda_info.key,
+            challenger_info.key,
+        )
+    })?;
+
+    // 1. mark the tries as refunded
+    let refund = refund_unused_tries(&challenge, &mut challenger)?;
+    challenger.serialize_into_account(challenger_pda_info)?;
+
+    // 2. pay the refund out of the escrow that holds the admit cost
+    log_state!("Refunding {} for unused tries", refund);
+    match challenge.admit_mint {
+        Some(admit_mint) => {
+            let AdmitMintAccounts {
+                admit_mint_info,
+                payer_ata_info: challenger_ata_info,
+                escrow_ata_info,
+                spl_token_program_info,
+            } = admit_mint_accounts(
+                "refund_unused_tries",
+                &admit_mint,
+                account_info_iter,
+            )?;
+            assert_owned_by(
+                "refund_unused_tries:escrow_ata",
+                escrow_ata_info,
+                &spl_token::id(),
+                "escrow",
+            )?;
+
+            let bump_arr = [bump];
+            let challenge_seeds = challenge.seeds(&bump_arr);
+            transfer_tokens_to_recvr(TransferTokensArgs {
+                payer_info: challenger_info,
+                source_owner_info: challenge_pda_info,
+                source_ata_info: escrow_ata_info,
+                recvr_info: challenger_info,
+                recvr_ata_info: challenger_ata_info,
+                mint_info: admit_mint_info,
+                spl_token_program_info,
+                amount: refund,
+                signer_seeds: Some(&challenge_seeds),
+            })
+        }
+        None => {
+            prize_pool_state(
+                "refund_unused_tries:prize_pool",
+                challenge_pda_info,
+                prize_pool_info,
+            )?;
+            let available = prize_pool_info.lamports().saturating_sub(
+                Rent::get()?.minimum_balance(PrizePool::size()),
+            );
+            assert_prize_pool_covers_refund(
+                "refund_unused_tries:prize_pool",
+                available,
+                refund,
+            )?;
+            transfer_lamports_from_program_account(
+                prize_pool_info,
+                challenger_info,
+                refund,
+            )
+        }
+    }
+}
+
+// -----------------
+// Migrate Account
+// -----------------
+fn process_migrate_account<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    kind: AccountKind,
+) -> ProgramResult {
+    log_ix!("migrate account");
+
+    assert_keys_equal(
+        "migrate_account:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let account_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_system_program(
+        "migrate_account:system_program",
+        system_program_info,
+    )?;
+
+    assert_is_signer("migrate_account:payer", payer_info, "payer")?;
+    assert_owned_by(
+        "migrate_account:owner",
+        account_info,
+        program_id,
+        "account",
+    )?;
+
+    match kind {
+        AccountKind::Challenge => migrate_challenge(payer_info, account_info),
+        AccountKind::Challenger => {
+            migrate_legacy_account::<Challenger>(payer_info, account_info)
+        }
+        AccountKind::SolutionCommitment => migrate_legacy_account::<
+            SolutionCommitment,
+        >(payer_info, account_info),
+        AccountKind::Config => {
+            migrate_legacy_account::<Config>(payer_info, account_info)
+        }
+        AccountKind::FirstSolvers => {
+            migrate_legacy_account::<FirstSolvers>(payer_info, account_info)
+        }
+        AccountKind::Leaderboard => {
+            migrate_legacy_account::<Leaderboard>(payer_info, account_info)
+        }
+        AccountKind::PrizePool => {
+            migrate_legacy_account::<PrizePool>(payer_info, account_info)
+        }
+        AccountKind::SolutionsUpload => {
+            migrate_legacy_account::<SolutionsUpload>(payer_info, account_info)
+        }
+        AccountKind::SponsorLog => {
+            migrate_legacy_account::<SponsorLog>(payer_info, account_info)
+        }
+        AccountKind::ChallengeTemplate => migrate_legacy_account::<
+            ChallengeTemplate,
+        >(payer_info, account_info),
+    }
+}
+
+/// Deserializes the legacy state of the account which has no discriminator, verifies that it
+/// is stored at its PDA and rewrites it prefixed with its discriminator.
+fn migrate_legacy_account<'a, T: HasDiscriminator + HasPda>(
+    payer_info: &'a AccountInfo<'a>,
+    account_info: &'a AccountInfo<'a>,
+) -> ProgramResult {
+    let legacy_data = account_info.try_borrow_data()?.to_vec();
+    assert_not_migrated("migrate_account", &legacy_data, &T::DISCRIMINATOR)?;
+
+    let state = T::try_from_slice(&legacy_data)?;
+    write_migrated_account(
+        payer_info,
+        account_info,
+        &state,
+        legacy_data.len() + DISCRIMINATOR_LEN,
+    )
+}
+
+/// Upgrades the challenge from the layout version it is stored with to the current one,
+/// including challenges that were stored before discriminators were introduced.
+fn migrate_challenge<'a>(
+    payer_info: &'a AccountInfo<'a>,
+    account_info: &'a AccountInfo<'a>,
+) -> ProgramResult {
+    let data = account_info.try_borrow_data()?.to_vec();
+    let version = Challenge::stored_version(&data);
+    let layout_data = if data.starts_with(&Challenge::DISCRIMINATOR) {
+        &data[DISCRIMINATOR_LEN..]
+    } else {
+        &data[..]
+    };
+
+    let mut challenge = Challenge::try_upgrade_from(version, layout_data)?;
+    log_state!(
+        "Upgrading challenge from version {} to version {}",
+        version,
+        challenge.version
+    );
+    let new_size = challenge.size();
+
+    // co-sponsored lamports were not accounted before, so all lamports the challenge holds
+    // beyond rent are assumed to be owed to never sweep any of them
+    if version <= UNACCOUNTED_CHALLENGE_VERSION {
+        challenge.sponsored_lamports = account_info
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(new_size));
+    }
+    write_migrated_account(payer_info, account_info, &challenge, new_size)
+}
+
+/// Verifies that the migrated [state] is stored at its PDA and writes it prefixed with its
+/// discriminator after resizing the account to [new_size].
+fn write_migrated_account<'a, T: HasDiscriminator + HasPda>(
+    payer_info: &'a AccountInfo<'a>,
+    account_info: &'a AccountInfo<'a>,
+    state: &T,
+    new_size: usize,
+) -> ProgramResult {
+    let (pda, _) = state.pda();
+    assert_keys_equal(
+        "migrate_account:pda",
+        account_info.key,
+        &pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+            "The PDA ({}) derived from the account state does not match the account ({})",
+            pda, account_info.key
+        )
+        },
+    )?;
+
+    reallocate_account(ReallocateAccountArgs {
+        payer_info,
+        account_info,
+        new_size,
+        zero_init: false,
+    })?;
+    state.serialize_into_account(account_info)
+}
+
+// -----------------
+// Admins
+// -----------------
+fn process_add_admin<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    admin: Pubkey,
+) -> ProgramResult {
+    log_ix!("add admin");
+
+    assert_keys_equal(
+        "add_admin:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "add_admin",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_can_add_admin("add_admin", &challenge, &admin)?;
+    challenge.admins.push(admin);
+
+    resize_account_settling_rent(ResizeAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: challenge.size(),
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+fn process_remove_admin<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    admin: Pubkey,
+) -> ProgramResult {
+    log_ix!("remove admin");
+
+    assert_keys_equal(
+        "remove_admin:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "remove_admin",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_is_admin("remove_admin", &challenge, &admin)?;
+    challenge.admins.retain(|key| key.ne(&admin));
+
+    resize_account_settling_rent(ResizeAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: challenge.size(),
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Hints
+// -----------------
+fn process_set_hints<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    hints: Vec<Hint>,
+    hint_fee: u64,
+) -> ProgramResult {
+    log_ix!("set hints");
+
+    assert_keys_equal(
+        "set_hints:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_hints",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_hints_match_solutions("set_hints", &challenge, &hints)?;
+    challenge.hints = hints;
+    challenge.hint_fee = hint_fee;
+
+    resize_account_settling_rent(ResizeAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: challenge.size(),
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.assert_invariants()?;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+fn process_reveal_hint<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    solution_index: u8,
+) -> ProgramResult {
+    log_ix!("reveal hint");
+
+    assert_keys_equal(
+        "reveal_hint:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+    let revealed_hints_pda_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let prize_pool_info = next_account_info(account_info_iter)?;
+
+    assert_is_signer("reveal_hint:payer", payer_info, "payer")?;
+    assert_is_signer("reveal_hint:challenger", challenger_info, "challenger")?;
+
+    let challenge: Challenge = challenge_pda_info.try_state_from_account()?;
+    assert_started("reveal_hint", &challenge)?;
+    assert_not_finished("reveal_hint", &challenge)?;
+
+    // 1. only admitted challengers may reveal hints
+    let (challenger_pda, _) = Challenger::shank_pda(
+        &challenge_id(),
+        challenge_pda_info.key,
+        challenger_info.key,
+    );
+    assert_keys_equal(
+        "reveal_hint:challenger_pda",
+        challenger_pda_info.key,
+        &challenger_pda,
+        ChallengeError::ProvidedChallengerPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the challenger is not a valid for this challenge",
+                challenger_pda_info.key
+            )
+        },
+    )?;
+    let _challenger: Challenger =
+        challenger_pda_info.try_state_from_account()?;
+
+    // 2. create the revealed hints account unless the challenger revealed a hint before
+    let (revealed_hints_pda, bump) =
+        RevealedHints::pda_for(challenger_pda_info.key);
+    assert_keys_equal(
+        "reveal_hint:revealed_hints_pda",
+        revealed_hints_pda_info.key,
+        &revealed_hints_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the revealed hints is not valid for this challenger",
+                revealed_hints_pda_info.key
+            )
+        },
+    )?;
+
+    let mut revealed_hints = if revealed_hints_pda_info.data_is_empty() {
+        let bump_arr = [bump];
+        let seeds = RevealedHints::shank_seeds_with_bump(
+            challenger_pda_info.key,
+            &bump_arr,
+        );
+        allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+            payer_info,
+            account_info: revealed_hints_pda_info,
+            owner: program_id,
+            signer_seeds: &seeds,
+            size: RevealedHints::size(),
+        })?;
+        RevealedHints {
+            challenger_pda: *challenger_pda_info.key,
+            revealed: [0; 32],
+        }
+    } else {
+        revealed_hints_pda_info.try_state_from_account()?
+    };
+
+    let hint = assert_can_reveal_hint(
+        "reveal_hint",
+        &challenge,
+        &revealed_hints,
+        solution_index,
+    )?;
+
+    // 3. pay the hint fee into the prize pool or to the authority
+    if challenge.hint_fee > 0 {
+        let recvr_info = if challenge.has_prize_pool {
+            prize_pool_state(
+                "reveal_hint:prize_pool",
+                challenge_pda_info,
+                prize_pool_info,
+            )?;
+            prize_pool_info
+        } else {
+            assert_keys_equal(
+                "reveal_hint:authority",
+                authority_info.key,
+                &challenge.authority,
+                ChallengeError::ProvidedCreatorIncorrect,
+                || {
+                    format!(
+                        "Provided authority ({}) is not the authority ({}) of the challenge",
+                        authority_info.key, challenge.authority
+                    )
+                },
+            )?;
+            authority_info
+        };
+        transfer_lamports(payer_info, recvr_info, challenge.hint_fee)?;
+    }
+
+    // 4. record the hint as accessible for the challenger
+    revealed_hints.mark_revealed(solution_index);
+    revealed_hints.serialize_into_account(revealed_hints_pda_info)?;
+
+    ChallengeEvent::HintRevealed {
+        challenge_pda: *challenge_pda_info.key,
+        challenger: *challenger_info.key,
+        solution_index,
+        hint: *hint,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+// -----------------
+// Set Attempt Cooldown
+// -----------------
+fn process_set_attempt_cooldown<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    attempt_cooldown_slots: u64,
+) -> ProgramResult {
+    log_ix!("set attempt cooldown");
+
+    assert_keys_equal(
+        "set_attempt_cooldown:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_attempt_cooldown",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    challenge.attempt_cooldown_slots = attempt_cooldown_slots;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Create Winners
+// -----------------
+fn process_create_winners<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    max_winners_per_solution: u8,
+) -> ProgramResult {
+    log_ix!("create winners");
+
+    assert_keys_equal(
+        "create_winners:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let winners_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "create_winners",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // solutions solved by a single winner would not be redeemable by more
+    assert_not_started("create_winners", &challenge)?;
+    assert_valid_max_winners_per_solution(
+        "create_winners",
+        max_winners_per_solution,
+    )?;
+
+    let (winners_pda, bump) = Winners::pda_for(challenge_pda_info.key);
+    assert_keys_equal(
+        "create_winners:winners_pda",
+        winners_pda_info.key,
+        &winners_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the winners is not valid for this challenge",
+                winners_pda_info.key
+            )
+        },
+    )?;
+    assert_account_has_no_data("create_winners:winners_pda", winners_pda_info)?;
+
+    // 1. create the leaderboard without winners for all current solutions
+    let winners =
+        Winners::new(*challenge_pda_info.key, challenge.solutions.len());
+
+    let bump_arr = [bump];
+    let seeds =
+        Winners::shank_seeds_with_bump(challenge_pda_info.key, &bump_arr);
+    allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+        payer_info,
+        account_info: winners_pda_info,
+        owner: program_id,
+        signer_seeds: &seeds,
+        size: winners.size(),
+    })?;
+
+    winners.serialize_into_account(winners_pda_info)?;
+
+    // 2. have redeem record the winners of each solution from now on
+    challenge.max_winners_per_solution = max_winners_per_solution;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Set Solution Normalization
+// -----------------
+fn process_set_solution_normalization<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    solution_normalization: SolutionNormalization,
+) -> ProgramResult {
+    log_ix!("set solution normalization");
+
+    assert_keys_equal(
+        "set_solution_normalization:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_solution_normalization",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // challengers may already be hashing their solutions with the current rules
+    assert_not_started("set_solution_normalization", &challenge)?;
+
+    challenge.solution_normalization = solution_normalization;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Check Solution
+// -----------------
+fn process_check_solution(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    solution: Solution,
+) -> ProgramResult {
+    log_ix!("check solution");
+
+    assert_keys_equal(
+        "check_solution:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    // the stored solutions are public, thus checking a solution without consuming a try
+    // reveals nothing that couldn't be computed from the account data
+    let header = ChallengeHeader::try_from_account(challenge_pda_info)?;
+    let solution_index = header.matching_solution_index(
+        &challenge_pda_info.try_borrow_data()?,
+        &solution,
+    );
+    match solution_index {
+        Some(solution_index) => {
+            log_state!("Provided solution solves solution {}", solution_index)
+        }
+        None => log_state!("Provided solution is incorrect"),
+    }
+
+    CheckSolutionResult { solution_index }.set()
+}
+
+// -----------------
+// Set Reject Weak Solutions
+// -----------------
+fn process_set_reject_weak_solutions<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    reject_weak_solutions: bool,
+) -> ProgramResult {
+    log_ix!("set reject weak solutions");
+
+    assert_keys_equal(
+        "set_reject_weak_solutions:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_reject_weak_solutions",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    challenge.reject_weak_solutions = reject_weak_solutions;
+    // the solutions added before need to hold up to the same standard
+    assert_no_weak_solutions(
+        "set_reject_weak_solutions",
+        &challenge,
+        &challenge.solutions,
+    )?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Set Ticket Admission
+// -----------------
+fn process_set_ticket_admission<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    enabled: bool,
+) -> ProgramResult {
+    log_ix!("set ticket admission");
+
+    assert_keys_equal(
+        "set_ticket_admission:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_ticket_admission",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+    assert_not_started("set_ticket_admission", &challenge)?;
+
+    challenge.ticket_admission = enabled;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Set Metadata
+// -----------------
+fn process_set_metadata<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    name: String,
+    description: String,
+    uri: String,
+) -> ProgramResult {
+    log_ix!("set metadata");
+
+    assert_keys_equal(
+        "set_metadata:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let metadata_pda_info = next_account_info(account_info_iter)?;
+
+    Challenge::account_state_verifying_creator(
+        "set_metadata",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    let metadata = ChallengeMetadata {
+        challenge_pda: *challenge_pda_info.key,
+        name,
+        description,
+        uri,
+    };
+    assert_valid_metadata("set_metadata", &metadata)?;
+
+    let (metadata_pda, bump) =
+        ChallengeMetadata::pda_for(challenge_pda_info.key);
+    assert_keys_equal(
+        "set_metadata:metadata_pda",
+        metadata_pda_info.key,
+        &metadata_pda,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "PDA account ({}) provided for the metadata is not valid for this challenge",
+                metadata_pda_info.key
+            )
+        },
+    )?;
+
+    // 1. create the metadata account the first time, otherwise resize it to fit
+    if metadata_pda_info.data_is_empty() {
+        let bump_arr = [bump];
+        let seeds = ChallengeMetadata::shank_seeds_with_bump(
+            challenge_pda_info.key,
+            &bump_arr,
+        );
+        allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+            payer_info,
+            account_info: metadata_pda_info,
+            owner: program_id,
+            signer_seeds: &seeds,
+            size: metadata.size(),
+        })?;
+    } else {
+        let _: ChallengeMetadata =
+            metadata_pda_info.try_state_from_account()?;
+        resize_account_settling_rent(ResizeAccountArgs {
+            payer_info,
+            account_info: metadata_pda_info,
+            new_size: metadata.size(),
+            reserved_lamports: 0,
+        })?;
+    }
+
+    // 2. replace the metadata
+    metadata.serialize_into_account(metadata_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Set Challenge Flags
+// -----------------
+fn process_set_challenge_flags<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    flags: u32,
+) -> ProgramResult {
+    log_ix!("set challenge flags");
+
+    assert_keys_equal(
+        "set_challenge_flags:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let _payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_challenge_flags",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+    assert_not_started("set_challenge_flags", &challenge)?;
+    assert_known_challenge_flags("set_challenge_flags", flags)?;
+
+    challenge.flags = flags;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Create Challenge Idempotent
+// -----------------
+fn process_create_challenge_idempotent<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: CreateChallengeArgs,
+    creator_registry_info: Option<&'a AccountInfo<'a>>,
+) -> ProgramResult {
+    log_ix!("create challenge idempotent");
+
+    assert_keys_equal(
+        "create_challenge_idempotent:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    if challenge_pda_info.data_is_empty() {
+        return process_create_challenge(
+            program_id,
+            accounts,
+            args,
+            creator_registry_info,
+        );
+    }
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: challenge, ..
+    } = Challenge::account_state(
+        "create_challenge_idempotent",
+        challenge_pda_info,
+        &args.id,
+    )?;
+
+    let CreateChallengeArgs {
+        id,
+        admit_cost,
+        tries_per_admit,
+        redeem,
+        solutions,
+        salt,
+        hash_algorithm,
+        starts_at,
+        ends_at,
+        seed,
+    } = args;
+    let expected = Challenge {
+        authority: *creator_info.key,
+        id,
+        admit_cost,
+        tries_per_admit,
+        redeem,
+        solutions,
+        salt,
+        hash_algorithm,
+        starts_at,
+        ends_at,
+        seed,
+        ..Challenge::default()
+    };
+    assert_challenge_matches(
+        "create_challenge_idempotent",
+        &challenge,
+        &expected,
+    )?;
+
+    // the registry is updated as well in case the earlier attempt did not include it
+    if let Some(creator_registry_info) = creator_registry_info {
+        register_challenge(RegisterChallengeArgs {
+            context: "create_challenge_idempotent:creator_registry",
+            program_id,
+            payer_info,
+            creator: creator_info.key,
+            challenge_pda: challenge_pda_info.key,
+            creator_registry_info,
+        })?;
+    }
+
+    log_state!("Challenge already exists with matching parameters");
+
+    Ok(())
+}
+
+// -----------------
+// Buy More Tries
+// -----------------
+fn process_buy_more_tries<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    challenge_pda: Pubkey,
+    count: u8,
+) -> ProgramResult {
+    log_ix!("buy more tries");
+
+    assert_keys_equal(
+        "buy_more_tries:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+
+    assert_keys_equal(
+        "buy_more_tries:challenge_pda",
+        challenge_pda_info.key,
+        &challenge_pda,
+        ChallengeError::ProvidedChallengePdaIncorrect,
+        || {
+            format!(
+                "Provided challenge pda ({}) does not match the PDA account ({}) provided in the instruction",
+                challenge_pda, challenge_pda_info.key
+            )
+        },
+    )?;
+
+    let challenge: Challenge = challenge_pda_info.try_state_from_account()?;
+    assert_started("buy_more_tries", &challenge)?;
+    assert_not_finished("buy_more_tries", &challenge)?;
+    assert_not_paused("buy_more_tries", &challenge)?;
+    assert_within_schedule(
+        "buy_more_tries",
+        &challenge,
+        Now::from_sysvar()?.unix_timestamp,
+    )?;
+    assert_keys_equal(
+        "buy_more_tries:creator",
+        creator_info.key,
+        &challenge.authority,
+        ChallengeError::ProvidedCreatorIncorrect,
+        || {
+            format!(
+                "Provided creator ({}) is not the authority ({}) of the challenge",
+                creator_info.key, challenge.authority
+            )
+        },
+    )?;
+
+    let StateFromPdaAccountValue::<Challenger> {
+        state: mut challenger,
+        ..
+    } = challenger_pda_info.try_state_from_pda_account(|| {
+        Challenger::shank_pda(
+            &challenge_id(),
+            &challenge_pda,
+            challenger_info.key,
+        )
+    })?;
+
+    // 1. add the bought tries to the ones the challenger has left
+    let cost = buy_more_tries(&challenge, &mut challenger, count)?;
+
+    // 2. transfer the pro-rated admit cost to creator account or escrow
+    let escrowed = pay_admit_cost(PayAdmitCostArgs {
+        context: "buy_more_tries",
+        challenge: &challenge,
+        cost,
+        payer_info,
+        creator_info,
+        challenge_pda_info,
+        account_info_iter,
+    })?;
+    challenger.record_escrowed(escrowed);
+    challenger.serialize_into_account(challenger_pda_info)?;
+
+    log_state!(
+        "Challenger ({}) bought {} tries and has {} tries left",
+        challenger.authority,
+        count,
+        challenger.tries_remaining
+    );
+
+    Ok(())
+}
+
+// -----------------
+// Set Max Tries Per Challenger
+// -----------------
+fn process_set_max_tries_per_challenger<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    max_tries: Option<u16>,
+) -> ProgramResult {
+    log_ix!("set max tries per challenger");
+
+    assert_keys_equal(
+        "set_max_tries_per_challenger:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_system_program(
+        "set_max_tries_per_challenger:system_program",
+        system_program_info,
+    )?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_max_tries_per_challenger",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    let size_change = set_max_tries_per_challenger(&mut challenge, max_tries)?;
+
+    resize_account_settling_rent(ResizeAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: size_change.after,
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Sweep Surplus
+// -----------------
+fn process_sweep_surplus<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+) -> ProgramResult {
+    log_ix!("sweep surplus");
+
+    assert_keys_equal(
+        "sweep_surplus:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let treasury_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: challenge, ..
+    } = Challenge::account_state_verifying_creator(
+        "sweep_surplus",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+    assert_keys_not_equal(
+        "sweep_surplus:treasury",
+        treasury_info.key,
+        challenge_pda_info.key,
+        || "The treasury cannot be the challenge being swept".to_string(),
+    )?;
+
+    // Only the challenge PDA is swept since all lamports of the prize pool beyond rent are
+    // owed to winners, and lamports co-sponsors contributed to the challenge stay with it
+    let surplus = challenge.surplus_lamports(
+        challenge_pda_info.lamports(),
+        Rent::get()?.minimum_balance(challenge_pda_info.data_len()),
+    );
+    assert_has_surplus("sweep_surplus", &challenge, surplus)?;
+
+    log_state!("Sweeping {} surplus lamports to treasury", surplus);
+    transfer_lamports_from_program_account(
+        challenge_pda_info,
+        treasury_info,
+        surplus,
+    )
+}
+
+// -----------------
+// Set Prerequisite
+// -----------------
+fn process_set_prerequisite<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    prerequisite: Option<Pubkey>,
+) -> ProgramResult {
+    log_ix!("set prerequisite");
+
+    assert_keys_equal(
+        "set_prerequisite:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    assert_system_program(
+        "set_prerequisite:system_program",
+        system_program_info,
+    )?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_prerequisite",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+    let size_change =
+        set_prerequisite(&mut challenge, challenge_pda_info.key, prerequisite)?;
+
+    resize_account_settling_rent(ResizeAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: size_change.after,
+        reserved_lamports: challenge.sponsored_lamports,
+    })?;
+
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
+
+// -----------------
+// Set Epochs
+// -----------------
+fn process_set_epochs(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    id: String,
+    epoch_slots: u64,
+    solutions_per_epoch: u8,
+) -> ProgramResult {
+    log_ix!("set epochs");
+
+    assert_keys_equal(
+        "set_epochs:program_id",
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program id ({}) does not match this program's id ({})",
+                program_id,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        "set_epochs",
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+    set_epochs(&mut challenge, epoch_slots, solutions_per_epoch)?;
+    challenge.serialize_into_account(challenge_pda_info)?;
+
+    Ok(())
+}
@@ -0,0 +1,420 @@
+//! State transitions of the processors which only operate on already deserialized state.
+//!
+//! The processors verify the provided accounts, load the state and hand it to the matching
+//! transition here before they resize and serialize the accounts. Keeping the account plumbing
+//! out of these allows covering the logic without running a validator.
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    clock::Now,
+    log::log_state,
+    state::{
+        Challenge, ChallengeHeader, Challenger, HasSize, Payout, PrizePool,
+        SolveMode,
+    },
+    utils::{
+        assert_admit_cost_not_locked, assert_attempt_cooldown_passed,
+        assert_can_add_after_start, assert_can_add_solutions,
+        assert_can_add_tries, assert_challenger_has_tries_remaining,
+        assert_has_admissions_left, assert_has_refund,
+        assert_has_refund_escrow, assert_has_solutions,
+        assert_no_challengers_admitted, assert_no_duplicate_solutions,
+        assert_no_solutions_redeemed, assert_no_weak_solutions,
+        assert_not_cancelled, assert_not_finished, assert_not_paused,
+        assert_not_started, assert_on_allowlist, assert_realloc_within_limit,
+        assert_refunds_allowed, assert_solutions_not_locked, assert_started,
+        assert_valid_epochs, assert_valid_prerequisite,
+        assert_valid_solution_indices_to_remove,
+        assert_valid_solutions_split_index, assert_valid_tries_per_admit,
+        assert_within_max_tries_per_challenger, assert_within_schedule,
+    },
+    Solution,
+};
+
+/// The size of an account before and after a transition was applied to its state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeChange {
+    pub before: usize,
+    pub after: usize,
+}
+
+impl SizeChange {
+    fn of<T: HasSize>(
+        state: &mut T,
+        transition: impl FnOnce(&mut T) -> Result<(), ProgramError>,
+    ) -> Result<Self, ProgramError> {
+        let before = state.size();
+        transition(state)?;
+        Ok(Self {
+            before,
+            after: state.size(),
+        })
+    }
+}
+
+// -----------------
+// Solutions
+// -----------------
+
+/// Appends the [extra] solutions together with their [rewards] to the [challenge].
+pub fn add_solutions(
+    challenge: &mut Challenge,
+    extra: Vec<Solution>,
+    allow_duplicates: bool,
+    rewards: &[u64],
+) -> Result<SizeChange, ProgramError> {
+    assert_solutions_not_locked("add_solutions", challenge)?;
+    assert_can_add_after_start("add_solutions", challenge)?;
+    assert_can_add_solutions("add_solutions", &challenge.solutions, &extra)?;
+    if !allow_duplicates {
+        assert_no_duplicate_solutions(
+            "add_solutions",
+            &challenge.solutions,
+            &extra,
+        )?;
+    }
+    assert_no_weak_solutions("add_solutions", challenge, &extra)?;
+    assert_realloc_within_limit(
+        "add_solutions",
+        challenge.size_delta(extra.len()),
+    )?;
+
+    SizeChange::of(challenge, |challenge| {
+        let solutions_before = challenge.solutions.len();
+        challenge.solutions.extend(extra);
+        challenge.append_rewards(solutions_before, rewards)?;
+        challenge.append_hints(solutions_before, &[]);
+        challenge.assert_invariants()
+    })
+}
+
+/// Keeps only the first [len] solutions of the [challenge].
+pub fn truncate_solutions(
+    challenge: &mut Challenge,
+    len: u8,
+) -> Result<SizeChange, ProgramError> {
+    assert_solutions_not_locked("truncate_solutions", challenge)?;
+    assert_no_solutions_redeemed("truncate_solutions", challenge)?;
+    assert_valid_solutions_split_index("truncate_solutions", challenge, len)?;
+
+    SizeChange::of(challenge, |challenge| {
+        challenge.solutions.truncate(len as usize);
+        challenge.rewards.truncate(len as usize);
+        challenge.hints.truncate(len as usize);
+        challenge.assert_invariants()
+    })
+}
+
+/// Removes the solutions at [indices] from the [challenge] while keeping solving pointed at
+/// the same solution.
+/// Only allowed until the first challenger is admitted as the remaining solutions shift to
+/// lower indices.
+pub fn remove_solutions(
+    challenge: &mut Challenge,
+    mut indices: Vec<u8>,
+) -> Result<SizeChange, ProgramError> {
+    assert_solutions_not_locked("remove_solutions", challenge)?;
+    assert_no_challengers_admitted("remove_solutions", challenge)?;
+    assert_valid_solution_indices_to_remove(
+        "remove_solutions",
+        challenge,
+        &indices,
+    )?;
+
+    SizeChange::of(challenge, |challenge| {
+        // 1. remove solutions starting with the highest index so lower indices stay valid
+        let len_before_removal = challenge.solutions.len();
+        indices.sort_unstable();
+        for idx in indices.iter().rev() {
+            challenge.solutions.remove(*idx as usize);
+        }
+        challenge.remove_rewards(&indices);
+        challenge.remove_hints(&indices);
+
+        // 2. keep solving pointed at the same solution or, when solving in any order, keep
+        //    the remaining solved solutions marked
+        match challenge.solve_mode {
+            SolveMode::Sequential => {
+                let removed_before_solving = indices
+                    .iter()
+                    .filter(|idx| **idx < challenge.solving)
+                    .count();
+                challenge.solving -= removed_before_solving as u8;
+            }
+            SolveMode::AnyOrder => {
+                challenge.unmark_removed_solutions(&indices, len_before_removal)
+            }
+        }
+        if challenge.started && challenge.is_complete() {
+            log_state!(
+                "All remaining solutions were solved, finishing challenge"
+            );
+            challenge.finished = true;
+        }
+        challenge.assert_invariants()
+    })
+}
+
+/// Replaces all solutions of the [challenge] with the given [solutions].
+pub fn replace_solutions(
+    challenge: &mut Challenge,
+    solutions: Vec<Solution>,
+) -> Result<SizeChange, ProgramError> {
+    assert_solutions_not_locked("replace_solutions", challenge)?;
+    assert_can_add_after_start("replace_solutions", challenge)?;
+    assert_no_solutions_redeemed("replace_solutions", challenge)?;
+    assert_no_weak_solutions("replace_solutions", challenge, &solutions)?;
+
+    SizeChange::of(challenge, |challenge| {
+        // the rewards and hints of the replaced solutions no longer apply
+        challenge.solutions = solutions;
+        challenge.rewards.clear();
+        challenge.hints.clear();
+        challenge.assert_invariants()
+    })
+}
+
+// -----------------
+// Lifecycle
+// -----------------
+
+/// Starts the [challenge] at the given [slot] which also starts its first epoch.
+pub fn start_challenge(
+    challenge: &mut Challenge,
+    slot: u64,
+) -> Result<(), ProgramError> {
+    assert_not_started("start_challenge", challenge)?;
+    assert_has_solutions("start_challenge", challenge, "be started")?;
+
+    challenge.started = true;
+    if challenge.epoch_slots > 0 {
+        challenge.epoch_start_slot = slot;
+    }
+    Ok(())
+}
+
+/// Configures the epochs of the [challenge], see [crate::state::epoch_at].
+pub fn set_epochs(
+    challenge: &mut Challenge,
+    epoch_slots: u64,
+    solutions_per_epoch: u8,
+) -> Result<(), ProgramError> {
+    assert_not_started("set_epochs", challenge)?;
+    assert_valid_epochs("set_epochs", epoch_slots, solutions_per_epoch)?;
+
+    // the epochs are counted once the challenge starts, see start_challenge
+    challenge.epoch_slots = epoch_slots;
+    challenge.solutions_per_epoch = solutions_per_epoch;
+    challenge.epoch = 0;
+    Ok(())
+}
+
+/// Gates the challenge at [challenge_pda] on completing the [prerequisite].
+pub fn set_prerequisite(
+    challenge: &mut Challenge,
+    challenge_pda: &Pubkey,
+    prerequisite: Option<Pubkey>,
+) -> Result<SizeChange, ProgramError> {
+    assert_valid_prerequisite(
+        "set_prerequisite",
+        challenge_pda,
+        prerequisite.as_ref(),
+    )?;
+
+    SizeChange::of(challenge, |challenge| {
+        challenge.prerequisite = prerequisite;
+        Ok(())
+    })
+}
+
+/// Updates the terms of the [challenge], only the provided values are changed.
+pub fn update_challenge(
+    challenge: &mut Challenge,
+    admit_cost: Option<u64>,
+    tries_per_admit: Option<u8>,
+    redeem: Option<Pubkey>,
+) -> Result<(), ProgramError> {
+    // the terms of a cancelled challenge are final while its challengers get refunded
+    assert_not_cancelled("update_challenge", challenge)?;
+    if let Some(admit_cost) = admit_cost {
+        assert_admit_cost_not_locked("update_challenge", challenge)?;
+        challenge.admit_cost = admit_cost;
+    }
+    if let Some(tries_per_admit) = tries_per_admit {
+        assert_valid_tries_per_admit(
+            "update_challenge:tries_per_admit",
+            tries_per_admit,
+        )?;
+        challenge.tries_per_admit = tries_per_admit;
+    }
+    if let Some(redeem) = redeem {
+        challenge.redeem = redeem;
+    }
+    Ok(())
+}
+
+/// Cancels the [challenge] which pauses it for good and allows its challengers to get
+/// their unused tries refunded, see [refund_unused_tries].
+pub fn cancel_challenge(challenge: &mut Challenge) -> Result<(), ProgramError> {
+    assert_not_finished("cancel_challenge", challenge)?;
+
+    challenge.paused = true;
+    challenge.cancelled = true;
+    Ok(())
+}
+
+// -----------------
+// Admissions
+// -----------------
+
+/// Admits the [challenger] to the [challenge] at [now] and returns the state of its account.
+/// A recurring challenge that finished rolls over into its next epoch first.
+/// The part of the admit cost that is escrowed is recorded on the returned challenger once
+/// it was paid.
+pub fn admit_challenger(
+    challenge: &mut Challenge,
+    challenge_pda: Pubkey,
+    challenger: Pubkey,
+    allowlist_proof: &[[u8; 32]],
+    now: &Now,
+) -> Result<Challenger, ProgramError> {
+    challenge.roll_over_epoch(challenge.solutions.len(), now.slot);
+    assert_started("admit_challenger", challenge)?;
+    assert_not_finished("admit_challenger", challenge)?;
+    assert_not_paused("admit_challenger", challenge)?;
+    assert_within_schedule("admit_challenger", challenge, now.unix_timestamp)?;
+    assert_on_allowlist(
+        "admit_challenger",
+        challenge,
+        &challenger,
+        allowlist_proof,
+    )?;
+    assert_has_admissions_left("admit_challenger", challenge)?;
+
+    let mut admitted = Challenger {
+        authority: challenger,
+        challenge_pda,
+        ..Challenger::default()
+    };
+    admitted.grant_tries(challenge.tries_per_admit);
+    challenge.admissions = challenge.admissions.saturating_add(1);
+    Ok(admitted)
+}
+
+/// Refunds the unused tries of the [challenger] of the cancelled [challenge] and returns
+/// the amount to pay back.
+/// What was actually escrowed for the tries is refunded since the admit cost and tries per
+/// admit may have changed since.
+pub fn refund_unused_tries(
+    challenge: &Challenge,
+    challenger: &mut Challenger,
+) -> Result<u64, ProgramError> {
+    assert_refunds_allowed("refund_unused_tries", challenge)?;
+    let refund = challenger.refundable;
+    assert_has_refund("refund_unused_tries", challenger, refund)?;
+    assert_has_refund_escrow("refund_unused_tries", challenge)?;
+
+    challenger.tries_remaining = 0;
+    challenger.refundable = 0;
+    Ok(refund)
+}
+
+// -----------------
+// Tries
+// -----------------
+
+/// Limits how many tries each challenger of the [challenge] can be admitted with in total.
+pub fn set_max_tries_per_challenger(
+    challenge: &mut Challenge,
+    max_tries: Option<u16>,
+) -> Result<SizeChange, ProgramError> {
+    SizeChange::of(challenge, |challenge| {
+        challenge.max_tries_per_challenger = max_tries;
+        Ok(())
+    })
+}
+
+/// Adds [count] tries to the [challenger] and returns what they cost.
+pub fn buy_more_tries(
+    challenge: &Challenge,
+    challenger: &mut Challenger,
+    count: u8,
+) -> Result<u64, ProgramError> {
+    assert_can_add_tries("buy_more_tries", challenger, count)?;
+    assert_within_max_tries_per_challenger(
+        "buy_more_tries",
+        challenge,
+        challenger,
+        count,
+    )?;
+
+    let cost = challenge.tries_cost(count)?;
+    challenger.add_tries(count);
+    Ok(cost)
+}
+
+// -----------------
+// Redeem
+// -----------------
+
+/// Uses up a try of the [challenger] attempting to solve the [challenge] at [slot].
+/// Incorrect guesses also use up a try.
+pub fn attempt_solve(
+    context: &str,
+    challenge: &Challenge,
+    challenger: &mut Challenger,
+    slot: u64,
+) -> Result<(), ProgramError> {
+    assert_challenger_has_tries_remaining(context, challenger)?;
+    assert_attempt_cooldown_passed(context, challenge, challenger, slot)?;
+
+    challenger.record_attempt(slot);
+    Ok(())
+}
+
+/// Marks the solution at [solution_index] as solved by the [challenger].
+/// The solution only counts as solved for the challenge once all its winners solved it, i.e.
+/// when [winners_before] other challengers solved it before the last winner.
+/// Returns `true` if the challenge in the [header] changed.
+pub fn solve(
+    header: &mut ChallengeHeader,
+    challenger: &mut Challenger,
+    solution_index: u8,
+    winners_before: u8,
+) -> Result<bool, ProgramError> {
+    let solved = winners_before + 1 >= header.challenge.winners_per_solution();
+    if solved {
+        header.mark_solved(solution_index)?;
+    }
+
+    challenger.redeemed = true;
+    challenger.mark_solved(solution_index);
+    Ok(solved)
+}
+
+/// Returns the lamports paid out of the [prize_pool] holding [available] lamports to a
+/// challenger solving the solution at [solution_index] which [winners_left] challengers
+/// still share including that challenger.
+/// Winners of the same solution split its reward equally while the prize of a solution
+/// without a reward is taken from the pool as configured by its [Payout].
+pub fn prize_for(
+    header: &ChallengeHeader,
+    mut prize_pool: PrizePool,
+    solution_index: u8,
+    winners_left: u8,
+    available: u64,
+) -> u64 {
+    match header.challenge.reward_for(solution_index) {
+        Some(reward) => (reward
+            / header.challenge.winners_per_solution() as u64)
+            .min(available),
+        None => {
+            // solutions solved in any order have no last solution to take it all
+            if header.challenge.solve_mode == SolveMode::AnyOrder {
+                prize_pool.payout = Payout::PerSolution;
+            }
+            prize_pool.payout_amount(available, header.remaining_solutions())
+                / winners_left.max(1) as u64
+        }
+    }
+}
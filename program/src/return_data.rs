@@ -0,0 +1,78 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    program::{get_return_data, set_return_data},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::challenge_id;
+
+/// The outcome of a `Redeem` or `RevealSolution` which the program sets as its return data,
+/// such that CPI callers and simulations can read it without fetching the challenge and
+/// challenger accounts afterwards.
+#[derive(
+    BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+pub struct RedeemResult {
+    /// Index of the solution the challenger solved, `None` if the guess was incorrect
+    pub solved_index: Option<u8>,
+    /// The number of solutions of the challenge that remain to be solved
+    pub remaining: u8,
+    /// Lamports paid out to the challenger from the prize pool
+    pub reward_paid: u64,
+}
+
+impl RedeemResult {
+    /// Sets the result as the return data of the current instruction.
+    pub fn set(&self) -> Result<(), ProgramError> {
+        set_return_data(&self.try_to_vec()?);
+        Ok(())
+    }
+
+    /// Decodes the result from return data set by the program with [program_id].
+    /// Returns `None` if the data was set by another program or isn't a redeem result.
+    pub fn from_return_data(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+        if program_id != &challenge_id() {
+            return None;
+        }
+        Self::try_from_slice(data).ok()
+    }
+
+    /// Reads the result of a `Redeem` the calling program just invoked, see
+    /// [crate::cpi::redeem].
+    pub fn get() -> Option<Self> {
+        let (program_id, data) = get_return_data()?;
+        Self::from_return_data(&program_id, &data)
+    }
+}
+
+/// The outcome of a `CheckSolution` which the program sets as its return data.
+#[derive(
+    BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+pub struct CheckSolutionResult {
+    /// Index of the solution the checked solution solves, `None` if it is incorrect
+    pub solution_index: Option<u8>,
+}
+
+impl CheckSolutionResult {
+    /// Sets the result as the return data of the current instruction.
+    pub fn set(&self) -> Result<(), ProgramError> {
+        set_return_data(&self.try_to_vec()?);
+        Ok(())
+    }
+
+    /// Decodes the result from return data set by the program with [program_id].
+    /// Returns `None` if the data was set by another program or isn't a check result.
+    pub fn from_return_data(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+        if program_id != &challenge_id() {
+            return None;
+        }
+        Self::try_from_slice(data).ok()
+    }
+
+    /// Returns `true` if the checked solution is correct.
+    pub fn is_correct(&self) -> bool {
+        self.solution_index.is_some()
+    }
+}
@@ -0,0 +1,167 @@
+//! Helpers used via `#[serde(with = "..")]` so that state and instructions serialize to
+//! human-readable JSON, i.e. pubkeys as base58 strings and hints as hex strings.
+use std::str::FromStr;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    utils::{decode_hex, encode_hex},
+    Hint, HINT_BYTES,
+};
+
+struct Base58(Pubkey);
+
+impl Serialize for Base58 {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Base58 {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(d)?;
+        Pubkey::from_str(&s).map(Base58).map_err(|_| {
+            de::Error::custom(format!("invalid base58 pubkey '{s}'"))
+        })
+    }
+}
+
+struct HexHint(Hint);
+
+impl Serialize for HexHint {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&encode_hex(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for HexHint {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(d)?;
+        let bytes = decode_hex(&s).map_err(de::Error::custom)?;
+        let len = bytes.len();
+        bytes.try_into().map(HexHint).map_err(|_| {
+            de::Error::invalid_length(len, &HINT_BYTES.to_string().as_str())
+        })
+    }
+}
+
+/// A [Pubkey] as base58 string.
+pub mod pubkey {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        key: &Pubkey,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        Base58(*key).serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Pubkey, D::Error> {
+        Ok(Base58::deserialize(d)?.0)
+    }
+}
+
+/// An optional [Pubkey] as base58 string or `null`.
+pub mod option_pubkey {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        key: &Option<Pubkey>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        key.map(Base58).serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Option<Pubkey>, D::Error> {
+        Ok(Option::<Base58>::deserialize(d)?.map(|x| x.0))
+    }
+}
+
+/// A list of [Pubkey]s as base58 strings.
+pub mod vec_pubkey {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        keys: &[Pubkey],
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.collect_seq(keys.iter().copied().map(Base58))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Vec<Pubkey>, D::Error> {
+        Ok(Vec::<Base58>::deserialize(d)?
+            .into_iter()
+            .map(|x| x.0)
+            .collect())
+    }
+}
+
+/// Lists of [Pubkey]s as base58 strings, i.e. the winners of each solution.
+pub mod vec_vec_pubkey {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        keys: &[Vec<Pubkey>],
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.collect_seq(
+            keys.iter()
+                .map(|xs| xs.iter().copied().map(Base58).collect::<Vec<_>>()),
+        )
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Vec<Vec<Pubkey>>, D::Error> {
+        Ok(Vec::<Vec<Base58>>::deserialize(d)?
+            .into_iter()
+            .map(|xs| xs.into_iter().map(|x| x.0).collect())
+            .collect())
+    }
+}
+
+/// A [Hint] as hex string, serde only supports arrays of up to 32 elements.
+pub mod hint {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        hint: &Hint,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        HexHint(*hint).serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Hint, D::Error> {
+        Ok(HexHint::deserialize(d)?.0)
+    }
+}
+
+/// A list of [Hint]s as hex strings.
+pub mod vec_hint {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        hints: &[Hint],
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.collect_seq(hints.iter().copied().map(HexHint))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Vec<Hint>, D::Error> {
+        Ok(Vec::<HexHint>::deserialize(d)?
+            .into_iter()
+            .map(|x| x.0)
+            .collect())
+    }
+}
@@ -0,0 +1,73 @@
+//! Snapshot of a challenge together with the accounts belonging to it, i.e. to reproduce
+//! the state of a challenge on a cluster in local tests.
+//!
+//! Snapshots are exported via `client::export_challenge` and imported into a `ProgramTest`
+//! via `test_utils::import_challenge_snapshot`.
+use solana_program::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use crate::state::{Challenge, Challenger, Leaderboard, PrizePool};
+
+/// The state of a program account together with its address and balance.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotAccount<T> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub address: Pubkey,
+
+    /// The lamports held by the account including its rent exempt minimum.
+    pub lamports: u64,
+
+    pub state: T,
+}
+
+/// The admit tokens held by the challenge PDA, see [Challenge::admit_mint].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EscrowSnapshot {
+    /// The ATA of the challenge PDA for the [EscrowSnapshot::mint], see
+    /// [EscrowSnapshot::ata_for].
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub address: Pubkey,
+
+    /// The admit mint, which is not part of the snapshot.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub mint: Pubkey,
+
+    /// The amount of admit tokens held by the escrow.
+    pub amount: u64,
+}
+
+impl EscrowSnapshot {
+    /// The ATA of the [challenge_pda] receiving admit costs paid in [admit_mint] tokens.
+    pub fn ata_for(challenge_pda: &Pubkey, admit_mint: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            challenge_pda,
+            admit_mint,
+            &spl_token::id(),
+        )
+    }
+}
+
+/// A challenge together with its challengers, leaderboard and the balances it escrows.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChallengeSnapshot {
+    pub challenge: SnapshotAccount<Challenge>,
+
+    /// All challengers admitted to the challenge.
+    pub challengers: Vec<SnapshotAccount<Challenger>>,
+
+    /// The leaderboard of the challenge if it has one.
+    pub leaderboard: Option<SnapshotAccount<Leaderboard>>,
+
+    /// The prize pool of the challenge if it has one, its lamports above the rent exempt
+    /// minimum make up the pool.
+    pub prize_pool: Option<SnapshotAccount<PrizePool>>,
+
+    /// The admit tokens held by the challenge if it is admitting with an admit mint.
+    pub escrow: Option<EscrowSnapshot>,
+
+    /// The number of redeem tokens minted to challengers that solved solutions.
+    pub redeem_supply: u64,
+}
@@ -1,25 +1,32 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankAccount;
 use solana_program::{
-    account_info::AccountInfo,
-    hash::{hash, HASH_BYTES},
-    program_error::ProgramError,
-    pubkey::Pubkey,
-    rent::Rent,
-    sysvar::Sysvar,
+    account_info::AccountInfo, borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult, hash::HASH_BYTES, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
 use crate::{
-    challenge_id,
-    utils::{assert_is_signer, assert_keys_equal},
-    Solution,
+    error::ChallengeError,
+    pda,
+    utils::{
+        assert_current_version, assert_discriminator,
+        assert_is_signer_or_delegated, assert_keys_equal,
+        assert_outdated_version, solution_stored_as,
+    },
+    Hint, Solution, HINT_BYTES,
 };
 
 use super::{
-    HasPda, HasSize, Redeem, StateFromPdaAccountValue, TryStateFromPdaAccount,
+    deserialize_header, epoch_at, epoch_solutions, ChallengeStatus, Challenger,
+    HasDiscriminator, HasPda, HasSize, HashAlgorithm, Redeem,
+    SolutionNormalization, SolveMode, StateFromPdaAccountValue,
+    TryStateFromAccount, ALLOW_ADD_AFTER_START, ALLOW_REFUNDS,
+    DEFAULT_CHALLENGE_FLAGS, DISCRIMINATOR_LEN, REQUIRE_COMMIT_REVEAL,
 };
 
-#[derive(ShankAccount, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, ShankAccount, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[seeds(
     "challenge",
     creator("The authority managing the challenge, usually the creator"),
@@ -31,14 +38,50 @@ use super::{
 /// This is the PDA account that holds the state of a challenge.
 /// The creator will usually be the update_authority, but this is not required.
 ///
-/// The `authority` is used as the creator seed when deriving the PDA of this challenge.
+/// The `authority` is used as the creator seed when deriving the PDA of this challenge unless
+/// it was transferred, see [Challenge::original_authority].
 ///   - it is not needed for all state changes, i.e. the `solving` will be incremented via the
 ///     without requiring the signature of the `authority`.
 ///   - however adding solutions requires the authority to sign
 pub struct Challenge {
+    /// The layout version the challenge was written with, see [CHALLENGE_VERSION].
+    /// It directly follows the discriminator so that older layouts can be detected and
+    /// upgraded via `MigrateAccount`.
+    pub version: u8,
+
+    /// The bump of the challenge PDA, stored when the challenge is created so that its
+    /// address can be verified without searching for the bump, see [crate::pda].
+    pub bump: u8,
+
     /// The authority that can update the challenge, normally the creator.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
     pub authority: Pubkey,
 
+    /// Set by the `authority` when transferring the challenge to a new authority.
+    /// The transfer only completes once the pending authority accepts it.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::option_pubkey")
+    )]
+    pub pending_authority: Option<Pubkey>,
+
+    /// Set once the authority was transferred and then holds the authority that created the
+    /// challenge, which remains the creator seed of its PDA, see [Challenge::creator].
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::option_pubkey")
+    )]
+    pub original_authority: Option<Pubkey>,
+
+    /// Co-admins added by the `authority` via `AddAdmin`, at most [MAX_CHALLENGE_ADMINS].
+    /// They may add solutions and pause or resume the challenge, but cannot transfer or
+    /// close it.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::vec_pubkey")
+    )]
+    pub admins: Vec<Pubkey>,
+
     /// The id of the challenge, needs to be unique for the creator.
     pub id: String,
 
@@ -50,10 +93,66 @@ pub struct Challenge {
     /// At this point no challengers can be admitted nor can any one redeem the price.
     pub finished: bool,
 
+    /// While set, no challengers are admitted and no solutions are redeemed.
+    /// The authority can still update the challenge, i.e. add solutions.
+    pub paused: bool,
+
+    /// Set once the challenge is cancelled via `CancelChallenge` which pauses it for good.
+    /// Challengers can then reclaim the admit cost for their unused tries via
+    /// `RefundUnusedTries`.
+    pub cancelled: bool,
+
     /// The fee that will be transferred to the creator from the challenger account
     /// when the admit instruction is processed.
     pub admit_cost: u64,
 
+    /// Once set the [Challenge::admit_cost] can no longer be changed.
+    /// This is irreversible, challengers can rely on the fee staying the same.
+    pub admit_cost_locked: bool,
+
+    /// If set the [Challenge::admit_cost] is paid in tokens of this mint instead of lamports.
+    /// The tokens are held in the associated token account of the challenge PDA until the
+    /// authority withdraws them.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::option_pubkey")
+    )]
+    pub admit_mint: Option<Pubkey>,
+
+    /// If set, admit fees paid in lamports are collected in the [crate::state::PrizePool] of
+    /// the challenge and paid out to challengers that redeem instead of going to the creator.
+    pub has_prize_pool: bool,
+
+    /// If set, every challenger that redeems also receives a unique NFT minted by the
+    /// challenge, see [crate::state::NftReward].
+    pub nft_reward: bool,
+
+    /// If set, `Redeem` records the first solver of each solution in the
+    /// [crate::state::FirstSolvers] leaderboard of the challenge.
+    pub has_first_solvers: bool,
+
+    /// If set, solutions can only be redeemed by committing to them via `CommitSolution`
+    /// and revealing them via `RevealSolution` which prevents them from being front-run.
+    pub commit_reveal: bool,
+
+    /// If set, only challengers that are part of the allowlist with this Merkle root are
+    /// admitted, see [crate::utils::allowlist_root].
+    pub allowlist_root: Option<[u8; 32]>,
+
+    /// If set, no more than this many challengers are admitted.
+    pub max_admissions: Option<u32>,
+
+    /// The number of challengers admitted so far.
+    pub admissions: u32,
+
+    /// If set, solutions are stored as `sha256(salt || solution)` instead of
+    /// `sha256(solution)`, see [crate::utils::solution_stored_as].
+    /// Challenges created without a salt keep storing unsalted hashes.
+    pub salt: Option<[u8; 32]>,
+
+    /// The algorithm used to hash the solutions of this challenge.
+    pub hash_algorithm: HashAlgorithm,
+
     /// Determines how many solutions a challenger can send per admission to try to redeem.
     pub tries_per_admit: u8,
 
@@ -63,11 +162,132 @@ pub struct Challenge {
     /// The address of the price token.
     /// Should this be an array/collection for case b) of the reason to have multiple solutions?
     /// See below ([Challenge::solutions])
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
     pub redeem: Pubkey,
 
-    /// The index of the solution that needs to be found next
+    /// The index of the solution that needs to be found next.
+    /// For challenges solved in [SolveMode::AnyOrder] this is the number of solved solutions.
     pub solving: u8,
 
+    /// Determines if solutions need to be redeemed in order, set via `SetSolveMode` before
+    /// the challenge starts.
+    pub solve_mode: SolveMode,
+
+    /// Bitset of the indexes of the solutions that were solved, see [Challenge::is_solved].
+    /// Only tracked for challenges solved in [SolveMode::AnyOrder].
+    pub solved: [u8; 32],
+
+    /// If set, solutions are only accepted via `Redeem` starting at this slot.
+    /// This does not affect admitting challengers.
+    pub solve_open_from_slot: Option<u64>,
+
+    /// If set, solutions are only accepted via `Redeem` up to and including this slot.
+    /// This does not affect admitting challengers.
+    pub solve_open_until_slot: Option<u64>,
+
+    /// If set, challengers are only admitted and solutions only accepted starting at this
+    /// unix timestamp.
+    pub starts_at: Option<i64>,
+
+    /// If set, challengers are only admitted and solutions only accepted up to and including
+    /// this unix timestamp.
+    pub ends_at: Option<i64>,
+
+    /// If set (not the default pubkey) challengers can redeem via a signature of this oracle
+    /// instead of providing the solution, see [Challenge::oracle_message].
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub oracle: Pubkey,
+
+    /// While set, solutions cannot be added, removed or replaced.
+    /// Admitting challengers and redeeming is not affected.
+    pub solutions_locked: bool,
+
+    /// Lamports a challenger pays to reveal the hint of a solution via `RevealHint`.
+    /// They are paid into the prize pool if the challenge has one, otherwise to the authority.
+    pub hint_fee: u64,
+
+    /// The number of slots a challenger needs to wait after an attempt to redeem before
+    /// attempting again, `0` for no cooldown, see [Challenger::last_attempt_slot].
+    pub attempt_cooldown_slots: u64,
+
+    /// The number of distinct challengers that may solve each solution before it counts as
+    /// solved, each receiving an equal split of its prize, `0` or `1` for a single winner.
+    /// More than one winner requires the [crate::state::Winners] leaderboard which is
+    /// created together with setting this via `CreateWinners`.
+    pub max_winners_per_solution: u8,
+
+    /// The rules clients apply to solutions in clear text before hashing them when adding
+    /// or redeeming them, see [crate::utils::normalize_solution].
+    /// Set via `SetSolutionNormalization` before the challenge starts.
+    pub solution_normalization: SolutionNormalization,
+
+    /// If `true` solutions matching one of the most common answers are rejected when they
+    /// are added, see [crate::utils::weak_solutions].
+    /// Set via `SetRejectWeakSolutions`.
+    pub reject_weak_solutions: bool,
+
+    /// If `true` admitting a challenger mints a ticket token to it, see
+    /// [crate::state::Ticket], and whoever holds the ticket redeems with the tries of the
+    /// admission instead of the admitted challenger, which makes admissions transferable.
+    /// Set via `SetTicketAdmission` before the challenge starts.
+    pub ticket_admission: bool,
+
+    /// Bitfield of optional behaviors of the challenge, see [crate::state::ALLOW_REFUNDS]
+    /// and the other flags next to it.
+    /// Set via `SetChallengeFlags` before the challenge starts.
+    pub flags: u32,
+
+    /// User chosen seed the challenge PDA was additionally derived from, i.e. to obtain a
+    /// vanity address, see [Challenge::pda_with_seed].
+    /// Stored so that the PDA can be re-derived, `None` if the challenge was created without
+    /// one.
+    /// Set via `CreateSeededChallenge`.
+    pub seed: Option<[u8; 32]>,
+
+    /// If set, no challenger is granted more than this many tries in total, counting the
+    /// tries of its admissions as well as the tries it bought, see
+    /// [crate::state::Challenger::tries_admitted].
+    /// Set via `SetMaxTriesPerChallenger`.
+    pub max_tries_per_challenger: Option<u16>,
+
+    /// The lamports contributed by co-sponsors that are held by the challenge PDA since the
+    /// challenge had no prize pool at the time, see `AddCoSponsor`.
+    /// They are never swept as surplus, see [Challenge::surplus_lamports].
+    pub sponsored_lamports: u64,
+
+    /// If set, only challengers that redeemed a solution of the challenge with this PDA are
+    /// admitted, i.e. to chain challenges into the stages of a puzzle hunt.
+    /// Set via `SetPrerequisite`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::option_pubkey")
+    )]
+    pub prerequisite: Option<Pubkey>,
+
+    /// The number of slots each epoch of the challenge lasts, i.e. to host a recurring
+    /// puzzle in the same account, `0` if the challenge has no epochs.
+    /// Once an epoch rolls over the solving progress resets or advances to the next block
+    /// of solutions, see [Challenge::roll_over_epoch].
+    /// Set via `SetEpochs`.
+    pub epoch_slots: u64,
+
+    /// The slot at which the first epoch started, see [epoch_at].
+    pub epoch_start_slot: u64,
+
+    /// The number of solutions solved per epoch such that epoch `n` accepts the block of
+    /// solutions starting at `n * solutions_per_epoch`, see [epoch_solutions].
+    /// `0` means that all solutions are solved again in every epoch.
+    pub solutions_per_epoch: u8,
+
+    /// The epoch the solving progress of the challenge belongs to.
+    pub epoch: u64,
+
+    /// The amount paid out of the prize pool for redeeming the solution at the same index,
+    /// overriding the [crate::state::Payout] of the pool.
+    /// Either empty if solutions aren't rewarded individually or one reward per solution,
+    /// see [Challenge::reward_for].
+    pub rewards: Vec<u64>,
+
     /// All solutions of the challenge, solving each will result in the redeem
     /// to be sent to the challenger.
     /// There are two reasons why multiple solutions exist:
@@ -78,67 +298,651 @@ pub struct Challenge {
     ///   than once
     // NOTE: shank doesn't handle type aliases yet nor an array with constant identifier
     pub solutions: Vec<[u8; 32]>,
+
+    /// The hint of the solution at the same index, i.e. the pubkey of an account holding the
+    /// encrypted hint or its URI, recorded as accessible for challengers that reveal it.
+    /// Either empty if the challenge has no hints or one hint per solution where a hint of
+    /// all zeros means that the solution has none, see [Challenge::hint_for].
+    /// Stored after the solutions so that redeeming doesn't need to read them.
+    // NOTE: shank doesn't handle type aliases yet nor an array with constant identifier
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::vec_hint")
+    )]
+    pub hints: Vec<[u8; 64]>,
 }
 
 impl std::fmt::Debug for Challenge {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Challenge")
+            .field("version", &self.version)
+            .field("bump", &self.bump)
             .field("authority", &self.authority)
+            .field("pending_authority", &self.pending_authority)
+            .field("original_authority", &self.original_authority)
+            .field("admins", &self.admins)
             .field("id", &self.id)
             .field("started", &self.started)
             .field("finished", &self.finished)
+            .field("paused", &self.paused)
+            .field("cancelled", &self.cancelled)
             .field("admit_cost", &self.admit_cost)
+            .field("admit_cost_locked", &self.admit_cost_locked)
+            .field("admit_mint", &self.admit_mint)
+            .field("has_prize_pool", &self.has_prize_pool)
+            .field("nft_reward", &self.nft_reward)
+            .field("has_first_solvers", &self.has_first_solvers)
+            .field("commit_reveal", &self.commit_reveal)
+            .field("allowlist_root", &self.allowlist_root.is_some())
+            .field("max_admissions", &self.max_admissions)
+            .field("admissions", &self.admissions)
+            .field("salt", &self.salt.is_some())
+            .field("hash_algorithm", &self.hash_algorithm)
             .field("tries_per_admit", &self.tries_per_admit)
             .field("redeem", &self.redeem)
             .field("solving", &self.solving)
+            .field("solve_mode", &self.solve_mode)
+            .field("solve_open_from_slot", &self.solve_open_from_slot)
+            .field("solve_open_until_slot", &self.solve_open_until_slot)
+            .field("starts_at", &self.starts_at)
+            .field("ends_at", &self.ends_at)
+            .field("oracle", &self.oracle)
+            .field("solutions_locked", &self.solutions_locked)
+            .field("hint_fee", &self.hint_fee)
+            .field("attempt_cooldown_slots", &self.attempt_cooldown_slots)
+            .field("max_winners_per_solution", &self.max_winners_per_solution)
+            .field("solution_normalization", &self.solution_normalization)
+            .field("reject_weak_solutions", &self.reject_weak_solutions)
+            .field("ticket_admission", &self.ticket_admission)
+            .field("flags", &format_args!("{:#b}", self.flags))
+            .field("seed", &self.seed)
+            .field("max_tries_per_challenger", &self.max_tries_per_challenger)
+            .field("sponsored_lamports", &self.sponsored_lamports)
+            .field("prerequisite", &self.prerequisite)
+            .field("epoch_slots", &self.epoch_slots)
+            .field("epoch_start_slot", &self.epoch_start_slot)
+            .field("solutions_per_epoch", &self.solutions_per_epoch)
+            .field("epoch", &self.epoch)
+            .field("rewards", &self.rewards)
             .field("solutions", &self.solutions.len())
+            .field("hints", &self.hints.len())
             .finish()
     }
 }
 
+impl Default for Challenge {
+    fn default() -> Self {
+        Self {
+            version: CHALLENGE_VERSION,
+            bump: 0,
+            authority: Pubkey::default(),
+            pending_authority: None,
+            original_authority: None,
+            admins: vec![],
+            id: String::default(),
+            started: false,
+            finished: false,
+            paused: false,
+            cancelled: false,
+            admit_cost: 0,
+            admit_cost_locked: false,
+            admit_mint: None,
+            has_prize_pool: false,
+            nft_reward: false,
+            has_first_solvers: false,
+            commit_reveal: false,
+            allowlist_root: None,
+            max_admissions: None,
+            admissions: 0,
+            salt: None,
+            hash_algorithm: HashAlgorithm::default(),
+            tries_per_admit: 0,
+            redeem: Pubkey::default(),
+            solving: 0,
+            solve_mode: SolveMode::default(),
+            solved: [0; 32],
+            solve_open_from_slot: None,
+            solve_open_until_slot: None,
+            starts_at: None,
+            ends_at: None,
+            oracle: Pubkey::default(),
+            solutions_locked: false,
+            hint_fee: 0,
+            attempt_cooldown_slots: 0,
+            max_winners_per_solution: 0,
+            solution_normalization: SolutionNormalization::NONE,
+            reject_weak_solutions: false,
+            ticket_admission: false,
+            flags: DEFAULT_CHALLENGE_FLAGS,
+            seed: None,
+            max_tries_per_challenger: None,
+            sponsored_lamports: 0,
+            prerequisite: None,
+            epoch_slots: 0,
+            epoch_start_slot: 0,
+            solutions_per_epoch: 0,
+            epoch: 0,
+            rewards: vec![],
+            solutions: vec![],
+            hints: vec![],
+        }
+    }
+}
+
 #[rustfmt::skip]
 pub const EMPTY_CHALLENGE_SIZE_WITH_EMPTY_ID: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* version */         1 +
+    /* bump */            1 +
     /* authority */      32 + 
+    /* pending_authority */  1 + /* Option tag, see space_to_store_authorities */
+    /* original_authority */ 1 +
+    /* admins */          4 + /* u32 for Vec::len, see space_to_store_admins */
     /* id */              4 + /* does not include string len */
     /* started */         1 +
     /* finished */        1 +
+    /* paused */          1 +
+    /* cancelled */       1 +
     /* admit_cost */      8 +
+    /* admit_cost_locked */ 1 +
+    /* admit_mint */      1 + /* Option tag, see space_to_store_admit_mint */
+    /* has_prize_pool */  1 +
+    /* nft_reward */      1 +
+    /* has_first_solvers */ 1 +
+    /* commit_reveal */   1 +
+    /* allowlist_root */  1 + /* Option tag, see space_to_store_allowlist_root */
+    /* max_admissions */  1 + /* Option tag, see space_to_store_max_admissions */
+    /* admissions */      4 +
+    /* salt */            1 + /* Option tag, see space_to_store_salt */
+    /* hash_algorithm */  1 +
     /* tries_per_admit */ 1 +
     /* redeem */         32 +
     /* solving */         1 +
-    /* solutions */       4; // u32 for Vec::len
+    /* solve_mode */      1 +
+    /* solved */         32 +
+    /* solve_open_from_slot */  1 + /* Option tag, see space_to_store_solve_window */
+    /* solve_open_until_slot */ 1 +
+    /* starts_at */       1 + /* Option tag, see space_to_store_schedule */
+    /* ends_at */         1 +
+    /* oracle */         32 +
+    /* solutions_locked */  1 +
+    /* hint_fee */        8 +
+    /* attempt_cooldown_slots */ 8 +
+    /* max_winners_per_solution */ 1 +
+    /* solution_normalization */ 4 +
+    /* reject_weak_solutions */ 1 +
+    /* ticket_admission */ 1 +
+    /* flags */           4 +
+    /* seed */            1 + /* Option tag, see space_to_store_seed */
+    /* max_tries_per_challenger */ 1 + /* Option tag, see space_to_store_max_tries_per_challenger */
+    /* sponsored_lamports */ 8 +
+    /* prerequisite */    1 + /* Option tag, see space_to_store_prerequisite */
+    /* epoch_slots */     8 +
+    /* epoch_start_slot */ 8 +
+    /* solutions_per_epoch */ 1 +
+    /* epoch */           8 +
+    /* rewards */         4 + /* u32 for Vec::len, see space_to_store_rewards */
+    /* solutions */       4 + /* u32 for Vec::len */
+    /* hints */           4; // u32 for Vec::len, see space_to_store_n_hints
 
 impl HasSize for Challenge {
     /// Returns the size assuming no more solutions will be added.
     fn size(&self) -> usize {
         Challenge::needed_size(&self.solutions, &self.id)
+            + Challenge::space_to_store_solve_window(
+                self.solve_open_from_slot,
+                self.solve_open_until_slot,
+            )
+            + Challenge::space_to_store_authorities(
+                self.pending_authority,
+                self.original_authority,
+            )
+            + Challenge::space_to_store_admins(self.admins.len())
+            + Challenge::space_to_store_rewards(self.rewards.len())
+            + Challenge::space_to_store_n_hints(self.hints.len() as u8)
+            + Challenge::space_to_store_admit_mint(self.admit_mint)
+            + Challenge::space_to_store_salt(self.salt)
+            + Challenge::space_to_store_schedule(self.starts_at, self.ends_at)
+            + Challenge::space_to_store_allowlist_root(self.allowlist_root)
+            + Challenge::space_to_store_max_admissions(self.max_admissions)
+            + Challenge::space_to_store_seed(self.seed)
+            + Challenge::space_to_store_max_tries_per_challenger(
+                self.max_tries_per_challenger,
+            )
+            + Challenge::space_to_store_prerequisite(self.prerequisite)
     }
 }
 
 impl HasPda for Challenge {
     fn pda(&self) -> (Pubkey, u8) {
-        Challenge::pda_for(&self.authority, &self.id)
+        match &self.seed {
+            Some(seed) => {
+                Challenge::pda_with_seed(self.creator(), &self.id, seed)
+            }
+            None => Challenge::pda_for(self.creator(), &self.id),
+        }
+    }
+
+    fn with_pda_bump(self, bump: u8) -> Self {
+        Challenge { bump, ..self }
+    }
+}
+
+impl HasDiscriminator for Challenge {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [119, 250, 161, 121, 119, 81, 22, 208];
+    const MISSING_ERR: ChallengeError = ChallengeError::ChallengeNotFound;
+
+    fn try_from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        assert_discriminator(
+            "try_from_account_data",
+            data,
+            &Self::DISCRIMINATOR,
+        )?;
+        Challenge::try_from_versioned_data(&data[DISCRIMINATOR_LEN..], true)
+    }
+
+    fn try_from_account_data_unchecked(
+        data: &[u8],
+    ) -> Result<Self, ProgramError> {
+        assert_discriminator(
+            "try_from_account_data_unchecked",
+            data,
+            &Self::DISCRIMINATOR,
+        )?;
+        Challenge::try_from_versioned_data(&data[DISCRIMINATOR_LEN..], false)
+    }
+}
+
+/// The layout version of challenges written by this program.
+/// Increment it whenever the layout of [Challenge] changes and add the upgrade from the
+/// previous layout to [Challenge::try_upgrade_from].
+pub const CHALLENGE_VERSION: u8 = 17;
+
+/// Challenges written before they could recur in epochs, see [Challenge::epoch_slots].
+pub const UNSEASONED_CHALLENGE_VERSION: u8 = 16;
+
+/// Challenges written before admissions could require completing another challenge, see
+/// [Challenge::prerequisite].
+pub const UNGATED_CHALLENGE_VERSION: u8 = 15;
+
+/// Challenges written before the lamports co-sponsors contributed to the challenge PDA were
+/// accounted, see [Challenge::sponsored_lamports].
+pub const UNACCOUNTED_CHALLENGE_VERSION: u8 = 14;
+
+/// Challenges written before the tries per challenger could be capped, see
+/// [Challenge::max_tries_per_challenger].
+pub const UNCAPPED_CHALLENGE_VERSION: u8 = 13;
+
+/// Challenges written before PDAs could be derived from a user chosen seed, see
+/// [Challenge::seed].
+pub const UNSEEDED_CHALLENGE_VERSION: u8 = 12;
+
+/// Challenges written before optional behaviors were stored as flags, see
+/// [Challenge::flags].
+pub const UNFLAGGED_CHALLENGE_VERSION: u8 = 11;
+
+/// Challenges written before admissions could be tickets, see [Challenge::ticket_admission].
+pub const UNTICKETED_CHALLENGE_VERSION: u8 = 10;
+
+/// Challenges written before weak solutions could be rejected, see
+/// [Challenge::reject_weak_solutions].
+pub const UNSCREENED_CHALLENGE_VERSION: u8 = 9;
+
+/// Challenges written before solutions could be normalized, see
+/// [Challenge::solution_normalization].
+pub const UNNORMALIZED_CHALLENGE_VERSION: u8 = 8;
+
+/// Challenges written before solutions could have multiple winners, see
+/// [Challenge::max_winners_per_solution].
+pub const SINGLE_WINNER_CHALLENGE_VERSION: u8 = 7;
+
+/// Challenges written before attempt cooldowns were introduced, see
+/// [Challenge::attempt_cooldown_slots].
+pub const UNTHROTTLED_CHALLENGE_VERSION: u8 = 6;
+
+/// Challenges written before hints were introduced, see [Challenge::hints].
+pub const UNHINTED_CHALLENGE_VERSION: u8 = 5;
+
+/// Challenges written before solutions could be rewarded individually, see
+/// [Challenge::rewards].
+pub const UNREWARDED_CHALLENGE_VERSION: u8 = 4;
+
+/// Challenges written before solve modes were introduced which are all solved in
+/// [SolveMode::Sequential] order, see [Challenge::solve_mode].
+pub const SEQUENTIAL_CHALLENGE_VERSION: u8 = 3;
+
+/// Challenges written before co-admins were introduced, see [Challenge::admins].
+pub const ADMINLESS_CHALLENGE_VERSION: u8 = 2;
+
+/// Challenges written before the bump of their PDA was stored, see [Challenge::bump].
+pub const BUMPLESS_CHALLENGE_VERSION: u8 = 1;
+
+/// Challenges created before versions were introduced are stored without discriminator and
+/// without version byte and are treated as this version.
+pub const LEGACY_CHALLENGE_VERSION: u8 = 0;
+
+/// Max number of co-admins a challenge can have, see [Challenge::admins].
+pub const MAX_CHALLENGE_ADMINS: usize = 5;
+
+impl Challenge {
+    /// Returns the layout version of the challenge stored in the account [data].
+    /// Data that does not start with the discriminator was written before discriminators and
+    /// versions were introduced.
+    pub fn stored_version(data: &[u8]) -> u8 {
+        if data.starts_with(&Challenge::DISCRIMINATOR) {
+            data.get(DISCRIMINATOR_LEN)
+                .copied()
+                .unwrap_or(LEGACY_CHALLENGE_VERSION)
+        } else {
+            LEGACY_CHALLENGE_VERSION
+        }
+    }
+
+    /// Deserializes the challenge from the [data] following the discriminator, dispatching
+    /// on its version byte.
+    /// Challenges stored with an older layout need to be migrated via `MigrateAccount`
+    /// before they can be used.
+    fn try_from_versioned_data(
+        data: &[u8],
+        strict: bool,
+    ) -> Result<Challenge, ProgramError> {
+        let version = data.first().copied().unwrap_or(LEGACY_CHALLENGE_VERSION);
+        assert_current_version(
+            "try_from_account_data",
+            version,
+            CHALLENGE_VERSION,
+        )?;
+        if strict {
+            Ok(Challenge::try_from_slice(data)?)
+        } else {
+            Ok(try_from_slice_unchecked(data)?)
+        }
+    }
+
+    /// Upgrades the challenge stored in [data] with the layout of the given [version] to the
+    /// current layout, filling in defaults for fields the older layout did not have.
+    /// The [data] of legacy challenges is the entire account data, otherwise it is the data
+    /// following the discriminator.
+    pub fn try_upgrade_from(
+        version: u8,
+        data: &[u8],
+    ) -> Result<Challenge, ProgramError> {
+        // data of layouts preceding solve modes is upgraded to the sequential layout
+        let (layout_version, data) = match version {
+            // The legacy layout matches the bumpless one without the version byte
+            LEGACY_CHALLENGE_VERSION => (
+                SEQUENTIAL_CHALLENGE_VERSION,
+                Challenge::with_empty_admins(&Challenge::with_empty_bump(
+                    version, data,
+                ))?,
+            ),
+            BUMPLESS_CHALLENGE_VERSION => match data.split_first() {
+                Some((_, fields)) => (
+                    SEQUENTIAL_CHALLENGE_VERSION,
+                    Challenge::with_empty_admins(&Challenge::with_empty_bump(
+                        version, fields,
+                    ))?,
+                ),
+                None => return Err(ProgramError::InvalidAccountData),
+            },
+            ADMINLESS_CHALLENGE_VERSION => (
+                SEQUENTIAL_CHALLENGE_VERSION,
+                Challenge::with_empty_admins(data)?,
+            ),
+            SEQUENTIAL_CHALLENGE_VERSION
+            | UNREWARDED_CHALLENGE_VERSION
+            | UNHINTED_CHALLENGE_VERSION
+            | UNTHROTTLED_CHALLENGE_VERSION
+            | SINGLE_WINNER_CHALLENGE_VERSION
+            | UNNORMALIZED_CHALLENGE_VERSION
+            | UNSCREENED_CHALLENGE_VERSION
+            | UNTICKETED_CHALLENGE_VERSION
+            | UNFLAGGED_CHALLENGE_VERSION
+            | UNSEEDED_CHALLENGE_VERSION
+            | UNCAPPED_CHALLENGE_VERSION
+            | UNACCOUNTED_CHALLENGE_VERSION
+            | UNGATED_CHALLENGE_VERSION
+            | UNSEASONED_CHALLENGE_VERSION => (version, data.to_vec()),
+            _ => {
+                assert_outdated_version(
+                    "upgrade_challenge",
+                    version,
+                    CHALLENGE_VERSION,
+                )?;
+                msg!("Err: no upgrade from challenge version {}", version);
+                return Err(ChallengeError::UnsupportedAccountVersion.into());
+            }
+        };
+        let mut challenge =
+            Challenge::try_from_outdated_data(layout_version, &data)?;
+        challenge.version = CHALLENGE_VERSION;
+        if version < ADMINLESS_CHALLENGE_VERSION {
+            challenge.bump = challenge.pda().1;
+        }
+        Ok(challenge)
+    }
+
+    /// Prefixes the [fields] of a challenge stored without the bump byte, i.e. all fields
+    /// following the version, with the [version] and a zero bump to be derived by the caller.
+    fn with_empty_bump(version: u8, fields: &[u8]) -> Vec<u8> {
+        let mut data = Vec::with_capacity(fields.len() + 2);
+        data.push(version);
+        data.push(0);
+        data.extend_from_slice(fields);
+        data
+    }
+
+    /// Inserts an empty admins Vec following the authorities into the [data] of a challenge
+    /// stored without admins.
+    fn with_empty_admins(data: &[u8]) -> Result<Vec<u8>, ProgramError> {
+        let buf = &mut &data[..];
+        u8::deserialize(buf)?; // version
+        u8::deserialize(buf)?; // bump
+        Pubkey::deserialize(buf)?; // authority
+        Option::<Pubkey>::deserialize(buf)?; // pending_authority
+        Option::<Pubkey>::deserialize(buf)?; // original_authority
+        let admins_offset = data.len() - buf.len();
+
+        let mut upgraded = Vec::with_capacity(data.len() + 4);
+        upgraded.extend_from_slice(&data[..admins_offset]);
+        upgraded.extend_from_slice(&0u32.to_le_bytes());
+        upgraded.extend_from_slice(&data[admins_offset..]);
+        Ok(upgraded)
+    }
+
+    /// Deserializes the [data] of a challenge stored with the layout of the given [version],
+    /// which precedes epochs and may precede prerequisites, accounting sponsored lamports,
+    /// capped tries per challenger, seeds, flags, ticket admissions, rejecting weak
+    /// solutions, solution normalization, multiple winners, attempt cooldowns, hints, rewards
+    /// and solve modes as well.
+    /// Fields the layout does not include are defaulted, see [deserialize_header].
+    fn try_from_outdated_data(
+        version: u8,
+        data: &[u8],
+    ) -> Result<Challenge, ProgramError> {
+        let buf = &mut &data[..];
+        let mut challenge = deserialize_header(buf, version)?;
+        challenge.solutions = BorshDeserialize::deserialize(buf)?;
+        if version > UNHINTED_CHALLENGE_VERSION {
+            challenge.hints = BorshDeserialize::deserialize(buf)?;
+        }
+        if !buf.is_empty() {
+            msg!("Err: challenge data has {} trailing bytes", buf.len());
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(challenge)
     }
 }
 
 impl Challenge {
     pub fn pda_for(creator: &Pubkey, id: &str) -> (Pubkey, u8) {
-        Challenge::shank_pda(&challenge_id(), creator, id)
+        pda::challenge_pda(creator, id)
     }
 
-    pub fn seeds<'a>(&'a self, bump: &'a [u8; 1]) -> [&'a [u8]; 4] {
-        Challenge::shank_seeds_with_bump(&self.authority, &self.id, bump)
+    /// PDA of the challenge with the given [id] created by [creator] which was additionally
+    /// derived from the user chosen [seed], see [Challenge::seed].
+    pub fn pda_with_seed(
+        creator: &Pubkey,
+        id: &str,
+        seed: &[u8; 32],
+    ) -> (Pubkey, u8) {
+        pda::challenge_pda_with_seed(creator, id, seed)
+    }
+
+    /// Seeds to sign for the challenge PDA, including the user chosen seed if the challenge
+    /// was derived from one.
+    pub fn seeds<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<&'a [u8]> {
+        let mut seeds =
+            Challenge::shank_seeds_with_bump(self.creator(), &self.id, bump)
+                .to_vec();
+        if let Some(seed) = &self.seed {
+            seeds.insert(seeds.len() - 1, seed);
+        }
+        seeds
     }
+
+    /// The creator seed of the challenge PDA, which is the current `authority` unless the
+    /// challenge was transferred.
+    pub fn creator(&self) -> &Pubkey {
+        self.original_authority.as_ref().unwrap_or(&self.authority)
+    }
+
     pub fn needed_size(solutions: &[Solution], id: &str) -> usize {
         EMPTY_CHALLENGE_SIZE_WITH_EMPTY_ID
             + id.len()
             + Challenge::space_to_store_n_solutions(solutions.len() as u8)
     }
 
+    /// Returns the size needed to store the given solutions together with one hint per
+    /// solution.
+    /// Fails if the number of hints does not match the number of solutions.
+    pub fn needed_size_with_hints(
+        solutions: &[Solution],
+        hints: &[Hint],
+        id: &str,
+    ) -> Result<usize, ProgramError> {
+        if solutions.len() != hints.len() {
+            msg!(
+                "Err: {} hints were provided for {} solutions, need exactly one hint per solution",
+                hints.len(),
+                solutions.len()
+            );
+            return Err(ChallengeError::HintsDoNotMatchSolutions.into());
+        }
+        Ok(Challenge::needed_size(solutions, id)
+            + Challenge::space_to_store_n_hints(hints.len() as u8))
+    }
+
+    /// Returns the number of bytes the account grows by when [additional] solutions are
+    /// appended, including their rewards and hints if the challenge keeps those parallel to
+    /// its solutions.
+    pub fn size_delta(&self, additional: usize) -> usize {
+        let reward_size = if self.rewards.is_empty() { 0 } else { 8 };
+        let hint_size = if self.hints.is_empty() { 0 } else { HINT_BYTES };
+        additional * (HASH_BYTES + reward_size + hint_size)
+    }
+
     pub fn space_to_store_n_solutions(solutions_len: u8) -> usize {
         solutions_len as usize * HASH_BYTES
     }
 
+    /// Extra space needed to store the given number of hints.
+    /// The space for the length of the Vec is already included in [Challenge::needed_size].
+    pub fn space_to_store_n_hints(hints_len: u8) -> usize {
+        hints_len as usize * HINT_BYTES
+    }
+
+    /// Extra space needed to store the slot values of the solve window in case they are set.
+    /// The space for the [Option] tags is already included in [Challenge::needed_size].
+    pub fn space_to_store_solve_window(
+        from_slot: Option<u64>,
+        until_slot: Option<u64>,
+    ) -> usize {
+        let slot_size = std::mem::size_of::<u64>();
+        from_slot.map_or(0, |_| slot_size) + until_slot.map_or(0, |_| slot_size)
+    }
+
+    /// Extra space needed to store the start and end timestamps in case they are set.
+    /// The space for the [Option] tags is already included in [Challenge::needed_size].
+    pub fn space_to_store_schedule(
+        starts_at: Option<i64>,
+        ends_at: Option<i64>,
+    ) -> usize {
+        let timestamp_size = std::mem::size_of::<i64>();
+        starts_at.map_or(0, |_| timestamp_size)
+            + ends_at.map_or(0, |_| timestamp_size)
+    }
+
+    /// Extra space needed to store the pending and original authorities in case they are set.
+    /// The space for the [Option] tags is already included in [Challenge::needed_size].
+    pub fn space_to_store_authorities(
+        pending_authority: Option<Pubkey>,
+        original_authority: Option<Pubkey>,
+    ) -> usize {
+        let key_size = std::mem::size_of::<Pubkey>();
+        pending_authority.map_or(0, |_| key_size)
+            + original_authority.map_or(0, |_| key_size)
+    }
+
+    /// Extra space needed to store the given number of admins.
+    /// The space for the length of the Vec is already included in [Challenge::needed_size].
+    pub fn space_to_store_admins(admins_len: usize) -> usize {
+        admins_len * std::mem::size_of::<Pubkey>()
+    }
+
+    /// Extra space needed to store the given number of rewards.
+    /// The space for the length of the Vec is already included in [Challenge::needed_size].
+    pub fn space_to_store_rewards(rewards_len: usize) -> usize {
+        rewards_len * std::mem::size_of::<u64>()
+    }
+
+    /// Extra space needed to store the admit mint in case it is set.
+    /// The space for the [Option] tag is already included in [Challenge::needed_size].
+    pub fn space_to_store_admit_mint(admit_mint: Option<Pubkey>) -> usize {
+        admit_mint.map_or(0, |_| std::mem::size_of::<Pubkey>())
+    }
+
+    /// Extra space needed to store the max tries per challenger in case they are set.
+    /// The space for the [Option] tag is already included in [Challenge::needed_size].
+    pub fn space_to_store_max_tries_per_challenger(
+        max_tries_per_challenger: Option<u16>,
+    ) -> usize {
+        max_tries_per_challenger.map_or(0, |_| std::mem::size_of::<u16>())
+    }
+
+    /// Extra space needed to store the prerequisite in case it is set.
+    /// The space for the [Option] tag is already included in [Challenge::needed_size].
+    pub fn space_to_store_prerequisite(prerequisite: Option<Pubkey>) -> usize {
+        prerequisite.map_or(0, |_| std::mem::size_of::<Pubkey>())
+    }
+
+    /// Extra space needed to store the max admissions in case they are set.
+    /// The space for the [Option] tag is already included in [Challenge::needed_size].
+    pub fn space_to_store_max_admissions(max_admissions: Option<u32>) -> usize {
+        max_admissions.map_or(0, |_| std::mem::size_of::<u32>())
+    }
+
+    /// Extra space needed to store the allowlist root in case it is set.
+    /// The space for the [Option] tag is already included in [Challenge::needed_size].
+    pub fn space_to_store_allowlist_root(
+        allowlist_root: Option<[u8; 32]>,
+    ) -> usize {
+        allowlist_root.map_or(0, |_| std::mem::size_of::<[u8; 32]>())
+    }
+
+    /// Extra space needed to store the salt in case it is set.
+    /// The space for the [Option] tag is already included in [Challenge::needed_size].
+    pub fn space_to_store_salt(salt: Option<[u8; 32]>) -> usize {
+        salt.map_or(0, |_| std::mem::size_of::<[u8; 32]>())
+    }
+
+    /// Extra space needed to store the user chosen PDA seed in case it is set.
+    /// The space for the [Option] tag is already included in [Challenge::needed_size].
+    pub fn space_to_store_seed(seed: Option<[u8; 32]>) -> usize {
+        seed.map_or(0, |_| std::mem::size_of::<[u8; 32]>())
+    }
+
     /// Only use on-chain as Rent::get is not available otherwise.
     #[allow(unused)]
     pub(crate) fn rent_exempt_lamports(&self) -> Result<u64, ProgramError> {
@@ -147,37 +951,430 @@ impl Challenge {
     }
 
     /// Deserializes a challenge from the given account data and verifies the following:
-    /// - the provided challenge pda account is for the provided creator and challenge id  
+    /// - the challenge account is funded and initialized (has data)
+    /// - the provided challenge pda account is for the creator seed stored with the challenge,
+    ///   see [Challenge::creator], the provided challenge id and the user chosen seed stored
+    ///   with the challenge if any, see [Challenge::seed]
+    ///
+    /// - [context] is included in the logs if any of the above checks fail
+    pub fn account_state(
+        context: &str,
+        challenge_pda_info: &AccountInfo,
+        id: &str,
+    ) -> Result<StateFromPdaAccountValue<Challenge>, ProgramError> {
+        let state: Challenge = challenge_pda_info.try_state_from_account()?;
+        let bump = state.bump;
+        let pda = match &state.seed {
+            Some(seed) => pda::challenge_pda_with_seed_and_bump(
+                state.creator(),
+                id,
+                seed,
+                bump,
+            ),
+            None => pda::challenge_pda_with_bump(state.creator(), id, bump),
+        }
+        .map_err(|_| ChallengeError::ProvidedChallengePdaIncorrect)?;
+
+        assert_keys_equal(
+            context,
+            challenge_pda_info.key,
+            &pda,
+            ChallengeError::ProvidedChallengePdaIncorrect,
+            || {
+                format!(
+                "The derrived PDA ({}) does not match the address of the provided PDA account ({})",
+                pda, challenge_pda_info.key
+            )
+            },
+        )?;
+        Ok(StateFromPdaAccountValue::<Challenge> { state, pda, bump })
+    }
+
+    /// Deserializes a challenge from the given account data and verifies the following:
+    /// - the provided challenge pda account is for the stored creator seed and challenge id
     /// - the challenge account is funded and initialized (has data)
     /// - the creator (authority) is signer
     /// - the creator is the authority for the challenge
+    ///
+    /// - [context] is included in the logs if any of the above checks fail
     pub fn account_state_verifying_creator(
+        context: &str,
         challenge_pda_info: &AccountInfo,
         creator_info: &AccountInfo,
         id: &str,
     ) -> Result<StateFromPdaAccountValue<Challenge>, ProgramError> {
         let StateFromPdaAccountValue::<Challenge> { state, pda, bump } =
-            challenge_pda_info.try_state_from_pda_account(|| {
-                Challenge::shank_pda(&challenge_id(), creator_info.key, id)
-            })?;
+            Challenge::account_state(context, challenge_pda_info, id)?;
 
-        assert_is_signer(creator_info, "creator")?;
+        assert_is_signer_or_delegated(context, creator_info, "creator")?;
 
-        assert_keys_equal(&state.authority, creator_info.key, || {
-            format!(
+        assert_keys_equal(
+            context,
+            &state.authority,
+            creator_info.key,
+            ChallengeError::ProvidedCreatorIncorrect,
+            || {
+                format!(
             "Challenge's authority ({}) does not match provided creator ({})",
             state.authority, creator_info.key
         )
-        })?;
+            },
+        )?;
         Ok(StateFromPdaAccountValue::<Challenge> { state, pda, bump })
     }
 
+    /// Returns `true` if [key] is the authority of the challenge or one of its admins.
+    pub fn is_authority_or_admin(&self, key: &Pubkey) -> bool {
+        self.authority.eq(key) || self.admins.contains(key)
+    }
+
+    /// Returns `true` if all bits of [flag] are set, see [Challenge::flags].
+    pub fn has_flag(&self, flag: u32) -> bool {
+        self.flags & flag == flag
+    }
+
+    /// Sets or clears the bits of [flag], see [Challenge::flags].
+    pub fn set_flag(&mut self, flag: u32, enabled: bool) {
+        if enabled {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+
+    /// Returns `true` if solutions can be added while the challenge is running.
+    pub fn allows_add_after_start(&self) -> bool {
+        self.has_flag(ALLOW_ADD_AFTER_START)
+    }
+
+    /// Returns `true` if challengers can refund their unused tries without the challenge
+    /// being cancelled.
+    pub fn allows_refunds(&self) -> bool {
+        self.has_flag(ALLOW_REFUNDS)
+    }
+
+    /// Returns `true` if solutions need to be committed and revealed in order to redeem,
+    /// either via [Challenge::commit_reveal] or the [REQUIRE_COMMIT_REVEAL] flag.
+    pub fn requires_commit_reveal(&self) -> bool {
+        self.commit_reveal || self.has_flag(REQUIRE_COMMIT_REVEAL)
+    }
+
+    /// The cost of buying [tries] in addition to the ones granted by admissions, pro-rated
+    /// by the tries granted per admission and rounded up.
+    /// Challenges that grant no tries per admission charge the admit cost per try.
+    pub fn tries_cost(&self, tries: u8) -> Result<u64, ProgramError> {
+        let cost = self.admit_cost as u128 * tries as u128;
+        let tries_per_admit = self.tries_per_admit.max(1) as u128;
+        let rounded_up = cost
+            .checked_add(tries_per_admit - 1)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            / tries_per_admit;
+        u64::try_from(rounded_up).map_err(|_| ProgramError::ArithmeticOverflow)
+    }
+
+    /// The lamports of the challenge PDA holding [lamports] that are neither needed to stay
+    /// rent exempt, given its [rent_exempt_minimum], nor contributed by co-sponsors.
+    pub fn surplus_lamports(
+        &self,
+        lamports: u64,
+        rent_exempt_minimum: u64,
+    ) -> u64 {
+        lamports
+            .saturating_sub(rent_exempt_minimum)
+            .saturating_sub(self.sponsored_lamports)
+    }
+
+    /// The number of challengers that can still be admitted, `None` if admissions are not
+    /// limited.
+    pub fn remaining_admissions(&self) -> Option<u32> {
+        self.max_admissions
+            .map(|max| max.saturating_sub(self.admissions))
+    }
+
+    /// Returns `true` if the solution at [solution_index] was solved already.
+    pub fn is_solved(&self, solution_index: u8) -> bool {
+        match self.solve_mode {
+            SolveMode::Sequential => solution_index < self.solving,
+            SolveMode::AnyOrder => {
+                self.solved[solution_index as usize / 8]
+                    & (1 << (solution_index % 8))
+                    != 0
+            }
+        }
+    }
+
+    /// Marks the solution at [solution_index] as solved and counts it via
+    /// [Challenge::solving].
+    /// The caller ensures that it is the current solution unless the challenge is solved in
+    /// [SolveMode::AnyOrder].
+    pub fn mark_solved(&mut self, solution_index: u8) {
+        if self.solve_mode == SolveMode::AnyOrder {
+            self.solved[solution_index as usize / 8] |=
+                1 << (solution_index % 8);
+        }
+        self.solving += 1;
+    }
+
+    /// Moves the solving progress into the epoch that includes the [slot] once the current
+    /// [Challenge::epoch] is over, returning `true` if it rolled over.
+    /// Solving restarts at the first solution the new epoch accepts, see [epoch_solutions],
+    /// and solutions of past epochs count as solved. The challenge is reopened unless the
+    /// new epoch has no solutions left to solve.
+    /// The [solutions_len] is passed in since a [crate::state::ChallengeHeader] does not
+    /// deserialize the solutions.
+    pub fn roll_over_epoch(&mut self, solutions_len: usize, slot: u64) -> bool {
+        let epoch = match epoch_at(self, slot) {
+            Some(epoch) if epoch > self.epoch => epoch,
+            _ => return false,
+        };
+        let first = epoch_solutions(self, epoch, solutions_len).start;
+        self.epoch = epoch;
+        self.solving = first as u8;
+        self.solved = [0; 32];
+        if self.solve_mode == SolveMode::AnyOrder {
+            for idx in 0..first {
+                self.solved[idx / 8] |= 1 << (idx % 8);
+            }
+        }
+        self.finished = first >= solutions_len;
+        true
+    }
+
+    /// Returns `true` if the solution at [solution_index] is accepted during the current
+    /// [Challenge::epoch], which is always the case for challenges without epochs.
+    pub fn accepts_in_epoch(
+        &self,
+        solution_index: u8,
+        solutions_len: usize,
+    ) -> bool {
+        epoch_solutions(self, self.epoch, solutions_len)
+            .contains(&(solution_index as usize))
+    }
+
+    /// Keeps the solved solutions marked after the solutions at the sorted [removed]
+    /// indexes were removed from the [len_before_removal] solutions the challenge had.
+    /// Only needed for challenges solved in [SolveMode::AnyOrder].
+    pub fn unmark_removed_solutions(
+        &mut self,
+        removed: &[u8],
+        len_before_removal: usize,
+    ) {
+        let solved_before = std::mem::take(&mut self.solved);
+        let is_set =
+            |idx: usize| solved_before[idx / 8] & (1 << (idx % 8)) != 0;
+        let mut solving = 0;
+        let remaining = (0..len_before_removal)
+            .filter(|idx| removed.binary_search(&(*idx as u8)).is_err());
+        for (new_idx, old_idx) in remaining.enumerate() {
+            if is_set(old_idx) {
+                self.solved[new_idx / 8] |= 1 << (new_idx % 8);
+                solving += 1;
+            }
+        }
+        self.solving = solving;
+    }
+
+    /// Returns the first slot at which the [challenger] may attempt to redeem again, `None`
+    /// if they can attempt right away, i.e. if the challenge has no cooldown or the
+    /// challenger did not attempt yet.
+    pub fn next_attempt_slot(&self, challenger: &Challenger) -> Option<u64> {
+        if self.attempt_cooldown_slots == 0 || challenger.tries_used == 0 {
+            return None;
+        }
+        Some(
+            challenger
+                .last_attempt_slot
+                .saturating_add(self.attempt_cooldown_slots),
+        )
+    }
+
+    /// The number of distinct challengers that need to solve each solution before it counts
+    /// as solved.
+    pub fn winners_per_solution(&self) -> u8 {
+        self.max_winners_per_solution.max(1)
+    }
+
+    /// Returns `true` if solutions may be solved by more than one challenger, in which case
+    /// the winners are recorded in the [crate::state::Winners] leaderboard.
+    pub fn has_multiple_winners(&self) -> bool {
+        self.max_winners_per_solution > 1
+    }
+
+    /// Returns the reward for redeeming the solution at [solution_index], `None` if the
+    /// solutions of the challenge aren't rewarded individually.
+    pub fn reward_for(&self, solution_index: u8) -> Option<u64> {
+        self.rewards.get(solution_index as usize).copied()
+    }
+
+    /// Appends the [rewards] of the solutions that were appended to the first
+    /// [solutions_before] solutions of the challenge, keeping [Challenge::rewards] parallel to
+    /// the solutions.
+    /// Once any solution is rewarded the solutions added without rewards are rewarded `0`.
+    /// Fails unless either no rewards or exactly one reward per added solution is provided.
+    pub fn append_rewards(
+        &mut self,
+        solutions_before: usize,
+        rewards: &[u64],
+    ) -> Result<(), ProgramError> {
+        let added = self.solutions.len() - solutions_before;
+        if !rewards.is_empty() && rewards.len() != added {
+            msg!(
+                "Err: {} rewards were provided for {} solutions, need exactly one reward per solution",
+                rewards.len(),
+                added
+            );
+            return Err(ChallengeError::RewardsDoNotMatchSolutions.into());
+        }
+        if rewards.is_empty() && self.rewards.is_empty() {
+            return Ok(());
+        }
+        self.rewards.resize(solutions_before, 0);
+        if rewards.is_empty() {
+            self.rewards.resize(self.solutions.len(), 0);
+        } else {
+            self.rewards.extend_from_slice(rewards);
+        }
+        Ok(())
+    }
+
+    /// Removes the rewards of the solutions at the sorted [removed] indexes.
+    pub fn remove_rewards(&mut self, removed: &[u8]) {
+        remove_parallel(&mut self.rewards, removed);
+    }
+
+    /// Returns the hint of the solution at [solution_index], `None` if the solution has no
+    /// hint.
+    pub fn hint_for(&self, solution_index: u8) -> Option<&Hint> {
+        self.hints
+            .get(solution_index as usize)
+            .filter(|hint| hint.iter().any(|b| *b != 0))
+    }
+
+    /// Appends the [hints] of the solutions that were appended to the first
+    /// [solutions_before] solutions of the challenge, keeping [Challenge::hints] parallel to
+    /// the solutions.
+    /// The [hints] are either empty or one per added solution, once the challenge has hints
+    /// the solutions added without hints have none.
+    pub fn append_hints(&mut self, solutions_before: usize, hints: &[Hint]) {
+        if hints.is_empty() && self.hints.is_empty() {
+            return;
+        }
+        self.hints.resize(solutions_before, [0; HINT_BYTES]);
+        if hints.is_empty() {
+            self.hints.resize(self.solutions.len(), [0; HINT_BYTES]);
+        } else {
+            self.hints.extend_from_slice(hints);
+        }
+    }
+
+    /// Removes the hints of the solutions at the sorted [removed] indexes.
+    pub fn remove_hints(&mut self, removed: &[u8]) {
+        remove_parallel(&mut self.hints, removed);
+    }
+
+    /// The solution at [Challenge::solving], for challenges solved in [SolveMode::AnyOrder]
+    /// it is only `None` once all solutions were solved.
     pub fn current_solution(&self) -> Option<&Solution> {
         self.solutions.get(self.solving as usize)
     }
 
+    /// The number of solutions that were not solved yet.
+    pub fn remaining_solutions(&self) -> u8 {
+        (self.solutions.len() as u8).saturating_sub(self.solving)
+    }
+
+    /// Returns `true` once no solutions remain to be solved which is trivially the case for
+    /// challenges without solutions.
+    pub fn is_complete(&self) -> bool {
+        self.remaining_solutions() == 0
+    }
+
+    /// The lifecycle stage of the challenge.
+    /// A challenge is [ChallengeStatus::Completed] once the redeem solving its last solution
+    /// marked it `finished`.
+    pub fn status(&self) -> ChallengeStatus {
+        if self.cancelled {
+            ChallengeStatus::Cancelled
+        } else if self.finished {
+            ChallengeStatus::Completed
+        } else if !self.started {
+            ChallengeStatus::Created
+        } else if self.paused {
+            ChallengeStatus::Paused
+        } else {
+            ChallengeStatus::Active
+        }
+    }
+
+    /// Verifies that the fields kept consistent with each other by the processor are, i.e.
+    /// that no more solutions were solved than exist and that the rewards and hints are
+    /// parallel to the solutions.
+    /// Checked before a challenge whose solutions changed is written.
+    pub fn assert_invariants(&self) -> ProgramResult {
+        let len = self.solutions.len();
+        let violation = if len > u8::MAX as usize {
+            Some(format!("has {} solutions which exceeds {}", len, u8::MAX))
+        } else if self.solving as usize > len {
+            Some(format!("solved {} of {} solutions", self.solving, len))
+        } else if !self.rewards.is_empty() && self.rewards.len() != len {
+            Some(format!(
+                "has {} rewards for {} solutions",
+                self.rewards.len(),
+                len
+            ))
+        } else if !self.hints.is_empty() && self.hints.len() != len {
+            Some(format!(
+                "has {} hints for {} solutions",
+                self.hints.len(),
+                len
+            ))
+        } else if self.admins.len() > MAX_CHALLENGE_ADMINS {
+            Some(format!(
+                "has {} admins which exceeds {}",
+                self.admins.len(),
+                MAX_CHALLENGE_ADMINS
+            ))
+        } else if self.solve_mode == SolveMode::AnyOrder
+            && !self.solved_matches_solving()
+        {
+            Some(format!(
+                "marks solutions solved inconsistent with solving {} of {} solutions",
+                self.solving, len
+            ))
+        } else {
+            None
+        };
+
+        match violation {
+            Some(violation) => {
+                msg!("Err: challenge '{}' {}", self.id, violation);
+                Err(ChallengeError::ChallengeInvariantViolated.into())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `true` if exactly [Challenge::solving] solutions are marked solved, none of
+    /// them out of range.
+    fn solved_matches_solving(&self) -> bool {
+        let marked = (0..self.solved.len() * 8)
+            .filter(|idx| self.solved[idx / 8] & (1 << (idx % 8)) != 0);
+        let mut count = 0;
+        for idx in marked {
+            if idx >= self.solutions.len() {
+                return false;
+            }
+            count += 1;
+        }
+        count == self.solving as usize
+    }
+
     pub fn is_solution_correct(&self, sent_solution: &Solution) -> bool {
-        let solution_stored_as = hash(sent_solution).to_bytes();
+        let solution_stored_as = solution_stored_as(
+            sent_solution,
+            self.salt.as_ref(),
+            self.hash_algorithm,
+        );
         let correct_solution = self.current_solution();
 
         // We should always get a solution here since we assert first that we have one
@@ -188,7 +1385,32 @@ impl Challenge {
         }
     }
 
+    pub fn has_oracle(&self) -> bool {
+        self.oracle.ne(&Pubkey::default())
+    }
+
+    /// The message the oracle signs to entitle the [challenger] to redeem the solution
+    /// with the given [solution_hash], i.e. `challenge_pda || challenger || solution_hash`.
+    pub fn oracle_message(
+        challenge_pda: &Pubkey,
+        challenger: &Pubkey,
+        solution_hash: &Solution,
+    ) -> Vec<u8> {
+        [challenge_pda.as_ref(), challenger.as_ref(), solution_hash].concat()
+    }
+
     pub fn redeem_pda(&self) -> (Pubkey, u8) {
         Redeem::new(self.pda().0).pda()
     }
 }
+
+/// Removes the [values] kept parallel to the solutions at the sorted [removed] indexes unless
+/// there are none.
+fn remove_parallel<T>(values: &mut Vec<T>, removed: &[u8]) {
+    if values.is_empty() {
+        return;
+    }
+    for idx in removed.iter().rev() {
+        values.remove(*idx as usize);
+    }
+}
@@ -0,0 +1,24 @@
+//! Bits of [super::Challenge::flags] which toggle optional behaviors of a challenge.
+//!
+//! New optional behaviors are added as further bits instead of dedicated fields so that
+//! they don't require a new challenge layout and migration.
+
+/// Solutions can still be added or replaced once the challenge started.
+/// Set by default, clear it to freeze the solutions a challenge starts with.
+pub const ALLOW_ADD_AFTER_START: u32 = 1 << 0;
+
+/// Challengers can reclaim the admit cost of their unused tries via `RefundUnusedTries`
+/// while the challenge runs and not only once it was cancelled.
+pub const ALLOW_REFUNDS: u32 = 1 << 1;
+
+/// Solutions can only be redeemed by committing to them and revealing them, same as
+/// [super::Challenge::commit_reveal].
+pub const REQUIRE_COMMIT_REVEAL: u32 = 1 << 2;
+
+/// All flags known to this program, setting any other bit is rejected.
+pub const KNOWN_CHALLENGE_FLAGS: u32 =
+    ALLOW_ADD_AFTER_START | ALLOW_REFUNDS | REQUIRE_COMMIT_REVEAL;
+
+/// The flags of newly created challenges and of challenges upgraded from layouts that
+/// preceded flags, which keeps their behavior unchanged.
+pub const DEFAULT_CHALLENGE_FLAGS: u32 = ALLOW_ADD_AFTER_START;
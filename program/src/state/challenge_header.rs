@@ -0,0 +1,390 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, hash::HASH_BYTES,
+    msg, program_error::ProgramError,
+};
+
+use crate::{
+    challenge_id,
+    error::ChallengeError,
+    utils::{
+        assert_account_is_funded_and_has_data, assert_current_version,
+        assert_discriminator, assert_owned_by, solution_stored_as,
+    },
+    Solution, HINT_BYTES,
+};
+
+use super::{
+    Challenge, HasDiscriminator, SolutionNormalization, SolveMode,
+    CHALLENGE_VERSION, DEFAULT_CHALLENGE_FLAGS, DISCRIMINATOR_LEN,
+    SEQUENTIAL_CHALLENGE_VERSION, SINGLE_WINNER_CHALLENGE_VERSION,
+    UNACCOUNTED_CHALLENGE_VERSION, UNCAPPED_CHALLENGE_VERSION,
+    UNFLAGGED_CHALLENGE_VERSION, UNGATED_CHALLENGE_VERSION,
+    UNHINTED_CHALLENGE_VERSION, UNNORMALIZED_CHALLENGE_VERSION,
+    UNREWARDED_CHALLENGE_VERSION, UNSCREENED_CHALLENGE_VERSION,
+    UNSEASONED_CHALLENGE_VERSION, UNSEEDED_CHALLENGE_VERSION,
+    UNTHROTTLED_CHALLENGE_VERSION, UNTICKETED_CHALLENGE_VERSION,
+};
+
+/// Zero-copy view of a challenge account used in the redeem hot path.
+///
+/// Only the fields preceding the solutions are deserialized. The solution at index
+/// [Challenge::solving] is read directly from the account data instead of deserializing all
+/// solutions just to compare a single hash. The hints following the solutions are not read.
+pub struct ChallengeHeader {
+    /// The challenge without its solutions and hints, i.e. `challenge.solutions` and
+    /// `challenge.hints` are always empty.
+    /// Use [ChallengeHeader::solutions_len] and [ChallengeHeader::current_solution] instead.
+    pub challenge: Challenge,
+
+    /// The number of solutions stored in the account.
+    pub solutions_len: usize,
+
+    /// The solution at index [Challenge::solving], `None` if all solutions were solved.
+    pub current_solution: Option<Solution>,
+
+    /// Length of the serialized fields preceding the solutions, excluding the discriminator.
+    header_len: usize,
+}
+
+impl ChallengeHeader {
+    pub fn try_from_account(
+        account_info: &AccountInfo,
+    ) -> Result<ChallengeHeader, ProgramError> {
+        assert_account_is_funded_and_has_data(
+            "challenge_header",
+            account_info,
+            ChallengeError::ChallengeNotFound,
+        )?;
+        assert_owned_by(
+            "challenge_header",
+            account_info,
+            &challenge_id(),
+            "challenge",
+        )?;
+        let data = account_info.try_borrow_data()?;
+        ChallengeHeader::try_from_account_data(&data)
+    }
+
+    /// Deserializes the fields preceding the solutions and copies out the current solution.
+    pub fn try_from_account_data(
+        data: &[u8],
+    ) -> Result<ChallengeHeader, ProgramError> {
+        assert_discriminator(
+            "challenge_header",
+            data,
+            &Challenge::DISCRIMINATOR,
+        )?;
+        assert_current_version(
+            "challenge_header",
+            Challenge::stored_version(data),
+            CHALLENGE_VERSION,
+        )?;
+
+        let state_data = &data[DISCRIMINATOR_LEN..];
+        let buf = &mut &state_data[..];
+        let challenge = deserialize_header(buf, CHALLENGE_VERSION)?;
+        let header_len = state_data.len() - buf.len();
+        let solutions_len = u32::deserialize(buf)? as usize;
+
+        let solutions_data = *buf;
+        if solutions_data.len() < solutions_len * HASH_BYTES {
+            msg!(
+                "Err: account data is too small to hold {} solutions",
+                solutions_len
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // the hints are not read, but need to follow the solutions
+        let hints_data = &mut &solutions_data[solutions_len * HASH_BYTES..];
+        let hints_len = u32::deserialize(hints_data)? as usize;
+        if hints_data.len() < hints_len * HINT_BYTES {
+            msg!("Err: account data is too small to hold {} hints", hints_len);
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let solving = challenge.solving as usize;
+        let current_solution = if solving < solutions_len {
+            let start = solving * HASH_BYTES;
+            let mut solution = [0u8; HASH_BYTES];
+            solution
+                .copy_from_slice(&solutions_data[start..start + HASH_BYTES]);
+            Some(solution)
+        } else {
+            None
+        };
+
+        Ok(ChallengeHeader {
+            challenge,
+            solutions_len,
+            current_solution,
+            header_len,
+        })
+    }
+
+    pub fn is_solution_correct(&self, sent_solution: &Solution) -> bool {
+        let solution_stored_as = solution_stored_as(
+            sent_solution,
+            self.challenge.salt.as_ref(),
+            self.challenge.hash_algorithm,
+        );
+        self.current_solution.as_ref() == Some(&solution_stored_as)
+    }
+
+    /// Returns the index of the solution that the [sent_solution] solves, `None` if it is
+    /// incorrect.
+    /// Challenges solved in [SolveMode::Sequential] order only accept their current solution
+    /// while the ones solved in [SolveMode::AnyOrder] accept any solution that was not
+    /// solved yet, which are read from the account [data] as needed.
+    pub fn matching_solution_index(
+        &self,
+        data: &[u8],
+        sent_solution: &Solution,
+    ) -> Option<u8> {
+        match self.challenge.solve_mode {
+            SolveMode::Sequential => self
+                .is_solution_correct(sent_solution)
+                .then_some(self.challenge.solving),
+            SolveMode::AnyOrder => {
+                let solution_stored_as = solution_stored_as(
+                    sent_solution,
+                    self.challenge.salt.as_ref(),
+                    self.challenge.hash_algorithm,
+                );
+                (0..self.solutions_len as u8).find(|idx| {
+                    !self.challenge.is_solved(*idx)
+                        && self.solution_at(data, *idx).as_ref()
+                            == Some(&solution_stored_as)
+                })
+            }
+        }
+    }
+
+    /// Reads the solution at [index] from the account [data], `None` if out of range.
+    pub fn solution_at(&self, data: &[u8], index: u8) -> Option<Solution> {
+        if index as usize >= self.solutions_len {
+            return None;
+        }
+        // the solutions follow the header and the u32 length of the solutions Vec
+        let start = DISCRIMINATOR_LEN
+            + self.header_len
+            + 4
+            + index as usize * HASH_BYTES;
+        let mut solution = [0u8; HASH_BYTES];
+        solution.copy_from_slice(data.get(start..start + HASH_BYTES)?);
+        Some(solution)
+    }
+
+    /// The number of solutions that were not solved yet, same as
+    /// [Challenge::remaining_solutions] which cannot be used here since the solutions
+    /// aren't deserialized.
+    pub fn remaining_solutions(&self) -> u8 {
+        (self.solutions_len as u8).saturating_sub(self.challenge.solving)
+    }
+
+    /// Rolls the challenge over into the epoch that includes the [slot], see
+    /// [Challenge::roll_over_epoch], and reads the solution it continues with from the
+    /// account [data].
+    /// Returns `true` if it rolled over.
+    pub fn roll_over_epoch(&mut self, data: &[u8], slot: u64) -> bool {
+        if !self.challenge.roll_over_epoch(self.solutions_len, slot) {
+            return false;
+        }
+        self.current_solution = self.solution_at(data, self.challenge.solving);
+        true
+    }
+
+    /// Marks the solution at [solution_index] as solved, see [Challenge::mark_solved], and
+    /// completes the challenge once it was the last one to solve.
+    /// Fails with [ChallengeError::ChallengeCompleted] if all solutions were solved already
+    /// such that [Challenge::solving] never exceeds the number of solutions.
+    pub fn mark_solved(&mut self, solution_index: u8) -> ProgramResult {
+        if self.remaining_solutions() == 0 {
+            msg!(
+                "Err: all {} solutions of challenge '{}' were solved already",
+                self.solutions_len,
+                self.challenge.id
+            );
+            return Err(ChallengeError::ChallengeCompleted.into());
+        }
+        self.challenge.mark_solved(solution_index);
+        self.challenge.finished = self.remaining_solutions() == 0;
+        Ok(())
+    }
+
+    /// Writes the fields preceding the solutions back into the account leaving the stored
+    /// solutions and hints untouched.
+    /// Fails if the size of those fields changed since they were read.
+    pub fn serialize_into_account(
+        &self,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        let mut header = self.challenge.try_to_vec()?;
+        // drop the u32 lengths of the empty solutions and hints Vecs
+        header.truncate(header.len() - 8);
+        if header.len() != self.header_len {
+            msg!(
+                "Err: challenge header changed size from {} to {}",
+                self.header_len,
+                header.len()
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut data = account_info.try_borrow_mut_data()?;
+        data[DISCRIMINATOR_LEN..DISCRIMINATOR_LEN + self.header_len]
+            .copy_from_slice(&header);
+        Ok(())
+    }
+}
+
+/// Deserializes all fields of the [Challenge] except its solutions and hints, leaving [buf]
+/// at the length of the solutions Vec.
+/// The fields need to be read in the order in which they are declared on [Challenge].
+/// Fields that the layout [version] does not include yet are defaulted, which is used when
+/// upgrading older challenges.
+pub(super) fn deserialize_header(
+    buf: &mut &[u8],
+    version: u8,
+) -> std::io::Result<Challenge> {
+    let has_solve_mode = version > SEQUENTIAL_CHALLENGE_VERSION;
+    let has_rewards = version > UNREWARDED_CHALLENGE_VERSION;
+    let has_hints = version > UNHINTED_CHALLENGE_VERSION;
+    let has_cooldown = version > UNTHROTTLED_CHALLENGE_VERSION;
+    let has_winners = version > SINGLE_WINNER_CHALLENGE_VERSION;
+    let has_normalization = version > UNNORMALIZED_CHALLENGE_VERSION;
+    let has_weak_solutions_flag = version > UNSCREENED_CHALLENGE_VERSION;
+    let has_ticket_admission = version > UNTICKETED_CHALLENGE_VERSION;
+    let has_flags = version > UNFLAGGED_CHALLENGE_VERSION;
+    let has_seed = version > UNSEEDED_CHALLENGE_VERSION;
+    let has_max_tries = version > UNCAPPED_CHALLENGE_VERSION;
+    let has_sponsored_lamports = version > UNACCOUNTED_CHALLENGE_VERSION;
+    let has_prerequisite = version > UNGATED_CHALLENGE_VERSION;
+    let has_epochs = version > UNSEASONED_CHALLENGE_VERSION;
+    Ok(Challenge {
+        version: BorshDeserialize::deserialize(buf)?,
+        bump: BorshDeserialize::deserialize(buf)?,
+        authority: BorshDeserialize::deserialize(buf)?,
+        pending_authority: BorshDeserialize::deserialize(buf)?,
+        original_authority: BorshDeserialize::deserialize(buf)?,
+        admins: BorshDeserialize::deserialize(buf)?,
+        id: BorshDeserialize::deserialize(buf)?,
+        started: BorshDeserialize::deserialize(buf)?,
+        finished: BorshDeserialize::deserialize(buf)?,
+        paused: BorshDeserialize::deserialize(buf)?,
+        cancelled: BorshDeserialize::deserialize(buf)?,
+        admit_cost: BorshDeserialize::deserialize(buf)?,
+        admit_cost_locked: BorshDeserialize::deserialize(buf)?,
+        admit_mint: BorshDeserialize::deserialize(buf)?,
+        has_prize_pool: BorshDeserialize::deserialize(buf)?,
+        nft_reward: BorshDeserialize::deserialize(buf)?,
+        has_first_solvers: BorshDeserialize::deserialize(buf)?,
+        commit_reveal: BorshDeserialize::deserialize(buf)?,
+        allowlist_root: BorshDeserialize::deserialize(buf)?,
+        max_admissions: BorshDeserialize::deserialize(buf)?,
+        admissions: BorshDeserialize::deserialize(buf)?,
+        salt: BorshDeserialize::deserialize(buf)?,
+        hash_algorithm: BorshDeserialize::deserialize(buf)?,
+        tries_per_admit: BorshDeserialize::deserialize(buf)?,
+        redeem: BorshDeserialize::deserialize(buf)?,
+        solving: BorshDeserialize::deserialize(buf)?,
+        solve_mode: if has_solve_mode {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            SolveMode::Sequential
+        },
+        solved: if has_solve_mode {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            [0; 32]
+        },
+        solve_open_from_slot: BorshDeserialize::deserialize(buf)?,
+        solve_open_until_slot: BorshDeserialize::deserialize(buf)?,
+        starts_at: BorshDeserialize::deserialize(buf)?,
+        ends_at: BorshDeserialize::deserialize(buf)?,
+        oracle: BorshDeserialize::deserialize(buf)?,
+        solutions_locked: BorshDeserialize::deserialize(buf)?,
+        hint_fee: if has_hints {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            0
+        },
+        attempt_cooldown_slots: if has_cooldown {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            0
+        },
+        max_winners_per_solution: if has_winners {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            0
+        },
+        solution_normalization: if has_normalization {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            SolutionNormalization::NONE
+        },
+        reject_weak_solutions: if has_weak_solutions_flag {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            false
+        },
+        ticket_admission: if has_ticket_admission {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            false
+        },
+        flags: if has_flags {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            DEFAULT_CHALLENGE_FLAGS
+        },
+        seed: if has_seed {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            None
+        },
+        max_tries_per_challenger: if has_max_tries {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            None
+        },
+        sponsored_lamports: if has_sponsored_lamports {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            0
+        },
+        prerequisite: if has_prerequisite {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            None
+        },
+        epoch_slots: if has_epochs {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            0
+        },
+        epoch_start_slot: if has_epochs {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            0
+        },
+        solutions_per_epoch: if has_epochs {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            0
+        },
+        epoch: if has_epochs {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            0
+        },
+        rewards: if has_rewards {
+            BorshDeserialize::deserialize(buf)?
+        } else {
+            vec![]
+        },
+        solutions: vec![],
+        hints: vec![],
+    })
+}
@@ -0,0 +1,21 @@
+/// The lifecycle stage of a challenge derived from its flags, see
+/// [crate::state::Challenge::status].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChallengeStatus {
+    /// The challenge was created but not started, solutions can still be changed.
+    Created,
+
+    /// The challenge admits challengers and accepts solutions.
+    Active,
+
+    /// The challenge was paused by its authority and temporarily neither admits challengers
+    /// nor accepts solutions.
+    Paused,
+
+    /// The challenge was cancelled and only refunds the tries challengers did not use.
+    Cancelled,
+
+    /// The last solution of the challenge was solved and no further redeems are accepted.
+    Completed,
+}
@@ -1,23 +1,35 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use shank::ShankAccount;
 use solana_program::pubkey::Pubkey;
 
-use crate::challenge_id;
+use crate::{challenge_id, error::ChallengeError};
 
-use super::{HasPda, HasSize};
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
 
-#[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    ShankAccount,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshSchema,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[seeds(
     "challenge",
     challenge_pda("The challenge PDA that the challenger wants to solve."),
     challenger("The address attempting to solve the challenge")
 )]
+/// Tracks the tries a challenger was admitted with and which solutions they redeemed.
 pub struct Challenger {
     /// The authority that can redeem the challenge, which is the account from
     /// which the challenger PDA (the owner of this account) was derived.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
     pub authority: Pubkey,
 
     /// The PDA of the challenge that the challenger is solving.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
     pub challenge_pda: Pubkey,
 
     /// How many more attempts the callenger has to provide a solution to redeem.
@@ -26,14 +38,37 @@ pub struct Challenger {
     /// This means that the challenger redeemed at least once.
     /// Shoud this be a count even though we could just mint multiple `redeem` tokens?
     pub redeemed: bool,
+
+    /// How many tries the challenger was granted across all admissions.
+    pub tries_admitted: u16,
+
+    /// How many tries the challenger used attempting to redeem.
+    pub tries_used: u16,
+
+    /// Bitset of the indexes of the solutions the challenger solved, see
+    /// [Challenger::has_solved].
+    pub solved: [u8; 32],
+
+    /// The slot at which the challenger last attempted to redeem.
+    pub last_attempt_slot: u64,
+
+    /// What the challenger paid into the escrow for the tries it has remaining, i.e.
+    /// without the protocol fee, which is what refunding those tries returns.
+    pub refundable: u64,
 }
 
 #[rustfmt::skip]
 pub const CHALLENGER_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
     /* authority */      32 + 
     /* challenge_pda */  32 + 
     /* tries_remaining */ 1 +
-    /* redeemed */        1;
+    /* redeemed */        1 +
+    /* tries_admitted */  2 +
+    /* tries_used */      2 +
+    /* solved */         32 +
+    /* last_attempt_slot */ 8 +
+    /* refundable */      8;
 
 impl HasSize for Challenger {
     fn size(&self) -> usize {
@@ -51,8 +86,67 @@ impl HasPda for Challenger {
     }
 }
 
+impl HasDiscriminator for Challenger {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [89, 121, 93, 18, 145, 185, 209, 216];
+    const MISSING_ERR: ChallengeError = ChallengeError::ChallengerNotAdmitted;
+}
+
 impl Challenger {
     pub fn size() -> usize {
         CHALLENGER_SIZE
     }
+
+    /// Grants the challenger [tries] to attempt to redeem, replacing the tries that remain.
+    pub fn grant_tries(&mut self, tries: u8) {
+        self.tries_remaining = tries;
+        self.tries_admitted = self.tries_admitted.saturating_add(tries as u16);
+        // what was paid for the replaced tries is no longer refundable
+        self.refundable = 0;
+    }
+
+    /// Records that [amount] of what was paid for the tries just granted or added went
+    /// into the escrow and is refunded if those tries go unused.
+    pub fn record_escrowed(&mut self, amount: u64) {
+        self.refundable = self.refundable.saturating_add(amount);
+    }
+
+    /// Adds the bought [tries] to the tries that remain, see `BuyMoreTries`.
+    /// The caller ensures that the tries remaining don't overflow.
+    pub fn add_tries(&mut self, tries: u8) {
+        self.tries_remaining += tries;
+        self.tries_admitted = self.tries_admitted.saturating_add(tries as u16);
+    }
+
+    /// Consumes one try for an attempt to redeem made at [slot].
+    pub fn record_attempt(&mut self, slot: u64) {
+        // the used try is no longer refundable, it takes its share of the escrowed amount
+        self.refundable -= self.refundable / self.tries_remaining as u64;
+        self.tries_remaining -= 1;
+        self.tries_used = self.tries_used.saturating_add(1);
+        self.last_attempt_slot = slot;
+    }
+
+    pub fn mark_solved(&mut self, solution_index: u8) {
+        self.solved[solution_index as usize / 8] |= 1 << (solution_index % 8);
+    }
+
+    pub fn has_solved(&self, solution_index: u8) -> bool {
+        self.solved[solution_index as usize / 8] & (1 << (solution_index % 8))
+            != 0
+    }
+
+    /// The number of solutions the challenger solved.
+    pub fn solved_count(&self) -> u32 {
+        self.solved.iter().map(|b| b.count_ones()).sum()
+    }
+
+    /// The score ranking the challenger on the leaderboard, see
+    /// [crate::state::LeaderboardEntry::score].
+    /// Challengers that solved more solutions rank higher and among those that solved the
+    /// same number the one that used fewer tries per solve ranks higher.
+    /// Tries that were bought but not used don't affect the score.
+    pub fn leaderboard_score(&self) -> u32 {
+        (self.solved_count() << 16) | (u16::MAX - self.tries_used) as u32
+    }
 }
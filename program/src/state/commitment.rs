@@ -0,0 +1,72 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
+
+/// Minimum number of slots that need to pass between committing to a solution and revealing
+/// it. This ensures that a solution copied from a reveal cannot be committed and revealed
+/// before the original reveal lands.
+pub const MIN_REVEAL_SLOT_GAP: u64 = 2;
+
+#[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds(
+    "commitment",
+    challenger_pda("The PDA of the challenger that committed to a solution.")
+)]
+/// Holds the commitment to a solution a challenger makes via `CommitSolution` before
+/// revealing it via `RevealSolution`.
+/// The account is closed once the solution is revealed.
+pub struct SolutionCommitment {
+    /// The PDA of the challenger that committed to the solution.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub challenger_pda: Pubkey,
+
+    /// The commitment, see [crate::utils::solution_commitment].
+    pub commitment: [u8; 32],
+
+    /// The slot at which the commitment was made.
+    pub committed_at_slot: u64,
+}
+
+#[rustfmt::skip]
+pub const SOLUTION_COMMITMENT_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* challenger_pda */    32 +
+    /* commitment */        32 +
+    /* committed_at_slot */  8;
+
+impl HasSize for SolutionCommitment {
+    fn size(&self) -> usize {
+        SOLUTION_COMMITMENT_SIZE
+    }
+}
+
+impl HasPda for SolutionCommitment {
+    fn pda(&self) -> (Pubkey, u8) {
+        SolutionCommitment::pda_for(&self.challenger_pda)
+    }
+}
+
+impl HasDiscriminator for SolutionCommitment {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [5, 244, 124, 220, 250, 149, 82, 89];
+}
+
+impl SolutionCommitment {
+    pub fn pda_for(challenger_pda: &Pubkey) -> (Pubkey, u8) {
+        SolutionCommitment::shank_pda(&challenge_id(), challenger_pda)
+    }
+
+    pub fn size() -> usize {
+        SOLUTION_COMMITMENT_SIZE
+    }
+
+    /// Returns `true` if enough slots passed since the commitment to reveal it at [slot].
+    pub fn can_reveal_at(&self, slot: u64) -> bool {
+        slot >= self.committed_at_slot.saturating_add(MIN_REVEAL_SLOT_GAP)
+    }
+}
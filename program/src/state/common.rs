@@ -1,45 +1,71 @@
-use borsh::BorshDeserialize;
 use solana_program::{
-    account_info::AccountInfo, borsh::try_from_slice_unchecked,
-    program_error::ProgramError, pubkey::Pubkey,
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
 };
 
-use crate::utils::{assert_account_is_funded_and_has_data, assert_keys_equal};
+use super::HasDiscriminator;
+use crate::{
+    challenge_id,
+    error::ChallengeError,
+    utils::{
+        assert_account_is_funded_and_has_data, assert_keys_equal,
+        assert_owned_by,
+    },
+};
 
 // -----------------
 // StateFromAccount
 // -----------------
-pub trait TryStateFromAccountUnchecked<T: BorshDeserialize> {
+pub trait TryStateFromAccountUnchecked<T: HasDiscriminator> {
     fn try_state_from_account_unchecked(&self) -> Result<T, ProgramError>;
 }
 
-impl<'a, T: BorshDeserialize> TryStateFromAccountUnchecked<T>
+impl<'a, T: HasDiscriminator> TryStateFromAccountUnchecked<T>
     for AccountInfo<'a>
 {
     /// NOTE: Deserializes without checking that the entire slice has been consumed
     fn try_state_from_account_unchecked(&self) -> Result<T, ProgramError> {
-        assert_account_is_funded_and_has_data(self)?;
+        assert_account_is_funded_and_has_data(
+            "try_state_from_account_unchecked",
+            self,
+            T::MISSING_ERR,
+        )?;
+        assert_owned_by(
+            "try_state_from_account_unchecked",
+            self,
+            &challenge_id(),
+            "state account",
+        )?;
 
         let account = {
             let account_data = self.try_borrow_data()?;
-            try_from_slice_unchecked::<T>(&account_data)?
+            T::try_from_account_data_unchecked(&account_data)?
         };
 
         Ok(account)
     }
 }
 
-pub trait TryStateFromAccount<T: BorshDeserialize> {
+pub trait TryStateFromAccount<T: HasDiscriminator> {
     fn try_state_from_account(&self) -> Result<T, ProgramError>;
 }
 
-impl<'a, T: BorshDeserialize> TryStateFromAccount<T> for AccountInfo<'a> {
+impl<'a, T: HasDiscriminator> TryStateFromAccount<T> for AccountInfo<'a> {
     fn try_state_from_account(&self) -> Result<T, ProgramError> {
-        assert_account_is_funded_and_has_data(self)?;
+        assert_account_is_funded_and_has_data(
+            "try_state_from_account",
+            self,
+            T::MISSING_ERR,
+        )?;
+        assert_owned_by(
+            "try_state_from_account",
+            self,
+            &challenge_id(),
+            "state account",
+        )?;
 
         let account = {
             let account_data = self.try_borrow_data()?;
-            BorshDeserialize::try_from_slice(&account_data)?
+            T::try_from_account_data(&account_data)?
         };
 
         Ok(account)
@@ -56,7 +82,7 @@ pub struct StateFromPdaAccountValue<T> {
 }
 
 pub trait TryStateFromPdaAccountUnchecked<
-    T: BorshDeserialize,
+    T: HasDiscriminator,
     F: FnOnce() -> (Pubkey, u8),
 >: TryStateFromAccountUnchecked<T>
 {
@@ -66,7 +92,7 @@ pub trait TryStateFromPdaAccountUnchecked<
     ) -> Result<StateFromPdaAccountValue<T>, ProgramError>;
 }
 
-impl<'a, T: BorshDeserialize, F: FnOnce() -> (Pubkey, u8)>
+impl<'a, T: HasDiscriminator, F: FnOnce() -> (Pubkey, u8)>
     TryStateFromPdaAccountUnchecked<T, F> for AccountInfo<'a>
 {
     /// Deserializes a the account state from the given account data and verifies the following:
@@ -83,19 +109,25 @@ impl<'a, T: BorshDeserialize, F: FnOnce() -> (Pubkey, u8)>
         let (pda, bump) = get_pda_and_bump();
         let state: T = self.try_state_from_account_unchecked()?;
 
-        assert_keys_equal(self.key, &pda, || {
-            format!(
+        assert_keys_equal(
+            "try_state_from_pda_account_unchecked",
+            self.key,
+            &pda,
+            ChallengeError::ProvidedPdaIncorrect,
+            || {
+                format!(
                 "The derrived PDA ({}) does not match the address of the provided PDA account ({})",
                 pda, self.key
             )
-        })?;
+            },
+        )?;
 
         Ok(StateFromPdaAccountValue { state, pda, bump })
     }
 }
 
 pub trait TryStateFromPdaAccount<
-    T: BorshDeserialize,
+    T: HasDiscriminator,
     F: FnOnce() -> (Pubkey, u8),
 >: TryStateFromAccount<T>
 {
@@ -105,7 +137,7 @@ pub trait TryStateFromPdaAccount<
     ) -> Result<StateFromPdaAccountValue<T>, ProgramError>;
 }
 
-impl<'a, T: BorshDeserialize, F: FnOnce() -> (Pubkey, u8)>
+impl<'a, T: HasDiscriminator, F: FnOnce() -> (Pubkey, u8)>
     TryStateFromPdaAccount<T, F> for AccountInfo<'a>
 {
     /// Deserializes a the account state from the given account data and verifies the following:
@@ -120,12 +152,18 @@ impl<'a, T: BorshDeserialize, F: FnOnce() -> (Pubkey, u8)>
         let (pda, bump) = get_pda_and_bump();
         let state: T = self.try_state_from_account()?;
 
-        assert_keys_equal(self.key, &pda, || {
-            format!(
+        assert_keys_equal(
+            "try_state_from_pda_account",
+            self.key,
+            &pda,
+            ChallengeError::ProvidedPdaIncorrect,
+            || {
+                format!(
                 "The derrived PDA ({}) does not match the address of the provided PDA account ({})",
                 pda, self.key
             )
-        })?;
+            },
+        )?;
 
         Ok(StateFromPdaAccountValue { state, pda, bump })
     }
@@ -140,4 +178,13 @@ pub trait HasSize {
 
 pub trait HasPda {
     fn pda(&self) -> (Pubkey, u8);
+
+    /// Stores the [bump] of the PDA with accounts that keep it, see [crate::pda].
+    /// Accounts that derive their bump whenever it is needed are returned unchanged.
+    fn with_pda_bump(self, _bump: u8) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
 }
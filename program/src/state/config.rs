@@ -0,0 +1,104 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{
+    challenge_id,
+    error::ChallengeError,
+    utils::{assert_is_signer, assert_keys_equal},
+};
+
+use super::{
+    HasDiscriminator, HasPda, HasSize, StateFromPdaAccountValue,
+    TryStateFromPdaAccount, DISCRIMINATOR_LEN,
+};
+
+/// Basis points that make up the entire admit cost.
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+#[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds("config")]
+/// Program wide configuration, initialized once by the admin via `InitConfig`.
+/// Until it is initialized no protocol fee is charged.
+pub struct Config {
+    /// The only authority allowed to update the config.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub admin: Pubkey,
+
+    /// Protocol fee in basis points of the admit cost, charged whenever a challenger is
+    /// admitted.
+    pub fee_bps: u16,
+
+    /// Receives the protocol fee, in lamports or, for challenges with an admit mint, in
+    /// its tokens to the ATA of the fee destination.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub fee_destination: Pubkey,
+}
+
+#[rustfmt::skip]
+pub const CONFIG_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* admin */           32 +
+    /* fee_bps */          2 +
+    /* fee_destination */ 32;
+
+impl HasSize for Config {
+    fn size(&self) -> usize {
+        CONFIG_SIZE
+    }
+}
+
+impl HasPda for Config {
+    fn pda(&self) -> (Pubkey, u8) {
+        Config::pda()
+    }
+}
+
+impl HasDiscriminator for Config {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [155, 12, 170, 224, 30, 250, 204, 130];
+}
+
+impl Config {
+    pub fn pda() -> (Pubkey, u8) {
+        Config::shank_pda(&challenge_id())
+    }
+
+    pub fn size() -> usize {
+        CONFIG_SIZE
+    }
+
+    /// Deserializes the config verifying that [admin_info] is its admin and signed.
+    pub fn account_state_verifying_admin(
+        context: &str,
+        config_info: &AccountInfo,
+        admin_info: &AccountInfo,
+    ) -> Result<StateFromPdaAccountValue<Config>, ProgramError> {
+        let StateFromPdaAccountValue::<Config> { state, pda, bump } =
+            config_info.try_state_from_pda_account(Config::pda)?;
+
+        assert_is_signer(context, admin_info, "admin")?;
+
+        assert_keys_equal(
+            context,
+            &state.admin,
+            admin_info.key,
+            ChallengeError::ProvidedAuthorityIncorrect,
+            || {
+                format!(
+                    "Config's admin ({}) does not match provided admin ({})",
+                    state.admin, admin_info.key
+                )
+            },
+        )?;
+        Ok(StateFromPdaAccountValue::<Config> { state, pda, bump })
+    }
+
+    /// The part of the [admit_cost] that goes to the fee destination.
+    pub fn fee_for(&self, admit_cost: u64) -> u64 {
+        (admit_cost as u128 * self.fee_bps as u128 / MAX_FEE_BPS as u128) as u64
+    }
+}
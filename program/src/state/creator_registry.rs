@@ -0,0 +1,83 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
+
+/// The most challenges a creator registry lists.
+pub const MAX_REGISTERED_CHALLENGES: usize = 128;
+
+#[derive(Debug, PartialEq, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds("registry", creator("The creator whose challenges are listed"))]
+/// Lists the challenges of a creator such that clients can enumerate them without scanning
+/// all program accounts.
+/// It is created the first time it is passed when creating a challenge and maintained by
+/// the instructions creating and closing challenges of the creator when passed to them.
+/// Challenges created while the registry was not passed are not listed.
+pub struct CreatorRegistry {
+    /// The creator whose challenges are listed, see [crate::state::Challenge::creator].
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub creator: Pubkey,
+
+    /// The PDAs of the challenges in the order they were created.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::vec_pubkey")
+    )]
+    pub challenges: Vec<Pubkey>,
+}
+
+#[rustfmt::skip]
+pub const EMPTY_CREATOR_REGISTRY_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* creator */       32 +
+    /* challenges */     4; // u32 for Vec::len
+
+impl HasSize for CreatorRegistry {
+    fn size(&self) -> usize {
+        EMPTY_CREATOR_REGISTRY_SIZE + self.challenges.len() * 32
+    }
+}
+
+impl HasPda for CreatorRegistry {
+    fn pda(&self) -> (Pubkey, u8) {
+        CreatorRegistry::pda_for(&self.creator)
+    }
+}
+
+impl HasDiscriminator for CreatorRegistry {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [14, 189, 133, 111, 190, 233, 2, 236];
+}
+
+impl CreatorRegistry {
+    pub fn new(creator: Pubkey) -> Self {
+        Self {
+            creator,
+            challenges: vec![],
+        }
+    }
+
+    pub fn pda_for(creator: &Pubkey) -> (Pubkey, u8) {
+        CreatorRegistry::shank_pda(&challenge_id(), creator)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.challenges.len() >= MAX_REGISTERED_CHALLENGES
+    }
+
+    pub fn is_registered(&self, challenge_pda: &Pubkey) -> bool {
+        self.challenges.contains(challenge_pda)
+    }
+
+    /// Removes the [challenge_pda] from the listed challenges.
+    /// Returns `true` if it was listed.
+    pub fn deregister(&mut self, challenge_pda: &Pubkey) -> bool {
+        let len = self.challenges.len();
+        self.challenges.retain(|x| x != challenge_pda);
+        self.challenges.len() != len
+    }
+}
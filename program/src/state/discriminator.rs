@@ -0,0 +1,88 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{error::ChallengeError, utils::assert_discriminator};
+
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// Implemented by all accounts written by the program.
+/// The account data starts with the [HasDiscriminator::DISCRIMINATOR] of the account kind
+/// followed by the Borsh serialized state which allows telling account kinds apart, i.e. for
+/// getProgramAccounts scans, and prevents one account kind being passed for another.
+///
+/// The discriminator is the first 8 bytes of `sha256("account:<AccountName>")`.
+pub trait HasDiscriminator: BorshSerialize + BorshDeserialize {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN];
+
+    /// The error returned when the account holding the state has no data, i.e. because it
+    /// was never created or was closed.
+    const MISSING_ERR: ChallengeError = ChallengeError::AccountHasNoData;
+
+    /// Serializes the state prefixed with its discriminator.
+    fn to_account_data(&self) -> Result<Vec<u8>, ProgramError> {
+        let mut data = Self::DISCRIMINATOR.to_vec();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+
+    /// Writes the discriminator followed by the state into the data of the [account_info]
+    /// which needs to be sized to fit both.
+    fn serialize_into_account(
+        &self,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        let mut data = account_info.try_borrow_mut_data()?;
+        if data.len() < DISCRIMINATOR_LEN {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        data[..DISCRIMINATOR_LEN].copy_from_slice(&Self::DISCRIMINATOR);
+        self.serialize(&mut &mut data[DISCRIMINATOR_LEN..])?;
+        Ok(())
+    }
+
+    /// Verifies the discriminator and deserializes the state that follows it requiring all
+    /// data to be consumed.
+    fn try_from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        assert_discriminator(
+            "try_from_account_data",
+            data,
+            &Self::DISCRIMINATOR,
+        )?;
+        Ok(Self::try_from_slice(&data[DISCRIMINATOR_LEN..])?)
+    }
+
+    /// Same as [HasDiscriminator::try_from_account_data] except that it does not check that
+    /// the entire data has been consumed.
+    fn try_from_account_data_unchecked(
+        data: &[u8],
+    ) -> Result<Self, ProgramError> {
+        assert_discriminator(
+            "try_from_account_data_unchecked",
+            data,
+            &Self::DISCRIMINATOR,
+        )?;
+        Ok(try_from_slice_unchecked(&data[DISCRIMINATOR_LEN..])?)
+    }
+}
+
+/// The kinds of accounts written by the program, used to migrate accounts that were created
+/// before discriminators were introduced.
+#[derive(
+    BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccountKind {
+    Challenge,
+    Challenger,
+    SolutionCommitment,
+    Config,
+    FirstSolvers,
+    Leaderboard,
+    PrizePool,
+    SolutionsUpload,
+    SponsorLog,
+    ChallengeTemplate,
+}
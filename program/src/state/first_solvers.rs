@@ -0,0 +1,115 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
+
+#[derive(
+    Debug, Default, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FirstSolver {
+    /// The challenger that solved the solution first, the default pubkey while unsolved.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub solver: Pubkey,
+
+    /// The slot at which the solution was solved.
+    pub solved_at_slot: u64,
+}
+
+#[rustfmt::skip]
+pub const FIRST_SOLVER_SIZE: usize =
+    /* solver */         32 +
+    /* solved_at_slot */  8;
+
+impl FirstSolver {
+    pub fn is_solved(&self) -> bool {
+        self.solver.ne(&Pubkey::default())
+    }
+}
+
+#[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds(
+    "first_solvers",
+    challenge_pda("The challenge PDA whose first solvers are recorded")
+)]
+/// Leaderboard recording the first solver of each solution of a challenge.
+/// It is written by `Redeem` if the challenge has one, see
+/// [crate::state::Challenge::has_first_solvers].
+pub struct FirstSolvers {
+    /// The PDA of the challenge this leaderboard belongs to.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub challenge_pda: Pubkey,
+
+    /// The first solver of each solution, indexed by the solution index.
+    pub solvers: Vec<FirstSolver>,
+}
+
+#[rustfmt::skip]
+pub const EMPTY_FIRST_SOLVERS_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* challenge_pda */  32 +
+    /* solvers */         4; // u32 for Vec::len
+
+impl HasSize for FirstSolvers {
+    fn size(&self) -> usize {
+        FirstSolvers::needed_size(self.solvers.len())
+    }
+}
+
+impl HasPda for FirstSolvers {
+    fn pda(&self) -> (Pubkey, u8) {
+        FirstSolvers::pda_for(&self.challenge_pda)
+    }
+}
+
+impl HasDiscriminator for FirstSolvers {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [62, 211, 108, 128, 16, 105, 210, 182];
+}
+
+impl FirstSolvers {
+    /// Creates the leaderboard with one unsolved entry for each of the [solutions_len]
+    /// solutions.
+    pub fn new(challenge_pda: Pubkey, solutions_len: usize) -> Self {
+        Self {
+            challenge_pda,
+            solvers: vec![FirstSolver::default(); solutions_len],
+        }
+    }
+
+    pub fn pda_for(challenge_pda: &Pubkey) -> (Pubkey, u8) {
+        FirstSolvers::shank_pda(&challenge_id(), challenge_pda)
+    }
+
+    pub fn needed_size(solutions_len: usize) -> usize {
+        EMPTY_FIRST_SOLVERS_SIZE + solutions_len * FIRST_SOLVER_SIZE
+    }
+
+    /// Records the [solver] of the solution at [solution_index] unless it already has one.
+    /// Grows the solvers in case solutions were added after the leaderboard was created.
+    ///
+    /// Returns `true` if the solver was recorded.
+    pub fn record(
+        &mut self,
+        solution_index: u8,
+        solver: Pubkey,
+        solved_at_slot: u64,
+    ) -> bool {
+        let idx = solution_index as usize;
+        if idx >= self.solvers.len() {
+            self.solvers.resize(idx + 1, FirstSolver::default());
+        }
+        if self.solvers[idx].is_solved() {
+            return false;
+        }
+        self.solvers[idx] = FirstSolver {
+            solver,
+            solved_at_slot,
+        };
+        true
+    }
+}
@@ -0,0 +1,30 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{blake3, hash, keccak};
+
+/// The algorithm used to hash the solutions of a challenge.
+/// Challengers send `H(solution)` and the program stores `H(H(solution))`, optionally salted,
+/// see [crate::utils::solution_stored_as].
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HashAlgorithm {
+    #[default]
+    Sha256,
+
+    /// Allows to interoperate with solutions hashed off-chain by EVM tooling.
+    Keccak256,
+
+    Blake3,
+}
+
+impl HashAlgorithm {
+    /// Hashes the concatenation of [vals] with this algorithm.
+    pub fn hashv(&self, vals: &[&[u8]]) -> [u8; 32] {
+        match self {
+            HashAlgorithm::Sha256 => hash::hashv(vals).to_bytes(),
+            HashAlgorithm::Keccak256 => keccak::hashv(vals).to_bytes(),
+            HashAlgorithm::Blake3 => blake3::hashv(vals).to_bytes(),
+        }
+    }
+}
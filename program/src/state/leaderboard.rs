@@ -0,0 +1,126 @@
+use std::cmp::Ordering;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
+
+pub const MAX_LEADERBOARD_ENTRIES: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeaderboardEntry {
+    /// The challenger that solved a solution of the challenge.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub wallet: Pubkey,
+
+    /// The score of the challenger, higher is better, see
+    /// [crate::state::Challenger::leaderboard_score].
+    pub score: u32,
+
+    /// The slot at which the entry was recorded, used to rank entries with the same score.
+    pub solved_at_slot: u64,
+}
+
+#[rustfmt::skip]
+pub const LEADERBOARD_ENTRY_SIZE: usize =
+    /* wallet */         32 +
+    /* score */           4 +
+    /* solved_at_slot */  8;
+
+impl LeaderboardEntry {
+    /// Orders entries by descending score and then by ascending slot, i.e. the best entry
+    /// is ordered first.
+    pub fn rank_cmp(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .cmp(&self.score)
+            .then(self.solved_at_slot.cmp(&other.solved_at_slot))
+    }
+}
+
+#[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds(
+    "leaderboard",
+    challenge_pda("The challenge PDA the leaderboard ranks")
+)]
+/// Tracks the top challengers of a challenge, keeping at most
+/// [MAX_LEADERBOARD_ENTRIES] entries sorted from best to worst.
+pub struct Leaderboard {
+    /// The PDA of the challenge this leaderboard belongs to.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub challenge_pda: Pubkey,
+
+    /// Entries sorted by descending score and then by ascending slot.
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+#[rustfmt::skip]
+pub const EMPTY_LEADERBOARD_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* challenge_pda */  32 +
+    /* entries */         4; // u32 for Vec::len
+
+impl HasSize for Leaderboard {
+    fn size(&self) -> usize {
+        Leaderboard::needed_size(self.entries.len())
+    }
+}
+
+impl HasPda for Leaderboard {
+    fn pda(&self) -> (Pubkey, u8) {
+        Leaderboard::pda_for(&self.challenge_pda)
+    }
+}
+
+impl HasDiscriminator for Leaderboard {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [247, 186, 238, 243, 194, 30, 9, 36];
+}
+
+impl Leaderboard {
+    pub fn new(challenge_pda: Pubkey) -> Self {
+        Self {
+            challenge_pda,
+            entries: vec![],
+        }
+    }
+
+    pub fn pda_for(challenge_pda: &Pubkey) -> (Pubkey, u8) {
+        Leaderboard::shank_pda(&challenge_id(), challenge_pda)
+    }
+
+    pub fn needed_size(entries_len: usize) -> usize {
+        EMPTY_LEADERBOARD_SIZE + entries_len * LEADERBOARD_ENTRY_SIZE
+    }
+
+    /// Inserts the entry at its rank or replaces the existing entry of the same wallet if the
+    /// new one ranks higher.
+    /// If the leaderboard is full the lowest ranked entry is evicted.
+    ///
+    /// Returns `true` if the leaderboard changed.
+    pub fn upsert(&mut self, entry: LeaderboardEntry) -> bool {
+        if let Some(idx) =
+            self.entries.iter().position(|x| x.wallet == entry.wallet)
+        {
+            if entry.rank_cmp(&self.entries[idx]) != Ordering::Less {
+                return false;
+            }
+            self.entries.remove(idx);
+        }
+
+        let idx = self
+            .entries
+            .partition_point(|x| x.rank_cmp(&entry) != Ordering::Greater);
+        if idx >= MAX_LEADERBOARD_ENTRIES {
+            return false;
+        }
+        self.entries.insert(idx, entry);
+        self.entries.truncate(MAX_LEADERBOARD_ENTRIES);
+        true
+    }
+}
@@ -0,0 +1,75 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
+
+/// The most bytes the UTF-8 encoded name of a challenge may take up.
+pub const MAX_METADATA_NAME_LEN: usize = 32;
+
+/// The most bytes the UTF-8 encoded description of a challenge may take up.
+pub const MAX_METADATA_DESCRIPTION_LEN: usize = 256;
+
+/// The most bytes the off-chain URI of a challenge may take up.
+pub const MAX_METADATA_URI_LEN: usize = 200;
+
+#[derive(Debug, PartialEq, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds(
+    "metadata",
+    challenge_pda("The challenge PDA whose metadata is stored")
+)]
+/// Describes a challenge such that explorers can render it without consulting an
+/// external registry.
+/// It is created and updated by the challenge authority via `SetMetadata`.
+pub struct ChallengeMetadata {
+    /// The PDA of the challenge this metadata describes.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub challenge_pda: Pubkey,
+
+    /// Display name of the challenge, at most [MAX_METADATA_NAME_LEN] bytes.
+    pub name: String,
+
+    /// Description of the challenge, at most [MAX_METADATA_DESCRIPTION_LEN] bytes.
+    pub description: String,
+
+    /// URI of off-chain data like an image or a JSON document describing the challenge
+    /// in more detail, at most [MAX_METADATA_URI_LEN] bytes.
+    pub uri: String,
+}
+
+#[rustfmt::skip]
+pub const EMPTY_METADATA_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* challenge_pda */ 32 +
+    /* name */           4 + // u32 for String::len
+    /* description */    4 + // u32 for String::len
+    /* uri */            4; // u32 for String::len
+
+impl HasSize for ChallengeMetadata {
+    fn size(&self) -> usize {
+        EMPTY_METADATA_SIZE
+            + self.name.len()
+            + self.description.len()
+            + self.uri.len()
+    }
+}
+
+impl HasPda for ChallengeMetadata {
+    fn pda(&self) -> (Pubkey, u8) {
+        ChallengeMetadata::pda_for(&self.challenge_pda)
+    }
+}
+
+impl HasDiscriminator for ChallengeMetadata {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [230, 159, 193, 189, 53, 132, 75, 183];
+}
+
+impl ChallengeMetadata {
+    pub fn pda_for(challenge_pda: &Pubkey) -> (Pubkey, u8) {
+        ChallengeMetadata::shank_pda(&challenge_id(), challenge_pda)
+    }
+}
@@ -1,9 +1,55 @@
 mod challenge;
+mod challenge_flags;
+mod challenge_header;
+mod challenge_status;
 mod challenger;
+mod commitment;
 mod common;
+mod config;
+mod creator_registry;
+mod discriminator;
+mod first_solvers;
+mod hash_algorithm;
+mod leaderboard;
+mod metadata;
+mod nft_reward;
+mod offsets;
+mod prize_pool;
 mod redeem;
+mod revealed_hints;
+mod schedule;
+mod solution_normalization;
+mod solutions_upload;
+mod solve_mode;
+mod sponsor;
+mod template;
+mod ticket;
+mod winners;
 
 pub use challenge::*;
+pub use challenge_flags::*;
+pub use challenge_header::*;
+pub use challenge_status::*;
 pub use challenger::*;
+pub use commitment::*;
 pub use common::*;
+pub use config::*;
+pub use creator_registry::*;
+pub use discriminator::*;
+pub use first_solvers::*;
+pub use hash_algorithm::*;
+pub use leaderboard::*;
+pub use metadata::*;
+pub use nft_reward::*;
+pub use offsets::*;
+pub use prize_pool::*;
 pub use redeem::*;
+pub use revealed_hints::*;
+pub use schedule::*;
+pub use solution_normalization::*;
+pub use solutions_upload::*;
+pub use solve_mode::*;
+pub use sponsor::*;
+pub use template::*;
+pub use ticket::*;
+pub use winners::*;
@@ -0,0 +1,60 @@
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use crate::challenge_id;
+
+use super::HasPda;
+
+#[derive(ShankAccount)]
+#[seeds(
+    "nft_reward",
+    challenge_pda("The PDA of the challenge"),
+    solution_index("Index of the solution whose solver receives the NFT", u8)
+)]
+/// This account is only constructed to access convenience methods.
+/// It's data is never stored on chain.
+pub struct NftReward {
+    /// The PDA of the challenge that rewards the NFT.
+    /// This address is also set to be the authority of the mint until the NFT is minted.
+    pub challenge_pda: Pubkey,
+
+    /// Index of the solution whose solver receives the NFT, each solution gets its own.
+    pub solution_index: u8,
+
+    /// Derived PDA of the NFT mint.
+    pub pda: Pubkey,
+}
+
+impl NftReward {
+    pub fn new(challenge_pda: Pubkey, solution_index: u8) -> Self {
+        let (pda, _) = NftReward::shank_pda(
+            &challenge_id(),
+            &challenge_pda,
+            solution_index,
+        );
+        Self {
+            challenge_pda,
+            solution_index,
+            pda,
+        }
+    }
+
+    pub fn ata(&self, recvr: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            recvr,
+            &self.pda,
+            &spl_token::id(),
+        )
+    }
+}
+
+impl HasPda for NftReward {
+    fn pda(&self) -> (Pubkey, u8) {
+        NftReward::shank_pda(
+            &challenge_id(),
+            &self.challenge_pda,
+            self.solution_index,
+        )
+    }
+}
@@ -0,0 +1,34 @@
+//! Byte offsets of account fields to build `getProgramAccounts` memcmp filters with.
+//!
+//! Offsets include the discriminator and are only provided for fields at a fixed position.
+//! All challenge fields following [super::Challenge::authority], i.e.
+//! [super::Challenge::solving], come after variable length fields and thus move whenever
+//! an optional authority, an admin or a longer id is stored. Filter on those client side
+//! after decoding the challenge instead.
+
+use super::DISCRIMINATOR_LEN;
+
+/// Offset of [super::Challenge::version], i.e. to only match challenges written with a
+/// specific layout.
+pub const CHALLENGE_VERSION_OFFSET: usize = DISCRIMINATOR_LEN;
+
+/// Offset of [super::Challenge::bump].
+pub const CHALLENGE_BUMP_OFFSET: usize = CHALLENGE_VERSION_OFFSET + 1;
+
+/// Offset of [super::Challenge::authority].
+pub const CHALLENGE_AUTHORITY_OFFSET: usize = CHALLENGE_BUMP_OFFSET + 1;
+
+/// Offset of [super::Challenger::authority].
+pub const CHALLENGER_AUTHORITY_OFFSET: usize = DISCRIMINATOR_LEN;
+
+/// Offset of [super::Challenger::challenge_pda].
+pub const CHALLENGER_CHALLENGE_PDA_OFFSET: usize =
+    CHALLENGER_AUTHORITY_OFFSET + 32;
+
+/// Offset of [super::Challenger::tries_remaining].
+pub const CHALLENGER_TRIES_REMAINING_OFFSET: usize =
+    CHALLENGER_CHALLENGE_PDA_OFFSET + 32;
+
+/// Offset of [super::Challenger::redeemed].
+pub const CHALLENGER_REDEEMED_OFFSET: usize =
+    CHALLENGER_TRIES_REMAINING_OFFSET + 1;
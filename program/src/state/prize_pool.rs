@@ -0,0 +1,89 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
+
+/// Determines how the lamports of a [PrizePool] are paid out to challengers that redeem.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Payout {
+    /// The challenger that solves the last solution receives the entire pool.
+    WinnerTakesAll,
+
+    /// Each challenger that redeems receives an equal fraction of the pool, namely the
+    /// pool divided by the number of solutions that remain to be solved.
+    PerSolution,
+}
+
+#[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds(
+    "prize_pool",
+    challenge_pda("The challenge PDA whose admit fees are pooled.")
+)]
+/// Escrow that accumulates the admit fees of a challenge that are paid in lamports and pays
+/// them out to challengers that redeem.
+/// The lamports held above the rent exempt minimum make up the pool.
+pub struct PrizePool {
+    /// The PDA of the challenge whose admit fees are pooled.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub challenge_pda: Pubkey,
+
+    /// How the pool is paid out to challengers that redeem.
+    pub payout: Payout,
+}
+
+#[rustfmt::skip]
+pub const PRIZE_POOL_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* challenge_pda */ 32 +
+    /* payout */         1;
+
+impl HasSize for PrizePool {
+    fn size(&self) -> usize {
+        PRIZE_POOL_SIZE
+    }
+}
+
+impl HasPda for PrizePool {
+    fn pda(&self) -> (Pubkey, u8) {
+        PrizePool::pda_for(&self.challenge_pda)
+    }
+}
+
+impl HasDiscriminator for PrizePool {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [51, 88, 38, 85, 206, 166, 162, 156];
+}
+
+impl PrizePool {
+    pub fn pda_for(challenge_pda: &Pubkey) -> (Pubkey, u8) {
+        PrizePool::shank_pda(&challenge_id(), challenge_pda)
+    }
+
+    pub fn size() -> usize {
+        PRIZE_POOL_SIZE
+    }
+
+    /// Returns the lamports paid to a challenger redeeming a solution given the lamports
+    /// [available] in the pool and the number of solutions that remained to be solved before
+    /// the redeem, including the one being redeemed.
+    pub fn payout_amount(
+        &self,
+        available: u64,
+        remaining_solutions: u8,
+    ) -> u64 {
+        match self.payout {
+            Payout::WinnerTakesAll if remaining_solutions <= 1 => available,
+            Payout::WinnerTakesAll => 0,
+            Payout::PerSolution => {
+                available / remaining_solutions.max(1) as u64
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
+
+#[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds(
+    "hints",
+    challenger_pda("The PDA of the challenger that revealed hints.")
+)]
+/// Records which hints of the challenge a challenger paid to reveal via `RevealHint`.
+/// The account is created when the challenger reveals their first hint.
+pub struct RevealedHints {
+    /// The PDA of the challenger that revealed the hints.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub challenger_pda: Pubkey,
+
+    /// Bitset of the indexes of the solutions whose hint was revealed, see
+    /// [RevealedHints::is_revealed].
+    pub revealed: [u8; 32],
+}
+
+#[rustfmt::skip]
+pub const REVEALED_HINTS_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* challenger_pda */ 32 +
+    /* revealed */       32;
+
+impl HasSize for RevealedHints {
+    fn size(&self) -> usize {
+        REVEALED_HINTS_SIZE
+    }
+}
+
+impl HasPda for RevealedHints {
+    fn pda(&self) -> (Pubkey, u8) {
+        RevealedHints::pda_for(&self.challenger_pda)
+    }
+}
+
+impl HasDiscriminator for RevealedHints {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [208, 73, 248, 63, 63, 51, 192, 242];
+}
+
+impl RevealedHints {
+    pub fn pda_for(challenger_pda: &Pubkey) -> (Pubkey, u8) {
+        RevealedHints::shank_pda(&challenge_id(), challenger_pda)
+    }
+
+    pub fn size() -> usize {
+        REVEALED_HINTS_SIZE
+    }
+
+    pub fn mark_revealed(&mut self, solution_index: u8) {
+        self.revealed[solution_index as usize / 8] |= 1 << (solution_index % 8);
+    }
+
+    pub fn is_revealed(&self, solution_index: u8) -> bool {
+        self.revealed[solution_index as usize / 8] & (1 << (solution_index % 8))
+            != 0
+    }
+}
@@ -0,0 +1,59 @@
+use std::ops::Range;
+
+use crate::clock::Now;
+
+use super::Challenge;
+
+/// Returns `true` if the solve window of the [challenge] includes the [slot], which is
+/// always the case if it has none.
+pub fn is_solve_window_open(challenge: &Challenge, slot: u64) -> bool {
+    !matches!(challenge.solve_open_from_slot, Some(from) if slot < from)
+        && !matches!(challenge.solve_open_until_slot, Some(until) if slot > until)
+}
+
+/// Returns `true` if the schedule of the [challenge] includes the [unix_timestamp], which is
+/// always the case if it has none.
+pub fn is_within_schedule(challenge: &Challenge, unix_timestamp: i64) -> bool {
+    !matches!(challenge.starts_at, Some(starts_at) if unix_timestamp < starts_at)
+        && !matches!(challenge.ends_at, Some(ends_at) if unix_timestamp > ends_at)
+}
+
+/// Returns `true` if the [challenge] accepts solutions at [now], i.e. it is running and
+/// both its solve window and schedule are open.
+/// Mirrors the checks `Redeem` performs so clients can tell in advance if redeeming would
+/// be rejected for timing reasons.
+pub fn is_open(challenge: &Challenge, now: &Now) -> bool {
+    challenge.started
+        && !challenge.finished
+        && !challenge.paused
+        && !challenge.cancelled
+        && is_solve_window_open(challenge, now.slot)
+        && is_within_schedule(challenge, now.unix_timestamp)
+}
+
+/// Returns the epoch of the [challenge] that includes the [slot], `None` if it has no
+/// epochs.
+/// Slots preceding the start of the first epoch belong to it.
+pub fn epoch_at(challenge: &Challenge, slot: u64) -> Option<u64> {
+    (challenge.epoch_slots > 0).then(|| {
+        slot.saturating_sub(challenge.epoch_start_slot) / challenge.epoch_slots
+    })
+}
+
+/// Returns the indexes of the solutions the [challenge] with [solutions_len] solutions
+/// accepts during the [epoch], which are all of them unless each epoch solves its own block
+/// of [Challenge::solutions_per_epoch].
+/// The range is empty once the epoch is past the last block.
+pub fn epoch_solutions(
+    challenge: &Challenge,
+    epoch: u64,
+    solutions_len: usize,
+) -> Range<usize> {
+    let per_epoch = challenge.solutions_per_epoch as u64;
+    if challenge.epoch_slots == 0 || per_epoch == 0 {
+        return 0..solutions_len;
+    }
+    let start = epoch.saturating_mul(per_epoch).min(solutions_len as u64);
+    let end = start.saturating_add(per_epoch).min(solutions_len as u64);
+    start as usize..end as usize
+}
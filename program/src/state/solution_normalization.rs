@@ -0,0 +1,49 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Rules applied to a solution in clear text before it is hashed, both when the creator
+/// adds it and when a challenger redeems it, see [crate::utils::normalize_solution].
+/// This way answers that only differ in casing or spacing, i.e. `"Hello World "` and
+/// `"hello world"`, can be treated as the same if the creator wants that.
+///
+/// The program only ever sees hashed solutions, the rules are stored with the challenge so
+/// that clients know how to normalize before hashing.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolutionNormalization {
+    /// Removes leading and trailing whitespace.
+    pub trim: bool,
+
+    /// Converts all characters to lowercase.
+    pub lowercase: bool,
+
+    /// Applies the unicode compatibility composition (NFKC), i.e. `"ﬁ"` becomes `"fi"`.
+    pub nfkc: bool,
+
+    /// Replaces each run of whitespace with a single space.
+    pub collapse_whitespace: bool,
+}
+
+impl SolutionNormalization {
+    /// Solutions are hashed exactly as they are provided.
+    pub const NONE: Self = Self {
+        trim: false,
+        lowercase: false,
+        nfkc: false,
+        collapse_whitespace: false,
+    };
+
+    /// All rules are applied.
+    pub const ALL: Self = Self {
+        trim: true,
+        lowercase: true,
+        nfkc: true,
+        collapse_whitespace: true,
+    };
+
+    /// Returns `true` if solutions are hashed exactly as they are provided.
+    pub fn is_none(&self) -> bool {
+        *self == Self::NONE
+    }
+}
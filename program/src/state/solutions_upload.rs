@@ -0,0 +1,82 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::{hash::HASH_BYTES, pubkey::Pubkey};
+
+use crate::{challenge_id, Solution};
+
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
+
+#[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds(
+    "solutions_upload",
+    challenge_pda("The challenge PDA the solutions are uploaded for")
+)]
+/// Collects solutions uploaded in chunks via `AppendSolutionsChunk` until the last chunk
+/// arrives, at which point they are appended to the challenge all at once and the account
+/// is closed.
+pub struct SolutionsUpload {
+    /// The PDA of the challenge the solutions are uploaded for.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub challenge_pda: Pubkey,
+
+    /// The total number of chunks in this upload session.
+    pub total_chunks: u8,
+
+    /// The index of the chunk that is expected next.
+    pub next_chunk: u8,
+
+    /// The solutions of all chunks received so far.
+    pub solutions: Vec<Solution>,
+}
+
+#[rustfmt::skip]
+pub const EMPTY_SOLUTIONS_UPLOAD_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* challenge_pda */  32 +
+    /* total_chunks */    1 +
+    /* next_chunk */      1 +
+    /* solutions */       4; // u32 for Vec::len
+
+impl HasSize for SolutionsUpload {
+    fn size(&self) -> usize {
+        SolutionsUpload::needed_size(self.solutions.len())
+    }
+}
+
+impl HasPda for SolutionsUpload {
+    fn pda(&self) -> (Pubkey, u8) {
+        SolutionsUpload::pda_for(&self.challenge_pda)
+    }
+}
+
+impl HasDiscriminator for SolutionsUpload {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [12, 232, 46, 138, 152, 125, 88, 127];
+}
+
+impl SolutionsUpload {
+    /// Starts a new upload session expecting [total_chunks] chunks.
+    pub fn new(challenge_pda: Pubkey, total_chunks: u8) -> Self {
+        Self {
+            challenge_pda,
+            total_chunks,
+            next_chunk: 0,
+            solutions: vec![],
+        }
+    }
+
+    pub fn pda_for(challenge_pda: &Pubkey) -> (Pubkey, u8) {
+        SolutionsUpload::shank_pda(&challenge_id(), challenge_pda)
+    }
+
+    pub fn needed_size(solutions_len: usize) -> usize {
+        EMPTY_SOLUTIONS_UPLOAD_SIZE + solutions_len * HASH_BYTES
+    }
+
+    /// Returns `true` if [chunk_index] is the chunk this session expects next and
+    /// [total_chunks] matches the total it was started with.
+    pub fn expects_chunk(&self, chunk_index: u8, total_chunks: u8) -> bool {
+        self.next_chunk == chunk_index && self.total_chunks == total_chunks
+    }
+}
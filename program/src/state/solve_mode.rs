@@ -0,0 +1,19 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Determines in which order the solutions of a challenge can be redeemed.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolveMode {
+    /// Solutions are redeemed in the order they were added, challengers always need to find
+    /// the solution at [crate::state::Challenge::solving].
+    #[default]
+    Sequential,
+
+    /// Any solution that was not solved yet can be redeemed, the solved ones are tracked in
+    /// [crate::state::Challenge::solved].
+    /// Each solution is rewarded on its own, i.e. a prize pool pays out per solution
+    /// instead of to the challenger reaching the end.
+    AnyOrder,
+}
@@ -0,0 +1,62 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
+
+#[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds(
+    "sponsor",
+    challenge_pda("The challenge PDA that the sponsor contributes to."),
+    sponsor("The address contributing lamports to the challenge")
+)]
+/// Records the lamports a sponsor contributed to the prize pool of a challenge.
+pub struct SponsorLog {
+    /// The account that contributed the lamports.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub sponsor: Pubkey,
+
+    /// The PDA of the challenge whose prize pool the sponsor contributed to.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub challenge_pda: Pubkey,
+
+    /// The total lamports contributed by the sponsor across all contributions.
+    pub total_contributed: u64,
+}
+
+#[rustfmt::skip]
+pub const SPONSOR_LOG_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* sponsor */           32 +
+    /* challenge_pda */     32 +
+    /* total_contributed */  8;
+
+impl HasSize for SponsorLog {
+    fn size(&self) -> usize {
+        SPONSOR_LOG_SIZE
+    }
+}
+
+impl HasPda for SponsorLog {
+    fn pda(&self) -> (Pubkey, u8) {
+        SponsorLog::pda_for(&self.challenge_pda, &self.sponsor)
+    }
+}
+
+impl HasDiscriminator for SponsorLog {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [168, 72, 207, 183, 137, 205, 64, 198];
+}
+
+impl SponsorLog {
+    pub fn pda_for(challenge_pda: &Pubkey, sponsor: &Pubkey) -> (Pubkey, u8) {
+        SponsorLog::shank_pda(&challenge_id(), challenge_pda, sponsor)
+    }
+
+    pub fn size() -> usize {
+        SPONSOR_LOG_SIZE
+    }
+}
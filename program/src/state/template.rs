@@ -0,0 +1,117 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::{
+    Challenge, HasDiscriminator, HasPda, HasSize, HashAlgorithm,
+    DISCRIMINATOR_LEN,
+};
+
+#[derive(
+    Debug, Default, Clone, ShankAccount, BorshDeserialize, BorshSerialize,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds(
+    "template",
+    creator("The authority that created the template and the challenges created from it"),
+    template_id("Id of the template, unique per creator", u8)
+)]
+/// Holds default settings which are applied when creating a challenge from this template.
+/// Any setting can be overridden when the challenge is created.
+pub struct ChallengeTemplate {
+    /// The authority that created the template, only this authority can create challenges
+    /// from it.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub authority: Pubkey,
+
+    /// The id of the template, needs to be unique for the creator.
+    pub template_id: u8,
+
+    /// Default for [crate::state::Challenge::admit_cost].
+    pub admit_cost: u64,
+
+    /// Default for [crate::state::Challenge::tries_per_admit].
+    pub tries_per_admit: u8,
+
+    /// Default for [crate::state::Challenge::salt].
+    pub salt: Option<[u8; 32]>,
+
+    /// Default for [crate::state::Challenge::hash_algorithm].
+    pub hash_algorithm: HashAlgorithm,
+
+    /// Default for [crate::state::Challenge::starts_at].
+    pub starts_at: Option<i64>,
+
+    /// Default for [crate::state::Challenge::ends_at].
+    pub ends_at: Option<i64>,
+}
+
+#[rustfmt::skip]
+pub const CHALLENGE_TEMPLATE_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* authority */      32 +
+    /* template_id */     1 +
+    /* admit_cost */      8 +
+    /* tries_per_admit */ 1 +
+    /* salt */            1 + /* Option tag, see Challenge::space_to_store_salt */
+    /* hash_algorithm */  1 +
+    /* starts_at */       1 + /* Option tag, see Challenge::space_to_store_schedule */
+    /* ends_at */         1;
+
+impl HasSize for ChallengeTemplate {
+    fn size(&self) -> usize {
+        CHALLENGE_TEMPLATE_SIZE
+            + Challenge::space_to_store_salt(self.salt)
+            + Challenge::space_to_store_schedule(self.starts_at, self.ends_at)
+    }
+}
+
+impl HasPda for ChallengeTemplate {
+    fn pda(&self) -> (Pubkey, u8) {
+        ChallengeTemplate::pda_for(&self.authority, self.template_id)
+    }
+}
+
+impl HasDiscriminator for ChallengeTemplate {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [83, 2, 108, 68, 110, 219, 175, 21];
+}
+
+impl ChallengeTemplate {
+    pub fn pda_for(creator: &Pubkey, template_id: u8) -> (Pubkey, u8) {
+        ChallengeTemplate::shank_pda(&challenge_id(), creator, template_id)
+    }
+
+    /// Returns the settings of a challenge created from this template, namely its defaults
+    /// unless they are overridden by the [overrides].
+    pub fn with_overrides(&self, overrides: &TemplateOverrides) -> Self {
+        Self {
+            authority: self.authority,
+            template_id: self.template_id,
+            admit_cost: overrides.admit_cost.unwrap_or(self.admit_cost),
+            tries_per_admit: overrides
+                .tries_per_admit
+                .unwrap_or(self.tries_per_admit),
+            salt: overrides.salt.or(self.salt),
+            hash_algorithm: overrides
+                .hash_algorithm
+                .unwrap_or(self.hash_algorithm),
+            starts_at: overrides.starts_at.or(self.starts_at),
+            ends_at: overrides.ends_at.or(self.ends_at),
+        }
+    }
+}
+
+/// Settings that override the defaults of a [ChallengeTemplate] when creating a challenge
+/// from it, settings that aren't provided keep the default of the template.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TemplateOverrides {
+    pub admit_cost: Option<u64>,
+    pub tries_per_admit: Option<u8>,
+    pub salt: Option<[u8; 32]>,
+    pub hash_algorithm: Option<HashAlgorithm>,
+    pub starts_at: Option<i64>,
+    pub ends_at: Option<i64>,
+}
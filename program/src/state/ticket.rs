@@ -0,0 +1,49 @@
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use crate::challenge_id;
+
+use super::HasPda;
+
+#[derive(ShankAccount)]
+#[seeds(
+    "ticket",
+    challenger_pda(
+        "The PDA of the challenger that was admitted with the ticket"
+    )
+)]
+/// This account is only constructed to access convenience methods.
+/// It's data is never stored on chain.
+pub struct Ticket {
+    /// The PDA of the challenger whose tries the holder of the ticket uses when redeeming.
+    /// The challenge PDA is the authority of the mint until the ticket is minted.
+    pub challenger_pda: Pubkey,
+
+    /// Derived PDA of the ticket mint.
+    pub pda: Pubkey,
+}
+
+impl Ticket {
+    pub fn new(challenger_pda: Pubkey) -> Self {
+        let (pda, _) = Ticket::shank_pda(&challenge_id(), &challenger_pda);
+        Self {
+            challenger_pda,
+            pda,
+        }
+    }
+
+    pub fn ata(&self, holder: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            holder,
+            &self.pda,
+            &spl_token::id(),
+        )
+    }
+}
+
+impl HasPda for Ticket {
+    fn pda(&self) -> (Pubkey, u8) {
+        Ticket::shank_pda(&challenge_id(), &self.challenger_pda)
+    }
+}
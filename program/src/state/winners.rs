@@ -0,0 +1,104 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::{HasDiscriminator, HasPda, HasSize, DISCRIMINATOR_LEN};
+
+/// The most challengers that may solve the same solution, see
+/// [crate::state::Challenge::max_winners_per_solution].
+pub const MAX_WINNERS_PER_SOLUTION: u8 = 10;
+
+#[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds(
+    "winners",
+    challenge_pda("The challenge PDA whose winners are recorded")
+)]
+/// Leaderboard recording the distinct challengers that solved each solution of a challenge
+/// that allows multiple winners per solution.
+/// It is written by `Redeem` if the challenge has one, see
+/// [crate::state::Challenge::max_winners_per_solution].
+pub struct Winners {
+    /// The PDA of the challenge this leaderboard belongs to.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub challenge_pda: Pubkey,
+
+    /// The challengers that solved each solution in the order they solved it, indexed by
+    /// the solution index.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::vec_vec_pubkey")
+    )]
+    pub winners: Vec<Vec<Pubkey>>,
+}
+
+#[rustfmt::skip]
+pub const EMPTY_WINNERS_SIZE: usize =
+    /* discriminator */ DISCRIMINATOR_LEN +
+    /* challenge_pda */  32 +
+    /* winners */         4; // u32 for Vec::len
+
+impl HasSize for Winners {
+    fn size(&self) -> usize {
+        EMPTY_WINNERS_SIZE
+            + self.winners.iter().map(|x| 4 + x.len() * 32).sum::<usize>()
+    }
+}
+
+impl HasPda for Winners {
+    fn pda(&self) -> (Pubkey, u8) {
+        Winners::pda_for(&self.challenge_pda)
+    }
+}
+
+impl HasDiscriminator for Winners {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [124, 173, 245, 175, 40, 115, 199, 91];
+}
+
+impl Winners {
+    /// Creates the leaderboard without winners for each of the [solutions_len] solutions.
+    pub fn new(challenge_pda: Pubkey, solutions_len: usize) -> Self {
+        Self {
+            challenge_pda,
+            winners: vec![vec![]; solutions_len],
+        }
+    }
+
+    pub fn pda_for(challenge_pda: &Pubkey) -> (Pubkey, u8) {
+        Winners::shank_pda(&challenge_id(), challenge_pda)
+    }
+
+    /// The number of challengers that solved the solution at [solution_index].
+    pub fn count(&self, solution_index: u8) -> u8 {
+        self.winners
+            .get(solution_index as usize)
+            .map_or(0, |x| x.len() as u8)
+    }
+
+    pub fn has_won(&self, solution_index: u8, challenger: &Pubkey) -> bool {
+        matches!(
+            self.winners.get(solution_index as usize),
+            Some(winners) if winners.contains(challenger)
+        )
+    }
+
+    /// Records the [winner] of the solution at [solution_index] unless they solved it
+    /// already.
+    /// Grows the winners in case solutions were added after the leaderboard was created.
+    ///
+    /// Returns `true` if the winner was recorded.
+    pub fn record(&mut self, solution_index: u8, winner: Pubkey) -> bool {
+        let idx = solution_index as usize;
+        if idx >= self.winners.len() {
+            self.winners.resize(idx + 1, vec![]);
+        }
+        if self.winners[idx].contains(&winner) {
+            return false;
+        }
+        self.winners[idx].push(winner);
+        true
+    }
+}
@@ -1,12 +1,5 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use challenge::{
-    challenge_id,
-    state::{Challenge, HasPda, HasSize, Redeem},
-    utils::hash_solutions,
-};
 use solana_program::{
-    borsh::try_from_slice_unchecked, program_option::COption,
-    program_pack::Pack, pubkey::Pubkey, rent::Rent,
+    program_option::COption, program_pack::Pack, pubkey::Pubkey, rent::Rent,
 };
 use solana_program_test::ProgramTestContext;
 use solana_sdk::{
@@ -15,7 +8,12 @@ use solana_sdk::{
 };
 use spl_token::state::Mint;
 
-#[allow(unused)]
+use crate::{
+    challenge_id,
+    state::{Challenge, HasDiscriminator, HasPda, HasSize, Redeem},
+    utils::hash_solutions,
+};
+
 pub async fn get_account(
     context: &mut ProgramTestContext,
     pubkey: &Pubkey,
@@ -28,7 +26,6 @@ pub async fn get_account(
         .expect("get_account(): account empty")
 }
 
-#[allow(unused)]
 pub async fn get_unpacked<T: Pack>(
     context: &mut ProgramTestContext,
     pubkey: &Pubkey,
@@ -39,19 +36,17 @@ pub async fn get_unpacked<T: Pack>(
     (acc, value)
 }
 
-#[allow(unused)] // it actually is in 01_create_challenge.rs
-pub async fn get_deserialized<T: BorshDeserialize>(
+pub async fn get_deserialized<T: HasDiscriminator>(
     context: &mut ProgramTestContext,
     pubkey: &Pubkey,
 ) -> (Account, T) {
     let acc = get_account(context, pubkey).await;
-    let value: T =
-        try_from_slice_unchecked(&acc.data).expect("Unable to deserialize");
+    let value = T::try_from_account_data_unchecked(&acc.data)
+        .expect("Unable to deserialize");
     (acc, value)
 }
 
-#[allow(unused)]
-pub async fn dump_account<T: BorshDeserialize + std::fmt::Debug>(
+pub async fn dump_account<T: HasDiscriminator + std::fmt::Debug>(
     context: &mut ProgramTestContext,
     pubkey: &Pubkey,
 ) {
@@ -60,7 +55,6 @@ pub async fn dump_account<T: BorshDeserialize + std::fmt::Debug>(
     eprintln!("{:#?}", acc);
 }
 
-#[allow(unused)]
 pub async fn dump_packed_account<T: Pack + std::fmt::Debug>(
     context: &mut ProgramTestContext,
     pubkey: &Pubkey,
@@ -75,7 +69,6 @@ pub fn rent_exempt_lamports<T: HasSize>(sized_acc: &T) -> u64 {
     rent.minimum_balance(sized_acc.size())
 }
 
-#[allow(unused)]
 pub async fn airdrop_rent(
     context: &mut ProgramTestContext,
     address: &Pubkey,
@@ -88,23 +81,34 @@ pub async fn airdrop_rent(
     lamports
 }
 
-#[allow(unused)]
-pub fn add_pda_account<T: HasSize + HasPda + BorshSerialize>(
+pub fn add_pda_account<T: HasSize + HasPda + HasDiscriminator>(
     context: &mut ProgramTestContext,
     value: &T,
 ) -> Account {
-    let (address, _) = value.pda();
+    let (address, bump) = value.pda();
     let lamports = rent_exempt_lamports(value);
     let space = value.size();
 
-    let mut account = AccountSharedData::new(lamports, space, &challenge_id());
-    account.set_data(value.try_to_vec().unwrap());
-    context.set_account(&address, &account);
+    // store the bump with accounts that keep it, as the program does when creating them
+    let data =
+        T::try_from_account_data_unchecked(&value.to_account_data().unwrap())
+            .unwrap()
+            .with_pda_bump(bump)
+            .to_account_data()
+            .unwrap();
+    debug_assert_eq!(data.len(), space);
+    let account = Account {
+        lamports,
+        data,
+        owner: challenge_id(),
+        executable: false,
+        rent_epoch: 0,
+    };
+    context.set_account(&address, &AccountSharedData::from(account.clone()));
 
-    account.into()
+    account
 }
 
-#[allow(unused)]
 pub fn add_pack_account<T: Pack>(
     context: &mut ProgramTestContext,
     address: &Pubkey,
@@ -114,16 +118,20 @@ pub fn add_pack_account<T: Pack>(
     let space = T::get_packed_len();
     let lamports = Rent::default().minimum_balance(space);
 
-    let mut account = AccountSharedData::new(lamports, space, owner);
-    let mut dst = vec![0u8; space];
-    T::pack_into_slice(value, dst.as_mut_slice());
-    account.set_data(dst);
-    context.set_account(address, &account);
+    let mut data = vec![0u8; space];
+    T::pack_into_slice(value, data.as_mut_slice());
+    let account = Account {
+        lamports,
+        data,
+        owner: *owner,
+        executable: false,
+        rent_epoch: 0,
+    };
+    context.set_account(address, &AccountSharedData::from(account.clone()));
 
-    account.into()
+    account
 }
 
-#[allow(unused)]
 pub fn add_mint_account(
     context: &mut ProgramTestContext,
     address: &Pubkey,
@@ -132,7 +140,6 @@ pub fn add_mint_account(
     add_pack_account(context, address, mint, &spl_token::id())
 }
 
-#[allow(unused)]
 pub fn add_mint_to_redeem(
     context: &mut ProgramTestContext,
     redeem: &Redeem,
@@ -148,7 +155,6 @@ pub fn add_mint_to_redeem(
     add_mint_account(context, &mint_pda, &mint)
 }
 
-#[allow(unused)] // it actually is in 02_add_solutions.rs
 pub fn add_challenge_with_solutions(
     context: &mut ProgramTestContext,
     id: &str,
@@ -168,11 +174,11 @@ pub fn add_challenge_with_solutions(
             redeem: Pubkey::new_unique(),
             solving: 0,
             solutions,
+            ..Challenge::default()
         },
     )
 }
 
-#[allow(unused)] // it actually is in 02_add_solutions.rs
 pub fn add_started_challenge_with_solutions(
     context: &mut ProgramTestContext,
     id: &str,
@@ -192,6 +198,7 @@ pub fn add_started_challenge_with_solutions(
             redeem: Pubkey::new_unique(),
             solving: 0,
             solutions,
+            ..Challenge::default()
         },
     )
 }
@@ -0,0 +1,31 @@
+use solana_program::instruction::InstructionError;
+use solana_program_test::BanksClientError;
+use solana_sdk::transaction::TransactionError;
+
+use crate::error::ChallengeError;
+
+/// Asserts that the transaction failed with the given [ChallengeError].
+pub fn assert_challenge_error(
+    res: Result<(), BanksClientError>,
+    expected: ChallengeError,
+) {
+    let expected_code = expected.clone() as u32;
+    let err = res.expect_err("transaction should have failed");
+    match err {
+        BanksClientError::TransactionError(
+            TransactionError::InstructionError(
+                _,
+                InstructionError::Custom(code),
+            ),
+        ) => assert_eq!(
+            code,
+            expected_code,
+            "expected {:?} ({}) but got {:?} ({})",
+            expected,
+            expected_code,
+            ChallengeError::from_code(code),
+            code
+        ),
+        err => panic!("expected {:?} but got {:?}", expected, err),
+    }
+}
@@ -0,0 +1,46 @@
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTestContext;
+
+/// The lamports held by each of the [pubkeys], `0` for accounts that don't exist.
+pub async fn get_lamports(
+    context: &mut ProgramTestContext,
+    pubkeys: &[Pubkey],
+) -> Vec<u64> {
+    let mut lamports = Vec::with_capacity(pubkeys.len());
+    for pubkey in pubkeys {
+        let account = context
+            .banks_client
+            .get_account(*pubkey)
+            .await
+            .expect("get_lamports(): failed to get account");
+        lamports.push(account.map_or(0, |acc| acc.lamports));
+    }
+    lamports
+}
+
+/// Asserts that the lamports of the accounts [before] and [after] a transaction add up,
+/// taking the [fees] paid for the transaction into account.
+/// Both are expected to list the lamports of the same accounts, i.e. as returned by
+/// [get_lamports], including every account that could have received lamports.
+pub fn assert_lamport_conservation(before: &[u64], after: &[u64], fees: u64) {
+    assert_eq!(
+        before.len(),
+        after.len(),
+        "lamports before and after need to be of the same accounts"
+    );
+    let total_before = before.iter().map(|x| *x as u128).sum::<u128>();
+    let total_after = after.iter().map(|x| *x as u128).sum::<u128>();
+    assert_eq!(
+        total_before,
+        total_after + fees as u128,
+        "lamports were {} (before: {:?}, after: {:?}, fees: {})",
+        if total_before > total_after + fees as u128 {
+            "burned"
+        } else {
+            "created"
+        },
+        before,
+        after,
+        fees
+    );
+}
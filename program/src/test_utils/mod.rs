@@ -0,0 +1,34 @@
+//! Fixtures to set up challenge accounts in `solana-program-test`, enabled via the
+//! `test-utils` feature, see [ChallengeScenario].
+
+use solana_program::hash::hash;
+use solana_program_test::ProgramTest;
+
+use crate::{challenge_id, Solution};
+
+mod accounts;
+mod errors;
+mod lamports;
+mod scenario;
+mod snapshot;
+mod transactions;
+
+pub use accounts::*;
+pub use errors::*;
+pub use lamports::*;
+pub use scenario::*;
+pub use snapshot::*;
+pub use transactions::*;
+
+/// Loads the program built via `cargo build-sbf`.
+pub fn program_test() -> ProgramTest {
+    ProgramTest::new("challenge", challenge_id(), None)
+}
+
+/// Mimics the `sha256(sha256(solution))` that is performed on each solution passed
+/// to ixs::create_challenge.
+pub fn hash_solution(solution: &str) -> Solution {
+    let users_sends = hash(solution.as_bytes()).to_bytes();
+    // program stores
+    hash(&users_sends).to_bytes()
+}
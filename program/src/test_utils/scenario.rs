@@ -0,0 +1,201 @@
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{
+    state::{Challenge, Challenger, HasPda, Redeem},
+    utils::hash_solutions,
+};
+
+use super::{add_mint_to_redeem, add_pda_account, process_ixs, program_test};
+
+/// Sets up a challenge, its redeem mint and admitted challengers in one go, i.e.
+///
+/// ```ignore
+/// let mut scenario = ChallengeScenario::new()
+///     .with_solutions(&["hello", "world"])
+///     .with_admitted(&[challenger.pubkey()])
+///     .started()
+///     .run()
+///     .await;
+/// ```
+///
+/// The accounts are stored directly, no instructions are processed to set them up.
+/// The payer of the test context is the authority of the challenge unless
+/// [ChallengeScenario::with_authority] is provided.
+pub struct ChallengeScenario {
+    id: String,
+    authority: Option<Pubkey>,
+    solutions: Vec<String>,
+    started: bool,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    admitted: Vec<Pubkey>,
+}
+
+impl Default for ChallengeScenario {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChallengeScenario {
+    pub const DEFAULT_ID: &'static str = "challenge-id";
+
+    pub fn new() -> Self {
+        Self {
+            id: Self::DEFAULT_ID.to_string(),
+            authority: None,
+            solutions: vec![],
+            started: false,
+            admit_cost: 200,
+            tries_per_admit: 1,
+            admitted: vec![],
+        }
+    }
+
+    pub fn with_id(self, id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            ..self
+        }
+    }
+
+    pub fn with_authority(self, authority: Pubkey) -> Self {
+        Self {
+            authority: Some(authority),
+            ..self
+        }
+    }
+
+    /// The solutions in clear text, they are stored hashed, see [hash_solutions].
+    pub fn with_solutions(self, solutions: &[&str]) -> Self {
+        Self {
+            solutions: solutions.iter().map(|s| s.to_string()).collect(),
+            ..self
+        }
+    }
+
+    pub fn with_admit_cost(self, admit_cost: u64) -> Self {
+        Self { admit_cost, ..self }
+    }
+
+    pub fn with_tries_per_admit(self, tries_per_admit: u8) -> Self {
+        Self {
+            tries_per_admit,
+            ..self
+        }
+    }
+
+    /// Admits each of the [challengers] once, granting them the tries per admit.
+    pub fn with_admitted(self, challengers: &[Pubkey]) -> Self {
+        let mut admitted = self.admitted;
+        admitted.extend_from_slice(challengers);
+        Self { admitted, ..self }
+    }
+
+    pub fn started(self) -> Self {
+        Self {
+            started: true,
+            ..self
+        }
+    }
+
+    /// Starts a test context with the program loaded and adds the scenario accounts to it.
+    pub async fn run(self) -> Scenario {
+        let mut context = program_test().start_with_context().await;
+        let ScenarioAccounts {
+            challenge,
+            challenge_pda,
+            redeem,
+            challengers,
+        } = self.add_to(&mut context);
+        Scenario {
+            context,
+            challenge,
+            challenge_pda,
+            redeem,
+            challengers,
+        }
+    }
+
+    /// Adds the scenario accounts to an already started [context].
+    pub fn add_to(self, context: &mut ProgramTestContext) -> ScenarioAccounts {
+        let authority =
+            self.authority.unwrap_or_else(|| context.payer.pubkey());
+        let redeem = Redeem::for_challenge_with(&authority, &self.id);
+        let solutions = self
+            .solutions
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        let challenge = Challenge {
+            authority,
+            id: self.id.clone(),
+            started: self.started,
+            admit_cost: self.admit_cost,
+            tries_per_admit: self.tries_per_admit,
+            redeem: redeem.pda().0,
+            solutions: hash_solutions(&solutions),
+            admissions: self.admitted.len() as u32,
+            ..Challenge::default()
+        };
+        let (challenge_pda, bump) = challenge.pda();
+        let challenge = challenge.with_pda_bump(bump);
+        add_pda_account(context, &challenge);
+        add_mint_to_redeem(context, &redeem);
+
+        let challengers = self
+            .admitted
+            .iter()
+            .map(|authority| {
+                let mut challenger = Challenger {
+                    authority: *authority,
+                    challenge_pda,
+                    ..Challenger::default()
+                };
+                challenger.grant_tries(self.tries_per_admit);
+                add_pda_account(context, &challenger);
+                challenger
+            })
+            .collect();
+
+        ScenarioAccounts {
+            challenge,
+            challenge_pda,
+            redeem,
+            challengers,
+        }
+    }
+}
+
+/// The accounts a [ChallengeScenario] added to the test context.
+pub struct ScenarioAccounts {
+    pub challenge: Challenge,
+    pub challenge_pda: Pubkey,
+    pub redeem: Redeem,
+    /// The challengers in the order they were admitted.
+    pub challengers: Vec<Challenger>,
+}
+
+/// A running [ChallengeScenario] together with its test context.
+pub struct Scenario {
+    pub context: ProgramTestContext,
+    pub challenge: Challenge,
+    pub challenge_pda: Pubkey,
+    pub redeem: Redeem,
+    /// The challengers in the order they were admitted.
+    pub challengers: Vec<Challenger>,
+}
+
+impl Scenario {
+    /// Processes the [ixs] in a single transaction paid for by the context payer which
+    /// signs in addition to the [signers].
+    pub async fn process_ixs(
+        &mut self,
+        ixs: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        process_ixs(&mut self.context, ixs, signers).await
+    }
+}
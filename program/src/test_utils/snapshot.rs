@@ -0,0 +1,93 @@
+use solana_program::{
+    program_option::COption, program_pack::Pack, pubkey::Pubkey, rent::Rent,
+};
+use solana_program_test::ProgramTest;
+use solana_sdk::account::Account;
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+
+use crate::{
+    challenge_id,
+    snapshot::{ChallengeSnapshot, SnapshotAccount},
+    state::HasDiscriminator,
+};
+
+/// Adds the accounts captured by the [snapshot] to the [program_test], reproducing the
+/// state of the challenge that was exported, see `client::export_challenge`.
+/// The redeem mint and the escrow ATA are recreated from the captured supply and amount.
+/// The admit mint is not part of the snapshot and needs to be added separately if the
+/// challenge admits with one.
+pub fn import_challenge_snapshot(
+    program_test: &mut ProgramTest,
+    snapshot: &ChallengeSnapshot,
+) {
+    add_snapshot_account(program_test, &snapshot.challenge);
+    for challenger in &snapshot.challengers {
+        add_snapshot_account(program_test, challenger);
+    }
+    if let Some(leaderboard) = &snapshot.leaderboard {
+        add_snapshot_account(program_test, leaderboard);
+    }
+    if let Some(prize_pool) = &snapshot.prize_pool {
+        add_snapshot_account(program_test, prize_pool);
+    }
+
+    let challenge_pda = snapshot.challenge.address;
+    let redeem_mint = Mint {
+        mint_authority: COption::Some(challenge_pda),
+        supply: snapshot.redeem_supply,
+        decimals: 0,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    add_token_account(
+        program_test,
+        &snapshot.challenge.state.redeem,
+        &redeem_mint,
+    );
+
+    if let Some(escrow) = &snapshot.escrow {
+        let escrow_ata = TokenAccount {
+            mint: escrow.mint,
+            owner: challenge_pda,
+            amount: escrow.amount,
+            state: AccountState::Initialized,
+            ..TokenAccount::default()
+        };
+        add_token_account(program_test, &escrow.address, &escrow_ata);
+    }
+}
+
+fn add_snapshot_account<T: HasDiscriminator>(
+    program_test: &mut ProgramTest,
+    snapshot_account: &SnapshotAccount<T>,
+) {
+    program_test.add_account(
+        snapshot_account.address,
+        Account {
+            lamports: snapshot_account.lamports,
+            data: snapshot_account.state.to_account_data().unwrap(),
+            owner: challenge_id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+fn add_token_account<T: Pack>(
+    program_test: &mut ProgramTest,
+    address: &Pubkey,
+    value: &T,
+) {
+    let mut data = vec![0u8; T::get_packed_len()];
+    T::pack_into_slice(value, &mut data);
+    program_test.add_account(
+        *address,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
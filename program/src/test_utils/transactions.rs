@@ -0,0 +1,24 @@
+use solana_program::instruction::Instruction;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// Processes the [ixs] in a single transaction paid for by the context payer which signs in
+/// addition to the [signers].
+pub async fn process_ixs(
+    context: &mut ProgramTestContext,
+    ixs: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend(signers);
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
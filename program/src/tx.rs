@@ -0,0 +1,304 @@
+use solana_program::{
+    hash::Hash, instruction::Instruction, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, message::Message,
+    transaction::Transaction,
+};
+
+use crate::{
+    ixs,
+    state::{HashAlgorithm, SolutionNormalization},
+    utils::{normalize_solution, normalize_solutions},
+};
+
+/// Compute units requested for redeeming since it may create the redeem ATA, mint the redeem
+/// token and transfer the NFT reward which exceeds the default budget.
+pub const REDEEM_COMPUTE_UNIT_LIMIT: u32 = 400_000;
+
+/// Compute budget settings which are prepended to the transaction as compute budget
+/// instructions when provided.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TxOptions {
+    /// Max compute units the transaction may consume
+    pub compute_unit_limit: Option<u32>,
+    /// Priority fee in micro-lamports per compute unit
+    pub compute_unit_price: Option<u64>,
+}
+
+/// Assembles an unsigned transaction paid for by [payer] which executes the [ixs] after the
+/// compute budget instructions derived from [options].
+/// Sign it via [Transaction::sign] or [Transaction::partial_sign].
+pub fn transaction(
+    payer: &Pubkey,
+    ixs: &[Instruction],
+    options: TxOptions,
+    recent_blockhash: Hash,
+) -> Transaction {
+    let mut all_ixs = compute_budget_ixs(options);
+    all_ixs.extend_from_slice(ixs);
+    let message =
+        Message::new_with_blockhash(&all_ixs, Some(payer), &recent_blockhash);
+    Transaction::new_unsigned(message)
+}
+
+fn compute_budget_ixs(options: TxOptions) -> Vec<Instruction> {
+    let TxOptions {
+        compute_unit_limit,
+        compute_unit_price,
+    } = options;
+    let mut ixs = vec![];
+    if let Some(units) = compute_unit_limit {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+    }
+    if let Some(micro_lamports) = compute_unit_price {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            micro_lamports,
+        ));
+    }
+    ixs
+}
+
+// -----------------
+// Create Challenge
+// -----------------
+#[derive(Debug, Clone)]
+pub struct CreateChallengeParams<'a> {
+    pub creator: Pubkey,
+    pub id: String,
+    pub admit_cost: u64,
+    pub tries_per_admit: u8,
+    /// Solutions in clear text, they are hashed before being sent
+    pub solutions: Vec<&'a str>,
+    pub starts_at: Option<i64>,
+    pub ends_at: Option<i64>,
+    pub salt: Option<[u8; 32]>,
+    pub hash_algorithm: HashAlgorithm,
+    /// Applied to the solutions before hashing them and stored with the challenge
+    pub solution_normalization: SolutionNormalization,
+    pub options: TxOptions,
+}
+
+/// Creates a challenge, see [ixs::create_salted_challenge], followed by setting its
+/// solution normalization unless no rules apply, see [ixs::set_solution_normalization].
+/// Needs to be signed by the [payer] and the creator if solutions are normalized.
+pub fn create_challenge(
+    payer: &Pubkey,
+    params: CreateChallengeParams,
+    recent_blockhash: Hash,
+) -> Result<Transaction, ProgramError> {
+    let CreateChallengeParams {
+        creator,
+        id,
+        admit_cost,
+        tries_per_admit,
+        solutions,
+        starts_at,
+        ends_at,
+        salt,
+        hash_algorithm,
+        solution_normalization,
+        options,
+    } = params;
+
+    let solutions = normalize_solutions(&solutions, solution_normalization);
+    let mut ixs = vec![ixs::create_salted_challenge(
+        *payer,
+        creator,
+        id.clone(),
+        admit_cost,
+        tries_per_admit,
+        solutions.iter().map(|s| s.as_ref()).collect(),
+        starts_at,
+        ends_at,
+        salt,
+        hash_algorithm,
+    )?];
+    if !solution_normalization.is_none() {
+        ixs.push(ixs::set_solution_normalization(
+            *payer,
+            creator,
+            id,
+            solution_normalization,
+        )?);
+    }
+    Ok(transaction(payer, &ixs, options, recent_blockhash))
+}
+
+// -----------------
+// Add Solutions
+// -----------------
+#[derive(Debug, Clone)]
+pub struct AddSolutionsParams<'a> {
+    pub creator: Pubkey,
+    pub id: String,
+    /// Solutions in clear text, they are hashed before being sent
+    pub solutions: Vec<&'a str>,
+    /// The solution normalization of the challenge
+    pub solution_normalization: SolutionNormalization,
+    pub options: TxOptions,
+}
+
+/// Adds solutions to a challenge, see [ixs::add_solutions].
+/// Needs to be signed by the [payer] and the creator.
+pub fn add_solutions(
+    payer: &Pubkey,
+    params: AddSolutionsParams,
+    recent_blockhash: Hash,
+) -> Result<Transaction, ProgramError> {
+    let AddSolutionsParams {
+        creator,
+        id,
+        solutions,
+        solution_normalization,
+        options,
+    } = params;
+
+    let solutions = normalize_solutions(&solutions, solution_normalization);
+    let ix = ixs::add_solutions(
+        *payer,
+        creator,
+        id,
+        solutions.iter().map(|s| s.as_ref()).collect(),
+    )?;
+    Ok(transaction(payer, &[ix], options, recent_blockhash))
+}
+
+// -----------------
+// Start Challenge
+// -----------------
+#[derive(Debug, Clone)]
+pub struct StartChallengeParams {
+    pub creator: Pubkey,
+    pub id: String,
+    pub options: TxOptions,
+}
+
+/// Starts a challenge, see [ixs::start_challenge].
+/// Needs to be signed by the [payer] and the creator.
+pub fn start_challenge(
+    payer: &Pubkey,
+    params: StartChallengeParams,
+    recent_blockhash: Hash,
+) -> Result<Transaction, ProgramError> {
+    let StartChallengeParams {
+        creator,
+        id,
+        options,
+    } = params;
+
+    let ix = ixs::start_challenge(creator, id)?;
+    Ok(transaction(payer, &[ix], options, recent_blockhash))
+}
+
+// -----------------
+// Admit Challenger
+// -----------------
+#[derive(Debug, Clone)]
+pub struct AdmitChallengerParams {
+    pub creator: Pubkey,
+    pub id: String,
+    pub challenger: Pubkey,
+    /// Admit mint of the challenge if the admit cost is paid in tokens
+    pub admit_mint: Option<Pubkey>,
+    pub options: TxOptions,
+}
+
+/// Admits a challenger paying the admit cost, see [ixs::admit_challenger].
+/// Only the [payer] needs to sign.
+pub fn admit_challenger(
+    payer: &Pubkey,
+    params: AdmitChallengerParams,
+    recent_blockhash: Hash,
+) -> Result<Transaction, ProgramError> {
+    let AdmitChallengerParams {
+        creator,
+        id,
+        challenger,
+        admit_mint,
+        options,
+    } = params;
+
+    let ix = match admit_mint {
+        Some(admit_mint) => ixs::admit_challenger_with_admit_mint(
+            *payer, creator, &id, challenger, admit_mint,
+        )?,
+        None => ixs::admit_challenger(*payer, creator, &id, challenger)?,
+    }
+    .ix;
+    Ok(transaction(payer, &[ix], options, recent_blockhash))
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[derive(Debug, Clone)]
+pub struct RedeemParams<'a> {
+    pub creator: Pubkey,
+    pub id: String,
+    pub challenger: Pubkey,
+    /// Solution in clear text
+    pub solution: &'a str,
+    /// The solution normalization of the challenge
+    pub solution_normalization: SolutionNormalization,
+    /// Defaults to [REDEEM_COMPUTE_UNIT_LIMIT] if no limit is provided
+    pub options: TxOptions,
+}
+
+/// Redeems a solution, see [ixs::redeem].
+/// Needs to be signed by the [payer] and the challenger.
+pub fn redeem(
+    payer: &Pubkey,
+    params: RedeemParams,
+    recent_blockhash: Hash,
+) -> Result<Transaction, ProgramError> {
+    let RedeemParams {
+        creator,
+        id,
+        challenger,
+        solution,
+        solution_normalization,
+        options,
+    } = params;
+
+    let solution = normalize_solution(solution, solution_normalization);
+    let ix = ixs::redeem(*payer, creator, &id, challenger, &solution)?;
+    let options = TxOptions {
+        compute_unit_limit: options
+            .compute_unit_limit
+            .or(Some(REDEEM_COMPUTE_UNIT_LIMIT)),
+        ..options
+    };
+    Ok(transaction(payer, &[ix], options, recent_blockhash))
+}
+
+// -----------------
+// Close Challenge
+// -----------------
+#[derive(Debug, Clone)]
+pub struct CloseChallengeParams {
+    pub creator: Pubkey,
+    pub id: String,
+    /// Receives the lamports of the closed challenge account
+    pub recipient: Pubkey,
+    pub options: TxOptions,
+}
+
+/// Closes a challenge, see [ixs::close_challenge].
+/// Needs to be signed by the [payer] and the creator.
+pub fn close_challenge(
+    payer: &Pubkey,
+    params: CloseChallengeParams,
+    recent_blockhash: Hash,
+) -> Result<Transaction, ProgramError> {
+    let CloseChallengeParams {
+        creator,
+        id,
+        recipient,
+        options,
+    } = params;
+
+    let ix = ixs::close_challenge(creator, id, recipient)?;
+    Ok(transaction(payer, &[ix], options, recent_blockhash))
+}
@@ -5,11 +5,14 @@ use solana_program::{
     program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
-    system_instruction,
+    system_instruction, system_program,
     sysvar::Sysvar,
 };
 
-use crate::error::ChallengeError;
+use crate::{
+    error::ChallengeError,
+    utils::{assert_realloc_within_limit, assert_rent_exempt},
+};
 
 // The below two methods create an account owned by the program, namely they initialize
 // the Challenge PDA.
@@ -35,6 +38,27 @@ pub fn transfer_lamports<'a>(
     )
 }
 
+/// Moves lamports out of a program owned account, i.e. one that cannot be the source of a
+/// system program transfer.
+pub fn transfer_lamports_from_program_account(
+    account_info: &AccountInfo,
+    recvr_info: &AccountInfo,
+    lamports: u64,
+) -> Result<(), ProgramError> {
+    msg!("  transfer_lamports_from_program_account()");
+    if account_info.lamports() < lamports {
+        msg!("Err: account has only {} lamports", account_info.lamports());
+        return Err(ChallengeError::InsufficientFunds.into());
+    }
+    **recvr_info.try_borrow_mut_lamports()? = recvr_info
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **account_info.try_borrow_mut_lamports()? -= lamports;
+
+    Ok(())
+}
+
 pub struct AllocateAndAssignAccountArgs<'a, 'b> {
     pub payer_info: &'a AccountInfo<'a>,
     pub account_info: &'a AccountInfo<'a>,
@@ -91,6 +115,15 @@ pub fn allocate_account_and_assign_owner(
     Ok(())
 }
 
+/// Returns the lamports an account holding [lamports] needs to receive in order to stay rent
+/// exempt once its data is resized to [new_size].
+/// Accounts that already hold enough lamports don't need a top-up, any surplus is left as is.
+pub fn rent_exempt_top_up(rent: &Rent, lamports: u64, new_size: usize) -> u64 {
+    rent.minimum_balance(new_size)
+        .max(1)
+        .saturating_sub(lamports)
+}
+
 pub struct ReallocateAccountArgs<'a> {
     pub payer_info: &'a AccountInfo<'a>,
     pub account_info: &'a AccountInfo<'a>,
@@ -110,18 +143,154 @@ pub fn reallocate_account(
         zero_init,
     } = args;
 
-    // 1. Transfer the extra rent to the account
+    assert_realloc_within_limit(
+        "reallocate_account",
+        new_size.saturating_sub(account_info.data_len()),
+    )?;
+
+    // 1. Transfer only the extra rent the account is missing at its new size
     let rent = Rent::get()?;
-    let required_lamports = rent
-        .minimum_balance(new_size)
-        .max(1)
-        .saturating_sub(account_info.lamports());
+    let required_lamports =
+        rent_exempt_top_up(&rent, account_info.lamports(), new_size);
 
     if required_lamports > 0 {
-        msg!("  reallocate_account() transfer extra rent");
+        msg!(
+            "  reallocate_account() transfer extra rent of {} lamports",
+            required_lamports
+        );
         transfer_lamports(payer_info, account_info, required_lamports)?;
     }
 
     // 2. Reallocate to the new size
-    account_info.realloc(new_size, zero_init)
+    account_info.realloc(new_size, zero_init)?;
+
+    // 3. Refuse to leave the account below rent exemption
+    assert_rent_exempt("reallocate_account", account_info, &rent)
+}
+
+pub struct CloseAccountArgs<'a> {
+    pub account_info: &'a AccountInfo<'a>,
+    pub recvr_info: &'a AccountInfo<'a>,
+}
+
+/// Closes a program owned account by moving all its lamports to the receiver, zeroing and
+/// removing its data and assigning it back to the system program.
+pub fn close_account(args: CloseAccountArgs) -> Result<(), ProgramError> {
+    msg!("  close_account()");
+
+    let CloseAccountArgs {
+        account_info,
+        recvr_info,
+    } = args;
+
+    let lamports = account_info.lamports();
+    **recvr_info.try_borrow_mut_lamports()? = recvr_info
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **account_info.try_borrow_mut_lamports()? = 0;
+
+    account_info.try_borrow_mut_data()?.fill(0);
+    account_info.realloc(0, false)?;
+    account_info.assign(&system_program::id());
+
+    Ok(())
+}
+
+pub struct ShrinkAccountArgs<'a> {
+    pub account_info: &'a AccountInfo<'a>,
+    pub recvr_info: &'a AccountInfo<'a>,
+    pub new_size: usize,
+    /// Lamports the account holds on behalf of others, i.e. the contributions of
+    /// co-sponsors, which stay with the account.
+    pub reserved_lamports: u64,
+}
+
+/// Shrinks a program owned account and moves the lamports that are no longer needed
+/// to keep it rent exempt to the receiver.
+/// The [ShrinkAccountArgs::reserved_lamports] are never refunded.
+pub fn shrink_account_refunding_rent(
+    args: ShrinkAccountArgs,
+) -> Result<(), ProgramError> {
+    msg!("  shrink_account_refunding_rent()");
+
+    let ShrinkAccountArgs {
+        account_info,
+        recvr_info,
+        new_size,
+        reserved_lamports,
+    } = args;
+
+    // 1. Reallocate to the new size
+    account_info.realloc(new_size, false)?;
+
+    // 2. Refund the rent that is no longer needed
+    let rent = Rent::get()?;
+    let excess_lamports = account_info
+        .lamports()
+        .saturating_sub(rent.minimum_balance(new_size).max(1))
+        .saturating_sub(reserved_lamports);
+
+    if excess_lamports > 0 {
+        msg!(
+            "  shrink_account_refunding_rent() refund {}",
+            excess_lamports
+        );
+        **recvr_info.try_borrow_mut_lamports()? = recvr_info
+            .lamports()
+            .checked_add(excess_lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **account_info.try_borrow_mut_lamports()? -= excess_lamports;
+    }
+
+    Ok(())
+}
+
+pub struct ResizeAccountArgs<'a> {
+    pub payer_info: &'a AccountInfo<'a>,
+    pub account_info: &'a AccountInfo<'a>,
+    pub new_size: usize,
+    /// See [ShrinkAccountArgs::reserved_lamports].
+    pub reserved_lamports: u64,
+}
+
+/// Resizes a program owned account, the payer funds the extra rent when it grows and
+/// receives the rent that is no longer needed when it shrinks.
+pub fn resize_account_settling_rent(
+    args: ResizeAccountArgs,
+) -> Result<(), ProgramError> {
+    let ResizeAccountArgs {
+        payer_info,
+        account_info,
+        new_size,
+        reserved_lamports,
+    } = args;
+
+    if new_size > account_info.data_len() {
+        reallocate_account(ReallocateAccountArgs {
+            payer_info,
+            account_info,
+            new_size,
+            zero_init: false,
+        })
+    } else {
+        shrink_account_refunding_rent(ShrinkAccountArgs {
+            account_info,
+            recvr_info: payer_info,
+            new_size,
+            reserved_lamports,
+        })
+    }
+}
+
+/// Sums the lamports held by the [accounts], counting accounts passed more than once only
+/// once.
+#[cfg(feature = "debug-lamports")]
+pub fn total_lamports(accounts: &[AccountInfo]) -> u128 {
+    let mut seen = std::collections::BTreeSet::new();
+    accounts
+        .iter()
+        .filter(|info| seen.insert(info.key))
+        .map(|info| info.lamports() as u128)
+        .sum()
 }
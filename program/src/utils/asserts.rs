@@ -1,30 +1,228 @@
+use std::{collections::BTreeSet, panic::Location};
+
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    account_info::AccountInfo,
+    bpf_loader_upgradeable,
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+    msg,
+    program::MAX_RETURN_DATA,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_program,
 };
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 
 use crate::{
+    accounts::AccountSpec,
     error::ChallengeError,
-    state::{Challenge, Challenger},
-    Solution,
+    pda::program_data_pda,
+    state::{
+        Challenge, ChallengeHeader, ChallengeMetadata, ChallengeStatus,
+        Challenger, CreatorRegistry, RevealedHints, SolutionCommitment,
+        SolutionsUpload, DISCRIMINATOR_LEN, KNOWN_CHALLENGE_FLAGS,
+        MAX_CHALLENGE_ADMINS, MAX_FEE_BPS, MAX_METADATA_DESCRIPTION_LEN,
+        MAX_METADATA_NAME_LEN, MAX_METADATA_URI_LEN, MAX_REGISTERED_CHALLENGES,
+        MAX_WINNERS_PER_SOLUTION, MIN_REVEAL_SLOT_GAP,
+    },
+    utils::{
+        solution_to_hex, verify_allowlist_proof, weak_solutions_stored_as,
+    },
+    Hint, Solution,
 };
 
+// All asserts take a `context` as their first argument which identifies the instruction
+// and/or account being checked, i.e. "add_solutions:payer".
+// It is logged together with the location of the caller whenever an assertion fails.
+
+#[track_caller]
+pub(super) fn log_context(context: &str) {
+    msg!("Err: [{}] at {}", context, Location::caller());
+}
+
+#[track_caller]
 pub fn assert_keys_equal<F: FnOnce() -> String>(
+    context: &str,
     provided_key: &Pubkey,
     expected_key: &Pubkey,
+    error: ChallengeError,
     get_msg: F,
 ) -> ProgramResult {
     if provided_key.ne(expected_key) {
+        log_context(context);
         msg!("Err: {}", get_msg());
         msg!("Err: provided {} expected {}", provided_key, expected_key);
+        Err(error.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_keys_not_equal<F: FnOnce() -> String>(
+    context: &str,
+    provided_key: &Pubkey,
+    other_key: &Pubkey,
+    get_msg: F,
+) -> ProgramResult {
+    if provided_key.eq(other_key) {
+        log_context(context);
+        msg!("Err: {}", get_msg());
+        msg!(
+            "Err: provided {} should differ from {}",
+            provided_key,
+            other_key
+        );
         Err(ChallengeError::ProvidedAtaIsIncorrect.into())
     } else {
         Ok(())
     }
 }
 
-pub fn assert_max_supported_solutions(solutions: &[Solution]) -> ProgramResult {
+/// Verifies that the [program_info] passed for the program with the given [name] is the
+/// program with the [expected] id, i.e. so that no other program is invoked in its place.
+#[track_caller]
+pub fn assert_program(
+    context: &str,
+    program_info: &AccountInfo,
+    expected: &Pubkey,
+    name: &str,
+) -> ProgramResult {
+    assert_keys_equal(
+        context,
+        program_info.key,
+        expected,
+        ChallengeError::ProvidedProgramIncorrect,
+        || format!("'{}' is not the {} program", program_info.key, name),
+    )
+}
+
+#[track_caller]
+pub fn assert_system_program(
+    context: &str,
+    program_info: &AccountInfo,
+) -> ProgramResult {
+    assert_program(context, program_info, &system_program::id(), "system")
+}
+
+#[track_caller]
+pub fn assert_spl_token_program(
+    context: &str,
+    program_info: &AccountInfo,
+) -> ProgramResult {
+    assert_program(context, program_info, &spl_token::id(), "spl_token")
+}
+
+#[track_caller]
+pub fn assert_associated_token_program(
+    context: &str,
+    program_info: &AccountInfo,
+) -> ProgramResult {
+    assert_program(
+        context,
+        program_info,
+        &spl_associated_token_account::id(),
+        "associated token",
+    )
+}
+
+/// Bincode layout of `UpgradeableLoaderState::ProgramData`, i.e. the `u32` variant
+/// followed by the deploy slot and the optional upgrade authority.
+const PROGRAM_DATA_VARIANT: u32 = 3;
+const PROGRAM_DATA_AUTHORITY_OPTION_OFFSET: usize = 4 + 8;
+const PROGRAM_DATA_AUTHORITY_OFFSET: usize =
+    PROGRAM_DATA_AUTHORITY_OPTION_OFFSET + 1;
+
+/// Verifies that [program_data_info] is the program data account of this program and
+/// that [authority] is its upgrade authority.
+#[track_caller]
+pub fn assert_program_upgrade_authority(
+    context: &str,
+    program_data_info: &AccountInfo,
+    authority: &Pubkey,
+) -> ProgramResult {
+    let (program_data, _) = program_data_pda();
+    assert_keys_equal(
+        context,
+        program_data_info.key,
+        &program_data,
+        ChallengeError::ProvidedPdaIncorrect,
+        || {
+            format!(
+                "Account ({}) is not the program data account of this program",
+                program_data_info.key
+            )
+        },
+    )?;
+    assert_owned_by(
+        context,
+        program_data_info,
+        &bpf_loader_upgradeable::id(),
+        "program data",
+    )?;
+
+    let data = program_data_info.try_borrow_data()?;
+    let upgrade_authority = match data.get(..PROGRAM_DATA_AUTHORITY_OFFSET + 32)
+    {
+        Some(bytes)
+            if bytes[..4] == PROGRAM_DATA_VARIANT.to_le_bytes()
+                && bytes[PROGRAM_DATA_AUTHORITY_OPTION_OFFSET] == 1 =>
+        {
+            bytes[PROGRAM_DATA_AUTHORITY_OFFSET..]
+                .try_into()
+                .ok()
+                .map(Pubkey::new_from_array)
+        }
+        _ => None,
+    };
+    match upgrade_authority {
+        Some(upgrade_authority) => assert_keys_equal(
+            context,
+            authority,
+            &upgrade_authority,
+            ChallengeError::ProvidedAuthorityIncorrect,
+            || {
+                format!(
+                    "Provided authority ({}) is not the upgrade authority ({}) of this program",
+                    authority, upgrade_authority
+                )
+            },
+        ),
+        None => {
+            log_context(context);
+            msg!("Err: program has no upgrade authority");
+            Err(ChallengeError::ProvidedAuthorityIncorrect.into())
+        }
+    }
+}
+
+/// Verifies that the [sysvar_info] passed for the sysvar with the given [name] is the
+/// sysvar with the [expected] id.
+#[track_caller]
+pub fn assert_sysvar(
+    context: &str,
+    sysvar_info: &AccountInfo,
+    expected: &Pubkey,
+    name: &str,
+) -> ProgramResult {
+    assert_keys_equal(
+        context,
+        sysvar_info.key,
+        expected,
+        ChallengeError::ProvidedSysvarIncorrect,
+        || format!("'{}' is not the {} sysvar", sysvar_info.key, name),
+    )
+}
+
+#[track_caller]
+pub fn assert_max_supported_solutions(
+    context: &str,
+    solutions: &[Solution],
+) -> ProgramResult {
     let len = solutions.len();
     if len > u8::MAX as usize {
+        log_context(context);
         msg!(
             "Err: solutions len ({}) is greater than maximum supported solutions ({})",
             len,
@@ -36,7 +234,9 @@ pub fn assert_max_supported_solutions(solutions: &[Solution]) -> ProgramResult {
     }
 }
 
+#[track_caller]
 pub fn assert_can_add_solutions(
+    context: &str,
     solutions: &[Solution],
     extra_solutions: &[Solution],
 ) -> ProgramResult {
@@ -45,6 +245,7 @@ pub fn assert_can_add_solutions(
 
     let final_len = solutions_len.saturating_add(extra_solutions_len);
     if final_len > u8::MAX as usize {
+        log_context(context);
         msg!(
             "Err: adding {} solutions would result in {} total solutions which exceeds max supported {}",
             extra_solutions_len,
@@ -57,8 +258,68 @@ pub fn assert_can_add_solutions(
     }
 }
 
-pub fn assert_adding_non_empty(extra_solutions: &[Solution]) -> ProgramResult {
+/// Verifies that none of the [solutions] is one of the [crate::utils::weak_solutions] if
+/// the [challenge] rejects weak solutions.
+#[track_caller]
+pub fn assert_no_weak_solutions(
+    context: &str,
+    challenge: &Challenge,
+    solutions: &[Solution],
+) -> ProgramResult {
+    if !challenge.reject_weak_solutions || solutions.is_empty() {
+        return Ok(());
+    }
+    let weak_solutions: BTreeSet<Solution> = weak_solutions_stored_as(
+        challenge.salt.as_ref(),
+        challenge.hash_algorithm,
+    )
+    .into_iter()
+    .collect();
+    match solutions
+        .iter()
+        .position(|solution| weak_solutions.contains(solution))
+    {
+        Some(idx) => {
+            log_context(context);
+            msg!(
+                "Err: solution {} ({}) is one of the most common answers, choose a harder one",
+                idx,
+                solution_to_hex(&solutions[idx])
+            );
+            Err(ChallengeError::WeakSolution.into())
+        }
+        None => Ok(()),
+    }
+}
+
+#[track_caller]
+pub fn assert_no_duplicate_solutions(
+    context: &str,
+    solutions: &[Solution],
+    extra_solutions: &[Solution],
+) -> ProgramResult {
+    let mut seen: BTreeSet<&Solution> = solutions.iter().collect();
+    for (idx, solution) in extra_solutions.iter().enumerate() {
+        if !seen.insert(solution) {
+            log_context(context);
+            msg!(
+                "Err: solution {} to add ({}) is already part of the challenge or added twice",
+                idx,
+                solution_to_hex(solution)
+            );
+            return Err(ChallengeError::DuplicateSolution.into());
+        }
+    }
+    Ok(())
+}
+
+#[track_caller]
+pub fn assert_adding_non_empty(
+    context: &str,
+    extra_solutions: &[Solution],
+) -> ProgramResult {
     if extra_solutions.is_empty() {
+        log_context(context);
         msg!("Err: no solutions to add cannot be empty");
         Err(ChallengeError::NoSolutionsToAddProvided.into())
     } else {
@@ -66,26 +327,86 @@ pub fn assert_adding_non_empty(extra_solutions: &[Solution]) -> ProgramResult {
     }
 }
 
+#[track_caller]
+pub fn assert_owned_by(
+    context: &str,
+    account: &AccountInfo,
+    owner: &Pubkey,
+    label: &str,
+) -> ProgramResult {
+    if account.owner.ne(owner) {
+        log_context(context);
+        msg!(
+            "Err: {} ({}) is owned by {} and not by {}",
+            label,
+            account.key,
+            account.owner,
+            owner
+        );
+        Err(ChallengeError::IncorrectAccountOwner.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_rent_exempt(
+    context: &str,
+    account: &AccountInfo,
+    rent: &Rent,
+) -> ProgramResult {
+    let lamports = account.lamports();
+    let size = account.data_len();
+    if !rent.is_exempt(lamports, size) {
+        log_context(context);
+        msg!(
+            "Err: account ({}) holds {} lamports, but needs {} to be rent exempt with {} bytes",
+            account.key,
+            lamports,
+            rent.minimum_balance(size),
+            size
+        );
+        Err(ChallengeError::AccountNotRentExempt.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
 pub fn assert_account_is_funded_and_has_data(
+    context: &str,
     account: &AccountInfo,
+    missing_err: ChallengeError,
 ) -> ProgramResult {
     if account.try_data_len()?.eq(&0) {
+        log_context(context);
         msg!(
-            "Err: account data is empty, did you intialize it via create_challenge()?",
+            "Err: account ({}) data is empty, did you intialize it via create_challenge()?",
+            account.key
         );
-        Err(ChallengeError::AccountHasNoData.into())
+        Err(missing_err.into())
     } else if account.try_lamports()? < 1 {
-        msg!("Err: account is not funded, did you intialize it via create_challenge()?");
+        log_context(context);
+        msg!(
+            "Err: account ({}) is not funded, did you intialize it via create_challenge()?",
+            account.key
+        );
         Err(ChallengeError::AccountNotFunded.into())
     } else {
         Ok(())
     }
 }
 
-pub fn assert_account_has_no_data(account: &AccountInfo) -> ProgramResult {
+#[track_caller]
+pub fn assert_account_has_no_data(
+    context: &str,
+    account: &AccountInfo,
+) -> ProgramResult {
     if account.try_data_len()?.ne(&0) {
+        log_context(context);
         msg!(
-            "Err: account data is not empty, did you already intialize it via create_challenge()?",
+            "Err: account ({}) data is not empty, did you already intialize it via create_challenge()?",
+            account.key
         );
         Err(ChallengeError::AccountAlreadyHasData.into())
     } else {
@@ -93,11 +414,14 @@ pub fn assert_account_has_no_data(account: &AccountInfo) -> ProgramResult {
     }
 }
 
+#[track_caller]
 pub fn assert_is_signer(
+    context: &str,
     account: &AccountInfo,
     account_label: &str,
 ) -> ProgramResult {
     if !account.is_signer {
+        log_context(context);
         msg!(
             "Err: account '{}' ({}) should be signer",
             account_label,
@@ -109,11 +433,38 @@ pub fn assert_is_signer(
     }
 }
 
+/// Verifies that the [authority] signed, either as part of the transaction or delegated by
+/// the program it is a PDA of, i.e. the vault of a multisig, which signs for it via
+/// `invoke_signed` when invoking this program.
+/// The runtime marks the [authority] as signer in both cases, thus DAOs can run challenges
+/// by making such a PDA their authority.
+#[track_caller]
+pub fn assert_is_signer_or_delegated(
+    context: &str,
+    authority: &AccountInfo,
+    authority_label: &str,
+) -> ProgramResult {
+    if !authority.is_signer {
+        log_context(context);
+        msg!(
+            "Err: account '{}' ({}) should be signer, if it is a PDA the program it belongs to needs to sign for it via invoke_signed",
+            authority_label,
+            authority.key
+        );
+        Err(ChallengeError::AccountShouldBeSigner.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
 pub fn assert_has_solutions(
+    context: &str,
     challenge: &Challenge,
     task: &str,
 ) -> ProgramResult {
     if challenge.solutions.is_empty() {
+        log_context(context);
         msg!(
             "Err: challenge '{}' has no solutions and thus cannot {}.",
             challenge.id,
@@ -125,8 +476,13 @@ pub fn assert_has_solutions(
     }
 }
 
-pub fn assert_not_started(challenge: &Challenge) -> ProgramResult {
+#[track_caller]
+pub fn assert_not_started(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
     if challenge.started {
+        log_context(context);
         msg!("Err: challenge '{}' has already started", challenge.id);
         Err(ChallengeError::ChallengeAlreadyStarted.into())
     } else {
@@ -134,8 +490,10 @@ pub fn assert_not_started(challenge: &Challenge) -> ProgramResult {
     }
 }
 
-pub fn assert_started(challenge: &Challenge) -> ProgramResult {
+#[track_caller]
+pub fn assert_started(context: &str, challenge: &Challenge) -> ProgramResult {
     if !challenge.started {
+        log_context(context);
         msg!("Err: challenge '{}' has not yet started and is not admitting challengers", challenge.id);
         Err(ChallengeError::ChallengeNotYetStarted.into())
     } else {
@@ -143,8 +501,13 @@ pub fn assert_started(challenge: &Challenge) -> ProgramResult {
     }
 }
 
-pub fn assert_not_finished(challenge: &Challenge) -> ProgramResult {
+#[track_caller]
+pub fn assert_not_finished(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
     if challenge.finished {
+        log_context(context);
         msg!("Err: challenge '{}' has already finished and is not admitting challengers nor accepting solutions", challenge.id);
         Err(ChallengeError::ChallengeAlreadyFinished.into())
     } else {
@@ -152,7 +515,42 @@ pub fn assert_not_finished(challenge: &Challenge) -> ProgramResult {
     }
 }
 
+/// Redeeming a challenge whose last solution was solved fails with
+/// [ChallengeError::ChallengeCompleted], see [crate::state::ChallengeStatus::Completed].
+#[track_caller]
+pub fn assert_not_completed(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.status() == ChallengeStatus::Completed {
+        log_context(context);
+        msg!(
+            "Err: all solutions of challenge '{}' were solved and it accepts no further redeems",
+            challenge.id
+        );
+        Err(ChallengeError::ChallengeCompleted.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_not_paused(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.paused {
+        log_context(context);
+        msg!("Err: challenge '{}' is paused and is not admitting challengers nor accepting solutions", challenge.id);
+        Err(ChallengeError::ChallengePaused.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
 pub fn assert_account_does_not_exist(
+    context: &str,
     account: &AccountInfo,
     acc_name: &str,
 ) -> ProgramResult {
@@ -160,6 +558,7 @@ pub fn assert_account_does_not_exist(
         || account.try_lamports()?.ne(&0)
         || account.try_data_len()?.ne(&0)
     {
+        log_context(context);
         msg!(
             "Err: account '{}' ({}) exists already",
             acc_name,
@@ -171,10 +570,15 @@ pub fn assert_account_does_not_exist(
     }
 }
 
-pub fn assert_has_solution(challenge: &Challenge) -> ProgramResult {
-    if challenge.current_solution().is_none() {
+#[track_caller]
+pub fn assert_has_solution(
+    context: &str,
+    header: &ChallengeHeader,
+) -> ProgramResult {
+    if header.current_solution.is_none() {
+        log_context(context);
         msg!("Err: challenge '{}' is out of solutions, not sure how that happened",
-            challenge.id);
+            header.challenge.id);
 
         Err(ChallengeError::SolutionIsIncorrect.into())
     } else {
@@ -182,13 +586,1469 @@ pub fn assert_has_solution(challenge: &Challenge) -> ProgramResult {
     }
 }
 
+#[track_caller]
 pub fn assert_challenger_has_tries_remaining(
+    context: &str,
     challenger: &Challenger,
 ) -> ProgramResult {
     if challenger.tries_remaining.eq(&0) {
-        msg!("Err: challenger has no tries left");
+        log_context(context);
+        msg!(
+            "Err: challenger ({}) has no tries left",
+            challenger.authority
+        );
         Err(ChallengeError::ChallengerHasNoTriesRemaining.into())
     } else {
         Ok(())
     }
 }
+
+#[track_caller]
+pub fn assert_solve_window_open(
+    context: &str,
+    challenge: &Challenge,
+    slot: u64,
+) -> ProgramResult {
+    match (
+        challenge.solve_open_from_slot,
+        challenge.solve_open_until_slot,
+    ) {
+        (Some(from), _) if slot < from => {
+            log_context(context);
+            msg!(
+                "Err: challenge '{}' accepts solutions starting at slot {}, current slot is {}",
+                challenge.id,
+                from,
+                slot
+            );
+            Err(ChallengeError::SolveWindowNotOpen.into())
+        }
+        (_, Some(until)) if slot > until => {
+            log_context(context);
+            msg!(
+                "Err: challenge '{}' stopped accepting solutions at slot {}, current slot is {}",
+                challenge.id,
+                until,
+                slot
+            );
+            Err(ChallengeError::SolveWindowClosed.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[track_caller]
+pub fn assert_valid_solve_window(
+    context: &str,
+    from_slot: Option<u64>,
+    until_slot: Option<u64>,
+) -> ProgramResult {
+    match (from_slot, until_slot) {
+        (Some(from), Some(until)) if from > until => {
+            log_context(context);
+            msg!(
+                "Err: solve window opens at slot {} which is after it closes at slot {}",
+                from,
+                until
+            );
+            Err(ChallengeError::InvalidSolveWindow.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[track_caller]
+pub fn assert_within_schedule(
+    context: &str,
+    challenge: &Challenge,
+    unix_timestamp: i64,
+) -> ProgramResult {
+    match (challenge.starts_at, challenge.ends_at) {
+        (Some(starts_at), _) if unix_timestamp < starts_at => {
+            log_context(context);
+            msg!(
+                "Err: challenge '{}' starts at {}, current time is {}",
+                challenge.id,
+                starts_at,
+                unix_timestamp
+            );
+            Err(ChallengeError::ChallengeNotOpenYet.into())
+        }
+        (_, Some(ends_at)) if unix_timestamp > ends_at => {
+            log_context(context);
+            msg!(
+                "Err: challenge '{}' ended at {}, current time is {}",
+                challenge.id,
+                ends_at,
+                unix_timestamp
+            );
+            Err(ChallengeError::ChallengeEnded.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[track_caller]
+pub fn assert_valid_schedule(
+    context: &str,
+    starts_at: Option<i64>,
+    ends_at: Option<i64>,
+) -> ProgramResult {
+    match (starts_at, ends_at) {
+        (Some(starts_at), Some(ends_at)) if starts_at > ends_at => {
+            log_context(context);
+            msg!(
+                "Err: challenge starts at {} which is after it ends at {}",
+                starts_at,
+                ends_at
+            );
+            Err(ChallengeError::InvalidSchedule.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[track_caller]
+pub fn assert_state_fits_return_data(
+    context: &str,
+    data: &[u8],
+) -> ProgramResult {
+    if data.len() > MAX_RETURN_DATA {
+        log_context(context);
+        msg!(
+            "Err: state is {} bytes which exceeds max return data size of {} bytes",
+            data.len(),
+            MAX_RETURN_DATA
+        );
+        Err(ChallengeError::StateTooLargeForReturnData.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_no_solutions_redeemed(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.solving > 0 {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' had {} solution(s) redeemed already",
+            challenge.id,
+            challenge.solving
+        );
+        Err(ChallengeError::ChallengeHasRedeemedSolutions.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that no challenger was admitted yet since the solved solutions of challengers as
+/// well as first solvers and winners refer to solutions by index.
+#[track_caller]
+pub fn assert_no_challengers_admitted(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.admissions > 0 {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' admitted {} challenger(s) already",
+            challenge.id,
+            challenge.admissions
+        );
+        Err(ChallengeError::ChallengeHasAdmittedChallengers.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that splitting the solutions at [index] leaves at least one solution on each side.
+#[track_caller]
+pub fn assert_valid_solutions_split_index(
+    context: &str,
+    challenge: &Challenge,
+    index: u8,
+) -> ProgramResult {
+    let len = challenge.solutions.len();
+    if index == 0 || index as usize >= len {
+        log_context(context);
+        msg!(
+            "Err: cannot split challenge '{}' with {} solutions at index {}",
+            challenge.id,
+            len,
+            index
+        );
+        Err(ChallengeError::InvalidSolutionsSplitIndex.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_admit_cost_not_locked(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.admit_cost_locked {
+        log_context(context);
+        msg!(
+            "Err: admit cost of challenge '{}' is locked at {}",
+            challenge.id,
+            challenge.admit_cost
+        );
+        Err(ChallengeError::AdmitCostLocked.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_has_oracle(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if !challenge.has_oracle() {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' has no oracle set and does not accept oracle signed solutions",
+            challenge.id
+        );
+        Err(ChallengeError::OracleNotSet.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_solution_index_is_current(
+    context: &str,
+    challenge: &Challenge,
+    solution_index: u8,
+) -> ProgramResult {
+    if solution_index != challenge.solving {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' is solving solution at index {}, not {}",
+            challenge.id,
+            challenge.solving,
+            solution_index
+        );
+        Err(ChallengeError::SolutionIndexNotCurrent.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that [index] refers to an existing solution which was not redeemed yet.
+#[track_caller]
+pub fn assert_valid_solution_index(
+    context: &str,
+    challenge: &Challenge,
+    index: u8,
+) -> ProgramResult {
+    let len = challenge.solutions.len();
+    if index as usize >= len {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' has {} solutions, index {} is out of range",
+            challenge.id,
+            len,
+            index
+        );
+        Err(ChallengeError::InvalidSolutionIndex.into())
+    } else if challenge.is_solved(index) {
+        log_context(context);
+        msg!(
+            "Err: solution {} of challenge '{}' was redeemed already",
+            index,
+            challenge.id
+        );
+        Err(ChallengeError::InvalidSolutionIndex.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_solutions_not_locked(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.solutions_locked {
+        log_context(context);
+        msg!(
+            "Err: solutions of challenge '{}' are locked, unlock them first",
+            challenge.id
+        );
+        Err(ChallengeError::SolutionsLocked.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_can_add_after_start(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.started && !challenge.allows_add_after_start() {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' started and does not allow adding solutions anymore",
+            challenge.id
+        );
+        Err(ChallengeError::AddingSolutionsAfterStartNotAllowed.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Verifies that the existing [challenge] was created with the parameters of the [expected]
+/// one, logging the first parameter that differs.
+#[track_caller]
+pub fn assert_challenge_matches(
+    context: &str,
+    challenge: &Challenge,
+    expected: &Challenge,
+) -> ProgramResult {
+    let mismatch = if challenge.creator() != expected.creator() {
+        Some("creator")
+    } else if challenge.admit_cost != expected.admit_cost {
+        Some("admit_cost")
+    } else if challenge.tries_per_admit != expected.tries_per_admit {
+        Some("tries_per_admit")
+    } else if challenge.redeem != expected.redeem {
+        Some("redeem")
+    } else if challenge.salt != expected.salt {
+        Some("salt")
+    } else if challenge.hash_algorithm != expected.hash_algorithm {
+        Some("hash_algorithm")
+    } else if challenge.starts_at != expected.starts_at
+        || challenge.ends_at != expected.ends_at
+    {
+        Some("schedule")
+    } else if challenge.solutions != expected.solutions {
+        Some("solutions")
+    } else {
+        None
+    };
+    match mismatch {
+        Some(param) => {
+            log_context(context);
+            msg!(
+                "Err: challenge '{}' already exists with a different {}",
+                challenge.id,
+                param
+            );
+            Err(ChallengeError::ChallengeParamsMismatch.into())
+        }
+        None => Ok(()),
+    }
+}
+
+#[track_caller]
+pub fn assert_known_challenge_flags(
+    context: &str,
+    flags: u32,
+) -> ProgramResult {
+    let unknown = flags & !KNOWN_CHALLENGE_FLAGS;
+    if unknown != 0 {
+        log_context(context);
+        msg!("Err: challenge flags {:#b} are not supported", unknown);
+        Err(ChallengeError::UnknownChallengeFlags.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_challenger_has_no_tries_remaining(
+    context: &str,
+    challenger: &Challenger,
+) -> ProgramResult {
+    if challenger.tries_remaining.ne(&0) {
+        log_context(context);
+        msg!(
+            "Err: challenger ({}) still has {} tries left",
+            challenger.authority,
+            challenger.tries_remaining
+        );
+        Err(ChallengeError::ChallengerHasTriesRemaining.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_can_add_tries(
+    context: &str,
+    challenger: &Challenger,
+    tries: u8,
+) -> ProgramResult {
+    if tries == 0 || challenger.tries_remaining.checked_add(tries).is_none() {
+        log_context(context);
+        msg!(
+            "Err: cannot add {} tries to the {} tries challenger ({}) has left",
+            tries,
+            challenger.tries_remaining,
+            challenger.authority
+        );
+        Err(ChallengeError::InvalidTriesCount.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Verifies that granting the challenger [tries] more keeps its total tries within the
+/// [Challenge::max_tries_per_challenger] if the challenge has a cap.
+#[track_caller]
+pub fn assert_within_max_tries_per_challenger(
+    context: &str,
+    challenge: &Challenge,
+    challenger: &Challenger,
+    tries: u8,
+) -> ProgramResult {
+    let max_tries = match challenge.max_tries_per_challenger {
+        Some(max_tries) => max_tries,
+        None => return Ok(()),
+    };
+    let total_tries = challenger.tries_admitted as u32 + tries as u32;
+    if total_tries > max_tries as u32 {
+        log_context(context);
+        msg!(
+            "Err: challenger ({}) would be granted {} tries in total which exceeds the max of {}",
+            challenger.authority,
+            total_tries,
+            max_tries
+        );
+        Err(ChallengeError::MaxTriesPerChallengerExceeded.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_challenger_redeemed(
+    context: &str,
+    challenger: &Challenger,
+) -> ProgramResult {
+    if !challenger.redeemed {
+        log_context(context);
+        msg!(
+            "Err: challenger ({}) has not redeemed yet",
+            challenger.authority
+        );
+        Err(ChallengeError::ChallengerHasNotRedeemed.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that all [indices] refer to existing solutions and that none is repeated.
+#[track_caller]
+pub fn assert_valid_solution_indices_to_remove(
+    context: &str,
+    challenge: &Challenge,
+    indices: &[u8],
+) -> ProgramResult {
+    let len = challenge.solutions.len();
+    if let Some(idx) = indices.iter().find(|idx| **idx as usize >= len) {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' has {} solutions, index {} is out of range",
+            challenge.id,
+            len,
+            idx
+        );
+        return Err(ChallengeError::InvalidSolutionIndex.into());
+    }
+
+    let mut seen = [false; u8::MAX as usize + 1];
+    for idx in indices {
+        if seen[*idx as usize] {
+            log_context(context);
+            msg!("Err: solution index {} is included more than once", idx);
+            return Err(ChallengeError::DuplicateSolutionIndices.into());
+        }
+        seen[*idx as usize] = true;
+    }
+    Ok(())
+}
+
+/// Ensures that the challenge is not started or already finished, since challengers can only
+/// be admitted while it is in progress and thus may still have tries remaining.
+#[track_caller]
+pub fn assert_not_in_progress(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.started && !challenge.finished {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' is in progress and challengers may still have tries remaining",
+            challenge.id
+        );
+        Err(ChallengeError::ChallengeInProgress.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that the challenge has a pending authority transfer and that it is to [new_authority].
+#[track_caller]
+pub fn assert_is_pending_authority(
+    context: &str,
+    challenge: &Challenge,
+    new_authority: &Pubkey,
+) -> ProgramResult {
+    match challenge.pending_authority {
+        None => {
+            log_context(context);
+            msg!(
+                "Err: challenge '{}' has no pending authority transfer",
+                challenge.id
+            );
+            Err(ChallengeError::NoPendingAuthority.into())
+        }
+        Some(pending_authority) => assert_keys_equal(
+            context,
+            new_authority,
+            &pending_authority,
+            ChallengeError::ProvidedAuthorityIncorrect,
+            || {
+                format!(
+                    "Provided new authority ({}) is not the pending authority ({})",
+                    new_authority, pending_authority
+                )
+            },
+        ),
+    }
+}
+
+/// Ensures that the [signer] signed and is either the authority of the challenge or one of
+/// its admins.
+#[track_caller]
+pub fn assert_is_authority_or_admin(
+    context: &str,
+    challenge: &Challenge,
+    signer: &AccountInfo,
+) -> ProgramResult {
+    assert_is_signer_or_delegated(context, signer, "authority or admin")?;
+    if challenge.is_authority_or_admin(signer.key) {
+        Ok(())
+    } else {
+        log_context(context);
+        msg!(
+            "Err: signer ({}) is neither the authority ({}) of challenge '{}' nor one of its admins",
+            signer.key,
+            challenge.authority,
+            challenge.id
+        );
+        Err(ChallengeError::NotAuthorityOrAdmin.into())
+    }
+}
+
+/// Ensures that [admin] can be added to the challenge, i.e. it is not its authority, was not
+/// added yet and the challenge has less than [MAX_CHALLENGE_ADMINS] admins.
+#[track_caller]
+pub fn assert_can_add_admin(
+    context: &str,
+    challenge: &Challenge,
+    admin: &Pubkey,
+) -> ProgramResult {
+    if challenge.authority.eq(admin) {
+        log_context(context);
+        msg!(
+            "Err: admin ({}) is the authority of challenge '{}'",
+            admin,
+            challenge.id
+        );
+        return Err(ChallengeError::AdminIsAuthority.into());
+    }
+    if challenge.admins.contains(admin) {
+        log_context(context);
+        msg!(
+            "Err: admin ({}) was already added to challenge '{}'",
+            admin,
+            challenge.id
+        );
+        return Err(ChallengeError::AdminAlreadyAdded.into());
+    }
+    if challenge.admins.len() >= MAX_CHALLENGE_ADMINS {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' already has the max of {} admins",
+            challenge.id,
+            MAX_CHALLENGE_ADMINS
+        );
+        return Err(ChallengeError::TooManyAdmins.into());
+    }
+    Ok(())
+}
+
+/// Ensures that [admin] is one of the admins of the challenge.
+#[track_caller]
+pub fn assert_is_admin(
+    context: &str,
+    challenge: &Challenge,
+    admin: &Pubkey,
+) -> ProgramResult {
+    if challenge.admins.contains(admin) {
+        Ok(())
+    } else {
+        log_context(context);
+        msg!(
+            "Err: ({}) is not an admin of challenge '{}'",
+            admin,
+            challenge.id
+        );
+        Err(ChallengeError::AdminNotFound.into())
+    }
+}
+
+#[track_caller]
+pub fn assert_valid_tries_per_admit(
+    context: &str,
+    tries_per_admit: u8,
+) -> ProgramResult {
+    if tries_per_admit == 0 {
+        log_context(context);
+        msg!("Err: tries per admit need to be at least one");
+        Err(ChallengeError::InvalidTriesPerAdmit.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that the challenge has an admit mint and returns it.
+#[track_caller]
+pub fn assert_has_admit_mint(
+    context: &str,
+    challenge: &Challenge,
+) -> Result<Pubkey, ProgramError> {
+    match challenge.admit_mint {
+        Some(admit_mint) => Ok(admit_mint),
+        None => {
+            log_context(context);
+            msg!("Err: challenge '{}' has no admit mint", challenge.id);
+            Err(ChallengeError::AdmitMintNotSet.into())
+        }
+    }
+}
+
+#[track_caller]
+pub fn assert_has_prize_pool(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if !challenge.has_prize_pool {
+        log_context(context);
+        msg!("Err: challenge '{}' has no prize pool", challenge.id);
+        Err(ChallengeError::PrizePoolNotSet.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_has_no_prize_pool(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.has_prize_pool {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' has a prize pool, withdraw it first",
+            challenge.id
+        );
+        Err(ChallengeError::PrizePoolNotWithdrawn.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Verifies that [escrow_ata_info] is the escrow of the challenge at [challenge_pda] for its
+/// [admit_mint] and that it holds no tokens. An escrow that was never created is empty.
+#[track_caller]
+pub fn assert_escrow_empty(
+    context: &str,
+    challenge_pda: &Pubkey,
+    admit_mint: &Pubkey,
+    escrow_ata_info: &AccountInfo,
+) -> ProgramResult {
+    let escrow_ata = get_associated_token_address_with_program_id(
+        challenge_pda,
+        admit_mint,
+        &spl_token::id(),
+    );
+    assert_keys_equal(
+        context,
+        escrow_ata_info.key,
+        &escrow_ata,
+        ChallengeError::ProvidedAtaIsIncorrect,
+        || {
+            format!(
+                "Provided escrow ({}) is not the ATA of the challenge ({}) for its admit mint ({})",
+                escrow_ata_info.key, challenge_pda, admit_mint
+            )
+        },
+    )?;
+    if escrow_ata_info.data_is_empty() {
+        return Ok(());
+    }
+    assert_owned_by(context, escrow_ata_info, &spl_token::id(), "escrow")?;
+
+    let amount =
+        spl_token::state::Account::unpack(&escrow_ata_info.try_borrow_data()?)?
+            .amount;
+    if amount > 0 {
+        log_context(context);
+        msg!(
+            "Err: escrow ({}) still holds {} admit tokens, withdraw them first",
+            escrow_ata_info.key,
+            amount
+        );
+        Err(ChallengeError::AdmitTokensNotWithdrawn.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_same_salt(
+    context: &str,
+    challenge: &Challenge,
+    other: &Challenge,
+) -> ProgramResult {
+    if challenge.salt != other.salt {
+        log_context(context);
+        msg!(
+            "Err: challenges '{}' and '{}' hash their solutions with different salts",
+            challenge.id,
+            other.id
+        );
+        Err(ChallengeError::SaltMismatch.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_same_hash_algorithm(
+    context: &str,
+    challenge: &Challenge,
+    other: &Challenge,
+) -> ProgramResult {
+    if challenge.hash_algorithm != other.hash_algorithm {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' hashes its solutions with {:?}, but '{}' uses {:?}",
+            challenge.id,
+            challenge.hash_algorithm,
+            other.id,
+            other.hash_algorithm
+        );
+        Err(ChallengeError::HashAlgorithmMismatch.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_same_solution_normalization(
+    context: &str,
+    challenge: &Challenge,
+    other: &Challenge,
+) -> ProgramResult {
+    if challenge.solution_normalization != other.solution_normalization {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' normalizes its solutions with {:?}, but '{}' uses {:?}",
+            challenge.id,
+            challenge.solution_normalization,
+            other.id,
+            other.solution_normalization
+        );
+        Err(ChallengeError::SolutionNormalizationMismatch.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_commit_reveal_not_required(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.requires_commit_reveal() {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' requires solutions to be committed and revealed",
+            challenge.id
+        );
+        Err(ChallengeError::CommitRevealRequired.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_commitment_matches(
+    context: &str,
+    commitment: &SolutionCommitment,
+    revealed: &[u8; 32],
+) -> ProgramResult {
+    if &commitment.commitment != revealed {
+        log_context(context);
+        msg!("Err: revealed solution and nonce do not match the commitment");
+        Err(ChallengeError::CommitmentMismatch.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_can_reveal(
+    context: &str,
+    commitment: &SolutionCommitment,
+    slot: u64,
+) -> ProgramResult {
+    if !commitment.can_reveal_at(slot) {
+        log_context(context);
+        msg!(
+            "Err: committed at slot {}, cannot reveal before slot {}, current slot is {}",
+            commitment.committed_at_slot,
+            commitment.committed_at_slot.saturating_add(MIN_REVEAL_SLOT_GAP),
+            slot
+        );
+        Err(ChallengeError::RevealTooEarly.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_valid_solutions_chunk(
+    context: &str,
+    chunk_index: u8,
+    total_chunks: u8,
+) -> ProgramResult {
+    if chunk_index >= total_chunks {
+        log_context(context);
+        msg!(
+            "Err: chunk index {} is out of range for {} total chunks",
+            chunk_index,
+            total_chunks
+        );
+        Err(ChallengeError::InvalidSolutionsChunk.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_expected_solutions_chunk(
+    context: &str,
+    upload: &SolutionsUpload,
+    chunk_index: u8,
+    total_chunks: u8,
+) -> ProgramResult {
+    if !upload.expects_chunk(chunk_index, total_chunks) {
+        log_context(context);
+        msg!(
+            "Err: upload expects chunk {} of {}, but got chunk {} of {}",
+            upload.next_chunk,
+            upload.total_chunks,
+            chunk_index,
+            total_chunks
+        );
+        Err(ChallengeError::UnexpectedSolutionsChunk.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_valid_fee_bps(context: &str, fee_bps: u16) -> ProgramResult {
+    if fee_bps > MAX_FEE_BPS {
+        log_context(context);
+        msg!(
+            "Err: fee of {} basis points exceeds the max of {}",
+            fee_bps,
+            MAX_FEE_BPS
+        );
+        Err(ChallengeError::InvalidFeeBps.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_on_allowlist(
+    context: &str,
+    challenge: &Challenge,
+    challenger: &Pubkey,
+    proof: &[[u8; 32]],
+) -> ProgramResult {
+    match challenge.allowlist_root {
+        Some(root) if !verify_allowlist_proof(&root, challenger, proof) => {
+            log_context(context);
+            msg!(
+                "Err: challenger ({}) is not on the allowlist of the challenge",
+                challenger
+            );
+            Err(ChallengeError::NotOnAllowlist.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[track_caller]
+pub fn assert_valid_prerequisite(
+    context: &str,
+    challenge_pda: &Pubkey,
+    prerequisite: Option<&Pubkey>,
+) -> ProgramResult {
+    if prerequisite == Some(challenge_pda) {
+        log_context(context);
+        msg!(
+            "Err: challenge ({}) cannot be its own prerequisite",
+            challenge_pda
+        );
+        Err(ChallengeError::InvalidPrerequisite.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Verifies that the [challenger] record of the [prerequisite] challenge, if it was
+/// admitted to it at all, shows that it redeemed a solution.
+#[track_caller]
+pub fn assert_completed_prerequisite(
+    context: &str,
+    prerequisite: &Pubkey,
+    challenger: &Pubkey,
+    record: Option<&Challenger>,
+) -> ProgramResult {
+    if matches!(record, Some(record) if record.redeemed) {
+        Ok(())
+    } else {
+        log_context(context);
+        msg!(
+            "Err: challenger ({}) did not complete the prerequisite challenge ({})",
+            challenger,
+            prerequisite
+        );
+        Err(ChallengeError::PrerequisiteNotCompleted.into())
+    }
+}
+
+#[track_caller]
+pub fn assert_valid_epochs(
+    context: &str,
+    epoch_slots: u64,
+    solutions_per_epoch: u8,
+) -> ProgramResult {
+    if epoch_slots == 0 && solutions_per_epoch > 0 {
+        log_context(context);
+        msg!(
+            "Err: {} solutions per epoch were provided for a challenge without epochs",
+            solutions_per_epoch
+        );
+        Err(ChallengeError::InvalidEpochs.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Verifies that the solution at [solution_index] belongs to the block of solutions the
+/// current epoch of the challenge accepts, see [Challenge::accepts_in_epoch].
+#[track_caller]
+pub fn assert_solution_in_epoch(
+    context: &str,
+    challenge: &Challenge,
+    solutions_len: usize,
+    solution_index: u8,
+) -> ProgramResult {
+    if challenge.accepts_in_epoch(solution_index, solutions_len) {
+        Ok(())
+    } else {
+        log_context(context);
+        msg!(
+            "Err: solution {} of challenge '{}' is not accepted during epoch {}",
+            solution_index,
+            challenge.id,
+            challenge.epoch
+        );
+        Err(ChallengeError::SolutionNotInCurrentEpoch.into())
+    }
+}
+
+#[track_caller]
+pub fn assert_has_admissions_left(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.remaining_admissions() == Some(0) {
+        log_context(context);
+        msg!(
+            "Err: challenge admitted its max of {} challengers already",
+            challenge.admissions
+        );
+        Err(ChallengeError::NoAdmissionsLeft.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Verifies that challengers can refund their unused tries, either because the challenge
+/// was cancelled or because it allows refunds while it runs, see
+/// [crate::state::ALLOW_REFUNDS].
+#[track_caller]
+pub fn assert_refunds_allowed(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.allows_refunds() {
+        Ok(())
+    } else {
+        assert_cancelled(context, challenge)
+    }
+}
+
+#[track_caller]
+pub fn assert_cancelled(context: &str, challenge: &Challenge) -> ProgramResult {
+    if !challenge.cancelled {
+        log_context(context);
+        msg!("Err: challenge '{}' was not cancelled", challenge.id);
+        Err(ChallengeError::ChallengeNotCancelled.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_not_cancelled(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.cancelled {
+        log_context(context);
+        msg!("Err: challenge '{}' was cancelled", challenge.id);
+        Err(ChallengeError::ChallengeCancelled.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_has_refund(
+    context: &str,
+    challenger: &Challenger,
+    refund: u64,
+) -> ProgramResult {
+    if refund == 0 {
+        log_context(context);
+        msg!(
+            "Err: challenger '{}' has no unused tries to refund",
+            challenger.authority
+        );
+        Err(ChallengeError::NothingToRefund.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_has_refund_escrow(
+    context: &str,
+    challenge: &Challenge,
+) -> ProgramResult {
+    if challenge.admit_mint.is_none() && !challenge.has_prize_pool {
+        log_context(context);
+        msg!(
+            "Err: the admit cost of challenge '{}' was paid to the creator directly",
+            challenge.id
+        );
+        Err(ChallengeError::NoRefundEscrow.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_has_surplus(
+    context: &str,
+    challenge: &Challenge,
+    surplus: u64,
+) -> ProgramResult {
+    if surplus == 0 {
+        log_context(context);
+        msg!(
+            "Err: challenge '{}' holds no lamports beyond rent and its {} sponsored lamports",
+            challenge.id,
+            challenge.sponsored_lamports
+        );
+        Err(ChallengeError::NoSurplusToSweep.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_prize_pool_covers_refund(
+    context: &str,
+    available: u64,
+    refund: u64,
+) -> ProgramResult {
+    if available < refund {
+        log_context(context);
+        msg!(
+            "Err: prize pool holds {} lamports which is not enough to refund {}",
+            available,
+            refund
+        );
+        Err(ChallengeError::InsufficientFunds.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_discriminator(
+    context: &str,
+    data: &[u8],
+    discriminator: &[u8; DISCRIMINATOR_LEN],
+) -> ProgramResult {
+    if data.len() < DISCRIMINATOR_LEN
+        || data[..DISCRIMINATOR_LEN] != discriminator[..]
+    {
+        log_context(context);
+        msg!(
+            "Err: account data does not start with discriminator {:?}",
+            discriminator
+        );
+        Err(ChallengeError::InvalidDiscriminator.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_not_migrated(
+    context: &str,
+    data: &[u8],
+    discriminator: &[u8; DISCRIMINATOR_LEN],
+) -> ProgramResult {
+    if data.starts_with(discriminator) {
+        log_context(context);
+        msg!(
+            "Err: account already starts with discriminator {:?}",
+            discriminator
+        );
+        Err(ChallengeError::AccountAlreadyMigrated.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_current_version(
+    context: &str,
+    version: u8,
+    current_version: u8,
+) -> ProgramResult {
+    if version < current_version {
+        log_context(context);
+        msg!(
+            "Err: account has version {} and needs to be migrated to version {}",
+            version,
+            current_version
+        );
+        Err(ChallengeError::AccountNeedsMigration.into())
+    } else if version > current_version {
+        log_context(context);
+        msg!(
+            "Err: account has version {} but the latest supported version is {}",
+            version,
+            current_version
+        );
+        Err(ChallengeError::UnsupportedAccountVersion.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_outdated_version(
+    context: &str,
+    version: u8,
+    current_version: u8,
+) -> ProgramResult {
+    if version == current_version {
+        log_context(context);
+        msg!("Err: account already has the current version {}", version);
+        Err(ChallengeError::AccountAlreadyMigrated.into())
+    } else if version > current_version {
+        log_context(context);
+        msg!(
+            "Err: account has version {} but the latest supported version is {}",
+            version,
+            current_version
+        );
+        Err(ChallengeError::UnsupportedAccountVersion.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that the [hints] are either empty or one per solution of the challenge.
+#[track_caller]
+pub fn assert_hints_match_solutions(
+    context: &str,
+    challenge: &Challenge,
+    hints: &[Hint],
+) -> ProgramResult {
+    if !hints.is_empty() && hints.len() != challenge.solutions.len() {
+        log_context(context);
+        msg!(
+            "Err: {} hints were provided for {} solutions, need exactly one hint per solution",
+            hints.len(),
+            challenge.solutions.len()
+        );
+        return Err(ChallengeError::HintsDoNotMatchSolutions.into());
+    }
+    Ok(())
+}
+
+/// Ensures that the solution at [solution_index] has a hint that was not revealed to the
+/// challenger yet and returns it.
+#[track_caller]
+pub fn assert_can_reveal_hint<'a>(
+    context: &str,
+    challenge: &'a Challenge,
+    revealed_hints: &RevealedHints,
+    solution_index: u8,
+) -> Result<&'a Hint, ProgramError> {
+    let hint = match challenge.hint_for(solution_index) {
+        Some(hint) => hint,
+        None => {
+            log_context(context);
+            msg!(
+                "Err: solution {} of challenge '{}' has no hint",
+                solution_index,
+                challenge.id
+            );
+            return Err(ChallengeError::HintNotFound.into());
+        }
+    };
+    if revealed_hints.is_revealed(solution_index) {
+        log_context(context);
+        msg!(
+            "Err: hint of solution {} was already revealed to the challenger",
+            solution_index
+        );
+        return Err(ChallengeError::HintAlreadyRevealed.into());
+    }
+    Ok(hint)
+}
+
+/// Ensures that the attempt cooldown of the challenge passed since the last attempt of
+/// the challenger, see [Challenge::next_attempt_slot].
+#[track_caller]
+pub fn assert_attempt_cooldown_passed(
+    context: &str,
+    challenge: &Challenge,
+    challenger: &Challenger,
+    slot: u64,
+) -> ProgramResult {
+    match challenge.next_attempt_slot(challenger) {
+        Some(next_attempt_slot) if slot < next_attempt_slot => {
+            log_context(context);
+            msg!(
+                "Err: challenger ({}) attempted at slot {} and needs to wait until slot {} to attempt again",
+                challenger.authority,
+                challenger.last_attempt_slot,
+                next_attempt_slot
+            );
+            Err(ChallengeError::AttemptTooSoon.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[track_caller]
+pub fn assert_valid_max_winners_per_solution(
+    context: &str,
+    max_winners_per_solution: u8,
+) -> ProgramResult {
+    if !(2..=MAX_WINNERS_PER_SOLUTION).contains(&max_winners_per_solution) {
+        log_context(context);
+        msg!(
+            "Err: max winners per solution ({}) need to be between 2 and {}",
+            max_winners_per_solution,
+            MAX_WINNERS_PER_SOLUTION
+        );
+        Err(ChallengeError::InvalidMaxWinnersPerSolution.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_valid_metadata(
+    context: &str,
+    metadata: &ChallengeMetadata,
+) -> ProgramResult {
+    for (field, len, max_len) in [
+        ("name", metadata.name.len(), MAX_METADATA_NAME_LEN),
+        (
+            "description",
+            metadata.description.len(),
+            MAX_METADATA_DESCRIPTION_LEN,
+        ),
+        ("uri", metadata.uri.len(), MAX_METADATA_URI_LEN),
+    ] {
+        if len > max_len {
+            log_context(context);
+            msg!(
+                "Err: metadata {} is {} bytes long which exceeds the max of {} bytes",
+                field,
+                len,
+                max_len
+            );
+            return Err(ChallengeError::MetadataTooLong.into());
+        }
+    }
+    Ok(())
+}
+
+#[track_caller]
+pub fn assert_creator_registry_not_full(
+    context: &str,
+    registry: &CreatorRegistry,
+) -> ProgramResult {
+    if registry.is_full() {
+        log_context(context);
+        msg!(
+            "Err: creator registry of ({}) already lists the max of {} challenges",
+            registry.creator,
+            MAX_REGISTERED_CHALLENGES
+        );
+        Err(ChallengeError::CreatorRegistryFull.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Verifies that the [instruction] neither created nor burned lamports, i.e. that the
+/// [accounts] passed to it hold the [lamports_before] they held before it was processed.
+/// Only checked with the `debug-lamports` feature since the runtime rejects unbalanced
+/// instructions as well, but without pointing to the instruction at fault.
+#[cfg(feature = "debug-lamports")]
+#[track_caller]
+pub fn assert_lamports_conserved(
+    instruction: &str,
+    accounts: &[AccountInfo],
+    lamports_before: u128,
+) -> ProgramResult {
+    let lamports_after = super::total_lamports(accounts);
+    if lamports_after != lamports_before {
+        log_context(instruction);
+        msg!(
+            "Err: accounts held {} lamports before and {} lamports after the instruction",
+            lamports_before,
+            lamports_after
+        );
+        Err(ChallengeError::LamportsNotConserved.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Solana only allows growing an account by [MAX_PERMITTED_DATA_INCREASE] bytes per
+/// instruction, exceeding it fails the transaction with an opaque runtime error.
+#[track_caller]
+pub fn assert_realloc_within_limit(
+    context: &str,
+    size_increase: usize,
+) -> ProgramResult {
+    if size_increase > MAX_PERMITTED_DATA_INCREASE {
+        log_context(context);
+        msg!(
+            "Err: growing the account by {} bytes exceeds the max of {} bytes per instruction",
+            size_increase,
+            MAX_PERMITTED_DATA_INCREASE
+        );
+        Err(ChallengeError::ReallocTooLarge.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Verifies that the leading required accounts of the instruction are provided at the
+/// index the [specs] expect them and are signers and writable as needed, such that a
+/// misconfigured instruction fails upfront naming the offending account.
+/// Accounts from the first optional one onwards shift depending on which optional accounts
+/// are provided, the processor verifies those once it uses them.
+/// The [instruction] name is logged in snake case to match the context the processor
+/// logs for the same instruction.
+#[track_caller]
+pub fn assert_account_metas(
+    instruction: &str,
+    accounts: &[AccountInfo],
+    specs: &[AccountSpec],
+) -> ProgramResult {
+    let context = || snake_case(instruction);
+    let fixed_specs = specs.iter().take_while(|spec| !spec.optional);
+    for (idx, spec) in fixed_specs.enumerate() {
+        let account = match accounts.get(idx) {
+            Some(account) => account,
+            None => {
+                log_context(&context());
+                msg!(
+                    "Err: account '{}' at index {} is missing, {} accounts were provided",
+                    spec.name,
+                    idx,
+                    accounts.len()
+                );
+                return Err(ProgramError::NotEnoughAccountKeys);
+            }
+        };
+        if spec.signer && !account.is_signer {
+            log_context(&context());
+            msg!(
+                "Err: account '{}' ({}) at index {} should be signer",
+                spec.name,
+                account.key,
+                idx
+            );
+            return Err(ChallengeError::AccountShouldBeSigner.into());
+        }
+        if spec.writable && !account.is_writable {
+            log_context(&context());
+            msg!(
+                "Err: account '{}' ({}) at index {} should be writable",
+                spec.name,
+                account.key,
+                idx
+            );
+            return Err(ChallengeError::AccountShouldBeWritable.into());
+        }
+    }
+    Ok(())
+}
+
+/// Converts an instruction or account name like `AddSolutions` to `add_solutions`.
+pub fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (idx, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && idx > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
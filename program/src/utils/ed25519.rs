@@ -0,0 +1,111 @@
+use solana_program::{
+    account_info::AccountInfo,
+    ed25519_program,
+    instruction::Instruction,
+    msg,
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+    sysvar::instructions::{
+        load_current_index_checked, load_instruction_at_checked,
+    },
+};
+
+use crate::error::ChallengeError;
+
+use super::{assert_keys_equal, asserts::log_context};
+
+pub const SIGNATURE_BYTES: usize = 64;
+
+// Layout of the Ed25519 program instruction data, see solana_sdk::ed25519_instruction
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+const DATA_START: usize =
+    SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+const PUBKEY_OFFSET: usize = DATA_START;
+const SIGNATURE_OFFSET: usize = PUBKEY_OFFSET + PUBKEY_BYTES;
+const MESSAGE_OFFSET: usize = SIGNATURE_OFFSET + SIGNATURE_BYTES;
+
+/// Instruction index indicating that the data is found in the Ed25519 instruction itself.
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Creates an Ed25519 program instruction which verifies that [signature] was created
+/// by [signer] signing [message].
+/// Signing happens off-chain and thus no keypair is needed here.
+pub fn ed25519_verify_instruction(
+    signer: &Pubkey,
+    signature: &[u8; SIGNATURE_BYTES],
+    message: &[u8],
+) -> Instruction {
+    let offsets: [u16; 7] = [
+        SIGNATURE_OFFSET as u16,
+        CURRENT_INSTRUCTION,
+        PUBKEY_OFFSET as u16,
+        CURRENT_INSTRUCTION,
+        MESSAGE_OFFSET as u16,
+        message.len() as u16,
+        CURRENT_INSTRUCTION,
+    ];
+
+    let mut data = Vec::with_capacity(MESSAGE_OFFSET + message.len());
+    // number of signatures followed by one byte of padding
+    data.extend_from_slice(&[1, 0]);
+    for offset in offsets {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Ensures that the instruction preceding the currently executing one is an Ed25519 program
+/// instruction verifying exactly the given [signature] of [signer] over [message].
+/// The Ed25519 program fails the transaction if the signature is invalid, thus if it is
+/// present the signature is known to be valid.
+#[track_caller]
+pub(crate) fn assert_ed25519_signature_verified(
+    context: &str,
+    instructions_sysvar_info: &AccountInfo,
+    signer: &Pubkey,
+    signature: &[u8; SIGNATURE_BYTES],
+    message: &[u8],
+) -> Result<(), ProgramError> {
+    let current_index = load_current_index_checked(instructions_sysvar_info)?;
+    if current_index == 0 {
+        log_context(context);
+        msg!("Err: no Ed25519 instruction precedes this instruction");
+        return Err(ChallengeError::InvalidOracleSignature.into());
+    }
+    let ix = load_instruction_at_checked(
+        current_index as usize - 1,
+        instructions_sysvar_info,
+    )?;
+
+    assert_keys_equal(
+        context,
+        &ix.program_id,
+        &ed25519_program::id(),
+        ChallengeError::ProvidedProgramIncorrect,
+        || {
+            "Instruction preceding this instruction is not an Ed25519 program instruction"
+            .to_string()
+        },
+    )?;
+
+    let expected = ed25519_verify_instruction(signer, signature, message);
+    if ix.data.ne(&expected.data) {
+        log_context(context);
+        msg!(
+            "Err: Ed25519 instruction does not verify the expected signature of {}",
+            signer
+        );
+        Err(ChallengeError::InvalidOracleSignature.into())
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use thiserror::Error;
+
+use crate::Solution;
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum HexError {
+    #[error("Hex string has odd length {0}")]
+    OddLength(usize),
+
+    #[error("Invalid hex character '{0}' at index {1}")]
+    InvalidCharacter(char, usize),
+
+    #[error("Expected {expected} bytes but hex string encodes {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+}
+
+/// Encodes the bytes as lowercase hex string.
+pub fn encode_hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        s.push(HEX_CHARS[(b >> 4) as usize] as char);
+        s.push(HEX_CHARS[(b & 0x0f) as usize] as char);
+    }
+    s
+}
+
+/// Decodes a hex string, upper and lowercase characters are accepted.
+pub fn decode_hex(s: &str) -> Result<Vec<u8>, HexError> {
+    let pairs = s.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(HexError::OddLength(s.len()));
+    }
+
+    fn nibble(c: u8, idx: usize) -> Result<u8, HexError> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(HexError::InvalidCharacter(c as char, idx)),
+        }
+    }
+
+    pairs
+        .enumerate()
+        .map(|(i, pair)| {
+            Ok(nibble(pair[0], i * 2)? << 4 | nibble(pair[1], i * 2 + 1)?)
+        })
+        .collect()
+}
+
+/// Encodes the solution as it is stored in the challenge as hex string.
+pub fn solution_to_hex(solution: &Solution) -> String {
+    encode_hex(solution)
+}
+
+/// Decodes a hex string into a solution, failing if it does not encode exactly 32 bytes.
+pub fn solution_from_hex(s: &str) -> Result<Solution, HexError> {
+    let bytes = decode_hex(s)?;
+    let len = bytes.len();
+    bytes.try_into().map_err(|_| HexError::InvalidLength {
+        expected: std::mem::size_of::<Solution>(),
+        actual: len,
+    })
+}
@@ -0,0 +1,77 @@
+use solana_program::{hash::hashv, pubkey::Pubkey};
+
+// Leaves and inner nodes are hashed with different prefixes so that an inner node can never
+// be passed off as a leaf.
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// The leaf representing the [pubkey] in an allowlist tree.
+pub fn allowlist_leaf(pubkey: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, pubkey.as_ref()]).to_bytes()
+}
+
+/// Hashes two nodes in sorted order which allows verifying proofs without knowing on which
+/// side each sibling is.
+fn hash_nodes(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (lhs, rhs) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, lhs, rhs]).to_bytes()
+}
+
+/// Hashes each pair of nodes into the next level, carrying an odd node up as is.
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [a, b] => hash_nodes(a, b),
+            [a] => *a,
+            _ => unreachable!("chunks of two"),
+        })
+        .collect()
+}
+
+/// Builds the root of the allowlist tree for the [pubkeys] which is stored with the
+/// challenge, see [crate::state::Challenge::allowlist_root].
+/// Returns the default root for an empty list which no proof verifies against.
+pub fn allowlist_root(pubkeys: &[Pubkey]) -> [u8; 32] {
+    let mut level: Vec<[u8; 32]> = pubkeys.iter().map(allowlist_leaf).collect();
+    if level.is_empty() {
+        return [0; 32];
+    }
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+/// Builds the proof that the [pubkey] is part of the allowlist tree for the [pubkeys].
+/// Returns `None` if the [pubkey] is not in the list.
+pub fn allowlist_proof(
+    pubkeys: &[Pubkey],
+    pubkey: &Pubkey,
+) -> Option<Vec<[u8; 32]>> {
+    let mut idx = pubkeys.iter().position(|x| x == pubkey)?;
+    let mut level: Vec<[u8; 32]> = pubkeys.iter().map(allowlist_leaf).collect();
+    let mut proof = vec![];
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(idx ^ 1) {
+            proof.push(*sibling);
+        }
+        level = next_level(&level);
+        idx /= 2;
+    }
+    Some(proof)
+}
+
+/// Returns `true` if the [proof] shows that the [pubkey] is part of the allowlist tree
+/// with the given [root].
+pub fn verify_allowlist_proof(
+    root: &[u8; 32],
+    pubkey: &Pubkey,
+    proof: &[[u8; 32]],
+) -> bool {
+    let computed =
+        proof.iter().fold(allowlist_leaf(pubkey), |node, sibling| {
+            hash_nodes(&node, sibling)
+        });
+    &computed == root
+}
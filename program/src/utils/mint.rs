@@ -3,16 +3,26 @@ use solana_program::{
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
+    program_option::COption,
     program_pack::Pack,
+    pubkey::Pubkey,
 };
 use spl_associated_token_account::{
     get_associated_token_address_with_program_id,
     instruction::create_associated_token_account_idempotent,
 };
-use spl_token::instruction::{initialize_mint2, mint_to};
+use spl_token::{
+    instruction::{
+        initialize_mint2, mint_to, set_authority, transfer, AuthorityType,
+    },
+    state::{Account, Mint},
+};
+
+use crate::{error::ChallengeError, state::Ticket};
 
 use super::{
     allocate_account_and_assign_owner, assert_keys_equal,
+    assert_spl_token_program, asserts::log_context,
     AllocateAndAssignAccountArgs,
 };
 
@@ -67,12 +77,10 @@ pub fn create_mint(args: CreateMintArgs) -> Result<(), ProgramError> {
         signer_seeds,
     } = args;
 
-    assert_keys_equal(spl_token_program_info.key, &spl_token::id(), || {
-        format!(
-            "'{}' does not match the spl_token program id",
-            spl_token_program_info.key
-        )
-    })?;
+    assert_spl_token_program(
+        "create_mint:token_program",
+        spl_token_program_info,
+    )?;
 
     allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
         payer_info,
@@ -89,6 +97,43 @@ pub fn create_mint(args: CreateMintArgs) -> Result<(), ProgramError> {
     })
 }
 
+struct CreateAtaArgs<'a> {
+    pub payer_info: &'a AccountInfo<'a>,
+    pub owner_info: &'a AccountInfo<'a>,
+    pub ata_info: &'a AccountInfo<'a>,
+    pub mint_info: &'a AccountInfo<'a>,
+    pub spl_token_program_info: &'a AccountInfo<'a>,
+}
+
+fn create_ata_idempotent(args: CreateAtaArgs) -> Result<(), ProgramError> {
+    let CreateAtaArgs {
+        payer_info,
+        owner_info,
+        ata_info,
+        mint_info,
+        spl_token_program_info,
+    } = args;
+
+    invoke(
+        &create_associated_token_account_idempotent(
+            payer_info.key, // payer
+            owner_info.key, // owner
+            mint_info.key,  // mint
+            spl_token_program_info.key,
+        ),
+        // 0. `[writeable,signer]` Funding account (must be a system account)
+        // 1. `[writeable]` Associated token account address to be created
+        // 2. `[]` Wallet address for the new associated token account
+        // 3. `[]` The token mint for the new associated token account
+        &[
+            payer_info.clone(),
+            ata_info.clone(),
+            owner_info.clone(),
+            mint_info.clone(),
+        ],
+    )
+}
+
 pub struct MintTokenArgs<'a, 'b> {
     pub payer_info: &'a AccountInfo<'a>,
     pub recvr_info: &'a AccountInfo<'a>,
@@ -115,32 +160,27 @@ pub fn mint_token_to_recvr(args: MintTokenArgs) -> Result<(), ProgramError> {
         mint_info.key,
         spl_token_program_info.key,
     );
-    assert_keys_equal(recvr_ata_info.key, &ata, || {
-        format!(
-            "The provided recvr ATA ('{}') does not match ('{}')",
-            recvr_ata_info.key, ata
-        )
-    })?;
+    assert_keys_equal(
+        "mint_token_to_recvr:recvr_ata",
+        recvr_ata_info.key,
+        &ata,
+        ChallengeError::ProvidedAtaIsIncorrect,
+        || {
+            format!(
+                "The provided recvr ATA ('{}') does not match ('{}')",
+                recvr_ata_info.key, ata
+            )
+        },
+    )?;
 
     msg!("mint_token_to_recvr() Creating ATA",);
-    invoke(
-        &create_associated_token_account_idempotent(
-            payer_info.key, // payer
-            recvr_info.key, // recvr
-            mint_info.key,  // mint
-            spl_token_program_info.key,
-        ),
-        // 0. `[writeable,signer]` Funding account (must be a system account)
-        // 1. `[writeable]` Associated token account address to be created
-        // 2. `[]` Wallet address for the new associated token account (same as funding)
-        // 3. `[]` The token mint for the new associated token account
-        &[
-            payer_info.clone(),
-            recvr_ata_info.clone(),
-            recvr_info.clone(),
-            mint_info.clone(),
-        ],
-    )?;
+    create_ata_idempotent(CreateAtaArgs {
+        payer_info,
+        owner_info: recvr_info,
+        ata_info: recvr_ata_info,
+        mint_info,
+        spl_token_program_info,
+    })?;
 
     invoke_signed(
         &mint_to(
@@ -164,3 +204,219 @@ pub fn mint_token_to_recvr(args: MintTokenArgs) -> Result<(), ProgramError> {
 
     Ok(())
 }
+
+pub struct RemoveMintAuthorityArgs<'a, 'b> {
+    pub mint_info: &'a AccountInfo<'a>,
+    pub mint_authority_info: &'a AccountInfo<'a>,
+    pub spl_token_program_info: &'a AccountInfo<'a>,
+    pub signer_seeds: &'b [&'b [u8]],
+}
+
+/// Removes the mint authority of the mint which fixes its supply, i.e. after minting a single
+/// token this turns the mint into an NFT.
+pub fn remove_mint_authority(
+    args: RemoveMintAuthorityArgs,
+) -> Result<(), ProgramError> {
+    msg!("  remove_mint_authority()");
+
+    let RemoveMintAuthorityArgs {
+        mint_info,
+        mint_authority_info,
+        spl_token_program_info,
+        signer_seeds,
+    } = args;
+
+    invoke_signed(
+        &set_authority(
+            spl_token_program_info.key,
+            mint_info.key,
+            None,
+            AuthorityType::MintTokens,
+            mint_authority_info.key,
+            &[mint_authority_info.key],
+        )?,
+        // 0. `[writable]` The mint or account to change the authority of.
+        // 1. `[signer]` The current authority of the mint or account.
+        &[mint_info.clone(), mint_authority_info.clone()],
+        &[signer_seeds],
+    )
+}
+
+pub struct TransferTokensArgs<'a, 'b> {
+    pub payer_info: &'a AccountInfo<'a>,
+    pub source_owner_info: &'a AccountInfo<'a>,
+    pub source_ata_info: &'a AccountInfo<'a>,
+    pub recvr_info: &'a AccountInfo<'a>,
+    pub recvr_ata_info: &'a AccountInfo<'a>,
+    pub mint_info: &'a AccountInfo<'a>,
+    pub spl_token_program_info: &'a AccountInfo<'a>,
+    pub amount: u64,
+    /// Seeds of the source owner if it is a PDA, otherwise it needs to sign the transaction
+    pub signer_seeds: Option<&'b [&'b [u8]]>,
+}
+
+/// Transfers [amount] tokens from the source owner's ATA to the ATA of the receiver,
+/// creating the latter if needed.
+pub fn transfer_tokens_to_recvr(
+    args: TransferTokensArgs,
+) -> Result<(), ProgramError> {
+    msg!("  transfer_tokens_to_recvr()");
+
+    let TransferTokensArgs {
+        payer_info,
+        source_owner_info,
+        source_ata_info,
+        recvr_info,
+        recvr_ata_info,
+        mint_info,
+        spl_token_program_info,
+        amount,
+        signer_seeds,
+    } = args;
+
+    for (context, owner, ata) in [
+        (
+            "transfer_tokens_to_recvr:source_ata",
+            source_owner_info.key,
+            source_ata_info.key,
+        ),
+        (
+            "transfer_tokens_to_recvr:recvr_ata",
+            recvr_info.key,
+            recvr_ata_info.key,
+        ),
+    ] {
+        let expected_ata = get_associated_token_address_with_program_id(
+            owner,
+            mint_info.key,
+            spl_token_program_info.key,
+        );
+        assert_keys_equal(
+            context,
+            ata,
+            &expected_ata,
+            ChallengeError::ProvidedAtaIsIncorrect,
+            || {
+                format!(
+                    "The provided ATA ('{}') does not match ('{}')",
+                    ata, expected_ata
+                )
+            },
+        )?;
+    }
+
+    create_ata_idempotent(CreateAtaArgs {
+        payer_info,
+        owner_info: recvr_info,
+        ata_info: recvr_ata_info,
+        mint_info,
+        spl_token_program_info,
+    })?;
+
+    let ix = transfer(
+        spl_token_program_info.key,
+        source_ata_info.key,
+        recvr_ata_info.key,
+        source_owner_info.key,
+        &[source_owner_info.key],
+        amount,
+    )?;
+    // 0. `[writable]` The source account.
+    // 1. `[writable]` The destination account.
+    // 2. `[signer]` The source account's owner/delegate.
+    let account_infos = [
+        source_ata_info.clone(),
+        recvr_ata_info.clone(),
+        source_owner_info.clone(),
+    ];
+    match signer_seeds {
+        Some(signer_seeds) => {
+            invoke_signed(&ix, &account_infos, &[signer_seeds])
+        }
+        None => invoke(&ix, &account_infos),
+    }
+}
+
+/// Ensures that [mint_info] is an initialized spl token mint and returns it.
+#[track_caller]
+pub fn assert_is_mint(
+    context: &str,
+    mint_info: &AccountInfo,
+) -> Result<Mint, ProgramError> {
+    if mint_info.owner.ne(&spl_token::id()) {
+        log_context(context);
+        msg!(
+            "Err: mint ({}) is not owned by the spl_token program",
+            mint_info.key
+        );
+        return Err(ChallengeError::InvalidAdmitMint.into());
+    }
+    Mint::unpack(&mint_info.try_borrow_data()?)
+}
+
+/// Ensures that [mint_info] is an initialized spl token mint whose mint authority is
+/// [mint_authority], i.e. that the program can mint tokens of it to challengers.
+#[track_caller]
+pub fn assert_mint_with_authority(
+    context: &str,
+    mint_info: &AccountInfo,
+    mint_authority: &Pubkey,
+) -> Result<(), ProgramError> {
+    if mint_info.owner.ne(&spl_token::id()) {
+        log_context(context);
+        msg!(
+            "Err: mint ({}) is not owned by the spl_token program",
+            mint_info.key
+        );
+        return Err(ChallengeError::InvalidRedeemMint.into());
+    }
+
+    let mint = Mint::unpack(&mint_info.try_borrow_data()?)?;
+    if mint.mint_authority.ne(&COption::Some(*mint_authority)) {
+        log_context(context);
+        msg!(
+            "Err: mint authority of mint ({}) is not ({})",
+            mint_info.key,
+            mint_authority
+        );
+        return Err(ChallengeError::InvalidRedeemMint.into());
+    }
+    Ok(())
+}
+
+/// Ensures that [holder] holds the ticket minted when the challenger with [challenger_pda]
+/// was admitted, i.e. that [ticket_ata_info] is a token account of the ticket mint owned by
+/// [holder] which holds the ticket.
+#[track_caller]
+pub fn assert_holds_ticket(
+    context: &str,
+    ticket_ata_info: &AccountInfo,
+    holder: &Pubkey,
+    challenger_pda: &Pubkey,
+) -> Result<(), ProgramError> {
+    if ticket_ata_info.owner.ne(&spl_token::id()) {
+        log_context(context);
+        msg!(
+            "Err: ticket account ({}) is not owned by the spl_token program",
+            ticket_ata_info.key
+        );
+        return Err(ChallengeError::TicketNotHeld.into());
+    }
+
+    let ticket = Ticket::new(*challenger_pda);
+    let account = Account::unpack(&ticket_ata_info.try_borrow_data()?)?;
+    if account.mint.ne(&ticket.pda)
+        || account.owner.ne(holder)
+        || account.amount == 0
+    {
+        log_context(context);
+        msg!(
+            "Err: ({}) does not hold the ticket ({}) of challenger ({})",
+            holder,
+            ticket.pda,
+            challenger_pda
+        );
+        return Err(ChallengeError::TicketNotHeld.into());
+    }
+    Ok(())
+}
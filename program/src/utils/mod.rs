@@ -1,10 +1,29 @@
+// Most account and assert helpers are only used by the processor which is not part of
+// offchain builds.
+#[cfg_attr(feature = "offchain", allow(dead_code))]
 mod accounts;
+#[cfg_attr(feature = "offchain", allow(dead_code))]
 mod asserts;
+#[cfg_attr(feature = "offchain", allow(dead_code))]
+mod ed25519;
+mod hex;
+mod merkle;
+#[cfg(not(feature = "offchain"))]
 mod mint;
+mod normalize;
 mod solutions;
+mod weak_solutions;
 
+pub use accounts::rent_exempt_top_up;
+#[cfg(not(feature = "offchain"))]
 pub(crate) use accounts::*;
 pub(crate) use asserts::*;
+pub use ed25519::*;
+pub use hex::*;
+pub use merkle::*;
+#[cfg(not(feature = "offchain"))]
 pub(crate) use mint::*;
+pub use normalize::*;
 
 pub use solutions::*;
+pub use weak_solutions::*;
@@ -0,0 +1,68 @@
+use std::borrow::Cow;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::state::SolutionNormalization;
+
+/// Normalizes the [solution] in clear text according to the [normalization] of the
+/// challenge before it is hashed.
+/// The rules are applied in this order: NFKC, lowercase, collapse whitespace and trim,
+/// such that characters composed into whitespace or uppercase are normalized as well.
+/// Returns the solution unchanged if no rule applies.
+pub fn normalize_solution(
+    solution: &str,
+    normalization: SolutionNormalization,
+) -> Cow<'_, str> {
+    let SolutionNormalization {
+        trim,
+        lowercase,
+        nfkc,
+        collapse_whitespace,
+    } = normalization;
+
+    let mut normalized = Cow::Borrowed(solution);
+    if nfkc {
+        normalized = Cow::Owned(normalized.nfkc().collect());
+    }
+    if lowercase {
+        normalized = Cow::Owned(normalized.to_lowercase());
+    }
+    if collapse_whitespace {
+        normalized = Cow::Owned(collapse_whitespace_runs(&normalized));
+    }
+    if trim {
+        normalized = match normalized {
+            Cow::Borrowed(s) => Cow::Borrowed(s.trim()),
+            Cow::Owned(s) => Cow::Owned(s.trim().to_string()),
+        };
+    }
+    normalized
+}
+
+/// Normalizes each of the [solutions], see [normalize_solution].
+pub fn normalize_solutions<'a>(
+    solutions: &[&'a str],
+    normalization: SolutionNormalization,
+) -> Vec<Cow<'a, str>> {
+    solutions
+        .iter()
+        .map(|s| normalize_solution(s, normalization))
+        .collect()
+}
+
+fn collapse_whitespace_runs(s: &str) -> String {
+    let mut collapsed = String::with_capacity(s.len());
+    let mut in_whitespace = false;
+    for c in s.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                collapsed.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            collapsed.push(c);
+            in_whitespace = false;
+        }
+    }
+    collapsed
+}
@@ -1,18 +1,86 @@
-use solana_program::hash::hash;
+use solana_program::{hash::hashv, pubkey::Pubkey};
 
-use crate::Solution;
+use crate::{state::HashAlgorithm, Solution};
 
 pub fn hash_solution_challenger_sends(s: &str) -> [u8; 32] {
-    hash(s.as_bytes()).to_bytes()
+    hash_solution_challenger_sends_with(s, HashAlgorithm::Sha256)
+}
+
+/// The solution as the challenger sends it to a challenge using the given [algorithm].
+pub fn hash_solution_challenger_sends_with(
+    s: &str,
+    algorithm: HashAlgorithm,
+) -> [u8; 32] {
+    algorithm.hashv(&[s.as_bytes()])
+}
+
+/// Parses solutions in clear text from a file with one solution per line.
+/// Surrounding whitespace is trimmed and empty lines are skipped.
+pub fn solutions_from_lines(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
 }
 
 pub fn hash_solutions(solutions: &[&str]) -> Vec<Solution> {
+    hash_solutions_with_salt(solutions, None)
+}
+
+/// Hashes the solutions the way they are stored for a challenge with the given [salt],
+/// see [solution_stored_as].
+pub fn hash_solutions_with_salt(
+    solutions: &[&str],
+    salt: Option<&[u8; 32]>,
+) -> Vec<Solution> {
+    hash_solutions_with_algorithm(solutions, salt, HashAlgorithm::Sha256)
+}
+
+/// Hashes the solutions the way they are stored for a challenge with the given [salt] and
+/// [algorithm], see [solution_stored_as].
+pub fn hash_solutions_with_algorithm(
+    solutions: &[&str],
+    salt: Option<&[u8; 32]>,
+    algorithm: HashAlgorithm,
+) -> Vec<Solution> {
     solutions
         .iter()
         .map(|s| {
-            let challenger_sends = hash_solution_challenger_sends(s);
+            let challenger_sends =
+                hash_solution_challenger_sends_with(s, algorithm);
             // program stores
-            hash(&challenger_sends).to_bytes()
+            solution_stored_as(&challenger_sends, salt, algorithm)
         })
         .collect::<Vec<Solution>>()
 }
+
+/// The hash the program stores for the solution a challenger sends.
+/// Challenges created without a salt store `H(challenger_sends)` while salted
+/// challenges store `H(salt || challenger_sends)` which prevents precomputed
+/// solution hashes from being reused across challenges.
+/// `H` is the [algorithm] of the challenge.
+pub fn solution_stored_as(
+    challenger_sends: &[u8; 32],
+    salt: Option<&[u8; 32]>,
+    algorithm: HashAlgorithm,
+) -> Solution {
+    match salt {
+        Some(salt) => algorithm.hashv(&[salt, challenger_sends]),
+        None => algorithm.hashv(&[challenger_sends]),
+    }
+}
+
+/// Commitment a challenger submits via `CommitSolution` before revealing the solution.
+/// It binds the solution to the challenger such that it cannot be reused by others and
+/// the [nonce] prevents the solution from being guessed from the commitment.
+///
+/// * [challenger]: the challenger committing to the solution
+/// * [challenger_sends]: the solution as sent when redeeming, see [hash_solution_challenger_sends]
+/// * [nonce]: secret chosen by the challenger, revealed together with the solution
+pub fn solution_commitment(
+    challenger: &Pubkey,
+    challenger_sends: &[u8; 32],
+    nonce: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[challenger.as_ref(), challenger_sends, nonce]).to_bytes()
+}
@@ -0,0 +1,36 @@
+use crate::{state::HashAlgorithm, Solution};
+
+use super::{hash_solution_challenger_sends_with, solution_stored_as};
+
+// Generated by build.rs from weak_solutions.txt, providing WEAK_SOLUTIONS and their hashes
+// as challengers send them to Sha256 challenges in WEAK_SOLUTION_HASHES.
+include!(concat!(env!("OUT_DIR"), "/weak_solutions.rs"));
+
+/// The most common passwords and answers in clear text, trimmed and lowercased.
+/// Challenges that reject weak solutions don't accept solutions matching any of them, see
+/// [crate::state::Challenge::reject_weak_solutions].
+pub fn weak_solutions() -> &'static [&'static str] {
+    &WEAK_SOLUTIONS
+}
+
+/// The [weak_solutions] hashed the way they are stored for a challenge with the given
+/// [salt] and [algorithm], see [solution_stored_as].
+/// The hashes challengers send to Sha256 challenges are computed at build time.
+pub fn weak_solutions_stored_as(
+    salt: Option<&[u8; 32]>,
+    algorithm: HashAlgorithm,
+) -> Vec<Solution> {
+    let stored_as = |challenger_sends| {
+        solution_stored_as(&challenger_sends, salt, algorithm)
+    };
+    match algorithm {
+        HashAlgorithm::Sha256 => {
+            WEAK_SOLUTION_HASHES.into_iter().map(stored_as).collect()
+        }
+        _ => WEAK_SOLUTIONS
+            .iter()
+            .map(|s| hash_solution_challenger_sends_with(s, algorithm))
+            .map(stored_as)
+            .collect(),
+    }
+}
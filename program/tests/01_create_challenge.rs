@@ -53,6 +53,8 @@ async fn create_challenge_without_solutions() {
         1000,
         1,
         vec![],
+        None,
+        None,
     )
     .expect("failed to create instruction");
 
@@ -87,6 +89,7 @@ async fn create_challenge_without_solutions() {
                 redeem: r,
                 solving: 0,
                 solutions,
+                ..
             } => {
                 assert_eq!(&authority, &creator);
                 assert_eq!(id, ID);
@@ -111,6 +114,8 @@ async fn create_challenge_with_two_solutions() {
         1000,
         1,
         vec!["hello", "world"],
+        None,
+        None,
     )
     .expect("failed to create instruction");
 
@@ -145,6 +150,7 @@ async fn create_challenge_with_two_solutions() {
             redeem: r,
             solving: 0,
             solutions,
+            ..
         } => {
             assert_eq!(&authority, &creator);
             assert_eq!(id, ID);
@@ -175,6 +181,8 @@ async fn create_two_challenges_same_creator_different_id() {
             1000,
             1,
             vec!["hello", "world"],
+            None,
+            None,
         )
         .expect("failed to create instruction");
 
@@ -201,6 +209,8 @@ async fn create_two_challenges_same_creator_different_id() {
             2000,
             2,
             vec!["hola", "mundo"],
+            None,
+            None,
         )
         .expect("failed to create instruction");
 
@@ -237,6 +247,7 @@ async fn create_two_challenges_same_creator_different_id() {
                 redeem: r,
                 solving: 0,
                 solutions,
+                ..
             } => {
                 assert_eq!(&authority, &creator);
                 assert_eq!(id, fst_id);
@@ -269,6 +280,7 @@ async fn create_two_challenges_same_creator_different_id() {
                 redeem: r,
                 solving: 0,
                 solutions,
+                ..
             } => {
                 assert_eq!(&authority, &creator);
                 assert_eq!(id, snd_id);
@@ -335,6 +347,8 @@ async fn create_two_challenges_same_creator_same_id() {
             1000,
             1,
             vec!["hello", "world"],
+            None,
+            None,
         )
         .expect("failed to create instruction");
 
@@ -361,6 +375,8 @@ async fn create_two_challenges_same_creator_same_id() {
             2000,
             2,
             vec!["hola", "mundo"],
+            None,
+            None,
         )
         .expect("failed to create instruction");
 
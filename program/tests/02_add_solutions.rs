@@ -72,6 +72,7 @@ async fn add_solutions_creator_pays_to_empty_solutions() {
             redeem: _,
             solving: 0,
             solutions,
+            ..
         } => {
             assert_eq!(&authority, &creator);
             assert_eq!(id, ID);
@@ -137,6 +138,7 @@ async fn add_solutions_creator_not_payer_to_empty_solutions() {
             redeem: _,
             solving: 0,
             solutions,
+            ..
         } => {
             assert_eq!(&authority, &creator.pubkey());
             assert_eq!(id, ID);
@@ -200,6 +202,7 @@ async fn add_solutions_creator_pays_to_two_solutions() {
             redeem: _,
             solving: 0,
             solutions,
+            ..
         } => {
             assert_eq!(&authority, &creator);
             assert_eq!(id, ID);
@@ -301,6 +304,8 @@ async fn add_solutions_with_invalid_creator() {
             data: ChallengeInstruction::AddSolutions {
                 id: ID.to_string(),
                 solutions,
+                allow_duplicates: false,
+                rewards: vec![],
             }
             .try_to_vec()
             .expect("failed to create custom instruction"),
@@ -346,6 +351,8 @@ async fn add_solutions_with_creator_not_signer() {
             data: ChallengeInstruction::AddSolutions {
                 id: ID.to_string(),
                 solutions,
+                allow_duplicates: false,
+                rewards: vec![],
             }
             .try_to_vec()
             .expect("failed to create custom instruction"),
@@ -390,6 +397,8 @@ async fn add_solutions_creator_matches_but_id_is_different() {
             data: ChallengeInstruction::AddSolutions {
                 id: "other_id".to_string(),
                 solutions,
+                allow_duplicates: false,
+                rewards: vec![],
             }
             .try_to_vec()
             .expect("failed to create custom instruction"),
@@ -409,3 +418,65 @@ async fn add_solutions_creator_matches_but_id_is_different() {
         .await
         .expect("Failed add solutions");
 }
+
+#[tokio::test]
+async fn add_solutions_with_creator_not_signer_logs_context() {
+    let mut context = program_test().start_with_context().await;
+    let creator_pair = Keypair::new();
+    let creator = creator_pair.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec![], Some(creator));
+
+    let solutions = vec!["hola", "mundo"];
+
+    let ix = {
+        let (challenge_pda, _) =
+            Challenge::shank_pda(&challenge_id(), &creator, ID);
+        let solutions = hash_solutions(&solutions);
+        Instruction {
+            program_id: challenge_id(),
+            accounts: vec![
+                AccountMeta::new(context.payer.pubkey(), true),
+                AccountMeta::new_readonly(creator, false),
+                AccountMeta::new(challenge_pda, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: ChallengeInstruction::AddSolutions {
+                id: ID.to_string(),
+                solutions,
+                allow_duplicates: false,
+                rewards: vec![],
+            }
+            .try_to_vec()
+            .expect("failed to create custom instruction"),
+        }
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("Failed to process transaction");
+
+    assert!(result.result.is_err(), "adding solutions should fail");
+
+    let logs = result.metadata.expect("transaction metadata").log_messages;
+    assert!(
+        logs.iter()
+            .any(|log| log.contains("Err: [add_solutions] at")),
+        "logs should include the assert context, got {:#?}",
+        logs
+    );
+    assert!(
+        logs.iter().any(|log| log.contains("account 'creator'")
+            && log.contains("should be signer")),
+        "logs should include the failing account, got {:#?}",
+        logs
+    );
+}
@@ -69,6 +69,7 @@ async fn start_challenge_that_is_not_started_and_has_solutions() {
             redeem: _,
             solving: 0,
             solutions,
+            ..
         } => {
             assert_eq!(&authority, &creator);
             assert_eq!(id, ID);
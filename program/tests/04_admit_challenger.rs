@@ -47,6 +47,7 @@ async fn admit_challenger_to_started_challenge() {
             redeem: Pubkey::new_unique(),
             solving: 0,
             solutions,
+            ..Challenge::default()
         },
     );
 
@@ -81,6 +82,7 @@ async fn admit_challenger_to_started_challenge() {
             challenge_pda: cpda,
             tries_remaining: TRIES_PER_ADMIT,
             redeemed: false,
+            ..
         } => {
             assert_eq!(&authority, &challenger);
             assert_eq!(&cpda, &challenge_pda);
@@ -125,6 +127,7 @@ async fn admit_challenger_trying_twice_for_same_challenge() {
         redeem: Pubkey::new_unique(),
         solving: 0,
         solutions,
+        ..Challenge::default()
     };
 
     let (challenge_pda, _) = challenge.pda();
@@ -137,6 +140,7 @@ async fn admit_challenger_trying_twice_for_same_challenge() {
             challenge_pda,
             tries_remaining: TRIES_PER_ADMIT,
             redeemed: false,
+            ..Challenger::default()
         },
     );
 
@@ -181,6 +185,7 @@ async fn admit_challenger_to_not_yet_started_challenge() {
         redeem: Pubkey::new_unique(),
         solving: 0,
         solutions,
+        ..Challenge::default()
     };
 
     add_pda_account(&mut context, challenge);
@@ -226,6 +231,7 @@ async fn admit_challenger_to_started_and_finished_challenge() {
         redeem: Pubkey::new_unique(),
         solving: 0,
         solutions,
+        ..Challenge::default()
     };
 
     add_pda_account(&mut context, challenge);
@@ -1,14 +1,18 @@
 #![cfg(feature = "test-sbf")]
 
 use assert_matches::assert_matches;
+use borsh::BorshSerialize;
 
 use challenge::{
-    ixs,
+    challenge_id, ixs,
+    error::ChallengeError,
+    return_data::RedeemResult,
     state::{Challenge, Challenger, HasPda, Redeem},
+    test_utils::{assert_challenge_error, ChallengeScenario},
     utils::hash_solutions,
 };
 
-use solana_program::pubkey::Pubkey;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_program_test::*;
 
 #[allow(unused)]
@@ -42,6 +46,7 @@ async fn admitted_challenger_redeems_with(
         challenge_pda: challenge.pda().0,
         tries_remaining: TRIES_PER_ADMIT,
         redeemed: false,
+        ..Challenger::default()
     };
     add_pda_account(context, &challenger);
 
@@ -89,6 +94,7 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
         redeem: redeem.pda().0,
         solving: 0,
         solutions,
+        ..Challenge::default()
     };
     add_pda_account(&mut context, challenge);
     add_mint_to_redeem(&mut context, &redeem);
@@ -117,6 +123,7 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
                 challenge_pda: _,
                 tries_remaining,
                 redeemed: true,
+                ..
             } => {
                 assert_eq!(tries_remaining, TRIES_PER_ADMIT - 1);
             }
@@ -136,6 +143,7 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
                 solutions: _,
                 tries_per_admit: TRIES_PER_ADMIT,
                 redeem: _,
+                ..
             }
         );
 
@@ -169,6 +177,7 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
                 challenge_pda: _,
                 tries_remaining,
                 redeemed: true,
+                ..
             } => {
                 assert_eq!(tries_remaining, TRIES_PER_ADMIT - 1);
             }
@@ -188,6 +197,7 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
                 solutions: _,
                 tries_per_admit: TRIES_PER_ADMIT,
                 redeem: _,
+                ..
             }
         );
         verify_minted_when_redeeming(
@@ -213,3 +223,116 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
 // - redeeming with challenger that was admitted but already redeemed (possibly need a config on
 //   the challenge if multiple redeems are allowed or not)
 //
+
+#[tokio::test]
+async fn redeem_with_challenger_pda_of_other_challenger_fails() {
+    let admitted = Keypair::new();
+    let intruder = Keypair::new();
+    let mut scenario = ChallengeScenario::new()
+        .with_solutions(&["hello", "world"])
+        .with_tries_per_admit(TRIES_PER_ADMIT)
+        .with_admitted(&[admitted.pubkey()])
+        .started()
+        .run()
+        .await;
+    let payer = scenario.context.payer.pubkey();
+
+    // the intruder signs but passes the PDA of the admitted challenger to use its tries
+    let mut ix = ixs::redeem(payer, payer, ID, intruder.pubkey(), "hello")
+        .expect("failed to create instruction");
+    ix.accounts[3] = AccountMeta::new(scenario.challengers[0].pda().0, false);
+
+    let res = scenario.process_ixs(&[ix], &[&intruder]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedPdaIncorrect);
+
+    let (_, challenger) = get_deserialized::<Challenger>(
+        &mut scenario.context,
+        &scenario.challengers[0].pda().0,
+    )
+    .await;
+    assert_eq!(challenger.tries_remaining, TRIES_PER_ADMIT);
+    assert!(!challenger.redeemed);
+}
+
+// -----------------
+// Return Data
+// -----------------
+async fn redeem_and_get_result(
+    solutions: &[&str],
+    solution: &str,
+) -> Option<RedeemResult> {
+    let challenger = Keypair::new();
+    let mut scenario = ChallengeScenario::new()
+        .with_solutions(solutions)
+        .with_tries_per_admit(TRIES_PER_ADMIT)
+        .with_admitted(&[challenger.pubkey()])
+        .started()
+        .run()
+        .await;
+    let payer = scenario.context.payer.pubkey();
+
+    let ix = ixs::redeem(payer, payer, ID, challenger.pubkey(), solution)
+        .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer),
+        &[&scenario.context.payer, &challenger],
+        scenario.context.last_blockhash,
+    );
+    let res = scenario
+        .context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("Failed to process transaction");
+    assert_eq!(res.result, Ok(()));
+
+    let return_data = res
+        .metadata
+        .expect("transaction metadata")
+        .return_data
+        .expect("return data");
+    assert_eq!(return_data.program_id, challenge_id());
+    RedeemResult::from_return_data(&return_data.program_id, &return_data.data)
+}
+
+#[tokio::test]
+async fn redeem_returns_solved_index_and_remaining_solutions() {
+    let result = redeem_and_get_result(&["hello", "world"], "hello").await;
+    assert_eq!(
+        result,
+        Some(RedeemResult {
+            solved_index: Some(0),
+            remaining: 1,
+            reward_paid: 0,
+        })
+    );
+}
+
+#[tokio::test]
+async fn redeem_with_incorrect_solution_returns_no_solved_index() {
+    let result = redeem_and_get_result(&["hello", "world"], "world").await;
+    assert_eq!(
+        result,
+        Some(RedeemResult {
+            solved_index: None,
+            remaining: 2,
+            reward_paid: 0,
+        })
+    );
+}
+
+#[test]
+fn redeem_result_set_by_other_program_is_ignored() {
+    let data = RedeemResult {
+        solved_index: Some(0),
+        remaining: 0,
+        reward_paid: 1,
+    }
+    .try_to_vec()
+    .unwrap();
+    assert!(RedeemResult::from_return_data(&challenge_id(), &data).is_some());
+    assert!(
+        RedeemResult::from_return_data(&Pubkey::new_unique(), &data).is_none()
+    );
+}
@@ -0,0 +1,302 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{
+        Challenge, Challenger, HasPda, HasSize, HashAlgorithm, Redeem,
+        SolutionNormalization, SolveMode, CHALLENGE_VERSION,
+        DEFAULT_CHALLENGE_FLAGS,
+    },
+    utils::hash_solutions,
+};
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_to_redeem, add_pda_account,
+    airdrop_rent,
+};
+use solana_sdk::{
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+use crate::utils::{get_deserialized, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 11;
+const SOLVE_FROM_SLOT: u64 = 1_000;
+const SOLVE_UNTIL_SLOT: u64 = 2_000;
+
+fn challenge_with_solve_window(creator: Pubkey) -> Challenge {
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    Challenge {
+        version: CHALLENGE_VERSION,
+        bump: Challenge::pda_for(&creator, ID).1,
+        authority: creator,
+        pending_authority: None,
+        original_authority: None,
+        admins: vec![],
+        id: ID.to_string(),
+        started: true,
+        finished: false,
+        paused: false,
+        cancelled: false,
+        admit_cost: ADMIT_COST,
+        admit_cost_locked: false,
+        admit_mint: None,
+        has_prize_pool: false,
+        nft_reward: false,
+        has_first_solvers: false,
+        commit_reveal: false,
+        allowlist_root: None,
+        max_admissions: None,
+        admissions: 0,
+        salt: None,
+        hash_algorithm: HashAlgorithm::Sha256,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: redeem.pda().0,
+        solving: 0,
+        solve_mode: SolveMode::Sequential,
+        solved: [0; 32],
+        solve_open_from_slot: Some(SOLVE_FROM_SLOT),
+        solve_open_until_slot: Some(SOLVE_UNTIL_SLOT),
+        starts_at: None,
+        ends_at: None,
+        oracle: Pubkey::default(),
+        solutions_locked: false,
+        hint_fee: 0,
+        attempt_cooldown_slots: 0,
+        max_winners_per_solution: 0,
+        solution_normalization: SolutionNormalization::NONE,
+        reject_weak_solutions: false,
+        ticket_admission: false,
+        flags: DEFAULT_CHALLENGE_FLAGS,
+        seed: None,
+        max_tries_per_challenger: None,
+        sponsored_lamports: 0,
+        prerequisite: None,
+        epoch_slots: 0,
+        epoch_start_slot: 0,
+        solutions_per_epoch: 0,
+        epoch: 0,
+        rewards: vec![],
+        solutions: hash_solutions(&["hello", "world"]),
+        hints: vec![],
+    }
+}
+
+async fn admitted_challenger_redeems_with(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+    solution: &str,
+) -> Result<(), BanksClientError> {
+    let challenger_pair = Keypair::new();
+    let challenger_key = challenger_pair.pubkey();
+
+    add_pda_account(
+        context,
+        &Challenger {
+            authority: challenger_key,
+            challenge_pda: challenge.pda().0,
+            tries_remaining: TRIES_PER_ADMIT,
+            redeemed: false,
+            ..Challenger::default()
+        },
+    );
+
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        challenge.authority,
+        ID,
+        challenger_key,
+        solution,
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &challenger_pair],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn set_solve_window_for_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["hello"], None);
+
+    let ix = ixs::set_solve_window(
+        context.payer.pubkey(),
+        creator,
+        ID.to_string(),
+        Some(SOLVE_FROM_SLOT),
+        Some(SOLVE_UNTIL_SLOT),
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to set solve window");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+
+    assert_eq!(acc.data.len(), value.size());
+    assert_matches!(
+        value,
+        Challenge {
+            solve_open_from_slot: Some(SOLVE_FROM_SLOT),
+            solve_open_until_slot: Some(SOLVE_UNTIL_SLOT),
+            solving: 0,
+            solutions,
+            ..
+        } => {
+            assert_eq!(solutions, hash_solutions(&["hello"]));
+        }
+    );
+}
+
+#[tokio::test]
+async fn admit_challenger_before_solve_window_opens() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+    add_pda_account(&mut context, &challenge_with_solve_window(creator));
+
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        Pubkey::new_unique(),
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to admit challenger outside of solve window");
+}
+
+#[tokio::test]
+async fn redeem_inside_solve_window() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    let challenge = challenge_with_solve_window(creator);
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &Redeem::for_challenge_with(&creator, ID));
+
+    context
+        .warp_to_slot(SOLVE_FROM_SLOT)
+        .expect("Failed to warp to slot");
+
+    admitted_challenger_redeems_with(&mut context, &challenge, "hello")
+        .await
+        .expect("Failed to redeem inside solve window");
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(value.solving, 1);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn redeem_before_solve_window_opens() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    let challenge = challenge_with_solve_window(creator);
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &Redeem::for_challenge_with(&creator, ID));
+
+    let res =
+        admitted_challenger_redeems_with(&mut context, &challenge, "hello")
+            .await;
+    assert!(res.is_err(), "should not redeem before solve window opens");
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(value.solving, 0);
+}
+
+#[tokio::test]
+async fn redeem_after_solve_window_closed() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    let challenge = challenge_with_solve_window(creator);
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &Redeem::for_challenge_with(&creator, ID));
+
+    context
+        .warp_to_slot(SOLVE_UNTIL_SLOT + 1)
+        .expect("Failed to warp to slot");
+
+    let res =
+        admitted_challenger_redeems_with(&mut context, &challenge, "hello")
+            .await;
+    assert!(res.is_err(), "should not redeem after solve window closed");
+}
+
+#[tokio::test]
+#[should_panic]
+async fn set_solve_window_that_closes_before_it_opens() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["hello"], None);
+
+    let ix = ixs::set_solve_window(
+        context.payer.pubkey(),
+        creator,
+        ID.to_string(),
+        Some(SOLVE_UNTIL_SLOT),
+        Some(SOLVE_FROM_SLOT),
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to set solve window");
+}
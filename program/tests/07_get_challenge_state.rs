@@ -0,0 +1,85 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    challenge_id, ixs,
+    state::{Challenge, HasDiscriminator},
+};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::utils::{add_challenge_with_solutions, get_account, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn get_challenge_state_tx(context: &ProgramTestContext) -> Transaction {
+    let ix = ixs::get_challenge_state(context.payer.pubkey(), ID)
+        .expect("failed to create instruction");
+
+    Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn get_challenge_state_returns_serialized_challenge() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge_with_solutions(
+        &mut context,
+        ID,
+        vec!["hello", "world"],
+        None,
+    );
+
+    let tx = get_challenge_state_tx(&context);
+    let res = context
+        .banks_client
+        .simulate_transaction(tx)
+        .await
+        .expect("Failed to simulate transaction");
+    assert_eq!(res.result, Some(Ok(())));
+
+    let return_data = res
+        .simulation_details
+        .expect("simulation details")
+        .return_data
+        .expect("return data");
+    assert_eq!(return_data.program_id, challenge_id());
+
+    let (challenge_pda, _) = Challenge::pda_for(&context.payer.pubkey(), ID);
+    let acc = get_account(&mut context, &challenge_pda).await;
+    assert_eq!(return_data.data, acc.data);
+
+    let challenge = Challenge::try_from_account_data(&return_data.data)
+        .expect("Unable to deserialize return data");
+    assert_eq!(challenge.id, ID);
+    assert_eq!(challenge.solutions.len(), 2);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn get_challenge_state_too_large_for_return_data() {
+    let mut context = program_test().start_with_context().await;
+    let solutions = (0..40).map(|i| i.to_string()).collect::<Vec<_>>();
+    add_challenge_with_solutions(
+        &mut context,
+        ID,
+        solutions.iter().map(String::as_str).collect(),
+        None,
+    );
+
+    let tx = get_challenge_state_tx(&context);
+    let res = context
+        .banks_client
+        .simulate_transaction(tx)
+        .await
+        .expect("Failed to simulate transaction");
+    assert_matches::assert_matches!(res.result, Some(Err(_)));
+}
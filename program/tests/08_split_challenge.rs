@@ -0,0 +1,187 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    ixs,
+    state::{Challenge, HasPda, Redeem},
+    utils::hash_solutions,
+};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_pda_account, get_deserialized,
+    get_unpacked, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const NEW_ID: &str = "challenge-id-2";
+
+#[tokio::test]
+async fn split_challenge_with_four_solutions_at_index_one() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(
+        &mut context,
+        ID,
+        vec!["one", "two", "three", "four"],
+        None,
+    );
+
+    let ix = ixs::split_challenge(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        NEW_ID.to_string(),
+        1,
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to split challenge");
+
+    // Original challenge keeps the first solution
+    {
+        let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+        let (acc, value) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_matches!(
+            value,
+            Challenge {
+                authority,
+                id,
+                solving: 0,
+                solutions,
+                ..
+            } => {
+                assert_eq!(authority, creator);
+                assert_eq!(id, ID);
+                assert_eq!(solutions, hash_solutions(&["one"]));
+                assert_eq!(acc.data.len(), Challenge::needed_size(&solutions, ID));
+            }
+        );
+    }
+
+    // New challenge has the remaining solutions and its own redeem mint
+    {
+        let (challenge_pda, _) = Challenge::pda_for(&creator, NEW_ID);
+        let (acc, value) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_matches!(
+            value,
+            Challenge {
+                authority,
+                id,
+                started: false,
+                finished: false,
+                admit_cost: 200,
+                tries_per_admit: 1,
+                redeem,
+                solving: 0,
+                solutions,
+                ..
+            } => {
+                assert_eq!(authority, creator);
+                assert_eq!(id, NEW_ID);
+                assert_eq!(redeem, Redeem::new(challenge_pda).pda().0);
+                assert_eq!(solutions, hash_solutions(&["two", "three", "four"]));
+                assert_eq!(acc.data.len(), Challenge::needed_size(&solutions, NEW_ID));
+            }
+        );
+
+        let (_, mint) = get_unpacked::<spl_token::state::Mint>(
+            &mut context,
+            &Redeem::new(challenge_pda).pda().0,
+        )
+        .await;
+        assert!(mint.is_initialized);
+    }
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+#[should_panic]
+async fn split_challenge_with_redeemed_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_pda_account(
+        &mut context,
+        &Challenge {
+            authority: creator,
+            id: ID.to_string(),
+            started: true,
+            solving: 1,
+            solutions: hash_solutions(&["one", "two", "three", "four"]),
+            ..Challenge::default()
+        },
+    );
+
+    let ix = ixs::split_challenge(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        NEW_ID.to_string(),
+        2,
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to split challenge");
+}
+
+#[tokio::test]
+#[should_panic]
+async fn split_challenge_at_last_index() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+
+    let ix = ixs::split_challenge(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        NEW_ID.to_string(),
+        2,
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to split challenge");
+}
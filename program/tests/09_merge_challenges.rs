@@ -0,0 +1,241 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    error::ChallengeError, ixs, state::Challenge, utils::hash_solutions,
+};
+use solana_program::{
+    instruction::Instruction, program_option::COption, pubkey::Pubkey,
+};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use spl_associated_token_account::get_associated_token_address;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_pack_account, add_pda_account,
+    add_started_challenge_with_solutions, assert_challenge_error,
+    get_deserialized, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const SOURCE_ID: &str = "challenge-id-2";
+
+#[tokio::test]
+async fn merge_challenge_with_one_solution_into_challenge_with_two() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+    add_challenge_with_solutions(&mut context, SOURCE_ID, vec!["three"], None);
+
+    let ix = ixs::merge_challenges(
+        context.payer.pubkey(),
+        creator,
+        ID.to_string(),
+        SOURCE_ID.to_string(),
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to merge challenges");
+
+    // Challenge has all solutions
+    {
+        let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+        let (acc, value) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_matches!(
+            value,
+            Challenge {
+                authority,
+                id,
+                solving: 0,
+                solutions,
+                ..
+            } => {
+                assert_eq!(authority, creator);
+                assert_eq!(id, ID);
+                assert_eq!(solutions, hash_solutions(&["one", "two", "three"]));
+                assert_eq!(acc.data.len(), Challenge::needed_size(&solutions, ID));
+            }
+        );
+    }
+
+    // Source challenge was closed
+    {
+        let (source_challenge_pda, _) = Challenge::pda_for(&creator, SOURCE_ID);
+        let acc = context
+            .banks_client
+            .get_account(source_challenge_pda)
+            .await
+            .expect("get_account(): failed");
+        assert!(acc.is_none());
+    }
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+#[should_panic]
+async fn merge_challenge_with_redeemed_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+    add_pda_account(
+        &mut context,
+        &Challenge {
+            authority: creator,
+            id: SOURCE_ID.to_string(),
+            started: true,
+            solving: 1,
+            solutions: hash_solutions(&["three", "four"]),
+            ..Challenge::default()
+        },
+    );
+
+    let ix = ixs::merge_challenges(
+        context.payer.pubkey(),
+        creator,
+        ID.to_string(),
+        SOURCE_ID.to_string(),
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to merge challenges");
+}
+
+#[tokio::test]
+#[should_panic]
+async fn merge_challenge_into_itself() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+
+    let ix = ixs::merge_challenges(
+        context.payer.pubkey(),
+        creator,
+        ID.to_string(),
+        ID.to_string(),
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to merge challenges");
+}
+
+async fn process_ix(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn merge_started_source_challenge_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+    add_started_challenge_with_solutions(
+        &mut context,
+        SOURCE_ID,
+        vec!["three"],
+        None,
+    );
+
+    let ix = ixs::merge_challenges(
+        creator,
+        creator,
+        ID.to_string(),
+        SOURCE_ID.to_string(),
+    )
+    .expect("failed to create instruction");
+    let res = process_ix(&mut context, ix).await;
+    assert_challenge_error(res, ChallengeError::ChallengeInProgress);
+}
+
+#[tokio::test]
+async fn merge_source_challenge_with_admit_tokens_in_escrow_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let admit_mint = Pubkey::new_unique();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+    let source = Challenge {
+        authority: creator,
+        id: SOURCE_ID.to_string(),
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        admit_mint: Some(admit_mint),
+        solutions: hash_solutions(&["three"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &source);
+    let (source_pda, _) = Challenge::pda_for(&creator, SOURCE_ID);
+    add_pack_account(
+        &mut context,
+        &get_associated_token_address(&source_pda, &admit_mint),
+        &spl_token::state::Account {
+            mint: admit_mint,
+            owner: source_pda,
+            amount: 100,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        },
+        &spl_token::id(),
+    );
+
+    let ix = ixs::merge_challenges_with_admit_mint(
+        creator,
+        creator,
+        ID.to_string(),
+        SOURCE_ID.to_string(),
+        admit_mint,
+    )
+    .expect("failed to create instruction");
+    let res = process_ix(&mut context, ix).await;
+    assert_challenge_error(res, ChallengeError::AdmitTokensNotWithdrawn);
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{ixs, state::Challenge};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, get_deserialized, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+#[tokio::test]
+async fn update_admit_cost_then_lock_it() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+
+    let update_ix = ixs::update_admit_cost(
+        context.payer.pubkey(),
+        creator,
+        ID.to_string(),
+        1000,
+    )
+    .expect("failed to create update instruction");
+    let lock_ix =
+        ixs::lock_admit_cost(context.payer.pubkey(), creator, ID.to_string())
+            .expect("failed to create lock instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix, lock_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to update and lock admit cost");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            admit_cost: 1000,
+            admit_cost_locked: true,
+            solutions,
+            ..
+        } => {
+            assert_eq!(acc.data.len(), Challenge::needed_size(&solutions, ID));
+        }
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+#[should_panic]
+async fn update_admit_cost_after_lock() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+
+    let lock_ix =
+        ixs::lock_admit_cost(context.payer.pubkey(), creator, ID.to_string())
+            .expect("failed to create lock instruction");
+    let update_ix = ixs::update_admit_cost(
+        context.payer.pubkey(),
+        creator,
+        ID.to_string(),
+        1000,
+    )
+    .expect("failed to create update instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[lock_ix, update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to update locked admit cost");
+}
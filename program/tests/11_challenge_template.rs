@@ -0,0 +1,253 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{
+        Challenge, ChallengeTemplate, HasPda, HasSize, HashAlgorithm,
+        TemplateOverrides,
+    },
+    utils::hash_solutions_with_algorithm,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    assert_challenge_error, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const TEMPLATE_ID: u8 = 1;
+const TEMPLATE_ADMIT_COST: u64 = 1000;
+const TEMPLATE_TRIES_PER_ADMIT: u8 = 3;
+const TEMPLATE_SALT: [u8; 32] = [7; 32];
+const TEMPLATE_STARTS_AT: i64 = 1_000;
+const TEMPLATE_ENDS_AT: i64 = 4_000_000_000;
+
+const ID: &str = "challenge-id";
+
+fn full_template(creator: Pubkey) -> ChallengeTemplate {
+    ChallengeTemplate {
+        authority: creator,
+        template_id: TEMPLATE_ID,
+        admit_cost: TEMPLATE_ADMIT_COST,
+        tries_per_admit: TEMPLATE_TRIES_PER_ADMIT,
+        salt: Some(TEMPLATE_SALT),
+        hash_algorithm: HashAlgorithm::Keccak256,
+        starts_at: Some(TEMPLATE_STARTS_AT),
+        ends_at: Some(TEMPLATE_ENDS_AT),
+    }
+}
+
+async fn create_template(
+    context: &mut ProgramTestContext,
+    template: &ChallengeTemplate,
+) {
+    let ix = ixs::create_template(context.payer.pubkey(), template)
+        .expect("failed to create instruction");
+    process_ixs(context, &[ix], &[])
+        .await
+        .expect("Failed to create template");
+}
+
+async fn create_challenge_from_template(
+    context: &mut ProgramTestContext,
+    template: &ChallengeTemplate,
+    overrides: TemplateOverrides,
+) -> Challenge {
+    let ix = ixs::create_challenge_from_template(
+        context.payer.pubkey(),
+        template,
+        ID.to_string(),
+        overrides,
+        vec!["hello"],
+    )
+    .expect("failed to create instruction");
+    process_ixs(context, &[ix], &[])
+        .await
+        .expect("Failed to create challenge from template");
+
+    let (challenge_pda, _) = Challenge::pda_for(&template.authority, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(context, &challenge_pda).await;
+    challenge
+}
+
+#[tokio::test]
+async fn create_template_stores_defaults() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let template = full_template(creator);
+    create_template(&mut context, &template).await;
+
+    let (template_pda, _) = ChallengeTemplate::pda_for(&creator, TEMPLATE_ID);
+    let (acc, value) =
+        get_deserialized::<ChallengeTemplate>(&mut context, &template_pda)
+            .await;
+    assert_matches!(
+        value,
+        ChallengeTemplate {
+            authority,
+            template_id: TEMPLATE_ID,
+            admit_cost: TEMPLATE_ADMIT_COST,
+            tries_per_admit: TEMPLATE_TRIES_PER_ADMIT,
+            salt: Some(TEMPLATE_SALT),
+            hash_algorithm: HashAlgorithm::Keccak256,
+            starts_at: Some(TEMPLATE_STARTS_AT),
+            ends_at: Some(TEMPLATE_ENDS_AT),
+        } => {
+            assert_eq!(authority, creator);
+            assert_eq!(acc.data.len(), template.size());
+        }
+    );
+}
+
+#[tokio::test]
+async fn create_template_without_optional_defaults() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let template = ChallengeTemplate {
+        salt: None,
+        starts_at: None,
+        ends_at: None,
+        ..full_template(creator)
+    };
+    create_template(&mut context, &template).await;
+
+    let (acc, value) =
+        get_deserialized::<ChallengeTemplate>(&mut context, &template.pda().0)
+            .await;
+    assert_matches!(
+        value,
+        ChallengeTemplate {
+            salt: None,
+            starts_at: None,
+            ends_at: None,
+            ..
+        }
+    );
+    assert_eq!(acc.data.len(), template.size());
+    assert!(template.size() < full_template(creator).size());
+}
+
+#[tokio::test]
+async fn create_challenge_from_template_without_overrides() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let template = full_template(creator);
+    create_template(&mut context, &template).await;
+
+    let challenge = create_challenge_from_template(
+        &mut context,
+        &template,
+        TemplateOverrides::default(),
+    )
+    .await;
+    assert_matches!(
+        challenge,
+        Challenge {
+            authority,
+            admit_cost: TEMPLATE_ADMIT_COST,
+            tries_per_admit: TEMPLATE_TRIES_PER_ADMIT,
+            salt: Some(TEMPLATE_SALT),
+            hash_algorithm: HashAlgorithm::Keccak256,
+            starts_at: Some(TEMPLATE_STARTS_AT),
+            ends_at: Some(TEMPLATE_ENDS_AT),
+            solutions,
+            ..
+        } => {
+            assert_eq!(authority, creator);
+            assert_eq!(
+                solutions,
+                hash_solutions_with_algorithm(
+                    &["hello"],
+                    Some(&TEMPLATE_SALT),
+                    HashAlgorithm::Keccak256
+                )
+            );
+        }
+    );
+}
+
+#[tokio::test]
+async fn create_challenge_from_template_with_overrides() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let template = full_template(creator);
+    create_template(&mut context, &template).await;
+
+    let challenge = create_challenge_from_template(
+        &mut context,
+        &template,
+        TemplateOverrides {
+            admit_cost: Some(5000),
+            salt: Some([9; 32]),
+            hash_algorithm: Some(HashAlgorithm::Blake3),
+            ends_at: Some(TEMPLATE_ENDS_AT + 1),
+            ..TemplateOverrides::default()
+        },
+    )
+    .await;
+    assert_matches!(
+        challenge,
+        Challenge {
+            admit_cost: 5000,
+            tries_per_admit: TEMPLATE_TRIES_PER_ADMIT,
+            salt: Some([9, ..]),
+            hash_algorithm: HashAlgorithm::Blake3,
+            starts_at: Some(TEMPLATE_STARTS_AT),
+            ends_at: Some(ends_at),
+            solutions,
+            ..
+        } => {
+            assert_eq!(ends_at, TEMPLATE_ENDS_AT + 1);
+            assert_eq!(
+                solutions,
+                hash_solutions_with_algorithm(
+                    &["hello"],
+                    Some(&[9; 32]),
+                    HashAlgorithm::Blake3
+                )
+            );
+        }
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+#[should_panic]
+async fn create_challenge_from_missing_template() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    create_challenge_from_template(
+        &mut context,
+        &full_template(creator),
+        TemplateOverrides::default(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn create_template_with_invalid_schedule() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let template = ChallengeTemplate {
+        starts_at: Some(TEMPLATE_ENDS_AT),
+        ends_at: Some(TEMPLATE_STARTS_AT),
+        ..full_template(creator)
+    };
+
+    let ix = ixs::create_template(creator, &template)
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::InvalidSchedule);
+}
@@ -0,0 +1,252 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, Challenger, HasPda, Redeem},
+    utils::hash_solutions,
+};
+
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, assert_challenge_error,
+    get_deserialized, program_test, verify_minted_when_redeeming,
+};
+use solana_sdk::{
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const TRIES_PER_ADMIT: u8 = 3;
+
+struct Setup {
+    creator: Pubkey,
+    oracle: Keypair,
+    challenge: Challenge,
+    challenger_pair: Keypair,
+    challenger: Challenger,
+}
+
+fn setup(context: &mut ProgramTestContext) -> Setup {
+    let creator = Pubkey::new_unique();
+    let oracle = Keypair::new();
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: redeem.pda().0,
+        oracle: oracle.pubkey(),
+        solutions: hash_solutions(&["hello", "world"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    add_mint_to_redeem(context, &redeem);
+
+    let challenger_pair = Keypair::new();
+    let challenger = Challenger {
+        authority: challenger_pair.pubkey(),
+        challenge_pda: challenge.pda().0,
+        tries_remaining: TRIES_PER_ADMIT,
+        redeemed: false,
+        ..Challenger::default()
+    };
+    add_pda_account(context, &challenger);
+
+    Setup {
+        creator,
+        oracle,
+        challenge,
+        challenger_pair,
+        challenger,
+    }
+}
+
+fn sign_solution(
+    oracle: &Keypair,
+    challenge: &Challenge,
+    challenger: &Pubkey,
+    solution_index: u8,
+) -> [u8; 64] {
+    let message = Challenge::oracle_message(
+        &challenge.pda().0,
+        challenger,
+        &challenge.solutions[solution_index as usize],
+    );
+    oracle
+        .sign_message(&message)
+        .as_ref()
+        .try_into()
+        .expect("signature should be 64 bytes")
+}
+
+async fn verify_solve_signature(
+    context: &mut ProgramTestContext,
+    setup: &Setup,
+    solution_index: u8,
+    oracle_signature: [u8; 64],
+) -> Result<(), BanksClientError> {
+    let ixs = ixs::verify_solve_signature(
+        context.payer.pubkey(),
+        setup.creator,
+        ID,
+        setup.challenger_pair.pubkey(),
+        setup.oracle.pubkey(),
+        solution_index,
+        &setup.challenge.solutions[solution_index as usize],
+        oracle_signature,
+    )
+    .expect("failed to create instructions");
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &setup.challenger_pair],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn verify_solve_signature_signed_by_oracle() {
+    let mut context = program_test().start_with_context().await;
+    let setup = setup(&mut context);
+
+    let signature = sign_solution(
+        &setup.oracle,
+        &setup.challenge,
+        &setup.challenger_pair.pubkey(),
+        0,
+    );
+    verify_solve_signature(&mut context, &setup, 0, signature)
+        .await
+        .expect("Failed to redeem with oracle signature");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &setup.challenge.pda().0)
+            .await;
+    assert_matches!(
+        challenge,
+        Challenge {
+            solving: 1,
+            finished: false,
+            ..
+        }
+    );
+
+    let (_, challenger) =
+        get_deserialized::<Challenger>(&mut context, &setup.challenger.pda().0)
+            .await;
+    assert_matches!(
+        challenger,
+        Challenger {
+            tries_remaining,
+            redeemed: true,
+            ..
+        } => {
+            assert_eq!(tries_remaining, TRIES_PER_ADMIT - 1);
+        }
+    );
+
+    let redeem = Redeem::for_challenge_with(&setup.creator, ID);
+    verify_minted_when_redeeming(
+        &mut context,
+        redeem.pda().0,
+        1,
+        &redeem,
+        &setup.challenger,
+    )
+    .await;
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn verify_solve_signature_signed_by_other_key() {
+    let mut context = program_test().start_with_context().await;
+    let setup = setup(&mut context);
+
+    let imposter = Keypair::new();
+    let signature = sign_solution(
+        &imposter,
+        &setup.challenge,
+        &setup.challenger_pair.pubkey(),
+        0,
+    );
+    let res = verify_solve_signature(&mut context, &setup, 0, signature).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn verify_solve_signature_signed_for_other_challenger() {
+    let mut context = program_test().start_with_context().await;
+    let setup = setup(&mut context);
+
+    let signature = sign_solution(
+        &setup.oracle,
+        &setup.challenge,
+        &Pubkey::new_unique(),
+        0,
+    );
+    let res = verify_solve_signature(&mut context, &setup, 0, signature).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn verify_solve_signature_for_solution_that_is_not_next() {
+    let mut context = program_test().start_with_context().await;
+    let setup = setup(&mut context);
+
+    let signature = sign_solution(
+        &setup.oracle,
+        &setup.challenge,
+        &setup.challenger_pair.pubkey(),
+        1,
+    );
+    let res = verify_solve_signature(&mut context, &setup, 1, signature).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn verify_solve_signature_with_challenger_pda_of_other_challenger() {
+    let mut context = program_test().start_with_context().await;
+    let setup = setup(&mut context);
+
+    // the oracle signs for the intruder who then passes the admitted challenger's PDA
+    let intruder = Keypair::new();
+    let signature =
+        sign_solution(&setup.oracle, &setup.challenge, &intruder.pubkey(), 0);
+    let mut ixs = ixs::verify_solve_signature(
+        context.payer.pubkey(),
+        setup.creator,
+        ID,
+        intruder.pubkey(),
+        setup.oracle.pubkey(),
+        0,
+        &setup.challenge.solutions[0],
+        signature,
+    )
+    .expect("failed to create instructions");
+    ixs[1].accounts[3] = AccountMeta::new(setup.challenger.pda().0, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &intruder],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert_challenge_error(res, ChallengeError::ProvidedPdaIncorrect);
+}
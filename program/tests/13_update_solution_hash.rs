@@ -0,0 +1,102 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{ixs, state::Challenge, utils::hash_solutions};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_pda_account, get_deserialized,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn update_solution_hash(
+    context: &mut ProgramTestContext,
+    index: u8,
+    solution: &str,
+) -> Result<(), BanksClientError> {
+    let creator = context.payer.pubkey();
+    let new_hash = hash_solutions(&[solution])[0];
+    let ix = ixs::update_solution_hash(
+        context.payer.pubkey(),
+        creator,
+        ID.to_string(),
+        index,
+        new_hash,
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn update_middle_solution_hash() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(
+        &mut context,
+        ID,
+        vec!["one", "two", "three"],
+        None,
+    );
+
+    update_solution_hash(&mut context, 1, "zwei")
+        .await
+        .expect("Failed to update solution hash");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge { solutions, .. } => {
+            assert_eq!(solutions, hash_solutions(&["one", "zwei", "three"]));
+        }
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn update_solution_hash_out_of_range() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+
+    let res = update_solution_hash(&mut context, 2, "three").await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn update_solution_hash_of_redeemed_solution() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_pda_account(
+        &mut context,
+        &Challenge {
+            authority: creator,
+            id: ID.to_string(),
+            started: true,
+            solving: 1,
+            solutions: hash_solutions(&["one", "two"]),
+            ..Challenge::default()
+        },
+    );
+
+    let res = update_solution_hash(&mut context, 0, "eins").await;
+    assert!(res.is_err());
+}
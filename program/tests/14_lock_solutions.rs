@@ -0,0 +1,159 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    ixs,
+    state::{Challenge, Challenger, HasPda, Redeem},
+    utils::hash_solutions,
+};
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_to_redeem, add_pda_account,
+    get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+#[tokio::test]
+async fn lock_solutions_then_unlock_and_add() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let lock_ix = ixs::lock_solutions(creator, creator, ID.to_string())
+        .expect("failed to create lock instruction");
+    process_ixs(&mut context, &[lock_ix], &[])
+        .await
+        .expect("Failed to lock solutions");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            solutions_locked: true,
+            ..
+        }
+    );
+
+    let unlock_ix = ixs::unlock_solutions(creator, creator, ID.to_string())
+        .expect("failed to create unlock instruction");
+    let add_ix =
+        ixs::add_solutions(creator, creator, ID.to_string(), vec!["two"])
+            .expect("failed to create add instruction");
+    process_ixs(&mut context, &[unlock_ix, add_ix], &[])
+        .await
+        .expect("Failed to unlock solutions and add solution");
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            solutions_locked: false,
+            solutions,
+            ..
+        } => {
+            assert_eq!(solutions, hash_solutions(&["one", "two"]));
+        }
+    );
+}
+
+#[tokio::test]
+async fn redeem_while_solutions_locked() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        redeem: redeem.pda().0,
+        solutions_locked: true,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &redeem);
+
+    let challenger_pair = Keypair::new();
+    let challenger = Challenger {
+        authority: challenger_pair.pubkey(),
+        challenge_pda: challenge.pda().0,
+        tries_remaining: 1,
+        redeemed: false,
+        ..Challenger::default()
+    };
+    add_pda_account(&mut context, &challenger);
+
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger_pair.pubkey(),
+        "one",
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &challenger_pair],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to redeem");
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_matches!(value, Challenge { solving: 1, .. });
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn add_solutions_while_locked() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let lock_ix = ixs::lock_solutions(creator, creator, ID.to_string())
+        .expect("failed to create lock instruction");
+    let add_ix =
+        ixs::add_solutions(creator, creator, ID.to_string(), vec!["two"])
+            .expect("failed to create add instruction");
+
+    let res = process_ixs(&mut context, &[lock_ix, add_ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn truncate_solutions_while_locked() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+
+    let lock_ix = ixs::lock_solutions(creator, creator, ID.to_string())
+        .expect("failed to create lock instruction");
+    let truncate_ix = ixs::truncate_solutions(creator, ID.to_string(), 1)
+        .expect("failed to create truncate instruction");
+
+    let res = process_ixs(&mut context, &[lock_ix, truncate_ix], &[]).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::utils::{
+    decode_hex, encode_hex, hash_solution_challenger_sends, solution_from_hex,
+    solution_to_hex, HexError,
+};
+
+const SHA256_EMPTY: &str =
+    "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+const SHA256_ABC: &str =
+    "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+#[test]
+fn encode_sha256_known_answers() {
+    assert_eq!(
+        solution_to_hex(&hash_solution_challenger_sends("")),
+        SHA256_EMPTY
+    );
+    assert_eq!(
+        solution_to_hex(&hash_solution_challenger_sends("abc")),
+        SHA256_ABC
+    );
+}
+
+#[test]
+fn decode_sha256_known_answers() {
+    assert_eq!(
+        solution_from_hex(SHA256_ABC).unwrap(),
+        hash_solution_challenger_sends("abc")
+    );
+    assert_eq!(
+        solution_from_hex(&SHA256_EMPTY.to_uppercase()).unwrap(),
+        hash_solution_challenger_sends("")
+    );
+}
+
+#[test]
+fn encode_decode_roundtrip() {
+    let bytes = [0x00, 0x01, 0x7f, 0x80, 0xfe, 0xff];
+    assert_eq!(encode_hex(&bytes), "00017f80feff");
+    assert_eq!(decode_hex("00017f80feff").unwrap(), bytes);
+    assert_eq!(encode_hex(&[]), "");
+    assert_eq!(decode_hex("").unwrap(), Vec::<u8>::new());
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[test]
+fn decode_invalid_hex() {
+    assert_eq!(decode_hex("abc"), Err(HexError::OddLength(3)));
+    assert_eq!(decode_hex("0g"), Err(HexError::InvalidCharacter('g', 1)));
+    assert_eq!(
+        solution_from_hex("abcd"),
+        Err(HexError::InvalidLength {
+            expected: 32,
+            actual: 2
+        })
+    );
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+
+use challenge::{
+    ixs,
+    state::{Challenge, Challenger, HasPda},
+    utils::hash_solutions,
+};
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{add_pda_account, airdrop_rent};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::utils::{get_account, get_deserialized, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 11;
+
+fn setup_challenger_with_tries(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+    tries_remaining: u8,
+) -> Challenger {
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(&["hello", "world"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+
+    let challenger = Challenger {
+        authority: Pubkey::new_unique(),
+        challenge_pda: challenge.pda().0,
+        tries_remaining,
+        redeemed: false,
+        ..Challenger::default()
+    };
+    add_pda_account(context, &challenger);
+    challenger
+}
+
+async fn reissue_admission(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+    challenger: &Challenger,
+) -> Result<(), BanksClientError> {
+    let ix = ixs::reissue_admission(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.authority,
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn reissue_admission_for_challenger_without_tries() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    let creator_lamports = airdrop_rent(&mut context, &creator, 0).await;
+    let challenger = setup_challenger_with_tries(&mut context, creator, 0);
+
+    reissue_admission(&mut context, creator, &challenger)
+        .await
+        .expect("Failed to reissue admission");
+
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger.pda().0).await;
+    assert_matches!(
+        value,
+        Challenger {
+            tries_remaining: TRIES_PER_ADMIT,
+            ..
+        }
+    );
+
+    let creator_acc = get_account(&mut context, &creator).await;
+    assert_eq!(
+        creator_acc.lamports,
+        creator_lamports + ADMIT_COST,
+        "creator should have received admit cost"
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn reissue_admission_for_challenger_with_tries_remaining() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+    let challenger = setup_challenger_with_tries(&mut context, creator, 1);
+
+    let res = reissue_admission(&mut context, creator, &challenger).await;
+    assert!(res.is_err());
+}
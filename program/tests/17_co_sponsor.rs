@@ -0,0 +1,142 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    ixs,
+    state::{Challenge, SponsorLog},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::Keypair, signer::Signer, system_instruction,
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, get_account, get_deserialized, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn fund_sponsor(context: &mut ProgramTestContext) -> Keypair {
+    let sponsor = Keypair::new();
+    let ix = system_instruction::transfer(
+        &context.payer.pubkey(),
+        &sponsor.pubkey(),
+        1_000_000_000,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to fund sponsor");
+    sponsor
+}
+
+async fn add_co_sponsor(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+    sponsor: &Keypair,
+    amount: u64,
+) {
+    let ix = ixs::add_co_sponsor(sponsor.pubkey(), creator, ID, amount)
+        .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&sponsor.pubkey()),
+        &[sponsor],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to add co-sponsor");
+}
+
+#[tokio::test]
+async fn co_sponsor_contributes_twice() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], Some(creator));
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let sponsor = fund_sponsor(&mut context).await;
+
+    let pool_lamports =
+        get_account(&mut context, &challenge_pda).await.lamports;
+
+    add_co_sponsor(&mut context, creator, &sponsor, 1_000).await;
+    add_co_sponsor(&mut context, creator, &sponsor, 2_500).await;
+
+    let (challenge_acc, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge_acc.lamports, pool_lamports + 3_500);
+    assert_eq!(challenge.sponsored_lamports, 3_500);
+
+    let (sponsor_log_pda, _) =
+        SponsorLog::pda_for(&challenge_pda, &sponsor.pubkey());
+    let (acc, value) =
+        get_deserialized::<SponsorLog>(&mut context, &sponsor_log_pda).await;
+    assert_matches!(
+        value,
+        SponsorLog {
+            sponsor: s,
+            challenge_pda: cpda,
+            total_contributed: 3_500,
+        } => {
+            assert_eq!(s, sponsor.pubkey());
+            assert_eq!(cpda, challenge_pda);
+            assert_eq!(acc.data.len(), SponsorLog::size());
+        }
+    );
+}
+
+#[tokio::test]
+async fn removing_solutions_keeps_co_sponsored_lamports() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(
+        &mut context,
+        ID,
+        vec!["one", "two", "three"],
+        None,
+    );
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let sponsor = fund_sponsor(&mut context).await;
+    add_co_sponsor(&mut context, creator, &sponsor, 5_000).await;
+
+    let ix = ixs::remove_solutions(creator, ID.to_string(), vec![2])
+        .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&creator),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to remove solutions");
+
+    let (acc, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(challenge.sponsored_lamports, 5_000);
+    assert_eq!(
+        acc.lamports,
+        rent.minimum_balance(acc.data.len()) + 5_000,
+        "only the rent that is no longer needed should be refunded"
+    );
+}
@@ -0,0 +1,233 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    ixs,
+    state::{
+        Challenge, Challenger, Leaderboard, LeaderboardEntry,
+        MAX_LEADERBOARD_ENTRIES,
+    },
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_pda_account, add_started_challenge_with_solutions, get_deserialized,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn entry(score: u32, solved_at_slot: u64) -> LeaderboardEntry {
+    LeaderboardEntry {
+        wallet: Pubkey::new_unique(),
+        score,
+        solved_at_slot,
+    }
+}
+
+fn scores_and_slots(leaderboard: &Leaderboard) -> Vec<(u32, u64)> {
+    leaderboard
+        .entries
+        .iter()
+        .map(|x| (x.score, x.solved_at_slot))
+        .collect()
+}
+
+// -----------------
+// Ordering
+// -----------------
+#[test]
+fn upsert_orders_by_score_then_slot() {
+    let mut leaderboard = Leaderboard::new(Pubkey::new_unique());
+    assert!(leaderboard.upsert(entry(2, 10)));
+    assert!(leaderboard.upsert(entry(5, 20)));
+    assert!(leaderboard.upsert(entry(2, 5)));
+    assert!(leaderboard.upsert(entry(5, 30)));
+    assert!(leaderboard.upsert(entry(1, 1)));
+
+    assert_eq!(
+        scores_and_slots(&leaderboard),
+        vec![(5, 20), (5, 30), (2, 5), (2, 10), (1, 1)]
+    );
+}
+
+#[test]
+fn upsert_replaces_entry_of_same_wallet_only_if_ranked_higher() {
+    let mut leaderboard = Leaderboard::new(Pubkey::new_unique());
+    let first = entry(2, 10);
+    let wallet = first.wallet;
+    leaderboard.upsert(first);
+    leaderboard.upsert(entry(3, 10));
+
+    assert!(!leaderboard.upsert(LeaderboardEntry {
+        wallet,
+        score: 1,
+        solved_at_slot: 20,
+    }));
+    assert!(leaderboard.upsert(LeaderboardEntry {
+        wallet,
+        score: 4,
+        solved_at_slot: 20,
+    }));
+
+    assert_eq!(leaderboard.entries.len(), 2);
+    assert_eq!(leaderboard.entries[0].wallet, wallet);
+    assert_eq!(scores_and_slots(&leaderboard), vec![(4, 20), (3, 10)]);
+}
+
+#[test]
+fn upsert_evicts_lowest_ranked_when_full() {
+    let mut leaderboard = Leaderboard::new(Pubkey::new_unique());
+    for i in 0..MAX_LEADERBOARD_ENTRIES {
+        assert!(leaderboard.upsert(entry(10 + i as u32, 0)));
+    }
+    assert_eq!(leaderboard.entries.len(), MAX_LEADERBOARD_ENTRIES);
+
+    // ranks below all entries and is not added
+    assert!(!leaderboard.upsert(entry(1, 0)));
+    // same score as the lowest entry but solved later is not added either
+    assert!(!leaderboard.upsert(entry(10, 1)));
+
+    // ranks above the lowest entry which is evicted
+    assert!(leaderboard.upsert(entry(11, 0)));
+    assert_eq!(leaderboard.entries.len(), MAX_LEADERBOARD_ENTRIES);
+    let last = leaderboard.entries.last().unwrap();
+    assert_eq!((last.score, last.solved_at_slot), (11, 0));
+    assert!(!leaderboard.entries.iter().any(|x| x.score == 10));
+}
+
+// -----------------
+// Instructions
+// -----------------
+fn add_challenger(
+    context: &mut ProgramTestContext,
+    challenge_pda: Pubkey,
+    solved: &[u8],
+    tries_used: u16,
+    tries_remaining: u8,
+) -> Keypair {
+    let challenger_pair = Keypair::new();
+    let mut challenger = Challenger {
+        authority: challenger_pair.pubkey(),
+        challenge_pda,
+        tries_remaining,
+        tries_used,
+        redeemed: !solved.is_empty(),
+        ..Challenger::default()
+    };
+    for solution_index in solved {
+        challenger.mark_solved(*solution_index);
+    }
+    add_pda_account(context, &challenger);
+    challenger_pair
+}
+
+async fn create_leaderboard(context: &mut ProgramTestContext) {
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_leaderboard(creator, creator, ID.to_string())
+        .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to create leaderboard");
+}
+
+async fn update_leaderboard(
+    context: &mut ProgramTestContext,
+    challenger_pair: &Keypair,
+) -> Result<(), BanksClientError> {
+    let creator = context.payer.pubkey();
+    let ix = ixs::update_leaderboard(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger_pair.pubkey(),
+    )
+    .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, challenger_pair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn update_leaderboard_ranks_by_solves_then_tries_used() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_started_challenge_with_solutions(
+        &mut context,
+        ID,
+        vec!["one", "two"],
+        None,
+    );
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    create_leaderboard(&mut context).await;
+
+    // bought lots of tries but only solved one solution
+    let one = add_challenger(&mut context, challenge_pda, &[0], 1, 200);
+    // solved both solutions but needed more tries than the others
+    let both = add_challenger(&mut context, challenge_pda, &[0, 1], 6, 0);
+    // solved both solutions with fewer tries
+    let both_fast = add_challenger(&mut context, challenge_pda, &[0, 1], 2, 0);
+
+    for challenger in [&one, &both, &both_fast] {
+        update_leaderboard(&mut context, challenger)
+            .await
+            .expect("Failed to update leaderboard");
+    }
+
+    let (leaderboard_pda, _) = Leaderboard::pda_for(&challenge_pda);
+    let (acc, value) =
+        get_deserialized::<Leaderboard>(&mut context, &leaderboard_pda).await;
+    assert_matches!(
+        value,
+        Leaderboard { challenge_pda: cpda, entries } => {
+            assert_eq!(cpda, challenge_pda);
+            assert_eq!(entries.len(), 3);
+            assert_eq!(entries[0].wallet, both_fast.pubkey());
+            assert_eq!(entries[1].wallet, both.pubkey());
+            assert_eq!(entries[2].wallet, one.pubkey());
+            assert_eq!(acc.data.len(), Leaderboard::needed_size(3));
+        }
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn update_leaderboard_for_challenger_that_did_not_redeem() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_started_challenge_with_solutions(
+        &mut context,
+        ID,
+        vec!["one", "two"],
+        None,
+    );
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    create_leaderboard(&mut context).await;
+
+    let challenger = add_challenger(&mut context, challenge_pda, &[], 1, 1);
+    let res = update_leaderboard(&mut context, &challenger).await;
+    assert!(res.is_err());
+}
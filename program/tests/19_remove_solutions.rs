@@ -0,0 +1,184 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, HasPda, HasSize},
+    utils::hash_solutions,
+};
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_pda_account, assert_challenge_error,
+    get_account, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+#[tokio::test]
+async fn remove_solutions_shrinks_account() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(
+        &mut context,
+        ID,
+        vec!["one", "two", "three", "four"],
+        None,
+    );
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let before = get_account(&mut context, &challenge_pda).await;
+
+    let ix = ixs::remove_solutions(creator, ID.to_string(), vec![3, 1])
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to remove solutions");
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(acc.data.len(), value.size());
+    assert_eq!(acc.data.len(), before.data.len() - 2 * 32);
+    assert!(acc.lamports < before.lamports);
+    assert_matches!(
+        value,
+        Challenge {
+            solving: 0,
+            solutions,
+            ..
+        } => {
+            assert_eq!(solutions, hash_solutions(&["one", "three"]));
+        }
+    );
+}
+
+#[tokio::test]
+async fn remove_solutions_before_solving() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        solving: 2,
+        solutions: hash_solutions(&["one", "two", "three", "four"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let ix = ixs::remove_solutions(creator, ID.to_string(), vec![0, 3])
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to remove solutions");
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_matches!(
+        value,
+        Challenge {
+            solving: 1,
+            finished: false,
+            solutions,
+            ..
+        } => {
+            assert_eq!(solutions, hash_solutions(&["two", "three"]));
+        }
+    );
+}
+
+#[tokio::test]
+async fn remove_all_unsolved_solutions_finishes_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        solving: 1,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let ix = ixs::remove_solutions(creator, ID.to_string(), vec![1])
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to remove solutions");
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_matches!(
+        value,
+        Challenge {
+            solving: 1,
+            finished: true,
+            ..
+        }
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn remove_solutions_index_out_of_range() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+
+    let ix = ixs::remove_solutions(creator, ID.to_string(), vec![2])
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn remove_solutions_duplicate_indices() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+
+    let ix = ixs::remove_solutions(creator, ID.to_string(), vec![1, 1])
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn remove_solutions_after_challengers_were_admitted() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        admissions: 1,
+        solving: 1,
+        solutions: hash_solutions(&["one", "two", "three"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let ix = ixs::remove_solutions(creator, ID.to_string(), vec![0])
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(
+        res,
+        ChallengeError::ChallengeHasAdmittedChallengers,
+    );
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    ixs,
+    state::{Challenge, HasPda, HasSize},
+    utils::hash_solutions,
+};
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_pda_account, get_account,
+    get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+#[tokio::test]
+async fn replace_with_more_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let before = get_account(&mut context, &challenge_pda).await;
+
+    let ix = ixs::replace_solutions(
+        creator,
+        creator,
+        ID.to_string(),
+        vec!["uno", "dos", "tres"],
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to replace solutions");
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(acc.data.len(), value.size());
+    assert!(acc.lamports > before.lamports);
+    assert_matches!(
+        value,
+        Challenge { solutions, .. } => {
+            assert_eq!(solutions, hash_solutions(&["uno", "dos", "tres"]));
+        }
+    );
+}
+
+#[tokio::test]
+async fn replace_with_fewer_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(
+        &mut context,
+        ID,
+        vec!["one", "two", "three"],
+        None,
+    );
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let before = get_account(&mut context, &challenge_pda).await;
+
+    let ix =
+        ixs::replace_solutions(creator, creator, ID.to_string(), vec!["uno"])
+            .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to replace solutions");
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(acc.data.len(), value.size());
+    assert!(acc.lamports < before.lamports);
+    assert_matches!(
+        value,
+        Challenge { solutions, .. } => {
+            assert_eq!(solutions, hash_solutions(&["uno"]));
+        }
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn replace_with_empty_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let ix = ixs::replace_solutions(creator, creator, ID.to_string(), vec![])
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn replace_after_solution_was_redeemed() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        solving: 1,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let ix =
+        ixs::replace_solutions(creator, creator, ID.to_string(), vec!["uno"])
+            .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(value.solutions, hash_solutions(&["one", "two"]));
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    state::{Challenge, HasPda},
+    utils::hash_solutions,
+};
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_pda_account, get_account, process_ixs,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+#[tokio::test]
+async fn close_challenge_not_started() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let challenge_lamports =
+        get_account(&mut context, &challenge_pda).await.lamports;
+
+    let recipient = Keypair::new().pubkey();
+    let ix = ixs::close_challenge(creator, ID.to_string(), recipient)
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to close challenge");
+
+    let closed = context
+        .banks_client
+        .get_account(challenge_pda)
+        .await
+        .expect("get_account");
+    assert!(closed.is_none());
+
+    let recipient_acc = get_account(&mut context, &recipient).await;
+    assert_eq!(recipient_acc.lamports, challenge_lamports);
+}
+
+#[tokio::test]
+async fn close_challenge_finished() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        finished: true,
+        tries_per_admit: 1,
+        solving: 1,
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let ix = ixs::close_challenge(creator, ID.to_string(), creator)
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to close challenge");
+
+    let closed = context
+        .banks_client
+        .get_account(challenge.pda().0)
+        .await
+        .expect("get_account");
+    assert!(closed.is_none());
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn close_challenge_in_progress() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let ix = ixs::close_challenge(creator, ID.to_string(), creator)
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn close_challenge_not_signed_by_creator() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let other = Keypair::new();
+    let mut ix = ixs::close_challenge(creator, ID.to_string(), other.pubkey())
+        .expect("failed to create instruction");
+    ix.accounts[0].pubkey = other.pubkey();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &other],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+}
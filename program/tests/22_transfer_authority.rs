@@ -0,0 +1,189 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, ChallengeAuthority},
+    state::{Challenge, HasSize},
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, assert_challenge_error, get_deserialized,
+    process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn transfer_to(
+    context: &mut ProgramTestContext,
+    new_authority: Pubkey,
+) -> Result<(), BanksClientError> {
+    let creator = context.payer.pubkey();
+    let ix = ixs::transfer_authority(
+        creator,
+        creator,
+        creator,
+        ID.to_string(),
+        new_authority,
+    )
+    .expect("failed to create transfer instruction");
+    process_ixs(context, &[ix], &[]).await
+}
+
+#[tokio::test]
+async fn transfer_and_accept_authority() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let new_authority = Keypair::new();
+    transfer_to(&mut context, new_authority.pubkey())
+        .await
+        .expect("Failed to transfer authority");
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(acc.data.len(), value.size());
+    assert_matches!(
+        value,
+        Challenge {
+            authority,
+            pending_authority: Some(pending_authority),
+            original_authority: None,
+            ..
+        } => {
+            assert_eq!(authority, creator);
+            assert_eq!(pending_authority, new_authority.pubkey());
+        }
+    );
+
+    // 1. Accept transfer
+    let ix =
+        ixs::accept_authority(new_authority.pubkey(), creator, ID.to_string())
+            .expect("failed to create accept instruction");
+    process_ixs(&mut context, &[ix], &[&new_authority])
+        .await
+        .expect("Failed to accept authority");
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(acc.data.len(), value.size());
+    assert_matches!(
+        value,
+        Challenge {
+            authority,
+            pending_authority: None,
+            original_authority: Some(original_authority),
+            ..
+        } => {
+            assert_eq!(authority, new_authority.pubkey());
+            assert_eq!(original_authority, creator);
+        }
+    );
+
+    // 2. New authority manages the challenge whose PDA is still derived from the creator
+    let ix = ixs::add_solutions(
+        creator,
+        ChallengeAuthority::transferred(new_authority.pubkey(), creator),
+        ID.to_string(),
+        vec!["two"],
+    )
+    .expect("failed to create add instruction");
+    process_ixs(&mut context, &[ix], &[&new_authority])
+        .await
+        .expect("Failed to add solutions as new authority");
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(value.solutions, hash_solutions(&["one", "two"]));
+
+    // 3. Previous authority no longer can
+    let ix =
+        ixs::add_solutions(creator, creator, ID.to_string(), vec!["three"])
+            .expect("failed to create add instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn cancel_authority_transfer() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let new_authority = Keypair::new();
+    transfer_to(&mut context, new_authority.pubkey())
+        .await
+        .expect("Failed to transfer authority");
+    transfer_to(&mut context, creator)
+        .await
+        .expect("Failed to cancel transfer");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            pending_authority: None,
+            original_authority: None,
+            ..
+        }
+    );
+
+    let ix =
+        ixs::accept_authority(new_authority.pubkey(), creator, ID.to_string())
+            .expect("failed to create accept instruction");
+    let res = process_ixs(&mut context, &[ix], &[&new_authority]).await;
+    assert!(res.is_err());
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn accept_authority_not_pending() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let new_authority = Keypair::new();
+    transfer_to(&mut context, new_authority.pubkey())
+        .await
+        .expect("Failed to transfer authority");
+
+    let impostor = Keypair::new();
+    let ix = ixs::accept_authority(impostor.pubkey(), creator, ID.to_string())
+        .expect("failed to create accept instruction");
+    let res = process_ixs(&mut context, &[ix], &[&impostor]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedAuthorityIncorrect);
+}
+
+#[tokio::test]
+async fn transfer_authority_not_signed_by_authority() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let other = Keypair::new();
+    let ix = ixs::transfer_authority(
+        creator,
+        other.pubkey(),
+        creator,
+        ID.to_string(),
+        other.pubkey(),
+    )
+    .expect("failed to create transfer instruction");
+    let res = process_ixs(&mut context, &[ix], &[&other]).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, Redeem},
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, add_started_challenge_with_solutions,
+    get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+#[tokio::test]
+async fn pause_add_solutions_and_resume() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_started_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let pause_ix = ixs::pause_challenge(creator, creator, ID.to_string())
+        .expect("failed to create pause instruction");
+    let add_ix =
+        ixs::add_solutions(creator, creator, ID.to_string(), vec!["two"])
+            .expect("failed to create add instruction");
+    process_ixs(&mut context, &[pause_ix, add_ix], &[])
+        .await
+        .expect("Failed to pause and add solutions");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            paused: true,
+            solutions,
+            ..
+        } => {
+            assert_eq!(solutions, hash_solutions(&["one", "two"]));
+        }
+    );
+
+    // While paused challengers are not admitted
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, creator, ID, Pubkey::new_unique())
+            .expect("failed to create admit instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+
+    // Once resumed they are
+    let resume_ix = ixs::resume_challenge(creator, creator, ID.to_string())
+        .expect("failed to create resume instruction");
+    let AdmitChallengerIx {
+        ix: admit_ix,
+        challenger_pda,
+        ..
+    } = ixs::admit_challenger(creator, creator, ID, Pubkey::new_unique())
+        .expect("failed to create admit instruction");
+    process_ixs(&mut context, &[resume_ix, admit_ix], &[])
+        .await
+        .expect("Failed to resume and admit challenger");
+
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_matches!(
+        value,
+        Challenger {
+            tries_remaining: 1,
+            ..
+        }
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn redeem_while_paused() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        paused: true,
+        tries_per_admit: 1,
+        redeem: redeem.pda().0,
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &redeem);
+
+    let challenger_pair = Keypair::new();
+    let challenger = Challenger {
+        authority: challenger_pair.pubkey(),
+        challenge_pda: challenge.pda().0,
+        tries_remaining: 1,
+        redeemed: false,
+        ..Challenger::default()
+    };
+    add_pda_account(&mut context, &challenger);
+
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger_pair.pubkey(),
+        "one",
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &challenger_pair],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_matches!(value, Challenge { solving: 0, .. });
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{ixs, state::Challenge};
+use solana_program::{program_option::COption, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+use spl_token::state::Mint;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_account, get_deserialized,
+    process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn add_mint_with_authority(
+    context: &mut ProgramTestContext,
+    mint_authority: Pubkey,
+) -> Pubkey {
+    let mint_address = Pubkey::new_unique();
+    let mint = Mint {
+        mint_authority: COption::Some(mint_authority),
+        supply: 0,
+        decimals: 0,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    add_mint_account(context, &mint_address, &mint);
+    mint_address
+}
+
+#[tokio::test]
+async fn update_all_values() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let redeem = add_mint_with_authority(&mut context, challenge_pda);
+
+    let ix = ixs::update_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        Some(1_000),
+        Some(3),
+        Some(redeem),
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to update challenge");
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            admit_cost: 1_000,
+            tries_per_admit: 3,
+            redeem: updated_redeem,
+            ..
+        } => {
+            assert_eq!(updated_redeem, redeem);
+        }
+    );
+}
+
+#[tokio::test]
+async fn update_some_values() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let ix = ixs::update_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        None,
+        Some(5),
+        None,
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to update challenge");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            admit_cost: 200,
+            tries_per_admit: 5,
+            ..
+        }
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn update_tries_per_admit_to_zero() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let ix = ixs::update_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        None,
+        Some(0),
+        None,
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn update_admit_cost_when_locked() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let lock_ix = ixs::lock_admit_cost(creator, creator, ID.to_string())
+        .expect("failed to create lock instruction");
+    let ix = ixs::update_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        Some(1_000),
+        None,
+        None,
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[lock_ix, ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn update_redeem_to_mint_not_controlled_by_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let redeem = add_mint_with_authority(&mut context, creator);
+
+    let ix = ixs::update_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        None,
+        None,
+        Some(redeem),
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
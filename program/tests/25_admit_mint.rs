@@ -0,0 +1,204 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, HasSize},
+    utils::hash_solutions,
+};
+use solana_program::{program_option::COption, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_account, add_pack_account,
+    add_pda_account, get_deserialized, get_unpacked, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const PAYER_TOKENS: u64 = 1_000;
+
+fn add_admit_mint(context: &mut ProgramTestContext) -> Pubkey {
+    let admit_mint = Pubkey::new_unique();
+    let mint = Mint {
+        mint_authority: COption::Some(Pubkey::new_unique()),
+        supply: PAYER_TOKENS,
+        decimals: 0,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    add_mint_account(context, &admit_mint, &mint);
+    admit_mint
+}
+
+fn add_token_account(
+    context: &mut ProgramTestContext,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let ata = get_associated_token_address(owner, mint);
+    let account = TokenAccount {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        state: AccountState::Initialized,
+        ..TokenAccount::default()
+    };
+    add_pack_account(context, &ata, &account, &spl_token::id());
+    ata
+}
+
+#[tokio::test]
+async fn admit_paying_with_admit_mint_and_withdraw() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let admit_mint = add_admit_mint(&mut context);
+    let payer_ata =
+        add_token_account(&mut context, &creator, &admit_mint, PAYER_TOKENS);
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        admit_mint: Some(admit_mint),
+        tries_per_admit: 1,
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+    let escrow_ata =
+        get_associated_token_address(&challenge.pda().0, &admit_mint);
+
+    // 1. Admit paying in tokens
+    let AdmitChallengerIx {
+        ix, challenger_pda, ..
+    } = ixs::admit_challenger_with_admit_mint(
+        creator,
+        creator,
+        ID,
+        Pubkey::new_unique(),
+        admit_mint,
+    )
+    .expect("failed to create admit instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to admit challenger");
+
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_matches!(
+        value,
+        Challenger {
+            tries_remaining: 1,
+            ..
+        }
+    );
+
+    let (_, escrow) =
+        get_unpacked::<TokenAccount>(&mut context, &escrow_ata).await;
+    assert_eq!(escrow.amount, ADMIT_COST);
+    let (_, payer) =
+        get_unpacked::<TokenAccount>(&mut context, &payer_ata).await;
+    assert_eq!(payer.amount, PAYER_TOKENS - ADMIT_COST);
+
+    // 2. Withdraw admit tokens to the creator
+    let ix = ixs::withdraw_admit_tokens(creator, ID.to_string(), admit_mint)
+        .expect("failed to create withdraw instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to withdraw admit tokens");
+
+    let (_, escrow) =
+        get_unpacked::<TokenAccount>(&mut context, &escrow_ata).await;
+    assert_eq!(escrow.amount, 0);
+    let (_, creator_tokens) =
+        get_unpacked::<TokenAccount>(&mut context, &payer_ata).await;
+    assert_eq!(creator_tokens.amount, PAYER_TOKENS);
+}
+
+#[tokio::test]
+async fn set_and_unset_admit_mint() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let admit_mint = add_admit_mint(&mut context);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let ix =
+        ixs::set_admit_mint(creator, creator, ID.to_string(), Some(admit_mint))
+            .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to set admit mint");
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(acc.data.len(), value.size());
+    assert_eq!(value.admit_mint, Some(admit_mint));
+
+    let ix = ixs::set_admit_mint(creator, creator, ID.to_string(), None)
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to unset admit mint");
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(acc.data.len(), value.size());
+    assert_eq!(value.admit_mint, None);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn admit_without_admit_mint_accounts() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let admit_mint = add_admit_mint(&mut context);
+    add_token_account(&mut context, &creator, &admit_mint, PAYER_TOKENS);
+
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        admit_mint: Some(admit_mint),
+        tries_per_admit: 1,
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, creator, ID, Pubkey::new_unique())
+            .expect("failed to create admit instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn set_admit_mint_when_admit_cost_locked() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let admit_mint = add_admit_mint(&mut context);
+
+    let lock_ix = ixs::lock_admit_cost(creator, creator, ID.to_string())
+        .expect("failed to create lock instruction");
+    let ix =
+        ixs::set_admit_mint(creator, creator, ID.to_string(), Some(admit_mint))
+            .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[lock_ix, ix], &[]).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,353 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, Payout, PrizePool, Redeem},
+    utils::hash_solutions,
+};
+use solana_program::{pubkey::Pubkey, rent::Rent, system_program};
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_to_redeem, add_pda_account,
+    get_account, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_000_000;
+const CHALLENGER_LAMPORTS: u64 = 1_000_000_000;
+
+fn add_challenge_with_prize_pool(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+    solutions: &[&str],
+    payout: Payout,
+) -> Challenge {
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: 1,
+        redeem: redeem.pda().0,
+        has_prize_pool: true,
+        solutions: hash_solutions(solutions),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    add_mint_to_redeem(context, &redeem);
+    add_pda_account(
+        context,
+        &PrizePool {
+            challenge_pda: challenge.pda().0,
+            payout,
+        },
+    );
+    challenge
+}
+
+async fn admit_and_redeem(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+    solution: &str,
+) -> Result<Pubkey, BanksClientError> {
+    let challenger = Keypair::new();
+    context.set_account(
+        &challenger.pubkey(),
+        &AccountSharedData::new(CHALLENGER_LAMPORTS, 0, &system_program::id()),
+    );
+
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+    )
+    .expect("failed to create admit instruction");
+    process_ixs(context, &[ix], &[]).await?;
+
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+        solution,
+    )
+    .expect("failed to create redeem instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &challenger],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await?;
+
+    Ok(challenger.pubkey())
+}
+
+fn pool_rent() -> u64 {
+    Rent::default().minimum_balance(PrizePool::size())
+}
+
+#[test]
+fn payout_amounts() {
+    let pool = |payout| PrizePool {
+        challenge_pda: Pubkey::new_unique(),
+        payout,
+    };
+    assert_eq!(pool(Payout::WinnerTakesAll).payout_amount(900, 3), 0);
+    assert_eq!(pool(Payout::WinnerTakesAll).payout_amount(900, 1), 900);
+    assert_eq!(pool(Payout::PerSolution).payout_amount(900, 3), 300);
+    assert_eq!(pool(Payout::PerSolution).payout_amount(900, 1), 900);
+    assert_eq!(pool(Payout::PerSolution).payout_amount(0, 2), 0);
+}
+
+#[tokio::test]
+async fn create_prize_pool_before_start() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let ix = ixs::create_prize_pool(
+        creator,
+        creator,
+        ID.to_string(),
+        Payout::PerSolution,
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to create prize pool");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(challenge.has_prize_pool);
+
+    let (prize_pool_pda, _) = PrizePool::pda_for(&challenge_pda);
+    let (_, prize_pool) =
+        get_deserialized::<PrizePool>(&mut context, &prize_pool_pda).await;
+    assert_matches!(
+        prize_pool,
+        PrizePool {
+            payout: Payout::PerSolution,
+            ..
+        }
+    );
+    assert_eq!(prize_pool.challenge_pda, challenge_pda);
+}
+
+#[tokio::test]
+async fn redeem_pays_per_solution_and_withdraw_leftover() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = add_challenge_with_prize_pool(
+        &mut context,
+        creator,
+        &["one", "two"],
+        Payout::PerSolution,
+    );
+    let (prize_pool_pda, _) = PrizePool::pda_for(&challenge.pda().0);
+
+    // First challenger is paid half of the single admit fee in the pool
+    let first = admit_and_redeem(&mut context, creator, "one")
+        .await
+        .expect("Failed to admit and redeem");
+    assert_eq!(
+        get_account(&mut context, &first).await.lamports,
+        CHALLENGER_LAMPORTS + ADMIT_COST / 2
+    );
+    assert_eq!(
+        get_account(&mut context, &prize_pool_pda).await.lamports,
+        pool_rent() + ADMIT_COST / 2
+    );
+
+    // Second challenger is paid everything that is left
+    let second = admit_and_redeem(&mut context, creator, "two")
+        .await
+        .expect("Failed to admit and redeem");
+    assert_eq!(
+        get_account(&mut context, &second).await.lamports,
+        CHALLENGER_LAMPORTS + ADMIT_COST / 2 + ADMIT_COST
+    );
+    assert_eq!(
+        get_account(&mut context, &prize_pool_pda).await.lamports,
+        pool_rent()
+    );
+
+    // Challenge is finished and the creator withdraws the leftover rent
+    let ix = ixs::withdraw_prize_pool(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to withdraw prize pool");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert!(challenge.finished);
+    assert!(!challenge.has_prize_pool);
+    assert!(context
+        .banks_client
+        .get_account(prize_pool_pda)
+        .await
+        .expect("get_account")
+        .is_none());
+}
+
+#[tokio::test]
+async fn redeem_winner_takes_all() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = add_challenge_with_prize_pool(
+        &mut context,
+        creator,
+        &["one", "two"],
+        Payout::WinnerTakesAll,
+    );
+    let (prize_pool_pda, _) = PrizePool::pda_for(&challenge.pda().0);
+
+    let first = admit_and_redeem(&mut context, creator, "one")
+        .await
+        .expect("Failed to admit and redeem");
+    assert_eq!(
+        get_account(&mut context, &first).await.lamports,
+        CHALLENGER_LAMPORTS
+    );
+
+    let second = admit_and_redeem(&mut context, creator, "two")
+        .await
+        .expect("Failed to admit and redeem");
+    assert_eq!(
+        get_account(&mut context, &second).await.lamports,
+        CHALLENGER_LAMPORTS + 2 * ADMIT_COST
+    );
+    assert_eq!(
+        get_account(&mut context, &prize_pool_pda).await.lamports,
+        pool_rent()
+    );
+}
+
+#[tokio::test]
+async fn admit_into_prize_pool() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let challenge = add_challenge_with_prize_pool(
+        &mut context,
+        creator,
+        &["one"],
+        Payout::WinnerTakesAll,
+    );
+    let (prize_pool_pda, _) = PrizePool::pda_for(&challenge.pda().0);
+
+    let AdmitChallengerIx {
+        ix, challenger_pda, ..
+    } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        Pubkey::new_unique(),
+    )
+    .expect("failed to create admit instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to admit challenger");
+
+    let (_, challenger) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(challenger.tries_remaining, 1);
+    assert_eq!(
+        get_account(&mut context, &prize_pool_pda).await.lamports,
+        pool_rent() + ADMIT_COST
+    );
+    assert!(context
+        .banks_client
+        .get_account(creator)
+        .await
+        .expect("get_account")
+        .is_none());
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn create_prize_pool_after_start() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let ix = ixs::create_prize_pool(
+        creator,
+        creator,
+        ID.to_string(),
+        Payout::WinnerTakesAll,
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn withdraw_prize_pool_in_progress() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_prize_pool(
+        &mut context,
+        creator,
+        &["one"],
+        Payout::WinnerTakesAll,
+    );
+
+    let ix = ixs::withdraw_prize_pool(creator, ID.to_string())
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn close_challenge_with_prize_pool() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        finished: true,
+        has_prize_pool: true,
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+    add_pda_account(
+        &mut context,
+        &PrizePool {
+            challenge_pda: challenge.pda().0,
+            payout: Payout::WinnerTakesAll,
+        },
+    );
+
+    let ix = ixs::close_challenge(creator, ID.to_string(), creator)
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
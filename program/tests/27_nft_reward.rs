@@ -0,0 +1,192 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    state::{Challenge, Challenger, HasPda, NftReward, Redeem},
+    utils::hash_solutions,
+};
+use solana_program::{
+    instruction::Instruction, program_option::COption, pubkey::Pubkey,
+};
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_token::state::Account as TokenAccount;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_to_redeem, add_pda_account,
+    get_deserialized, get_mint, get_unpacked, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn add_challenge_rewarding_nfts(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+) -> Challenge {
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        redeem: redeem.pda().0,
+        nft_reward: true,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    add_mint_to_redeem(context, &redeem);
+    challenge
+}
+
+fn add_challenger(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+) -> Keypair {
+    let challenger = Keypair::new();
+    add_pda_account(
+        context,
+        &Challenger {
+            authority: challenger.pubkey(),
+            challenge_pda: challenge.pda().0,
+            tries_remaining: 1,
+            redeemed: false,
+            ..Challenger::default()
+        },
+    );
+    challenger
+}
+
+async fn process_signed_by(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn redeem_with_nft_mints_one_per_solution() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let challenge = add_challenge_rewarding_nfts(&mut context, creator);
+
+    for (solution_index, solution) in ["one", "two"].into_iter().enumerate() {
+        let challenger = add_challenger(&mut context, &challenge);
+        let ix = ixs::redeem_with_nft(
+            context.payer.pubkey(),
+            creator,
+            ID,
+            challenger.pubkey(),
+            solution,
+            solution_index as u8,
+        )
+        .expect("failed to create instruction");
+        process_signed_by(&mut context, ix, &challenger)
+            .await
+            .expect("Failed to redeem with nft");
+
+        let nft_reward =
+            NftReward::new(challenge.pda().0, solution_index as u8);
+        let (_, mint) = get_mint(&mut context, &nft_reward.pda).await;
+        assert_eq!(mint.supply, 1);
+        assert_eq!(mint.decimals, 0);
+        assert_eq!(mint.mint_authority, COption::None);
+
+        let (_, nft_ata) = get_unpacked::<TokenAccount>(
+            &mut context,
+            &nft_reward.ata(&challenger.pubkey()),
+        )
+        .await;
+        assert_eq!(nft_ata.amount, 1);
+        assert_eq!(nft_ata.owner, challenger.pubkey());
+    }
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert!(challenge.finished);
+}
+
+#[tokio::test]
+async fn enable_and_disable_nft_reward() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    for enabled in [true, false] {
+        let ix = ixs::set_nft_reward(creator, creator, ID.to_string(), enabled)
+            .expect("failed to create instruction");
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&creator),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .expect("Failed to set nft reward");
+
+        let (_, challenge) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_eq!(challenge.nft_reward, enabled);
+    }
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn redeem_without_nft_accounts() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let challenge = add_challenge_rewarding_nfts(&mut context, creator);
+    let challenger = add_challenger(&mut context, &challenge);
+
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+        "one",
+    )
+    .expect("failed to create instruction");
+    let res = process_signed_by(&mut context, ix, &challenger).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn redeem_with_nft_for_wrong_solution_index() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let challenge = add_challenge_rewarding_nfts(&mut context, creator);
+    let challenger = add_challenger(&mut context, &challenge);
+
+    let ix = ixs::redeem_with_nft(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+        "one",
+        1,
+    )
+    .expect("failed to create instruction");
+    let res = process_signed_by(&mut context, ix, &challenger).await;
+    assert!(res.is_err());
+}
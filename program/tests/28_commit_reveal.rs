@@ -0,0 +1,263 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    state::{
+        Challenge, Challenger, HasPda, Redeem, SolutionCommitment,
+        MIN_REVEAL_SLOT_GAP,
+    },
+    utils::{
+        hash_solution_challenger_sends, hash_solutions, solution_commitment,
+    },
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_to_redeem, add_pda_account,
+    get_deserialized, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const NONCE: [u8; 32] = [7; 32];
+const COMMIT_SLOT: u64 = 100;
+
+struct Setup {
+    context: ProgramTestContext,
+    creator: Pubkey,
+    challenge: Challenge,
+    challenger: Keypair,
+}
+
+async fn setup() -> Setup {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 2,
+        redeem: redeem.pda().0,
+        commit_reveal: true,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &redeem);
+
+    let challenger = Keypair::new();
+    add_pda_account(
+        &mut context,
+        &Challenger {
+            authority: challenger.pubkey(),
+            challenge_pda: challenge.pda().0,
+            tries_remaining: 2,
+            redeemed: false,
+            ..Challenger::default()
+        },
+    );
+
+    context
+        .warp_to_slot(COMMIT_SLOT)
+        .expect("Failed to warp to slot");
+
+    Setup {
+        context,
+        creator,
+        challenge,
+        challenger,
+    }
+}
+
+async fn process_signed_by(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn commit(
+    setup: &mut Setup,
+    solution: &str,
+) -> Result<(), BanksClientError> {
+    let ix = ixs::commit_solution(
+        setup.context.payer.pubkey(),
+        setup.creator,
+        ID,
+        setup.challenger.pubkey(),
+        solution,
+        NONCE,
+    )
+    .expect("failed to create commit instruction");
+    process_signed_by(&mut setup.context, ix, &setup.challenger).await
+}
+
+async fn reveal(
+    setup: &mut Setup,
+    solution: &str,
+    nonce: [u8; 32],
+) -> Result<(), BanksClientError> {
+    let ix = ixs::reveal_solution(
+        setup.context.payer.pubkey(),
+        setup.creator,
+        ID,
+        setup.challenger.pubkey(),
+        solution,
+        nonce,
+    )
+    .expect("failed to create reveal instruction");
+    process_signed_by(&mut setup.context, ix, &setup.challenger).await
+}
+
+fn commitment_pda(setup: &Setup) -> Pubkey {
+    let (challenger_pda, _) = Challenger::shank_pda(
+        &challenge::id(),
+        &setup.challenge.pda().0,
+        &setup.challenger.pubkey(),
+    );
+    SolutionCommitment::pda_for(&challenger_pda).0
+}
+
+#[tokio::test]
+async fn commit_and_reveal_solution() {
+    let mut setup = setup().await;
+
+    commit(&mut setup, "one").await.expect("Failed to commit");
+
+    let commitment_pda = commitment_pda(&setup);
+    let (_, commitment) = get_deserialized::<SolutionCommitment>(
+        &mut setup.context,
+        &commitment_pda,
+    )
+    .await;
+    assert_eq!(
+        commitment.commitment,
+        solution_commitment(
+            &setup.challenger.pubkey(),
+            &hash_solution_challenger_sends("one"),
+            &NONCE
+        )
+    );
+    assert!(commitment.committed_at_slot >= COMMIT_SLOT);
+
+    setup
+        .context
+        .warp_to_slot(commitment.committed_at_slot + MIN_REVEAL_SLOT_GAP)
+        .expect("Failed to warp to slot");
+    reveal(&mut setup, "one", NONCE)
+        .await
+        .expect("Failed to reveal");
+
+    let (_, challenge) = get_deserialized::<Challenge>(
+        &mut setup.context,
+        &setup.challenge.pda().0,
+    )
+    .await;
+    assert_eq!(challenge.solving, 1);
+    assert!(setup
+        .context
+        .banks_client
+        .get_account(commitment_pda)
+        .await
+        .expect("get_account")
+        .is_none());
+}
+
+#[tokio::test]
+async fn enable_and_disable_commit_reveal() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    for required in [true, false] {
+        let ix =
+            ixs::set_commit_reveal(creator, creator, ID.to_string(), required)
+                .expect("failed to create instruction");
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&creator),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .expect("Failed to set commit reveal");
+
+        let (_, challenge) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_eq!(challenge.commit_reveal, required);
+    }
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn redeem_when_commit_reveal_is_required() {
+    let mut setup = setup().await;
+
+    let ix = ixs::redeem(
+        setup.context.payer.pubkey(),
+        setup.creator,
+        ID,
+        setup.challenger.pubkey(),
+        "one",
+    )
+    .expect("failed to create instruction");
+    let res =
+        process_signed_by(&mut setup.context, ix, &setup.challenger).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn reveal_too_early() {
+    let mut setup = setup().await;
+
+    commit(&mut setup, "one").await.expect("Failed to commit");
+    let res = reveal(&mut setup, "one", NONCE).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn reveal_with_wrong_nonce_or_solution() {
+    let mut setup = setup().await;
+
+    commit(&mut setup, "one").await.expect("Failed to commit");
+    setup
+        .context
+        .warp_to_slot(COMMIT_SLOT + 10 * MIN_REVEAL_SLOT_GAP)
+        .expect("Failed to warp to slot");
+
+    let res = reveal(&mut setup, "one", [8; 32]).await;
+    assert!(res.is_err());
+    let res = reveal(&mut setup, "two", NONCE).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn reveal_without_commitment() {
+    let mut setup = setup().await;
+
+    let res = reveal(&mut setup, "one", NONCE).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,172 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    state::{Challenge, Challenger, HasPda, HasSize, HashAlgorithm, Redeem},
+    utils::{hash_solutions, hash_solutions_with_salt},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_to_redeem, add_pda_account,
+    get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const SALT: [u8; 32] = [3; 32];
+
+#[test]
+fn salted_hashes() {
+    assert_eq!(
+        hash_solutions_with_salt(&["one"], None),
+        hash_solutions(&["one"])
+    );
+    assert_ne!(
+        hash_solutions_with_salt(&["one"], Some(&SALT)),
+        hash_solutions(&["one"])
+    );
+    assert_ne!(
+        hash_solutions_with_salt(&["one"], Some(&SALT)),
+        hash_solutions_with_salt(&["one"], Some(&[4; 32]))
+    );
+}
+
+#[tokio::test]
+async fn create_salted_challenge_and_add_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let create_ix = ixs::create_salted_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        100,
+        1,
+        vec!["one"],
+        None,
+        None,
+        Some(SALT),
+        HashAlgorithm::Sha256,
+    )
+    .expect("failed to create instruction");
+    let add_ix = ixs::add_salted_solutions(
+        creator,
+        creator,
+        ID.to_string(),
+        vec!["two"],
+        Some(SALT),
+        HashAlgorithm::Sha256,
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[create_ix, add_ix], &[])
+        .await
+        .expect("Failed to create salted challenge");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (account, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.salt, Some(SALT));
+    assert_eq!(
+        challenge.solutions,
+        hash_solutions_with_salt(&["one", "two"], Some(&SALT))
+    );
+    assert_eq!(account.data.len(), challenge.size());
+}
+
+#[tokio::test]
+async fn redeem_salted_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 2,
+        redeem: redeem.pda().0,
+        salt: Some(SALT),
+        solutions: hash_solutions_with_salt(&["one"], Some(&SALT)),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &redeem);
+
+    let challenger = Keypair::new();
+    add_pda_account(
+        &mut context,
+        &Challenger {
+            authority: challenger.pubkey(),
+            challenge_pda: challenge.pda().0,
+            tries_remaining: 2,
+            redeemed: false,
+            ..Challenger::default()
+        },
+    );
+
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+        "one",
+    )
+    .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &challenger],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to redeem");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(challenge.solving, 1);
+    assert!(challenge.finished);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn merge_challenges_with_different_salts() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    add_pda_account(
+        &mut context,
+        &Challenge {
+            authority: creator,
+            id: "source-id".to_string(),
+            tries_per_admit: 1,
+            redeem: Pubkey::new_unique(),
+            salt: Some(SALT),
+            solutions: hash_solutions_with_salt(&["two"], Some(&SALT)),
+            ..Challenge::default()
+        },
+    );
+
+    let ix = ixs::merge_challenges(
+        creator,
+        creator,
+        ID.to_string(),
+        "source-id".to_string(),
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    state::{Challenge, Challenger, HasPda, HashAlgorithm, Redeem},
+    utils::{
+        hash_solution_challenger_sends_with, hash_solutions,
+        hash_solutions_with_algorithm,
+    },
+};
+use solana_program::{keccak, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_to_redeem, add_pda_account,
+    get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+#[test]
+fn hashes_per_algorithm() {
+    assert_eq!(
+        hash_solutions_with_algorithm(&["one"], None, HashAlgorithm::Sha256),
+        hash_solutions(&["one"])
+    );
+
+    let keccak_sends = keccak::hash(b"one").to_bytes();
+    assert_eq!(
+        hash_solution_challenger_sends_with("one", HashAlgorithm::Keccak256),
+        keccak_sends
+    );
+    assert_eq!(
+        hash_solutions_with_algorithm(&["one"], None, HashAlgorithm::Keccak256),
+        vec![keccak::hash(&keccak_sends).to_bytes()]
+    );
+
+    let blake3 =
+        hash_solutions_with_algorithm(&["one"], None, HashAlgorithm::Blake3);
+    assert_ne!(blake3, hash_solutions(&["one"]));
+}
+
+#[tokio::test]
+async fn create_keccak_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let ix = ixs::create_salted_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        100,
+        1,
+        vec!["one", "two"],
+        None,
+        None,
+        None,
+        HashAlgorithm::Keccak256,
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to create challenge");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.hash_algorithm, HashAlgorithm::Keccak256);
+    assert_eq!(
+        challenge.solutions,
+        hash_solutions_with_algorithm(
+            &["one", "two"],
+            None,
+            HashAlgorithm::Keccak256
+        )
+    );
+}
+
+#[tokio::test]
+async fn redeem_with_hash_for_each_algorithm() {
+    for (idx, algorithm) in [
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Keccak256,
+        HashAlgorithm::Blake3,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let mut context = program_test().start_with_context().await;
+        let creator = Pubkey::new_unique();
+        let id = format!("{}-{}", ID, idx);
+        let salt = [idx as u8; 32];
+        let redeem = Redeem::for_challenge_with(&creator, &id);
+        let challenge = Challenge {
+            authority: creator,
+            id: id.clone(),
+            started: true,
+            tries_per_admit: 1,
+            redeem: redeem.pda().0,
+            salt: Some(salt),
+            hash_algorithm: algorithm,
+            solutions: hash_solutions_with_algorithm(
+                &["one"],
+                Some(&salt),
+                algorithm,
+            ),
+            ..Challenge::default()
+        };
+        add_pda_account(&mut context, &challenge);
+        add_mint_to_redeem(&mut context, &redeem);
+
+        let challenger = Keypair::new();
+        add_pda_account(
+            &mut context,
+            &Challenger {
+                authority: challenger.pubkey(),
+                challenge_pda: challenge.pda().0,
+                tries_remaining: 1,
+                redeemed: false,
+                ..Challenger::default()
+            },
+        );
+
+        let ix = ixs::redeem_with_hash(
+            context.payer.pubkey(),
+            creator,
+            &id,
+            challenger.pubkey(),
+            hash_solution_challenger_sends_with("one", algorithm),
+        )
+        .expect("failed to create instruction");
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &challenger],
+            context.last_blockhash,
+        );
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .expect("Failed to redeem");
+
+        let (_, challenge) =
+            get_deserialized::<Challenge>(&mut context, &challenge.pda().0)
+                .await;
+        assert_eq!(challenge.solving, 1);
+    }
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn merge_challenges_with_different_algorithms() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    add_pda_account(
+        &mut context,
+        &Challenge {
+            authority: creator,
+            id: "source-id".to_string(),
+            tries_per_admit: 1,
+            redeem: Pubkey::new_unique(),
+            hash_algorithm: HashAlgorithm::Blake3,
+            solutions: hash_solutions_with_algorithm(
+                &["two"],
+                None,
+                HashAlgorithm::Blake3,
+            ),
+            ..Challenge::default()
+        },
+    );
+
+    let ix = ixs::merge_challenges(
+        creator,
+        creator,
+        ID.to_string(),
+        "source-id".to_string(),
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
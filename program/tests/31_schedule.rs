@@ -0,0 +1,208 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, HasSize, Redeem},
+    utils::hash_solutions,
+};
+use solana_program::{clock::Clock, instruction::Instruction};
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, get_deserialized, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn process_signed_by(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&[&context.payer], signers].concat(),
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn now(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .expect("Failed to get clock")
+        .unix_timestamp
+}
+
+fn add_scheduled_challenge(
+    context: &mut ProgramTestContext,
+    starts_at: Option<i64>,
+    ends_at: Option<i64>,
+) -> Challenge {
+    let creator = context.payer.pubkey();
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        redeem: redeem.pda().0,
+        starts_at,
+        ends_at,
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    add_mint_to_redeem(context, &redeem);
+    challenge
+}
+
+async fn admit(
+    context: &mut ProgramTestContext,
+    challenger: &Keypair,
+) -> Result<(), BanksClientError> {
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        context.payer.pubkey(),
+        ID,
+        challenger.pubkey(),
+    )
+    .expect("failed to create admit instruction");
+    process_signed_by(context, ix, &[]).await
+}
+
+async fn redeem(
+    context: &mut ProgramTestContext,
+    challenger: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        context.payer.pubkey(),
+        ID,
+        challenger.pubkey(),
+        "one",
+    )
+    .expect("failed to create redeem instruction");
+    process_signed_by(context, ix, &[challenger]).await
+}
+
+#[tokio::test]
+async fn create_challenge_with_schedule() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        100,
+        1,
+        vec!["one"],
+        Some(1_000),
+        Some(2_000),
+    )
+    .expect("failed to create instruction");
+    process_signed_by(&mut context, ix, &[])
+        .await
+        .expect("Failed to create challenge");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (account, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.starts_at, Some(1_000));
+    assert_eq!(challenge.ends_at, Some(2_000));
+    assert_eq!(account.data.len(), challenge.size());
+}
+
+#[tokio::test]
+async fn admit_and_redeem_within_schedule() {
+    let mut context = program_test().start_with_context().await;
+    let now = now(&mut context).await;
+    let challenge = add_scheduled_challenge(
+        &mut context,
+        Some(now - 100),
+        Some(now + 10_000),
+    );
+
+    let challenger = Keypair::new();
+    admit(&mut context, &challenger)
+        .await
+        .expect("Failed to admit");
+    redeem(&mut context, &challenger)
+        .await
+        .expect("Failed to redeem");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(challenge.solving, 1);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn create_challenge_ending_before_it_starts() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        100,
+        1,
+        vec!["one"],
+        Some(2_000),
+        Some(1_000),
+    )
+    .expect("failed to create instruction");
+    let res = process_signed_by(&mut context, ix, &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn admit_before_start() {
+    let mut context = program_test().start_with_context().await;
+    let now = now(&mut context).await;
+    add_scheduled_challenge(&mut context, Some(now + 10_000), None);
+
+    let res = admit(&mut context, &Keypair::new()).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn redeem_after_end() {
+    let mut context = program_test().start_with_context().await;
+    let now = now(&mut context).await;
+    let challenge = add_scheduled_challenge(&mut context, None, Some(now - 1));
+
+    let challenger = Keypair::new();
+    add_pda_account(
+        &mut context,
+        &Challenger {
+            authority: challenger.pubkey(),
+            challenge_pda: challenge.pda().0,
+            tries_remaining: 1,
+            redeemed: false,
+            ..Challenger::default()
+        },
+    );
+
+    let res = redeem(&mut context, &challenger).await;
+    assert!(res.is_err());
+
+    let res = admit(&mut context, &Keypair::new()).await;
+    assert!(res.is_err());
+}
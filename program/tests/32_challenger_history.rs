@@ -0,0 +1,144 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSchema;
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, Redeem},
+    utils::hash_solutions,
+};
+use solana_program::instruction::Instruction;
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, get_deserialized, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const TRIES_PER_ADMIT: u8 = 2;
+const ATTEMPT_SLOT: u64 = 100;
+
+async fn process_signed_by(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&[&context.payer], signers].concat(),
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn redeem(
+    context: &mut ProgramTestContext,
+    challenger: &Keypair,
+    solution: &str,
+) {
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        context.payer.pubkey(),
+        ID,
+        challenger.pubkey(),
+        solution,
+    )
+    .expect("failed to create redeem instruction");
+    process_signed_by(context, ix, &[challenger])
+        .await
+        .expect("Failed to redeem");
+}
+
+#[test]
+fn solved_bitset() {
+    let mut challenger = Challenger::default();
+    assert!(!challenger.has_solved(0));
+
+    challenger.mark_solved(0);
+    challenger.mark_solved(9);
+    challenger.mark_solved(255);
+    assert!(challenger.has_solved(0));
+    assert!(challenger.has_solved(9));
+    assert!(challenger.has_solved(255));
+    assert!(!challenger.has_solved(8));
+    assert_eq!(challenger.solved_count(), 3);
+}
+
+#[test]
+fn borsh_schema() {
+    let container = Challenger::schema_container();
+    assert_eq!(container.declaration, "Challenger");
+}
+
+#[tokio::test]
+async fn admit_redeem_and_reissue_records_history() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let redeem_mint = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: redeem_mint.pda().0,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &redeem_mint);
+
+    // Admit
+    let challenger = Keypair::new();
+    let AdmitChallengerIx {
+        ix, challenger_pda, ..
+    } = ixs::admit_challenger(creator, creator, ID, challenger.pubkey())
+        .expect("failed to create admit instruction");
+    process_signed_by(&mut context, ix, &[])
+        .await
+        .expect("Failed to admit");
+
+    let (_, state) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(state.tries_admitted, TRIES_PER_ADMIT as u16);
+    assert_eq!(state.tries_used, 0);
+    assert_eq!(state.solved_count(), 0);
+
+    // Redeem once incorrectly and once correctly
+    context
+        .warp_to_slot(ATTEMPT_SLOT)
+        .expect("Failed to warp to slot");
+    redeem(&mut context, &challenger, "wrong").await;
+    redeem(&mut context, &challenger, "one").await;
+
+    let (_, state) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(state.tries_remaining, 0);
+    assert_eq!(state.tries_used, 2);
+    assert!(state.has_solved(0));
+    assert!(!state.has_solved(1));
+    assert!(state.last_attempt_slot >= ATTEMPT_SLOT);
+
+    // Reissue and solve the next solution
+    let ix = ixs::reissue_admission(creator, creator, ID, challenger.pubkey())
+        .expect("failed to create reissue instruction");
+    process_signed_by(&mut context, ix, &[])
+        .await
+        .expect("Failed to reissue admission");
+    redeem(&mut context, &challenger, "two").await;
+
+    let (_, state) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(state.tries_admitted, 2 * TRIES_PER_ADMIT as u16);
+    assert_eq!(state.tries_used, 3);
+    assert!(state.has_solved(1));
+    assert_eq!(state.solved_count(), 2);
+}
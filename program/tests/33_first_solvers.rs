@@ -0,0 +1,211 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    state::{
+        Challenge, Challenger, FirstSolver, FirstSolvers, HasPda, HasSize,
+        Redeem,
+    },
+    utils::hash_solutions,
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_to_redeem, add_pda_account,
+    get_deserialized, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn add_challenge_with_first_solvers(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+) -> Challenge {
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        redeem: redeem.pda().0,
+        has_first_solvers: true,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    add_mint_to_redeem(context, &redeem);
+    add_pda_account(context, &FirstSolvers::new(challenge.pda().0, 2));
+    challenge
+}
+
+fn add_challenger(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+) -> Keypair {
+    let challenger = Keypair::new();
+    add_pda_account(
+        context,
+        &Challenger {
+            authority: challenger.pubkey(),
+            challenge_pda: challenge.pda().0,
+            tries_remaining: 1,
+            redeemed: false,
+            ..Challenger::default()
+        },
+    );
+    challenger
+}
+
+async fn process_signed_by(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+#[test]
+fn record_keeps_first_solver() {
+    let first = Pubkey::new_unique();
+    let mut first_solvers = FirstSolvers::new(Pubkey::new_unique(), 1);
+
+    assert!(first_solvers.record(0, first, 10));
+    assert!(!first_solvers.record(0, Pubkey::new_unique(), 11));
+    assert_eq!(
+        first_solvers.solvers[0],
+        FirstSolver {
+            solver: first,
+            solved_at_slot: 10
+        }
+    );
+
+    // grows when solutions were added after the leaderboard was created
+    assert!(first_solvers.record(2, first, 12));
+    assert_eq!(first_solvers.solvers.len(), 3);
+    assert!(!first_solvers.solvers[1].is_solved());
+}
+
+#[tokio::test]
+async fn create_first_solvers() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let ix = ixs::create_first_solvers(creator, creator, ID.to_string())
+        .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&creator),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to create first solvers");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(challenge.has_first_solvers);
+
+    let (account, first_solvers) = get_deserialized::<FirstSolvers>(
+        &mut context,
+        &FirstSolvers::pda_for(&challenge_pda).0,
+    )
+    .await;
+    assert_eq!(first_solvers.challenge_pda, challenge_pda);
+    assert_eq!(first_solvers.solvers.len(), 2);
+    assert!(first_solvers.solvers.iter().all(|x| !x.is_solved()));
+    assert_eq!(account.data.len(), first_solvers.size());
+}
+
+#[tokio::test]
+async fn redeem_records_first_solver() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let challenge = add_challenge_with_first_solvers(&mut context, creator);
+    let challenger = add_challenger(&mut context, &challenge);
+
+    let mut ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+        "one",
+    )
+    .expect("failed to create instruction");
+    ix.accounts
+        .extend(ixs::first_solvers_accounts(&challenge.pda().0));
+    process_signed_by(&mut context, ix, &challenger)
+        .await
+        .expect("Failed to redeem");
+
+    let (_, first_solvers) = get_deserialized::<FirstSolvers>(
+        &mut context,
+        &FirstSolvers::pda_for(&challenge.pda().0).0,
+    )
+    .await;
+    assert_eq!(first_solvers.solvers[0].solver, challenger.pubkey());
+    assert!(first_solvers.solvers[0].solved_at_slot > 0);
+    assert!(!first_solvers.solvers[1].is_solved());
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn redeem_without_first_solvers_account() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let challenge = add_challenge_with_first_solvers(&mut context, creator);
+    let challenger = add_challenger(&mut context, &challenge);
+
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+        "one",
+    )
+    .expect("failed to create instruction");
+    let res = process_signed_by(&mut context, ix, &challenger).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn create_first_solvers_when_it_exists() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    add_pda_account(&mut context, &FirstSolvers::new(challenge_pda, 1));
+
+    let ix = ixs::create_first_solvers(creator, creator, ID.to_string())
+        .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&creator),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    state::{Challenge, HasSize, HashAlgorithm, SolutionsUpload},
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signature::Signer;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn solutions(n: usize) -> Vec<String> {
+    (0..n).map(|x| format!("solution-{}", x)).collect()
+}
+
+fn upload_pda(creator: &Pubkey) -> Pubkey {
+    let (challenge_pda, _) = Challenge::pda_for(creator, ID);
+    SolutionsUpload::pda_for(&challenge_pda).0
+}
+
+#[tokio::test]
+async fn append_solutions_in_chunks() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["existing"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let solutions = solutions(50);
+    let solutions: Vec<&str> = solutions.iter().map(String::as_str).collect();
+    let ixs = ixs::append_solutions_in_chunks(
+        creator,
+        creator,
+        ID.to_string(),
+        &solutions,
+        20,
+        None,
+        HashAlgorithm::Sha256,
+    )
+    .expect("failed to create instructions");
+    assert_eq!(ixs.len(), 3);
+
+    let (first_ixs, last_ix) = ixs.split_at(2);
+    for ix in first_ixs {
+        process_ixs(&mut context, std::slice::from_ref(ix), &[])
+            .await
+            .expect("Failed to append chunk");
+    }
+
+    // solutions are only added once the last chunk arrives
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.solutions.len(), 1);
+    let (_, upload) = get_deserialized::<SolutionsUpload>(
+        &mut context,
+        &upload_pda(&creator),
+    )
+    .await;
+    assert_eq!(upload.next_chunk, 2);
+    assert_eq!(upload.total_chunks, 3);
+    assert_eq!(upload.solutions.len(), 40);
+
+    process_ixs(&mut context, last_ix, &[])
+        .await
+        .expect("Failed to append last chunk");
+
+    let (account, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    let mut expected = hash_solutions(&["existing"]);
+    expected.extend(hash_solutions(&solutions));
+    assert_eq!(challenge.solutions, expected);
+    assert_eq!(account.data.len(), challenge.size());
+    assert!(context
+        .banks_client
+        .get_account(upload_pda(&creator))
+        .await
+        .expect("get_account")
+        .is_none());
+}
+
+#[tokio::test]
+async fn append_single_chunk() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec![], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let ix = ixs::append_solutions_chunk(
+        creator,
+        creator,
+        ID.to_string(),
+        0,
+        1,
+        hash_solutions(&["one", "two"]),
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to append chunk");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.solutions, hash_solutions(&["one", "two"]));
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn append_chunk_out_of_order() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec![], None);
+
+    let chunk = |chunk_index: u8, solution: &str| {
+        ixs::append_solutions_chunk(
+            creator,
+            creator,
+            ID.to_string(),
+            chunk_index,
+            3,
+            hash_solutions(&[solution]),
+        )
+        .expect("failed to create instruction")
+    };
+
+    process_ixs(&mut context, &[chunk(0, "one")], &[])
+        .await
+        .expect("Failed to append first chunk");
+    let res = process_ixs(&mut context, &[chunk(2, "three")], &[]).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn append_chunk_with_index_out_of_range() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec![], None);
+
+    let ix = ixs::append_solutions_chunk(
+        creator,
+        creator,
+        ID.to_string(),
+        2,
+        2,
+        hash_solutions(&["one"]),
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,282 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx},
+    pda::program_data_pda,
+    state::{Challenge, Config, HasPda, Redeem},
+    utils::hash_solutions,
+};
+use solana_program::{
+    bpf_loader_upgradeable, instruction::Instruction, pubkey::Pubkey,
+    system_program,
+};
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_pda_account, assert_challenge_error, get_account, get_deserialized,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_000_000;
+const FEE_BPS: u16 = 250;
+const STARTING_LAMPORTS: u64 = 1_000_000_000;
+
+async fn process_signed_by(
+    context: &mut ProgramTestContext,
+    ixs: &[Instruction],
+    signer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+fn add_funded_account(context: &mut ProgramTestContext) -> Pubkey {
+    let pubkey = Pubkey::new_unique();
+    context.set_account(
+        &pubkey,
+        &AccountSharedData::new(STARTING_LAMPORTS, 0, &system_program::id()),
+    );
+    pubkey
+}
+
+/// Stores the program data account of the challenge program with the given
+/// [upgrade_authority], laid out as the bincode encoded
+/// `UpgradeableLoaderState::ProgramData`.
+fn add_program_data(
+    context: &mut ProgramTestContext,
+    upgrade_authority: Pubkey,
+) {
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(upgrade_authority.as_ref());
+
+    let mut account = AccountSharedData::new(
+        STARTING_LAMPORTS,
+        data.len(),
+        &bpf_loader_upgradeable::id(),
+    );
+    account.set_data(data);
+    context.set_account(&program_data_pda().0, &account);
+}
+
+fn add_started_challenge(context: &mut ProgramTestContext, creator: Pubkey) {
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    add_pda_account(
+        context,
+        &Challenge {
+            authority: creator,
+            id: ID.to_string(),
+            started: true,
+            admit_cost: ADMIT_COST,
+            tries_per_admit: 1,
+            redeem: redeem.pda().0,
+            solutions: hash_solutions(&["one"]),
+            ..Challenge::default()
+        },
+    );
+}
+
+#[test]
+fn fee_amounts() {
+    let config = |fee_bps| Config {
+        admin: Pubkey::new_unique(),
+        fee_bps,
+        fee_destination: Pubkey::new_unique(),
+    };
+    assert_eq!(config(0).fee_for(ADMIT_COST), 0);
+    assert_eq!(config(FEE_BPS).fee_for(ADMIT_COST), 25_000);
+    assert_eq!(config(10_000).fee_for(ADMIT_COST), ADMIT_COST);
+    assert_eq!(config(10_000).fee_for(u64::MAX), u64::MAX);
+}
+
+#[tokio::test]
+async fn init_and_update_config() {
+    let mut context = program_test().start_with_context().await;
+    let admin = Keypair::new();
+    let fee_destination = Pubkey::new_unique();
+    add_program_data(&mut context, admin.pubkey());
+
+    let ix = ixs::init_config(
+        context.payer.pubkey(),
+        admin.pubkey(),
+        FEE_BPS,
+        fee_destination,
+    )
+    .expect("failed to create instruction");
+    process_signed_by(&mut context, &[ix], &admin)
+        .await
+        .expect("Failed to init config");
+
+    let (_, config) =
+        get_deserialized::<Config>(&mut context, &Config::pda().0).await;
+    assert_eq!(config.admin, admin.pubkey());
+    assert_eq!(config.fee_bps, FEE_BPS);
+    assert_eq!(config.fee_destination, fee_destination);
+
+    let new_admin = Pubkey::new_unique();
+    let ix =
+        ixs::update_config(admin.pubkey(), Some(500), None, Some(new_admin))
+            .expect("failed to create instruction");
+    process_signed_by(&mut context, &[ix], &admin)
+        .await
+        .expect("Failed to update config");
+
+    let (_, config) =
+        get_deserialized::<Config>(&mut context, &Config::pda().0).await;
+    assert_eq!(config.admin, new_admin);
+    assert_eq!(config.fee_bps, 500);
+    assert_eq!(config.fee_destination, fee_destination);
+}
+
+#[tokio::test]
+async fn admit_splits_protocol_fee() {
+    let mut context = program_test().start_with_context().await;
+    let creator = add_funded_account(&mut context);
+    let fee_destination = add_funded_account(&mut context);
+    add_started_challenge(&mut context, creator);
+    add_pda_account(
+        &mut context,
+        &Config {
+            admin: Pubkey::new_unique(),
+            fee_bps: FEE_BPS,
+            fee_destination,
+        },
+    );
+
+    let AdmitChallengerIx { mut ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        Pubkey::new_unique(),
+    )
+    .expect("failed to create instruction");
+    ix.accounts
+        .extend(ixs::protocol_fee_accounts(&fee_destination, None));
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to admit challenger");
+
+    let fee = ADMIT_COST * FEE_BPS as u64 / 10_000;
+    assert_eq!(
+        get_account(&mut context, &creator).await.lamports,
+        STARTING_LAMPORTS + ADMIT_COST - fee
+    );
+    assert_eq!(
+        get_account(&mut context, &fee_destination).await.lamports,
+        STARTING_LAMPORTS + fee
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn admit_without_fee_destination_once_config_is_initialized() {
+    let mut context = program_test().start_with_context().await;
+    let creator = add_funded_account(&mut context);
+    add_started_challenge(&mut context, creator);
+    add_pda_account(
+        &mut context,
+        &Config {
+            admin: Pubkey::new_unique(),
+            fee_bps: FEE_BPS,
+            fee_destination: Pubkey::new_unique(),
+        },
+    );
+
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        Pubkey::new_unique(),
+    )
+    .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn init_config_with_fee_above_max() {
+    let mut context = program_test().start_with_context().await;
+    let admin = Keypair::new();
+    add_program_data(&mut context, admin.pubkey());
+
+    let ix = ixs::init_config(
+        context.payer.pubkey(),
+        admin.pubkey(),
+        10_001,
+        Pubkey::new_unique(),
+    )
+    .expect("failed to create instruction");
+    let res = process_signed_by(&mut context, &[ix], &admin).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn init_config_by_non_upgrade_authority() {
+    let mut context = program_test().start_with_context().await;
+    add_program_data(&mut context, Pubkey::new_unique());
+
+    let not_upgrade_authority = Keypair::new();
+    let ix = ixs::init_config(
+        context.payer.pubkey(),
+        not_upgrade_authority.pubkey(),
+        FEE_BPS,
+        not_upgrade_authority.pubkey(),
+    )
+    .expect("failed to create instruction");
+    let res =
+        process_signed_by(&mut context, &[ix], &not_upgrade_authority).await;
+    assert_challenge_error(res, ChallengeError::ProvidedAuthorityIncorrect);
+}
+
+#[tokio::test]
+async fn update_config_by_non_admin() {
+    let mut context = program_test().start_with_context().await;
+    add_pda_account(
+        &mut context,
+        &Config {
+            admin: Pubkey::new_unique(),
+            fee_bps: FEE_BPS,
+            fee_destination: Pubkey::new_unique(),
+        },
+    );
+
+    let not_admin = Keypair::new();
+    let ix = ixs::update_config(not_admin.pubkey(), Some(0), None, None)
+        .expect("failed to create instruction");
+    let res = process_signed_by(&mut context, &[ix], &not_admin).await;
+    assert_challenge_error(res, ChallengeError::ProvidedAuthorityIncorrect);
+}
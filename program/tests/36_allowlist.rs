@@ -0,0 +1,153 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, HasSize, Redeem},
+    utils::{
+        allowlist_proof, allowlist_root, hash_solutions, verify_allowlist_proof,
+    },
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signature::Signer;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_pda_account, get_deserialized,
+    process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn allowlist() -> Vec<Pubkey> {
+    (0..5).map(|_| Pubkey::new_unique()).collect()
+}
+
+fn add_challenge_with_allowlist(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+    allowlist: &[Pubkey],
+) -> Challenge {
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        redeem: Redeem::for_challenge_with(&creator, ID).pda().0,
+        allowlist_root: Some(allowlist_root(allowlist)),
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    challenge
+}
+
+#[test]
+fn allowlist_proofs() {
+    for len in 1..=7 {
+        let allowlist: Vec<Pubkey> =
+            (0..len).map(|_| Pubkey::new_unique()).collect();
+        let root = allowlist_root(&allowlist);
+        for pubkey in &allowlist {
+            let proof = allowlist_proof(&allowlist, pubkey)
+                .expect("pubkey should be on the allowlist");
+            assert!(verify_allowlist_proof(&root, pubkey, &proof));
+            assert!(!verify_allowlist_proof(
+                &root,
+                &Pubkey::new_unique(),
+                &proof
+            ));
+        }
+    }
+    assert!(allowlist_proof(&allowlist(), &Pubkey::new_unique()).is_none());
+}
+
+#[tokio::test]
+async fn set_and_clear_allowlist() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    for root in [Some(allowlist_root(&allowlist())), None] {
+        let ix = ixs::set_allowlist(creator, creator, ID.to_string(), root)
+            .expect("failed to create instruction");
+        process_ixs(&mut context, &[ix], &[])
+            .await
+            .expect("Failed to set allowlist");
+
+        let (account, challenge) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_eq!(challenge.allowlist_root, root);
+        assert_eq!(account.data.len(), challenge.size());
+    }
+}
+
+#[tokio::test]
+async fn admit_challenger_on_allowlist() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let allowlist = allowlist();
+    add_challenge_with_allowlist(&mut context, creator, &allowlist);
+
+    let challenger = allowlist[3];
+    let AdmitChallengerIx {
+        ix, challenger_pda, ..
+    } = ixs::admit_allowlisted_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger,
+        None,
+        allowlist_proof(&allowlist, &challenger).unwrap(),
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to admit challenger");
+
+    let (_, challenger) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(challenger.tries_remaining, 1);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn admit_challenger_not_on_allowlist() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let allowlist = allowlist();
+    add_challenge_with_allowlist(&mut context, creator, &allowlist);
+
+    // proof of another challenger
+    let ix = ixs::admit_allowlisted_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        Pubkey::new_unique(),
+        None,
+        allowlist_proof(&allowlist, &allowlist[0]).unwrap(),
+    )
+    .expect("failed to create instruction")
+    .ix;
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+
+    // no proof at all
+    let ix = ixs::admit_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        allowlist[0],
+    )
+    .expect("failed to create instruction")
+    .ix;
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    state::{Challenge, HasPda, HasSize, Redeem},
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signature::Signer;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_pda_account, get_deserialized,
+    process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn admit(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+) -> Result<(), BanksClientError> {
+    let ix = ixs::admit_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        Pubkey::new_unique(),
+    )
+    .expect("failed to create instruction")
+    .ix;
+    process_ixs(context, &[ix], &[]).await
+}
+
+#[test]
+fn remaining_admissions() {
+    let challenge = |max_admissions, admissions| Challenge {
+        max_admissions,
+        admissions,
+        ..Challenge::default()
+    };
+    assert_eq!(challenge(None, 10).remaining_admissions(), None);
+    assert_eq!(challenge(Some(10), 3).remaining_admissions(), Some(7));
+    assert_eq!(challenge(Some(2), 3).remaining_admissions(), Some(0));
+}
+
+#[tokio::test]
+async fn set_and_clear_max_admissions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    for max_admissions in [Some(100), None] {
+        let ix = ixs::set_max_admissions(
+            creator,
+            creator,
+            ID.to_string(),
+            max_admissions,
+        )
+        .expect("failed to create instruction");
+        process_ixs(&mut context, &[ix], &[])
+            .await
+            .expect("Failed to set max admissions");
+
+        let (account, challenge) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_eq!(challenge.max_admissions, max_admissions);
+        assert_eq!(account.data.len(), challenge.size());
+    }
+}
+
+#[tokio::test]
+async fn admit_up_to_max_admissions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        redeem: Redeem::for_challenge_with(&creator, ID).pda().0,
+        max_admissions: Some(2),
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+
+    for _ in 0..2 {
+        admit(&mut context, creator)
+            .await
+            .expect("Failed to admit challenger");
+    }
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(challenge.admissions, 2);
+    assert_eq!(challenge.remaining_admissions(), Some(0));
+
+    let res = admit(&mut context, creator).await;
+    assert!(res.is_err());
+}
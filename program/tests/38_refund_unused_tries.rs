@@ -0,0 +1,286 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, Payout, PrizePool, Redeem},
+    utils::hash_solutions,
+};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData,
+    signature::{Keypair, Signer},
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, add_started_challenge_with_solutions,
+    assert_challenge_error, get_account, get_deserialized, process_ixs,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_000_000;
+const TRIES_PER_ADMIT: u8 = 4;
+const CHALLENGER_LAMPORTS: u64 = 1_000_000_000;
+
+fn add_challenge_with_prize_pool(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+) -> Challenge {
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: redeem.pda().0,
+        has_prize_pool: true,
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    add_mint_to_redeem(context, &redeem);
+    add_pda_account(
+        context,
+        &PrizePool {
+            challenge_pda: challenge.pda().0,
+            payout: Payout::WinnerTakesAll,
+        },
+    );
+    challenge
+}
+
+async fn admit(context: &mut ProgramTestContext, creator: Pubkey) -> Keypair {
+    let challenger = Keypair::new();
+    context.set_account(
+        &challenger.pubkey(),
+        &AccountSharedData::new(CHALLENGER_LAMPORTS, 0, &system_program::id()),
+    );
+
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+    )
+    .expect("failed to create admit instruction");
+    process_ixs(context, &[ix], &[])
+        .await
+        .expect("failed to admit challenger");
+    challenger
+}
+
+async fn cancel(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+) -> Result<(), BanksClientError> {
+    let ix = ixs::cancel_challenge(creator, ID.to_string())
+        .expect("failed to create cancel instruction");
+    process_ixs(context, &[ix], &[]).await
+}
+
+async fn refund(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+    challenger: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix = ixs::refund_unused_tries(
+        creator,
+        ID.to_string(),
+        challenger.pubkey(),
+        None,
+    )
+    .expect("failed to create refund instruction");
+    process_ixs(context, &[ix], &[challenger]).await
+}
+
+#[test]
+fn refundable_amounts() {
+    let mut challenger = Challenger::default();
+    challenger.grant_tries(4);
+    challenger.record_escrowed(1_000);
+    assert_eq!(challenger.refundable, 1_000);
+
+    // each used try takes its share of what was escrowed
+    challenger.record_attempt(1);
+    assert_eq!(challenger.refundable, 750);
+
+    // bought tries add what was escrowed for them
+    challenger.add_tries(2);
+    challenger.record_escrowed(300);
+    assert_eq!(challenger.refundable, 1_050);
+
+    for slot in 2..7 {
+        challenger.record_attempt(slot);
+    }
+    assert_eq!(challenger.tries_remaining, 0);
+    assert_eq!(challenger.refundable, 0);
+
+    // what was escrowed for replaced tries is not refundable
+    challenger.record_escrowed(1_000);
+    challenger.grant_tries(4);
+    assert_eq!(challenger.refundable, 0);
+}
+
+#[tokio::test]
+async fn refund_unused_tries_of_cancelled_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = add_challenge_with_prize_pool(&mut context, creator);
+    let challenge_pda = challenge.pda().0;
+    let challenger = admit(&mut context, creator).await;
+
+    // 1. Refunds are only available once the challenge is cancelled
+    refund(&mut context, creator, &challenger)
+        .await
+        .expect_err("should not refund before the challenge is cancelled");
+
+    cancel(&mut context, creator)
+        .await
+        .expect("failed to cancel challenge");
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(challenge.paused);
+    assert!(challenge.cancelled);
+
+    // 2. A cancelled challenge cannot be resumed
+    let ix = ixs::resume_challenge(creator, creator, ID.to_string())
+        .expect("failed to create resume instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect_err("should not resume a cancelled challenge");
+
+    // 3. The challenger gets the admit cost back for all unused tries
+    let before = get_account(&mut context, &challenger.pubkey())
+        .await
+        .lamports;
+    refund(&mut context, creator, &challenger)
+        .await
+        .expect("failed to refund unused tries");
+    let after = get_account(&mut context, &challenger.pubkey())
+        .await
+        .lamports;
+    assert_eq!(after - before, ADMIT_COST);
+
+    let (challenger_pda, _) = Challenger::shank_pda(
+        &challenge::challenge_id(),
+        &challenge_pda,
+        &challenger.pubkey(),
+    );
+    let (_, state) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(state.tries_remaining, 0);
+
+    // 4. Tries are only refunded once
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    refund(&mut context, creator, &challenger)
+        .await
+        .expect_err("should not refund twice");
+}
+
+#[tokio::test]
+async fn refund_without_escrow_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_started_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let challenger = admit(&mut context, creator).await;
+
+    cancel(&mut context, creator)
+        .await
+        .expect("failed to cancel challenge");
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(challenge.cancelled);
+
+    refund(&mut context, creator, &challenger)
+        .await
+        .expect_err("should not refund admit cost paid to the creator");
+}
+
+#[tokio::test]
+async fn only_creator_cancels_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Keypair::new();
+    add_challenge_with_prize_pool(&mut context, creator.pubkey());
+
+    let mut ix = ixs::cancel_challenge(creator.pubkey(), ID.to_string())
+        .expect("failed to create cancel instruction");
+    ix.accounts[0].pubkey = context.payer.pubkey();
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedCreatorIncorrect);
+}
+
+#[tokio::test]
+async fn refund_returns_what_was_paid_when_admit_cost_changed() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_prize_pool(&mut context, creator);
+    let challenger = admit(&mut context, creator).await;
+
+    let ix = ixs::update_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        Some(0),
+        None,
+        None,
+    )
+    .expect("failed to create update instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to update admit cost");
+    cancel(&mut context, creator)
+        .await
+        .expect("failed to cancel challenge");
+
+    let before = get_account(&mut context, &challenger.pubkey())
+        .await
+        .lamports;
+    refund(&mut context, creator, &challenger)
+        .await
+        .expect("failed to refund unused tries");
+    let after = get_account(&mut context, &challenger.pubkey())
+        .await
+        .lamports;
+    assert_eq!(after - before, ADMIT_COST);
+}
+
+#[tokio::test]
+async fn updating_or_withdrawing_from_cancelled_challenge_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_prize_pool(&mut context, creator);
+    admit(&mut context, creator).await;
+    cancel(&mut context, creator)
+        .await
+        .expect("failed to cancel challenge");
+
+    let ix = ixs::update_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        Some(0),
+        None,
+        None,
+    )
+    .expect("failed to create update instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ChallengeCancelled);
+
+    let ix = ixs::withdraw_prize_pool(creator, ID.to_string())
+        .expect("failed to create withdraw instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ChallengeCancelled);
+}
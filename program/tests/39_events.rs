@@ -0,0 +1,204 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    challenge_id,
+    events::{parse_logs, ChallengeEvent},
+    ixs,
+    state::Challenge,
+    utils::hash_solution_challenger_sends,
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::program_test;
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn process_ixs_returning_events(
+    context: &mut ProgramTestContext,
+    ixs: &[Instruction],
+) -> Vec<ChallengeEvent> {
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("Failed to process transaction");
+    assert!(result.result.is_ok(), "transaction failed {:?}", result);
+
+    let logs = result.metadata.expect("transaction metadata").log_messages;
+    parse_logs(&logs)
+}
+
+fn program_data(event: &ChallengeEvent) -> String {
+    format!(
+        "Program data: {}",
+        base64::encode(event.try_to_vec().unwrap())
+    )
+}
+
+#[test]
+fn parse_logs_only_decodes_events_of_this_program() {
+    let other_program = Pubkey::new_unique();
+    let challenge_pda = Pubkey::new_unique();
+    let closed = ChallengeEvent::ChallengeClosed { challenge_pda };
+    let admitted = ChallengeEvent::ChallengerAdmitted {
+        challenge_pda,
+        challenger: Pubkey::new_unique(),
+        tries: 3,
+    };
+
+    let logs = vec![
+        format!("Program {} invoke [1]", challenge_id()),
+        "Program log: IX: admit challenger".to_string(),
+        format!("Program {} invoke [2]", other_program),
+        program_data(&closed),
+        format!("Program {} success", other_program),
+        program_data(&admitted),
+        "Program data: not-an-event".to_string(),
+        format!(
+            "Program {} consumed 1000 of 200000 compute units",
+            challenge_id()
+        ),
+        format!("Program {} success", challenge_id()),
+        program_data(&closed),
+    ];
+
+    assert_eq!(parse_logs(&logs), vec![admitted]);
+}
+
+#[tokio::test]
+async fn create_challenge_and_add_solutions_emit_events() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        1000,
+        1,
+        vec!["one"],
+        None,
+        None,
+    )
+    .expect("failed to create instruction");
+    let events = process_ixs_returning_events(&mut context, &[ix]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::ChallengeCreated {
+            challenge_pda,
+            authority: creator,
+            id: ID.to_string(),
+            solutions: 1,
+        }]
+    );
+
+    let ix = ixs::add_solutions(
+        creator,
+        creator,
+        ID.to_string(),
+        vec!["two", "three"],
+    )
+    .expect("failed to create instruction");
+    let events = process_ixs_returning_events(&mut context, &[ix]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::SolutionsAdded {
+            challenge_pda,
+            added: 2,
+            total: 3,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn admit_challenger_emits_event() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenger = Pubkey::new_unique();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let create_ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        1000,
+        2,
+        vec!["one"],
+        None,
+        None,
+    )
+    .expect("failed to create instruction");
+    let start_ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ixs_returning_events(&mut context, &[create_ix, start_ix]).await;
+
+    let ix = ixs::admit_challenger(creator, creator, ID, challenger)
+        .expect("failed to create instruction")
+        .ix;
+    let events = process_ixs_returning_events(&mut context, &[ix]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::ChallengerAdmitted {
+            challenge_pda,
+            challenger,
+            tries: 2,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn incorrect_guess_emits_event_and_burns_try() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let create_ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        1000,
+        2,
+        vec!["one"],
+        None,
+        None,
+    )
+    .expect("failed to create instruction");
+    let start_ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    let admit_ix = ixs::admit_challenger(creator, creator, ID, creator)
+        .expect("failed to create instruction")
+        .ix;
+    process_ixs_returning_events(
+        &mut context,
+        &[create_ix, start_ix, admit_ix],
+    )
+    .await;
+
+    let ix = ixs::redeem(creator, creator, ID, creator, "wrong")
+        .expect("failed to create instruction");
+    let events = process_ixs_returning_events(&mut context, &[ix]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::GuessFailed {
+            challenge_pda,
+            challenger: creator,
+            guess: hash_solution_challenger_sends("wrong"),
+            tries_remaining: 1,
+        }]
+    );
+}
@@ -0,0 +1,285 @@
+#![cfg(all(feature = "test-sbf", feature = "client"))]
+
+use challenge::{
+    challenge_id,
+    client::{
+        challenge_authority_filters, challenge_from_ui_account,
+        challenge_snapshot_from_accounts, challenger_filters,
+        challengers_from_program_accounts, challenges_from_program_accounts,
+        open_challenges,
+    },
+    clock::Now,
+    snapshot::EscrowSnapshot,
+    state::{
+        Challenge, Challenger, HasDiscriminator, HasPda, Leaderboard,
+        CHALLENGER_CHALLENGE_PDA_OFFSET, CHALLENGER_REDEEMED_OFFSET,
+        CHALLENGER_TRIES_REMAINING_OFFSET, CHALLENGE_AUTHORITY_OFFSET,
+        CHALLENGE_VERSION, CHALLENGE_VERSION_OFFSET,
+    },
+};
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use solana_client::rpc_filter::RpcFilterType;
+use solana_program::{
+    program_option::COption, program_pack::Pack, pubkey::Pubkey,
+};
+use solana_sdk::account::Account;
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+
+fn program_account<T: HasDiscriminator>(value: &T) -> Account {
+    Account {
+        lamports: 1,
+        data: value.to_account_data().unwrap(),
+        owner: challenge_id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[test]
+fn decodes_challenges_stored_at_their_pda() {
+    let challenge = Challenge {
+        authority: Pubkey::new_unique(),
+        id: "challenge-id".to_string(),
+        ..Challenge::default()
+    };
+    let (challenge_pda, _) = challenge.pda();
+    let challenger = Challenger {
+        authority: Pubkey::new_unique(),
+        challenge_pda,
+        ..Challenger::default()
+    };
+    let (challenger_pda, _) = challenger.pda();
+
+    let accounts = vec![
+        (challenge_pda, program_account(&challenge)),
+        // valid challenge data that is not stored at its PDA
+        (Pubkey::new_unique(), program_account(&challenge)),
+        (challenger_pda, program_account(&challenger)),
+    ];
+
+    let challenges = challenges_from_program_accounts(accounts.clone());
+    assert_eq!(challenges.len(), 1);
+    assert_eq!(challenges[0].0, challenge_pda);
+    assert_eq!(challenges[0].1.id, challenge.id);
+
+    let challengers = challengers_from_program_accounts(accounts);
+    assert_eq!(challengers.len(), 1);
+    assert_eq!(challengers[0].0, challenger_pda);
+    assert_eq!(challengers[0].1.authority, challenger.authority);
+}
+
+#[test]
+fn filters_match_account_layout() {
+    let challenge_pda = Pubkey::new_unique();
+    let challenger = Challenger {
+        challenge_pda,
+        ..Challenger::default()
+    };
+    let data = challenger.to_account_data().unwrap();
+    assert_eq!(
+        &data[CHALLENGER_CHALLENGE_PDA_OFFSET
+            ..CHALLENGER_CHALLENGE_PDA_OFFSET + 32],
+        challenge_pda.as_ref()
+    );
+
+    for filter in challenger_filters(&challenge_pda) {
+        match filter {
+            RpcFilterType::DataSize(size) => {
+                assert_eq!(size, data.len() as u64)
+            }
+            RpcFilterType::Memcmp(memcmp) => {
+                assert!(memcmp.bytes_match(&data))
+            }
+            _ => panic!("unexpected filter {:?}", filter),
+        }
+    }
+
+    let authority = Pubkey::new_unique();
+    let challenge = Challenge {
+        authority,
+        ..Challenge::default()
+    };
+    let data = challenge.to_account_data().unwrap();
+    for filter in challenge_authority_filters(&authority) {
+        match filter {
+            RpcFilterType::Memcmp(memcmp) => {
+                assert!(memcmp.bytes_match(&data))
+            }
+            _ => panic!("unexpected filter {:?}", filter),
+        }
+    }
+}
+
+#[test]
+fn offsets_match_account_layout() {
+    let authority = Pubkey::new_unique();
+    // variable length fields don't move the fields before them
+    let challenge = Challenge {
+        authority,
+        pending_authority: Some(Pubkey::new_unique()),
+        admins: vec![Pubkey::new_unique()],
+        id: "challenge-id".to_string(),
+        ..Challenge::default()
+    };
+    let data = challenge.to_account_data().unwrap();
+    assert_eq!(data[CHALLENGE_VERSION_OFFSET], CHALLENGE_VERSION);
+    assert_eq!(
+        &data[CHALLENGE_AUTHORITY_OFFSET..CHALLENGE_AUTHORITY_OFFSET + 32],
+        authority.as_ref()
+    );
+
+    let challenger = Challenger {
+        tries_remaining: 7,
+        redeemed: true,
+        ..Challenger::default()
+    };
+    let data = challenger.to_account_data().unwrap();
+    assert_eq!(data[CHALLENGER_TRIES_REMAINING_OFFSET], 7);
+    assert_eq!(data[CHALLENGER_REDEEMED_OFFSET], 1);
+}
+
+#[test]
+fn keeps_only_open_challenges() {
+    let now = Now::new(100, 1_000);
+    let challenge = |id: &str, started: bool, ends_at: Option<i64>| {
+        let challenge = Challenge {
+            id: id.to_string(),
+            started,
+            ends_at,
+            ..Challenge::default()
+        };
+        (challenge.pda().0, challenge)
+    };
+    let challenges = vec![
+        challenge("open", true, None),
+        challenge("not-started", false, None),
+        challenge("ended", true, Some(999)),
+    ];
+
+    let open_challenges = open_challenges(challenges, &now);
+    assert_eq!(open_challenges.len(), 1);
+    assert_eq!(open_challenges[0].1.id, "open");
+}
+
+#[test]
+fn decodes_challenges_from_account_notifications() {
+    let challenge = Challenge {
+        id: "challenge-id".to_string(),
+        solving: 1,
+        ..Challenge::default()
+    };
+    let (challenge_pda, _) = challenge.pda();
+    let notification = |account: &Account| {
+        UiAccount::encode(
+            &challenge_pda,
+            account,
+            UiAccountEncoding::Base64,
+            None,
+            None,
+        )
+    };
+
+    let decoded =
+        challenge_from_ui_account(&notification(&program_account(&challenge)))
+            .expect("should decode challenge");
+    assert_eq!(decoded.id, challenge.id);
+    assert_eq!(decoded.solving, 1);
+
+    // closed challenge
+    let closed = Account {
+        data: vec![],
+        ..program_account(&challenge)
+    };
+    assert!(challenge_from_ui_account(&notification(&closed)).is_none());
+}
+
+fn token_account<T: Pack>(value: &T) -> Account {
+    let mut data = vec![0u8; T::get_packed_len()];
+    T::pack_into_slice(value, &mut data);
+    Account {
+        lamports: 1,
+        data,
+        owner: spl_token::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[test]
+fn snapshot_includes_accounts_of_the_challenge() {
+    let admit_mint = Pubkey::new_unique();
+    let challenge = Challenge {
+        id: "challenge-id".to_string(),
+        redeem: Pubkey::new_unique(),
+        admit_mint: Some(admit_mint),
+        ..Challenge::default()
+    };
+    let (challenge_pda, _) = challenge.pda();
+    let challenger = Challenger {
+        authority: Pubkey::new_unique(),
+        challenge_pda,
+        tries_remaining: 2,
+        ..Challenger::default()
+    };
+    let other_challenger = Challenger {
+        authority: Pubkey::new_unique(),
+        challenge_pda: Pubkey::new_unique(),
+        ..Challenger::default()
+    };
+    let leaderboard = Leaderboard::new(challenge_pda);
+    let escrow_ata = EscrowSnapshot::ata_for(&challenge_pda, &admit_mint);
+    let escrow = TokenAccount {
+        mint: admit_mint,
+        owner: challenge_pda,
+        amount: 300,
+        state: AccountState::Initialized,
+        ..TokenAccount::default()
+    };
+    let redeem_mint = Mint {
+        mint_authority: COption::Some(challenge_pda),
+        supply: 4,
+        is_initialized: true,
+        ..Mint::default()
+    };
+
+    let accounts = vec![
+        (challenger.pda().0, program_account(&challenger)),
+        (other_challenger.pda().0, program_account(&other_challenger)),
+        (leaderboard.pda().0, program_account(&leaderboard)),
+        (escrow_ata, token_account(&escrow)),
+        (challenge.redeem, token_account(&redeem_mint)),
+        (challenge_pda, program_account(&challenge)),
+    ];
+    let snapshot = challenge_snapshot_from_accounts(&challenge_pda, accounts)
+        .expect("should create snapshot");
+
+    assert_eq!(snapshot.challenge.address, challenge_pda);
+    assert_eq!(snapshot.challenge.state.id, challenge.id);
+    assert_eq!(snapshot.challengers.len(), 1);
+    assert_eq!(snapshot.challengers[0].address, challenger.pda().0);
+    assert_eq!(snapshot.challengers[0].state.tries_remaining, 2);
+    assert_eq!(
+        snapshot.leaderboard.map(|x| x.address),
+        Some(leaderboard.pda().0)
+    );
+    assert!(snapshot.prize_pool.is_none());
+    assert_eq!(
+        snapshot.escrow,
+        Some(EscrowSnapshot {
+            address: escrow_ata,
+            mint: admit_mint,
+            amount: 300,
+        })
+    );
+    assert_eq!(snapshot.redeem_supply, 4);
+}
+
+#[test]
+fn snapshot_without_challenge_account_fails() {
+    let challenge_pda = Pubkey::new_unique();
+    assert!(challenge_snapshot_from_accounts::<Account>(
+        &challenge_pda,
+        vec![]
+    )
+    .is_err());
+}
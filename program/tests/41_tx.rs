@@ -0,0 +1,242 @@
+#![cfg(all(feature = "test-sbf", feature = "client"))]
+
+use challenge::{
+    state::{Challenge, Challenger, HashAlgorithm, SolutionNormalization},
+    tx::{
+        self, AdmitChallengerParams, CloseChallengeParams,
+        CreateChallengeParams, RedeemParams, StartChallengeParams, TxOptions,
+        REDEEM_COMPUTE_UNIT_LIMIT,
+    },
+    utils::solutions_from_lines,
+};
+use solana_program::{hash::Hash, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    compute_budget, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{get_deserialized, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn process_signed(
+    context: &mut ProgramTestContext,
+    mut tx: Transaction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend(signers);
+    tx.sign(&all_signers, context.last_blockhash);
+    context.banks_client.process_transaction(tx).await
+}
+
+fn create_params(creator: Pubkey) -> CreateChallengeParams<'static> {
+    CreateChallengeParams {
+        creator,
+        id: ID.to_string(),
+        admit_cost: 1000,
+        tries_per_admit: 1,
+        solutions: vec!["one"],
+        starts_at: None,
+        ends_at: None,
+        salt: None,
+        hash_algorithm: HashAlgorithm::Sha256,
+        solution_normalization: SolutionNormalization::NONE,
+        options: TxOptions::default(),
+    }
+}
+
+#[test]
+fn compute_budget_instructions_are_prepended() {
+    let payer = Pubkey::new_unique();
+    let tx = tx::create_challenge(
+        &payer,
+        CreateChallengeParams {
+            options: TxOptions {
+                compute_unit_limit: Some(300_000),
+                compute_unit_price: Some(10),
+            },
+            ..create_params(payer)
+        },
+        Hash::default(),
+    )
+    .expect("failed to create transaction");
+
+    let program_ids = tx
+        .message
+        .instructions
+        .iter()
+        .map(|ix| *ix.program_id(&tx.message.account_keys))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        program_ids,
+        vec![
+            compute_budget::id(),
+            compute_budget::id(),
+            challenge::challenge_id()
+        ]
+    );
+    assert_eq!(tx.message.account_keys[0], payer);
+
+    let tx =
+        tx::create_challenge(&payer, create_params(payer), Hash::default())
+            .expect("failed to create transaction");
+    assert_eq!(tx.message.instructions.len(), 1);
+
+    let tx = tx::redeem(
+        &payer,
+        RedeemParams {
+            creator: payer,
+            id: ID.to_string(),
+            challenger: Pubkey::new_unique(),
+            solution: "one",
+            solution_normalization: SolutionNormalization::NONE,
+            options: TxOptions::default(),
+        },
+        Hash::default(),
+    )
+    .expect("failed to create transaction");
+    let budget_ix = &tx.message.instructions[0];
+    assert_eq!(
+        *budget_ix.program_id(&tx.message.account_keys),
+        compute_budget::id()
+    );
+    assert_eq!(
+        budget_ix.data,
+        compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(
+            REDEEM_COMPUTE_UNIT_LIMIT
+        )
+        .data
+    );
+}
+
+#[test]
+fn create_challenge_with_normalization_stores_the_rules() {
+    let payer = Pubkey::new_unique();
+    let tx = tx::create_challenge(
+        &payer,
+        CreateChallengeParams {
+            solutions: vec![" One "],
+            solution_normalization: SolutionNormalization::ALL,
+            ..create_params(payer)
+        },
+        Hash::default(),
+    )
+    .expect("failed to create transaction");
+    assert_eq!(tx.message.instructions.len(), 2);
+
+    let normalized =
+        tx::create_challenge(&payer, create_params(payer), Hash::default())
+            .expect("failed to create transaction");
+    // " One " hashes the same as "one" once normalized
+    assert_eq!(
+        tx.message.instructions[0].data,
+        normalized.message.instructions[0].data
+    );
+}
+
+#[test]
+fn solutions_file_has_one_solution_per_line() {
+    let text = "one\n  two \n\n\tthree\r\n";
+    assert_eq!(solutions_from_lines(text), vec!["one", "two", "three"]);
+    assert!(solutions_from_lines("\n \n").is_empty());
+}
+
+#[test]
+fn close_challenge_needs_payer_and_creator_to_sign() {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let tx = tx::close_challenge(
+        &payer,
+        CloseChallengeParams {
+            creator,
+            id: ID.to_string(),
+            recipient: creator,
+            options: TxOptions::default(),
+        },
+        Hash::default(),
+    )
+    .expect("failed to create transaction");
+    assert_eq!(tx.message.header.num_required_signatures, 2);
+    assert_eq!(tx.message.account_keys[..2], [payer, creator]);
+}
+
+#[tokio::test]
+async fn create_start_admit_and_redeem_via_transactions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenger = Keypair::new();
+    let blockhash = context.last_blockhash;
+
+    let tx = tx::create_challenge(&creator, create_params(creator), blockhash)
+        .expect("failed to create transaction");
+    process_signed(&mut context, tx, &[])
+        .await
+        .expect("failed to create challenge");
+
+    let tx = tx::start_challenge(
+        &creator,
+        StartChallengeParams {
+            creator,
+            id: ID.to_string(),
+            options: TxOptions::default(),
+        },
+        blockhash,
+    )
+    .expect("failed to create transaction");
+    process_signed(&mut context, tx, &[])
+        .await
+        .expect("failed to start challenge");
+
+    let tx = tx::admit_challenger(
+        &creator,
+        AdmitChallengerParams {
+            creator,
+            id: ID.to_string(),
+            challenger: challenger.pubkey(),
+            admit_mint: None,
+            options: TxOptions::default(),
+        },
+        blockhash,
+    )
+    .expect("failed to create transaction");
+    process_signed(&mut context, tx, &[])
+        .await
+        .expect("failed to admit challenger");
+
+    let tx = tx::redeem(
+        &creator,
+        RedeemParams {
+            creator,
+            id: ID.to_string(),
+            challenger: challenger.pubkey(),
+            solution: "one",
+            solution_normalization: SolutionNormalization::NONE,
+            options: TxOptions::default(),
+        },
+        blockhash,
+    )
+    .expect("failed to create transaction");
+    process_signed(&mut context, tx, &[&challenger])
+        .await
+        .expect("failed to redeem");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(challenge.finished);
+
+    let (challenger_pda, _) = Challenger::shank_pda(
+        &challenge::challenge_id(),
+        &challenge_pda,
+        &challenger.pubkey(),
+    );
+    let (_, challenger) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert!(challenger.redeemed);
+}
@@ -0,0 +1,261 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    challenge_id, ixs,
+    state::{
+        AccountKind, Challenge, ChallengeMetadata, ChallengeTemplate,
+        Challenger, Config, CreatorRegistry, FirstSolvers, HasDiscriminator,
+        HasPda, HasSize, Leaderboard, PrizePool, RevealedHints,
+        SolutionCommitment, SolutionsUpload, SponsorLog, Winners,
+        CHALLENGE_VERSION, DISCRIMINATOR_LEN,
+    },
+    utils::hash_solutions,
+};
+use solana_program::{hash::hash, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{account::AccountSharedData, rent::Rent, signature::Signer};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_pda_account, get_account, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+/// Adds the account the way it was stored before discriminators were introduced.
+fn add_legacy_pda_account<T: HasPda + BorshSerialize>(
+    context: &mut ProgramTestContext,
+    value: &T,
+) -> Pubkey {
+    let (address, _) = value.pda();
+    add_legacy_account(context, address, value.try_to_vec().unwrap());
+    address
+}
+
+/// Adds the challenge the way it was stored before discriminators and versions were
+/// introduced, i.e. without the leading version and bump bytes, without admins and without
+/// solve mode, rewards, hints, attempt cooldown, max winners, solution normalization, the
+/// weak solutions flag, ticket admission, flags and seed.
+fn add_legacy_challenge_account(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+) -> Pubkey {
+    let (address, _) = challenge.pda();
+    let mut data = challenge.try_to_vec().unwrap();
+    // the empty hints Vec follows the solutions
+    data.truncate(data.len() - 4);
+    // the hint fee, the attempt cooldown, the max winners, the solution normalization, the
+    // weak solutions flag, ticket admission, flags, the tags of the unset seed and max tries,
+    // the sponsored lamports, the tag of the unset prerequisite, the epochs and the empty
+    // rewards Vec directly precede the solutions
+    let rewards_end = data.len() - (4 + challenge.solutions.len() * 32);
+    data.drain(
+        rewards_end
+            - (8 + 8 + 1 + 4 + 1 + 1 + 4 + 1 + 1 + 8 + 1 + 8 + 8 + 1 + 8 + 4)
+            ..rewards_end,
+    );
+    // the solve mode and solved bitset are followed by the tags of the unset solve window
+    // and schedule, the oracle, solutions_locked and the solutions
+    let solve_mode_end =
+        data.len() - (4 + challenge.solutions.len() * 32) - 1 - 32 - 4;
+    data.drain(solve_mode_end - (1 + 32)..solve_mode_end);
+    // version, bump, authority and the tags of the unset pending and original authorities
+    // precede the empty admins Vec
+    let admins_offset = 1 + 1 + 32 + 1 + 1;
+    data.drain(admins_offset..admins_offset + 4);
+    add_legacy_account(context, address, data[2..].to_vec());
+    address
+}
+
+fn add_legacy_account(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+    data: Vec<u8>,
+) {
+    let lamports = Rent::default().minimum_balance(data.len());
+
+    let mut account =
+        AccountSharedData::new(lamports, data.len(), &challenge_id());
+    account.set_data(data);
+    context.set_account(&address, &account);
+}
+
+fn challenge(creator: Pubkey) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        admit_cost: 200,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    }
+}
+
+fn assert_discriminator_of<T: HasDiscriminator>(name: &str) {
+    let expected = hash(format!("account:{}", name).as_bytes()).to_bytes();
+    assert_eq!(
+        T::DISCRIMINATOR[..],
+        expected[..DISCRIMINATOR_LEN],
+        "discriminator of {}",
+        name
+    );
+}
+
+#[test]
+fn discriminators_are_derived_from_account_names() {
+    assert_discriminator_of::<Challenge>("Challenge");
+    assert_discriminator_of::<Challenger>("Challenger");
+    assert_discriminator_of::<SolutionCommitment>("SolutionCommitment");
+    assert_discriminator_of::<Config>("Config");
+    assert_discriminator_of::<FirstSolvers>("FirstSolvers");
+    assert_discriminator_of::<Leaderboard>("Leaderboard");
+    assert_discriminator_of::<PrizePool>("PrizePool");
+    assert_discriminator_of::<SolutionsUpload>("SolutionsUpload");
+    assert_discriminator_of::<SponsorLog>("SponsorLog");
+    assert_discriminator_of::<ChallengeTemplate>("ChallengeTemplate");
+    assert_discriminator_of::<RevealedHints>("RevealedHints");
+    assert_discriminator_of::<Winners>("Winners");
+    assert_discriminator_of::<ChallengeMetadata>("ChallengeMetadata");
+    assert_discriminator_of::<CreatorRegistry>("CreatorRegistry");
+}
+
+#[test]
+fn account_data_round_trip() {
+    let challenge = challenge(Pubkey::new_unique());
+    let data = challenge.to_account_data().unwrap();
+    assert_eq!(data.len(), challenge.size());
+    assert_eq!(data[..DISCRIMINATOR_LEN], Challenge::DISCRIMINATOR);
+
+    let decoded = Challenge::try_from_account_data(&data).unwrap();
+    assert_eq!(decoded.id, challenge.id);
+
+    assert!(Challenger::try_from_account_data(&data).is_err());
+    assert!(
+        Challenge::try_from_account_data(&data[DISCRIMINATOR_LEN..]).is_err()
+    );
+}
+
+#[tokio::test]
+async fn challenger_passed_as_challenge_is_rejected() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    // a challenger account stored at the challenge address
+    let challenger = Challenger {
+        authority: creator,
+        challenge_pda,
+        ..Challenger::default()
+    };
+    let mut account = AccountSharedData::new(
+        Rent::default().minimum_balance(Challenger::size()),
+        Challenger::size(),
+        &challenge_id(),
+    );
+    account.set_data(challenger.to_account_data().unwrap());
+    context.set_account(&challenge_pda, &account);
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect_err("should not accept a challenger as challenge");
+}
+
+#[tokio::test]
+async fn migrate_legacy_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator);
+    let challenge_pda = add_legacy_challenge_account(&mut context, &challenge);
+
+    // 1. Legacy accounts are rejected until they are migrated
+    let start_ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ixs(&mut context, std::slice::from_ref(&start_ix), &[])
+        .await
+        .expect_err("should not accept a legacy challenge");
+
+    // 2. Migrating with the wrong kind fails since the state is not stored at its PDA
+    let ix = ixs::migrate_account(creator, challenge_pda, AccountKind::Config)
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect_err("should not migrate as the wrong kind");
+
+    // 3. Migrating prefixes the discriminator
+    let ix =
+        ixs::migrate_account(creator, challenge_pda, AccountKind::Challenge)
+            .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to migrate challenge");
+
+    let (account, migrated) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(account.data.len(), challenge.size());
+    assert_eq!(migrated.version, CHALLENGE_VERSION);
+    assert_eq!(migrated.id, challenge.id);
+    assert_eq!(migrated.solutions, challenge.solutions);
+
+    // 4. Migrated accounts are accepted
+    process_ixs(&mut context, &[start_ix], &[])
+        .await
+        .expect("failed to start migrated challenge");
+    let (_, started) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(started.started);
+
+    // 5. Accounts are only migrated once
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    let ix =
+        ixs::migrate_account(creator, challenge_pda, AccountKind::Challenge)
+            .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect_err("should not migrate twice");
+}
+
+#[tokio::test]
+async fn migrated_accounts_stay_rent_exempt() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator);
+    add_pda_account(&mut context, &challenge);
+
+    let challenger = Challenger {
+        authority: Pubkey::new_unique(),
+        challenge_pda: challenge.pda().0,
+        tries_remaining: 1,
+        ..Challenger::default()
+    };
+    let challenger_pda = add_legacy_pda_account(&mut context, &challenger);
+
+    let ix =
+        ixs::migrate_account(creator, challenger_pda, AccountKind::Challenger)
+            .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to migrate challenger");
+
+    let account = get_account(&mut context, &challenger_pda).await;
+    assert_eq!(account.data.len(), Challenger::size());
+    assert!(
+        account.lamports >= Rent::default().minimum_balance(Challenger::size())
+    );
+
+    let (_, migrated) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(migrated.authority, challenger.authority);
+    assert_eq!(migrated.tries_remaining, 1);
+}
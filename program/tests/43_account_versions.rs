@@ -0,0 +1,997 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    challenge_id, ixs,
+    state::{
+        AccountKind, Challenge, HasDiscriminator, HasPda, HasSize,
+        SolutionNormalization, SolveMode, ADMINLESS_CHALLENGE_VERSION,
+        ALLOW_REFUNDS, BUMPLESS_CHALLENGE_VERSION, CHALLENGE_VERSION,
+        DEFAULT_CHALLENGE_FLAGS, DISCRIMINATOR_LEN, LEGACY_CHALLENGE_VERSION,
+        SEQUENTIAL_CHALLENGE_VERSION, SINGLE_WINNER_CHALLENGE_VERSION,
+        UNACCOUNTED_CHALLENGE_VERSION, UNCAPPED_CHALLENGE_VERSION,
+        UNFLAGGED_CHALLENGE_VERSION, UNGATED_CHALLENGE_VERSION,
+        UNHINTED_CHALLENGE_VERSION, UNNORMALIZED_CHALLENGE_VERSION,
+        UNREWARDED_CHALLENGE_VERSION, UNSCREENED_CHALLENGE_VERSION,
+        UNSEASONED_CHALLENGE_VERSION, UNSEEDED_CHALLENGE_VERSION,
+        UNTHROTTLED_CHALLENGE_VERSION, UNTICKETED_CHALLENGE_VERSION,
+    },
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::{account::AccountSharedData, rent::Rent, signature::Signer};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{get_deserialized, process_ixs, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn challenge(creator: Pubkey) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        admit_cost: 200,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    }
+    .with_pda_bump(Challenge::pda_for(&creator, ID).1)
+}
+
+/// Account data of the challenge stored with the given layout [version].
+fn account_data_with_version(challenge: &Challenge, version: u8) -> Vec<u8> {
+    let mut data = challenge.to_account_data().unwrap();
+    data[DISCRIMINATOR_LEN] = version;
+    data
+}
+
+/// Offset of the admins Vec in the serialized challenge which is preceded by version, bump,
+/// authority and the tags of the unset pending and original authorities.
+const ADMINS_OFFSET: usize = 1 + 1 + 32 + 1 + 1;
+
+/// Offset of the rewards Vec in the serialized [challenge] which precedes the solutions.
+fn rewards_start(challenge: &Challenge, data: &[u8]) -> usize {
+    data.len()
+        - (4 + challenge.solutions.len() * 32)
+        - (4 + challenge.rewards.len() * 8)
+}
+
+/// Offset of the rewards Vec in the serialized [challenge] which still includes the hints
+/// Vec following the solutions.
+fn rewards_start_before_hints(challenge: &Challenge, data: &[u8]) -> usize {
+    let hints_len = 4 + challenge.hints.len() * 64;
+    rewards_start(challenge, &data[..data.len() - hints_len])
+}
+
+/// Serialized [challenge] stored before challenges could recur in epochs, i.e. without the
+/// epoch slots, start slot, solutions per epoch and epoch which directly precede the rewards.
+fn without_epochs(challenge: &Challenge, mut data: Vec<u8>) -> Vec<u8> {
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - (8 + 8 + 1 + 8)..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before admissions could require a prerequisite, i.e.
+/// without the tag of the unset prerequisite which directly precedes the rewards, and
+/// without the epochs which were introduced later.
+fn without_prerequisite(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_epochs(challenge, data);
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - 1..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before sponsored lamports were accounted, i.e. without the
+/// sponsored lamports which directly precede the rewards, and without the prerequisite
+/// which was introduced later.
+fn without_sponsored_lamports(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_prerequisite(challenge, data);
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - 8..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before tries per challenger could be capped, i.e. without
+/// the tag of the unset cap which directly precedes the rewards, and without the sponsored
+/// lamports which were introduced later.
+fn without_max_tries(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_sponsored_lamports(challenge, data);
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - 1..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before PDAs could be derived from a user chosen seed, i.e.
+/// without the tag of the unset seed which directly precedes the rewards, and without the
+/// cap of tries which was introduced later.
+fn without_seed(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_max_tries(challenge, data);
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - 1..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before optional behaviors were stored as flags, i.e.
+/// without the flags which directly precede the rewards, and without the seed which was
+/// introduced later.
+fn without_flags(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_seed(challenge, data);
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - 4..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before admissions could be tickets, i.e. without the
+/// ticket admission flag which directly precedes the rewards, and without the flags which
+/// were introduced later.
+fn without_ticket_admission(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_flags(challenge, data);
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - 1..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before weak solutions could be rejected, i.e. without the
+/// flag which directly precedes the rewards, and without the ticket admission flag which was
+/// introduced later.
+fn without_weak_solutions_flag(
+    challenge: &Challenge,
+    data: Vec<u8>,
+) -> Vec<u8> {
+    let mut data = without_ticket_admission(challenge, data);
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - 1..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before solutions could be normalized, i.e. without the
+/// solution normalization which directly precedes the rewards, and without the weak
+/// solutions flag which was introduced later.
+fn without_normalization(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_weak_solutions_flag(challenge, data);
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - 4..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before solutions could have multiple winners, i.e.
+/// without the max winners per solution which directly precedes the rewards, and without
+/// solution normalization which was introduced later.
+fn without_winners(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_normalization(challenge, data);
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - 1..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before attempt cooldowns were introduced, i.e. without
+/// the attempt cooldown which directly precedes the rewards, and without multiple winners
+/// which were introduced later.
+fn without_cooldown(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_winners(challenge, data);
+    let rewards_start = rewards_start_before_hints(challenge, &data);
+    data.drain(rewards_start - 8..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before hints were introduced, i.e. without the empty hints
+/// Vec following the solutions and without the hint fee which precedes the rewards, and
+/// without attempt cooldowns which were introduced later.
+fn without_hints(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_cooldown(challenge, data);
+    data.truncate(data.len() - 4);
+    let rewards_start = rewards_start(challenge, &data);
+    data.drain(rewards_start - 8..rewards_start);
+    data
+}
+
+/// Serialized [challenge] stored before rewards were introduced, i.e. without the empty
+/// rewards Vec which directly precedes the solutions, and without hints which were
+/// introduced later.
+fn without_rewards(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_hints(challenge, data);
+    let rewards_end = data.len() - (4 + challenge.solutions.len() * 32);
+    data.drain(rewards_end - 4..rewards_end);
+    data
+}
+
+/// Serialized [challenge] stored before solve modes were introduced, i.e. without the solve
+/// mode and solved bitset which are followed by the tags of the unset solve window and
+/// schedule, the oracle, solutions_locked and the solutions, and without rewards which were
+/// introduced later.
+fn without_solve_mode(challenge: &Challenge, data: Vec<u8>) -> Vec<u8> {
+    let mut data = without_rewards(challenge, data);
+    let solve_mode_end =
+        data.len() - (4 + challenge.solutions.len() * 32) - 1 - 32 - 4;
+    data.drain(solve_mode_end - (1 + 32)..solve_mode_end);
+    data
+}
+
+/// Serialized [challenge] stored before admins were introduced, i.e. without the admins Vec
+/// and without solve mode which was introduced later.
+fn without_admins(
+    challenge: &Challenge,
+    data: Vec<u8>,
+    offset: usize,
+) -> Vec<u8> {
+    let mut data = without_solve_mode(challenge, data);
+    let admins_offset = offset + ADMINS_OFFSET;
+    data.drain(admins_offset..admins_offset + 4);
+    data
+}
+
+fn add_challenge_account(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+    data: Vec<u8>,
+) -> Pubkey {
+    let (address, _) = challenge.pda();
+    let lamports = Rent::default().minimum_balance(data.len());
+    let mut account =
+        AccountSharedData::new(lamports, data.len(), &challenge_id());
+    account.set_data(data);
+    context.set_account(&address, &account);
+    address
+}
+
+#[test]
+fn new_challenges_use_the_current_version() {
+    let challenge = challenge(Pubkey::new_unique());
+    assert_eq!(challenge.version, CHALLENGE_VERSION);
+
+    let data = challenge.to_account_data().unwrap();
+    assert_eq!(data[DISCRIMINATOR_LEN], CHALLENGE_VERSION);
+    assert_eq!(Challenge::stored_version(&data), CHALLENGE_VERSION);
+}
+
+#[test]
+fn deserialization_dispatches_on_the_version() {
+    let challenge = challenge(Pubkey::new_unique());
+
+    let outdated =
+        account_data_with_version(&challenge, LEGACY_CHALLENGE_VERSION);
+    assert!(Challenge::try_from_account_data(&outdated).is_err());
+    assert!(Challenge::try_from_account_data_unchecked(&outdated).is_err());
+
+    let unsupported =
+        account_data_with_version(&challenge, CHALLENGE_VERSION + 1);
+    assert!(Challenge::try_from_account_data(&unsupported).is_err());
+}
+
+#[test]
+fn legacy_challenges_are_upgraded_with_defaults() {
+    let challenge = challenge(Pubkey::new_unique());
+    let legacy_data =
+        without_admins(&challenge, challenge.try_to_vec().unwrap(), 0)[2..]
+            .to_vec();
+    assert_eq!(
+        Challenge::stored_version(&legacy_data),
+        LEGACY_CHALLENGE_VERSION
+    );
+
+    let upgraded =
+        Challenge::try_upgrade_from(LEGACY_CHALLENGE_VERSION, &legacy_data)
+            .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.bump, challenge.pda().1);
+    assert_eq!(upgraded.id, challenge.id);
+    assert_eq!(upgraded.solutions, challenge.solutions);
+    assert_eq!(upgraded.size(), challenge.size());
+
+    let current = challenge.try_to_vec().unwrap();
+    assert!(Challenge::try_upgrade_from(CHALLENGE_VERSION, &current).is_err());
+    assert!(
+        Challenge::try_upgrade_from(CHALLENGE_VERSION + 1, &current).is_err()
+    );
+}
+
+/// Account data of the challenge stored before its bump was stored, i.e. without the byte
+/// following the version and without admins.
+fn bumpless_account_data(challenge: &Challenge) -> Vec<u8> {
+    let mut data = without_admins(
+        challenge,
+        account_data_with_version(challenge, BUMPLESS_CHALLENGE_VERSION),
+        DISCRIMINATOR_LEN,
+    );
+    data.remove(DISCRIMINATOR_LEN + 1);
+    data
+}
+
+/// Account data of the challenge stored before admins were introduced.
+fn adminless_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_admins(
+        challenge,
+        account_data_with_version(challenge, ADMINLESS_CHALLENGE_VERSION),
+        DISCRIMINATOR_LEN,
+    )
+}
+
+/// Account data of the challenge stored before it could recur in epochs.
+fn unseasoned_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_epochs(
+        challenge,
+        account_data_with_version(challenge, UNSEASONED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before admissions could require a prerequisite.
+fn ungated_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_prerequisite(
+        challenge,
+        account_data_with_version(challenge, UNGATED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before sponsored lamports were accounted.
+fn unaccounted_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_sponsored_lamports(
+        challenge,
+        account_data_with_version(challenge, UNACCOUNTED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before tries per challenger could be capped.
+fn uncapped_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_max_tries(
+        challenge,
+        account_data_with_version(challenge, UNCAPPED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before PDAs could be derived from a user chosen
+/// seed.
+fn unseeded_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_seed(
+        challenge,
+        account_data_with_version(challenge, UNSEEDED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before optional behaviors were stored as flags.
+fn unflagged_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_flags(
+        challenge,
+        account_data_with_version(challenge, UNFLAGGED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before admissions could be tickets.
+fn unticketed_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_ticket_admission(
+        challenge,
+        account_data_with_version(challenge, UNTICKETED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before weak solutions could be rejected.
+fn unscreened_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_weak_solutions_flag(
+        challenge,
+        account_data_with_version(challenge, UNSCREENED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before solutions could be normalized.
+fn unnormalized_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_normalization(
+        challenge,
+        account_data_with_version(challenge, UNNORMALIZED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before solutions could have multiple winners.
+fn single_winner_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_winners(
+        challenge,
+        account_data_with_version(challenge, SINGLE_WINNER_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before attempt cooldowns were introduced.
+fn unthrottled_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_cooldown(
+        challenge,
+        account_data_with_version(challenge, UNTHROTTLED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before hints were introduced.
+fn unhinted_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_hints(
+        challenge,
+        account_data_with_version(challenge, UNHINTED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before rewards were introduced.
+fn unrewarded_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_rewards(
+        challenge,
+        account_data_with_version(challenge, UNREWARDED_CHALLENGE_VERSION),
+    )
+}
+
+/// Account data of the challenge stored before solve modes were introduced.
+fn sequential_account_data(challenge: &Challenge) -> Vec<u8> {
+    without_solve_mode(
+        challenge,
+        account_data_with_version(challenge, SEQUENTIAL_CHALLENGE_VERSION),
+    )
+}
+
+#[test]
+fn bumpless_challenges_are_upgraded_with_their_bump() {
+    let challenge = challenge(Pubkey::new_unique());
+    let data = bumpless_account_data(&challenge);
+    assert_eq!(Challenge::stored_version(&data), BUMPLESS_CHALLENGE_VERSION);
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        BUMPLESS_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.bump, challenge.pda().1);
+    assert_eq!(upgraded.authority, challenge.authority);
+    assert_eq!(upgraded.id, challenge.id);
+    assert_eq!(upgraded.solutions, challenge.solutions);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn adminless_challenges_are_upgraded_keeping_their_bump() {
+    let challenge = challenge(Pubkey::new_unique());
+    let data = adminless_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        ADMINLESS_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        ADMINLESS_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.bump, challenge.bump);
+    assert_eq!(upgraded.authority, challenge.authority);
+    assert!(upgraded.admins.is_empty());
+    assert_eq!(upgraded.id, challenge.id);
+    assert_eq!(upgraded.admit_cost, challenge.admit_cost);
+    assert_eq!(upgraded.solutions, challenge.solutions);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn sequential_challenges_are_upgraded_to_sequential_solve_mode() {
+    let challenge = Challenge {
+        solving: 1,
+        solutions: hash_solutions(&["one", "two"]),
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = sequential_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        SEQUENTIAL_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        SEQUENTIAL_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.bump, challenge.bump);
+    assert_eq!(upgraded.solve_mode, SolveMode::Sequential);
+    assert_eq!(upgraded.solved, [0; 32]);
+    assert_eq!(upgraded.solving, 1);
+    assert_eq!(upgraded.oracle, challenge.oracle);
+    assert_eq!(upgraded.solutions, challenge.solutions);
+    assert_eq!(upgraded.size(), challenge.size());
+
+    // trailing bytes are rejected
+    let mut trailing = data[DISCRIMINATOR_LEN..].to_vec();
+    trailing.push(0);
+    assert!(Challenge::try_upgrade_from(
+        SEQUENTIAL_CHALLENGE_VERSION,
+        &trailing
+    )
+    .is_err());
+}
+
+#[test]
+fn unrewarded_challenges_are_upgraded_without_rewards() {
+    let challenge = Challenge {
+        solve_mode: SolveMode::AnyOrder,
+        solutions: hash_solutions(&["one", "two"]),
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = unrewarded_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        UNREWARDED_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNREWARDED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.solve_mode, SolveMode::AnyOrder);
+    assert!(upgraded.rewards.is_empty());
+    assert_eq!(upgraded.solutions, challenge.solutions);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn unhinted_challenges_are_upgraded_without_hints() {
+    let challenge = Challenge {
+        rewards: vec![100],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = unhinted_account_data(&challenge);
+    assert_eq!(Challenge::stored_version(&data), UNHINTED_CHALLENGE_VERSION);
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNHINTED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hint_fee, 0);
+    assert!(upgraded.hints.is_empty());
+    assert_eq!(upgraded.solutions, challenge.solutions);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn unthrottled_challenges_are_upgraded_without_cooldown() {
+    let challenge = Challenge {
+        hint_fee: 1_000,
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = unthrottled_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        UNTHROTTLED_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNTHROTTLED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.hint_fee, 1_000);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.attempt_cooldown_slots, 0);
+    assert_eq!(upgraded.solutions, challenge.solutions);
+}
+
+#[test]
+fn single_winner_challenges_are_upgraded_with_one_winner_per_solution() {
+    let challenge = Challenge {
+        attempt_cooldown_slots: 5,
+        rewards: vec![100],
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = single_winner_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        SINGLE_WINNER_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        SINGLE_WINNER_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.attempt_cooldown_slots, 5);
+    assert_eq!(upgraded.max_winners_per_solution, 0);
+    assert_eq!(upgraded.winners_per_solution(), 1);
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn unnormalized_challenges_are_upgraded_without_normalization() {
+    let challenge = Challenge {
+        max_winners_per_solution: 3,
+        rewards: vec![100],
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = unnormalized_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        UNNORMALIZED_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNNORMALIZED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.max_winners_per_solution, 3);
+    assert!(upgraded.solution_normalization.is_none());
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn unscreened_challenges_are_upgraded_accepting_weak_solutions() {
+    let challenge = Challenge {
+        solution_normalization: SolutionNormalization::ALL,
+        rewards: vec![100],
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = unscreened_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        UNSCREENED_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNSCREENED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.solution_normalization, SolutionNormalization::ALL);
+    assert!(!upgraded.reject_weak_solutions);
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn unticketed_challenges_are_upgraded_without_ticket_admission() {
+    let challenge = Challenge {
+        reject_weak_solutions: true,
+        rewards: vec![100],
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = unticketed_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        UNTICKETED_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNTICKETED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert!(upgraded.reject_weak_solutions);
+    assert!(!upgraded.ticket_admission);
+    assert_eq!(upgraded.flags, DEFAULT_CHALLENGE_FLAGS);
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn unflagged_challenges_are_upgraded_with_default_flags() {
+    let challenge = Challenge {
+        ticket_admission: true,
+        flags: ALLOW_REFUNDS,
+        rewards: vec![100],
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = unflagged_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        UNFLAGGED_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNFLAGGED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert!(upgraded.ticket_admission);
+    assert_eq!(upgraded.flags, DEFAULT_CHALLENGE_FLAGS);
+    assert!(upgraded.allows_add_after_start());
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn unseeded_challenges_are_upgraded_without_seed() {
+    let challenge = Challenge {
+        flags: ALLOW_REFUNDS,
+        rewards: vec![100],
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = unseeded_account_data(&challenge);
+    assert_eq!(Challenge::stored_version(&data), UNSEEDED_CHALLENGE_VERSION);
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNSEEDED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.flags, ALLOW_REFUNDS);
+    assert_eq!(upgraded.seed, None);
+    assert_eq!(upgraded.pda(), challenge.pda());
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn uncapped_challenges_are_upgraded_without_cap() {
+    let challenge = Challenge {
+        seed: Some([7; 32]),
+        rewards: vec![100],
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = uncapped_account_data(&challenge);
+    assert_eq!(Challenge::stored_version(&data), UNCAPPED_CHALLENGE_VERSION);
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNCAPPED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.seed, Some([7; 32]));
+    assert_eq!(upgraded.max_tries_per_challenger, None);
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn unaccounted_challenges_are_upgraded_without_sponsored_lamports() {
+    let challenge = Challenge {
+        max_tries_per_challenger: Some(5),
+        rewards: vec![100],
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = unaccounted_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        UNACCOUNTED_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNACCOUNTED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.max_tries_per_challenger, Some(5));
+    assert_eq!(upgraded.sponsored_lamports, 0);
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn ungated_challenges_are_upgraded_without_prerequisite() {
+    let challenge = Challenge {
+        sponsored_lamports: 1_000,
+        rewards: vec![100],
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = ungated_account_data(&challenge);
+    assert_eq!(Challenge::stored_version(&data), UNGATED_CHALLENGE_VERSION);
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNGATED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.sponsored_lamports, 1_000);
+    assert_eq!(upgraded.prerequisite, None);
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[test]
+fn unseasoned_challenges_are_upgraded_without_epochs() {
+    let prerequisite = Pubkey::new_unique();
+    let challenge = Challenge {
+        prerequisite: Some(prerequisite),
+        rewards: vec![100],
+        hints: vec![[1; 64]],
+        ..challenge(Pubkey::new_unique())
+    };
+    let data = unseasoned_account_data(&challenge);
+    assert_eq!(
+        Challenge::stored_version(&data),
+        UNSEASONED_CHALLENGE_VERSION
+    );
+    assert!(Challenge::try_from_account_data(&data).is_err());
+
+    let upgraded = Challenge::try_upgrade_from(
+        UNSEASONED_CHALLENGE_VERSION,
+        &data[DISCRIMINATOR_LEN..],
+    )
+    .unwrap();
+    assert_eq!(upgraded.version, CHALLENGE_VERSION);
+    assert_eq!(upgraded.prerequisite, Some(prerequisite));
+    assert_eq!(upgraded.epoch_slots, 0);
+    assert_eq!(upgraded.solutions_per_epoch, 0);
+    assert_eq!(upgraded.epoch, 0);
+    assert_eq!(upgraded.rewards, vec![100]);
+    assert_eq!(upgraded.hints, challenge.hints);
+    assert_eq!(upgraded.size(), challenge.size());
+}
+
+#[tokio::test]
+async fn adminless_challenge_is_migrated() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator);
+    let data = adminless_account_data(&challenge);
+    let challenge_pda = add_challenge_account(&mut context, &challenge, data);
+
+    let ix =
+        ixs::migrate_account(creator, challenge_pda, AccountKind::Challenge)
+            .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to migrate adminless challenge");
+
+    let (account, migrated) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(account.data.len(), challenge.size());
+    assert_eq!(migrated.version, CHALLENGE_VERSION);
+    assert!(migrated.admins.is_empty());
+}
+
+#[tokio::test]
+async fn unaccounted_challenge_is_migrated_keeping_held_lamports() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator);
+    let data = unaccounted_account_data(&challenge);
+    let challenge_pda = add_challenge_account(&mut context, &challenge, data);
+
+    // lamports held beyond rent may have been contributed by co-sponsors
+    let mut account = context
+        .banks_client
+        .get_account(challenge_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    account.lamports = Rent::default().minimum_balance(challenge.size()) + 500;
+    context.set_account(&challenge_pda, &account.into());
+
+    let ix =
+        ixs::migrate_account(creator, challenge_pda, AccountKind::Challenge)
+            .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to migrate unaccounted challenge");
+
+    let (account, migrated) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(account.data.len(), challenge.size());
+    assert_eq!(migrated.version, CHALLENGE_VERSION);
+    assert_eq!(migrated.sponsored_lamports, 500);
+}
+
+#[tokio::test]
+async fn bumpless_challenge_is_migrated() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator);
+    let data = bumpless_account_data(&challenge);
+    let challenge_pda = add_challenge_account(&mut context, &challenge, data);
+
+    let start_ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ixs(&mut context, std::slice::from_ref(&start_ix), &[])
+        .await
+        .expect_err("should not accept a bumpless challenge");
+
+    let ix =
+        ixs::migrate_account(creator, challenge_pda, AccountKind::Challenge)
+            .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to migrate bumpless challenge");
+
+    let (account, migrated) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(account.data.len(), challenge.size());
+    assert_eq!(migrated.version, CHALLENGE_VERSION);
+    assert_eq!(migrated.bump, challenge.pda().1);
+
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    process_ixs(&mut context, &[start_ix], &[])
+        .await
+        .expect("failed to start migrated challenge");
+}
+
+#[tokio::test]
+async fn unsupported_versions_are_rejected() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator);
+    let data = account_data_with_version(&challenge, CHALLENGE_VERSION + 1);
+    let challenge_pda = add_challenge_account(&mut context, &challenge, data);
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect_err("should not accept an unsupported version");
+
+    let ix =
+        ixs::migrate_account(creator, challenge_pda, AccountKind::Challenge)
+            .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect_err("should not migrate an unsupported version");
+}
+
+#[tokio::test]
+async fn current_version_is_not_migrated() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator);
+    let data = challenge.to_account_data().unwrap();
+    let challenge_pda = add_challenge_account(&mut context, &challenge, data);
+
+    let ix =
+        ixs::migrate_account(creator, challenge_pda, AccountKind::Challenge)
+            .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect_err("should not migrate the current version");
+
+    let (account, stored) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(account.data.len(), challenge.size());
+    assert_eq!(stored.version, CHALLENGE_VERSION);
+}
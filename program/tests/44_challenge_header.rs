@@ -0,0 +1,110 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    challenge_id,
+    state::{Challenge, ChallengeHeader, HasDiscriminator, DISCRIMINATOR_LEN},
+    utils::{hash_solution_challenger_sends, hash_solutions},
+};
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+fn challenge() -> Challenge {
+    Challenge {
+        authority: Pubkey::new_unique(),
+        id: "challenge-id".to_string(),
+        admit_cost: 200,
+        tries_per_admit: 1,
+        started: true,
+        redeem: Pubkey::new_unique(),
+        starts_at: Some(1_000),
+        solutions: hash_solutions(&["one", "two", "three"]),
+        ..Challenge::default()
+    }
+}
+
+#[test]
+fn header_matches_challenge_without_solutions() {
+    let challenge = Challenge {
+        solving: 1,
+        ..challenge()
+    };
+    let data = challenge.to_account_data().unwrap();
+
+    let header = ChallengeHeader::try_from_account_data(&data).unwrap();
+    assert_eq!(header.solutions_len, 3);
+    assert_eq!(header.remaining_solutions(), 2);
+    assert_eq!(header.current_solution, Some(challenge.solutions[1]));
+    assert!(header.challenge.solutions.is_empty());
+    assert_eq!(header.challenge.id, challenge.id);
+    assert_eq!(header.challenge.authority, challenge.authority);
+    assert_eq!(header.challenge.redeem, challenge.redeem);
+    assert_eq!(header.challenge.starts_at, challenge.starts_at);
+    assert_eq!(header.challenge.solving, 1);
+}
+
+#[test]
+fn header_checks_solutions() {
+    let challenge = challenge();
+    let data = challenge.to_account_data().unwrap();
+    let header = ChallengeHeader::try_from_account_data(&data).unwrap();
+    let solution = hash_solution_challenger_sends("one");
+    assert!(header.is_solution_correct(&solution));
+    assert!(!header.is_solution_correct(&[0u8; 32]));
+
+    let solved = Challenge {
+        solving: 3,
+        ..challenge
+    };
+    let data = solved.to_account_data().unwrap();
+    let header = ChallengeHeader::try_from_account_data(&data).unwrap();
+    assert_eq!(header.current_solution, None);
+    assert_eq!(header.remaining_solutions(), 0);
+    assert!(!header.is_solution_correct(&solution));
+}
+
+#[test]
+fn header_rejects_invalid_data() {
+    let data = challenge().to_account_data().unwrap();
+
+    let truncated = &data[..data.len() - 1];
+    assert!(ChallengeHeader::try_from_account_data(truncated).is_err());
+
+    let mut outdated = data.clone();
+    outdated[DISCRIMINATOR_LEN] = 0;
+    assert!(ChallengeHeader::try_from_account_data(&outdated).is_err());
+
+    assert!(
+        ChallengeHeader::try_from_account_data(&data[DISCRIMINATOR_LEN..])
+            .is_err()
+    );
+}
+
+#[test]
+fn header_serializes_without_touching_solutions() {
+    let challenge = challenge();
+    let mut data = challenge.to_account_data().unwrap();
+    let mut header = ChallengeHeader::try_from_account_data(&data).unwrap();
+    header.challenge.solving += 1;
+    header.challenge.finished = true;
+
+    let key = Pubkey::new_unique();
+    let owner = challenge_id();
+    let mut lamports = 1;
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    header.serialize_into_account(&account_info).unwrap();
+    drop(account_info);
+
+    let updated = Challenge::try_from_account_data(&data).unwrap();
+    assert_eq!(updated.solving, 1);
+    assert!(updated.finished);
+    assert_eq!(updated.id, challenge.id);
+    assert_eq!(updated.solutions, challenge.solutions);
+}
@@ -0,0 +1,145 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::ixs;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{program_test, ComputeUnitsBench};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1000;
+const TRIES_PER_ADMIT: u8 = 3;
+
+// Budgets are upper bounds for the compute units each instruction may consume with the
+// accounts used below. Raise them deliberately when an instruction is expected to get more
+// expensive.
+const CREATE_CHALLENGE_BUDGET: u64 = 60_000;
+const ADD_SOLUTIONS_BUDGET: u64 = 25_000;
+const START_CHALLENGE_BUDGET: u64 = 15_000;
+const PAUSE_CHALLENGE_BUDGET: u64 = 15_000;
+const ADMIT_CHALLENGER_BUDGET: u64 = 30_000;
+const REDEEM_INCORRECT_BUDGET: u64 = 25_000;
+const REDEEM_BUDGET: u64 = 80_000;
+
+#[tokio::test]
+async fn instructions_stay_within_compute_unit_budgets() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let payer = context.payer.pubkey();
+    let challenger = Keypair::new();
+    let mut bench = ComputeUnitsBench::default();
+
+    let ix = ixs::create_challenge(
+        payer,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["one", "two"],
+        None,
+        None,
+    )
+    .expect("failed to create instruction");
+    bench
+        .measure(
+            &mut context,
+            "create_challenge",
+            ix,
+            &[],
+            CREATE_CHALLENGE_BUDGET,
+        )
+        .await
+        .expect("failed to create challenge");
+
+    let ix = ixs::add_solutions(payer, creator, ID.to_string(), vec!["three"])
+        .expect("failed to create instruction");
+    bench
+        .measure(&mut context, "add_solutions", ix, &[], ADD_SOLUTIONS_BUDGET)
+        .await
+        .expect("failed to add solutions");
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    bench
+        .measure(
+            &mut context,
+            "start_challenge",
+            ix,
+            &[],
+            START_CHALLENGE_BUDGET,
+        )
+        .await
+        .expect("failed to start challenge");
+
+    let ix = ixs::pause_challenge(payer, creator, ID.to_string())
+        .expect("failed to create instruction");
+    bench
+        .measure(
+            &mut context,
+            "pause_challenge",
+            ix,
+            &[],
+            PAUSE_CHALLENGE_BUDGET,
+        )
+        .await
+        .expect("failed to pause challenge");
+
+    let ix = ixs::resume_challenge(payer, creator, ID.to_string())
+        .expect("failed to create instruction");
+    bench
+        .measure(
+            &mut context,
+            "resume_challenge",
+            ix,
+            &[],
+            PAUSE_CHALLENGE_BUDGET,
+        )
+        .await
+        .expect("failed to resume challenge");
+
+    let ix = ixs::admit_challenger(payer, creator, ID, challenger.pubkey())
+        .expect("failed to create instruction")
+        .ix;
+    bench
+        .measure(
+            &mut context,
+            "admit_challenger",
+            ix,
+            &[],
+            ADMIT_CHALLENGER_BUDGET,
+        )
+        .await
+        .expect("failed to admit challenger");
+
+    let ix = ixs::redeem(payer, creator, ID, challenger.pubkey(), "wrong")
+        .expect("failed to create instruction");
+    bench
+        .measure(
+            &mut context,
+            "redeem (incorrect)",
+            ix,
+            &[&challenger],
+            REDEEM_INCORRECT_BUDGET,
+        )
+        .await
+        .expect("failed to redeem incorrect solution");
+
+    let ix = ixs::redeem(payer, creator, ID, challenger.pubkey(), "one")
+        .expect("failed to create instruction");
+    bench
+        .measure(
+            &mut context,
+            "redeem (correct)",
+            ix,
+            &[&challenger],
+            REDEEM_BUDGET,
+        )
+        .await
+        .expect("failed to redeem");
+
+    bench.assert_within_budgets();
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, Challenger, HasDiscriminator, HasPda, HasSize},
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData,
+    rent::Rent,
+    signature::{Keypair, Signer},
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_pda_account, assert_challenge_error, get_deserialized, process_ixs,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+/// Stores the [value] at its PDA exactly as the program would, except that the account is
+/// owned by the given [owner].
+fn add_pda_account_owned_by<T: HasPda + HasSize + HasDiscriminator>(
+    context: &mut ProgramTestContext,
+    value: &T,
+    owner: &Pubkey,
+) -> Pubkey {
+    let (address, _) = value.pda();
+    let mut account = AccountSharedData::new(
+        Rent::default().minimum_balance(value.size()),
+        value.size(),
+        owner,
+    );
+    account.set_data(value.to_account_data().unwrap());
+    context.set_account(&address, &account);
+    address
+}
+
+fn challenge(creator: Pubkey) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        admit_cost: 200,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    }
+}
+
+#[tokio::test]
+async fn challenge_not_owned_by_program_is_rejected() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator);
+    add_pda_account_owned_by(&mut context, &challenge, &Pubkey::new_unique());
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::IncorrectAccountOwner);
+}
+
+#[tokio::test]
+async fn challenger_not_owned_by_program_is_rejected() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = Challenge {
+        started: true,
+        ..challenge(creator)
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let challenger = Keypair::new();
+    let challenger_state = Challenger {
+        authority: challenger.pubkey(),
+        challenge_pda: challenge.pda().0,
+        tries_remaining: 1,
+        ..Challenger::default()
+    };
+    let challenger_pda = add_pda_account_owned_by(
+        &mut context,
+        &challenger_state,
+        &Pubkey::new_unique(),
+    );
+
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+        "wrong",
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[&challenger])
+        .await
+        .expect_err("should not accept a challenger owned by another program");
+
+    // the challenger is left untouched
+    let account = context
+        .banks_client
+        .get_account(challenger_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        Challenger::try_from_account_data(&account.data)
+            .unwrap()
+            .tries_remaining,
+        1
+    );
+
+    // the same challenger owned by the program is accepted
+    add_pda_account(&mut context, &challenger_state);
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+        "wrong",
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[&challenger])
+        .await
+        .expect("failed to redeem with program owned challenger");
+    let (_, redeemed) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(redeemed.tries_remaining, 0);
+}
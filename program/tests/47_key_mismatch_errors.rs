@@ -0,0 +1,116 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    accounts,
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signature::{Keypair, Signer};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_pda_account,
+    add_started_challenge_with_solutions, assert_challenge_error, process_ixs,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const OTHER_ID: &str = "other-challenge-id";
+
+fn add_challenger(
+    context: &mut ProgramTestContext,
+    challenge_pda: Pubkey,
+    challenger: &Keypair,
+) -> Pubkey {
+    let challenger = Challenger {
+        authority: challenger.pubkey(),
+        challenge_pda,
+        tries_remaining: 1,
+        ..Challenger::default()
+    };
+    add_pda_account(context, &challenger);
+    challenger.pda().0
+}
+
+#[tokio::test]
+async fn redeem_with_incorrect_redeem() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    // the challenge redeem is a random key and thus not the redeem PDA the ix derives
+    add_started_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let challenger = Keypair::new();
+    add_challenger(&mut context, challenge_pda, &challenger);
+
+    let ix = ixs::redeem(creator, creator, ID, challenger.pubkey(), "one")
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[&challenger]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedRedeemIncorrect);
+}
+
+#[tokio::test]
+async fn redeem_with_challenger_of_other_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_started_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    add_started_challenge_with_solutions(
+        &mut context,
+        OTHER_ID,
+        vec!["one"],
+        None,
+    );
+    let (other_challenge_pda, _) = Challenge::pda_for(&creator, OTHER_ID);
+
+    let challenger = Keypair::new();
+    let other_challenger_pda =
+        add_challenger(&mut context, other_challenge_pda, &challenger);
+
+    let mut ix = ixs::redeem(creator, creator, ID, challenger.pubkey(), "one")
+        .expect("failed to create instruction");
+    ix.accounts[3].pubkey = other_challenger_pda;
+    let res = process_ixs(&mut context, &[ix], &[&challenger]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedChallengePdaIncorrect);
+}
+
+#[tokio::test]
+async fn start_challenge_by_other_creator() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let other = Keypair::new();
+    let mut ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    ix.accounts[0].pubkey = other.pubkey();
+    let res = process_ixs(&mut context, &[ix], &[&other]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedCreatorIncorrect);
+}
+
+#[tokio::test]
+async fn admit_challenger_paying_other_creator() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    let creator = Pubkey::new_unique();
+    add_started_challenge_with_solutions(
+        &mut context,
+        ID,
+        vec!["one"],
+        Some(creator),
+    );
+
+    let AdmitChallengerIx { mut ix, .. } =
+        ixs::admit_challenger(payer, creator, ID, Pubkey::new_unique())
+            .expect("failed to create instruction");
+    // the payer tries to pay the admit cost to itself
+    ix.accounts[accounts::admit_challenger::CREATOR].pubkey = payer;
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedCreatorIncorrect);
+}
@@ -0,0 +1,205 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    state::{Challenge, HasDiscriminator, HasPda, HasSize},
+    utils::{hash_solutions, rent_exempt_top_up},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData, rent::Rent, signer::Signer,
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{get_account, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn challenge(creator: Pubkey, solutions: &[&str]) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        admit_cost: 200,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(solutions),
+        ..Challenge::default()
+    }
+    .with_pda_bump(Challenge::pda_for(&creator, ID).1)
+}
+
+/// Stores the [challenge] at its PDA holding exactly [lamports].
+fn add_challenge_with_lamports(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+    lamports: u64,
+) -> Pubkey {
+    let (address, _) = challenge.pda();
+    let mut account = AccountSharedData::new(
+        lamports,
+        challenge.size(),
+        &challenge::challenge_id(),
+    );
+    account.set_data(challenge.to_account_data().unwrap());
+    context.set_account(&address, &account);
+    address
+}
+
+/// Size of the [challenge] account once the [extra] solutions were added.
+fn size_with_solutions(challenge: &Challenge, extra: &[&str]) -> usize {
+    let mut solutions = challenge.solutions.clone();
+    solutions.extend(hash_solutions(extra));
+    Challenge {
+        id: challenge.id.clone(),
+        solutions,
+        ..Challenge::default()
+    }
+    .size()
+}
+
+/// Adds the [solutions] and returns the lamports the payer was charged for them, excluding
+/// the transaction fee.
+async fn add_solutions(
+    context: &mut ProgramTestContext,
+    solutions: Vec<&str>,
+) -> Result<u64, BanksClientError> {
+    let creator = context.payer.pubkey();
+    let ix = ixs::add_solutions(creator, creator, ID.to_string(), solutions)
+        .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let fee = context
+        .banks_client
+        .get_fee_for_message(tx.message().clone())
+        .await?
+        .expect("fee should be known");
+    let before = context.banks_client.get_balance(creator).await?;
+    context.banks_client.process_transaction(tx).await?;
+    let after = context.banks_client.get_balance(creator).await?;
+    Ok(before - after - fee)
+}
+
+// -----------------
+// Top-up Calculation
+// -----------------
+#[test]
+fn top_up_is_exact_rent_delta() {
+    let rent = Rent::default();
+    let old_size = 200;
+    let new_size = 200 + 3 * 32;
+    let lamports = rent.minimum_balance(old_size);
+
+    assert_eq!(
+        rent_exempt_top_up(&rent, lamports, new_size),
+        rent.minimum_balance(new_size) - rent.minimum_balance(old_size)
+    );
+}
+
+#[test]
+fn top_up_edge_balances() {
+    let rent = Rent::default();
+    let size = 296;
+    let required = rent.minimum_balance(size);
+
+    // exactly rent exempt
+    assert_eq!(rent_exempt_top_up(&rent, required, size), 0);
+    // one lamport short
+    assert_eq!(rent_exempt_top_up(&rent, required - 1, size), 1);
+    // over funded, surplus is not refunded
+    assert_eq!(rent_exempt_top_up(&rent, required + 1_000, size), 0);
+    // empty account
+    assert_eq!(rent_exempt_top_up(&rent, 0, size), required);
+    // shrinking never requires a top-up
+    assert_eq!(rent_exempt_top_up(&rent, required, size - 32), 0);
+    // zero sized accounts still need to hold lamports
+    assert_eq!(
+        rent_exempt_top_up(&rent, 0, 0),
+        rent.minimum_balance(0).max(1)
+    );
+}
+
+// -----------------
+// Add Solutions
+// -----------------
+#[tokio::test]
+async fn add_solutions_transfers_exact_rent_delta() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator, &["one"]);
+    let extra = vec!["two", "three", "four"];
+
+    let rent = Rent::default();
+    let lamports = rent.minimum_balance(challenge.size());
+    let address =
+        add_challenge_with_lamports(&mut context, &challenge, lamports);
+
+    let charged = add_solutions(&mut context, extra.clone())
+        .await
+        .expect("failed to add solutions");
+
+    let new_size = size_with_solutions(&challenge, &extra);
+    let account = get_account(&mut context, &address).await;
+    assert_eq!(account.data.len(), new_size);
+    assert_eq!(account.lamports, rent.minimum_balance(new_size));
+    assert_eq!(
+        account.lamports - lamports,
+        rent_exempt_top_up(&rent, lamports, new_size)
+    );
+    assert_eq!(charged, account.lamports - lamports);
+}
+
+#[tokio::test]
+async fn add_solutions_one_lamport_short_tops_up_one_lamport() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator, &["one"]);
+    let extra = vec!["two"];
+
+    let rent = Rent::default();
+    let new_size = size_with_solutions(&challenge, &extra);
+    let lamports = rent.minimum_balance(new_size) - 1;
+    let address =
+        add_challenge_with_lamports(&mut context, &challenge, lamports);
+
+    add_solutions(&mut context, extra)
+        .await
+        .expect("failed to add solutions");
+
+    let account = get_account(&mut context, &address).await;
+    assert_eq!(account.lamports, lamports + 1);
+    assert!(rent.is_exempt(account.lamports, account.data.len()));
+}
+
+#[tokio::test]
+async fn add_solutions_to_over_funded_challenge_transfers_nothing() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator, &["one"]);
+    let extra = vec!["two", "three"];
+
+    let rent = Rent::default();
+    let new_size = size_with_solutions(&challenge, &extra);
+    let lamports = rent.minimum_balance(new_size) + 1_000;
+    let address =
+        add_challenge_with_lamports(&mut context, &challenge, lamports);
+
+    let charged = add_solutions(&mut context, extra)
+        .await
+        .expect("failed to add solutions");
+
+    let account = get_account(&mut context, &address).await;
+    assert_eq!(account.data.len(), new_size);
+    assert_eq!(account.lamports, lamports);
+    // the surplus covers the new size, the payer only pays the transaction fee
+    assert_eq!(charged, 0);
+}
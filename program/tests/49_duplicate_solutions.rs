@@ -0,0 +1,107 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{error::ChallengeError, ixs, state::Challenge};
+use solana_program::instruction::Instruction;
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, assert_challenge_error, get_deserialized,
+    hash_solution, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn process_ix(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn solutions(context: &mut ProgramTestContext) -> Vec<[u8; 32]> {
+    let (challenge_pda, _) = Challenge::pda_for(&context.payer.pubkey(), ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(context, &challenge_pda).await;
+    challenge.solutions
+}
+
+#[tokio::test]
+async fn add_solution_already_part_of_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one", "two"], None);
+
+    let ix = ixs::add_solutions(
+        creator,
+        creator,
+        ID.to_string(),
+        vec!["three", "two"],
+    )
+    .expect("failed to create instruction");
+    let res = process_ix(&mut context, ix).await;
+    assert_challenge_error(res, ChallengeError::DuplicateSolution);
+
+    assert_eq!(
+        solutions(&mut context).await,
+        vec![hash_solution("one"), hash_solution("two")]
+    );
+}
+
+#[tokio::test]
+async fn add_solution_twice_in_same_batch() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let ix = ixs::add_solutions(
+        creator,
+        creator,
+        ID.to_string(),
+        vec!["two", "three", "two"],
+    )
+    .expect("failed to create instruction");
+    let res = process_ix(&mut context, ix).await;
+    assert_challenge_error(res, ChallengeError::DuplicateSolution);
+
+    assert_eq!(solutions(&mut context).await, vec![hash_solution("one")]);
+}
+
+#[tokio::test]
+async fn add_duplicate_solutions_when_allowed() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let ix = ixs::add_solutions_allowing_duplicates(
+        creator,
+        creator,
+        ID.to_string(),
+        vec!["one", "two", "two"],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix)
+        .await
+        .expect("failed to add duplicate solutions");
+
+    assert_eq!(
+        solutions(&mut context).await,
+        vec![
+            hash_solution("one"),
+            hash_solution("one"),
+            hash_solution("two"),
+            hash_solution("two"),
+        ]
+    );
+}
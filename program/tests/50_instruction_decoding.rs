@@ -0,0 +1,251 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    error::ChallengeError,
+    ixs::{
+        self, ChallengeInstruction, MAX_INSTRUCTION_DATA_LEN,
+        MAX_INSTRUCTION_SOLUTIONS,
+    },
+    state::HashAlgorithm,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, assert_challenge_error, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const SOLUTION: [u8; 32] = [0xab; 32];
+
+fn decode_err(data: &[u8]) -> ProgramError {
+    ChallengeInstruction::try_from_slice_checked(data)
+        .expect_err("should fail to decode")
+}
+
+fn custom(err: ChallengeError) -> ProgramError {
+    ProgramError::Custom(err as u32)
+}
+
+// -----------------
+// Decoding
+// -----------------
+#[test]
+fn decodes_valid_instruction() {
+    let ix = ixs::start_challenge(Pubkey::new_unique(), ID.to_string())
+        .expect("failed to create instruction");
+    let instruction = ChallengeInstruction::try_from_slice_checked(&ix.data)
+        .expect("failed to decode");
+    assert_eq!(instruction.name(), "StartChallenge");
+    assert_eq!(instruction.to_string(), "StartChallenge");
+}
+
+#[test]
+fn decodes_unit_instruction() {
+    let data = ChallengeInstruction::UpdateLeaderboard
+        .try_to_vec()
+        .unwrap();
+    let instruction =
+        ChallengeInstruction::try_from_slice_checked(&data).unwrap();
+    assert_eq!(instruction.name(), "UpdateLeaderboard");
+}
+
+#[test]
+fn rejects_empty_data() {
+    assert_eq!(
+        decode_err(&[]),
+        custom(ChallengeError::InstructionDataEmpty)
+    );
+}
+
+#[test]
+fn rejects_data_exceeding_max_len() {
+    let mut data = ChallengeInstruction::UpdateLeaderboard
+        .try_to_vec()
+        .unwrap();
+    data.resize(MAX_INSTRUCTION_DATA_LEN + 1, 0);
+    assert_eq!(
+        decode_err(&data),
+        custom(ChallengeError::InstructionDataTooLarge)
+    );
+}
+
+#[test]
+fn rejects_trailing_bytes() {
+    let mut data = ChallengeInstruction::StartChallenge { id: ID.to_string() }
+        .try_to_vec()
+        .unwrap();
+    data.push(0);
+    assert_eq!(
+        decode_err(&data),
+        custom(ChallengeError::InstructionDataHasTrailingBytes)
+    );
+}
+
+#[test]
+fn rejects_truncated_data() {
+    let data = ChallengeInstruction::StartChallenge { id: ID.to_string() }
+        .try_to_vec()
+        .unwrap();
+    assert_eq!(
+        decode_err(&data[..data.len() - 1]),
+        custom(ChallengeError::MalformedInstructionData)
+    );
+}
+
+/// The instructions carrying solutions with the single [SOLUTION].
+fn instructions_with_solutions() -> Vec<ChallengeInstruction> {
+    let solutions = vec![SOLUTION];
+    vec![
+        ChallengeInstruction::CreateChallenge {
+            id: ID.to_string(),
+            admit_cost: 200,
+            tries_per_admit: 1,
+            redeem: Pubkey::new_unique(),
+            solutions: solutions.clone(),
+            salt: None,
+            hash_algorithm: HashAlgorithm::Sha256,
+            starts_at: None,
+            ends_at: None,
+        },
+        ChallengeInstruction::AddSolutions {
+            id: ID.to_string(),
+            solutions: solutions.clone(),
+            allow_duplicates: false,
+            rewards: vec![],
+        },
+        ChallengeInstruction::CreateChallengeFromTemplate {
+            template_id: 1,
+            id: ID.to_string(),
+            admit_cost: Some(200),
+            tries_per_admit: None,
+            redeem: Pubkey::new_unique(),
+            solutions: solutions.clone(),
+            salt: None,
+            hash_algorithm: None,
+            starts_at: None,
+            ends_at: None,
+        },
+        ChallengeInstruction::ReplaceSolutions {
+            id: ID.to_string(),
+            solutions: solutions.clone(),
+        },
+        ChallengeInstruction::AppendSolutionsChunk {
+            id: ID.to_string(),
+            chunk_index: 0,
+            total_chunks: 2,
+            solutions: solutions.clone(),
+        },
+        ChallengeInstruction::CreateChallengeIdempotent {
+            id: ID.to_string(),
+            admit_cost: 200,
+            tries_per_admit: 1,
+            redeem: Pubkey::new_unique(),
+            solutions: solutions.clone(),
+            salt: None,
+            hash_algorithm: HashAlgorithm::Sha256,
+            starts_at: None,
+            ends_at: None,
+        },
+        ChallengeInstruction::CreateSeededChallenge {
+            id: ID.to_string(),
+            seed: [1; 32],
+            admit_cost: 200,
+            tries_per_admit: 1,
+            redeem: Pubkey::new_unique(),
+            solutions,
+            salt: None,
+            hash_algorithm: HashAlgorithm::Sha256,
+            starts_at: None,
+            ends_at: None,
+        },
+    ]
+}
+
+/// Replaces the length prefix of the solutions in the instruction [data] with [len].
+fn with_solutions_len(mut data: Vec<u8>, len: u32) -> Vec<u8> {
+    let solutions_start = data
+        .windows(SOLUTION.len())
+        .position(|window| window == SOLUTION)
+        .expect("instruction should carry the solution");
+    data[solutions_start - 4..solutions_start]
+        .copy_from_slice(&len.to_le_bytes());
+    data
+}
+
+#[test]
+fn decodes_instructions_with_max_solutions_len_prefix() {
+    for instruction in instructions_with_solutions() {
+        let name = instruction.name();
+        let data = instruction.try_to_vec().unwrap();
+        ChallengeInstruction::try_from_slice_checked(&data)
+            .unwrap_or_else(|_| panic!("failed to decode {}", name));
+
+        // the prefix is within bounds so decoding fails on the missing solutions
+        let data = with_solutions_len(data, MAX_INSTRUCTION_SOLUTIONS);
+        assert_eq!(
+            decode_err(&data),
+            custom(ChallengeError::MalformedInstructionData),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn rejects_solutions_len_prefix_exceeding_max() {
+    for instruction in instructions_with_solutions() {
+        let name = instruction.name();
+        let data = instruction.try_to_vec().unwrap();
+        for len in [MAX_INSTRUCTION_SOLUTIONS + 1, u32::MAX] {
+            assert_eq!(
+                decode_err(&with_solutions_len(data.clone(), len)),
+                custom(ChallengeError::ExceedingMaxSupportedSolutions),
+                "{} with {} solutions",
+                name,
+                len
+            );
+        }
+    }
+}
+
+#[test]
+fn rejects_unknown_instruction() {
+    assert_eq!(
+        decode_err(&[u8::MAX]),
+        custom(ChallengeError::MalformedInstructionData)
+    );
+}
+
+// -----------------
+// Processor
+// -----------------
+#[tokio::test]
+async fn process_instruction_with_trailing_bytes() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let mut ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    ix.data.push(0);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert_challenge_error(
+        res,
+        ChallengeError::InstructionDataHasTrailingBytes,
+    );
+}
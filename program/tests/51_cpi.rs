@@ -0,0 +1,174 @@
+#![cfg(all(feature = "test-sbf", feature = "cpi"))]
+
+use challenge::{
+    challenge_id,
+    cpi::{self, AdmitChallengerAccounts},
+    error::ChallengeError,
+    state::{Challenge, Challenger, Config, PrizePool},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_started_challenge_with_solutions, assert_challenge_error, get_account,
+    get_deserialized, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const GAME_PAYER_SEED: &[u8] = b"game_payer";
+const GAME_PAYER_LAMPORTS: u64 = 1_000_000_000;
+
+fn game_id() -> Pubkey {
+    Pubkey::new_from_array([7; 32])
+}
+
+fn game_payer() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GAME_PAYER_SEED], &game_id())
+}
+
+/// A program admitting players to a challenge, paying the admit cost from its own PDA.
+fn process_game<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let payer = next_account_info(account_info_iter)?;
+    let creator = next_account_info(account_info_iter)?;
+    let challenge_pda = next_account_info(account_info_iter)?;
+    let challenger = next_account_info(account_info_iter)?;
+    let challenger_pda = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let prize_pool = next_account_info(account_info_iter)?;
+    let config = next_account_info(account_info_iter)?;
+    let program_info = next_account_info(account_info_iter)?;
+
+    let (_, bump) = game_payer();
+    cpi::admit(
+        program_info,
+        AdmitChallengerAccounts {
+            payer,
+            creator,
+            challenge_pda,
+            challenger,
+            challenger_pda,
+            system_program,
+            prize_pool,
+            config,
+            admit_mint: None,
+            protocol_fee: None,
+        },
+        vec![],
+        &[&[GAME_PAYER_SEED, &[bump]]],
+    )
+}
+
+async fn setup() -> ProgramTestContext {
+    let mut program_test = program_test();
+    program_test.add_program("game", game_id(), processor!(process_game));
+    let mut context = program_test.start_with_context().await;
+    context.set_account(
+        &game_payer().0,
+        &AccountSharedData::new(GAME_PAYER_LAMPORTS, 0, &system_program::id()),
+    );
+    context
+}
+
+fn admit_via_game_ix(
+    creator: Pubkey,
+    challenger: Pubkey,
+    program: Pubkey,
+) -> Instruction {
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    Instruction {
+        program_id: game_id(),
+        accounts: vec![
+            AccountMeta::new(game_payer().0, false),
+            AccountMeta::new(creator, false),
+            AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(challenger, false),
+            AccountMeta::new(challenger_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(PrizePool::pda_for(&challenge_pda).0, false),
+            AccountMeta::new_readonly(Config::pda().0, false),
+            AccountMeta::new_readonly(program, false),
+        ],
+        data: vec![],
+    }
+}
+
+async fn process_ix(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn game_program_admits_challenger_via_cpi() {
+    let mut context = setup().await;
+    let creator = context.payer.pubkey();
+    add_started_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let challenger = Keypair::new();
+    let ix = admit_via_game_ix(creator, challenger.pubkey(), challenge_id());
+    process_ix(&mut context, ix)
+        .await
+        .expect("failed to admit challenger via cpi");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (challenger_pda, _) = Challenger::shank_pda(
+        &challenge_id(),
+        &challenge_pda,
+        &challenger.pubkey(),
+    );
+    let (_, admitted) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(admitted.authority, challenger.pubkey());
+    assert_eq!(admitted.challenge_pda, challenge_pda);
+    assert_eq!(admitted.tries_remaining, 1);
+
+    // the game payer paid the admit cost and the rent of the challenger PDA
+    let game_payer = get_account(&mut context, &game_payer().0).await;
+    let challenger_account = get_account(&mut context, &challenger_pda).await;
+    assert_eq!(
+        game_payer.lamports,
+        GAME_PAYER_LAMPORTS - 200 - challenger_account.lamports
+    );
+}
+
+#[tokio::test]
+async fn game_program_passing_wrong_program() {
+    let mut context = setup().await;
+    let creator = context.payer.pubkey();
+    add_started_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let challenger = Keypair::new();
+    let ix =
+        admit_via_game_ix(creator, challenger.pubkey(), system_program::id());
+    let res = process_ix(&mut context, ix).await;
+    assert_challenge_error(res, ChallengeError::ProvidedProgramIncorrect);
+}
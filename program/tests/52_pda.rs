@@ -0,0 +1,145 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    challenge_id,
+    error::ChallengeError,
+    ixs, pda,
+    state::{
+        Challenge, Challenger, HasDiscriminator, HasPda, PrizePool, Redeem,
+    },
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData, rent::Rent, signer::Signer,
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{assert_challenge_error, get_deserialized, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+// -----------------
+// Derivation
+// -----------------
+#[test]
+fn pdas_match_the_state_accounts() {
+    let creator = Pubkey::new_unique();
+    let challenger = Pubkey::new_unique();
+
+    let (challenge_pda, challenge_bump) = pda::challenge_pda(&creator, ID);
+    assert_eq!(
+        (challenge_pda, challenge_bump),
+        Challenge::shank_pda(&challenge_id(), &creator, ID)
+    );
+    assert_eq!(
+        pda::challenger_pda(&challenge_pda, &challenger),
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger)
+    );
+    assert_eq!(
+        pda::redeem_pda(&challenge_pda),
+        Redeem::new(challenge_pda).pda()
+    );
+    assert_eq!(
+        pda::prize_pool_pda(&challenge_pda),
+        PrizePool::pda_for(&challenge_pda)
+    );
+}
+
+#[test]
+fn challenge_pda_with_stored_bump() {
+    let creator = Pubkey::new_unique();
+    let (challenge_pda, bump) = pda::challenge_pda(&creator, ID);
+
+    assert_eq!(
+        pda::challenge_pda_with_bump(&creator, ID, bump).unwrap(),
+        challenge_pda
+    );
+    assert_ne!(
+        pda::challenge_pda_with_bump(&creator, "other-id", bump).ok(),
+        Some(challenge_pda)
+    );
+    assert_ne!(
+        pda::challenge_pda_with_bump(&creator, ID, bump.wrapping_sub(1)).ok(),
+        Some(challenge_pda)
+    );
+}
+
+// -----------------
+// Stored Bump
+// -----------------
+#[tokio::test]
+async fn created_challenge_stores_its_bump() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        200,
+        1,
+        vec!["one"],
+        None,
+        None,
+    )
+    .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("failed to create challenge");
+
+    let (challenge_pda, bump) = pda::challenge_pda(&creator, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.bump, bump);
+}
+
+#[tokio::test]
+async fn challenge_with_incorrect_stored_bump_is_rejected() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let (challenge_pda, bump) = pda::challenge_pda(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        admit_cost: 200,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(&["one"]),
+        bump: bump.wrapping_sub(1),
+        ..Challenge::default()
+    };
+    let data = challenge.to_account_data().unwrap();
+    let mut account = AccountSharedData::new(
+        Rent::default().minimum_balance(data.len()),
+        data.len(),
+        &challenge_id(),
+    );
+    account.set_data(data);
+    context.set_account(&challenge_pda, &account);
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert_challenge_error(res, ChallengeError::ProvidedChallengePdaIncorrect);
+}
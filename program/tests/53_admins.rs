@@ -0,0 +1,237 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, HasPda, HasSize, MAX_CHALLENGE_ADMINS},
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signature::{Keypair, Signer};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_pda_account, add_started_challenge_with_solutions,
+    assert_challenge_error, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn challenge_with_admins(creator: Pubkey, admins: Vec<Pubkey>) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: 200,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        admins,
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    }
+}
+
+#[tokio::test]
+async fn add_and_remove_admin() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_started_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    // 1. Adding an admin grows the challenge
+    let admin = Pubkey::new_unique();
+    let ix = ixs::add_admin(creator, creator, ID.to_string(), admin)
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to add admin");
+
+    let (account, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.admins, vec![admin]);
+    assert_eq!(account.data.len(), challenge.size());
+
+    // 2. Adding the same admin again fails
+    let ix = ixs::add_admin(creator, creator, ID.to_string(), admin)
+        .expect("failed to create instruction");
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::AdminAlreadyAdded);
+
+    // 3. Removing the admin shrinks the challenge back
+    let ix = ixs::remove_admin(creator, creator, ID.to_string(), admin)
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to remove admin");
+
+    let (account, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(challenge.admins.is_empty());
+    assert_eq!(account.data.len(), challenge.size());
+
+    // 4. Removing it again fails
+    let ix = ixs::remove_admin(creator, creator, ID.to_string(), admin)
+        .expect("failed to create instruction");
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::AdminNotFound);
+}
+
+#[tokio::test]
+async fn add_admin_rejects_authority_and_too_many_admins() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let admins = (0..MAX_CHALLENGE_ADMINS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    add_pda_account(&mut context, &challenge_with_admins(creator, admins));
+
+    let ix = ixs::add_admin(creator, creator, ID.to_string(), creator)
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::AdminIsAuthority);
+
+    let ix =
+        ixs::add_admin(creator, creator, ID.to_string(), Pubkey::new_unique())
+            .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::TooManyAdmins);
+}
+
+#[tokio::test]
+async fn admin_adds_solutions_and_pauses() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let admin = Keypair::new();
+    add_pda_account(
+        &mut context,
+        &challenge_with_admins(creator, vec![admin.pubkey()]),
+    );
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let add_ix = ixs::add_solutions_as_admin(
+        creator,
+        admin.pubkey(),
+        creator,
+        ID.to_string(),
+        vec!["two"],
+    )
+    .expect("failed to create add instruction");
+    let pause_ix = ixs::pause_challenge_as_admin(
+        creator,
+        admin.pubkey(),
+        creator,
+        ID.to_string(),
+    )
+    .expect("failed to create pause instruction");
+    process_ixs(&mut context, &[add_ix, pause_ix], &[&admin])
+        .await
+        .expect("admin failed to add solutions and pause");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(challenge.paused);
+    assert_eq!(challenge.solutions, hash_solutions(&["one", "two"]));
+
+    let resume_ix = ixs::resume_challenge_as_admin(
+        creator,
+        admin.pubkey(),
+        creator,
+        ID.to_string(),
+    )
+    .expect("failed to create resume instruction");
+    process_ixs(&mut context, &[resume_ix], &[&admin])
+        .await
+        .expect("admin failed to resume");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(!challenge.paused);
+}
+
+#[tokio::test]
+async fn non_admin_cannot_add_solutions_nor_pause() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_pda_account(
+        &mut context,
+        &challenge_with_admins(creator, vec![Pubkey::new_unique()]),
+    );
+
+    let other = Keypair::new();
+    let ix = ixs::add_solutions_as_admin(
+        creator,
+        other.pubkey(),
+        creator,
+        ID.to_string(),
+        vec!["two"],
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[&other]).await;
+    assert_challenge_error(res, ChallengeError::NotAuthorityOrAdmin);
+
+    let ix = ixs::pause_challenge_as_admin(
+        creator,
+        other.pubkey(),
+        creator,
+        ID.to_string(),
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[&other]).await;
+    assert_challenge_error(res, ChallengeError::NotAuthorityOrAdmin);
+}
+
+#[tokio::test]
+async fn admin_cannot_manage_admins_transfer_nor_close() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let admin = Keypair::new();
+    let challenge = Challenge {
+        started: false,
+        ..challenge_with_admins(creator, vec![admin.pubkey()])
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let mut ix =
+        ixs::add_admin(creator, creator, ID.to_string(), Pubkey::new_unique())
+            .expect("failed to create instruction");
+    ix.accounts[1].pubkey = admin.pubkey();
+    let res = process_ixs(&mut context, &[ix], &[&admin]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedCreatorIncorrect);
+
+    let ix = ixs::transfer_authority(
+        creator,
+        admin.pubkey(),
+        creator,
+        ID.to_string(),
+        admin.pubkey(),
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[&admin]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedCreatorIncorrect);
+
+    let mut ix = ixs::close_challenge(creator, ID.to_string(), admin.pubkey())
+        .expect("failed to create instruction");
+    ix.accounts[0].pubkey = admin.pubkey();
+    let res = process_ixs(&mut context, &[ix], &[&admin]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedCreatorIncorrect);
+
+    let (_, unchanged) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(unchanged.authority, creator);
+    assert_eq!(unchanged.admins, vec![admin.pubkey()]);
+}
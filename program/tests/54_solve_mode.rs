@@ -0,0 +1,227 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{
+        Challenge, ChallengeHeader, Challenger, HasDiscriminator, HasPda,
+        Redeem, SolveMode,
+    },
+    utils::{hash_solution_challenger_sends, hash_solutions},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signature::{Keypair, Signer};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, assert_challenge_error,
+    get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn any_order_challenge(creator: Pubkey, solutions: &[&str]) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        redeem: Redeem::for_challenge_with(&creator, ID).pda().0,
+        solve_mode: SolveMode::AnyOrder,
+        solutions: hash_solutions(solutions),
+        ..Challenge::default()
+    }
+}
+
+fn add_challenger(
+    context: &mut ProgramTestContext,
+    challenge_pda: Pubkey,
+    challenger: &Keypair,
+    tries_remaining: u8,
+) -> Pubkey {
+    let challenger = Challenger {
+        authority: challenger.pubkey(),
+        challenge_pda,
+        tries_remaining,
+        ..Challenger::default()
+    };
+    add_pda_account(context, &challenger);
+    challenger.pda().0
+}
+
+// -----------------
+// Solved Solutions
+// -----------------
+#[test]
+fn any_order_header_matches_any_unsolved_solution() {
+    let mut challenge =
+        any_order_challenge(Pubkey::new_unique(), &["one", "two", "three"]);
+    challenge.mark_solved(1);
+    assert_eq!(challenge.solving, 1);
+    assert!(challenge.is_solved(1));
+    assert!(!challenge.is_solved(0));
+
+    let data = challenge.to_account_data().unwrap();
+    let header = ChallengeHeader::try_from_account_data(&data).unwrap();
+    let matching = |solution: &str| {
+        header.matching_solution_index(
+            &data,
+            &hash_solution_challenger_sends(solution),
+        )
+    };
+    assert_eq!(matching("three"), Some(2));
+    assert_eq!(matching("one"), Some(0));
+    assert_eq!(matching("two"), None, "already solved");
+    assert_eq!(matching("four"), None, "incorrect");
+
+    assert_eq!(header.solution_at(&data, 2), Some(challenge.solutions[2]));
+    assert_eq!(header.solution_at(&data, 3), None);
+}
+
+#[test]
+fn sequential_header_only_matches_current_solution() {
+    let challenge = Challenge {
+        solve_mode: SolveMode::Sequential,
+        ..any_order_challenge(Pubkey::new_unique(), &["one", "two"])
+    };
+    let data = challenge.to_account_data().unwrap();
+    let header = ChallengeHeader::try_from_account_data(&data).unwrap();
+
+    let sent = hash_solution_challenger_sends("two");
+    assert_eq!(header.matching_solution_index(&data, &sent), None);
+    let sent = hash_solution_challenger_sends("one");
+    assert_eq!(header.matching_solution_index(&data, &sent), Some(0));
+}
+
+#[test]
+fn removing_solutions_keeps_remaining_solved_marked() {
+    let mut challenge = any_order_challenge(
+        Pubkey::new_unique(),
+        &["one", "two", "three", "four"],
+    );
+    challenge.mark_solved(1);
+    challenge.mark_solved(3);
+
+    // removing "one" and "four" leaves "two" (solved) and "three"
+    challenge.unmark_removed_solutions(&[0, 3], 4);
+    assert_eq!(challenge.solving, 1);
+    assert!(challenge.is_solved(0));
+    assert!(!challenge.is_solved(1));
+    assert!(!challenge.is_solved(2));
+}
+
+// -----------------
+// Set Solve Mode
+// -----------------
+#[tokio::test]
+async fn set_solve_mode_before_start() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = Challenge {
+        started: false,
+        solve_mode: SolveMode::Sequential,
+        ..any_order_challenge(creator, &["one"])
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let ix = ixs::set_solve_mode(
+        creator,
+        creator,
+        ID.to_string(),
+        SolveMode::AnyOrder,
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to set solve mode");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(challenge.solve_mode, SolveMode::AnyOrder);
+}
+
+#[tokio::test]
+async fn set_solve_mode_after_start_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_pda_account(&mut context, &any_order_challenge(creator, &["one"]));
+
+    let ix = ixs::set_solve_mode(
+        creator,
+        creator,
+        ID.to_string(),
+        SolveMode::Sequential,
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ChallengeAlreadyStarted);
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeem_solutions_in_any_order() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = any_order_challenge(creator, &["one", "two"]);
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &Redeem::for_challenge_with(&creator, ID));
+    let challenge_pda = challenge.pda().0;
+
+    let challenger = Keypair::new();
+    let challenger_pda =
+        add_challenger(&mut context, challenge_pda, &challenger, 3);
+
+    // 1. The last solution is accepted first
+    let ix = ixs::redeem(creator, creator, ID, challenger.pubkey(), "two")
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[&challenger])
+        .await
+        .expect("failed to redeem second solution");
+
+    let (_, redeemed) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(redeemed.solving, 1);
+    assert!(redeemed.is_solved(1));
+    assert!(!redeemed.finished);
+
+    // 2. Solving it again is treated as incorrect
+    let ix = ixs::redeem(creator, creator, ID, challenger.pubkey(), "two")
+        .expect("failed to create instruction");
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    process_ixs(&mut context, &[ix], &[&challenger])
+        .await
+        .expect("failed to attempt solved solution");
+    let (_, unchanged) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(unchanged.solving, 1);
+
+    // 3. Solving the remaining solution finishes the challenge
+    let ix = ixs::redeem(creator, creator, ID, challenger.pubkey(), "one")
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[&challenger])
+        .await
+        .expect("failed to redeem first solution");
+
+    let (_, finished) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(finished.solving, 2);
+    assert!(finished.is_solved(0));
+    assert!(finished.finished);
+
+    let (_, challenger) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert!(challenger.has_solved(0));
+    assert!(challenger.has_solved(1));
+    assert_eq!(challenger.tries_remaining, 0);
+}
@@ -0,0 +1,204 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, HasPda, HasSize, Payout, PrizePool, Redeem},
+    utils::hash_solutions,
+};
+use solana_program::{pubkey::Pubkey, rent::Rent, system_program};
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_to_redeem, add_pda_account,
+    assert_challenge_error, get_account, get_deserialized, process_ixs,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_000_000;
+const CHALLENGER_LAMPORTS: u64 = 1_000_000_000;
+
+async fn admit_and_redeem(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+    solution: &str,
+) -> Result<Pubkey, BanksClientError> {
+    let challenger = Keypair::new();
+    context.set_account(
+        &challenger.pubkey(),
+        &AccountSharedData::new(CHALLENGER_LAMPORTS, 0, &system_program::id()),
+    );
+
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+    )
+    .expect("failed to create admit instruction");
+    process_ixs(context, &[ix], &[]).await?;
+
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+        solution,
+    )
+    .expect("failed to create redeem instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &challenger],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await?;
+
+    Ok(challenger.pubkey())
+}
+
+fn challenge_with_solutions(solutions: &[&str]) -> Challenge {
+    Challenge {
+        solutions: hash_solutions(solutions),
+        ..Challenge::default()
+    }
+}
+
+// -----------------
+// Rewards
+// -----------------
+#[test]
+fn rewards_stay_parallel_to_solutions() {
+    let mut challenge = challenge_with_solutions(&["one", "two"]);
+
+    // 1. solutions added without rewards leave the challenge unrewarded
+    challenge.append_rewards(0, &[]).unwrap();
+    assert!(challenge.rewards.is_empty());
+    assert_eq!(challenge.reward_for(0), None);
+
+    // 2. rewarding added solutions rewards the existing ones with 0
+    challenge.solutions.extend(hash_solutions(&["three"]));
+    challenge.append_rewards(2, &[300]).unwrap();
+    assert_eq!(challenge.rewards, vec![0, 0, 300]);
+    assert_eq!(challenge.reward_for(2), Some(300));
+
+    // 3. once rewarded, solutions added without rewards are rewarded with 0
+    challenge.solutions.extend(hash_solutions(&["four"]));
+    challenge.append_rewards(3, &[]).unwrap();
+    assert_eq!(challenge.rewards, vec![0, 0, 300, 0]);
+    assert_eq!(challenge.size(), Challenge::default().size() + 4 * (32 + 8));
+
+    // 4. removing solutions removes their rewards
+    challenge.remove_rewards(&[0, 3]);
+    assert_eq!(challenge.rewards, vec![0, 300]);
+}
+
+#[test]
+fn rewards_need_to_match_added_solutions() {
+    let mut challenge = challenge_with_solutions(&["one", "two"]);
+    let err = challenge.append_rewards(0, &[100]).unwrap_err();
+    assert_eq!(err, ChallengeError::RewardsDoNotMatchSolutions.into());
+    assert!(challenge.rewards.is_empty());
+}
+
+#[tokio::test]
+async fn add_solutions_with_rewards() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let ix = ixs::add_solutions_with_rewards(
+        creator,
+        creator,
+        ID.to_string(),
+        vec!["two", "three"],
+        vec![100, 200],
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to add solutions with rewards");
+
+    let (account, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.rewards, vec![0, 100, 200]);
+    assert_eq!(account.data.len(), challenge.size());
+
+    let ix = ixs::add_solutions_with_rewards(
+        creator,
+        creator,
+        ID.to_string(),
+        vec!["four", "five"],
+        vec![100],
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::RewardsDoNotMatchSolutions);
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeem_pays_reward_of_solution() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: 1,
+        redeem: redeem.pda().0,
+        has_prize_pool: true,
+        rewards: vec![ADMIT_COST / 4, 5 * ADMIT_COST],
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &redeem);
+    add_pda_account(
+        &mut context,
+        &PrizePool {
+            challenge_pda: challenge.pda().0,
+            payout: Payout::WinnerTakesAll,
+        },
+    );
+    let (prize_pool_pda, _) = PrizePool::pda_for(&challenge.pda().0);
+    let pool_rent = Rent::default().minimum_balance(PrizePool::size());
+
+    // First challenger is paid the reward of the first solution regardless of the payout
+    let first = admit_and_redeem(&mut context, creator, "one")
+        .await
+        .expect("Failed to admit and redeem");
+    assert_eq!(
+        get_account(&mut context, &first).await.lamports,
+        CHALLENGER_LAMPORTS + ADMIT_COST / 4
+    );
+
+    // Second challenger is paid at most what is left in the pool
+    let second = admit_and_redeem(&mut context, creator, "two")
+        .await
+        .expect("Failed to admit and redeem");
+    assert_eq!(
+        get_account(&mut context, &second).await.lamports,
+        CHALLENGER_LAMPORTS + 2 * ADMIT_COST - ADMIT_COST / 4
+    );
+    assert_eq!(
+        get_account(&mut context, &prize_pool_pda).await.lamports,
+        pool_rent
+    );
+}
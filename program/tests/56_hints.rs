@@ -0,0 +1,175 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, Challenger, HasPda, HasSize, RevealedHints},
+    utils::hash_solutions,
+    Hint, HINT_BYTES,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signature::{Keypair, Signer};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_pda_account, assert_challenge_error, get_account, get_deserialized,
+    process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const HINT_FEE: u64 = 1_000_000;
+
+fn hint(uri: &str) -> Hint {
+    let mut hint = [0; HINT_BYTES];
+    hint[..uri.len()].copy_from_slice(uri.as_bytes());
+    hint
+}
+
+fn challenge_with_hints(authority: Pubkey, hints: Vec<Hint>) -> Challenge {
+    Challenge {
+        authority,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        hint_fee: HINT_FEE,
+        solutions: hash_solutions(&["one", "two"]),
+        hints,
+        ..Challenge::default()
+    }
+}
+
+// -----------------
+// Hints
+// -----------------
+#[test]
+fn hints_stay_parallel_to_solutions() {
+    let mut challenge =
+        challenge_with_hints(Pubkey::new_unique(), vec![hint("a"), [0; 64]]);
+    assert_eq!(challenge.hint_for(0), Some(&hint("a")));
+    assert_eq!(challenge.hint_for(1), None, "all zeros is no hint");
+    assert_eq!(challenge.hint_for(2), None);
+
+    challenge.solutions.extend(hash_solutions(&["three"]));
+    challenge.append_hints(2, &[]);
+    assert_eq!(challenge.hints.len(), 3);
+    assert_eq!(challenge.hint_for(2), None);
+    assert_eq!(
+        challenge.size(),
+        Challenge::needed_size_with_hints(
+            &challenge.solutions,
+            &challenge.hints,
+            ID
+        )
+        .unwrap()
+    );
+
+    challenge.remove_hints(&[1, 2]);
+    assert_eq!(challenge.hints, vec![hint("a")]);
+}
+
+#[test]
+fn revealed_hints_bitset() {
+    let mut revealed_hints = RevealedHints {
+        challenger_pda: Pubkey::new_unique(),
+        revealed: [0; 32],
+    };
+    revealed_hints.mark_revealed(9);
+    assert!(revealed_hints.is_revealed(9));
+    assert!(!revealed_hints.is_revealed(8));
+}
+
+#[tokio::test]
+async fn set_hints_resizes_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = Challenge {
+        started: false,
+        ..challenge_with_hints(creator, vec![])
+    };
+    add_pda_account(&mut context, &challenge);
+
+    // 1. hints need to match the solutions
+    let ix =
+        ixs::set_hints(creator, creator, ID.to_string(), vec![hint("a")], 1)
+            .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::HintsDoNotMatchSolutions);
+
+    // 2. one hint per solution grows the challenge
+    let ix = ixs::set_hints(
+        creator,
+        creator,
+        ID.to_string(),
+        vec![hint("a"), hint("b")],
+        HINT_FEE,
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to set hints");
+
+    let (account, updated) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(updated.hints, vec![hint("a"), hint("b")]);
+    assert_eq!(updated.hint_fee, HINT_FEE);
+    assert_eq!(account.data.len(), updated.size());
+}
+
+// -----------------
+// Reveal Hint
+// -----------------
+#[tokio::test]
+async fn reveal_hint_pays_fee_to_authority() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    let creator = Pubkey::new_unique();
+    let challenge = challenge_with_hints(creator, vec![hint("a"), [0; 64]]);
+    add_pda_account(&mut context, &challenge);
+
+    let challenger = Keypair::new();
+    let challenger_state = Challenger {
+        authority: challenger.pubkey(),
+        challenge_pda: challenge.pda().0,
+        tries_remaining: 1,
+        ..Challenger::default()
+    };
+    add_pda_account(&mut context, &challenger_state);
+
+    // 1. revealing the hint records it for the challenger and pays the authority
+    let ix = ixs::reveal_hint(payer, creator, ID, challenger.pubkey(), 0)
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[&challenger])
+        .await
+        .expect("failed to reveal hint");
+
+    assert_eq!(get_account(&mut context, &creator).await.lamports, HINT_FEE);
+    let (_, revealed_hints) = get_deserialized::<RevealedHints>(
+        &mut context,
+        &RevealedHints::pda_for(&challenger_state.pda().0).0,
+    )
+    .await;
+    assert!(revealed_hints.is_revealed(0));
+
+    // 2. revealing it again fails
+    let ix = ixs::reveal_hint(payer, creator, ID, challenger.pubkey(), 0)
+        .expect("failed to create instruction");
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    let res = process_ixs(&mut context, &[ix], &[&challenger]).await;
+    assert_challenge_error(res, ChallengeError::HintAlreadyRevealed);
+
+    // 3. solutions without hint have nothing to reveal
+    let ix = ixs::reveal_hint(payer, creator, ID, challenger.pubkey(), 1)
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[&challenger]).await;
+    assert_challenge_error(res, ChallengeError::HintNotFound);
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, Challenger, HasPda, Redeem},
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signature::{Keypair, Signer};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, assert_challenge_error,
+    get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const COOLDOWN_SLOTS: u64 = 10;
+
+fn throttled_challenge(creator: Pubkey) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 3,
+        redeem: Redeem::for_challenge_with(&creator, ID).pda().0,
+        attempt_cooldown_slots: COOLDOWN_SLOTS,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    }
+}
+
+// -----------------
+// Cooldown
+// -----------------
+#[test]
+fn cooldown_applies_after_first_attempt() {
+    let challenge = throttled_challenge(Pubkey::new_unique());
+    let mut challenger = Challenger {
+        tries_remaining: 3,
+        ..Challenger::default()
+    };
+
+    // 1. the first attempt is never throttled
+    assert_eq!(challenge.next_attempt_slot(&challenger), None);
+
+    // 2. further attempts need to wait for the cooldown to pass
+    challenger.record_attempt(100);
+    assert_eq!(
+        challenge.next_attempt_slot(&challenger),
+        Some(100 + COOLDOWN_SLOTS)
+    );
+
+    // 3. challenges without cooldown never throttle
+    let unthrottled = Challenge {
+        attempt_cooldown_slots: 0,
+        ..challenge
+    };
+    assert_eq!(unthrottled.next_attempt_slot(&challenger), None);
+}
+
+#[tokio::test]
+async fn set_attempt_cooldown() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = throttled_challenge(creator);
+    add_pda_account(&mut context, &challenge);
+
+    let ix = ixs::set_attempt_cooldown(creator, creator, ID.to_string(), 0)
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to set attempt cooldown");
+
+    let (_, updated) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(updated.attempt_cooldown_slots, 0);
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeem_again_before_cooldown_passed_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = throttled_challenge(creator);
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &Redeem::for_challenge_with(&creator, ID));
+
+    let challenger = Keypair::new();
+    let challenger_state = Challenger {
+        authority: challenger.pubkey(),
+        challenge_pda: challenge.pda().0,
+        tries_remaining: 3,
+        ..Challenger::default()
+    };
+    add_pda_account(&mut context, &challenger_state);
+
+    // 1. An incorrect first attempt consumes a try
+    let ix = ixs::redeem(creator, creator, ID, challenger.pubkey(), "wrong")
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[&challenger])
+        .await
+        .expect("failed to attempt redeem");
+
+    // 2. Attempting again within the cooldown fails
+    let ix = ixs::redeem(creator, creator, ID, challenger.pubkey(), "one")
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[&challenger]).await;
+    assert_challenge_error(res, ChallengeError::AttemptTooSoon);
+
+    // 3. Once the cooldown passed the challenger can attempt again
+    let (_, attempted) =
+        get_deserialized::<Challenger>(&mut context, &challenger_state.pda().0)
+            .await;
+    context
+        .warp_to_slot(attempted.last_attempt_slot + COOLDOWN_SLOTS)
+        .expect("failed to warp to slot");
+    let ix = ixs::redeem(creator, creator, ID, challenger.pubkey(), "one")
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[&challenger])
+        .await
+        .expect("failed to redeem after cooldown");
+
+    let (_, redeemed) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(redeemed.solving, 1);
+}
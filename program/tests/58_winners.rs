@@ -0,0 +1,230 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{
+        Challenge, Challenger, HasPda, HasSize, Payout, PrizePool, Redeem,
+        Winners,
+    },
+    utils::hash_solutions,
+};
+use solana_program::{pubkey::Pubkey, rent::Rent, system_program};
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData,
+    signature::{Keypair, Signer},
+};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, assert_challenge_error, get_account,
+    get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const REWARD: u64 = 1_000_000;
+const CHALLENGER_LAMPORTS: u64 = 1_000_000_000;
+
+fn challenge_with_winners(creator: Pubkey, max_winners: u8) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 2,
+        redeem: Redeem::for_challenge_with(&creator, ID).pda().0,
+        max_winners_per_solution: max_winners,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    }
+}
+
+async fn challenger_redeems(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+    challenger: &Keypair,
+    solution: &str,
+) -> Result<(), BanksClientError> {
+    let mut ix = ixs::redeem(
+        context.payer.pubkey(),
+        challenge.authority,
+        ID,
+        challenger.pubkey(),
+        solution,
+    )
+    .expect("failed to create instruction");
+    ix.accounts
+        .extend(ixs::winners_accounts(&challenge.pda().0));
+    process_ixs(context, &[ix], &[challenger]).await
+}
+
+fn add_challenger(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+) -> Keypair {
+    let challenger = Keypair::new();
+    context.set_account(
+        &challenger.pubkey(),
+        &AccountSharedData::new(CHALLENGER_LAMPORTS, 0, &system_program::id()),
+    );
+    add_pda_account(
+        context,
+        &Challenger {
+            authority: challenger.pubkey(),
+            challenge_pda: challenge.pda().0,
+            tries_remaining: challenge.tries_per_admit,
+            ..Challenger::default()
+        },
+    );
+    challenger
+}
+
+// -----------------
+// Winners
+// -----------------
+#[test]
+fn winners_are_recorded_once_per_solution() {
+    let challenger = Pubkey::new_unique();
+    let mut winners = Winners::new(Pubkey::new_unique(), 1);
+    assert_eq!(winners.count(0), 0);
+
+    assert!(winners.record(0, challenger));
+    assert!(!winners.record(0, challenger), "same challenger twice");
+    assert!(winners.has_won(0, &challenger));
+    assert!(!winners.has_won(1, &challenger));
+
+    // grows for solutions added after the leaderboard was created
+    assert!(winners.record(2, challenger));
+    assert_eq!(winners.count(0), 1);
+    assert_eq!(winners.count(1), 0);
+    assert_eq!(winners.count(2), 1);
+    assert_eq!(
+        winners.size(),
+        Winners::new(Pubkey::default(), 3).size() + 64
+    );
+}
+
+#[test]
+fn single_winner_challenges_have_one_winner_per_solution() {
+    let challenge = challenge_with_winners(Pubkey::new_unique(), 0);
+    assert_eq!(challenge.winners_per_solution(), 1);
+    assert!(!challenge.has_multiple_winners());
+
+    let challenge = challenge_with_winners(Pubkey::new_unique(), 3);
+    assert_eq!(challenge.winners_per_solution(), 3);
+    assert!(challenge.has_multiple_winners());
+}
+
+// -----------------
+// Create Winners
+// -----------------
+#[tokio::test]
+async fn create_winners_before_start() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = Challenge {
+        started: false,
+        ..challenge_with_winners(creator, 0)
+    };
+    add_pda_account(&mut context, &challenge);
+
+    // 1. a single winner is not multiple winners
+    let ix = ixs::create_winners(creator, creator, ID.to_string(), 1)
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::InvalidMaxWinnersPerSolution);
+
+    // 2. creates the leaderboard and configures the challenge
+    let ix = ixs::create_winners(creator, creator, ID.to_string(), 2)
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to create winners");
+
+    let (_, updated) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(updated.max_winners_per_solution, 2);
+    let (_, winners) = get_deserialized::<Winners>(
+        &mut context,
+        &Winners::pda_for(&challenge.pda().0).0,
+    )
+    .await;
+    assert_eq!(winners.winners, vec![vec![], vec![]]);
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn solution_stays_redeemable_until_all_winners_solved_it() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let challenge = Challenge {
+        has_prize_pool: true,
+        rewards: vec![REWARD, REWARD],
+        ..challenge_with_winners(creator, 2)
+    };
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &Redeem::for_challenge_with(&creator, ID));
+    add_pda_account(
+        &mut context,
+        &Winners::new(challenge.pda().0, challenge.solutions.len()),
+    );
+    let prize_pool = PrizePool {
+        challenge_pda: challenge.pda().0,
+        payout: Payout::PerSolution,
+    };
+    let mut prize_pool_account = add_pda_account(&mut context, &prize_pool);
+    prize_pool_account.lamports =
+        Rent::default().minimum_balance(PrizePool::size()) + 2 * REWARD;
+    context.set_account(&prize_pool.pda().0, &prize_pool_account.into());
+
+    // 1. the first winner receives half the reward and the solution stays open
+    let first = add_challenger(&mut context, &challenge);
+    challenger_redeems(&mut context, &challenge, &first, "one")
+        .await
+        .expect("failed to redeem as first winner");
+    let (_, redeemed) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(redeemed.solving, 0);
+    assert_eq!(
+        get_account(&mut context, &first.pubkey()).await.lamports,
+        CHALLENGER_LAMPORTS + REWARD / 2
+    );
+
+    // 2. the same challenger cannot win it again
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    let res = challenger_redeems(&mut context, &challenge, &first, "one").await;
+    assert_challenge_error(
+        res,
+        ChallengeError::SolutionAlreadySolvedByChallenger,
+    );
+
+    // 3. the second winner receives the other half and solves it
+    let second = add_challenger(&mut context, &challenge);
+    challenger_redeems(&mut context, &challenge, &second, "one")
+        .await
+        .expect("failed to redeem as second winner");
+    let (_, solved) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(solved.solving, 1);
+    assert_eq!(
+        get_account(&mut context, &second.pubkey()).await.lamports,
+        CHALLENGER_LAMPORTS + REWARD / 2
+    );
+
+    let (_, winners) = get_deserialized::<Winners>(
+        &mut context,
+        &Winners::pda_for(&challenge.pda().0).0,
+    )
+    .await;
+    assert_eq!(winners.winners[0], vec![first.pubkey(), second.pubkey()]);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    clock::Now,
+    state::{is_open, is_solve_window_open, is_within_schedule, Challenge},
+};
+use solana_program::clock::Clock;
+
+const SOLVE_FROM_SLOT: u64 = 1_000;
+const SOLVE_UNTIL_SLOT: u64 = 2_000;
+const STARTS_AT: i64 = 1_700_000_000;
+const ENDS_AT: i64 = 1_700_086_400;
+
+fn scheduled_challenge() -> Challenge {
+    Challenge {
+        started: true,
+        solve_open_from_slot: Some(SOLVE_FROM_SLOT),
+        solve_open_until_slot: Some(SOLVE_UNTIL_SLOT),
+        starts_at: Some(STARTS_AT),
+        ends_at: Some(ENDS_AT),
+        ..Challenge::default()
+    }
+}
+
+#[test]
+fn now_from_clock() {
+    let clock = Clock {
+        slot: SOLVE_FROM_SLOT,
+        unix_timestamp: STARTS_AT,
+        ..Clock::default()
+    };
+    assert_eq!(Now::from(clock), Now::new(SOLVE_FROM_SLOT, STARTS_AT));
+}
+
+#[test]
+fn solve_window_and_schedule_include_their_bounds() {
+    let challenge = scheduled_challenge();
+    assert!(!is_solve_window_open(&challenge, SOLVE_FROM_SLOT - 1));
+    assert!(is_solve_window_open(&challenge, SOLVE_FROM_SLOT));
+    assert!(is_solve_window_open(&challenge, SOLVE_UNTIL_SLOT));
+    assert!(!is_solve_window_open(&challenge, SOLVE_UNTIL_SLOT + 1));
+
+    assert!(!is_within_schedule(&challenge, STARTS_AT - 1));
+    assert!(is_within_schedule(&challenge, STARTS_AT));
+    assert!(is_within_schedule(&challenge, ENDS_AT));
+    assert!(!is_within_schedule(&challenge, ENDS_AT + 1));
+
+    let unscheduled = Challenge::default();
+    assert!(is_solve_window_open(&unscheduled, 0));
+    assert!(is_within_schedule(&unscheduled, 0));
+}
+
+#[test]
+fn challenge_is_open_while_running_inside_window_and_schedule() {
+    let challenge = scheduled_challenge();
+    let open = Now::new(SOLVE_FROM_SLOT, STARTS_AT);
+    assert!(is_open(&challenge, &open));
+    assert!(!is_open(
+        &challenge,
+        &Now::new(SOLVE_FROM_SLOT - 1, STARTS_AT)
+    ));
+    assert!(!is_open(
+        &challenge,
+        &Now::new(SOLVE_FROM_SLOT, ENDS_AT + 1)
+    ));
+
+    let paused = Challenge {
+        paused: true,
+        ..scheduled_challenge()
+    };
+    assert!(!is_open(&paused, &open));
+
+    let not_started = Challenge {
+        started: false,
+        ..scheduled_challenge()
+    };
+    assert!(!is_open(&not_started, &open));
+
+    let finished = Challenge {
+        finished: true,
+        ..scheduled_challenge()
+    };
+    assert!(!is_open(&finished, &open));
+}
@@ -0,0 +1,116 @@
+#![cfg(all(feature = "test-sbf", feature = "serde"))]
+
+use borsh::BorshSerialize;
+use challenge::{
+    ixs::ChallengeInstruction,
+    snapshot::{ChallengeSnapshot, SnapshotAccount},
+    state::{Challenge, Challenger, HasPda, Winners},
+    utils::hash_solutions,
+    HINT_BYTES,
+};
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn challenge_roundtrips_through_json_with_base58_pubkeys() {
+    let authority = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let challenge = Challenge {
+        authority,
+        pending_authority: Some(Pubkey::new_unique()),
+        admins: vec![admin],
+        id: "challenge-id".to_string(),
+        solutions: hash_solutions(&["one", "two"]),
+        hints: vec![[1; HINT_BYTES], [0; HINT_BYTES]],
+        ..Challenge::default()
+    };
+
+    let json = serde_json::to_value(&challenge).unwrap();
+    assert_eq!(json["authority"], authority.to_string());
+    assert_eq!(json["admins"][0], admin.to_string());
+    assert_eq!(json["original_authority"], serde_json::Value::Null);
+    assert_eq!(json["hints"][0], "01".repeat(HINT_BYTES));
+
+    let roundtripped: Challenge = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        roundtripped.try_to_vec().unwrap(),
+        challenge.try_to_vec().unwrap()
+    );
+}
+
+#[test]
+fn winners_and_instructions_roundtrip_through_json() {
+    let challenge_pda = Pubkey::new_unique();
+    let mut winners = Winners::new(challenge_pda, 2);
+    winners.record(1, Pubkey::new_unique());
+    let json = serde_json::to_string(&winners).unwrap();
+    let roundtripped: Winners = serde_json::from_str(&json).unwrap();
+    assert_eq!(roundtripped.challenge_pda, challenge_pda);
+    assert_eq!(roundtripped.winners, winners.winners);
+
+    let ix = ChallengeInstruction::TransferAuthority {
+        id: "challenge-id".to_string(),
+        new_authority: Pubkey::new_unique(),
+    };
+    let json = serde_json::to_string(&ix).unwrap();
+    let roundtripped: ChallengeInstruction =
+        serde_json::from_str(&json).unwrap();
+    assert_eq!(roundtripped.try_to_vec().unwrap(), ix.try_to_vec().unwrap());
+}
+
+#[test]
+fn invalid_base58_pubkey_fails_to_deserialize() {
+    let mut json = serde_json::to_value(Challenge::default()).unwrap();
+    json["authority"] = "not-a-pubkey".into();
+    assert!(serde_json::from_value::<Challenge>(json).is_err());
+}
+
+#[test]
+fn challenge_snapshot_roundtrips_through_json() {
+    let challenge = Challenge {
+        id: "challenge-id".to_string(),
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    let (challenge_pda, _) = challenge.pda();
+    let challenger = Challenger {
+        authority: Pubkey::new_unique(),
+        challenge_pda,
+        ..Challenger::default()
+    };
+    let snapshot = ChallengeSnapshot {
+        challenge: SnapshotAccount {
+            address: challenge_pda,
+            lamports: 1_000,
+            state: challenge,
+        },
+        challengers: vec![SnapshotAccount {
+            address: challenger.pda().0,
+            lamports: 500,
+            state: challenger,
+        }],
+        leaderboard: None,
+        prize_pool: None,
+        escrow: None,
+        redeem_supply: 1,
+    };
+
+    let json = serde_json::to_value(&snapshot).unwrap();
+    assert_eq!(json["challenge"]["address"], challenge_pda.to_string());
+
+    let roundtripped: ChallengeSnapshot = serde_json::from_value(json).unwrap();
+    assert_eq!(roundtripped.challenge.lamports, 1_000);
+    assert_eq!(
+        roundtripped.challenge.state.try_to_vec().unwrap(),
+        snapshot.challenge.state.try_to_vec().unwrap()
+    );
+    assert_eq!(roundtripped.challengers.len(), 1);
+    assert_eq!(
+        roundtripped.challengers[0].address,
+        snapshot.challengers[0].address
+    );
+    assert_eq!(
+        roundtripped.challengers[0].state.try_to_vec().unwrap(),
+        snapshot.challengers[0].state.try_to_vec().unwrap()
+    );
+    assert_eq!(roundtripped.redeem_supply, 1);
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, HasPda, Redeem, SolutionNormalization},
+    utils::{hash_solutions, normalize_solution, normalize_solutions},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signature::Signer;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_pda_account, assert_challenge_error, get_deserialized, process_ixs,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn challenge(creator: Pubkey, started: bool) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started,
+        tries_per_admit: 1,
+        redeem: Redeem::for_challenge_with(&creator, ID).pda().0,
+        solutions: hash_solutions(&["hello world"]),
+        ..Challenge::default()
+    }
+}
+
+// -----------------
+// Normalize
+// -----------------
+#[test]
+fn no_rules_keep_the_solution_unchanged() {
+    let normalized =
+        normalize_solution(" Hello  World ", SolutionNormalization::NONE);
+    assert_eq!(normalized, " Hello  World ");
+}
+
+#[test]
+fn each_rule_applies_on_its_own() {
+    let solution = "  Hello \t World\u{FB01} ";
+    let only = |rules: SolutionNormalization| {
+        normalize_solution(solution, rules).into_owned()
+    };
+
+    let trim = SolutionNormalization {
+        trim: true,
+        ..SolutionNormalization::NONE
+    };
+    assert_eq!(only(trim), "Hello \t World\u{FB01}");
+
+    let lowercase = SolutionNormalization {
+        lowercase: true,
+        ..SolutionNormalization::NONE
+    };
+    assert_eq!(only(lowercase), "  hello \t world\u{FB01} ");
+
+    let nfkc = SolutionNormalization {
+        nfkc: true,
+        ..SolutionNormalization::NONE
+    };
+    assert_eq!(only(nfkc), "  Hello \t Worldfi ");
+
+    let collapse_whitespace = SolutionNormalization {
+        collapse_whitespace: true,
+        ..SolutionNormalization::NONE
+    };
+    assert_eq!(only(collapse_whitespace), " Hello World\u{FB01} ");
+}
+
+#[test]
+fn all_rules_treat_equivalent_answers_the_same() {
+    let normalized = normalize_solutions(
+        &["Hello World ", "hello world", "  HELLO\n\nWORLD"],
+        SolutionNormalization::ALL,
+    );
+    assert!(normalized.iter().all(|s| s == "hello world"));
+}
+
+// -----------------
+// Set Solution Normalization
+// -----------------
+#[tokio::test]
+async fn set_solution_normalization_before_start() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator, false);
+    add_pda_account(&mut context, &challenge);
+
+    let ix = ixs::set_solution_normalization(
+        creator,
+        creator,
+        ID.to_string(),
+        SolutionNormalization::ALL,
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to set solution normalization");
+
+    let (_, updated) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(updated.solution_normalization, SolutionNormalization::ALL);
+}
+
+#[tokio::test]
+async fn set_solution_normalization_after_start_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge = challenge(creator, true);
+    add_pda_account(&mut context, &challenge);
+
+    let ix = ixs::set_solution_normalization(
+        creator,
+        creator,
+        ID.to_string(),
+        SolutionNormalization::ALL,
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ChallengeAlreadyStarted);
+}
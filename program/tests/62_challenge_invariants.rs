@@ -0,0 +1,132 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    state::{Challenge, SolveMode, MAX_CHALLENGE_ADMINS},
+    utils::hash_solutions,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+fn challenge_with_solutions(solving: u8) -> Challenge {
+    Challenge {
+        id: "challenge-id".to_string(),
+        solving,
+        solutions: hash_solutions(&["one", "two", "three"]),
+        ..Challenge::default()
+    }
+}
+
+fn assert_violated(challenge: &Challenge) {
+    assert_eq!(
+        challenge.assert_invariants(),
+        Err(ProgramError::from(
+            ChallengeError::ChallengeInvariantViolated
+        ))
+    );
+}
+
+// -----------------
+// Getters
+// -----------------
+#[test]
+fn solving_index_at_start_middle_and_end() {
+    let start = challenge_with_solutions(0);
+    assert_eq!(start.remaining_solutions(), 3);
+    assert_eq!(start.current_solution(), Some(&start.solutions[0]));
+    assert!(!start.is_complete());
+
+    let middle = challenge_with_solutions(2);
+    assert_eq!(middle.remaining_solutions(), 1);
+    assert_eq!(middle.current_solution(), Some(&middle.solutions[2]));
+    assert!(!middle.is_complete());
+
+    let end = challenge_with_solutions(3);
+    assert_eq!(end.remaining_solutions(), 0);
+    assert_eq!(end.current_solution(), None);
+    assert!(end.is_complete());
+}
+
+#[test]
+fn challenge_without_solutions_is_complete() {
+    let challenge = Challenge::default();
+    assert_eq!(challenge.remaining_solutions(), 0);
+    assert_eq!(challenge.current_solution(), None);
+    assert!(challenge.is_complete());
+}
+
+#[test]
+fn solving_past_the_solutions_has_none_remaining() {
+    let challenge = challenge_with_solutions(5);
+    assert_eq!(challenge.remaining_solutions(), 0);
+    assert_eq!(challenge.current_solution(), None);
+    assert!(challenge.is_complete());
+}
+
+// -----------------
+// Invariants
+// -----------------
+#[test]
+fn consistent_challenges_hold_their_invariants() {
+    assert!(Challenge::default().assert_invariants().is_ok());
+    for solving in 0..=3 {
+        assert!(challenge_with_solutions(solving)
+            .assert_invariants()
+            .is_ok());
+    }
+
+    let mut any_order = Challenge {
+        solve_mode: SolveMode::AnyOrder,
+        rewards: vec![1, 2, 3],
+        hints: vec![[0; 64]; 3],
+        ..challenge_with_solutions(0)
+    };
+    any_order.mark_solved(2);
+    any_order.mark_solved(0);
+    assert!(any_order.assert_invariants().is_ok());
+}
+
+#[test]
+fn solving_more_than_the_solutions_violates_invariants() {
+    assert_violated(&challenge_with_solutions(4));
+}
+
+#[test]
+fn rewards_and_hints_not_parallel_to_solutions_violate_invariants() {
+    assert_violated(&Challenge {
+        rewards: vec![1, 2],
+        ..challenge_with_solutions(0)
+    });
+    assert_violated(&Challenge {
+        hints: vec![[1; 64]; 4],
+        ..challenge_with_solutions(0)
+    });
+}
+
+#[test]
+fn too_many_admins_violate_invariants() {
+    assert_violated(&Challenge {
+        admins: vec![Pubkey::new_unique(); MAX_CHALLENGE_ADMINS + 1],
+        ..challenge_with_solutions(0)
+    });
+}
+
+#[test]
+fn solved_marks_inconsistent_with_solving_violate_invariants() {
+    // marked solved without counting it
+    let mut solved = [0; 32];
+    solved[0] = 0b010;
+    assert_violated(&Challenge {
+        solve_mode: SolveMode::AnyOrder,
+        solved,
+        ..challenge_with_solutions(0)
+    });
+
+    // marked solved beyond the solutions
+    let mut solved = [0; 32];
+    solved[0] = 0b1000;
+    assert_violated(&Challenge {
+        solve_mode: SolveMode::AnyOrder,
+        solved,
+        ..challenge_with_solutions(1)
+    });
+}
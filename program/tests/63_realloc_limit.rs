@@ -0,0 +1,93 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, HasSize},
+    utils::hash_solutions,
+    HINT_BYTES,
+};
+use solana_program::{entrypoint::MAX_PERMITTED_DATA_INCREASE, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{add_pda_account, assert_challenge_error, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const SOURCE_ID: &str = "challenge-id-2";
+
+fn solutions(n: usize) -> Vec<String> {
+    (0..n).map(|i| format!("solution-{i}")).collect()
+}
+
+fn challenge(creator: Pubkey, id: &str, solutions: &[String]) -> Challenge {
+    let solutions = solutions.iter().map(String::as_str).collect::<Vec<_>>();
+    Challenge {
+        authority: creator,
+        id: id.to_string(),
+        solutions: hash_solutions(&solutions),
+        ..Challenge::default()
+    }
+}
+
+// -----------------
+// Size Delta
+// -----------------
+#[test]
+fn size_delta_matches_the_size_after_appending() {
+    let creator = Pubkey::new_unique();
+    let plain = |n: usize| challenge(creator, ID, &solutions(n));
+    assert_eq!(plain(2).size() + plain(2).size_delta(3), plain(5).size());
+    assert_eq!(plain(2).size_delta(0), 0);
+
+    let rewarded_and_hinted = |n: usize| Challenge {
+        rewards: vec![1; n],
+        hints: vec![[1; HINT_BYTES]; n],
+        ..plain(n)
+    };
+    assert_eq!(
+        rewarded_and_hinted(2).size() + rewarded_and_hinted(2).size_delta(3),
+        rewarded_and_hinted(5).size()
+    );
+}
+
+// -----------------
+// Merge Challenges
+// -----------------
+#[tokio::test]
+async fn merge_growing_the_account_beyond_the_realloc_limit_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let hinted = |id: &str, solutions: &[String]| Challenge {
+        hints: vec![[1; HINT_BYTES]; solutions.len()],
+        ..challenge(creator, id, solutions)
+    };
+    let target = hinted(ID, &solutions(1));
+    let source = hinted(SOURCE_ID, &solutions(150)[1..]);
+    assert!(
+        target.size_delta(source.solutions.len()) > MAX_PERMITTED_DATA_INCREASE
+    );
+    add_pda_account(&mut context, &target);
+    add_pda_account(&mut context, &source);
+
+    let ix = ixs::merge_challenges(
+        creator,
+        creator,
+        ID.to_string(),
+        SOURCE_ID.to_string(),
+    )
+    .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&creator),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert_challenge_error(res, ChallengeError::ReallocTooLarge);
+}
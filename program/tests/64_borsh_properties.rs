@@ -0,0 +1,347 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use challenge::{
+    ixs::ChallengeInstruction,
+    state::{
+        Challenge, Challenger, HasDiscriminator, HasSize, HashAlgorithm,
+        SolutionNormalization, SolveMode, CHALLENGER_SIZE, CHALLENGE_VERSION,
+    },
+    Hint, Solution, HINT_BYTES,
+};
+use proptest::{collection::vec, option, prelude::*};
+use solana_program::pubkey::Pubkey;
+
+// -----------------
+// Strategies
+// -----------------
+fn pubkey() -> impl Strategy<Value = Pubkey> {
+    any::<[u8; 32]>().prop_map(Pubkey::new_from_array)
+}
+
+fn hint() -> impl Strategy<Value = Hint> {
+    vec(any::<u8>(), HINT_BYTES).prop_map(|bytes| {
+        let mut hint = [0; HINT_BYTES];
+        hint.copy_from_slice(&bytes);
+        hint
+    })
+}
+
+fn solutions(max: usize) -> impl Strategy<Value = Vec<Solution>> {
+    vec(any::<Solution>(), 0..=max)
+}
+
+fn hash_algorithm() -> impl Strategy<Value = HashAlgorithm> {
+    prop_oneof![
+        Just(HashAlgorithm::Sha256),
+        Just(HashAlgorithm::Keccak256),
+        Just(HashAlgorithm::Blake3),
+    ]
+}
+
+fn solve_mode() -> impl Strategy<Value = SolveMode> {
+    prop_oneof![Just(SolveMode::Sequential), Just(SolveMode::AnyOrder)]
+}
+
+fn solution_normalization() -> impl Strategy<Value = SolutionNormalization> {
+    any::<[bool; 4]>().prop_map(
+        |[trim, lowercase, nfkc, collapse_whitespace]| SolutionNormalization {
+            trim,
+            lowercase,
+            nfkc,
+            collapse_whitespace,
+        },
+    )
+}
+
+prop_compose! {
+    fn challenge()(
+        (bump, authority, pending_authority, original_authority, admins, id) in (
+            any::<u8>(),
+            pubkey(),
+            option::of(pubkey()),
+            option::of(pubkey()),
+            vec(pubkey(), 0..4),
+            "[a-z0-9-]{0,32}",
+        ),
+        (started, finished, paused, cancelled, admit_cost, admit_cost_locked) in (
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<u64>(),
+            any::<bool>(),
+        ),
+        (admit_mint, has_prize_pool, nft_reward, has_first_solvers, commit_reveal) in (
+            option::of(pubkey()),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+        ),
+        (allowlist_root, max_admissions, admissions, salt, hash_algorithm) in (
+            option::of(any::<[u8; 32]>()),
+            option::of(any::<u32>()),
+            any::<u32>(),
+            option::of(any::<[u8; 32]>()),
+            hash_algorithm(),
+        ),
+        (tries_per_admit, redeem, solving, solve_mode, solved) in (
+            any::<u8>(),
+            pubkey(),
+            any::<u8>(),
+            solve_mode(),
+            any::<[u8; 32]>(),
+        ),
+        (solve_open_from_slot, solve_open_until_slot, starts_at, ends_at) in (
+            option::of(any::<u64>()),
+            option::of(any::<u64>()),
+            option::of(any::<i64>()),
+            option::of(any::<i64>()),
+        ),
+        (oracle, solutions_locked, hint_fee, attempt_cooldown_slots) in (
+            pubkey(),
+            any::<bool>(),
+            any::<u64>(),
+            any::<u64>(),
+        ),
+        (
+            max_winners_per_solution,
+            solution_normalization,
+            reject_weak_solutions,
+            ticket_admission,
+            flags,
+            seed,
+            max_tries_per_challenger,
+            sponsored_lamports,
+            prerequisite,
+        ) in (
+            any::<u8>(),
+            solution_normalization(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<u32>(),
+            option::of(any::<[u8; 32]>()),
+            option::of(any::<u16>()),
+            any::<u64>(),
+            option::of(pubkey()),
+        ),
+        (epoch_slots, epoch_start_slot, solutions_per_epoch, epoch) in (
+            any::<u64>(),
+            any::<u64>(),
+            any::<u8>(),
+            any::<u64>(),
+        ),
+        (rewards, solutions, hints) in (
+            vec(any::<u64>(), 0..16),
+            solutions(u8::MAX as usize),
+            vec(hint(), 0..16),
+        ),
+    ) -> Challenge {
+        Challenge {
+            version: CHALLENGE_VERSION,
+            bump,
+            authority,
+            pending_authority,
+            original_authority,
+            admins,
+            id,
+            started,
+            finished,
+            paused,
+            cancelled,
+            admit_cost,
+            admit_cost_locked,
+            admit_mint,
+            has_prize_pool,
+            nft_reward,
+            has_first_solvers,
+            commit_reveal,
+            allowlist_root,
+            max_admissions,
+            admissions,
+            salt,
+            hash_algorithm,
+            tries_per_admit,
+            redeem,
+            solving,
+            solve_mode,
+            solved,
+            solve_open_from_slot,
+            solve_open_until_slot,
+            starts_at,
+            ends_at,
+            oracle,
+            solutions_locked,
+            hint_fee,
+            attempt_cooldown_slots,
+            max_winners_per_solution,
+            solution_normalization,
+            reject_weak_solutions,
+            ticket_admission,
+            flags,
+            seed,
+            max_tries_per_challenger,
+            sponsored_lamports,
+            prerequisite,
+            epoch_slots,
+            epoch_start_slot,
+            solutions_per_epoch,
+            epoch,
+            rewards,
+            solutions,
+            hints,
+        }
+    }
+}
+
+prop_compose! {
+    fn challenger()(
+        authority in pubkey(),
+        challenge_pda in pubkey(),
+        tries_remaining in any::<u8>(),
+        redeemed in any::<bool>(),
+        tries_admitted in any::<u16>(),
+        tries_used in any::<u16>(),
+        solved in any::<[u8; 32]>(),
+        last_attempt_slot in any::<u64>(),
+        refundable in any::<u64>(),
+    ) -> Challenger {
+        Challenger {
+            authority,
+            challenge_pda,
+            tries_remaining,
+            redeemed,
+            tries_admitted,
+            tries_used,
+            solved,
+            last_attempt_slot,
+            refundable,
+        }
+    }
+}
+
+fn instruction() -> impl Strategy<Value = ChallengeInstruction> {
+    prop_oneof![
+        (
+            "[a-z0-9-]{0,32}",
+            any::<u64>(),
+            any::<u8>(),
+            pubkey(),
+            solutions(16),
+            option::of(any::<[u8; 32]>()),
+            hash_algorithm(),
+            option::of(any::<i64>()),
+            option::of(any::<i64>()),
+        )
+            .prop_map(
+                |(
+                    id,
+                    admit_cost,
+                    tries_per_admit,
+                    redeem,
+                    solutions,
+                    salt,
+                    hash_algorithm,
+                    starts_at,
+                    ends_at,
+                )| ChallengeInstruction::CreateChallenge {
+                    id,
+                    admit_cost,
+                    tries_per_admit,
+                    redeem,
+                    solutions,
+                    salt,
+                    hash_algorithm,
+                    starts_at,
+                    ends_at,
+                }
+            ),
+        (
+            "[a-z0-9-]{0,32}",
+            solutions(16),
+            any::<bool>(),
+            vec(any::<u64>(), 0..16),
+        )
+            .prop_map(|(id, solutions, allow_duplicates, rewards)| {
+                ChallengeInstruction::AddSolutions {
+                    id,
+                    solutions,
+                    allow_duplicates,
+                    rewards,
+                }
+            }),
+        any::<Solution>()
+            .prop_map(|solution| ChallengeInstruction::Redeem { solution }),
+        any::<Solution>().prop_map(|solution| {
+            ChallengeInstruction::CheckSolution { solution }
+        }),
+        ("[a-z0-9-]{0,32}", solution_normalization()).prop_map(
+            |(id, solution_normalization)| {
+                ChallengeInstruction::SetSolutionNormalization {
+                    id,
+                    solution_normalization,
+                }
+            }
+        ),
+    ]
+}
+
+// -----------------
+// Challenge
+// -----------------
+proptest! {
+    #[test]
+    fn challenge_round_trips(challenge in challenge()) {
+        let data = challenge.to_account_data().unwrap();
+        let decoded = Challenge::try_from_account_data(&data).unwrap();
+        prop_assert_eq!(decoded.to_account_data().unwrap(), data);
+    }
+
+    #[test]
+    fn challenge_size_matches_serialized_len(challenge in challenge()) {
+        let data = challenge.to_account_data().unwrap();
+        prop_assert_eq!(challenge.size(), data.len());
+    }
+
+    #[test]
+    fn needed_size_matches_serialized_len(
+        id in "[a-z0-9-]{0,32}",
+        solutions in solutions(u8::MAX as usize),
+    ) {
+        let needed_size = Challenge::needed_size(&solutions, &id);
+        let challenge = Challenge {
+            id,
+            solutions,
+            ..Challenge::default()
+        };
+        let data = challenge.to_account_data().unwrap();
+        prop_assert_eq!(needed_size, data.len());
+    }
+}
+
+// -----------------
+// Challenger
+// -----------------
+proptest! {
+    #[test]
+    fn challenger_round_trips(challenger in challenger()) {
+        let data = challenger.to_account_data().unwrap();
+        prop_assert_eq!(data.len(), CHALLENGER_SIZE);
+
+        let decoded = Challenger::try_from_account_data(&data).unwrap();
+        prop_assert_eq!(decoded.to_account_data().unwrap(), data);
+    }
+}
+
+// -----------------
+// Instructions
+// -----------------
+proptest! {
+    #[test]
+    fn instruction_round_trips(ix in instruction()) {
+        let data = ix.try_to_vec().unwrap();
+        let decoded = ChallengeInstruction::try_from_slice(&data).unwrap();
+        prop_assert_eq!(decoded.try_to_vec().unwrap(), data);
+    }
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    state::{Challenge, Challenger, HasPda},
+    test_utils::{get_deserialized, hash_solution, ChallengeScenario},
+};
+use solana_program_test::*;
+use solana_sdk::signature::{Keypair, Signer};
+
+const ID: &str = "challenge-id";
+
+#[tokio::test]
+async fn scenario_sets_up_started_challenge_with_admitted_challengers() {
+    let challenger = Keypair::new();
+    let mut scenario = ChallengeScenario::new()
+        .with_solutions(&["hello", "world"])
+        .with_tries_per_admit(3)
+        .with_admitted(&[challenger.pubkey()])
+        .started()
+        .run()
+        .await;
+    let creator = scenario.context.payer.pubkey();
+
+    assert_eq!(scenario.challenge.authority, creator);
+    assert_eq!(scenario.challenge_pda, Challenge::pda_for(&creator, ID).0);
+    assert_eq!(scenario.challenge.redeem, scenario.redeem.pda().0);
+    assert_eq!(scenario.challenge.solutions[0], hash_solution("hello"));
+    assert_eq!(scenario.challenge.admissions, 1);
+
+    let (_, challenge) = get_deserialized::<Challenge>(
+        &mut scenario.context,
+        &scenario.challenge_pda,
+    )
+    .await;
+    assert!(challenge.started);
+    assert_eq!(challenge.solutions.len(), 2);
+
+    let challenger_pda = scenario.challengers[0].pda().0;
+    let (_, admitted) =
+        get_deserialized::<Challenger>(&mut scenario.context, &challenger_pda)
+            .await;
+    assert_eq!(admitted.authority, challenger.pubkey());
+    assert_eq!(admitted.challenge_pda, scenario.challenge_pda);
+    assert_eq!(admitted.tries_remaining, 3);
+}
+
+#[tokio::test]
+async fn admitted_challenger_redeems_in_scenario() {
+    let challenger = Keypair::new();
+    let mut scenario = ChallengeScenario::new()
+        .with_solutions(&["hello", "world"])
+        .with_admitted(&[challenger.pubkey()])
+        .started()
+        .run()
+        .await;
+    let payer = scenario.context.payer.pubkey();
+
+    let ix = ixs::redeem(payer, payer, ID, challenger.pubkey(), "hello")
+        .expect("failed to create instruction");
+    scenario
+        .process_ixs(&[ix], &[&challenger])
+        .await
+        .expect("failed to redeem");
+
+    let (_, challenge) = get_deserialized::<Challenge>(
+        &mut scenario.context,
+        &scenario.challenge_pda,
+    )
+    .await;
+    assert_eq!(challenge.solving, 1);
+
+    let challenger_pda = scenario.challengers[0].pda().0;
+    let (_, redeemed) =
+        get_deserialized::<Challenger>(&mut scenario.context, &challenger_pda)
+            .await;
+    assert!(redeemed.redeemed);
+}
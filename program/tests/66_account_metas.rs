@@ -0,0 +1,406 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshDeserialize;
+use challenge::{
+    accounts::{self, AccountSpec},
+    challenge_id,
+    error::ChallengeError,
+    ixs::{self, ChallengeInstruction},
+    state::{
+        AccountKind, ChallengeTemplate, HashAlgorithm, Payout,
+        SolutionNormalization, SolveMode, TemplateOverrides, ALLOW_REFUNDS,
+    },
+    HINT_BYTES,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::*;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::utils::{assert_challenge_error, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+/// One instruction per builder, the accounts are made up since only their metas matter.
+fn all_instructions() -> Vec<Instruction> {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let challenger = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let id = || ID.to_string();
+    let solutions = || vec!["hello", "world"];
+    let template = ChallengeTemplate {
+        authority: creator,
+        template_id: 1,
+        admit_cost: 1,
+        tries_per_admit: 1,
+        ..ChallengeTemplate::default()
+    };
+
+    let mut all = vec![
+        ixs::create_challenge(
+            payer,
+            creator,
+            id(),
+            1,
+            1,
+            solutions(),
+            None,
+            None,
+        ),
+        ixs::create_salted_challenge(
+            payer,
+            creator,
+            id(),
+            1,
+            1,
+            solutions(),
+            None,
+            None,
+            Some([1; 32]),
+            HashAlgorithm::Keccak256,
+        ),
+        ixs::add_solutions(payer, creator, id(), solutions()),
+        ixs::add_salted_solutions(
+            payer,
+            creator,
+            id(),
+            solutions(),
+            Some([1; 32]),
+            HashAlgorithm::Blake3,
+        ),
+        ixs::add_solutions_with_rewards(
+            payer,
+            creator,
+            id(),
+            solutions(),
+            vec![1, 2],
+        ),
+        ixs::add_solutions_allowing_duplicates(
+            payer,
+            creator,
+            id(),
+            solutions(),
+        ),
+        ixs::add_solutions_as_admin(payer, other, creator, id(), solutions()),
+        ixs::start_challenge(creator, id()),
+        ixs::admit_challenger(payer, creator, ID, challenger).map(|ix| ix.ix),
+        ixs::admit_allowlisted_challenger(
+            payer,
+            creator,
+            ID,
+            challenger,
+            Some(other),
+            vec![[1; 32]],
+        )
+        .map(|ix| ix.ix),
+        ixs::admit_challenger_with_admit_mint(
+            payer, creator, ID, challenger, other,
+        )
+        .map(|ix| ix.ix),
+        ixs::admit_challenger_with_ticket(payer, creator, ID, challenger, None)
+            .map(|ix| ix.ix),
+        ixs::redeem(payer, creator, ID, challenger, "hello"),
+        ixs::redeem_with_hash(payer, creator, ID, challenger, [1; 32]),
+        ixs::redeem_with_nft(payer, creator, ID, challenger, "hello", 0),
+        ixs::redeem_with_ticket(payer, creator, ID, other, challenger, "hello"),
+        ixs::set_solve_window(payer, creator, id(), Some(1), Some(2)),
+        ixs::get_challenge_state(creator, ID),
+        ixs::split_challenge(payer, creator, ID, "new-id".to_string(), 1),
+        ixs::truncate_solutions(creator, id(), 1),
+        ixs::merge_challenges(payer, creator, id(), "source-id".to_string()),
+        ixs::update_admit_cost(payer, creator, id(), 1),
+        ixs::lock_admit_cost(payer, creator, id()),
+        ixs::create_template(payer, &template),
+        ixs::create_challenge_from_template(
+            payer,
+            &template,
+            id(),
+            TemplateOverrides {
+                admit_cost: Some(1),
+                ..TemplateOverrides::default()
+            },
+            solutions(),
+        ),
+        ixs::set_oracle(payer, creator, id(), other),
+        ixs::update_solution_hash(payer, creator, id(), 0, [1; 32]),
+        ixs::lock_solutions(payer, creator, id()),
+        ixs::unlock_solutions(payer, creator, id()),
+        ixs::reissue_admission(payer, creator, ID, challenger),
+        ixs::reissue_admission_with_admit_mint(
+            payer, creator, ID, challenger, other,
+        ),
+        ixs::add_co_sponsor(payer, creator, ID, 1),
+        ixs::create_leaderboard(payer, creator, id()),
+        ixs::update_leaderboard(payer, creator, ID, challenger),
+        ixs::remove_solutions(creator, id(), vec![0]),
+        ixs::replace_solutions(payer, creator, id(), solutions()),
+        ixs::replace_salted_solutions(
+            payer,
+            creator,
+            id(),
+            solutions(),
+            Some([1; 32]),
+            HashAlgorithm::Sha256,
+        ),
+        ixs::close_challenge(creator, id(), other),
+        ixs::transfer_authority(payer, creator, creator, id(), other),
+        ixs::accept_authority(other, creator, id()),
+        ixs::pause_challenge(payer, creator, id()),
+        ixs::resume_challenge(payer, creator, id()),
+        ixs::pause_challenge_as_admin(payer, other, creator, id()),
+        ixs::resume_challenge_as_admin(payer, other, creator, id()),
+        ixs::update_challenge(
+            payer,
+            creator,
+            id(),
+            Some(1),
+            Some(1),
+            Some(other),
+        ),
+        ixs::set_admit_mint(payer, creator, id(), Some(other)),
+        ixs::set_admit_mint(payer, creator, id(), None),
+        ixs::withdraw_admit_tokens(creator, id(), other),
+        ixs::create_prize_pool(payer, creator, id(), Payout::PerSolution),
+        ixs::withdraw_prize_pool(creator, id()),
+        ixs::set_nft_reward(payer, creator, id(), true),
+        ixs::commit_solution(payer, creator, ID, challenger, "hello", [1; 32]),
+        ixs::commit_solution_with_ticket(
+            payer, creator, ID, other, challenger, "hello", [1; 32],
+        ),
+        ixs::reveal_solution(payer, creator, ID, challenger, "hello", [1; 32]),
+        ixs::set_commit_reveal(payer, creator, id(), true),
+        ixs::set_solve_mode(payer, creator, id(), SolveMode::AnyOrder),
+        ixs::set_attempt_cooldown(payer, creator, id(), 1),
+        ixs::set_hints(payer, creator, id(), vec![[1; HINT_BYTES]; 2], 1),
+        ixs::reveal_hint(payer, creator, ID, challenger, 0),
+        ixs::create_first_solvers(payer, creator, id()),
+        ixs::create_winners(payer, creator, id(), 2),
+        ixs::set_solution_normalization(
+            payer,
+            creator,
+            id(),
+            SolutionNormalization::ALL,
+        ),
+        ixs::append_solutions_chunk(payer, creator, id(), 0, 1, vec![[1; 32]]),
+        ixs::init_config(payer, other, 1, other),
+        ixs::update_config(other, Some(1), Some(other), Some(other)),
+        ixs::set_allowlist(payer, creator, id(), Some([1; 32])),
+        ixs::set_max_admissions(payer, creator, id(), Some(1)),
+        ixs::cancel_challenge(creator, id()),
+        ixs::refund_unused_tries(creator, id(), challenger, None),
+        ixs::refund_unused_tries(creator, id(), challenger, Some(other)),
+        ixs::migrate_account(payer, other, AccountKind::Challenge),
+        ixs::add_admin(payer, creator, id(), other),
+        ixs::remove_admin(payer, creator, id(), other),
+        ixs::check_solution(creator, ID, "hello"),
+        ixs::set_reject_weak_solutions(payer, creator, id(), true),
+        ixs::set_ticket_admission(payer, creator, id(), true),
+        ixs::set_metadata(
+            payer,
+            creator,
+            id(),
+            "name".to_string(),
+            "description".to_string(),
+            "https://example.com".to_string(),
+        ),
+        ixs::set_challenge_flags(payer, creator, id(), ALLOW_REFUNDS),
+        ixs::create_challenge_idempotent(
+            payer,
+            creator,
+            id(),
+            1,
+            1,
+            solutions(),
+            None,
+            None,
+            None,
+            HashAlgorithm::Sha256,
+        ),
+        ixs::create_seeded_challenge(
+            payer,
+            creator,
+            id(),
+            [1; 32],
+            1,
+            1,
+            solutions(),
+            None,
+            None,
+            None,
+            HashAlgorithm::Sha256,
+        ),
+        ixs::buy_more_tries(payer, creator, ID, challenger, 1),
+        ixs::buy_more_tries_with_admit_mint(
+            payer, creator, ID, challenger, 1, other,
+        ),
+        ixs::set_max_tries_per_challenger(payer, creator, id(), Some(1)),
+        ixs::sweep_surplus(creator, id(), other),
+        ixs::set_prerequisite(payer, creator, id(), Some(other)),
+        ixs::admit_challenger_with_prerequisite(
+            payer, creator, ID, challenger, other,
+        )
+        .map(|ix| ix.ix),
+        ixs::set_epochs(creator, id(), 100, 1),
+    ]
+    .into_iter()
+    .map(|ix| ix.expect("failed to create instruction"))
+    .collect::<Vec<_>>();
+
+    let multi_ixs = vec![
+        ixs::verify_solve_signature(
+            payer, creator, ID, challenger, other, 0, &[1; 32], [1; 64],
+        ),
+        ixs::verify_solve_signature_with_ticket(
+            payer, creator, ID, other, challenger, other, 0, &[1; 32], [1; 64],
+        ),
+        ixs::append_solutions_in_chunks(
+            payer,
+            creator,
+            id(),
+            &solutions(),
+            1,
+            None,
+            HashAlgorithm::Sha256,
+        ),
+    ];
+    for ixs in multi_ixs {
+        all.extend(ixs.expect("failed to create instructions"));
+    }
+    all.retain(|ix| ix.program_id == challenge_id());
+    all
+}
+
+fn assert_matches_specs(ix: &Instruction) {
+    let instruction = ChallengeInstruction::try_from_slice(&ix.data)
+        .expect("failed to decode instruction");
+    let specs = instruction.account_specs();
+    assert!(
+        ix.accounts.len() <= specs.len(),
+        "{} provides {} accounts but only {} are specified",
+        instruction,
+        ix.accounts.len(),
+        specs.len()
+    );
+    let fixed_specs = specs.iter().take_while(|spec| !spec.optional);
+    for (idx, spec) in fixed_specs.enumerate() {
+        let meta = ix.accounts.get(idx).unwrap_or_else(|| {
+            panic!(
+                "{} is missing required account '{}' at {}",
+                instruction, spec.name, idx
+            )
+        });
+        assert!(
+            !spec.signer || meta.is_signer,
+            "{} account '{}' at {} should be signer",
+            instruction,
+            spec.name,
+            idx
+        );
+        assert!(
+            !spec.writable || meta.is_writable,
+            "{} account '{}' at {} should be writable",
+            instruction,
+            spec.name,
+            idx
+        );
+    }
+}
+
+// -----------------
+// Specs
+// -----------------
+#[test]
+fn builders_provide_accounts_as_specified() {
+    for ix in all_instructions() {
+        assert_matches_specs(&ix);
+    }
+}
+
+#[test]
+fn index_constants_match_specs() {
+    let AccountSpec { name, .. } =
+        accounts::redeem::ACCOUNTS[accounts::redeem::CHALLENGER];
+    assert_eq!(name, "challenger");
+
+    let AccountSpec {
+        name,
+        optional,
+        writable,
+        ..
+    } = accounts::redeem::ACCOUNTS[accounts::redeem::PRIZE_POOL];
+    assert_eq!(name, "prize_pool");
+    assert!(optional);
+    assert!(writable);
+
+    assert_eq!(
+        accounts::admit_challenger::ACCOUNTS[accounts::admit_challenger::PAYER],
+        AccountSpec {
+            name: "payer",
+            writable: true,
+            signer: true,
+            optional: false,
+        }
+    );
+}
+
+// -----------------
+// Processor
+// -----------------
+fn with_meta(
+    ix: &mut Instruction,
+    idx: usize,
+    meta: impl Fn(Pubkey) -> AccountMeta,
+) {
+    ix.accounts[idx] = meta(ix.accounts[idx].pubkey);
+}
+
+#[tokio::test]
+async fn missing_signer_fails_upfront() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    let creator = Pubkey::new_unique();
+
+    // the creator would need to sign
+    let mut ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    with_meta(&mut ix, accounts::start_challenge::CREATOR, |pubkey| {
+        AccountMeta::new_readonly(pubkey, false)
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert_challenge_error(res, ChallengeError::AccountShouldBeSigner);
+}
+
+#[tokio::test]
+async fn readonly_account_that_is_written_fails_upfront() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let mut ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    with_meta(
+        &mut ix,
+        accounts::start_challenge::CHALLENGE_PDA,
+        |pubkey| AccountMeta::new_readonly(pubkey, false),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&creator),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert_challenge_error(res, ChallengeError::AccountShouldBeWritable);
+}
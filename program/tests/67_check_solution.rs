@@ -0,0 +1,127 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    challenge_id, ixs,
+    return_data::CheckSolutionResult,
+    state::{Challenge, HasPda, SolveMode},
+    utils::hash_solutions,
+};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::utils::{add_pda_account, get_account, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn add_challenge(
+    context: &mut ProgramTestContext,
+    solving: u8,
+    solve_mode: SolveMode,
+) -> Challenge {
+    let challenge = Challenge {
+        authority: context.payer.pubkey(),
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        solving,
+        solve_mode,
+        solutions: hash_solutions(&["hello", "world"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    challenge
+}
+
+async fn check_solution(
+    context: &mut ProgramTestContext,
+    solution: &str,
+) -> CheckSolutionResult {
+    let ix = ixs::check_solution(context.payer.pubkey(), ID, solution)
+        .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let res = context
+        .banks_client
+        .simulate_transaction(tx)
+        .await
+        .expect("Failed to simulate transaction");
+    assert_eq!(res.result, Some(Ok(())));
+
+    let return_data = res
+        .simulation_details
+        .expect("simulation details")
+        .return_data
+        .expect("return data");
+    CheckSolutionResult::from_return_data(
+        &return_data.program_id,
+        &return_data.data,
+    )
+    .expect("check solution result")
+}
+
+#[tokio::test]
+async fn check_current_solution_is_correct() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, 1, SolveMode::Sequential);
+
+    let result = check_solution(&mut context, "world").await;
+    assert!(result.is_correct());
+    assert_eq!(result.solution_index, Some(1));
+}
+
+#[tokio::test]
+async fn check_solution_that_is_not_current_is_incorrect() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, 1, SolveMode::Sequential);
+
+    let result = check_solution(&mut context, "hello").await;
+    assert!(!result.is_correct());
+
+    let result = check_solution(&mut context, "not-a-solution").await;
+    assert_eq!(result.solution_index, None);
+}
+
+#[tokio::test]
+async fn check_any_order_solution_is_correct() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, 0, SolveMode::AnyOrder);
+
+    let result = check_solution(&mut context, "world").await;
+    assert_eq!(result.solution_index, Some(1));
+}
+
+#[tokio::test]
+async fn check_solution_does_not_modify_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = add_challenge(&mut context, 0, SolveMode::Sequential);
+    let challenge_pda = challenge.pda().0;
+    let before = get_account(&mut context, &challenge_pda).await;
+
+    let ix = ixs::check_solution(context.payer.pubkey(), ID, "hello")
+        .expect("failed to create instruction");
+    assert!(ix.accounts.iter().all(|meta| !meta.is_writable));
+    assert_eq!(ix.program_id, challenge_id());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to check solution");
+
+    let after = get_account(&mut context, &challenge_pda).await;
+    assert_eq!(after.data, before.data);
+    assert_eq!(after.lamports, before.lamports);
+}
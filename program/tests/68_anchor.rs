@@ -0,0 +1,67 @@
+#![cfg(all(feature = "test-sbf", feature = "anchor"))]
+
+use anchor_lang::{
+    AccountDeserialize, AccountSerialize, Discriminator, Id, Owner,
+};
+use challenge::{
+    anchor::ChallengeProgram,
+    challenge_id,
+    ixs::ChallengeInstruction,
+    state::{Challenge, Challenger, HasDiscriminator},
+    utils::hash_solutions,
+};
+use solana_program::{hash::hash, pubkey::Pubkey};
+
+fn challenge() -> Challenge {
+    Challenge {
+        authority: Pubkey::new_unique(),
+        id: "challenge-id".to_string(),
+        solutions: hash_solutions(&["hello", "world"]),
+        ..Challenge::default()
+    }
+}
+
+#[test]
+fn anchor_discriminators_match_account_discriminators() {
+    assert_eq!(
+        <Challenge as Discriminator>::DISCRIMINATOR,
+        <Challenge as HasDiscriminator>::DISCRIMINATOR
+    );
+    assert_eq!(
+        <Challenge as Discriminator>::DISCRIMINATOR,
+        hash(b"account:Challenge").to_bytes()[..8]
+    );
+    assert_eq!(Challenge::owner(), challenge_id());
+    assert_eq!(ChallengeProgram::id(), challenge_id());
+}
+
+#[test]
+fn challenge_account_round_trips_via_anchor() {
+    let challenge = challenge();
+    let mut data = vec![];
+    challenge.try_serialize(&mut data).unwrap();
+    assert_eq!(data, challenge.to_account_data().unwrap());
+
+    // trailing bytes, i.e. left over after solutions were removed, are ignored
+    data.extend_from_slice(&[0; 32]);
+    let decoded = Challenge::try_deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!(decoded.id, challenge.id);
+    assert_eq!(decoded.solutions, challenge.solutions);
+}
+
+#[test]
+fn deserializing_other_account_kind_fails() {
+    let data = Challenger::default().to_account_data().unwrap();
+    assert!(Challenge::try_deserialize(&mut data.as_slice()).is_err());
+    assert!(Challenger::try_deserialize(&mut data.as_slice()).is_ok());
+    assert!(Challenge::try_deserialize(&mut [0u8; 4].as_slice()).is_err());
+}
+
+#[test]
+fn instruction_anchor_discriminator() {
+    let ix = ChallengeInstruction::CheckSolution { solution: [0; 32] };
+    assert_eq!(
+        ix.anchor_discriminator(),
+        hash(b"global:check_solution").to_bytes()[..8]
+    );
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, ChallengeAuthority},
+    state::{Challenge, HasPda, HashAlgorithm},
+    utils::{
+        hash_solutions, hash_solutions_with_algorithm, weak_solutions,
+        weak_solutions_stored_as,
+    },
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+
+use crate::utils::{
+    add_pda_account, assert_challenge_error, get_deserialized, process_ixs,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn add_challenge(
+    context: &mut ProgramTestContext,
+    solutions: &[&str],
+    reject_weak_solutions: bool,
+) -> Pubkey {
+    let challenge = Challenge {
+        authority: context.payer.pubkey(),
+        id: ID.to_string(),
+        tries_per_admit: 1,
+        solutions: hash_solutions(solutions),
+        reject_weak_solutions,
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    challenge.pda().0
+}
+
+// -----------------
+// Weak Solutions
+// -----------------
+#[test]
+fn weak_solutions_include_common_passwords() {
+    let solutions = weak_solutions();
+    assert!(solutions.len() >= 100);
+    assert!(solutions.contains(&"password"));
+    assert!(solutions.contains(&"123456"));
+    assert!(solutions
+        .iter()
+        .all(|s| *s == s.trim() && *s == s.to_lowercase()));
+}
+
+#[test]
+fn weak_solutions_hashed_at_build_time_match_the_stored_hashes() {
+    for algorithm in [
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Keccak256,
+        HashAlgorithm::Blake3,
+    ] {
+        for salt in [None, Some([7; 32])] {
+            assert_eq!(
+                weak_solutions_stored_as(salt.as_ref(), algorithm),
+                hash_solutions_with_algorithm(
+                    weak_solutions(),
+                    salt.as_ref(),
+                    algorithm
+                ),
+            );
+        }
+    }
+}
+
+// -----------------
+// Adding Solutions
+// -----------------
+#[tokio::test]
+async fn adding_weak_solution_fails_when_rejected() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    add_challenge(&mut context, &["zebra crossing"], true);
+
+    let ix = ixs::add_solutions(
+        payer,
+        payer,
+        ID.to_string(),
+        vec!["purple elephant", "password"],
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::WeakSolution);
+
+    let ix = ixs::update_solution_hash(
+        payer,
+        payer,
+        ID.to_string(),
+        0,
+        hash_solutions(&["123456"])[0],
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::WeakSolution);
+}
+
+#[tokio::test]
+async fn adding_strong_or_unchecked_weak_solutions_succeeds() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    let challenge_pda = add_challenge(&mut context, &["zebra crossing"], true);
+
+    let ix = ixs::add_solutions(
+        payer,
+        payer,
+        ID.to_string(),
+        vec!["purple elephant"],
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("failed to add strong solution");
+
+    // weak solutions are accepted once the challenge no longer rejects them
+    let ixs = [
+        ixs::set_reject_weak_solutions(payer, payer, ID.to_string(), false)
+            .expect("failed to create instruction"),
+        ixs::add_solutions(payer, payer, ID.to_string(), vec!["password"])
+            .expect("failed to create instruction"),
+    ];
+    process_ixs(&mut context, &ixs, &[])
+        .await
+        .expect("failed to add weak solution");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(!challenge.reject_weak_solutions);
+    assert_eq!(challenge.solutions.len(), 3);
+}
+
+// -----------------
+// Set Reject Weak Solutions
+// -----------------
+#[tokio::test]
+async fn rejecting_weak_solutions_verifies_existing_ones() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    let challenge_pda =
+        add_challenge(&mut context, &["zebra crossing", "qwerty"], false);
+
+    let ix = ixs::set_reject_weak_solutions(payer, payer, ID.to_string(), true)
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::WeakSolution);
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(!challenge.reject_weak_solutions);
+}
+
+#[tokio::test]
+async fn rejecting_weak_solutions_requires_the_authority() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    add_challenge(&mut context, &["zebra crossing"], false);
+
+    let other = Pubkey::new_unique();
+    let mut ix = ixs::set_reject_weak_solutions(
+        payer,
+        ChallengeAuthority::transferred(other, payer),
+        ID.to_string(),
+        true,
+    )
+    .expect("failed to create instruction");
+    // provide the challenge of the payer with a creator that didn't sign
+    ix.accounts[1].is_signer = false;
+    assert!(process_ixs(&mut context, &[ix], &[]).await.is_err());
+}
@@ -0,0 +1,319 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, Redeem, Ticket},
+    utils::hash_solutions,
+};
+use solana_program::{program_option::COption, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::instruction::create_associated_token_account;
+use spl_token::state::Account as TokenAccount;
+
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, assert_challenge_error,
+    get_deserialized, get_mint, get_unpacked, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn add_challenge(
+    context: &mut ProgramTestContext,
+    started: bool,
+    ticket_admission: bool,
+) -> Challenge {
+    add_challenge_with_oracle(
+        context,
+        started,
+        ticket_admission,
+        Pubkey::default(),
+    )
+}
+
+fn add_challenge_with_oracle(
+    context: &mut ProgramTestContext,
+    started: bool,
+    ticket_admission: bool,
+    oracle: Pubkey,
+) -> Challenge {
+    let creator = context.payer.pubkey();
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started,
+        tries_per_admit: 2,
+        redeem: redeem.pda().0,
+        ticket_admission,
+        oracle,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    let (_, bump) = challenge.pda();
+    let challenge = challenge.with_pda_bump(bump);
+    add_pda_account(context, &challenge);
+    add_mint_to_redeem(context, &redeem);
+    challenge
+}
+
+/// Admits the [challenger] which receives the ticket, returning its challenger PDA.
+async fn admit_with_ticket(
+    context: &mut ProgramTestContext,
+    challenger: &Pubkey,
+) -> Pubkey {
+    let payer = context.payer.pubkey();
+    let AdmitChallengerIx {
+        ix, challenger_pda, ..
+    } = ixs::admit_challenger_with_ticket(payer, payer, ID, *challenger, None)
+        .expect("failed to create instruction");
+    process_ixs(context, &[ix], &[])
+        .await
+        .expect("Failed to admit challenger with ticket");
+    challenger_pda
+}
+
+/// Transfers the ticket of the admission with [challenger_pda] from [from] to [to].
+async fn transfer_ticket(
+    context: &mut ProgramTestContext,
+    challenger_pda: &Pubkey,
+    from: &Keypair,
+    to: &Pubkey,
+) {
+    let ticket = Ticket::new(*challenger_pda);
+    let ixs = [
+        create_associated_token_account(
+            &context.payer.pubkey(),
+            to,
+            &ticket.pda,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::transfer(
+            &spl_token::id(),
+            &ticket.ata(&from.pubkey()),
+            &ticket.ata(to),
+            &from.pubkey(),
+            &[],
+            1,
+        )
+        .unwrap(),
+    ];
+    process_ixs(context, &ixs, &[from])
+        .await
+        .expect("Failed to transfer ticket");
+}
+
+// -----------------
+// Admit
+// -----------------
+#[tokio::test]
+async fn admitting_mints_ticket_to_challenger() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, true, true);
+    let challenger = Pubkey::new_unique();
+
+    let challenger_pda = admit_with_ticket(&mut context, &challenger).await;
+
+    let ticket = Ticket::new(challenger_pda);
+    let (_, mint) = get_mint(&mut context, &ticket.pda).await;
+    assert_eq!(mint.supply, 1);
+    assert_eq!(mint.decimals, 0);
+    assert_eq!(mint.mint_authority, COption::None);
+
+    let (_, ticket_ata) =
+        get_unpacked::<TokenAccount>(&mut context, &ticket.ata(&challenger))
+            .await;
+    assert_eq!(ticket_ata.amount, 1);
+    assert_eq!(ticket_ata.owner, challenger);
+
+    let (_, challenger) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(challenger.tries_remaining, 2);
+}
+
+#[tokio::test]
+async fn admitting_without_ticket_accounts_fails() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, true, true);
+    let payer = context.payer.pubkey();
+
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(payer, payer, ID, Pubkey::new_unique())
+            .expect("failed to create instruction");
+    assert!(process_ixs(&mut context, &[ix], &[]).await.is_err());
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn holder_of_transferred_ticket_redeems_with_its_tries() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = add_challenge(&mut context, true, true);
+    let payer = context.payer.pubkey();
+    let challenger = Keypair::new();
+    let holder = Keypair::new();
+
+    let challenger_pda =
+        admit_with_ticket(&mut context, &challenger.pubkey()).await;
+    transfer_ticket(
+        &mut context,
+        &challenger_pda,
+        &challenger,
+        &holder.pubkey(),
+    )
+    .await;
+
+    // the challenger that was admitted no longer holds the ticket
+    let ix = ixs::redeem_with_ticket(
+        payer,
+        payer,
+        ID,
+        challenger.pubkey(),
+        challenger.pubkey(),
+        "one",
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[&challenger]).await;
+    assert_challenge_error(res, ChallengeError::TicketNotHeld);
+
+    let ix = ixs::redeem_with_ticket(
+        payer,
+        payer,
+        ID,
+        holder.pubkey(),
+        challenger.pubkey(),
+        "one",
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[&holder])
+        .await
+        .expect("Failed to redeem with ticket");
+
+    let (_, redeem_ata) = get_unpacked::<TokenAccount>(
+        &mut context,
+        &Redeem::new(challenge.pda().0).ata(&holder.pubkey()),
+    )
+    .await;
+    assert_eq!(redeem_ata.amount, 1);
+
+    let (_, challenger) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(challenger.tries_remaining, 1);
+    assert!(challenger.redeemed);
+}
+
+#[tokio::test]
+async fn holder_of_transferred_ticket_verifies_signed_solve() {
+    let mut context = program_test().start_with_context().await;
+    let oracle = Keypair::new();
+    let challenge =
+        add_challenge_with_oracle(&mut context, true, true, oracle.pubkey());
+    let payer = context.payer.pubkey();
+    let challenger = Keypair::new();
+    let holder = Keypair::new();
+
+    let challenger_pda =
+        admit_with_ticket(&mut context, &challenger.pubkey()).await;
+    transfer_ticket(
+        &mut context,
+        &challenger_pda,
+        &challenger,
+        &holder.pubkey(),
+    )
+    .await;
+
+    // the oracle signs the solve of the holder
+    let message = Challenge::oracle_message(
+        &challenge.pda().0,
+        &holder.pubkey(),
+        &challenge.solutions[0],
+    );
+    let signature: [u8; 64] = oracle
+        .sign_message(&message)
+        .as_ref()
+        .try_into()
+        .expect("signature should be 64 bytes");
+    let ixs = ixs::verify_solve_signature_with_ticket(
+        payer,
+        payer,
+        ID,
+        holder.pubkey(),
+        challenger.pubkey(),
+        oracle.pubkey(),
+        0,
+        &challenge.solutions[0],
+        signature,
+    )
+    .expect("failed to create instructions");
+    process_ixs(&mut context, &ixs, &[&holder])
+        .await
+        .expect("Failed to verify signed solve with ticket");
+
+    let (_, redeem_ata) = get_unpacked::<TokenAccount>(
+        &mut context,
+        &Redeem::new(challenge.pda().0).ata(&holder.pubkey()),
+    )
+    .await;
+    assert_eq!(redeem_ata.amount, 1);
+
+    let (_, challenger) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(challenger.tries_remaining, 1);
+    assert!(challenger.redeemed);
+}
+
+#[tokio::test]
+async fn redeeming_without_ticket_account_fails() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, true, true);
+    let payer = context.payer.pubkey();
+    let challenger = Keypair::new();
+    admit_with_ticket(&mut context, &challenger.pubkey()).await;
+
+    let ix = ixs::redeem(payer, payer, ID, challenger.pubkey(), "one")
+        .expect("failed to create instruction");
+    assert!(process_ixs(&mut context, &[ix], &[&challenger])
+        .await
+        .is_err());
+}
+
+// -----------------
+// Set Ticket Admission
+// -----------------
+#[tokio::test]
+async fn enable_and_disable_ticket_admission() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = add_challenge(&mut context, false, false);
+    let payer = context.payer.pubkey();
+
+    for enabled in [true, false] {
+        let ix =
+            ixs::set_ticket_admission(payer, payer, ID.to_string(), enabled)
+                .expect("failed to create instruction");
+        process_ixs(&mut context, &[ix], &[])
+            .await
+            .expect("Failed to set ticket admission");
+
+        let (_, challenge) =
+            get_deserialized::<Challenge>(&mut context, &challenge.pda().0)
+                .await;
+        assert_eq!(challenge.ticket_admission, enabled);
+    }
+}
+
+#[tokio::test]
+async fn setting_ticket_admission_of_started_challenge_fails() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, true, false);
+    let payer = context.payer.pubkey();
+
+    let ix = ixs::set_ticket_admission(payer, payer, ID.to_string(), true)
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ChallengeAlreadyStarted);
+}
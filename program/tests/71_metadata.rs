@@ -0,0 +1,132 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, ChallengeAuthority},
+    state::{
+        Challenge, ChallengeMetadata, HasPda, HasSize, MAX_METADATA_NAME_LEN,
+    },
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+
+use crate::utils::{
+    add_pda_account, assert_challenge_error, get_deserialized, process_ixs,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn add_challenge(context: &mut ProgramTestContext) -> Pubkey {
+    let challenge = Challenge {
+        authority: context.payer.pubkey(),
+        id: ID.to_string(),
+        tries_per_admit: 1,
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    challenge.pda().0
+}
+
+fn set_metadata_ix(
+    payer: Pubkey,
+    name: &str,
+    description: &str,
+    uri: &str,
+) -> Instruction {
+    ixs::set_metadata(
+        payer,
+        payer,
+        ID.to_string(),
+        name.to_string(),
+        description.to_string(),
+        uri.to_string(),
+    )
+    .expect("failed to create instruction")
+}
+
+// -----------------
+// Set Metadata
+// -----------------
+#[tokio::test]
+async fn set_metadata_creates_and_replaces_it() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    let challenge_pda = add_challenge(&mut context);
+    let (metadata_pda, _) = ChallengeMetadata::pda_for(&challenge_pda);
+
+    let ix = set_metadata_ix(
+        payer,
+        "Riddles",
+        "Solve the riddles of the sphinx",
+        "https://example.com/riddles.json",
+    );
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to set metadata");
+
+    let (acc, metadata) =
+        get_deserialized::<ChallengeMetadata>(&mut context, &metadata_pda)
+            .await;
+    assert_eq!(
+        metadata,
+        ChallengeMetadata {
+            challenge_pda,
+            name: "Riddles".to_string(),
+            description: "Solve the riddles of the sphinx".to_string(),
+            uri: "https://example.com/riddles.json".to_string(),
+        }
+    );
+    assert_eq!(acc.data.len(), metadata.size());
+
+    // shrinks the account when the metadata gets shorter
+    let ix = set_metadata_ix(payer, "Sphinx", "", "");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to replace metadata");
+
+    let (acc, metadata) =
+        get_deserialized::<ChallengeMetadata>(&mut context, &metadata_pda)
+            .await;
+    assert_eq!(metadata.name, "Sphinx");
+    assert!(metadata.description.is_empty());
+    assert!(metadata.uri.is_empty());
+    assert_eq!(acc.data.len(), metadata.size());
+}
+
+#[tokio::test]
+async fn set_metadata_exceeding_max_len_fails() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    add_challenge(&mut context);
+
+    let name = "x".repeat(MAX_METADATA_NAME_LEN + 1);
+    let ix = set_metadata_ix(payer, &name, "", "");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::MetadataTooLong);
+}
+
+#[tokio::test]
+async fn set_metadata_requires_the_authority() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    add_challenge(&mut context);
+
+    let other = Pubkey::new_unique();
+    let mut ix = ixs::set_metadata(
+        payer,
+        ChallengeAuthority::transferred(other, payer),
+        ID.to_string(),
+        "name".to_string(),
+        String::new(),
+        String::new(),
+    )
+    .expect("failed to create instruction");
+    // provide the challenge of the payer with a creator that didn't sign
+    ix.accounts[1].is_signer = false;
+    assert!(process_ixs(&mut context, &[ix], &[]).await.is_err());
+}
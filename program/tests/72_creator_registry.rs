@@ -0,0 +1,149 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, CreatorRegistry, HasSize},
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+
+use crate::utils::{
+    assert_challenge_error, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+fn create_challenge_ix(creator: Pubkey, id: &str) -> Instruction {
+    let mut ix = ixs::create_challenge(
+        creator,
+        creator,
+        id.to_string(),
+        0,
+        1,
+        vec!["hello"],
+        None,
+        None,
+    )
+    .expect("failed to create instruction");
+    ix.accounts.extend(ixs::creator_registry_accounts(&creator));
+    ix
+}
+
+async fn get_registry(
+    context: &mut ProgramTestContext,
+    creator: &Pubkey,
+) -> CreatorRegistry {
+    let (acc, registry) = get_deserialized::<CreatorRegistry>(
+        context,
+        &CreatorRegistry::pda_for(creator).0,
+    )
+    .await;
+    assert_eq!(acc.data.len(), registry.size());
+    registry
+}
+
+// -----------------
+// Create Challenge
+// -----------------
+#[tokio::test]
+async fn creating_challenges_lists_them_in_the_registry() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    process_ixs(&mut context, &[create_challenge_ix(creator, "first")], &[])
+        .await
+        .expect("Failed to create first challenge");
+    process_ixs(&mut context, &[create_challenge_ix(creator, "second")], &[])
+        .await
+        .expect("Failed to create second challenge");
+
+    // challenges created without the registry are not listed
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        "unlisted".to_string(),
+        0,
+        1,
+        vec!["hello"],
+        None,
+        None,
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to create unlisted challenge");
+
+    let registry = get_registry(&mut context, &creator).await;
+    assert_eq!(
+        registry,
+        CreatorRegistry {
+            creator,
+            challenges: vec![
+                Challenge::pda_for(&creator, "first").0,
+                Challenge::pda_for(&creator, "second").0,
+            ],
+        }
+    );
+}
+
+#[tokio::test]
+async fn creating_challenge_with_registry_of_other_creator_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let mut ix = create_challenge_ix(creator, "first");
+    let registry_idx = ix.accounts.len() - 1;
+    ix.accounts[registry_idx] = AccountMeta::new(
+        CreatorRegistry::pda_for(&Pubkey::new_unique()).0,
+        false,
+    );
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedPdaIncorrect);
+}
+
+// -----------------
+// Close Challenge
+// -----------------
+#[tokio::test]
+async fn closing_and_merging_challenges_removes_them_from_the_registry() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    for id in ["first", "second", "third"] {
+        process_ixs(&mut context, &[create_challenge_ix(creator, id)], &[])
+            .await
+            .expect("Failed to create challenge");
+    }
+
+    let mut close_ix =
+        ixs::close_challenge(creator, "first".to_string(), creator)
+            .expect("failed to create instruction");
+    close_ix
+        .accounts
+        .extend(ixs::creator_registry_accounts(&creator));
+    let mut merge_ix = ixs::merge_challenges(
+        creator,
+        creator,
+        "second".to_string(),
+        "third".to_string(),
+    )
+    .expect("failed to create instruction");
+    merge_ix
+        .accounts
+        .extend(ixs::creator_registry_accounts(&creator));
+    process_ixs(&mut context, &[close_ix, merge_ix], &[])
+        .await
+        .expect("Failed to close and merge challenges");
+
+    let registry = get_registry(&mut context, &creator).await;
+    assert_eq!(
+        registry.challenges,
+        vec![Challenge::pda_for(&creator, "second").0]
+    );
+}
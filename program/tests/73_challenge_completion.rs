@@ -0,0 +1,169 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{
+        Challenge, ChallengeHeader, ChallengeStatus, Challenger,
+        HasDiscriminator, HasPda, Redeem, SolveMode,
+    },
+    utils::hash_solutions,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, assert_challenge_error,
+    get_deserialized, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+fn challenge(solving: u8) -> Challenge {
+    Challenge {
+        authority: Pubkey::new_unique(),
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 2,
+        solving,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    }
+}
+
+fn header(challenge: &Challenge) -> ChallengeHeader {
+    let data = challenge.to_account_data().unwrap();
+    ChallengeHeader::try_from_account_data(&data).unwrap()
+}
+
+// -----------------
+// Status
+// -----------------
+#[test]
+fn status_follows_the_challenge_lifecycle() {
+    let created = Challenge {
+        started: false,
+        ..challenge(0)
+    };
+    assert_eq!(created.status(), ChallengeStatus::Created);
+    assert_eq!(challenge(0).status(), ChallengeStatus::Active);
+
+    let paused = Challenge {
+        paused: true,
+        ..challenge(0)
+    };
+    assert_eq!(paused.status(), ChallengeStatus::Paused);
+
+    let cancelled = Challenge {
+        paused: true,
+        cancelled: true,
+        ..challenge(0)
+    };
+    assert_eq!(cancelled.status(), ChallengeStatus::Cancelled);
+
+    let completed = Challenge {
+        finished: true,
+        ..challenge(2)
+    };
+    assert_eq!(completed.status(), ChallengeStatus::Completed);
+}
+
+// -----------------
+// Mark Solved
+// -----------------
+#[test]
+fn marking_last_solution_solved_completes_the_challenge() {
+    let mut header = header(&challenge(0));
+
+    header.mark_solved(0).unwrap();
+    assert_eq!(header.challenge.solving, 1);
+    assert_eq!(header.challenge.status(), ChallengeStatus::Active);
+
+    header.mark_solved(1).unwrap();
+    assert_eq!(header.challenge.solving, 2);
+    assert_eq!(header.remaining_solutions(), 0);
+    assert_eq!(header.challenge.status(), ChallengeStatus::Completed);
+}
+
+#[test]
+fn marking_solved_once_all_are_solved_fails() {
+    for solve_mode in [SolveMode::Sequential, SolveMode::AnyOrder] {
+        let mut solved = Challenge {
+            solve_mode,
+            ..challenge(2)
+        };
+        solved.solved[0] = 0b11;
+        let mut header = header(&solved);
+
+        assert_eq!(
+            header.mark_solved(1),
+            Err(ProgramError::from(ChallengeError::ChallengeCompleted))
+        );
+        assert_eq!(header.challenge.solving, 2);
+    }
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeeming_completed_challenge_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        redeem: redeem.pda().0,
+        solving: 1,
+        ..challenge(0)
+    };
+    let challenge_pda = challenge.pda().0;
+    add_pda_account(&mut context, &challenge);
+    add_mint_to_redeem(&mut context, &redeem);
+
+    let challengers = [Keypair::new(), Keypair::new()];
+    for challenger in &challengers {
+        add_pda_account(
+            &mut context,
+            &Challenger {
+                authority: challenger.pubkey(),
+                challenge_pda,
+                tries_remaining: 2,
+                ..Challenger::default()
+            },
+        );
+    }
+
+    // both challengers attempt to redeem the last solution
+    let mut txs = challengers.iter().map(|challenger| {
+        let ix = ixs::redeem(creator, creator, ID, challenger.pubkey(), "two")
+            .expect("failed to create instruction");
+        Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&creator),
+            &[&context.payer, challenger],
+            context.last_blockhash,
+        )
+    });
+    let (first_tx, second_tx) = (txs.next().unwrap(), txs.next().unwrap());
+
+    context
+        .banks_client
+        .process_transaction(first_tx)
+        .await
+        .expect("Failed to redeem last solution");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.solving, 2);
+    assert_eq!(challenge.status(), ChallengeStatus::Completed);
+
+    let res = context.banks_client.process_transaction(second_tx).await;
+    assert_challenge_error(res, ChallengeError::ChallengeCompleted);
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::ixs::{self, AdmitChallengerIx};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::utils::{
+    add_challenge_with_solutions, add_started_challenge_with_solutions,
+    assert_lamport_conservation, get_lamports, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+/// Processes the [ix] signed by the payer and asserts that the lamports of all accounts
+/// passed to it add up.
+async fn process_conserving_lamports(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) {
+    let pubkeys = ix
+        .accounts
+        .iter()
+        .map(|meta| meta.pubkey)
+        .collect::<Vec<_>>();
+    let fees = context
+        .genesis_config()
+        .fee_rate_governor
+        .lamports_per_signature;
+
+    let before = get_lamports(context, &pubkeys).await;
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to process instruction");
+    let after = get_lamports(context, &pubkeys).await;
+
+    assert_lamport_conservation(&before, &after, fees);
+}
+
+// -----------------
+// Helper
+// -----------------
+#[test]
+fn lamports_moved_between_accounts_are_conserved() {
+    assert_lamport_conservation(&[100, 50, 0], &[40, 50, 55], 5);
+}
+
+#[test]
+#[should_panic(expected = "lamports were created")]
+fn lamports_appearing_are_not_conserved() {
+    assert_lamport_conservation(&[100, 50], &[100, 51], 0);
+}
+
+#[test]
+#[should_panic(expected = "lamports were burned")]
+fn lamports_disappearing_are_not_conserved() {
+    assert_lamport_conservation(&[100, 50], &[90, 50], 5);
+}
+
+// -----------------
+// Instructions
+// -----------------
+#[tokio::test]
+async fn admitting_challenger_conserves_lamports() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_started_challenge_with_solutions(&mut context, ID, vec!["hello"], None);
+
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, creator, ID, Pubkey::new_unique())
+            .expect("failed to create instruction");
+    process_conserving_lamports(&mut context, ix).await;
+}
+
+#[tokio::test]
+async fn closing_challenge_conserves_lamports() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["hello"], None);
+
+    let ix =
+        ixs::close_challenge(creator, ID.to_string(), Pubkey::new_unique())
+            .expect("failed to create instruction");
+    process_conserving_lamports(&mut context, ix).await;
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    challenge_id,
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, HasPda},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    pubkey::Pubkey,
+    system_program,
+};
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData, signer::Signer, transaction::Transaction,
+};
+
+use crate::utils::{
+    add_pda_account, assert_challenge_error, get_deserialized, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const VAULT_SEED: &[u8] = b"vault";
+const VAULT_LAMPORTS: u64 = 1_000_000_000;
+
+fn multisig_id() -> Pubkey {
+    Pubkey::new_from_array([9; 32])
+}
+
+fn vault() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED], &multisig_id())
+}
+
+/// A multisig executing an approved challenge instruction on behalf of its vault.
+/// The instruction is passed as its data followed by its accounts and the challenge
+/// program, the vault signs for it via `invoke_signed`.
+fn process_multisig<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (vault, bump) = vault();
+    let (_program_info, ix_accounts) = accounts.split_last().unwrap();
+    let ix = Instruction {
+        program_id: challenge_id(),
+        accounts: ix_accounts
+            .iter()
+            .map(|info| AccountMeta {
+                pubkey: *info.key,
+                is_signer: info.key == &vault || info.is_signer,
+                is_writable: info.is_writable,
+            })
+            .collect(),
+        data: instruction_data.to_vec(),
+    };
+    invoke_signed(&ix, accounts, &[&[VAULT_SEED, &[bump]]])
+}
+
+async fn setup() -> ProgramTestContext {
+    let mut program_test = program_test();
+    program_test.add_program(
+        "multisig",
+        multisig_id(),
+        processor!(process_multisig),
+    );
+    let mut context = program_test.start_with_context().await;
+    context.set_account(
+        &vault().0,
+        &AccountSharedData::new(VAULT_LAMPORTS, 0, &system_program::id()),
+    );
+    context
+}
+
+/// Wraps the challenge [ix] into an instruction executed by the multisig.
+fn via_multisig(ix: Instruction) -> Instruction {
+    let vault = vault().0;
+    let mut accounts = ix
+        .accounts
+        .into_iter()
+        .map(|meta| AccountMeta {
+            is_signer: meta.is_signer && meta.pubkey != vault,
+            ..meta
+        })
+        .collect::<Vec<_>>();
+    accounts.push(AccountMeta::new_readonly(challenge_id(), false));
+    Instruction {
+        program_id: multisig_id(),
+        accounts,
+        data: ix.data,
+    }
+}
+
+async fn process_ix(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+fn add_challenge(
+    context: &mut ProgramTestContext,
+    authority: Pubkey,
+    pending_authority: Option<Pubkey>,
+) -> Pubkey {
+    let challenge = Challenge {
+        authority,
+        pending_authority,
+        id: ID.to_string(),
+        tries_per_admit: 1,
+        ..Challenge::default()
+    };
+    let (pda, bump) = challenge.pda();
+    add_pda_account(context, &challenge.with_pda_bump(bump));
+    pda
+}
+
+// -----------------
+// Vault Authority
+// -----------------
+#[tokio::test]
+async fn multisig_vault_starts_its_challenge() {
+    let mut context = setup().await;
+    let vault = vault().0;
+    let challenge_pda = add_challenge(&mut context, vault, None);
+
+    let ix = ixs::start_challenge(vault, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, via_multisig(ix))
+        .await
+        .expect("Failed to start challenge via multisig");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(challenge.started);
+}
+
+#[tokio::test]
+async fn multisig_vault_accepts_authority() {
+    let mut context = setup().await;
+    let creator = context.payer.pubkey();
+    let vault = vault().0;
+    let challenge_pda = add_challenge(&mut context, creator, Some(vault));
+
+    let ix = ixs::accept_authority(vault, creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, via_multisig(ix))
+        .await
+        .expect("Failed to accept authority via multisig");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.authority, vault);
+    assert_eq!(challenge.original_authority, Some(creator));
+}
+
+#[tokio::test]
+async fn vault_that_is_not_signed_for_is_rejected() {
+    let mut context = setup().await;
+    let vault = vault().0;
+    add_challenge(&mut context, vault, None);
+
+    let mut ix = ixs::start_challenge(vault, ID.to_string())
+        .expect("failed to create instruction");
+    ix.accounts[0].is_signer = false;
+    let res = process_ix(&mut context, ix).await;
+    assert_challenge_error(res, ChallengeError::AccountShouldBeSigner);
+}
@@ -0,0 +1,236 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx},
+    state::{
+        Challenge, Challenger, HasPda, Payout, PrizePool, Redeem,
+        ALLOW_ADD_AFTER_START, ALLOW_REFUNDS, DEFAULT_CHALLENGE_FLAGS,
+        KNOWN_CHALLENGE_FLAGS, REQUIRE_COMMIT_REVEAL,
+    },
+    utils::hash_solutions,
+};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_program_test::*;
+
+use solana_sdk::{
+    account::AccountSharedData,
+    signature::{Keypair, Signer},
+};
+
+use crate::utils::{
+    add_mint_to_redeem, add_pda_account, assert_challenge_error,
+    get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_000_000;
+const TRIES_PER_ADMIT: u8 = 2;
+const CHALLENGER_LAMPORTS: u64 = 1_000_000_000;
+
+fn add_challenge(
+    context: &mut ProgramTestContext,
+    started: bool,
+    flags: u32,
+) -> Challenge {
+    let creator = context.payer.pubkey();
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: redeem.pda().0,
+        has_prize_pool: true,
+        flags,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+    add_mint_to_redeem(context, &redeem);
+    add_pda_account(
+        context,
+        &PrizePool {
+            challenge_pda: challenge.pda().0,
+            payout: Payout::WinnerTakesAll,
+        },
+    );
+    challenge
+}
+
+/// Admits a funded challenger, returning it together with its challenger PDA.
+async fn admit(context: &mut ProgramTestContext) -> (Keypair, Pubkey) {
+    let challenger = Keypair::new();
+    context.set_account(
+        &challenger.pubkey(),
+        &AccountSharedData::new(CHALLENGER_LAMPORTS, 0, &system_program::id()),
+    );
+
+    let payer = context.payer.pubkey();
+    let AdmitChallengerIx {
+        ix, challenger_pda, ..
+    } = ixs::admit_challenger(payer, payer, ID, challenger.pubkey())
+        .expect("failed to create admit instruction");
+    process_ixs(context, &[ix], &[])
+        .await
+        .expect("failed to admit challenger");
+    (challenger, challenger_pda)
+}
+
+async fn set_flags(
+    context: &mut ProgramTestContext,
+    flags: u32,
+) -> Result<(), BanksClientError> {
+    let payer = context.payer.pubkey();
+    let ix = ixs::set_challenge_flags(payer, payer, ID.to_string(), flags)
+        .expect("failed to create instruction");
+    process_ixs(context, &[ix], &[]).await
+}
+
+#[test]
+fn flag_helpers() {
+    let mut challenge = Challenge::default();
+    assert_eq!(challenge.flags, DEFAULT_CHALLENGE_FLAGS);
+    assert!(challenge.allows_add_after_start());
+    assert!(!challenge.allows_refunds());
+    assert!(!challenge.requires_commit_reveal());
+
+    challenge.set_flag(ALLOW_REFUNDS | REQUIRE_COMMIT_REVEAL, true);
+    challenge.set_flag(ALLOW_ADD_AFTER_START, false);
+    assert_eq!(challenge.flags, ALLOW_REFUNDS | REQUIRE_COMMIT_REVEAL);
+    assert!(challenge.has_flag(ALLOW_REFUNDS | REQUIRE_COMMIT_REVEAL));
+    assert!(!challenge.has_flag(ALLOW_REFUNDS | ALLOW_ADD_AFTER_START));
+    assert!(!challenge.allows_add_after_start());
+    assert!(challenge.allows_refunds());
+    assert!(challenge.requires_commit_reveal());
+
+    let commit_reveal = Challenge {
+        commit_reveal: true,
+        ..Challenge::default()
+    };
+    assert!(commit_reveal.requires_commit_reveal());
+}
+
+// -----------------
+// Set Challenge Flags
+// -----------------
+#[tokio::test]
+async fn set_flags_before_start() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = add_challenge(&mut context, false, DEFAULT_CHALLENGE_FLAGS);
+
+    set_flags(&mut context, KNOWN_CHALLENGE_FLAGS)
+        .await
+        .expect("Failed to set challenge flags");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(challenge.flags, KNOWN_CHALLENGE_FLAGS);
+}
+
+#[tokio::test]
+async fn setting_unknown_flags_fails() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, false, DEFAULT_CHALLENGE_FLAGS);
+
+    let res = set_flags(&mut context, ALLOW_REFUNDS | 1 << 31).await;
+    assert_challenge_error(res, ChallengeError::UnknownChallengeFlags);
+}
+
+#[tokio::test]
+async fn setting_flags_of_started_challenge_fails() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, true, DEFAULT_CHALLENGE_FLAGS);
+
+    let res = set_flags(&mut context, ALLOW_REFUNDS).await;
+    assert_challenge_error(res, ChallengeError::ChallengeAlreadyStarted);
+}
+
+// -----------------
+// Enforcement
+// -----------------
+#[tokio::test]
+async fn adding_solutions_after_start_requires_flag() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = add_challenge(&mut context, true, 0);
+    let payer = context.payer.pubkey();
+
+    let ix = ixs::add_solutions(payer, payer, ID.to_string(), vec!["three"])
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(
+        res,
+        ChallengeError::AddingSolutionsAfterStartNotAllowed,
+    );
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(challenge.solutions.len(), 2);
+}
+
+#[tokio::test]
+async fn adding_solutions_after_start_with_default_flags() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = add_challenge(&mut context, true, DEFAULT_CHALLENGE_FLAGS);
+    let payer = context.payer.pubkey();
+
+    let ix = ixs::add_solutions(payer, payer, ID.to_string(), vec!["three"])
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to add solutions");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(challenge.solutions.len(), 3);
+}
+
+#[tokio::test]
+async fn refunds_without_cancelling_require_flag() {
+    for (flags, allowed) in [
+        (DEFAULT_CHALLENGE_FLAGS, false),
+        (DEFAULT_CHALLENGE_FLAGS | ALLOW_REFUNDS, true),
+    ] {
+        let mut context = program_test().start_with_context().await;
+        add_challenge(&mut context, true, flags);
+        let (challenger, challenger_pda) = admit(&mut context).await;
+
+        let ix = ixs::refund_unused_tries(
+            context.payer.pubkey(),
+            ID.to_string(),
+            challenger.pubkey(),
+            None,
+        )
+        .expect("failed to create refund instruction");
+        let res = process_ixs(&mut context, &[ix], &[&challenger]).await;
+        if !allowed {
+            assert_challenge_error(res, ChallengeError::ChallengeNotCancelled);
+            continue;
+        }
+        res.expect("Failed to refund unused tries");
+
+        let (_, challenger) =
+            get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+        assert_eq!(challenger.tries_remaining, 0);
+    }
+}
+
+#[tokio::test]
+async fn redeeming_without_commitment_fails_when_flag_requires_it() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(
+        &mut context,
+        true,
+        DEFAULT_CHALLENGE_FLAGS | REQUIRE_COMMIT_REVEAL,
+    );
+    let (challenger, _) = admit(&mut context).await;
+    let payer = context.payer.pubkey();
+
+    let ix = ixs::redeem(payer, payer, ID, challenger.pubkey(), "one")
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[&challenger]).await;
+    assert_challenge_error(res, ChallengeError::CommitRevealRequired);
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{ixs, state::Challenge, utils::hash_solutions};
+use solana_program::instruction::Instruction;
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::utils::{add_pda_account, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn process_ix_returning_logs(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) -> Vec<String> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("Failed to process transaction");
+    assert!(result.result.is_ok(), "transaction failed {:?}", result);
+
+    result.metadata.expect("transaction metadata").log_messages
+}
+
+fn assert_logged(logs: &[String], line: &str) {
+    assert!(
+        logs.iter().any(|log| log == line),
+        "logs should include '{}', got {:#?}",
+        line,
+        logs
+    );
+}
+
+fn add_challenge(context: &mut ProgramTestContext, started: bool) {
+    let challenge = Challenge {
+        authority: context.payer.pubkey(),
+        id: ID.to_string(),
+        started,
+        tries_per_admit: 1,
+        solutions: hash_solutions(&["one", "two"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+}
+
+#[tokio::test]
+async fn logs_processed_instruction() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, false);
+
+    let ix = ixs::start_challenge(context.payer.pubkey(), ID.to_string())
+        .expect("failed to create instruction");
+    let logs = process_ix_returning_logs(&mut context, ix).await;
+
+    assert_logged(&logs, "Program log: IX: start challenge");
+}
+
+#[tokio::test]
+async fn logs_state_changes() {
+    let mut context = program_test().start_with_context().await;
+    add_challenge(&mut context, true);
+
+    let ix = ixs::check_solution(context.payer.pubkey(), ID, "one")
+        .expect("failed to create instruction");
+    let logs = process_ix_returning_logs(&mut context, ix).await;
+    assert_logged(&logs, "Program log: IX: check solution");
+    assert_logged(
+        &logs,
+        "Program log: State: Provided solution solves solution 0",
+    );
+
+    let ix = ixs::check_solution(context.payer.pubkey(), ID, "three")
+        .expect("failed to create instruction");
+    let logs = process_ix_returning_logs(&mut context, ix).await;
+    assert_logged(&logs, "Program log: State: Provided solution is incorrect");
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, CreatorRegistry, HashAlgorithm},
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+
+use crate::utils::{
+    assert_challenge_error, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn process_ixs_with_new_blockhash(
+    context: &mut ProgramTestContext,
+    ixs: &[Instruction],
+) -> Result<(), BanksClientError> {
+    // retries are sent with a new blockhash so they aren't rejected as duplicates
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("failed to get new blockhash");
+    process_ixs(context, ixs, &[]).await
+}
+
+fn create_ix(creator: Pubkey, admit_cost: u64) -> Instruction {
+    ixs::create_challenge_idempotent(
+        creator,
+        creator,
+        ID.to_string(),
+        admit_cost,
+        1,
+        vec!["hello", "world"],
+        None,
+        None,
+        Some([1; 32]),
+        HashAlgorithm::Sha256,
+    )
+    .expect("failed to create instruction")
+}
+
+#[tokio::test]
+async fn creating_twice_with_same_params_succeeds() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    process_ixs_with_new_blockhash(&mut context, &[create_ix(creator, 100)])
+        .await
+        .expect("Failed to create challenge");
+    process_ixs_with_new_blockhash(&mut context, &[create_ix(creator, 100)])
+        .await
+        .expect("Failed to create challenge again");
+
+    let (_, challenge) = get_deserialized::<Challenge>(
+        &mut context,
+        &Challenge::pda_for(&creator, ID).0,
+    )
+    .await;
+    assert_eq!(challenge.admit_cost, 100);
+    assert_eq!(challenge.solutions.len(), 2);
+}
+
+#[tokio::test]
+async fn creating_idempotent_after_regular_create_succeeds() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let ix = ixs::create_salted_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        100,
+        1,
+        vec!["hello", "world"],
+        None,
+        None,
+        Some([1; 32]),
+        HashAlgorithm::Sha256,
+    )
+    .expect("failed to create instruction");
+    process_ixs_with_new_blockhash(&mut context, &[ix])
+        .await
+        .expect("Failed to create challenge");
+
+    process_ixs_with_new_blockhash(&mut context, &[create_ix(creator, 100)])
+        .await
+        .expect("Failed to create challenge idempotent");
+}
+
+#[tokio::test]
+async fn creating_again_with_different_params_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    process_ixs_with_new_blockhash(&mut context, &[create_ix(creator, 100)])
+        .await
+        .expect("Failed to create challenge");
+
+    let res = process_ixs_with_new_blockhash(
+        &mut context,
+        &[create_ix(creator, 200)],
+    )
+    .await;
+    assert_challenge_error(res, ChallengeError::ChallengeParamsMismatch);
+}
+
+#[tokio::test]
+async fn retrying_with_registry_lists_challenge_once() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    // the first attempt did not include the registry
+    process_ixs_with_new_blockhash(&mut context, &[create_ix(creator, 100)])
+        .await
+        .expect("Failed to create challenge");
+
+    for _ in 0..2 {
+        let mut ix = create_ix(creator, 100);
+        ix.accounts.extend(ixs::creator_registry_accounts(&creator));
+        process_ixs_with_new_blockhash(&mut context, &[ix])
+            .await
+            .expect("Failed to create challenge with registry");
+    }
+
+    let (_, registry) = get_deserialized::<CreatorRegistry>(
+        &mut context,
+        &CreatorRegistry::pda_for(&creator).0,
+    )
+    .await;
+    assert_eq!(
+        registry.challenges,
+        vec![Challenge::pda_for(&creator, ID).0]
+    );
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    accounts,
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, HasPda, HashAlgorithm},
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+
+use crate::utils::{
+    assert_challenge_error, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const SEED: [u8; 32] = [7; 32];
+
+fn create_seeded_ix(creator: Pubkey, seed: [u8; 32]) -> Instruction {
+    ixs::create_seeded_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        seed,
+        100,
+        1,
+        vec!["hello", "world"],
+        None,
+        None,
+        None,
+        HashAlgorithm::Sha256,
+    )
+    .expect("failed to create instruction")
+}
+
+/// Start instruction for the challenge at [challenge_pda] since the builder derives the PDA
+/// without a seed.
+fn start_ix(creator: Pubkey, challenge_pda: Pubkey) -> Instruction {
+    let mut ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    ix.accounts[accounts::start_challenge::CHALLENGE_PDA] =
+        AccountMeta::new(challenge_pda, false);
+    ix
+}
+
+#[tokio::test]
+async fn create_seeded_challenge_stores_seed_at_derived_address() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    process_ixs(&mut context, &[create_seeded_ix(creator, SEED)], &[])
+        .await
+        .expect("Failed to create seeded challenge");
+
+    let (challenge_pda, bump) = Challenge::pda_with_seed(&creator, ID, &SEED);
+    assert_ne!(challenge_pda, Challenge::pda_for(&creator, ID).0);
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.seed, Some(SEED));
+    assert_eq!(challenge.bump, bump);
+    assert_eq!(challenge.pda(), (challenge_pda, bump));
+    assert_eq!(challenge.solutions.len(), 2);
+}
+
+#[tokio::test]
+async fn seeded_challenge_is_managed_via_its_seeded_address() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    process_ixs(&mut context, &[create_seeded_ix(creator, SEED)], &[])
+        .await
+        .expect("Failed to create seeded challenge");
+
+    let (challenge_pda, _) = Challenge::pda_with_seed(&creator, ID, &SEED);
+    process_ixs(&mut context, &[start_ix(creator, challenge_pda)], &[])
+        .await
+        .expect("Failed to start seeded challenge");
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert!(challenge.started);
+}
+
+#[tokio::test]
+async fn create_seeded_challenge_with_pda_of_other_seed_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let mut ix = create_seeded_ix(creator, SEED);
+    ix.accounts[accounts::create_seeded_challenge::CHALLENGE_PDA] =
+        AccountMeta::new(
+            Challenge::pda_with_seed(&creator, ID, &[8; 32]).0,
+            false,
+        );
+
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedChallengePdaIncorrect);
+}
+
+#[tokio::test]
+async fn seeded_challenge_at_address_of_other_seed_is_rejected() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    process_ixs(&mut context, &[create_seeded_ix(creator, SEED)], &[])
+        .await
+        .expect("Failed to create seeded challenge");
+
+    // the account of the seeded challenge is copied to the address of another seed
+    let (challenge_pda, _) = Challenge::pda_with_seed(&creator, ID, &SEED);
+    let account = context
+        .banks_client
+        .get_account(challenge_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let (other_pda, _) = Challenge::pda_with_seed(&creator, ID, &[8; 32]);
+    context.set_account(&other_pda, &account.into());
+
+    let res =
+        process_ixs(&mut context, &[start_ix(creator, other_pda)], &[]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedChallengePdaIncorrect);
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs,
+    snapshot::{ChallengeSnapshot, SnapshotAccount},
+    state::{Challenge, Challenger, HasPda, HasSize, Redeem},
+    test_utils::{import_challenge_snapshot, rent_exempt_lamports},
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_token::state::Mint;
+
+use crate::utils::{get_deserialized, get_unpacked, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+/// Snapshot of a started challenge whose first solution was solved and which admitted the
+/// [challenger] with one try left.
+fn snapshot(creator: &Pubkey, challenger: &Pubkey) -> ChallengeSnapshot {
+    let redeem = Redeem::for_challenge_with(creator, ID);
+    let challenge = Challenge {
+        authority: *creator,
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 2,
+        redeem: redeem.pda().0,
+        solutions: hash_solutions(&["one", "two"]),
+        solving: 1,
+        admissions: 1,
+        ..Challenge::default()
+    };
+    let (challenge_pda, bump) = challenge.pda();
+    let challenge = challenge.with_pda_bump(bump);
+    let challenger = Challenger {
+        authority: *challenger,
+        challenge_pda,
+        tries_remaining: 1,
+        tries_admitted: 2,
+        tries_used: 1,
+        ..Challenger::default()
+    };
+    let (challenger_pda, _) = challenger.pda();
+
+    ChallengeSnapshot {
+        challenge: SnapshotAccount {
+            address: challenge_pda,
+            lamports: rent_exempt_lamports(&challenge) + 1_000,
+            state: challenge,
+        },
+        challengers: vec![SnapshotAccount {
+            address: challenger_pda,
+            lamports: rent_exempt_lamports(&challenger),
+            state: challenger,
+        }],
+        leaderboard: None,
+        prize_pool: None,
+        escrow: None,
+        redeem_supply: 1,
+    }
+}
+
+#[tokio::test]
+async fn imported_snapshot_reproduces_challenge_state() {
+    let creator = Pubkey::new_unique();
+    let challenger = Keypair::new();
+    let snapshot = snapshot(&creator, &challenger.pubkey());
+
+    let mut program_test = program_test();
+    import_challenge_snapshot(&mut program_test, &snapshot);
+    let mut context = program_test.start_with_context().await;
+
+    let (account, challenge) = get_deserialized::<Challenge>(
+        &mut context,
+        &snapshot.challenge.address,
+    )
+    .await;
+    assert_eq!(account.lamports, snapshot.challenge.lamports);
+    assert_eq!(account.data.len(), challenge.size());
+    assert_eq!(challenge.solving, 1);
+
+    let (_, redeem_mint) =
+        get_unpacked::<Mint>(&mut context, &challenge.redeem).await;
+    assert_eq!(redeem_mint.supply, 1);
+
+    // the imported challenger continues with the next solution
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.pubkey(),
+        "two",
+    )
+    .expect("failed to create instruction");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &challenger],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to redeem imported challenge");
+
+    let (_, challenger) = get_deserialized::<Challenger>(
+        &mut context,
+        &snapshot.challengers[0].address,
+    )
+    .await;
+    assert!(challenger.redeemed);
+    assert_eq!(challenger.tries_remaining, 0);
+
+    let (_, redeem_mint) =
+        get_unpacked::<Mint>(&mut context, &challenge.redeem).await;
+    assert_eq!(redeem_mint.supply, 2);
+}
@@ -0,0 +1,176 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, Challenger, HasPda},
+    utils::hash_solutions,
+};
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signer::Signer;
+
+use crate::utils::{
+    add_pda_account, airdrop_rent, assert_challenge_error, get_account,
+    get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 300;
+const TRIES_PER_ADMIT: u8 = 4;
+
+fn setup_challenger(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+    max_tries_per_challenger: Option<u16>,
+) -> (Challenge, Challenger) {
+    let challenge = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(&["hello", "world"]),
+        max_tries_per_challenger,
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+
+    let challenger = Challenger {
+        authority: Pubkey::new_unique(),
+        challenge_pda: challenge.pda().0,
+        tries_remaining: 1,
+        tries_admitted: TRIES_PER_ADMIT as u16,
+        tries_used: 3,
+        ..Challenger::default()
+    };
+    add_pda_account(context, &challenger);
+    (challenge, challenger)
+}
+
+async fn buy_more_tries(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+    challenger: &Challenger,
+    count: u8,
+) -> Result<(), BanksClientError> {
+    let ix = ixs::buy_more_tries(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger.authority,
+        count,
+    )
+    .expect("failed to create instruction");
+    process_ixs(context, &[ix], &[]).await
+}
+
+#[tokio::test]
+async fn buy_more_tries_adds_tries_and_pays_pro_rated_cost() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    let creator_lamports = airdrop_rent(&mut context, &creator, 0).await;
+    let (challenge, challenger) = setup_challenger(&mut context, creator, None);
+
+    buy_more_tries(&mut context, creator, &challenger, 3)
+        .await
+        .expect("Failed to buy more tries");
+
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger.pda().0).await;
+    assert_eq!(value.tries_remaining, 4);
+    assert_eq!(value.tries_admitted, TRIES_PER_ADMIT as u16 + 3);
+    assert_eq!(value.tries_used, 3);
+
+    // 300 per 4 tries makes 225 for 3 tries
+    assert_eq!(challenge.tries_cost(3), Ok(225));
+    let creator_acc = get_account(&mut context, &creator).await;
+    assert_eq!(
+        creator_acc.lamports,
+        creator_lamports + 225,
+        "creator should have received the cost of the tries"
+    );
+}
+
+#[tokio::test]
+async fn set_max_tries_per_challenger_updates_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    setup_challenger(&mut context, creator, None);
+
+    let ix = ixs::set_max_tries_per_challenger(
+        creator,
+        creator,
+        ID.to_string(),
+        Some(6),
+    )
+    .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to set max tries per challenger");
+
+    let (_, challenge) = get_deserialized::<Challenge>(
+        &mut context,
+        &Challenge::pda_for(&creator, ID).0,
+    )
+    .await;
+    assert_eq!(challenge.max_tries_per_challenger, Some(6));
+}
+
+#[tokio::test]
+async fn buy_more_tries_up_to_max_tries_per_challenger() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+    let (_, challenger) = setup_challenger(
+        &mut context,
+        creator,
+        Some(TRIES_PER_ADMIT as u16 + 2),
+    );
+
+    buy_more_tries(&mut context, creator, &challenger, 2)
+        .await
+        .expect("Failed to buy tries up to the max");
+
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger.pda().0).await;
+    assert_eq!(value.tries_admitted, TRIES_PER_ADMIT as u16 + 2);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn buy_zero_tries_fails() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+    let (_, challenger) = setup_challenger(&mut context, creator, None);
+
+    let res = buy_more_tries(&mut context, creator, &challenger, 0).await;
+    assert_challenge_error(res, ChallengeError::InvalidTriesCount);
+}
+
+#[tokio::test]
+async fn buy_more_tries_exceeding_max_tries_per_challenger_fails() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+    let (_, challenger) = setup_challenger(
+        &mut context,
+        creator,
+        Some(TRIES_PER_ADMIT as u16 + 2),
+    );
+
+    let res = buy_more_tries(&mut context, creator, &challenger, 3).await;
+    assert_challenge_error(res, ChallengeError::MaxTriesPerChallengerExceeded);
+}
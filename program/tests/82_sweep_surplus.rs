@@ -0,0 +1,132 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{error::ChallengeError, ixs, state::Challenge};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_instruction,
+};
+
+use crate::utils::{
+    add_challenge_with_solutions, airdrop_rent, assert_challenge_error,
+    get_account, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+/// Adds a challenge of the payer and a treasury that already exists.
+async fn setup(context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(context, ID, vec!["one"], Some(creator));
+    let treasury = Pubkey::new_unique();
+    airdrop_rent(context, &treasury, 0).await;
+    (Challenge::pda_for(&creator, ID).0, treasury)
+}
+
+fn over_fund_ix(
+    context: &ProgramTestContext,
+    challenge_pda: &Pubkey,
+) -> Instruction {
+    system_instruction::transfer(&context.payer.pubkey(), challenge_pda, 1_000)
+}
+
+fn sweep_ix(context: &ProgramTestContext, treasury: Pubkey) -> Instruction {
+    ixs::sweep_surplus(context.payer.pubkey(), ID.to_string(), treasury)
+        .expect("failed to create instruction")
+}
+
+fn rent_exempt_minimum(account_len: usize) -> u64 {
+    Rent::default().minimum_balance(account_len)
+}
+
+#[tokio::test]
+async fn sweep_surplus_moves_lamports_beyond_rent_to_treasury() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge_pda, treasury) = setup(&mut context).await;
+    let treasury_lamports = get_account(&mut context, &treasury).await.lamports;
+
+    let ixs = [
+        over_fund_ix(&context, &challenge_pda),
+        sweep_ix(&context, treasury),
+    ];
+    process_ixs(&mut context, &ixs, &[])
+        .await
+        .expect("Failed to sweep surplus");
+
+    let challenge_acc = get_account(&mut context, &challenge_pda).await;
+    assert_eq!(
+        challenge_acc.lamports,
+        rent_exempt_minimum(challenge_acc.data.len())
+    );
+    let treasury_acc = get_account(&mut context, &treasury).await;
+    assert_eq!(treasury_acc.lamports, treasury_lamports + 1_000);
+}
+
+#[tokio::test]
+async fn sweep_surplus_keeps_sponsored_lamports() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge_pda, treasury) = setup(&mut context).await;
+    let treasury_lamports = get_account(&mut context, &treasury).await.lamports;
+
+    let sponsor = Keypair::new();
+    let ixs = [
+        system_instruction::transfer(
+            &context.payer.pubkey(),
+            &sponsor.pubkey(),
+            1_000_000_000,
+        ),
+        ixs::add_co_sponsor(
+            sponsor.pubkey(),
+            context.payer.pubkey(),
+            ID,
+            3_000,
+        )
+        .expect("failed to create instruction"),
+        over_fund_ix(&context, &challenge_pda),
+        sweep_ix(&context, treasury),
+    ];
+    process_ixs(&mut context, &ixs, &[&sponsor])
+        .await
+        .expect("Failed to sweep surplus");
+
+    let (challenge_acc, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(challenge.sponsored_lamports, 3_000);
+    assert_eq!(
+        challenge_acc.lamports,
+        rent_exempt_minimum(challenge_acc.data.len()) + 3_000
+    );
+    let treasury_acc = get_account(&mut context, &treasury).await;
+    assert_eq!(treasury_acc.lamports, treasury_lamports + 1_000);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn sweep_surplus_without_surplus_fails() {
+    let mut context = program_test().start_with_context().await;
+    let (_, treasury) = setup(&mut context).await;
+
+    let ix = sweep_ix(&context, treasury);
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::NoSurplusToSweep);
+}
+
+#[tokio::test]
+async fn sweep_surplus_to_challenge_itself_fails() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge_pda, _) = setup(&mut context).await;
+
+    let ixs = [
+        over_fund_ix(&context, &challenge_pda),
+        sweep_ix(&context, challenge_pda),
+    ];
+    let res = process_ixs(&mut context, &ixs, &[]).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,216 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    challenge_id,
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, Challenger, HasPda},
+    utils::hash_solutions,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::*;
+
+use solana_sdk::signature::Signer;
+
+use crate::utils::{
+    add_pda_account, assert_challenge_error, get_deserialized, process_ixs,
+    program_test,
+};
+
+mod utils;
+
+const STAGE_ONE: &str = "stage-one";
+const STAGE_TWO: &str = "stage-two";
+
+fn started_challenge(
+    creator: Pubkey,
+    id: &str,
+    prerequisite: Option<Pubkey>,
+) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: id.to_string(),
+        started: true,
+        admit_cost: 200,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(&["one"]),
+        prerequisite,
+        ..Challenge::default()
+    }
+}
+
+/// Adds the first stage and the second stage gated by it, returning the PDA of the first.
+fn add_stages(context: &mut ProgramTestContext) -> Pubkey {
+    let creator = context.payer.pubkey();
+    let stage_one = started_challenge(creator, STAGE_ONE, None);
+    let (stage_one_pda, _) = stage_one.pda();
+    add_pda_account(context, &stage_one);
+    add_pda_account(
+        context,
+        &started_challenge(creator, STAGE_TWO, Some(stage_one_pda)),
+    );
+    stage_one_pda
+}
+
+/// Adds the record of the [challenger] on the first stage.
+fn add_stage_one_record(
+    context: &mut ProgramTestContext,
+    stage_one_pda: Pubkey,
+    challenger: Pubkey,
+    redeemed: bool,
+) {
+    add_pda_account(
+        context,
+        &Challenger {
+            authority: challenger,
+            challenge_pda: stage_one_pda,
+            redeemed,
+            ..Challenger::default()
+        },
+    );
+}
+
+fn admit_ix(
+    context: &ProgramTestContext,
+    stage_one_pda: Pubkey,
+    challenger: Pubkey,
+) -> Instruction {
+    let creator = context.payer.pubkey();
+    ixs::admit_challenger_with_prerequisite(
+        creator,
+        creator,
+        STAGE_TWO,
+        challenger,
+        stage_one_pda,
+    )
+    .expect("failed to create instruction")
+    .ix
+}
+
+#[tokio::test]
+async fn set_and_clear_prerequisite() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let stage_one_pda = add_stages(&mut context);
+    let (stage_two_pda, _) = Challenge::pda_for(&creator, STAGE_TWO);
+
+    for prerequisite in [None, Some(stage_one_pda)] {
+        let ix = ixs::set_prerequisite(
+            creator,
+            creator,
+            STAGE_TWO.to_string(),
+            prerequisite,
+        )
+        .expect("failed to create instruction");
+        process_ixs(&mut context, &[ix], &[])
+            .await
+            .expect("Failed to set prerequisite");
+
+        let (_, challenge) =
+            get_deserialized::<Challenge>(&mut context, &stage_two_pda).await;
+        assert_eq!(challenge.prerequisite, prerequisite);
+    }
+}
+
+#[tokio::test]
+async fn admit_challenger_that_completed_prerequisite() {
+    let mut context = program_test().start_with_context().await;
+    let stage_one_pda = add_stages(&mut context);
+    let challenger = Pubkey::new_unique();
+    add_stage_one_record(&mut context, stage_one_pda, challenger, true);
+
+    let ix = admit_ix(&context, stage_one_pda, challenger);
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to admit challenger that completed stage one");
+
+    let creator = context.payer.pubkey();
+    let (stage_two_pda, _) = Challenge::pda_for(&creator, STAGE_TWO);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &stage_two_pda, &challenger);
+    let (_, record) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(record.tries_remaining, 1);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn challenge_cannot_be_its_own_prerequisite() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_stages(&mut context);
+    let (stage_two_pda, _) = Challenge::pda_for(&creator, STAGE_TWO);
+
+    let ix = ixs::set_prerequisite(
+        creator,
+        creator,
+        STAGE_TWO.to_string(),
+        Some(stage_two_pda),
+    )
+    .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::InvalidPrerequisite);
+}
+
+#[tokio::test]
+async fn admit_challenger_that_did_not_redeem_prerequisite_fails() {
+    let mut context = program_test().start_with_context().await;
+    let stage_one_pda = add_stages(&mut context);
+    let challenger = Pubkey::new_unique();
+    add_stage_one_record(&mut context, stage_one_pda, challenger, false);
+
+    let ix = admit_ix(&context, stage_one_pda, challenger);
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::PrerequisiteNotCompleted);
+}
+
+#[tokio::test]
+async fn admit_challenger_never_admitted_to_prerequisite_fails() {
+    let mut context = program_test().start_with_context().await;
+    let stage_one_pda = add_stages(&mut context);
+
+    let ix = admit_ix(&context, stage_one_pda, Pubkey::new_unique());
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::PrerequisiteNotCompleted);
+}
+
+#[tokio::test]
+async fn admit_challenger_with_record_of_other_challenger_fails() {
+    let mut context = program_test().start_with_context().await;
+    let stage_one_pda = add_stages(&mut context);
+    let other = Pubkey::new_unique();
+    add_stage_one_record(&mut context, stage_one_pda, other, true);
+
+    let mut ix = admit_ix(&context, stage_one_pda, Pubkey::new_unique());
+    let (other_record, _) =
+        Challenger::shank_pda(&challenge_id(), &stage_one_pda, &other);
+    *ix.accounts.last_mut().unwrap() =
+        AccountMeta::new_readonly(other_record, false);
+
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedChallengerPdaIncorrect);
+}
+
+#[tokio::test]
+async fn admit_challenger_without_prerequisite_record_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_stages(&mut context);
+
+    let ix = ixs::admit_challenger(
+        creator,
+        creator,
+        STAGE_TWO,
+        Pubkey::new_unique(),
+    )
+    .expect("failed to create instruction")
+    .ix;
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,251 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{epoch_at, Challenge, Challenger, HasPda, Redeem, SolveMode},
+    utils::hash_solutions,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::utils::{
+    add_challenge_with_solutions, add_mint_to_redeem, add_pda_account,
+    assert_challenge_error, get_deserialized, process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const EPOCH_SLOTS: u64 = 100;
+
+fn recurring_challenge(
+    creator: Pubkey,
+    solutions: &[&str],
+    solutions_per_epoch: u8,
+) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: 200,
+        tries_per_admit: 1,
+        redeem: Redeem::for_challenge_with(&creator, ID).pda().0,
+        solutions: hash_solutions(solutions),
+        epoch_slots: EPOCH_SLOTS,
+        solutions_per_epoch,
+        ..Challenge::default()
+    }
+}
+
+/// Adds the [challenge] together with its redeem mint and an admitted challenger which
+/// then redeems the [solution].
+async fn redeem_with(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+    solution: &str,
+) -> Result<(), BanksClientError> {
+    add_pda_account(context, challenge);
+    add_mint_to_redeem(
+        context,
+        &Redeem::for_challenge_with(&challenge.authority, ID),
+    );
+
+    let challenger = Keypair::new();
+    add_pda_account(
+        context,
+        &Challenger {
+            authority: challenger.pubkey(),
+            challenge_pda: challenge.pda().0,
+            tries_remaining: 1,
+            ..Challenger::default()
+        },
+    );
+
+    let ix = ixs::redeem(
+        context.payer.pubkey(),
+        challenge.authority,
+        ID,
+        challenger.pubkey(),
+        solution,
+    )
+    .expect("failed to create instruction");
+    process_ixs(context, &[ix], &[&challenger]).await
+}
+
+// -----------------
+// Epoch Math
+// -----------------
+#[test]
+fn epochs_are_counted_from_their_start_slot() {
+    let challenge = Challenge {
+        epoch_start_slot: 1_000,
+        ..recurring_challenge(Pubkey::new_unique(), &["one"], 0)
+    };
+    assert_eq!(epoch_at(&challenge, 0), Some(0));
+    assert_eq!(epoch_at(&challenge, 1_099), Some(0));
+    assert_eq!(epoch_at(&challenge, 1_100), Some(1));
+    assert_eq!(epoch_at(&challenge, 1_350), Some(3));
+
+    let without_epochs = Challenge {
+        epoch_slots: 0,
+        ..challenge
+    };
+    assert_eq!(epoch_at(&without_epochs, 1_350), None);
+}
+
+#[test]
+fn rolling_over_restarts_solving() {
+    let mut challenge = Challenge {
+        finished: true,
+        solving: 2,
+        ..recurring_challenge(Pubkey::new_unique(), &["one", "two"], 0)
+    };
+    assert!(!challenge.roll_over_epoch(2, EPOCH_SLOTS - 1));
+
+    assert!(challenge.roll_over_epoch(2, EPOCH_SLOTS));
+    assert_eq!(challenge.epoch, 1);
+    assert_eq!(challenge.solving, 0);
+    assert!(!challenge.finished);
+    assert!(!challenge.roll_over_epoch(2, EPOCH_SLOTS + 1));
+}
+
+#[test]
+fn rolling_over_advances_to_next_block_of_solutions() {
+    let mut challenge = Challenge {
+        solve_mode: SolveMode::AnyOrder,
+        solving: 1,
+        ..recurring_challenge(
+            Pubkey::new_unique(),
+            &["one", "two", "three", "four"],
+            2,
+        )
+    };
+    challenge.mark_solved(1);
+    assert!(challenge.accepts_in_epoch(0, 4));
+    assert!(!challenge.accepts_in_epoch(2, 4));
+
+    assert!(challenge.roll_over_epoch(4, EPOCH_SLOTS));
+    assert_eq!(challenge.solving, 2);
+    assert!(challenge.is_solved(0));
+    assert!(challenge.is_solved(1));
+    assert!(!challenge.is_solved(2));
+    assert!(!challenge.accepts_in_epoch(1, 4));
+    assert!(challenge.accepts_in_epoch(3, 4));
+}
+
+#[test]
+fn rolling_over_past_last_block_finishes_challenge() {
+    let mut challenge =
+        recurring_challenge(Pubkey::new_unique(), &["one", "two"], 1);
+
+    assert!(challenge.roll_over_epoch(2, 2 * EPOCH_SLOTS));
+    assert_eq!(challenge.epoch, 2);
+    assert_eq!(challenge.solving, 2);
+    assert!(challenge.finished);
+}
+
+// -----------------
+// Instructions
+// -----------------
+#[tokio::test]
+async fn set_epochs_and_start_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    context.warp_to_slot(500).expect("Failed to warp to slot");
+    let ixs = [
+        ixs::set_epochs(creator, ID.to_string(), EPOCH_SLOTS, 0)
+            .expect("failed to create instruction"),
+        ixs::start_challenge(creator, ID.to_string())
+            .expect("failed to create instruction"),
+    ];
+    process_ixs(&mut context, &ixs, &[])
+        .await
+        .expect("Failed to set epochs and start challenge");
+
+    let (_, challenge) = get_deserialized::<Challenge>(
+        &mut context,
+        &Challenge::pda_for(&creator, ID).0,
+    )
+    .await;
+    assert_eq!(challenge.epoch_slots, EPOCH_SLOTS);
+    assert_eq!(challenge.solutions_per_epoch, 0);
+    assert_eq!(challenge.epoch, 0);
+    assert!(challenge.epoch_start_slot >= 500);
+}
+
+#[tokio::test]
+async fn redeem_reopens_finished_challenge_once_epoch_rolled_over() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = Challenge {
+        finished: true,
+        solving: 1,
+        ..recurring_challenge(context.payer.pubkey(), &["one"], 0)
+    };
+
+    context
+        .warp_to_slot(EPOCH_SLOTS + 1)
+        .expect("Failed to warp to slot");
+    redeem_with(&mut context, &challenge, "one")
+        .await
+        .expect("Failed to redeem after epoch rolled over");
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(value.epoch, 1);
+    assert_eq!(value.solving, 1);
+    assert!(value.finished);
+}
+
+#[tokio::test]
+async fn redeem_next_block_once_epoch_rolled_over() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = Challenge {
+        solving: 1,
+        ..recurring_challenge(context.payer.pubkey(), &["one", "two"], 1)
+    };
+
+    context
+        .warp_to_slot(EPOCH_SLOTS + 1)
+        .expect("Failed to warp to slot");
+    redeem_with(&mut context, &challenge, "two")
+        .await
+        .expect("Failed to redeem the block of the next epoch");
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge.pda().0).await;
+    assert_eq!(value.epoch, 1);
+    assert_eq!(value.solving, 2);
+    assert!(value.finished);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn redeem_solution_of_next_block_fails() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = Challenge {
+        solving: 1,
+        ..recurring_challenge(context.payer.pubkey(), &["one", "two"], 1)
+    };
+
+    let res = redeem_with(&mut context, &challenge, "two").await;
+    assert_challenge_error(res, ChallengeError::SolutionNotInCurrentEpoch);
+}
+
+#[tokio::test]
+async fn set_solutions_per_epoch_without_epochs_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let ix = ixs::set_epochs(creator, ID.to_string(), 0, 1)
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::InvalidEpochs);
+}
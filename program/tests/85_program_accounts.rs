@@ -0,0 +1,118 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs,
+    state::{Challenge, Challenger, HasPda},
+    utils::hash_solutions,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::*;
+
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::utils::{
+    add_challenge_with_solutions, add_pda_account, assert_challenge_error,
+    process_ixs, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+/// Replaces the account of the [ix] at [index] with a fake program or sysvar.
+fn with_fake_account(mut ix: Instruction, index: usize) -> Instruction {
+    ix.accounts[index] = AccountMeta::new_readonly(Pubkey::new_unique(), false);
+    ix
+}
+
+/// Adds a started challenge of the payer and a challenger admitted to it.
+fn add_challenger(context: &mut ProgramTestContext) -> Keypair {
+    let challenge = Challenge {
+        authority: context.payer.pubkey(),
+        id: ID.to_string(),
+        started: true,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(&["one"]),
+        ..Challenge::default()
+    };
+    add_pda_account(context, &challenge);
+
+    let challenger = Keypair::new();
+    add_pda_account(
+        context,
+        &Challenger {
+            authority: challenger.pubkey(),
+            challenge_pda: challenge.pda().0,
+            tries_remaining: 1,
+            ..Challenger::default()
+        },
+    );
+    challenger
+}
+
+fn redeem_ix(
+    context: &ProgramTestContext,
+    challenger: &Keypair,
+) -> Instruction {
+    ixs::redeem(
+        context.payer.pubkey(),
+        context.payer.pubkey(),
+        ID,
+        challenger.pubkey(),
+        "one",
+    )
+    .expect("failed to create instruction")
+}
+
+#[tokio::test]
+async fn set_prerequisite_with_fake_system_program_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+
+    let ix = ixs::set_prerequisite(creator, creator, ID.to_string(), None)
+        .expect("failed to create instruction");
+    let ix = with_fake_account(ix, 3);
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedProgramIncorrect);
+}
+
+#[tokio::test]
+async fn redeem_with_fake_programs_fails() {
+    let mut context = program_test().start_with_context().await;
+    let challenger = add_challenger(&mut context);
+
+    // the spl token, associated token and system programs follow the redeem accounts
+    for index in 6..=8 {
+        let ix = with_fake_account(redeem_ix(&context, &challenger), index);
+        let res = process_ixs(&mut context, &[ix], &[&challenger]).await;
+        assert_challenge_error(res, ChallengeError::ProvidedProgramIncorrect);
+    }
+}
+
+#[tokio::test]
+async fn verify_solve_signature_with_fake_instructions_sysvar_fails() {
+    let mut context = program_test().start_with_context().await;
+    let challenger = add_challenger(&mut context);
+
+    // the ed25519 instruction isn't needed since the sysvar is checked first
+    let ixs = ixs::verify_solve_signature(
+        context.payer.pubkey(),
+        context.payer.pubkey(),
+        ID,
+        challenger.pubkey(),
+        Pubkey::new_unique(),
+        0,
+        &hash_solutions(&["one"])[0],
+        [0; 64],
+    )
+    .expect("failed to create instructions");
+    let ix = with_fake_account(ixs[1].clone(), 9);
+    let res = process_ixs(&mut context, &[ix], &[&challenger]).await;
+    assert_challenge_error(res, ChallengeError::ProvidedSysvarIncorrect);
+}
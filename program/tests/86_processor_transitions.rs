@@ -0,0 +1,414 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    clock::Now,
+    error::ChallengeError,
+    processor::{
+        add_solutions, admit_challenger, attempt_solve, buy_more_tries,
+        cancel_challenge, prize_for, refund_unused_tries, remove_solutions,
+        replace_solutions, set_epochs, set_max_tries_per_challenger,
+        set_prerequisite, solve, start_challenge, truncate_solutions,
+        update_challenge, SizeChange,
+    },
+    state::{
+        Challenge, ChallengeHeader, Challenger, HasDiscriminator, HasSize,
+        Payout, PrizePool, SolveMode,
+    },
+    utils::hash_solutions,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+fn challenge_with(solutions: &[&str]) -> Challenge {
+    Challenge {
+        authority: Pubkey::new_unique(),
+        id: "challenge-id".to_string(),
+        admit_cost: 300,
+        tries_per_admit: 4,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(solutions),
+        ..Challenge::default()
+    }
+}
+
+fn assert_error<T: std::fmt::Debug>(
+    res: Result<T, ProgramError>,
+    err: ChallengeError,
+) {
+    assert_eq!(res.unwrap_err(), ProgramError::from(err));
+}
+
+// -----------------
+// Solutions
+// -----------------
+#[test]
+fn add_solutions_reports_grown_size() {
+    let mut challenge = challenge_with(&["one"]);
+    let before = challenge.size();
+
+    let size_change = add_solutions(
+        &mut challenge,
+        hash_solutions(&["two", "three"]),
+        false,
+        &[],
+    )
+    .expect("Failed to add solutions");
+
+    assert_eq!(
+        challenge.solutions,
+        hash_solutions(&["one", "two", "three"])
+    );
+    assert_eq!(
+        size_change,
+        SizeChange {
+            before,
+            after: challenge.size()
+        }
+    );
+    assert_eq!(size_change.after - size_change.before, 2 * 32);
+}
+
+#[test]
+fn add_duplicate_solutions_only_when_allowed() {
+    let mut challenge = challenge_with(&["one"]);
+
+    let res =
+        add_solutions(&mut challenge, hash_solutions(&["one"]), false, &[]);
+    assert_error(res, ChallengeError::DuplicateSolution);
+    assert_eq!(challenge.solutions.len(), 1);
+
+    add_solutions(&mut challenge, hash_solutions(&["one"]), true, &[])
+        .expect("Failed to add duplicate solution");
+    assert_eq!(challenge.solutions.len(), 2);
+}
+
+#[test]
+fn truncate_and_remove_solutions_report_shrunk_size() {
+    let mut challenge = challenge_with(&["one", "two", "three", "four"]);
+
+    let truncated = truncate_solutions(&mut challenge, 3)
+        .expect("Failed to truncate solutions");
+    assert_eq!(truncated.before - truncated.after, 32);
+
+    let removed = remove_solutions(&mut challenge, vec![2, 0])
+        .expect("Failed to remove solutions");
+    assert_eq!(removed.before, truncated.after);
+    assert_eq!(removed.before - removed.after, 2 * 32);
+    assert_eq!(challenge.solutions, hash_solutions(&["two"]));
+}
+
+#[test]
+fn remove_solutions_keeps_solving_pointed_at_same_solution() {
+    let mut challenge = Challenge {
+        started: true,
+        solving: 2,
+        ..challenge_with(&["one", "two", "three", "four"])
+    };
+
+    remove_solutions(&mut challenge, vec![3, 1])
+        .expect("Failed to remove solutions");
+    assert_eq!(challenge.solving, 1);
+    assert_eq!(challenge.solutions[1], hash_solutions(&["three"])[0]);
+    assert!(!challenge.finished);
+
+    remove_solutions(&mut challenge, vec![1])
+        .expect("Failed to remove solutions");
+    assert!(challenge.finished);
+}
+
+#[test]
+fn replace_solutions_clears_rewards() {
+    let mut challenge = Challenge {
+        rewards: vec![1, 2],
+        ..challenge_with(&["one", "two"])
+    };
+
+    replace_solutions(&mut challenge, hash_solutions(&["three"]))
+        .expect("Failed to replace solutions");
+    assert_eq!(challenge.solutions, hash_solutions(&["three"]));
+    assert!(challenge.rewards.is_empty());
+}
+
+#[test]
+fn changing_solutions_after_redeeming_fails() {
+    let mut challenge = Challenge {
+        started: true,
+        solving: 1,
+        ..challenge_with(&["one", "two"])
+    };
+
+    let res = truncate_solutions(&mut challenge, 1);
+    assert_error(res, ChallengeError::ChallengeHasRedeemedSolutions);
+    let res = replace_solutions(&mut challenge, hash_solutions(&["three"]));
+    assert!(res.is_err());
+    assert_eq!(challenge.solutions, hash_solutions(&["one", "two"]));
+}
+
+// -----------------
+// Lifecycle
+// -----------------
+#[test]
+fn start_challenge_starts_first_epoch() {
+    let mut challenge = challenge_with(&["one"]);
+    set_epochs(&mut challenge, 100, 0).expect("Failed to set epochs");
+
+    start_challenge(&mut challenge, 500).expect("Failed to start challenge");
+    assert!(challenge.started);
+    assert_eq!(challenge.epoch_start_slot, 500);
+
+    let res = start_challenge(&mut challenge, 600);
+    assert_error(res, ChallengeError::ChallengeAlreadyStarted);
+    let res = set_epochs(&mut challenge, 200, 0);
+    assert_error(res, ChallengeError::ChallengeAlreadyStarted);
+}
+
+#[test]
+fn start_challenge_without_solutions_fails() {
+    let mut challenge = challenge_with(&[]);
+    let res = start_challenge(&mut challenge, 500);
+    assert_error(res, ChallengeError::ChallengeHasNoSolutions);
+    assert!(!challenge.started);
+}
+
+#[test]
+fn update_challenge_only_changes_provided_terms() {
+    let mut challenge = challenge_with(&["one"]);
+    let redeem = challenge.redeem;
+
+    update_challenge(&mut challenge, Some(500), None, None)
+        .expect("Failed to update challenge");
+    assert_eq!(challenge.admit_cost, 500);
+    assert_eq!(challenge.tries_per_admit, 4);
+    assert_eq!(challenge.redeem, redeem);
+
+    cancel_challenge(&mut challenge).expect("Failed to cancel challenge");
+    assert!(challenge.paused);
+    assert!(challenge.cancelled);
+
+    let res = update_challenge(&mut challenge, None, Some(2), None);
+    assert_error(res, ChallengeError::ChallengeCancelled);
+    assert_eq!(challenge.tries_per_admit, 4);
+}
+
+#[test]
+fn cancel_finished_challenge_fails() {
+    let mut challenge = Challenge {
+        started: true,
+        finished: true,
+        ..challenge_with(&["one"])
+    };
+    let res = cancel_challenge(&mut challenge);
+    assert_error(res, ChallengeError::ChallengeAlreadyFinished);
+    assert!(!challenge.cancelled);
+}
+
+#[test]
+fn set_prerequisite_resizes_challenge() {
+    let mut challenge = challenge_with(&["one"]);
+    let challenge_pda = Pubkey::new_unique();
+
+    let set = set_prerequisite(
+        &mut challenge,
+        &challenge_pda,
+        Some(Pubkey::new_unique()),
+    )
+    .expect("Failed to set prerequisite");
+    assert_eq!(set.after - set.before, 32);
+
+    let cleared = set_prerequisite(&mut challenge, &challenge_pda, None)
+        .expect("Failed to clear prerequisite");
+    assert_eq!(cleared.after, set.before);
+
+    let res =
+        set_prerequisite(&mut challenge, &challenge_pda, Some(challenge_pda));
+    assert_error(res, ChallengeError::InvalidPrerequisite);
+}
+
+// -----------------
+// Admissions
+// -----------------
+#[test]
+fn admit_challenger_grants_tries_and_counts_admission() {
+    let mut challenge = Challenge {
+        started: true,
+        max_admissions: Some(1),
+        ..challenge_with(&["one"])
+    };
+    let challenge_pda = Pubkey::new_unique();
+    let challenger = Pubkey::new_unique();
+    let now = Now::new(500, 1_000);
+
+    let admitted =
+        admit_challenger(&mut challenge, challenge_pda, challenger, &[], &now)
+            .expect("Failed to admit challenger");
+    assert_eq!(admitted.authority, challenger);
+    assert_eq!(admitted.challenge_pda, challenge_pda);
+    assert_eq!(admitted.tries_remaining, 4);
+    assert_eq!(admitted.tries_admitted, 4);
+    assert_eq!(challenge.admissions, 1);
+
+    let res = admit_challenger(
+        &mut challenge,
+        challenge_pda,
+        Pubkey::new_unique(),
+        &[],
+        &now,
+    );
+    assert_error(res, ChallengeError::NoAdmissionsLeft);
+    assert_eq!(challenge.admissions, 1);
+}
+
+#[test]
+fn admit_challenger_before_start_fails() {
+    let mut challenge = challenge_with(&["one"]);
+    let res = admit_challenger(
+        &mut challenge,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        &[],
+        &Now::new(500, 1_000),
+    );
+    assert_error(res, ChallengeError::ChallengeNotYetStarted);
+    assert_eq!(challenge.admissions, 0);
+}
+
+#[test]
+fn refund_unused_tries_of_cancelled_challenge() {
+    let mut challenge = Challenge {
+        has_prize_pool: true,
+        ..challenge_with(&["one"])
+    };
+    let mut challenger = Challenger {
+        tries_remaining: 2,
+        refundable: 150,
+        ..Challenger::default()
+    };
+
+    let res = refund_unused_tries(&challenge, &mut challenger);
+    assert_error(res, ChallengeError::ChallengeNotCancelled);
+
+    cancel_challenge(&mut challenge).expect("Failed to cancel challenge");
+    let refund = refund_unused_tries(&challenge, &mut challenger)
+        .expect("Failed to refund unused tries");
+    assert_eq!(refund, 150);
+    assert_eq!(challenger.tries_remaining, 0);
+    assert_eq!(challenger.refundable, 0);
+
+    let res = refund_unused_tries(&challenge, &mut challenger);
+    assert_error(res, ChallengeError::NothingToRefund);
+}
+
+// -----------------
+// Tries
+// -----------------
+#[test]
+fn buy_more_tries_returns_pro_rated_cost() {
+    let mut challenge = challenge_with(&["one"]);
+    set_max_tries_per_challenger(&mut challenge, Some(6))
+        .expect("Failed to set max tries per challenger");
+    let mut challenger = Challenger {
+        tries_remaining: 1,
+        tries_admitted: 4,
+        tries_used: 3,
+        ..Challenger::default()
+    };
+
+    let cost = buy_more_tries(&challenge, &mut challenger, 2)
+        .expect("Failed to buy more tries");
+    assert_eq!(cost, 150);
+    assert_eq!(challenger.tries_remaining, 3);
+    assert_eq!(challenger.tries_admitted, 6);
+
+    let res = buy_more_tries(&challenge, &mut challenger, 1);
+    assert_error(res, ChallengeError::MaxTriesPerChallengerExceeded);
+    let res = buy_more_tries(&challenge, &mut challenger, 0);
+    assert_error(res, ChallengeError::InvalidTriesCount);
+    assert_eq!(challenger.tries_remaining, 3);
+}
+
+// -----------------
+// Redeem
+// -----------------
+fn header_of(challenge: &Challenge) -> ChallengeHeader {
+    let data = challenge
+        .to_account_data()
+        .expect("Failed to serialize challenge");
+    ChallengeHeader::try_from_account_data(&data)
+        .expect("Failed to read challenge header")
+}
+
+#[test]
+fn attempt_solve_uses_up_tries_respecting_cooldown() {
+    let challenge = Challenge {
+        attempt_cooldown_slots: 10,
+        ..challenge_with(&["one"])
+    };
+    let mut challenger = Challenger {
+        tries_remaining: 2,
+        ..Challenger::default()
+    };
+
+    attempt_solve("redeem", &challenge, &mut challenger, 100)
+        .expect("Failed to attempt solve");
+    assert_eq!(challenger.tries_remaining, 1);
+    assert_eq!(challenger.tries_used, 1);
+    assert_eq!(challenger.last_attempt_slot, 100);
+
+    let res = attempt_solve("redeem", &challenge, &mut challenger, 105);
+    assert_error(res, ChallengeError::AttemptTooSoon);
+
+    attempt_solve("redeem", &challenge, &mut challenger, 110)
+        .expect("Failed to attempt solve after cooldown");
+    let res = attempt_solve("redeem", &challenge, &mut challenger, 120);
+    assert_error(res, ChallengeError::ChallengerHasNoTriesRemaining);
+}
+
+#[test]
+fn solve_marks_solution_solved_once_all_winners_solved_it() {
+    let mut header = header_of(&Challenge {
+        started: true,
+        max_winners_per_solution: 2,
+        ..challenge_with(&["one", "two"])
+    });
+    let mut first = Challenger::default();
+    let mut second = Challenger::default();
+
+    let solved = solve(&mut header, &mut first, 0, 0).expect("Failed to solve");
+    assert!(!solved);
+    assert!(first.redeemed);
+    assert_eq!(header.challenge.solving, 0);
+
+    let solved =
+        solve(&mut header, &mut second, 0, 1).expect("Failed to solve");
+    assert!(solved);
+    assert!(second.redeemed);
+    assert_eq!(header.challenge.solving, 1);
+    assert!(!header.challenge.finished);
+}
+
+#[test]
+fn prize_for_splits_pool_among_remaining_solutions() {
+    let prize_pool = || PrizePool {
+        challenge_pda: Pubkey::new_unique(),
+        payout: Payout::WinnerTakesAll,
+    };
+    let sequential = header_of(&Challenge {
+        started: true,
+        ..challenge_with(&["one", "two"])
+    });
+    assert_eq!(prize_for(&sequential, prize_pool(), 0, 1, 1_000), 0);
+
+    let any_order = header_of(&Challenge {
+        started: true,
+        solve_mode: SolveMode::AnyOrder,
+        ..challenge_with(&["one", "two"])
+    });
+    assert_eq!(prize_for(&any_order, prize_pool(), 1, 1, 1_000), 500);
+
+    let rewarded = header_of(&Challenge {
+        started: true,
+        rewards: vec![300, 0],
+        ..challenge_with(&["one", "two"])
+    });
+    assert_eq!(prize_for(&rewarded, prize_pool(), 0, 1, 1_000), 300);
+    assert_eq!(prize_for(&rewarded, prize_pool(), 0, 1, 200), 200);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{error::ChallengeError, ixs};
+use solana_program::{
+    decode_error::DecodeError, program_error::ProgramError, pubkey::Pubkey,
+};
+use solana_program_test::*;
+
+use solana_sdk::signature::Signer;
+
+use crate::utils::{
+    add_challenge_with_solutions, assert_challenge_error, process_ixs,
+    program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+// -----------------
+// Codes
+// -----------------
+#[test]
+fn error_codes_map_back_to_errors() {
+    for err in [
+        ChallengeError::AccountShouldBeSigner,
+        ChallengeError::SolutionNotInCurrentEpoch,
+        ChallengeError::ChallengeNotFound,
+        ChallengeError::ChallengerNotAdmitted,
+        ChallengeError::ContributionOverflow,
+        ChallengeError::AdmitTokensNotWithdrawn,
+        ChallengeError::ChallengeHasAdmittedChallengers,
+    ] {
+        let code = match ProgramError::from(err.clone()) {
+            ProgramError::Custom(code) => code,
+            other => panic!("expected a custom error but got {:?}", other),
+        };
+        assert_eq!(ChallengeError::from_code(code), Some(err));
+    }
+}
+
+#[test]
+fn unknown_error_codes_do_not_map_to_errors() {
+    let first = ChallengeError::AccountShouldBeSigner as u32;
+    let last = ChallengeError::ChallengeHasAdmittedChallengers as u32;
+    assert_eq!(ChallengeError::from_code(first - 1), None);
+    assert_eq!(ChallengeError::from_code(last + 1), None);
+    assert_eq!(ChallengeError::from_code(0), None);
+    assert_eq!(
+        <ChallengeError as DecodeError<ChallengeError>>::type_of(),
+        "ChallengeError"
+    );
+}
+
+// -----------------
+// Missing Accounts
+// -----------------
+#[tokio::test]
+async fn start_challenge_that_does_not_exist_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ChallengeNotFound);
+}
+
+#[tokio::test]
+async fn buy_more_tries_without_being_admitted_fails() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_challenge_with_solutions(&mut context, ID, vec!["one"], None);
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ixs(&mut context, &[ix], &[])
+        .await
+        .expect("Failed to start challenge");
+
+    let ix = ixs::buy_more_tries(creator, creator, ID, Pubkey::new_unique(), 1)
+        .expect("failed to create instruction");
+    let res = process_ixs(&mut context, &[ix], &[]).await;
+    assert_challenge_error(res, ChallengeError::ChallengerNotAdmitted);
+}
@@ -0,0 +1,134 @@
+#![cfg(all(feature = "test-sbf", feature = "client"))]
+
+use challenge::{
+    event_stream::ChallengeEventStream,
+    events::{parse_logs, ChallengeEvent},
+    ixs,
+    state::Challenge,
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::program_test;
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn process_ixs_returning_logs(
+    context: &mut ProgramTestContext,
+    ixs: &[Instruction],
+) -> Vec<String> {
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("Failed to process transaction");
+    assert!(result.result.is_ok(), "transaction failed {:?}", result);
+
+    result.metadata.expect("transaction metadata").log_messages
+}
+
+fn mock_parser(logs: &[String]) -> Vec<ChallengeEvent> {
+    logs.iter()
+        .filter(|log| log.starts_with("mock: "))
+        .map(|_| ChallengeEvent::ChallengeClosed {
+            challenge_pda: Pubkey::default(),
+        })
+        .collect()
+}
+
+#[test]
+fn event_stream_broadcasts_events_of_the_parser() {
+    let (stream, mut receiver) = ChallengeEventStream::new(mock_parser);
+    let logs = vec![
+        "mock: closed".to_string(),
+        "Program log: ignored".to_string(),
+        "mock: closed".to_string(),
+    ];
+
+    assert_eq!(stream.publish_logs(&logs), 2);
+    for _ in 0..2 {
+        assert_eq!(
+            receiver.try_recv(),
+            Ok(ChallengeEvent::ChallengeClosed {
+                challenge_pda: Pubkey::default(),
+            })
+        );
+    }
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn event_stream_publishes_without_receivers() {
+    let (stream, receiver) = ChallengeEventStream::new(mock_parser);
+    drop(receiver);
+
+    assert_eq!(stream.publish_logs(&["mock: closed".to_string()]), 1);
+
+    // receivers only get events published after they subscribed
+    let mut receiver = stream.subscribe();
+    assert!(receiver.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn event_stream_broadcasts_events_of_processed_transactions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let (stream, mut receiver) = ChallengeEventStream::new(parse_logs);
+    let mut other_receiver = stream.subscribe();
+
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        1000,
+        1,
+        vec!["one"],
+        None,
+        None,
+    )
+    .expect("failed to create instruction");
+    let logs = process_ixs_returning_logs(&mut context, &[ix]).await;
+    assert_eq!(stream.publish_logs(&logs), 1);
+
+    let created = ChallengeEvent::ChallengeCreated {
+        challenge_pda,
+        authority: creator,
+        id: ID.to_string(),
+        solutions: 1,
+    };
+    assert_eq!(receiver.recv().await, Ok(created.clone()));
+    assert_eq!(other_receiver.recv().await, Ok(created));
+
+    let ix = ixs::add_solutions(
+        creator,
+        creator,
+        ID.to_string(),
+        vec!["two", "three"],
+    )
+    .expect("failed to create instruction");
+    let logs = process_ixs_returning_logs(&mut context, &[ix]).await;
+    assert_eq!(stream.publish_logs(&logs), 1);
+
+    assert_eq!(
+        receiver.recv().await,
+        Ok(ChallengeEvent::SolutionsAdded {
+            challenge_pda,
+            added: 2,
+            total: 3,
+        })
+    );
+}
@@ -0,0 +1,113 @@
+use solana_program::instruction::Instruction;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+/// Compute units an instruction consumed together with the budget it should stay within.
+pub struct ComputeUnits {
+    pub label: String,
+    pub consumed: u64,
+    pub budget: u64,
+}
+
+/// Measures the compute units consumed by each instruction in order to catch regressions in
+/// processor cost.
+/// Run with `--nocapture` to see the per instruction report.
+#[derive(Default)]
+pub struct ComputeUnitsBench {
+    measurements: Vec<ComputeUnits>,
+}
+
+impl ComputeUnitsBench {
+    /// Simulates a transaction holding only the [ix] to measure its compute units and then
+    /// processes it so that following instructions observe its effects.
+    /// The [context] payer always signs, additional [signers] are provided as needed.
+    #[allow(unused)]
+    pub async fn measure(
+        &mut self,
+        context: &mut ProgramTestContext,
+        label: &str,
+        ix: Instruction,
+        signers: &[&Keypair],
+        budget: u64,
+    ) -> Result<u64, BanksClientError> {
+        let tx = {
+            let mut all_signers = vec![&context.payer];
+            all_signers.extend(signers);
+            Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&context.payer.pubkey()),
+                &all_signers,
+                context.last_blockhash,
+            )
+        };
+
+        let simulation = context
+            .banks_client
+            .simulate_transaction(tx.clone())
+            .await?;
+        if let Some(Err(err)) = simulation.result {
+            return Err(BanksClientError::TransactionError(err));
+        }
+        let consumed = simulation
+            .simulation_details
+            .map(|details| details.units_consumed)
+            .unwrap_or_default();
+
+        context.banks_client.process_transaction(tx).await?;
+
+        self.measurements.push(ComputeUnits {
+            label: label.to_string(),
+            consumed,
+            budget,
+        });
+        Ok(consumed)
+    }
+
+    #[allow(unused)]
+    pub fn report(&self) -> String {
+        let label_width = self
+            .measurements
+            .iter()
+            .map(|x| x.label.len())
+            .max()
+            .unwrap_or_default();
+
+        let mut report = format!(
+            "{:<label_width$} {:>10} {:>10}\n",
+            "instruction", "consumed", "budget"
+        );
+        for ComputeUnits {
+            label,
+            consumed,
+            budget,
+        } in &self.measurements
+        {
+            let marker = if consumed > budget { " !" } else { "" };
+            report.push_str(&format!(
+                "{:<label_width$} {:>10} {:>10}{}\n",
+                label, consumed, budget, marker
+            ));
+        }
+        report
+    }
+
+    /// Prints the report and fails if any instruction exceeded its budget.
+    #[allow(unused)]
+    pub fn assert_within_budgets(&self) {
+        println!("{}", self.report());
+
+        let exceeded = self
+            .measurements
+            .iter()
+            .filter(|x| x.consumed > x.budget)
+            .map(|x| format!("{} ({} > {})", x.label, x.consumed, x.budget))
+            .collect::<Vec<_>>();
+        assert!(
+            exceeded.is_empty(),
+            "instructions exceeded their compute unit budget: {}",
+            exceeded.join(", ")
+        );
+    }
+}
@@ -2,7 +2,7 @@ use borsh::BorshSerialize;
 use challenge::{
     challenge_id,
     ixs::ChallengeInstruction,
-    state::{HasPda, Redeem},
+    state::{HasPda, HashAlgorithm, Redeem},
     Solution,
 };
 use solana_program::{
@@ -36,6 +36,10 @@ pub fn create_challenge_with_pda(
             tries_per_admit,
             redeem,
             solutions,
+            salt: None,
+            hash_algorithm: HashAlgorithm::Sha256,
+            starts_at: None,
+            ends_at: None,
         }
         .try_to_vec()?,
     };